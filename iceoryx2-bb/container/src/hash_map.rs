@@ -0,0 +1,770 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Three hash map variations that are similar to [`std::collections::HashMap`].
+//!
+//!  * [`FixedSizeHashMap`](crate::hash_map::FixedSizeHashMap), compile-time fixed size hash map
+//!     that is self-contained.
+//!  * [`RelocatableHashMap`](crate::hash_map::RelocatableHashMap), run-time fixed size hash map
+//!     that acquires the required memory from a custom user-provided allocator.
+//!  * [`HashMap`](crate::hash_map::HashMap), run-time fixed size hash map that uses by default
+//!     heap memory.
+//!
+//! The hash map never reallocates, it can store at most `capacity` entries. It uses open
+//! addressing with linear probing and the bucket of a key is computed with a fixed hash function
+//! from the byte stream defined by [`StableHash`], so that every process that has the hash map
+//! mapped finds the same entry, even when it was built with another Rust version or for another
+//! pointer width.
+//!
+//! # Basic Examples
+//!
+//! ## Use the [`FixedSizeHashMap`](crate::hash_map::FixedSizeHashMap)
+//!
+//! ```
+//! use iceoryx2_bb_container::hash_map::FixedSizeHashMap;
+//!
+//! const MAP_CAPACITY: usize = 8;
+//! let mut map = FixedSizeHashMap::<u64, u32, MAP_CAPACITY>::new();
+//!
+//! map.insert(123, 456);
+//!
+//! println!("value of 123 is {}", map.get(&123).unwrap());
+//! ```
+//!
+//! ## Use the [`HashMap`](crate::hash_map::HashMap)
+//!
+//! ```
+//! use iceoryx2_bb_container::hash_map::HashMap;
+//!
+//! let map_capacity = 1234;
+//! let mut map = HashMap::<u64, u32>::new(map_capacity);
+//!
+//! map.insert(123, 456);
+//!
+//! println!("removed value {}", map.remove(&123).unwrap());
+//! ```
+//!
+//! # Advanced Examples
+//!
+//! ## Create [`RelocatableHashMap`](crate::hash_map::RelocatableHashMap) with allocator
+//!
+//! ```
+//! use iceoryx2_bb_container::hash_map::RelocatableHashMap;
+//! use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+//! use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+//!
+//! const MAP_CAPACITY: usize = 12;
+//! const MEM_SIZE: usize = RelocatableHashMap::<u64, u64>::const_memory_size(MAP_CAPACITY);
+//! let mut memory = [0u8; MEM_SIZE];
+//!
+//! let bump_allocator = BumpAllocator::new(memory.as_mut_ptr() as usize);
+//!
+//! let mut map = unsafe { RelocatableHashMap::<u64, u64>::new_uninit(MAP_CAPACITY) };
+//! unsafe { map.init(&bump_allocator).expect("hash map init failed") };
+//!
+//! unsafe { map.insert(1, 2) };
+//! ```
+//!
+use crate::byte_string::FixedSizeByteString;
+use iceoryx2_bb_elementary::allocator::{AllocationError, BaseAllocator};
+use iceoryx2_bb_elementary::math::align_to;
+use iceoryx2_bb_elementary::math::unaligned_mem_size;
+use iceoryx2_bb_elementary::owning_pointer::OwningPointer;
+use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+use iceoryx2_bb_elementary::pointer_trait::PointerTrait;
+pub use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+use std::hash::Hasher;
+use std::marker::PhantomData;
+use std::{alloc::Layout, mem::MaybeUninit};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// FNV-1a hasher. In contrast to the [`std::collections::hash_map::DefaultHasher`] it is
+/// neither seeded nor does its algorithm depend on the Rust version, therefore every process
+/// computes the same bucket for a key.
+struct FixedHasher {
+    state: u64,
+}
+
+impl Default for FixedHasher {
+    fn default() -> Self {
+        Self {
+            state: FNV_OFFSET_BASIS,
+        }
+    }
+}
+
+impl Hasher for FixedHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= *byte as u64;
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.state
+    }
+}
+
+/// The key of a hash map. In contrast to [`std::hash::Hash`] the byte stream that is fed into
+/// the [`Hasher`] does not depend on the Rust version or the platform. Integers are hashed as
+/// little endian bytes and [`usize`] and [`isize`] always with 64 bits.
+///
+/// ```
+/// use iceoryx2_bb_container::hash_map::{FixedSizeHashMap, StableHash};
+/// use std::hash::Hasher;
+///
+/// #[derive(PartialEq, Eq)]
+/// struct MyKey {
+///     id: u32,
+///     index: usize,
+/// }
+///
+/// impl StableHash for MyKey {
+///     fn stable_hash<H: Hasher>(&self, state: &mut H) {
+///         self.id.stable_hash(state);
+///         self.index.stable_hash(state);
+///     }
+/// }
+///
+/// let mut map = FixedSizeHashMap::<MyKey, u64, 8>::new();
+/// map.insert(MyKey { id: 1, index: 2 }, 3);
+/// ```
+pub trait StableHash {
+    /// Feeds the value into the provided [`Hasher`].
+    fn stable_hash<H: Hasher>(&self, state: &mut H);
+}
+
+macro_rules! impl_stable_hash_for_integer {
+    ($($t:ty),*) => {
+        $(impl StableHash for $t {
+            fn stable_hash<H: Hasher>(&self, state: &mut H) {
+                state.write(&self.to_le_bytes());
+            }
+        })*
+    };
+}
+
+impl_stable_hash_for_integer!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl StableHash for usize {
+    fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        (*self as u64).stable_hash(state);
+    }
+}
+
+impl StableHash for isize {
+    fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        (*self as i64).stable_hash(state);
+    }
+}
+
+impl StableHash for bool {
+    fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        (*self as u8).stable_hash(state);
+    }
+}
+
+impl StableHash for char {
+    fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        (*self as u32).stable_hash(state);
+    }
+}
+
+impl<T: StableHash, const N: usize> StableHash for [T; N] {
+    fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        for value in self {
+            value.stable_hash(state);
+        }
+    }
+}
+
+impl<A: StableHash, B: StableHash> StableHash for (A, B) {
+    fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        self.0.stable_hash(state);
+        self.1.stable_hash(state);
+    }
+}
+
+impl<A: StableHash, B: StableHash, C: StableHash> StableHash for (A, B, C) {
+    fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        self.0.stable_hash(state);
+        self.1.stable_hash(state);
+        self.2.stable_hash(state);
+    }
+}
+
+impl<const CAPACITY: usize> StableHash for FixedSizeByteString<CAPACITY> {
+    fn stable_hash<H: Hasher>(&self, state: &mut H) {
+        self.len().stable_hash(state);
+        state.write(self.as_bytes());
+    }
+}
+
+/// Hash map with run-time fixed size capacity. In contrast to its counterpart the
+/// [`RelocatableHashMap`] it is movable but is not shared memory compatible.
+pub type HashMap<K, V> = details::HashMap<K, V, OwningPointer<details::Slot<K, V>>>;
+/// **Non-movable** relocatable hash map with runtime fixed size capacity.
+pub type RelocatableHashMap<K, V> = details::HashMap<K, V, RelocatablePointer<details::Slot<K, V>>>;
+
+/// Iterator over all key-value pairs of a hash map in an unspecified order.
+pub struct Iter<'a, K, V> {
+    slots: *const details::Slot<K, V>,
+    index: usize,
+    remaining: usize,
+    _phantom_data: PhantomData<&'a (K, V)>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            let slot = unsafe { &*self.slots.add(self.index) };
+            self.index += 1;
+
+            if slot.is_occupied {
+                self.remaining -= 1;
+                return Some(unsafe { (slot.key.assume_init_ref(), slot.value.assume_init_ref()) });
+            }
+        }
+
+        None
+    }
+}
+
+pub mod details {
+    use super::*;
+
+    /// A single entry of the hash map. Only the key and value of an occupied slot are
+    /// initialized.
+    #[repr(C)]
+    #[derive(Debug)]
+    pub struct Slot<K, V> {
+        pub(super) is_occupied: bool,
+        pub(super) key: MaybeUninit<K>,
+        pub(super) value: MaybeUninit<V>,
+    }
+
+    /// **Non-movable** relocatable hash map with runtime fixed size capacity.
+    #[repr(C)]
+    #[derive(Debug)]
+    pub struct HashMap<K, V, PointerType: PointerTrait<Slot<K, V>>> {
+        data_ptr: PointerType,
+        len: usize,
+        capacity: usize,
+        are_slots_initialized: bool,
+        is_initialized: IoxAtomicBool,
+        _phantom_data: PhantomData<(K, V)>,
+    }
+
+    unsafe impl<K: Send, V: Send, PointerType: PointerTrait<Slot<K, V>>> Send
+        for HashMap<K, V, PointerType>
+    {
+    }
+
+    impl<K: StableHash + Eq, V> HashMap<K, V, OwningPointer<Slot<K, V>>> {
+        /// Creates a new [`HashMap`] with the provided capacity
+        pub fn new(capacity: usize) -> Self {
+            Self {
+                data_ptr: OwningPointer::<Slot<K, V>>::new_with_alloc(capacity),
+                len: 0,
+                capacity,
+                are_slots_initialized: false,
+                is_initialized: IoxAtomicBool::new(true),
+                _phantom_data: PhantomData,
+            }
+        }
+
+        /// Removes all entries from the hash map
+        pub fn clear(&mut self) {
+            unsafe { self.clear_impl() }
+        }
+
+        /// Inserts a key-value pair into the hash map. If the key is already contained, its value
+        /// is replaced. If the hash map is full it returns false, otherwise true.
+        pub fn insert(&mut self, key: K, value: V) -> bool {
+            unsafe { self.insert_impl(key, value) }
+        }
+
+        /// Removes the entry of the provided key and returns its value. If the key is not
+        /// contained it returns [`None`].
+        pub fn remove(&mut self, key: &K) -> Option<V> {
+            unsafe { self.remove_impl(key) }
+        }
+
+        /// Returns a reference to the value of the provided key. If the key is not contained it
+        /// returns [`None`].
+        pub fn get(&self, key: &K) -> Option<&V> {
+            unsafe { self.get_impl(key) }
+        }
+
+        /// Returns a mutable reference to the value of the provided key. If the key is not
+        /// contained it returns [`None`].
+        pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+            unsafe { self.get_mut_impl(key) }
+        }
+
+        /// Returns true if the hash map contains the provided key, otherwise false
+        pub fn contains_key(&self, key: &K) -> bool {
+            unsafe { self.find_index(key).is_some() }
+        }
+
+        /// Returns an iterator over all key-value pairs in an unspecified order
+        pub fn iter(&self) -> Iter<'_, K, V> {
+            unsafe { self.iter_impl() }
+        }
+    }
+
+    impl<K, V> RelocatableContainer for HashMap<K, V, RelocatablePointer<Slot<K, V>>> {
+        unsafe fn new(capacity: usize, distance_to_data: isize) -> Self {
+            Self {
+                data_ptr: RelocatablePointer::new(distance_to_data),
+                len: 0,
+                capacity,
+                are_slots_initialized: false,
+                is_initialized: IoxAtomicBool::new(true),
+                _phantom_data: PhantomData,
+            }
+        }
+
+        unsafe fn new_uninit(capacity: usize) -> Self {
+            Self {
+                data_ptr: RelocatablePointer::new_uninit(),
+                len: 0,
+                capacity,
+                are_slots_initialized: false,
+                is_initialized: IoxAtomicBool::new(false),
+                _phantom_data: PhantomData,
+            }
+        }
+
+        unsafe fn init<Allocator: BaseAllocator>(
+            &self,
+            allocator: &Allocator,
+        ) -> Result<(), AllocationError> {
+            if self
+                .is_initialized
+                .load(std::sync::atomic::Ordering::Relaxed)
+            {
+                fatal_panic!(
+                    from "HashMap::init()",
+                    "Memory already initialized. Initializing it twice may lead to undefined behavior."
+                );
+            }
+
+            self.data_ptr.init(fail!(from "HashMap::init", when allocator
+                 .allocate(Layout::from_size_align_unchecked(
+                     std::mem::size_of::<Slot<K, V>>() * self.capacity,
+                     std::mem::align_of::<Slot<K, V>>(),
+                 )), "Failed to initialize hash map since the allocation of the data memory failed."
+            ));
+            self.is_initialized
+                .store(true, std::sync::atomic::Ordering::Relaxed);
+
+            Ok(())
+        }
+
+        fn memory_size(capacity: usize) -> usize {
+            Self::const_memory_size(capacity)
+        }
+    }
+
+    impl<K: StableHash + Eq, V> HashMap<K, V, RelocatablePointer<Slot<K, V>>> {
+        /// Removes all entries from the hash map
+        ///
+        /// # Safety
+        ///
+        ///  * [`HashMap::init()`] must have been called once before
+        ///
+        pub unsafe fn clear(&mut self) {
+            self.clear_impl()
+        }
+
+        /// Inserts a key-value pair into the hash map. If the key is already contained, its value
+        /// is replaced. If the hash map is full it returns false, otherwise true.
+        ///
+        /// # Safety
+        ///
+        ///  * [`HashMap::init()`] must have been called once before
+        ///
+        pub unsafe fn insert(&mut self, key: K, value: V) -> bool {
+            self.insert_impl(key, value)
+        }
+
+        /// Removes the entry of the provided key and returns its value. If the key is not
+        /// contained it returns [`None`].
+        ///
+        /// # Safety
+        ///
+        ///  * [`HashMap::init()`] must have been called once before
+        ///
+        pub unsafe fn remove(&mut self, key: &K) -> Option<V> {
+            self.remove_impl(key)
+        }
+
+        /// Returns a reference to the value of the provided key. If the key is not contained it
+        /// returns [`None`].
+        ///
+        /// # Safety
+        ///
+        ///  * [`HashMap::init()`] must have been called once before
+        ///
+        pub unsafe fn get(&self, key: &K) -> Option<&V> {
+            self.get_impl(key)
+        }
+
+        /// Returns a mutable reference to the value of the provided key. If the key is not
+        /// contained it returns [`None`].
+        ///
+        /// # Safety
+        ///
+        ///  * [`HashMap::init()`] must have been called once before
+        ///
+        pub unsafe fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+            self.get_mut_impl(key)
+        }
+
+        /// Returns true if the hash map contains the provided key, otherwise false
+        ///
+        /// # Safety
+        ///
+        ///  * [`HashMap::init()`] must have been called once before
+        ///
+        pub unsafe fn contains_key(&self, key: &K) -> bool {
+            self.find_index(key).is_some()
+        }
+
+        /// Returns an iterator over all key-value pairs in an unspecified order
+        ///
+        /// # Safety
+        ///
+        ///  * [`HashMap::init()`] must have been called once before
+        ///
+        pub unsafe fn iter(&self) -> Iter<'_, K, V> {
+            self.iter_impl()
+        }
+    }
+
+    impl<K, V, PointerType: PointerTrait<Slot<K, V>>> HashMap<K, V, PointerType> {
+        #[inline(always)]
+        fn verify_init(&self, source: &str) {
+            debug_assert!(
+                self.is_initialized
+                    .load(std::sync::atomic::Ordering::Relaxed),
+                "From: HashMap<{}, {}>::{}, Undefined behavior - the object was not initialized with 'init' before.",
+                std::any::type_name::<K>(),
+                std::any::type_name::<V>(),
+                source
+            );
+        }
+
+        /// Returns the required memory size for a hash map with a specified capacity
+        pub const fn const_memory_size(capacity: usize) -> usize {
+            unaligned_mem_size::<Slot<K, V>>(capacity)
+        }
+
+        /// Returns true if the hash map is empty, otherwise false
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        /// Returns the capacity of the hash map
+        pub fn capacity(&self) -> usize {
+            self.capacity
+        }
+
+        /// Returns the number of entries inside the hash map
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        /// Returns true if the hash map is full, otherwise false
+        pub fn is_full(&self) -> bool {
+            self.len() == self.capacity()
+        }
+
+        pub(crate) unsafe fn clear_impl(&mut self) {
+            if !self.are_slots_initialized {
+                return;
+            }
+
+            self.verify_init("clear()");
+            for i in 0..self.capacity {
+                let slot = &mut *self.data_ptr.as_mut_ptr().add(i);
+                if slot.is_occupied {
+                    slot.is_occupied = false;
+                    slot.key.assume_init_drop();
+                    slot.value.assume_init_drop();
+                }
+            }
+            self.len = 0;
+        }
+
+        pub(crate) unsafe fn iter_impl(&self) -> Iter<'_, K, V> {
+            if !self.is_empty() {
+                self.verify_init("iter()");
+            }
+
+            Iter {
+                slots: self.data_ptr.as_ptr(),
+                index: 0,
+                remaining: self.len,
+                _phantom_data: PhantomData,
+            }
+        }
+
+        // The slot memory is only touched when the first entry is inserted since a hash map
+        // that was created with `RelocatableContainer::new()` must not access its memory
+        // earlier.
+        unsafe fn initialize_slots(&mut self) {
+            for i in 0..self.capacity {
+                self.data_ptr.as_mut_ptr().add(i).write(Slot {
+                    is_occupied: false,
+                    key: MaybeUninit::uninit(),
+                    value: MaybeUninit::uninit(),
+                });
+            }
+            self.are_slots_initialized = true;
+        }
+    }
+
+    impl<K: StableHash + Eq, V, PointerType: PointerTrait<Slot<K, V>>> HashMap<K, V, PointerType> {
+        fn bucket_of(&self, key: &K) -> usize {
+            let mut hasher = FixedHasher::default();
+            key.stable_hash(&mut hasher);
+            (hasher.finish() % self.capacity as u64) as usize
+        }
+
+        pub(crate) unsafe fn find_index(&self, key: &K) -> Option<usize> {
+            if self.is_empty() {
+                return None;
+            }
+
+            self.verify_init("find_index()");
+            let mut index = self.bucket_of(key);
+            for _ in 0..self.capacity {
+                let slot = &*self.data_ptr.as_ptr().add(index);
+                if !slot.is_occupied {
+                    return None;
+                }
+
+                if slot.key.assume_init_ref() == key {
+                    return Some(index);
+                }
+
+                index = (index + 1) % self.capacity;
+            }
+
+            None
+        }
+
+        pub(crate) unsafe fn get_impl(&self, key: &K) -> Option<&V> {
+            let index = self.find_index(key)?;
+            Some((*self.data_ptr.as_ptr().add(index)).value.assume_init_ref())
+        }
+
+        pub(crate) unsafe fn get_mut_impl(&mut self, key: &K) -> Option<&mut V> {
+            let index = self.find_index(key)?;
+            Some(
+                (*self.data_ptr.as_mut_ptr().add(index))
+                    .value
+                    .assume_init_mut(),
+            )
+        }
+
+        pub(crate) unsafe fn insert_impl(&mut self, key: K, value: V) -> bool {
+            if let Some(index) = self.find_index(&key) {
+                let slot = &mut *self.data_ptr.as_mut_ptr().add(index);
+                slot.value.assume_init_drop();
+                slot.value.write(value);
+                return true;
+            }
+
+            if self.is_full() {
+                return false;
+            }
+
+            self.verify_init("insert()");
+            if !self.are_slots_initialized {
+                self.initialize_slots();
+            }
+
+            let mut index = self.bucket_of(&key);
+            while (*self.data_ptr.as_ptr().add(index)).is_occupied {
+                index = (index + 1) % self.capacity;
+            }
+
+            self.data_ptr.as_mut_ptr().add(index).write(Slot {
+                is_occupied: true,
+                key: MaybeUninit::new(key),
+                value: MaybeUninit::new(value),
+            });
+            self.len += 1;
+            true
+        }
+
+        pub(crate) unsafe fn remove_impl(&mut self, key: &K) -> Option<V> {
+            let mut hole = self.find_index(key)?;
+
+            let slot = &mut *self.data_ptr.as_mut_ptr().add(hole);
+            slot.is_occupied = false;
+            slot.key.assume_init_drop();
+            let value = slot.value.assume_init_read();
+            self.len -= 1;
+
+            // backward shift deletion: move every following entry of the probe sequence that
+            // would no longer be found into the hole so that no tombstones are required
+            let mut index = (hole + 1) % self.capacity;
+            loop {
+                let slot = self.data_ptr.as_mut_ptr().add(index);
+                if !(*slot).is_occupied {
+                    break;
+                }
+
+                let bucket = self.bucket_of((*slot).key.assume_init_ref());
+                let is_reachable_from_bucket = if hole < index {
+                    hole < bucket && bucket <= index
+                } else {
+                    hole < bucket || bucket <= index
+                };
+
+                if !is_reachable_from_bucket {
+                    core::ptr::copy_nonoverlapping(slot, self.data_ptr.as_mut_ptr().add(hole), 1);
+                    (*slot).is_occupied = false;
+                    hole = index;
+                }
+
+                index = (index + 1) % self.capacity;
+            }
+
+            Some(value)
+        }
+    }
+
+    impl<K, V, PointerType: PointerTrait<Slot<K, V>>> Drop for HashMap<K, V, PointerType> {
+        fn drop(&mut self) {
+            unsafe { self.clear_impl() }
+        }
+    }
+}
+
+/// Relocatable hash map with compile time fixed size capacity. In contrast to its counterpart
+/// the [`HashMap`] it is movable.
+#[repr(C)]
+#[derive(Debug)]
+pub struct FixedSizeHashMap<K, V, const CAPACITY: usize> {
+    state: RelocatableHashMap<K, V>,
+    _data: [MaybeUninit<details::Slot<K, V>>; CAPACITY],
+}
+
+impl<K, V, const CAPACITY: usize> PlacementDefault for FixedSizeHashMap<K, V, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        let state_ptr = core::ptr::addr_of_mut!((*ptr).state);
+        state_ptr.write(Self::initialize_state());
+    }
+}
+
+impl<K, V, const CAPACITY: usize> Default for FixedSizeHashMap<K, V, CAPACITY> {
+    fn default() -> Self {
+        Self {
+            state: Self::initialize_state(),
+            _data: unsafe { MaybeUninit::uninit().assume_init() },
+        }
+    }
+}
+
+unsafe impl<K: Send, V: Send, const CAPACITY: usize> Send for FixedSizeHashMap<K, V, CAPACITY> {}
+unsafe impl<K: Sync, V: Sync, const CAPACITY: usize> Sync for FixedSizeHashMap<K, V, CAPACITY> {}
+
+impl<K, V, const CAPACITY: usize> FixedSizeHashMap<K, V, CAPACITY> {
+    fn initialize_state() -> RelocatableHashMap<K, V> {
+        unsafe {
+            RelocatableHashMap::new(
+                CAPACITY,
+                align_to::<details::Slot<K, V>>(std::mem::size_of::<RelocatableHashMap<K, V>>())
+                    as isize,
+            )
+        }
+    }
+
+    /// Creates a new hash map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if the hash map is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.state.is_empty()
+    }
+
+    /// Returns the capacity of the hash map
+    pub fn capacity(&self) -> usize {
+        self.state.capacity()
+    }
+
+    /// Returns the number of entries inside the hash map
+    pub fn len(&self) -> usize {
+        self.state.len()
+    }
+
+    /// Returns true if the hash map is full, otherwise false
+    pub fn is_full(&self) -> bool {
+        self.state.is_full()
+    }
+
+    /// Removes all entries from the hash map
+    pub fn clear(&mut self) {
+        unsafe { self.state.clear_impl() }
+    }
+
+    /// Returns an iterator over all key-value pairs in an unspecified order
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        unsafe { self.state.iter_impl() }
+    }
+}
+
+impl<K: StableHash + Eq, V, const CAPACITY: usize> FixedSizeHashMap<K, V, CAPACITY> {
+    /// Inserts a key-value pair into the hash map. If the key is already contained, its value
+    /// is replaced. If the hash map is full it returns false, otherwise true.
+    pub fn insert(&mut self, key: K, value: V) -> bool {
+        unsafe { self.state.insert_impl(key, value) }
+    }
+
+    /// Removes the entry of the provided key and returns its value. If the key is not
+    /// contained it returns [`None`].
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        unsafe { self.state.remove_impl(key) }
+    }
+
+    /// Returns a reference to the value of the provided key. If the key is not contained it
+    /// returns [`None`].
+    pub fn get(&self, key: &K) -> Option<&V> {
+        unsafe { self.state.get_impl(key) }
+    }
+
+    /// Returns a mutable reference to the value of the provided key. If the key is not
+    /// contained it returns [`None`].
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        unsafe { self.state.get_mut_impl(key) }
+    }
+
+    /// Returns true if the hash map contains the provided key, otherwise false
+    pub fn contains_key(&self, key: &K) -> bool {
+        unsafe { self.state.find_index(key).is_some() }
+    }
+}
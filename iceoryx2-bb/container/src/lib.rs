@@ -85,6 +85,8 @@
 
 /// A byte string similar to [`std::string::String`] but it does not support UTF-8
 pub mod byte_string;
+/// A hash map similar to [`std::collections::HashMap`]
+pub mod hash_map;
 /// A queue similar to [`std::collections::VecDeque`]
 pub mod queue;
 /// Extends the [ByteString](crate::byte_string) so that custom string types with a semantic
@@ -296,7 +296,8 @@ pub mod details {
             debug_assert!(
                 self.is_initialized
                     .load(std::sync::atomic::Ordering::Relaxed),
-                "From: {}, Undefined behavior - the object was not initialized with 'init' before.",
+                "From: Queue<{}>::{}, Undefined behavior - the object was not initialized with 'init' before.",
+                std::any::type_name::<T>(),
                 source
             );
         }
@@ -335,7 +336,7 @@ pub mod details {
                 return None;
             }
 
-            self.verify_init(&format!("Queue<{}>::pop()", std::any::type_name::<T>()));
+            self.verify_init("pop()");
             let index = (self.start - self.len) % self.capacity;
             self.len -= 1;
             let value = std::mem::replace(
@@ -350,7 +351,7 @@ pub mod details {
                 return false;
             }
 
-            self.verify_init(&format!("Queue<{}>::push()", std::any::type_name::<T>()));
+            self.verify_init("push()");
 
             self.unchecked_push(value);
             true
@@ -363,10 +364,7 @@ pub mod details {
                 None
             };
 
-            self.verify_init(&format!(
-                "Queue<{}>::push_with_overflow()",
-                std::any::type_name::<T>()
-            ));
+            self.verify_init("push_with_overflow()");
             self.unchecked_push(value);
             overridden_value
         }
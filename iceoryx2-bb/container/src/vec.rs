@@ -160,14 +160,14 @@ impl<T> Deref for RelocatableVec<T> {
     type Target = [T];
 
     fn deref(&self) -> &Self::Target {
-        self.verify_init(&format!("Vec<{}>::push()", std::any::type_name::<T>()));
+        self.verify_init("push()");
         unsafe { core::slice::from_raw_parts((*self.data_ptr.as_ptr()).as_ptr(), self.len) }
     }
 }
 
 impl<T> DerefMut for RelocatableVec<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        self.verify_init(&format!("Vec<{}>::push()", std::any::type_name::<T>()));
+        self.verify_init("push()");
         unsafe {
             core::slice::from_raw_parts_mut((*self.data_ptr.as_mut_ptr()).as_mut_ptr(), self.len)
         }
@@ -198,7 +198,8 @@ impl<T> RelocatableVec<T> {
         debug_assert!(
             self.is_initialized
                 .load(std::sync::atomic::Ordering::Relaxed),
-            "From: {}, Undefined behavior - the object was not initialized with 'init' before.",
+            "From: Vec<{}>::{}, Undefined behavior - the object was not initialized with 'init' before.",
+            std::any::type_name::<T>(),
             source
         );
     }
@@ -240,7 +241,7 @@ impl<T> RelocatableVec<T> {
             return false;
         }
 
-        self.verify_init(&format!("Vec<{}>::push()", std::any::type_name::<T>()));
+        self.verify_init("push()");
         self.push_unchecked(value);
         true
     }
@@ -302,7 +303,7 @@ impl<T> RelocatableVec<T> {
             return None;
         }
 
-        self.verify_init(&format!("Vec<{}>::pop()", std::any::type_name::<T>()));
+        self.verify_init("pop()");
         Some(self.pop_unchecked())
     }
 
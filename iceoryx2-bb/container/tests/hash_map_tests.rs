@@ -0,0 +1,296 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod hash_map {
+    use iceoryx2_bb_container::hash_map::*;
+    use iceoryx2_bb_elementary::{
+        bump_allocator::BumpAllocator, placement_default::PlacementDefault,
+    };
+    use iceoryx2_bb_testing::{assert_that, lifetime_tracker::LifetimeTracker, memory::RawMemory};
+    use std::hash::Hasher;
+
+    const SUT_CAPACITY: usize = 128;
+    type Sut = FixedSizeHashMap<usize, usize, SUT_CAPACITY>;
+
+    #[test]
+    fn newly_created_hash_map_is_empty() {
+        let sut = Sut::new();
+        assert_that!(sut, is_empty);
+        assert_that!(sut, len 0);
+        assert_that!(sut.is_full(), eq false);
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+        assert_that!(sut.get(&0), eq None);
+        assert_that!(sut.iter().count(), eq 0);
+    }
+
+    #[test]
+    fn insert_until_full_and_get_works() {
+        let mut sut = Sut::new();
+
+        for i in 0..sut.capacity() {
+            assert_that!(sut.is_full(), eq false);
+            assert_that!(sut.insert(i * 7, i), eq true);
+            assert_that!(sut, len i + 1);
+        }
+        assert_that!(sut.is_full(), eq true);
+        assert_that!(sut.insert(12345, 0), eq false);
+
+        for i in 0..sut.capacity() {
+            assert_that!(sut.get(&(i * 7)), eq Some(&i));
+            assert_that!(sut.contains_key(&(i * 7)), eq true);
+        }
+        assert_that!(sut.contains_key(&12345), eq false);
+    }
+
+    #[test]
+    fn insert_replaces_value_of_existing_key() {
+        let mut sut = Sut::new();
+
+        assert_that!(sut.insert(42, 1), eq true);
+        assert_that!(sut.insert(42, 2), eq true);
+
+        assert_that!(sut, len 1);
+        assert_that!(sut.get(&42), eq Some(&2));
+    }
+
+    #[test]
+    fn insert_into_full_hash_map_replaces_existing_key() {
+        let mut sut = FixedSizeHashMap::<usize, usize, 2>::new();
+
+        assert_that!(sut.insert(1, 1), eq true);
+        assert_that!(sut.insert(2, 2), eq true);
+        assert_that!(sut.insert(2, 3), eq true);
+
+        assert_that!(sut.get(&2), eq Some(&3));
+    }
+
+    #[test]
+    fn get_mut_modifies_value() {
+        let mut sut = Sut::new();
+
+        sut.insert(8, 9);
+        *sut.get_mut(&8).unwrap() = 10;
+
+        assert_that!(sut.get(&8), eq Some(&10));
+        assert_that!(sut.get_mut(&9), eq None);
+    }
+
+    #[test]
+    fn remove_keeps_all_other_entries_accessible() {
+        let mut sut = Sut::new();
+
+        for i in 0..sut.capacity() {
+            sut.insert(i * 3, i);
+        }
+
+        for i in (0..sut.capacity()).step_by(2) {
+            assert_that!(sut.remove(&(i * 3)), eq Some(i));
+            assert_that!(sut.remove(&(i * 3)), eq None);
+        }
+        assert_that!(sut, len SUT_CAPACITY / 2);
+
+        for i in 0..sut.capacity() {
+            if i % 2 == 0 {
+                assert_that!(sut.get(&(i * 3)), eq None);
+            } else {
+                assert_that!(sut.get(&(i * 3)), eq Some(&i));
+            }
+        }
+    }
+
+    #[test]
+    fn removed_entries_can_be_inserted_again() {
+        let mut sut = Sut::new();
+
+        for _ in 0..3 {
+            for i in 0..sut.capacity() {
+                assert_that!(sut.insert(i, i + 1), eq true);
+            }
+            for i in 0..sut.capacity() {
+                assert_that!(sut.remove(&i), eq Some(i + 1));
+            }
+            assert_that!(sut, is_empty);
+        }
+    }
+
+    #[test]
+    fn iter_visits_all_entries() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY / 2 {
+            sut.insert(i, i * 2);
+        }
+
+        let mut number_of_entries = 0;
+        for (key, value) in sut.iter() {
+            assert_that!(*value, eq * key * 2);
+            number_of_entries += 1;
+        }
+        assert_that!(number_of_entries, eq SUT_CAPACITY / 2);
+    }
+
+    #[test]
+    fn moved_fixed_size_hash_map_keeps_entries() {
+        let mut sut = Sut::new();
+        sut.insert(1, 2);
+
+        let moved_sut = Box::new(sut);
+        assert_that!(moved_sut.get(&1), eq Some(&2));
+    }
+
+    #[test]
+    fn relocatable_insert_remove_works_with_uninitialized_memory() {
+        const MEM_SIZE: usize = RelocatableHashMap::<usize, usize>::const_memory_size(SUT_CAPACITY);
+        let mut memory = [0u8; MEM_SIZE];
+        let allocator = BumpAllocator::new(memory.as_mut_ptr() as usize);
+
+        let mut sut = unsafe { RelocatableHashMap::<usize, usize>::new_uninit(SUT_CAPACITY) };
+        unsafe { assert_that!(sut.init(&allocator), is_ok) };
+
+        for i in 0..sut.capacity() {
+            assert_that!(unsafe { sut.insert(i, i + 5) }, eq true);
+        }
+        assert_that!(sut.is_full(), eq true);
+
+        for i in 0..sut.capacity() {
+            assert_that!(unsafe { sut.get(&i) }, eq Some(&(i + 5)));
+            assert_that!(unsafe { sut.remove(&i) }, eq Some(i + 5));
+        }
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    fn hash_map_with_heap_memory_works() {
+        let mut sut = HashMap::<usize, usize>::new(SUT_CAPACITY);
+
+        assert_that!(sut.insert(3, 4), eq true);
+        assert_that!(sut.get(&3), eq Some(&4));
+        assert_that!(sut.remove(&3), eq Some(4));
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    fn hash_map_with_zero_capacity_rejects_entries() {
+        let mut sut = HashMap::<usize, usize>::new(0);
+
+        assert_that!(sut.insert(3, 4), eq false);
+        assert_that!(sut.get(&3), eq None);
+        assert_that!(sut.remove(&3), eq None);
+    }
+
+    #[test]
+    fn drops_all_objects_when_out_of_scope() {
+        LifetimeTracker::start_tracking();
+        let mut sut = FixedSizeHashMap::<usize, LifetimeTracker, SUT_CAPACITY>::new();
+
+        for i in 0..sut.capacity() {
+            sut.insert(i, LifetimeTracker::new());
+        }
+
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq SUT_CAPACITY);
+        drop(sut);
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 0);
+    }
+
+    #[test]
+    fn drops_all_objects_with_clear() {
+        LifetimeTracker::start_tracking();
+        let mut sut = HashMap::<usize, LifetimeTracker>::new(SUT_CAPACITY);
+
+        for i in 0..sut.capacity() {
+            sut.insert(i, LifetimeTracker::new());
+        }
+
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq SUT_CAPACITY);
+        sut.clear();
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 0);
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    fn remove_and_replace_release_object() {
+        LifetimeTracker::start_tracking();
+        let mut sut = FixedSizeHashMap::<usize, LifetimeTracker, SUT_CAPACITY>::new();
+
+        sut.insert(1, LifetimeTracker::new());
+        sut.insert(2, LifetimeTracker::new());
+        sut.insert(2, LifetimeTracker::new());
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 2);
+
+        drop(sut.remove(&1));
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 1);
+    }
+
+    #[derive(Default)]
+    struct ByteStream {
+        bytes: Vec<u8>,
+    }
+
+    impl Hasher for ByteStream {
+        fn write(&mut self, bytes: &[u8]) {
+            self.bytes.extend_from_slice(bytes);
+        }
+
+        fn finish(&self) -> u64 {
+            0
+        }
+    }
+
+    fn stable_byte_stream<K: StableHash>(key: &K) -> Vec<u8> {
+        let mut hasher = ByteStream::default();
+        key.stable_hash(&mut hasher);
+        hasher.bytes
+    }
+
+    #[test]
+    fn stable_hash_does_not_depend_on_pointer_width() {
+        assert_that!(stable_byte_stream(&1234usize), eq stable_byte_stream(&1234u64));
+        assert_that!(stable_byte_stream(&-1234isize), eq stable_byte_stream(&-1234i64));
+        assert_that!(stable_byte_stream(&0x0102u16), eq vec![0x02, 0x01]);
+    }
+
+    #[test]
+    fn hash_map_with_custom_stable_hash_key_works() {
+        #[derive(Debug, PartialEq, Eq)]
+        struct Key {
+            id: u32,
+            index: usize,
+        }
+
+        impl StableHash for Key {
+            fn stable_hash<H: Hasher>(&self, state: &mut H) {
+                (self.id, self.index).stable_hash(state);
+            }
+        }
+
+        let mut sut = FixedSizeHashMap::<Key, usize, SUT_CAPACITY>::new();
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(Key { id: 7, index: i }, i), eq true);
+        }
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.get(&Key { id: 7, index: i }), eq Some(&i));
+        }
+        assert_that!(sut.get(&Key { id: 8, index: 0 }), eq None);
+    }
+
+    #[test]
+    fn placement_default_works() {
+        let mut sut = RawMemory::<Sut>::new_filled(0xff);
+        unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+        assert_that!(unsafe {sut.assume_init()}, len 0);
+        assert_that!(unsafe {sut.assume_init_mut()}.insert(123, 456), eq true);
+        assert_that!(unsafe {sut.assume_init()}.get(&123), eq Some(&456));
+        assert_that!(unsafe {sut.assume_init_mut()}.remove(&123), eq Some(456));
+    }
+}
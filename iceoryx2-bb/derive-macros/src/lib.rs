@@ -99,3 +99,77 @@ pub fn placement_default_derive(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Implements the [`iceoryx2_bb_elementary::type_layout::TypeLayout`] trait and describes the
+/// offset, size and alignment of every field of the struct.
+///
+/// ```
+/// use iceoryx2_bb_derive_macros::TypeLayout;
+/// use iceoryx2_bb_elementary::type_layout::TypeLayout;
+///
+/// #[derive(TypeLayout)]
+/// #[repr(C)]
+/// struct MyType {
+///     value_1: u8,
+///     value_2: u64,
+/// }
+///
+/// let layouts = MyType::field_layouts();
+/// assert_eq!(layouts[1].offset, core::mem::align_of::<u64>());
+/// ```
+#[proc_macro_derive(TypeLayout)]
+pub fn type_layout_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let field_layout = |member: proc_macro2::TokenStream, ty: &syn::Type| {
+        quote! {
+            iceoryx2_bb_elementary::type_layout::FieldLayout {
+                offset: unsafe {
+                    (core::ptr::addr_of!((*base).#member) as *const u8)
+                        .offset_from(base as *const u8) as usize
+                },
+                size: core::mem::size_of::<#ty>(),
+                alignment: core::mem::align_of::<#ty>(),
+            }
+        }
+    };
+
+    let field_layouts: Vec<proc_macro2::TokenStream> = match input.data {
+        Data::Struct(ref data_struct) => match data_struct.fields {
+            Fields::Named(ref fields_named) => fields_named
+                .named
+                .iter()
+                .map(|f| {
+                    let name = &f.ident;
+                    field_layout(quote! { #name }, &f.ty)
+                })
+                .collect(),
+            Fields::Unnamed(ref fields_unnamed) => fields_unnamed
+                .unnamed
+                .iter()
+                .enumerate()
+                .map(|(i, f)| {
+                    let index = syn::Index::from(i);
+                    field_layout(quote! { #index }, &f.ty)
+                })
+                .collect(),
+            Fields::Unit => vec![],
+        },
+        _ => unimplemented!(),
+    };
+
+    let expanded = quote! {
+        impl #impl_generics iceoryx2_bb_elementary::type_layout::TypeLayout for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn field_layouts() -> Vec<iceoryx2_bb_elementary::type_layout::FieldLayout> {
+                let uninit = core::mem::MaybeUninit::<Self>::uninit();
+                let base = uninit.as_ptr();
+                vec![#(#field_layouts),*]
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
@@ -0,0 +1,66 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod type_layout {
+    use iceoryx2_bb_derive_macros::TypeLayout;
+    use iceoryx2_bb_elementary::type_layout::{FieldLayout, TypeLayout};
+    use iceoryx2_bb_testing::assert_that;
+
+    #[derive(TypeLayout)]
+    #[repr(C)]
+    struct NamedTestStruct {
+        value1: u8,
+        value2: u64,
+        value3: [u16; 3],
+    }
+
+    #[derive(TypeLayout)]
+    #[repr(C)]
+    struct UnnamedTestStruct(u32, u8, u64);
+
+    #[derive(TypeLayout)]
+    struct UnitTestStruct;
+
+    #[test]
+    fn type_layout_derive_works_for_named_struct() {
+        let sut = NamedTestStruct::field_layouts();
+
+        assert_that!(sut, len 3);
+        assert_that!(sut[0], eq FieldLayout { offset: 0, size: 1, alignment: 1 });
+        assert_that!(sut[1], eq FieldLayout {
+            offset: core::mem::align_of::<u64>(),
+            size: 8,
+            alignment: core::mem::align_of::<u64>()
+        });
+        assert_that!(sut[2], eq FieldLayout {
+            offset: core::mem::align_of::<u64>() + 8,
+            size: 6,
+            alignment: 2
+        });
+    }
+
+    #[test]
+    fn type_layout_derive_works_for_unnamed_struct() {
+        let sut = UnnamedTestStruct::field_layouts();
+
+        assert_that!(sut, len 3);
+        assert_that!(sut[0], eq FieldLayout { offset: 0, size: 4, alignment: 4 });
+        assert_that!(sut[1], eq FieldLayout { offset: 4, size: 1, alignment: 1 });
+        assert_that!(sut[2].offset, eq 8);
+    }
+
+    #[test]
+    fn type_layout_derive_works_for_unit_struct() {
+        assert_that!(UnitTestStruct::field_layouts(), len 0);
+    }
+}
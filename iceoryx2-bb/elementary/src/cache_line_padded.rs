@@ -0,0 +1,91 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Pads and aligns a value to a multiple of the cache line size, see [`CacheLinePadded`].
+
+use core::ops::{Deref, DerefMut};
+
+/// Pads and aligns the contained value to a multiple of the cache line size so that two
+/// neighboring elements of an array never share a cache line. Multiple threads can
+/// therefore modify disjoint elements without false sharing.
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2_bb_elementary::cache_line_padded::CacheLinePadded;
+///
+/// let mut values = [CacheLinePadded::new(0u64); 4];
+/// *values[1] = 12;
+///
+/// assert_eq!(*values[1], 12);
+/// assert_eq!(
+///     core::mem::size_of::<CacheLinePadded<u64>>(),
+///     CacheLinePadded::<u64>::CACHE_LINE_SIZE
+/// );
+/// ```
+// x86_64 prefetches pairs of cache lines and aarch64 and powerpc64 have 128 byte cache lines
+// on some platforms
+#[cfg_attr(
+    any(
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "powerpc64"
+    ),
+    repr(C, align(128))
+)]
+#[cfg_attr(
+    not(any(
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "powerpc64"
+    )),
+    repr(C, align(64))
+)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CacheLinePadded<T> {
+    value: T,
+}
+
+impl<T> CacheLinePadded<T> {
+    /// The size of the cache line the value is padded to.
+    pub const CACHE_LINE_SIZE: usize = core::mem::align_of::<Self>();
+
+    /// Creates a new [`CacheLinePadded`] value.
+    pub const fn new(value: T) -> Self {
+        Self { value }
+    }
+
+    /// Consumes the [`CacheLinePadded`] and returns the contained value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for CacheLinePadded<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for CacheLinePadded<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T> From<T> for CacheLinePadded<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
@@ -19,13 +19,16 @@ pub mod enum_gen;
 pub mod alignment;
 pub mod allocator;
 pub mod bump_allocator;
+pub mod cache_line_padded;
 pub mod lazy_singleton;
 pub mod math;
 pub mod owning_pointer;
 pub mod package_version;
 pub mod placement_default;
+pub mod plain_old_data;
 pub mod pointer_trait;
 pub mod relocatable_container;
 pub mod relocatable_ptr;
 pub mod scope_guard;
+pub mod type_layout;
 pub mod unique_id;
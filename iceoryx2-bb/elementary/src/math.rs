@@ -31,6 +31,16 @@ pub const fn align_to<T>(value: usize) -> usize {
     align(value, std::mem::align_of::<T>())
 }
 
+/// Returns the greatest common divisor of `a` and `b`. The greatest common divisor of a value
+/// and zero is the value itself.
+pub const fn greatest_common_divisor(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        greatest_common_divisor(b, a % b)
+    }
+}
+
 pub trait ToB64 {
     fn to_b64(&self) -> String;
 }
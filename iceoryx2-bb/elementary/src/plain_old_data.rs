@@ -0,0 +1,65 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Trait that marks types whose memory can be viewed and modified as raw bytes.
+//! See [`PlainOldData`] for example.
+
+use std::mem::MaybeUninit;
+
+/// Marks types that can be safely viewed and modified as byte slice, for instance to copy
+/// recorded data into them or to compute a checksum over them.
+///
+/// ```
+/// use iceoryx2_bb_elementary::plain_old_data::PlainOldData;
+///
+/// #[repr(C)]
+/// struct Position {
+///     x: f64,
+///     y: f64,
+///     timestamp: u64,
+/// }
+///
+/// // repr(C), no padding bytes and all fields are plain old data
+/// unsafe impl PlainOldData for Position {}
+/// ```
+///
+/// # Safety
+///
+///  * the type must not contain padding bytes
+///  * every bit pattern must be a valid value of the type
+///  * the type must not contain pointers, references or other resources
+pub unsafe trait PlainOldData {}
+
+macro_rules! Impl {
+    ($type:ty) => {
+        unsafe impl PlainOldData for $type {}
+    };
+}
+
+Impl!(f32);
+Impl!(f64);
+Impl!(u8);
+Impl!(u16);
+Impl!(u32);
+Impl!(u64);
+Impl!(u128);
+Impl!(i8);
+Impl!(i16);
+Impl!(i32);
+Impl!(i64);
+Impl!(i128);
+Impl!(isize);
+Impl!(usize);
+
+unsafe impl<T: PlainOldData> PlainOldData for [T] {}
+unsafe impl<T: PlainOldData, const CAPACITY: usize> PlainOldData for [T; CAPACITY] {}
+unsafe impl<T: PlainOldData> PlainOldData for MaybeUninit<T> {}
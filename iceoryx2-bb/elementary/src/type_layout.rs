@@ -0,0 +1,61 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Describes the memory layout of the fields of a type, see [`TypeLayout`]. The description
+//! can be compared with the layout of the corresponding struct in another language like C or
+//! C++ to detect ABI mismatches.
+
+use serde::{Deserialize, Serialize};
+
+/// The memory layout of a single field of a type. It is stored as is in the static config of
+/// a service.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FieldLayout {
+    /// The offset of the field in bytes from the start of the type
+    pub offset: usize,
+    /// The size of the field in bytes
+    pub size: usize,
+    /// The alignment of the field
+    pub alignment: usize,
+}
+
+/// A trait that describes the memory layout of every field of a type in declaration order.
+///
+/// ```
+/// use iceoryx2_bb_elementary::type_layout::{FieldLayout, TypeLayout};
+///
+/// #[repr(C)]
+/// struct MyType {
+///     value_1: u8,
+///     value_2: u64,
+/// }
+///
+/// impl TypeLayout for MyType {
+///     fn field_layouts() -> Vec<FieldLayout> {
+///         vec![
+///             FieldLayout { offset: 0, size: 1, alignment: 1 },
+///             FieldLayout {
+///                 offset: core::mem::align_of::<u64>(),
+///                 size: 8,
+///                 alignment: core::mem::align_of::<u64>(),
+///             },
+///         ]
+///     }
+/// }
+///
+/// assert_eq!(MyType::field_layouts().len(), 2);
+/// ```
+pub trait TypeLayout {
+    /// Returns the [`FieldLayout`] of every field in declaration order.
+    fn field_layouts() -> Vec<FieldLayout>;
+}
@@ -0,0 +1,41 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_elementary::cache_line_padded::CacheLinePadded;
+use iceoryx2_bb_testing::assert_that;
+
+#[test]
+fn cache_line_padded_has_size_and_alignment_of_cache_line() {
+    assert_that!(CacheLinePadded::<u64>::CACHE_LINE_SIZE, ge 64);
+    assert_that!(core::mem::align_of::<CacheLinePadded<u8>>(), eq CacheLinePadded::<u8>::CACHE_LINE_SIZE);
+    assert_that!(core::mem::size_of::<CacheLinePadded<u8>>(), eq CacheLinePadded::<u8>::CACHE_LINE_SIZE);
+    assert_that!(core::mem::size_of::<CacheLinePadded<[u8; 129]>>() % CacheLinePadded::<u8>::CACHE_LINE_SIZE, eq 0);
+}
+
+#[test]
+fn cache_line_padded_elements_of_array_never_share_a_cache_line() {
+    let sut = [CacheLinePadded::new(0u32); 4];
+
+    for i in 1..sut.len() {
+        let distance = (&*sut[i] as *const u32 as usize) - (&*sut[i - 1] as *const u32 as usize);
+        assert_that!(distance, eq CacheLinePadded::<u32>::CACHE_LINE_SIZE);
+    }
+}
+
+#[test]
+fn cache_line_padded_gives_access_to_value() {
+    let mut sut = CacheLinePadded::from(123u64);
+    assert_that!(*sut, eq 123);
+
+    *sut = 456;
+    assert_that!(sut.into_inner(), eq 456);
+}
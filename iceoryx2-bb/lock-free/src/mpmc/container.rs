@@ -116,6 +116,19 @@ impl<T: Copy + Debug> ContainerState<T> {
             }
         }
     }
+
+    /// Returns the [`ContainerHandle`] of the element at the provided index if the
+    /// [`ContainerState`] contains an element at this index. Useful in IPC context to remove
+    /// the elements of an application that has died with [`Container::remove()`].
+    pub fn handle(&self, index: u32) -> Option<ContainerHandle> {
+        match self.active_index.get(index as usize) {
+            Some(active_index) if active_index % 2 == 1 => Some(ContainerHandle {
+                index,
+                container_id: self.container_id,
+            }),
+            _ => None,
+        }
+    }
 }
 
 /// A **threadsafe** and **lock-free** runtime fixed size container. The compile time fixed size
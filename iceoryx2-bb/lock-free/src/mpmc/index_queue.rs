@@ -0,0 +1,205 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A **threadsafe** **lock-free** bounded multi producer multi consumer queue which can store
+//! [`usize`] integers or indices. In contrast to the
+//! [`crate::spsc::index_queue::IndexQueue`] any number of threads can push and pop
+//! concurrently.
+//!
+//! It is a thin wrapper around the [`crate::mpmc::queue::Queue`] that stores [`usize`] values
+//! and offers the same interface as the other index queues.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_lock_free::mpmc::index_queue::*;
+//!
+//! const QUEUE_CAPACITY: usize = 128;
+//! let queue = FixedSizeIndexQueue::<QUEUE_CAPACITY>::new();
+//!
+//! std::thread::scope(|s| {
+//!     s.spawn(|| {
+//!         if !queue.push(1234) {
+//!             println!("queue is full");
+//!         }
+//!     });
+//!
+//!     s.spawn(|| {
+//!         match queue.pop() {
+//!             None => println!("queue is empty"),
+//!             Some(v) => println!("got {}", v)
+//!         }
+//!     });
+//! });
+//! ```
+
+use std::fmt::Debug;
+
+use iceoryx2_bb_elementary::{
+    owning_pointer::OwningPointer, pointer_trait::PointerTrait,
+    relocatable_container::RelocatableContainer, relocatable_ptr::RelocatablePointer,
+};
+
+use crate::mpmc::queue::{FixedSizeQueue, RelocatableQueue};
+
+pub type IndexQueue = details::IndexQueue<OwningPointer<details::Slot>>;
+pub type RelocatableIndexQueue = details::IndexQueue<RelocatablePointer<details::Slot>>;
+
+pub mod details {
+    use super::*;
+
+    /// A single entry of the [`IndexQueue`], see [`crate::mpmc::queue::details::Slot`].
+    pub type Slot = crate::mpmc::queue::details::Slot<usize>;
+
+    /// A threadsafe lock-free multi producer multi consumer index queue with a capacity which
+    /// can be set up at runtime, when the queue is created.
+    #[repr(C)]
+    #[derive(Debug)]
+    pub struct IndexQueue<PointerType: PointerTrait<Slot>> {
+        queue: crate::mpmc::queue::details::Queue<usize, PointerType>,
+    }
+
+    impl IndexQueue<OwningPointer<Slot>> {
+        pub fn new(capacity: usize) -> Self {
+            Self {
+                queue: crate::mpmc::queue::Queue::new(capacity),
+            }
+        }
+    }
+
+    impl RelocatableContainer for IndexQueue<RelocatablePointer<Slot>> {
+        unsafe fn new_uninit(capacity: usize) -> Self {
+            Self {
+                queue: <RelocatableQueue<usize> as RelocatableContainer>::new_uninit(capacity),
+            }
+        }
+
+        unsafe fn init<T: iceoryx2_bb_elementary::allocator::BaseAllocator>(
+            &self,
+            allocator: &T,
+        ) -> Result<(), iceoryx2_bb_elementary::allocator::AllocationError> {
+            self.queue.init(allocator)
+        }
+
+        unsafe fn new(capacity: usize, distance_to_data: isize) -> Self {
+            // the queue is the only member, therefore the distance to the data is the same
+            Self {
+                queue: <RelocatableQueue<usize> as RelocatableContainer>::new(
+                    capacity,
+                    distance_to_data,
+                ),
+            }
+        }
+
+        fn memory_size(capacity: usize) -> usize {
+            Self::const_memory_size(capacity)
+        }
+    }
+
+    impl<PointerType: PointerTrait<Slot> + Debug> IndexQueue<PointerType> {
+        /// Returns the amount of memory required to create a [`IndexQueue`] with the provided
+        /// capacity.
+        pub const fn const_memory_size(capacity: usize) -> usize {
+            crate::mpmc::queue::details::Queue::<usize, PointerType>::const_memory_size(capacity)
+        }
+
+        /// Pushes a value into the queue. If the queue is full it returns false, otherwise true.
+        /// Can be called concurrently from any number of threads.
+        pub fn push(&self, value: usize) -> bool {
+            self.queue.push(&value)
+        }
+
+        /// Acquires a value from the queue. If the queue is empty it returns [`None`] otherwise
+        /// the value. Can be called concurrently from any number of threads.
+        pub fn pop(&self) -> Option<usize> {
+            self.queue.pop()
+        }
+
+        /// Returns true when the [`IndexQueue`] is empty, otherwise false.
+        /// Note: This method may make only sense in a non-concurrent setup since the information
+        ///       could be out-of-date as soon as it is acquired.
+        pub fn is_empty(&self) -> bool {
+            self.queue.is_empty()
+        }
+
+        /// Returns the length of the [`IndexQueue`].
+        /// Note: This method may make only sense in a non-concurrent setup since the information
+        ///       could be out-of-date as soon as it is acquired.
+        pub fn len(&self) -> usize {
+            self.queue.len()
+        }
+
+        /// Returns the capacity of the [`IndexQueue`].
+        pub const fn capacity(&self) -> usize {
+            self.queue.capacity()
+        }
+
+        /// Returns true when the [`IndexQueue`] is full, otherwise false.
+        /// Note: This method may make only sense in a non-concurrent setup since the information
+        ///       could be out-of-date as soon as it is acquired.
+        pub fn is_full(&self) -> bool {
+            self.queue.is_full()
+        }
+    }
+}
+
+/// The compile-time fixed size version of the [`IndexQueue`].
+#[derive(Debug)]
+#[repr(C)]
+pub struct FixedSizeIndexQueue<const CAPACITY: usize> {
+    queue: FixedSizeQueue<usize, CAPACITY>,
+}
+
+impl<const CAPACITY: usize> Default for FixedSizeIndexQueue<CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CAPACITY: usize> FixedSizeIndexQueue<CAPACITY> {
+    /// Creates a new empty [`FixedSizeIndexQueue`].
+    pub fn new() -> Self {
+        Self {
+            queue: FixedSizeQueue::new(),
+        }
+    }
+
+    /// See [`IndexQueue::is_empty()`]
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// See [`IndexQueue::len()`]
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// See [`IndexQueue::capacity()`]
+    pub const fn capacity(&self) -> usize {
+        self.queue.capacity()
+    }
+
+    /// See [`IndexQueue::is_full()`]
+    pub fn is_full(&self) -> bool {
+        self.queue.is_full()
+    }
+
+    /// See [`IndexQueue::push()`]
+    pub fn push(&self, value: usize) -> bool {
+        self.queue.push(&value)
+    }
+
+    /// See [`IndexQueue::pop()`]
+    pub fn pop(&self) -> Option<usize> {
+        self.queue.pop()
+    }
+}
@@ -14,4 +14,6 @@
 
 pub mod bit_set;
 pub mod container;
+pub mod index_queue;
+pub mod queue;
 pub mod unique_index_set;
@@ -0,0 +1,378 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A **threadsafe** **lock-free** bounded multi producer multi consumer queue. The
+//! [`crate::mpmc::index_queue::IndexQueue`] is built on top of it.
+//!
+//! Every slot of the queue carries a sequence number that tells whether the slot can be written
+//! or read in the current round. A producer or consumer reserves a position with a single
+//! compare-and-swap and afterwards exclusively owns the slot until it publishes the new sequence
+//! number.
+//! **IMPORTANT** Can only be used with trivially copyable types which are also trivially dropable.
+//!
+//! The [`RelocatableQueue`] and the [`FixedSizeQueue`] do not contain any pointers and can be
+//! placed in shared memory, when the stored type is shared memory compatible as well.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_lock_free::mpmc::queue::*;
+//!
+//! const QUEUE_CAPACITY: usize = 128;
+//! let queue = FixedSizeQueue::<u64, QUEUE_CAPACITY>::new();
+//!
+//! std::thread::scope(|s| {
+//!     s.spawn(|| {
+//!         if !queue.push(&1234) {
+//!             println!("queue is full");
+//!         }
+//!     });
+//!
+//!     s.spawn(|| {
+//!         match queue.pop() {
+//!             None => println!("queue is empty"),
+//!             Some(v) => println!("got {}", v)
+//!         }
+//!     });
+//! });
+//! ```
+
+use std::{alloc::Layout, cell::UnsafeCell, fmt::Debug, mem::MaybeUninit, sync::atomic::Ordering};
+
+use iceoryx2_bb_elementary::{
+    math::align_to, owning_pointer::OwningPointer, pointer_trait::PointerTrait,
+    relocatable_container::RelocatableContainer, relocatable_ptr::RelocatablePointer,
+};
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
+
+pub type Queue<T> = details::Queue<T, OwningPointer<details::Slot<T>>>;
+pub type RelocatableQueue<T> = details::Queue<T, RelocatablePointer<details::Slot<T>>>;
+
+pub mod details {
+    use std::fmt::Debug;
+
+    use iceoryx2_bb_elementary::math::unaligned_mem_size;
+
+    use super::*;
+
+    /// A single entry of the [`Queue`]. When the sequence equals the position of a producer,
+    /// the slot can be written, when it equals the position of a consumer + 1, it can be read.
+    #[derive(Debug)]
+    #[repr(C)]
+    pub struct Slot<T: Copy> {
+        sequence: IoxAtomicUsize,
+        value: UnsafeCell<MaybeUninit<T>>,
+    }
+
+    impl<T: Copy> Slot<T> {
+        pub(super) fn new(sequence: usize) -> Self {
+            Self {
+                sequence: IoxAtomicUsize::new(sequence),
+                value: UnsafeCell::new(MaybeUninit::uninit()),
+            }
+        }
+    }
+
+    /// A threadsafe lock-free multi producer multi consumer queue with a capacity which can be
+    /// set up at runtime, when the queue is created.
+    #[repr(C)]
+    #[derive(Debug)]
+    pub struct Queue<T: Copy, PointerType: PointerTrait<Slot<T>>> {
+        data_ptr: PointerType,
+        capacity: usize,
+        write_position: IoxAtomicUsize,
+        read_position: IoxAtomicUsize,
+        is_memory_initialized: IoxAtomicBool,
+        _phantom: core::marker::PhantomData<T>,
+    }
+
+    unsafe impl<T: Copy + Send, PointerType: PointerTrait<Slot<T>>> Sync for Queue<T, PointerType> {}
+    unsafe impl<T: Copy + Send, PointerType: PointerTrait<Slot<T>>> Send for Queue<T, PointerType> {}
+
+    impl<T: Copy> Queue<T, OwningPointer<Slot<T>>> {
+        pub fn new(capacity: usize) -> Self {
+            let mut data_ptr = OwningPointer::<Slot<T>>::new_with_alloc(capacity);
+
+            for i in 0..capacity {
+                unsafe { data_ptr.as_mut_ptr().add(i).write(Slot::new(i)) };
+            }
+
+            Self {
+                data_ptr,
+                capacity,
+                write_position: IoxAtomicUsize::new(0),
+                read_position: IoxAtomicUsize::new(0),
+                is_memory_initialized: IoxAtomicBool::new(true),
+                _phantom: core::marker::PhantomData,
+            }
+        }
+    }
+
+    impl<T: Copy + Debug> RelocatableContainer for Queue<T, RelocatablePointer<Slot<T>>> {
+        unsafe fn new_uninit(capacity: usize) -> Self {
+            Self {
+                data_ptr: RelocatablePointer::new_uninit(),
+                capacity,
+                write_position: IoxAtomicUsize::new(0),
+                read_position: IoxAtomicUsize::new(0),
+                is_memory_initialized: IoxAtomicBool::new(false),
+                _phantom: core::marker::PhantomData,
+            }
+        }
+
+        unsafe fn init<Allocator: iceoryx2_bb_elementary::allocator::BaseAllocator>(
+            &self,
+            allocator: &Allocator,
+        ) -> Result<(), iceoryx2_bb_elementary::allocator::AllocationError> {
+            if self.is_memory_initialized.load(Ordering::Relaxed) {
+                fatal_panic!(from self, "Memory already initialized. Initializing it twice may lead to undefined behavior.");
+            }
+
+            self.data_ptr.init(fail!(from self, when allocator
+            .allocate(Layout::from_size_align_unchecked(
+                    std::mem::size_of::<Slot<T>>() * self.capacity,
+                    std::mem::align_of::<Slot<T>>())),
+            "Failed to initialize since the allocation of the data memory failed."));
+
+            for i in 0..self.capacity {
+                (self.data_ptr.as_ptr() as *mut Slot<T>)
+                    .add(i)
+                    .write(Slot::new(i));
+            }
+
+            self.is_memory_initialized.store(true, Ordering::Relaxed);
+            Ok(())
+        }
+
+        unsafe fn new(capacity: usize, distance_to_data: isize) -> Self {
+            Self {
+                data_ptr: RelocatablePointer::new(distance_to_data),
+                capacity,
+                write_position: IoxAtomicUsize::new(0),
+                read_position: IoxAtomicUsize::new(0),
+                is_memory_initialized: IoxAtomicBool::new(true),
+                _phantom: core::marker::PhantomData,
+            }
+        }
+
+        fn memory_size(capacity: usize) -> usize {
+            Self::const_memory_size(capacity)
+        }
+    }
+
+    impl<T: Copy + Debug, PointerType: PointerTrait<Slot<T>> + Debug> Queue<T, PointerType> {
+        #[inline(always)]
+        fn verify_init(&self, source: &str) {
+            debug_assert!(
+                self.is_memory_initialized.load(Ordering::Relaxed),
+                "Undefined behavior when calling \"{}\" and the object is not initialized.",
+                source
+            );
+        }
+
+        /// Returns the amount of memory required to create a [`Queue`] with the provided
+        /// capacity.
+        pub const fn const_memory_size(capacity: usize) -> usize {
+            unaligned_mem_size::<Slot<T>>(capacity)
+        }
+
+        fn at(&self, position: usize) -> &Slot<T> {
+            unsafe { &*self.data_ptr.as_ptr().add(position % self.capacity) }
+        }
+
+        /// Pushes a copy of the value into the queue. If the queue is full it returns false,
+        /// otherwise true. Can be called concurrently from any number of threads.
+        pub fn push(&self, value: &T) -> bool {
+            self.verify_init("push");
+            let mut write_position = self.write_position.load(Ordering::Relaxed);
+
+            let slot = loop {
+                let slot = self.at(write_position);
+                ////////////////
+                // SYNC POINT with pop
+                ////////////////
+                let sequence = slot.sequence.load(Ordering::Acquire);
+
+                if sequence == write_position {
+                    match self.write_position.compare_exchange_weak(
+                        write_position,
+                        write_position + 1,
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    ) {
+                        Ok(_) => break slot,
+                        Err(v) => write_position = v,
+                    }
+                } else if sequence < write_position {
+                    // the slot was not yet read in the previous round
+                    return false;
+                } else {
+                    write_position = self.write_position.load(Ordering::Relaxed);
+                }
+            };
+
+            unsafe { slot.value.get().write(MaybeUninit::new(*value)) };
+            ////////////////
+            // SYNC POINT with pop
+            ////////////////
+            slot.sequence.store(write_position + 1, Ordering::Release);
+
+            true
+        }
+
+        /// Acquires the oldest value from the queue. If the queue is empty it returns [`None`]
+        /// otherwise the value. Can be called concurrently from any number of threads.
+        pub fn pop(&self) -> Option<T> {
+            self.verify_init("pop");
+            let mut read_position = self.read_position.load(Ordering::Relaxed);
+
+            let slot = loop {
+                let slot = self.at(read_position);
+                ////////////////
+                // SYNC POINT with push
+                ////////////////
+                let sequence = slot.sequence.load(Ordering::Acquire);
+
+                if sequence == read_position + 1 {
+                    match self.read_position.compare_exchange_weak(
+                        read_position,
+                        read_position + 1,
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    ) {
+                        Ok(_) => break slot,
+                        Err(v) => read_position = v,
+                    }
+                } else if sequence < read_position + 1 {
+                    // the slot was not yet written in the current round
+                    return None;
+                } else {
+                    read_position = self.read_position.load(Ordering::Relaxed);
+                }
+            };
+
+            // the slot was written by the producer that published the sequence
+            let value = unsafe { (*slot.value.get()).assume_init() };
+            ////////////////
+            // SYNC POINT with push
+            ////////////////
+            slot.sequence
+                .store(read_position + self.capacity, Ordering::Release);
+
+            Some(value)
+        }
+
+        fn acquire_read_and_write_position(&self) -> (usize, usize) {
+            loop {
+                let write_position = self.write_position.load(Ordering::Relaxed);
+                let read_position = self.read_position.load(Ordering::Relaxed);
+
+                if write_position == self.write_position.load(Ordering::Relaxed)
+                    && read_position == self.read_position.load(Ordering::Relaxed)
+                {
+                    return (write_position, read_position);
+                }
+            }
+        }
+
+        /// Returns true when the [`Queue`] is empty, otherwise false.
+        /// Note: This method may make only sense in a non-concurrent setup since the information
+        ///       could be out-of-date as soon as it is acquired.
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        /// Returns the length of the [`Queue`].
+        /// Note: This method may make only sense in a non-concurrent setup since the information
+        ///       could be out-of-date as soon as it is acquired.
+        pub fn len(&self) -> usize {
+            let (write_position, read_position) = self.acquire_read_and_write_position();
+            write_position.saturating_sub(read_position)
+        }
+
+        /// Returns the capacity of the [`Queue`].
+        pub const fn capacity(&self) -> usize {
+            self.capacity
+        }
+
+        /// Returns true when the [`Queue`] is full, otherwise false.
+        /// Note: This method may make only sense in a non-concurrent setup since the information
+        ///       could be out-of-date as soon as it is acquired.
+        pub fn is_full(&self) -> bool {
+            self.len() >= self.capacity
+        }
+    }
+}
+
+/// The compile-time fixed size version of the [`Queue`].
+#[derive(Debug)]
+#[repr(C)]
+pub struct FixedSizeQueue<T: Copy, const CAPACITY: usize> {
+    state: RelocatableQueue<T>,
+    data: [details::Slot<T>; CAPACITY],
+}
+
+unsafe impl<T: Copy + Send, const CAPACITY: usize> Sync for FixedSizeQueue<T, CAPACITY> {}
+unsafe impl<T: Copy + Send, const CAPACITY: usize> Send for FixedSizeQueue<T, CAPACITY> {}
+
+impl<T: Copy + Debug, const CAPACITY: usize> Default for FixedSizeQueue<T, CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Debug, const CAPACITY: usize> FixedSizeQueue<T, CAPACITY> {
+    /// Creates a new empty [`FixedSizeQueue`].
+    pub fn new() -> Self {
+        Self {
+            state: unsafe {
+                RelocatableQueue::new(
+                    CAPACITY,
+                    align_to::<details::Slot<T>>(std::mem::size_of::<RelocatableQueue<T>>())
+                        as isize,
+                )
+            },
+            data: core::array::from_fn(details::Slot::new),
+        }
+    }
+
+    /// See [`Queue::is_empty()`]
+    pub fn is_empty(&self) -> bool {
+        self.state.is_empty()
+    }
+
+    /// See [`Queue::len()`]
+    pub fn len(&self) -> usize {
+        self.state.len()
+    }
+
+    /// See [`Queue::capacity()`]
+    pub const fn capacity(&self) -> usize {
+        self.state.capacity()
+    }
+
+    /// See [`Queue::is_full()`]
+    pub fn is_full(&self) -> bool {
+        self.state.is_full()
+    }
+
+    /// See [`Queue::push()`]
+    pub fn push(&self, value: &T) -> bool {
+        self.state.push(value)
+    }
+
+    /// See [`Queue::pop()`]
+    pub fn pop(&self) -> Option<T> {
+        self.state.pop()
+    }
+}
@@ -166,6 +166,52 @@ mod mpmc_container {
         }
     }
 
+    #[test]
+    fn mpmc_container_handle_from_state_removes_element<
+        T: Debug + Copy + From<usize> + Into<usize>,
+    >() {
+        let sut = FixedSizeContainer::<T, CAPACITY>::new();
+
+        for i in 0..CAPACITY {
+            let handle = unsafe { sut.add(i.into()) };
+            assert_that!(handle, is_some);
+        }
+
+        let state = sut.get_state();
+        let mut handles = vec![];
+        state.for_each(|index: u32, value: &T| {
+            if (*value).into() % 2 == 0 {
+                handles.push(state.handle(index).unwrap());
+            }
+        });
+
+        for handle in handles {
+            unsafe { sut.remove(handle) };
+        }
+
+        let state = sut.get_state();
+        let mut contained_values = vec![];
+        state.for_each(|_: u32, value: &T| contained_values.push((*value).into()));
+
+        assert_that!(contained_values, len CAPACITY / 2);
+        for value in contained_values {
+            assert_that!(value % 2, eq 1);
+        }
+    }
+
+    #[test]
+    fn mpmc_container_handle_from_state_of_unused_index_is_none<
+        T: Debug + Copy + From<usize> + Into<usize>,
+    >() {
+        let sut = FixedSizeContainer::<T, CAPACITY>::new();
+        let handle = unsafe { sut.add(0.into()) }.unwrap();
+
+        let state = sut.get_state();
+        assert_that!(state.handle(handle.index()), eq Some(handle));
+        assert_that!(state.handle(handle.index() + 1), eq None);
+        assert_that!(state.handle(CAPACITY as u32), eq None);
+    }
+
     #[test]
     fn mpmc_container_state_of_empty_container_is_empty<
         T: Debug + Copy + From<usize> + Into<usize>,
@@ -0,0 +1,135 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_lock_free::mpmc::index_queue::*;
+use iceoryx2_bb_posix::barrier::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+use std::collections::VecDeque;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+use std::thread;
+
+#[test]
+fn mpmc_index_queue_push_works_until_full() {
+    const CAPACITY: usize = 128;
+    let sut = FixedSizeIndexQueue::<CAPACITY>::new();
+
+    assert_that!(sut.capacity(), eq CAPACITY);
+    assert_that!(sut, len 0);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut, is_empty);
+
+    for i in 0..CAPACITY {
+        assert_that!(sut, len i);
+        assert_that!(sut.push(i), eq true);
+    }
+    assert_that!(sut.push(1234), eq false);
+
+    assert_that!(sut, len CAPACITY);
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut, is_not_empty);
+}
+
+#[test]
+fn mpmc_index_queue_pop_works_until_empty() {
+    const CAPACITY: usize = 128;
+    let sut = IndexQueue::new(CAPACITY);
+    for i in 0..CAPACITY {
+        assert_that!(sut.push(i), eq true);
+    }
+
+    for i in 0..CAPACITY {
+        assert_that!(sut, len CAPACITY - i);
+        assert_that!(sut.pop(), eq Some(i));
+    }
+    assert_that!(sut.pop(), is_none);
+
+    assert_that!(sut, len 0);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut, is_empty);
+}
+
+#[test]
+fn mpmc_index_queue_push_pop_alteration_works_over_multiple_rounds() {
+    const CAPACITY: usize = 7;
+    let sut = FixedSizeIndexQueue::<CAPACITY>::new();
+
+    let mut expected = VecDeque::new();
+
+    for i in 0..CAPACITY * 10 {
+        while sut.len() + 2 > CAPACITY {
+            assert_that!(sut.pop(), eq expected.pop_front());
+        }
+
+        for value in [2 * i, 2 * i + 1] {
+            assert_that!(sut.push(value), eq true);
+            expected.push_back(value);
+        }
+
+        assert_that!(sut.pop(), eq expected.pop_front());
+    }
+    assert_that!(sut, len expected.len());
+}
+
+#[test]
+fn mpmc_index_queue_push_pop_works_concurrently_with_many_producers_and_consumers() {
+    const NUMBER_OF_PRODUCERS: usize = 4;
+    const NUMBER_OF_CONSUMERS: usize = 4;
+    const VALUES_PER_PRODUCER: usize = 10000;
+    const CAPACITY: usize = 64;
+
+    let sut = FixedSizeIndexQueue::<CAPACITY>::new();
+    let received = Mutex::new(vec![]);
+    let number_of_received_values = IoxAtomicUsize::new(0);
+    let handle = BarrierHandle::new();
+    let barrier = BarrierBuilder::new((NUMBER_OF_PRODUCERS + NUMBER_OF_CONSUMERS) as u32)
+        .is_interprocess_capable(false)
+        .create(&handle)
+        .unwrap();
+
+    thread::scope(|s| {
+        for producer in 0..NUMBER_OF_PRODUCERS {
+            let sut = &sut;
+            let barrier = &barrier;
+            s.spawn(move || {
+                barrier.wait();
+                for i in 0..VALUES_PER_PRODUCER {
+                    while !sut.push(producer * VALUES_PER_PRODUCER + i) {}
+                }
+            });
+        }
+
+        for _ in 0..NUMBER_OF_CONSUMERS {
+            s.spawn(|| {
+                let mut values = vec![];
+                barrier.wait();
+                while number_of_received_values.load(Ordering::Relaxed)
+                    < NUMBER_OF_PRODUCERS * VALUES_PER_PRODUCER
+                {
+                    if let Some(value) = sut.pop() {
+                        values.push(value);
+                        number_of_received_values.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+                received.lock().unwrap().append(&mut values);
+            });
+        }
+    });
+
+    let mut received = received.into_inner().unwrap();
+    received.sort();
+    assert_that!(received, len NUMBER_OF_PRODUCERS * VALUES_PER_PRODUCER);
+    for (i, value) in received.iter().enumerate() {
+        assert_that!(*value, eq i);
+    }
+}
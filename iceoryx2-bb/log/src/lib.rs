@@ -118,6 +118,20 @@
 //!     }
 //! }
 //! ```
+//! ## Logger Backends
+//!
+//! The log messages are forwarded to the [`Logger`] that was installed with [`set_logger()`].
+//! When no [`Logger`] is installed, the default logger is used which is selected with the
+//! features
+//!
+//!  * `logger_log` - [`crate::logger::log::Logger`] forwards to the `log` crate
+//!  * `logger_tracing` - [`crate::logger::tracing::Logger`] forwards to the `tracing` crate
+//!  * otherwise [`crate::logger::console::Logger`] prints to the console
+//!
+//! Additionally, [`crate::logger::buffer::Logger`] collects all messages in a buffer and
+//! [`crate::logger::ring_buffer::Logger`] is a lock-free ring buffer for real-time threads that
+//! can also be placed in shared memory. Custom loggers implement the [`Logger`] trait.
+//!
 //! ## Setting custom logger on application startup
 //!
 //! In this example we use the [`crate::logger::buffer::Logger`], that stores every log
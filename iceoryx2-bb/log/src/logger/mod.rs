@@ -17,6 +17,7 @@ pub mod buffer;
 pub mod console;
 #[cfg(feature = "logger_log")]
 pub mod log;
+pub mod ring_buffer;
 #[cfg(feature = "logger_tracing")]
 pub mod tracing;
 
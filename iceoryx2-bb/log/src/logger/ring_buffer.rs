@@ -0,0 +1,335 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A lock-free [`Logger`] that stores the log messages in a fixed size ring buffer. Logging
+//! never allocates, never blocks and never performs a syscall, therefore it can be used from
+//! real-time threads. The log messages are read by another, non real-time thread with
+//! [`Logger::pop()`] and forwarded to their final destination.
+//!
+//! When the ring buffer is full the oldest entries are overwritten. Origins and messages that
+//! do not fit into an [`Entry`] are truncated.
+//!
+//! The [`Logger`] is `#[repr(C)]`, contains no pointers and a zeroed memory region is an empty
+//! [`Logger`]. It can therefore be placed in shared memory, see [`Logger::new_in_place()`], so
+//! that the log messages of a real-time process are collected by a separate process.
+//!
+//! ```
+//! use iceoryx2_bb_log::{set_logger, info};
+//! use iceoryx2_bb_log::logger::ring_buffer::Logger;
+//!
+//! static LOGGER: Logger<64> = Logger::new();
+//!
+//! assert!(set_logger(&LOGGER));
+//! info!("hello world");
+//!
+//! // in a non real-time thread
+//! while let Some(entry) = LOGGER.pop() {
+//!     println!("{:?} {} {}", entry.log_level(), entry.origin(), entry.message());
+//! }
+//! ```
+
+use core::cell::UnsafeCell;
+use core::fmt::{Arguments, Write};
+use core::sync::atomic::{fence, Ordering};
+
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+
+use crate::{get_log_level, LogLevel};
+
+/// The maximum length of the origin of an [`Entry`] in bytes.
+pub const ORIGIN_CAPACITY: usize = 128;
+/// The maximum length of the message of an [`Entry`] in bytes.
+pub const MESSAGE_CAPACITY: usize = 384;
+
+/// A single log message stored in the [`Logger`].
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Entry {
+    log_level: u8,
+    origin_len: u16,
+    message_len: u16,
+    origin: [u8; ORIGIN_CAPACITY],
+    message: [u8; MESSAGE_CAPACITY],
+}
+
+impl core::fmt::Debug for Entry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Entry {{ log_level: {:?}, origin: {:?}, message: {:?} }}",
+            self.log_level(),
+            self.origin(),
+            self.message()
+        )
+    }
+}
+
+impl Entry {
+    const EMPTY: Entry = Entry {
+        log_level: 0,
+        origin_len: 0,
+        message_len: 0,
+        origin: [0; ORIGIN_CAPACITY],
+        message: [0; MESSAGE_CAPACITY],
+    };
+
+    /// Returns the [`LogLevel`] of the log message
+    pub fn log_level(&self) -> LogLevel {
+        match self.log_level {
+            0 => LogLevel::Trace,
+            1 => LogLevel::Debug,
+            2 => LogLevel::Info,
+            3 => LogLevel::Warn,
+            4 => LogLevel::Error,
+            _ => LogLevel::Fatal,
+        }
+    }
+
+    /// Returns the origin of the log message, truncated to [`ORIGIN_CAPACITY`] bytes
+    pub fn origin(&self) -> &str {
+        as_str(&self.origin[..self.origin_len as usize])
+    }
+
+    /// Returns the log message, truncated to [`MESSAGE_CAPACITY`] bytes
+    pub fn message(&self) -> &str {
+        as_str(&self.message[..self.message_len as usize])
+    }
+}
+
+// the writer truncates only at char boundaries, but the entry may come from shared memory
+fn as_str(value: &[u8]) -> &str {
+    match core::str::from_utf8(value) {
+        Ok(v) => v,
+        Err(e) => unsafe { core::str::from_utf8_unchecked(&value[..e.valid_up_to()]) },
+    }
+}
+
+struct TruncatingWriter<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for TruncatingWriter<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let mut n = s.len().min(self.buffer.len() - self.len);
+        while !s.is_char_boundary(n) {
+            n -= 1;
+        }
+
+        self.buffer[self.len..self.len + n].copy_from_slice(&s.as_bytes()[..n]);
+        self.len += n;
+        Ok(())
+    }
+}
+
+fn format_into(buffer: &mut [u8], args: Arguments) -> u16 {
+    let mut writer = TruncatingWriter { buffer, len: 0 };
+    // the writer never fails, only a faulty Display implementation can abort the formatting
+    let _ = writer.write_fmt(args);
+    writer.len as u16
+}
+
+// The sequence of a slot is 2 * position + 1 while the entry at position is written and
+// 2 * position + 2 when it is complete. Zero marks a slot that was never written.
+#[repr(C)]
+struct Slot {
+    sequence: IoxAtomicU64,
+    entry: UnsafeCell<Entry>,
+}
+
+impl Slot {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const EMPTY: Slot = Slot {
+        sequence: IoxAtomicU64::new(0),
+        entry: UnsafeCell::new(Entry::EMPTY),
+    };
+}
+
+/// A lock-free ring buffer [`Logger`] that can store `CAPACITY` log messages. Any number of
+/// threads can log concurrently, the entries are read by one thread with [`Logger::pop()`].
+#[repr(C)]
+pub struct Logger<const CAPACITY: usize> {
+    write_position: IoxAtomicU64,
+    read_position: IoxAtomicU64,
+    number_of_lost_entries: IoxAtomicU64,
+    slots: [Slot; CAPACITY],
+}
+
+unsafe impl<const CAPACITY: usize> Send for Logger<CAPACITY> {}
+unsafe impl<const CAPACITY: usize> Sync for Logger<CAPACITY> {}
+
+impl<const CAPACITY: usize> Default for Logger<CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CAPACITY: usize> core::fmt::Debug for Logger<CAPACITY> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ring_buffer::Logger<{}> {{ write_position: {}, read_position: {}, number_of_lost_entries: {} }}",
+            CAPACITY,
+            self.write_position.load(Ordering::Relaxed),
+            self.read_position.load(Ordering::Relaxed),
+            self.number_of_lost_entries()
+        )
+    }
+}
+
+impl<const CAPACITY: usize> Logger<CAPACITY> {
+    /// Creates a new empty [`Logger`].
+    pub const fn new() -> Self {
+        assert!(
+            CAPACITY > 0,
+            "The capacity of the ring buffer logger must be at least 1."
+        );
+        Self {
+            write_position: IoxAtomicU64::new(0),
+            read_position: IoxAtomicU64::new(0),
+            number_of_lost_entries: IoxAtomicU64::new(0),
+            slots: [Slot::EMPTY; CAPACITY],
+        }
+    }
+
+    /// Initializes an empty [`Logger`] in the provided memory, for instance in shared memory,
+    /// and returns a reference to it. Since a zeroed memory region is already an empty
+    /// [`Logger`], processes that open an initialized memory region can cast it directly.
+    ///
+    /// # Safety
+    ///
+    ///  * `memory` must point to valid memory with the size and alignment of [`Logger`]
+    ///  * the memory must stay valid for the lifetime `'a`
+    ///  * the memory must not be initialized concurrently by another process
+    pub unsafe fn new_in_place<'a>(memory: *mut Self) -> &'a Self {
+        assert!(
+            CAPACITY > 0,
+            "The capacity of the ring buffer logger must be at least 1."
+        );
+        memory.write_bytes(0, 1);
+        &*memory
+    }
+
+    /// Returns the number of log messages the [`Logger`] can store
+    pub const fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Returns the number of log messages that were overwritten or dropped before they could
+    /// be read with [`Logger::pop()`]
+    pub fn number_of_lost_entries(&self) -> u64 {
+        self.number_of_lost_entries.load(Ordering::Relaxed)
+    }
+
+    /// Removes the oldest log message from the ring buffer and returns it. Returns [`None`]
+    /// when the ring buffer is empty or when the oldest log message is still being written.
+    /// Must not be called concurrently from multiple threads.
+    pub fn pop(&self) -> Option<Entry> {
+        loop {
+            let write_position = self.write_position.load(Ordering::Acquire);
+            let mut read_position = self.read_position.load(Ordering::Relaxed);
+
+            if read_position >= write_position {
+                return None;
+            }
+
+            if write_position - read_position > CAPACITY as u64 {
+                let lost = write_position - read_position - CAPACITY as u64;
+                self.number_of_lost_entries
+                    .fetch_add(lost, Ordering::Relaxed);
+                read_position = write_position - CAPACITY as u64;
+            }
+
+            let slot = &self.slots[(read_position % CAPACITY as u64) as usize];
+            let complete = 2 * read_position + 2;
+            let sequence = slot.sequence.load(Ordering::Acquire);
+
+            if sequence < complete {
+                // the writer claimed the position but has not finished yet, or it dropped its
+                // message, then the reader catches up when the slot is written in the next lap
+                self.read_position.store(read_position, Ordering::Relaxed);
+                return None;
+            }
+
+            let entry = if sequence == complete {
+                // a writer of the next lap may overwrite the entry while it is copied, the
+                // sequence is verified afterwards and the copy is discarded in that case
+                let entry = unsafe { core::ptr::read_volatile(slot.entry.get()) };
+                fence(Ordering::Acquire);
+                if slot.sequence.load(Ordering::Relaxed) == complete {
+                    Some(entry)
+                } else {
+                    None
+                }
+            } else {
+                None
+            };
+
+            self.read_position
+                .store(read_position + 1, Ordering::Relaxed);
+
+            match entry {
+                Some(entry) => return Some(entry),
+                None => {
+                    self.number_of_lost_entries.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+    }
+
+    /// Removes all log messages from the ring buffer. Must not be called concurrently with
+    /// [`Logger::pop()`].
+    pub fn clear(&self) {
+        self.read_position.store(
+            self.write_position.load(Ordering::Acquire),
+            Ordering::Relaxed,
+        );
+    }
+
+    fn push(&self, log_level: LogLevel, origin: Arguments, message: Arguments) {
+        let position = self.write_position.fetch_add(1, Ordering::Relaxed);
+        let slot = &self.slots[(position % CAPACITY as u64) as usize];
+        let writing = 2 * position + 1;
+
+        // another writer that is one lap behind may still write into the slot, the message
+        // is dropped instead of waiting for it
+        let sequence = slot.sequence.load(Ordering::Relaxed);
+        if sequence % 2 == 1
+            || sequence >= writing
+            || slot
+                .sequence
+                .compare_exchange(sequence, writing, Ordering::Relaxed, Ordering::Relaxed)
+                .is_err()
+        {
+            self.number_of_lost_entries.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        fence(Ordering::Release);
+
+        let entry = unsafe { &mut *slot.entry.get() };
+        entry.log_level = log_level as u8;
+        entry.origin_len = format_into(&mut entry.origin, origin);
+        entry.message_len = format_into(&mut entry.message, message);
+
+        slot.sequence.store(writing + 1, Ordering::Release);
+    }
+}
+
+impl<const CAPACITY: usize> crate::logger::Logger for Logger<CAPACITY> {
+    fn log(&self, log_level: LogLevel, origin: Arguments, formatted_message: Arguments) {
+        if get_log_level() > log_level as u8 {
+            return;
+        }
+
+        self.push(log_level, origin, formatted_message);
+    }
+}
@@ -84,6 +84,10 @@ impl PoolAllocator {
         self.buckets.capacity()
     }
 
+    pub fn number_of_used_buckets(&self) -> usize {
+        self.buckets.borrowed_indices()
+    }
+
     pub fn bucket_size(&self) -> usize {
         self.bucket_size
     }
@@ -16,6 +16,7 @@
 //! * [`ClockType`] - describes certain types of clocks
 //! * [`nanosleep()`] & [`nanosleep_with_clock()`] - wait a defined amount of time on a custom
 //!                           clock
+//! * [`nanosleep_until()`] - wait until an absolute point in [`Time`] has been reached
 //! * [`AsTimeval`] - trait for easy [`posix::timeval`] conversion, required for low level posix
 //!                     calls
 //! * [`AsTimespec`] - trait for easy [`posix::timespec`] conversion, required for low level posix
@@ -253,6 +254,30 @@ impl Time {
     pub fn as_duration(&self) -> Duration {
         Duration::from_secs(self.seconds) + Duration::from_nanos(self.nanoseconds as u64)
     }
+
+    /// Returns the [`Time`] that lies the provided [`Duration`] in the future of this [`Time`]
+    /// on the same [`ClockType`].
+    ///
+    /// # Examples
+    /// ```
+    /// use iceoryx2_bb_posix::clock::*;
+    /// use std::time::Duration;
+    ///
+    /// let time = TimeBuilder::new().seconds(1).nanoseconds(900_000_000).create();
+    /// let later = time.add(Duration::from_millis(200));
+    ///
+    /// assert_eq!(later.seconds(), 2);
+    /// assert_eq!(later.nanoseconds(), 100_000_000);
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(&self, duration: Duration) -> Time {
+        let sum = self.as_duration() + duration;
+        Time {
+            clock_type: self.clock_type,
+            seconds: sum.as_secs(),
+            nanoseconds: sum.subsec_nanos(),
+        }
+    }
 }
 
 impl AsTimespec for Time {
@@ -301,39 +326,51 @@ pub fn nanosleep_with_clock(
     duration: Duration,
     clock_type: ClockType,
 ) -> Result<(), NanosleepError> {
-    let wait_time = Time::now_with_clock(clock_type)?.as_duration() + duration;
-
-    let timeout = posix::timespec {
-        tv_sec: wait_time.as_secs() as posix::time_t,
-        tv_nsec: wait_time.subsec_nanos() as posix::long,
-    };
+    nanosleep_until(&Time::now_with_clock(clock_type)?.add(duration))
+}
 
-    let mut time_left = posix::timespec {
-        tv_sec: 0,
-        tv_nsec: 0,
-    };
+/// Suspends the current thread until the provided absolute [`Time`] has been reached on its
+/// [`ClockType`]. In contrast to [`nanosleep()`] the wake up time does not depend on the time
+/// spent before the call, so it can be used to implement loops with a fixed rate that do not
+/// drift, see [`crate::cyclic_wait::CyclicWait`]. When the [`Time`] lies in the past it returns
+/// immediately.
+///
+/// # Examples
+/// ```
+/// use iceoryx2_bb_posix::clock::*;
+/// use std::time::Duration;
+///
+/// let wake_up_time = Time::now().unwrap().add(Duration::from_millis(100));
+/// nanosleep_until(&wake_up_time).unwrap();
+/// ```
+pub fn nanosleep_until(time: &Time) -> Result<(), NanosleepError> {
+    let timeout = time.as_timespec();
+    let clock_type = time.clock_type;
 
+    // with an absolute time the remaining time is not provided by clock_nanosleep
     let mut remaining_sleeping_time = Duration::ZERO;
-    handle_errno!(NanosleepError, from "nanosleep_with_clock",
+    handle_errno!(NanosleepError, from "nanosleep_until",
         errno_source unsafe {
             let e = posix::clock_nanosleep(
                 clock_type as _,
                 posix::CLOCK_TIMER_ABSTIME,
                 &timeout,
-                &mut time_left,
+                core::ptr::null_mut(),
             ).into();
 
             if e == Errno::EINTR {
-                remaining_sleeping_time = Duration::from_secs(time_left.tv_sec as u64)
-                    + Duration::from_nanos(time_left.tv_nsec as u64);
+                if let Ok(now) = Time::now_with_clock(clock_type) {
+                    remaining_sleeping_time =
+                        time.as_duration().saturating_sub(now.as_duration());
+                }
             }
             e
         },
         success Errno::ESUCCES => (),
         Errno::EINTR => (InterruptedBySignal(remaining_sleeping_time),
-            "Interrupted \"nanosleep\": {{ duration: {:?}, clock_type: {:?} }}, remaining sleeping time: {:?}", duration, clock_type, remaining_sleeping_time),
-        Errno::EINVAL => (DurationOutOfRange, "Invalid argument in \"nanosleep\". Either the duration: {:?} is out of range or the clock type {:?} is invalid.", duration, clock_type),
-        Errno::ENOTSUP => (ClockTypeIsNotSupported, "Clock not supported in \"nanosleep\": {{ duration: {:?}, clock_type: {:?} }}", duration, clock_type),
-        v => (UnknownError(v as i32), "Unknown error occurred in \"nanosleep\": {{ duration: {:?}, clock_type: {:?} }}, ({})", duration, clock_type, v)
+            "Interrupted \"nanosleep\": {{ time: {:?} }}, remaining sleeping time: {:?}", time, remaining_sleeping_time),
+        Errno::EINVAL => (DurationOutOfRange, "Invalid argument in \"nanosleep\". Either the time: {:?} is out of range or the clock type is invalid.", time),
+        Errno::ENOTSUP => (ClockTypeIsNotSupported, "Clock not supported in \"nanosleep\": {{ time: {:?} }}", time),
+        v => (UnknownError(v as i32), "Unknown error occurred in \"nanosleep\": {{ time: {:?} }}, ({})", time, v)
     );
 }
@@ -0,0 +1,160 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`CyclicWait`] is a building block for loops that shall run with a fixed rate, like control
+//! loops. The wake up times are multiples of the period starting at the creation of the
+//! [`CyclicWait`]. Since every wake up time is an absolute point in time the loop does not
+//! drift, independent of how long the work inside the loop takes.
+//!
+//! When the work took longer than a period, [`CyclicWait::wait()`] returns immediately and
+//! reports how many wake up times were missed. The missed wake up times are skipped so that
+//! the loop does not try to catch up with a burst of cycles.
+//!
+//! # Examples
+//! ```
+//! use iceoryx2_bb_posix::cyclic_wait::*;
+//! use iceoryx2_bb_posix::clock::*;
+//! use std::time::Duration;
+//!
+//! let mut cyclic_wait = CyclicWaitBuilder::new(Duration::from_millis(10))
+//!     .clock_type(ClockType::Monotonic)
+//!     .create().expect("Unable to create cyclic wait");
+//!
+//! for i in 0..3 {
+//!     // do the work of the cycle
+//!     let missed_cycles = cyclic_wait.wait().expect("unable to wait");
+//!     if missed_cycles > 0 {
+//!         println!("cycle {} overran by {} periods", i, missed_cycles);
+//!     }
+//! }
+//! ```
+
+use std::fmt::Debug;
+use std::time::Duration;
+
+use crate::clock::*;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::fail;
+
+/// The CyclicWaitBuilder is required to produce a [`CyclicWait`] object.
+/// The default value for clock is defined in [`ClockType::default()`].
+#[derive(Debug)]
+pub struct CyclicWaitBuilder {
+    period: Duration,
+    clock_type: ClockType,
+}
+
+impl CyclicWaitBuilder {
+    /// Creates a new builder for a [`CyclicWait`] that wakes up every `period`. A period of
+    /// zero is increased to one nanosecond.
+    pub fn new(period: Duration) -> CyclicWaitBuilder {
+        Self {
+            period: period.max(Duration::from_nanos(1)),
+            clock_type: ClockType::default(),
+        }
+    }
+
+    pub fn clock_type(mut self, clock_type: ClockType) -> Self {
+        self.clock_type = clock_type;
+        self
+    }
+
+    pub fn create(self) -> Result<CyclicWait, TimeError> {
+        CyclicWait::new(self)
+    }
+}
+
+enum_gen! { CyclicWaitError
+  mapping:
+    NanosleepError,
+    TimeError
+}
+
+/// Suspends the current thread until the next multiple of the period has been reached, see the
+/// [module documentation](crate::cyclic_wait) for details.
+#[derive(Debug)]
+pub struct CyclicWait {
+    period: Duration,
+    next_wake_up_time: Time,
+    number_of_cycles: u64,
+    number_of_missed_cycles: u64,
+}
+
+impl CyclicWait {
+    fn new(config: CyclicWaitBuilder) -> Result<Self, TimeError> {
+        let now = fail!(from config, when Time::now_with_clock(config.clock_type),
+                "Unable to create CyclicWait since the Time could not be acquired.");
+
+        Ok(CyclicWait {
+            period: config.period,
+            next_wake_up_time: now.add(config.period),
+            number_of_cycles: 0,
+            number_of_missed_cycles: 0,
+        })
+    }
+
+    /// Returns the period of the [`CyclicWait`].
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    pub fn clock_type(&self) -> ClockType {
+        self.next_wake_up_time.clock_type()
+    }
+
+    /// Returns the absolute [`Time`] the next [`CyclicWait::wait()`] call wakes up.
+    pub fn next_wake_up_time(&self) -> Time {
+        self.next_wake_up_time
+    }
+
+    /// Returns how many cycles were completed with [`CyclicWait::wait()`].
+    pub fn number_of_cycles(&self) -> u64 {
+        self.number_of_cycles
+    }
+
+    /// Returns how many wake up times were missed in total since the work of a cycle took
+    /// longer than the period.
+    pub fn number_of_missed_cycles(&self) -> u64 {
+        self.number_of_missed_cycles
+    }
+
+    /// Waits until the next wake up time has been reached and returns the number of wake up
+    /// times that were missed since the previous call. When it is interrupted by a signal
+    /// [`NanosleepError::InterruptedBySignal`] is returned and the next call waits for the same
+    /// wake up time.
+    pub fn wait(&mut self) -> Result<u64, CyclicWaitError> {
+        let msg = "Failure while waiting for the next cycle";
+        let now = fail!(from self, when Time::now_with_clock(self.clock_type()),
+                "{} since the current time could not be acquired.", msg);
+
+        let next_wake_up_time = self.next_wake_up_time.as_duration();
+        let now = now.as_duration();
+        let missed_cycles = if now > next_wake_up_time {
+            let delay = (now - next_wake_up_time).as_nanos();
+            (delay / self.period.as_nanos()) as u64
+        } else {
+            fail!(from self, when nanosleep_until(&self.next_wake_up_time),
+                "{} since the underlying sleep failed.", msg);
+            0
+        };
+
+        // the missed wake up times are skipped, the current one counts as the completed cycle
+        self.next_wake_up_time = self.next_wake_up_time.add(
+            self.period
+                .saturating_mul(u32::try_from(missed_cycles + 1).unwrap_or(u32::MAX)),
+        );
+        self.number_of_cycles += 1;
+        self.number_of_missed_cycles += missed_cycles;
+
+        Ok(missed_cycles)
+    }
+}
@@ -157,7 +157,7 @@ impl FileDescriptorSet {
     }
 
     /// Adds a file descriptor
-    pub fn add<'set, 'fd, F: SynchronousMultiplexing>(
+    pub fn add<'set, 'fd, F: SynchronousMultiplexing + ?Sized>(
         &'set self,
         fd: &'fd F,
     ) -> Result<FileDescriptorSetGuard<'set, 'fd>, FileDescriptorSetAddError> {
@@ -237,6 +237,25 @@ impl FileDescriptorSet {
         &self,
         timeout: Duration,
         event: FileEvent,
+        fd_callback: F,
+    ) -> Result<(), FileDescriptorSetWaitError> {
+        self.wait(Some(timeout), event, fd_callback)
+    }
+
+    /// Blocks until the specified event has occurred. It calls the provided callback with
+    /// every [`FileDescriptor`] which was triggered.
+    pub fn blocking_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        event: FileEvent,
+        fd_callback: F,
+    ) -> Result<(), FileDescriptorSetWaitError> {
+        self.wait(None, event, fd_callback)
+    }
+
+    fn wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        timeout: Option<Duration>,
+        event: FileEvent,
         mut fd_callback: F,
     ) -> Result<(), FileDescriptorSetWaitError> {
         let mut fd_set: posix::fd_set = self.internals().fd_set;
@@ -263,7 +282,11 @@ impl FileDescriptorSet {
             _ => std::ptr::null_mut::<posix::fd_set>(),
         };
 
-        let mut raw_timeout = timeout.as_timeval();
+        let mut raw_timeout = timeout.map(|t| t.as_timeval());
+        let timeout_ptr: *mut posix::timeval = match raw_timeout.as_mut() {
+            Some(raw_timeout) => raw_timeout,
+            None => std::ptr::null_mut::<posix::timeval>(),
+        };
         let msg = "Failure while waiting for file descriptor events";
 
         if unsafe {
@@ -272,7 +295,7 @@ impl FileDescriptorSet {
                 read_fd,
                 write_fd,
                 exceptional_fd,
-                &mut raw_timeout,
+                timeout_ptr,
             )
         } == -1
         {
@@ -41,6 +41,7 @@ pub mod clock;
 pub mod condition_variable;
 pub mod config;
 pub mod creation_mode;
+pub mod cyclic_wait;
 pub mod udp_socket;
 #[macro_use]
 pub mod handle_errno;
@@ -60,9 +61,11 @@ pub mod mutex;
 pub mod ownership;
 pub mod permission;
 pub mod process;
+pub mod process_monitor;
 pub mod process_state;
 pub mod read_write_mutex;
 pub mod scheduler;
+pub mod sealed_shared_memory;
 pub mod semaphore;
 pub mod shared_memory;
 pub mod signal;
@@ -71,6 +74,7 @@ pub mod system_configuration;
 #[doc(hidden)]
 pub mod testing;
 pub mod thread;
+pub mod timer;
 pub mod unique_system_id;
 pub mod unix_datagram_socket;
 pub mod user;
@@ -274,6 +274,21 @@ pub enum MutexThreadTerminationBehavior {
     ReleaseWhenLocked = posix::PTHREAD_MUTEX_ROBUST,
 }
 
+/// Defines the outcome of the consistency restoration callback that is provided to
+/// [`Mutex::lock_with_recovery()`], [`Mutex::try_lock_with_recovery()`] or
+/// [`Mutex::timed_lock_with_recovery()`] and called when the previous owner died while holding
+/// the lock.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum MutexRecovery {
+    /// The guarded value was repaired. The [`Mutex`] is marked as consistent and can be used
+    /// again.
+    Consistent,
+    /// The guarded value cannot be repaired. The lock is released and the [`Mutex`] becomes
+    /// unrecoverable, every further lock attempt fails with
+    /// [`MutexLockError::UnrecoverableState`].
+    Unrecoverable,
+}
+
 /// Creates a [`Mutex`].
 #[derive(Debug)]
 pub struct MutexBuilder {
@@ -340,6 +355,36 @@ impl MutexBuilder {
         self
     }
 
+    /// Configures an inter-process capable, robust [`Mutex`] with priority inheritance, the
+    /// building block for data structures in shared memory that must survive the death of a
+    /// process. It combines [`MutexBuilder::is_interprocess_capable()`],
+    /// [`MutexType::WithDeadlockDetection`],
+    /// [`MutexThreadTerminationBehavior::ReleaseWhenLocked`] and
+    /// [`MutexPriorityInheritance::Inherit`]. The state of a dead owner shall be repaired with
+    /// [`Mutex::try_lock_with_recovery()`] or [`Mutex::timed_lock_with_recovery()`].
+    ///
+    /// ```
+    /// use iceoryx2_bb_posix::mutex::*;
+    ///
+    /// let handle = MutexHandle::<i32>::new();
+    /// let mutex = MutexBuilder::new()
+    ///                 .robust_interprocess_priority_inheritance()
+    ///                 .create(123, &handle)
+    ///                 .expect("failed to create mutex");
+    ///
+    /// let guard = mutex.try_lock_with_recovery(|value| {
+    ///     // the previous owner died, repair the guarded value
+    ///     *value = 0;
+    ///     MutexRecovery::Consistent
+    /// }).expect("failed to lock");
+    /// ```
+    pub fn robust_interprocess_priority_inheritance(self) -> Self {
+        self.is_interprocess_capable(true)
+            .mutex_type(MutexType::WithDeadlockDetection)
+            .thread_termination_behavior(MutexThreadTerminationBehavior::ReleaseWhenLocked)
+            .priority_inheritance(MutexPriorityInheritance::Inherit)
+    }
+
     fn initialize_mutex<T: Debug>(
         &self,
         mutex: *mut posix::pthread_mutex_t,
@@ -642,6 +687,7 @@ impl<'a, T: Debug> Mutex<'a, T> {
                     success Errno::EDEADLK => None,
                     Errno::EAGAIN => (MutexLockError(MutexLockError::ExceededMaximumNumberOfRecursiveLocks), "{} since the maximum number of recursive locks exceeded.", msg),
                     Errno::EINVAL => (TimeoutExceedsMaximumSupportedDuration, "{} since the timeout of {:?} exceeds the maximum supported duration.", msg, duration),
+                    Errno::EOWNERDEAD => (MutexLockError(MutexLockError::LockAcquiredButOwnerDied(MutexGuard { mutex: self })), "{} since the thread/process holding the mutex died.", msg),
                     Errno::ENOTRECOVERABLE => (MutexLockError(MutexLockError::UnrecoverableState), "{} since the thread/process holding the mutex died and the next owner did not repair the state with Mutex::make_consistent.", msg),
                     v => (MutexLockError(MutexLockError::UnknownError(v as i32)), "{} since unknown error occurred while acquiring the lock ({})", msg, v)
                 )
@@ -687,6 +733,71 @@ impl<'a, T: Debug> Mutex<'a, T> {
         }
     }
 
+    /// Like [`Mutex::lock()`] but when the previous owner died while holding the lock, the
+    /// provided callback is called with the guarded value to restore its consistency. When the
+    /// callback returns [`MutexRecovery::Consistent`] the [`Mutex`] is marked as consistent
+    /// and the [`MutexGuard`] is returned, otherwise the lock is released and
+    /// [`MutexLockError::UnrecoverableState`] is returned.
+    pub fn lock_with_recovery<F: FnOnce(&mut T) -> MutexRecovery>(
+        &self,
+        recover: F,
+    ) -> Result<MutexGuard<'_, '_, T>, MutexLockError<'_, '_, T>> {
+        self.restore_consistency(self.lock(), recover)
+    }
+
+    /// Like [`Mutex::try_lock()`] but restores the consistency of the guarded value with the
+    /// provided callback when the previous owner died while holding the lock, see
+    /// [`Mutex::lock_with_recovery()`].
+    pub fn try_lock_with_recovery<F: FnOnce(&mut T) -> MutexRecovery>(
+        &self,
+        recover: F,
+    ) -> Result<Option<MutexGuard<'_, '_, T>>, MutexLockError<'_, '_, T>> {
+        match self.try_lock() {
+            Err(e) => self.restore_consistency(Err(e), recover).map(Some),
+            v => v,
+        }
+    }
+
+    /// Like [`Mutex::timed_lock()`] but restores the consistency of the guarded value with the
+    /// provided callback when the previous owner died while holding the lock, see
+    /// [`Mutex::lock_with_recovery()`].
+    pub fn timed_lock_with_recovery<F: FnOnce(&mut T) -> MutexRecovery>(
+        &self,
+        duration: Duration,
+        recover: F,
+    ) -> Result<Option<MutexGuard<'_, '_, T>>, MutexTimedLockError<'_, '_, T>> {
+        match self.timed_lock(duration) {
+            Err(MutexTimedLockError::MutexLockError(e)) => self
+                .restore_consistency(Err(e), recover)
+                .map(Some)
+                .map_err(MutexTimedLockError::MutexLockError),
+            v => v,
+        }
+    }
+
+    fn restore_consistency<'m, F: FnOnce(&mut T) -> MutexRecovery>(
+        &'m self,
+        result: Result<MutexGuard<'m, 'm, T>, MutexLockError<'m, 'm, T>>,
+        recover: F,
+    ) -> Result<MutexGuard<'m, 'm, T>, MutexLockError<'m, 'm, T>> {
+        match result {
+            Err(MutexLockError::LockAcquiredButOwnerDied(mut guard)) => {
+                match recover(&mut *guard) {
+                    MutexRecovery::Consistent => {
+                        self.make_consistent();
+                        Ok(guard)
+                    }
+                    MutexRecovery::Unrecoverable => {
+                        drop(guard);
+                        fail!(from self, with MutexLockError::UnrecoverableState,
+                            "Unable to lock since the thread/process holding the mutex died and the guarded value could not be repaired.");
+                    }
+                }
+            }
+            v => v,
+        }
+    }
+
     /// Returns the current priority ceiling of the mutex.
     pub fn priority_ceiling(&self) -> Result<i32, MutexGetPrioCeilingError> {
         let mut value: i32 = 0;
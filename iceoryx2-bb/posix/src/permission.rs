@@ -17,12 +17,13 @@
 
 use bitflags::bitflags;
 use iceoryx2_pal_posix::*;
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
 type ModeType = posix::mode_t;
 
 /// Defines the permission of a file or directory in a POSIX system.
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Permission(ModeType);
 
 bitflags! {
@@ -50,7 +50,7 @@ use iceoryx2_pal_posix::*;
 
 use crate::{
     scheduler::{Scheduler, SchedulerConversionError},
-    signal::Signal,
+    signal::{RealTimeSignal, Signal},
 };
 
 enum_gen! { ProcessSendSignalError
@@ -165,7 +165,20 @@ impl Process {
 
     /// Sends a signal to the process.
     pub fn send_signal(&self, signal: Signal) -> Result<(), ProcessSendSignalError> {
-        if unsafe { posix::kill(self.pid.0, signal as i32) } == 0 {
+        self.send_raw_signal(signal as i32)
+    }
+
+    /// Sends a [`RealTimeSignal`] to the process. In contrast to [`Process::send_signal()`]
+    /// every sent signal is delivered, even when the same signal is still pending.
+    pub fn send_real_time_signal(
+        &self,
+        signal: RealTimeSignal,
+    ) -> Result<(), ProcessSendSignalError> {
+        self.send_raw_signal(signal.value())
+    }
+
+    fn send_raw_signal(&self, signal: i32) -> Result<(), ProcessSendSignalError> {
+        if unsafe { posix::kill(self.pid.0, signal) } == 0 {
             return Ok(());
         }
 
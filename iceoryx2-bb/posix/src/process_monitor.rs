@@ -0,0 +1,257 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Event driven detection of the termination of an arbitrary [`Process`]. On Linux the
+//! [`ProcessMonitor`] is based on a pidfd and on macOS and FreeBSD on a kqueue. Both are
+//! [`FileDescriptor`]s that become readable as soon as the process terminates and can therefore
+//! be attached to an event loop. On all other platforms, or when the kernel does not support it,
+//! the state of the process is polled.
+//!
+//! In contrast to the [`ProcessMonitor`](crate::process_state::ProcessMonitor) of
+//! [`crate::process_state`] the monitored process does not have to cooperate by holding a
+//! file lock.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2_bb_posix::process::ProcessId;
+//! use iceoryx2_bb_posix::process_monitor::*;
+//! use std::time::Duration;
+//!
+//! let monitor = ProcessMonitor::new(ProcessId::new(123)).unwrap();
+//!
+//! if monitor.timed_wait(Duration::from_secs(1)).unwrap() {
+//!     println!("process {} terminated", monitor.pid());
+//! }
+//! ```
+
+use std::time::Duration;
+
+use crate::adaptive_wait::AdaptiveWaitBuilder;
+use crate::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use crate::file_descriptor_set::{
+    FileDescriptorSet, FileDescriptorSetWaitError, FileEvent, SynchronousMultiplexing,
+};
+use crate::process::{Process, ProcessId};
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::*;
+
+// select does not support arbitrary long timeouts, a blocking wait waits in multiple rounds
+const BLOCKING_WAIT_INTERVAL: Duration = Duration::from_secs(60);
+
+enum_gen! { ProcessMonitorCreateError
+  entry:
+    ProcessDoesNotExist,
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    InsufficientMemory,
+    UnknownError(i32)
+}
+
+enum_gen! { ProcessMonitorWaitError
+  entry:
+    Interrupt,
+    InsufficientPermissions,
+    InternalFailure,
+    UnknownError(i32)
+}
+
+// the file descriptor itself is not exposed as SynchronousMultiplexing object since reading
+// from or writing to it is meaningless
+struct ExitNotification<'a>(&'a FileDescriptor);
+
+impl FileDescriptorBased for ExitNotification<'_> {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.0
+    }
+}
+
+impl SynchronousMultiplexing for ExitNotification<'_> {}
+
+/// Detects the termination of a [`Process`], see the [module](crate::process_monitor)
+/// documentation for an example.
+#[derive(Debug)]
+pub struct ProcessMonitor {
+    process: Process,
+    // the pidfd or kqueue that becomes readable when the process terminates, if it is not
+    // available the process is polled
+    exit_notification: Option<FileDescriptor>,
+}
+
+impl ProcessMonitor {
+    /// Starts the monitoring of the [`Process`] with the provided [`ProcessId`]. Fails when
+    /// the process does not exist.
+    pub fn new(pid: ProcessId) -> Result<Self, ProcessMonitorCreateError> {
+        let msg = "Unable to monitor process";
+        let raw_fd = unsafe { posix::pidfd_open(pid.value(), 0) };
+
+        if raw_fd != -1 {
+            let exit_notification = match FileDescriptor::new(raw_fd) {
+                Some(fd) => fd,
+                None => {
+                    fatal_panic!(from "ProcessMonitor::new()",
+                        "This should never happen! {} {} since the acquired file descriptor {} is invalid.", msg, pid, raw_fd);
+                }
+            };
+
+            return Ok(Self {
+                process: Process::from_pid(pid),
+                exit_notification: Some(exit_notification),
+            });
+        }
+
+        let origin = format!("ProcessMonitor::new({})", pid);
+        match Errno::get() {
+            // unsupported by the platform or the kernel, fall back to polling
+            Errno::ENOSYS | Errno::ENODEV => {
+                if !Self::is_alive(pid) {
+                    fail!(from origin, with ProcessMonitorCreateError::ProcessDoesNotExist,
+                        "{} since the process does not exist.", msg);
+                }
+
+                Ok(Self {
+                    process: Process::from_pid(pid),
+                    exit_notification: None,
+                })
+            }
+            Errno::ESRCH | Errno::EINVAL => {
+                fail!(from origin, with ProcessMonitorCreateError::ProcessDoesNotExist,
+                    "{} since the process does not exist.", msg);
+            }
+            Errno::EMFILE => {
+                fail!(from origin, with ProcessMonitorCreateError::PerProcessFileHandleLimitReached,
+                    "{} since the processes file descriptor limit was reached.", msg);
+            }
+            Errno::ENFILE => {
+                fail!(from origin, with ProcessMonitorCreateError::SystemWideFileHandleLimitReached,
+                    "{} since the system wide file descriptor limit was reached.", msg);
+            }
+            Errno::ENOMEM => {
+                fail!(from origin, with ProcessMonitorCreateError::InsufficientMemory,
+                    "{} due to insufficient memory.", msg);
+            }
+            v => {
+                fail!(from origin, with ProcessMonitorCreateError::UnknownError(v as i32),
+                    "{} since an unknown error occurred ({}).", msg, v);
+            }
+        }
+    }
+
+    /// Returns the [`ProcessId`] of the monitored [`Process`].
+    pub fn pid(&self) -> ProcessId {
+        self.process.id()
+    }
+
+    /// Returns true when the termination is signaled by the operating system, otherwise the
+    /// state of the [`Process`] is polled.
+    pub fn is_event_driven(&self) -> bool {
+        self.exit_notification.is_some()
+    }
+
+    /// Returns the [`FileDescriptor`] that becomes readable when the [`Process`] terminates so
+    /// that it can be attached to an event loop. Returns [`None`] when the
+    /// [`ProcessMonitor`] is not event driven.
+    pub fn file_descriptor(&self) -> Option<&FileDescriptor> {
+        self.exit_notification.as_ref()
+    }
+
+    /// Returns true when the [`Process`] has terminated, otherwise false. Does not block.
+    pub fn has_terminated(&self) -> Result<bool, ProcessMonitorWaitError> {
+        self.timed_wait(Duration::ZERO)
+    }
+
+    /// Waits until the [`Process`] has terminated or the timeout has passed. Returns true when
+    /// the [`Process`] has terminated, otherwise false.
+    pub fn timed_wait(&self, timeout: Duration) -> Result<bool, ProcessMonitorWaitError> {
+        match &self.exit_notification {
+            Some(fd) => self.wait_for_exit_notification(fd, timeout),
+            None => self.poll(timeout),
+        }
+    }
+
+    /// Blocks until the [`Process`] has terminated.
+    pub fn blocking_wait(&self) -> Result<(), ProcessMonitorWaitError> {
+        while !self.timed_wait(BLOCKING_WAIT_INTERVAL)? {}
+        Ok(())
+    }
+
+    fn wait_for_exit_notification(
+        &self,
+        fd: &FileDescriptor,
+        timeout: Duration,
+    ) -> Result<bool, ProcessMonitorWaitError> {
+        let msg = "Unable to wait for the process termination";
+        let fd_set = FileDescriptorSet::new();
+        let exit_notification = ExitNotification(fd);
+        let _guard = fatal_panic!(from self, when fd_set.add(&exit_notification),
+                        "This should never happen! {} since a single file descriptor exceeds the capacity of the file descriptor set.", msg);
+
+        let mut has_terminated = false;
+        match fd_set.timed_wait(timeout, FileEvent::Read, |_| has_terminated = true) {
+            Ok(()) => Ok(has_terminated),
+            Err(FileDescriptorSetWaitError::Interrupt) => {
+                fail!(from self, with ProcessMonitorWaitError::Interrupt,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(FileDescriptorSetWaitError::InsufficientPermissions) => {
+                fail!(from self, with ProcessMonitorWaitError::InsufficientPermissions,
+                    "{} due to insufficient permissions.", msg);
+            }
+            Err(FileDescriptorSetWaitError::TooManyAttachedFileDescriptors) => {
+                fail!(from self, with ProcessMonitorWaitError::InternalFailure,
+                    "{} since the file descriptor could not be waited on.", msg);
+            }
+            Err(FileDescriptorSetWaitError::UnknownError(v)) => {
+                fail!(from self, with ProcessMonitorWaitError::UnknownError(v),
+                    "{} since an unknown error occurred ({}).", msg, v);
+            }
+        }
+    }
+
+    fn poll(&self, timeout: Duration) -> Result<bool, ProcessMonitorWaitError> {
+        let msg = "Unable to poll the process state";
+        let pid = self.pid();
+
+        if !Self::is_alive(pid) {
+            return Ok(true);
+        }
+
+        if timeout.is_zero() {
+            return Ok(false);
+        }
+
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                    with ProcessMonitorWaitError::InternalFailure,
+                                    "{} since the adaptive wait could not be created.", msg);
+
+        match adaptive_wait
+            .timed_wait_while(|| -> Result<bool, ()> { Ok(Self::is_alive(pid)) }, timeout)
+        {
+            Ok(has_terminated) => Ok(has_terminated),
+            Err(e) => {
+                fail!(from self, with ProcessMonitorWaitError::InternalFailure,
+                    "{} since the adaptive wait failed ({:?}).", msg, e);
+            }
+        }
+    }
+
+    fn is_alive(pid: ProcessId) -> bool {
+        if unsafe { posix::kill(pid.value(), 0) } == 0 {
+            return true;
+        }
+
+        // the process exists but belongs to another user
+        Errno::get() == Errno::EPERM
+    }
+}
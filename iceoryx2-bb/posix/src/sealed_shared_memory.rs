@@ -0,0 +1,314 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Provides a [`SealedSharedMemory`] that is based on an anonymous memory file (`memfd`) whose
+//! size is sealed with `F_SEAL_SHRINK` and `F_SEAL_GROW` right after its creation. In contrast
+//! to the [`SharedMemory`](crate::shared_memory::SharedMemory) no process can truncate or resize
+//! the memory afterwards, a misbehaving peer can therefore not cause a `SIGBUS` in the other
+//! processes that have the memory mapped.
+//!
+//! The memory has no name in the file system, other processes open it via its
+//! [`SealedSharedMemory::handle()`] as long as the creating process is alive.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::sealed_shared_memory::*;
+//! use iceoryx2_bb_system_types::file_name::FileName;
+//! use iceoryx2_bb_container::semantic_string::*;
+//! use iceoryx2_pal_posix::posix::POSIX_SUPPORT_SEALED_SHARED_MEMORY;
+//!
+//! # if POSIX_SUPPORT_SEALED_SHARED_MEMORY {
+//! let name = FileName::new(b"someSealedMemory").unwrap();
+//! let mut memory = SealedSharedMemoryBuilder::new(&name)
+//!                     .size(1024)
+//!                     .create()
+//!                     .expect("failed to create sealed shared memory");
+//!
+//! memory.as_mut_slice()[0] = 0xFF;
+//!
+//! // usually a different process
+//! let view = SealedSharedMemoryBuilder::new(&name)
+//!                     .open_existing(memory.handle(), AccessMode::Read)
+//!                     .expect("failed to open sealed shared memory");
+//!
+//! println!("first byte: {}", view.as_slice()[0]);
+//! # }
+//! ```
+
+use crate::file::{FileStatError, FileTruncateError};
+use crate::file_descriptor::*;
+use crate::handle_errno;
+use crate::process::Process;
+use iceoryx2_bb_container::semantic_string::*;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::{fail, fatal_panic, trace};
+use iceoryx2_bb_system_types::file_name::*;
+use iceoryx2_bb_system_types::file_path::*;
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::*;
+use std::ptr::NonNull;
+
+pub use crate::access_mode::AccessMode;
+
+const REQUIRED_SEALS: posix::int = posix::F_SEAL_SHRINK | posix::F_SEAL_GROW;
+
+enum_gen! { SealedSharedMemoryCreationError
+  entry:
+    NotSupported,
+    UnsupportedSizeOfZero,
+    InsufficientMemory,
+    InsufficientPermissions,
+    InvalidName,
+    MappedRegionLimitReached,
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    DoesNotExist,
+    MissingSeals,
+    UnknownError(i32)
+  mapping:
+    FileTruncateError,
+    FileStatError
+}
+
+/// The builder for the [`SealedSharedMemory`].
+#[derive(Debug)]
+pub struct SealedSharedMemoryBuilder {
+    name: FileName,
+    size: usize,
+}
+
+impl SealedSharedMemoryBuilder {
+    /// Creates a new builder. The name is only used for debugging purposes, it is neither
+    /// required to be unique nor can it be used to open the memory.
+    pub fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            size: 0,
+        }
+    }
+
+    /// The size of the sealed shared memory.
+    pub fn size(mut self, value: usize) -> Self {
+        self.size = value;
+        self
+    }
+
+    /// Opens the [`SealedSharedMemory`] that is referred to by the provided
+    /// [`SealedSharedMemory::handle()`]. Fails with
+    /// [`SealedSharedMemoryCreationError::MissingSeals`] when the memory can still be resized.
+    pub fn open_existing(
+        mut self,
+        handle: &FilePath,
+        access_mode: AccessMode,
+    ) -> Result<SealedSharedMemory, SealedSharedMemoryCreationError> {
+        let msg = "Unable to open sealed shared memory";
+        let fd = match FileDescriptor::new(unsafe {
+            posix::open(handle.as_c_str(), access_mode.as_oflag())
+        }) {
+            Some(fd) => fd,
+            None => {
+                handle_errno!(SealedSharedMemoryCreationError, from self,
+                    Errno::ENOENT => (DoesNotExist, "{} since the handle \"{}\" does not exist.", msg, handle),
+                    Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+                    Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process file handle limit was reached.", msg),
+                    Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since the system-wide file handle limit was reached.", msg),
+                    v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+                );
+            }
+        };
+
+        let seals = unsafe { posix::fcntl_int(fd.native_handle(), posix::F_GET_SEALS, 0) };
+        if seals == -1 || seals & REQUIRED_SEALS != REQUIRED_SEALS {
+            fail!(from self, with SealedSharedMemoryCreationError::MissingSeals,
+                "{} since the memory behind the handle \"{}\" is not sealed against resizing.", msg, handle);
+        }
+
+        self.size = fail!(from self, when fd.metadata(),
+                "{} since a failure occurred while acquiring the file attributes.", msg)
+        .size() as usize;
+
+        let base_address = fail!(from self, when Self::mmap(&fd, self.size, access_mode),
+                "{} since the memory could not be mapped.", msg);
+
+        let memory = SealedSharedMemory {
+            name: self.name,
+            size: self.size,
+            base_address: base_address as *mut u8,
+            handle: *handle,
+            file_descriptor: fd,
+        };
+
+        trace!(from memory, "open");
+        Ok(memory)
+    }
+
+    /// Creates a new [`SealedSharedMemory`]. The memory is zeroed by the operating system.
+    pub fn create(self) -> Result<SealedSharedMemory, SealedSharedMemoryCreationError> {
+        let msg = "Unable to create sealed shared memory";
+        if self.size == 0 {
+            fail!(from self, with SealedSharedMemoryCreationError::UnsupportedSizeOfZero,
+                "{} since a size of zero is not supported.", msg);
+        }
+
+        let mut fd = match FileDescriptor::new(unsafe {
+            posix::memfd_create(
+                self.name.as_c_str(),
+                posix::MFD_CLOEXEC | posix::MFD_ALLOW_SEALING,
+            )
+        }) {
+            Some(fd) => fd,
+            None => {
+                handle_errno!(SealedSharedMemoryCreationError, from self,
+                    Errno::ENOSYS => (NotSupported, "{} since memory files are not supported on this platform.", msg),
+                    Errno::EINVAL => (InvalidName, "{} since the provided name \"{}\" is invalid.", msg, self.name),
+                    Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+                    Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process file handle limit was reached.", msg),
+                    Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since the system-wide file handle limit was reached.", msg),
+                    v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+                );
+            }
+        };
+
+        fail!(from self, when fd.truncate(self.size),
+            "{} since the memory file could not be truncated to the size of {}.", msg, self.size);
+
+        if unsafe {
+            posix::fcntl_int(
+                fd.native_handle(),
+                posix::F_ADD_SEALS,
+                REQUIRED_SEALS | posix::F_SEAL_SEAL,
+            )
+        } != 0
+        {
+            fail!(from self, with SealedSharedMemoryCreationError::MissingSeals,
+                "{} since the seals could not be applied ({}).", msg, Errno::get());
+        }
+
+        let base_address = fail!(from self, when Self::mmap(&fd, self.size, AccessMode::ReadWrite),
+                "{} since the memory could not be mapped.", msg);
+
+        let native_handle = unsafe { fd.native_handle() };
+        let handle = format!("/proc/{}/fd/{}", Process::from_self().id(), native_handle);
+        let handle = fatal_panic!(from self, when FilePath::new(handle.as_bytes()),
+                "This should never happen! The handle \"{}\" of the sealed shared memory is not a valid file path.", handle);
+
+        let memory = SealedSharedMemory {
+            name: self.name,
+            size: self.size,
+            base_address: base_address as *mut u8,
+            handle,
+            file_descriptor: fd,
+        };
+
+        trace!(from memory, "create");
+        Ok(memory)
+    }
+
+    fn mmap(
+        file_descriptor: &FileDescriptor,
+        size: usize,
+        access_mode: AccessMode,
+    ) -> Result<*mut posix::void, SealedSharedMemoryCreationError> {
+        let base_address = unsafe {
+            posix::mmap(
+                std::ptr::null_mut::<posix::void>(),
+                size,
+                access_mode.as_protflag(),
+                posix::MAP_SHARED,
+                file_descriptor.native_handle(),
+                0,
+            )
+        };
+
+        if base_address != posix::MAP_FAILED {
+            return Ok(base_address);
+        }
+
+        let msg = "Unable to map sealed shared memory";
+        handle_errno!(SealedSharedMemoryCreationError, from "SealedSharedMemoryBuilder::mmap()",
+            Errno::EINVAL => (UnsupportedSizeOfZero, "{} since a size of zero is not supported.", msg),
+            Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+            Errno::EMFILE => (MappedRegionLimitReached, "{} since the number of mapped regions would exceed the process or system limit.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+}
+
+/// A shared memory whose size cannot be changed after its creation. It is built by the
+/// [`SealedSharedMemoryBuilder`].
+#[derive(Debug)]
+pub struct SealedSharedMemory {
+    name: FileName,
+    size: usize,
+    base_address: *mut u8,
+    handle: FilePath,
+    file_descriptor: FileDescriptor,
+}
+
+impl Drop for SealedSharedMemory {
+    fn drop(&mut self) {
+        if unsafe { posix::munmap(self.base_address as *mut posix::void, self.size) } != 0 {
+            fatal_panic!(from self, "This should never happen! Unable to unmap since the base address or range is invalid.");
+        }
+        trace!(from self, "close");
+    }
+}
+
+impl SealedSharedMemory {
+    /// Returns the name of the sealed shared memory
+    pub fn name(&self) -> &FileName {
+        &self.name
+    }
+
+    /// Returns the handle with which other processes can open the sealed shared memory with
+    /// [`SealedSharedMemoryBuilder::open_existing()`]. The handle is only valid as long as the
+    /// creating process holds the memory.
+    pub fn handle(&self) -> &FilePath {
+        &self.handle
+    }
+
+    /// Returns the base address of the sealed shared memory. The base address is always aligned
+    /// to the page size.
+    pub fn base_address(&self) -> NonNull<u8> {
+        match NonNull::new(self.base_address) {
+            Some(v) => v,
+            None => {
+                fatal_panic!(from self,
+                    "This should never happen! A valid sealed shared memory object should never contain a base address with null value.");
+            }
+        }
+    }
+
+    /// Returns the size of the sealed shared memory
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns a slice to the memory
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.base_address, self.size) }
+    }
+
+    /// Returns a mutable slice to the memory
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.base_address, self.size) }
+    }
+}
+
+impl FileDescriptorBased for SealedSharedMemory {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.file_descriptor
+    }
+}
+
+impl FileDescriptorManagement for SealedSharedMemory {}
@@ -68,10 +68,10 @@ use crate::file_descriptor::*;
 use crate::handle_errno;
 use crate::memory_lock::{MemoryLock, MemoryLockCreationError};
 use crate::signal::SignalHandler;
-use crate::system_configuration::Limit;
+use crate::system_configuration::{Limit, SystemInfo};
 use iceoryx2_bb_container::semantic_string::*;
 use iceoryx2_bb_elementary::enum_gen;
-use iceoryx2_bb_log::{error, fail, fatal_panic, trace};
+use iceoryx2_bb_log::{debug, error, fail, fatal_panic, trace};
 use iceoryx2_bb_system_types::file_name::*;
 use iceoryx2_bb_system_types::file_path::*;
 use iceoryx2_bb_system_types::path::*;
@@ -130,6 +130,7 @@ pub struct SharedMemoryBuilder {
     zero_memory: bool,
     access_mode: AccessMode,
     enforce_base_address: Option<u64>,
+    huge_page_size: usize,
 }
 
 impl SharedMemoryBuilder {
@@ -144,6 +145,7 @@ impl SharedMemoryBuilder {
             creation_mode: None,
             zero_memory: true,
             enforce_base_address: None,
+            huge_page_size: 0,
         }
     }
 
@@ -161,6 +163,15 @@ impl SharedMemoryBuilder {
         self
     }
 
+    /// Requests that the shared memory is backed by huge pages of the provided size to reduce
+    /// TLB misses. When huge pages are not available it falls back to the default page size,
+    /// see [`SharedMemory::page_size()`]. A value that is not larger than the default page size
+    /// disables huge pages, which is the default.
+    pub fn huge_page_size(mut self, value: usize) -> Self {
+        self.huge_page_size = value;
+        self
+    }
+
     /// Opens an already existing shared memory.
     pub fn open_existing(
         mut self,
@@ -189,10 +200,12 @@ impl SharedMemoryBuilder {
                 "{} since the memory was mapped at {:X} which is not enforced base address.", msg, base_address as u64);
         }
 
+        let page_size = SharedMemory::advise_huge_pages(&fd, base_address, &self);
         let shm = SharedMemory {
             name: self.name,
             base_address: base_address as *mut u8,
             size: actual_shm_size as usize,
+            page_size,
             has_ownership: IoxAtomicBool::new(false),
             memory_lock: None,
             file_descriptor: fd,
@@ -292,6 +305,7 @@ impl SharedMemoryCreationBuilder {
             name: self.config.name,
             base_address: core::ptr::null_mut::<u8>(),
             size: self.config.size,
+            page_size: SystemInfo::PageSize.value(),
             has_ownership: IoxAtomicBool::new(self.config.has_ownership),
             memory_lock: None,
             file_descriptor: fd,
@@ -309,6 +323,11 @@ impl SharedMemoryCreationBuilder {
             shm.base_address = fail!(from self.config, when SharedMemory::mmap(&shm.file_descriptor, &self.config),
                                     "{} since the memory could not be mapped.", msg)
                 as *mut u8;
+            shm.page_size = SharedMemory::advise_huge_pages(
+                &shm.file_descriptor,
+                shm.base_address.cast(),
+                &self.config,
+            );
 
             trace!(from shm, "open");
             return Ok(shm);
@@ -335,6 +354,12 @@ impl SharedMemoryCreationBuilder {
                 "{} since the actual size {} is less than to the configured size {}.", msg, actual_shm_size, self.config.size);
         }
         shm.size = actual_shm_size as _;
+        // must be advised before the memory is touched the first time
+        shm.page_size = SharedMemory::advise_huge_pages(
+            &shm.file_descriptor,
+            shm.base_address.cast(),
+            &self.config,
+        );
 
         if self.config.is_memory_locked {
             shm.memory_lock = Some(
@@ -370,6 +395,7 @@ impl SharedMemoryCreationBuilder {
 pub struct SharedMemory {
     name: FileName,
     size: usize,
+    page_size: usize,
     base_address: *mut u8,
     has_ownership: IoxAtomicBool,
     file_descriptor: FileDescriptor,
@@ -492,6 +518,13 @@ impl SharedMemory {
         self.size
     }
 
+    /// returns the page size that backs the shared memory. It is the huge page size when huge
+    /// pages were requested with [`SharedMemoryBuilder::huge_page_size()`] and are available,
+    /// otherwise the default page size.
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
     /// returns a slice to the memory
     pub fn as_slice(&self) -> &[u8] {
         unsafe { std::slice::from_raw_parts(self.base_address, self.size) }
@@ -590,6 +623,31 @@ impl SharedMemory {
         );
     }
 
+    fn advise_huge_pages(
+        file_descriptor: &FileDescriptor,
+        base_address: *mut posix::void,
+        config: &SharedMemoryBuilder,
+    ) -> usize {
+        let page_size = SystemInfo::PageSize.value();
+        if config.huge_page_size <= page_size {
+            return page_size;
+        }
+
+        if unsafe { posix::madvise(base_address, config.size, posix::MADV_HUGEPAGE) } != 0 {
+            debug!(from config, "Huge pages are not available, fall back to the page size of {}.", page_size);
+            return page_size;
+        }
+
+        // the block size reports the page size of the underlying file system, it is the huge
+        // page size only when the shared memory file system supports huge pages
+        match file_descriptor.metadata() {
+            Ok(metadata) => {
+                (metadata.block_size() as usize).clamp(page_size, config.huge_page_size)
+            }
+            Err(_) => page_size,
+        }
+    }
+
     fn shm_unlink(name: &FileName) -> Result<bool, SharedMemoryRemoveError> {
         let file_path =
             FilePath::from_path_and_file(&Path::new(&[PATH_SEPARATOR; 1]).unwrap(), name).unwrap();
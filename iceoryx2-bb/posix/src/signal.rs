@@ -164,6 +164,82 @@ pub enum SignalRegisterError {
     AlreadyRegistered,
 }
 
+enum_gen! {
+    /// Describes the failures when a [`RealTimeSignal`] is created with [`RealTimeSignal::new()`].
+    RealTimeSignalCreationError
+  entry:
+    NotSupported,
+    ExceedsMaximumSignal
+}
+
+/// Represents the POSIX real-time signal `SIGRTMIN + offset`. In contrast to the standard
+/// [`Signal`]s, real-time signals are queued. When the same real-time signal is raised multiple
+/// times before it is handled, every instance is delivered, whereas multiple pending instances
+/// of a standard [`Signal`] are merged into one.
+///
+/// ```
+/// use iceoryx2_bb_posix::signal::*;
+///
+/// match RealTimeSignal::new(1) {
+///     Ok(signal) => println!("SIGRTMIN + 1 has the value {}", signal.value()),
+///     Err(RealTimeSignalCreationError::NotSupported) => println!("no real-time signals"),
+///     Err(e) => println!("unable to acquire SIGRTMIN + 1: {:?}", e),
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct RealTimeSignal {
+    value: i32,
+    offset: u32,
+}
+
+impl RealTimeSignal {
+    /// Creates the real-time signal `SIGRTMIN + offset`. Fails when the platform does not
+    /// support real-time signals or when the signal would exceed `SIGRTMAX`.
+    pub fn new(offset: u32) -> Result<Self, RealTimeSignalCreationError> {
+        let origin = "RealTimeSignal::new()";
+        let min = unsafe { posix::sigrtmin() };
+        let max = unsafe { posix::sigrtmax() };
+
+        if min < 0 || max < min {
+            fail!(from origin, with RealTimeSignalCreationError::NotSupported,
+                "Unable to create the real-time signal SIGRTMIN + {} since the platform does not support real-time signals.",
+                offset);
+        }
+
+        match i32::try_from(offset).ok().and_then(|v| min.checked_add(v)) {
+            Some(value) if value <= max => Ok(Self { value, offset }),
+            _ => {
+                fail!(from origin, with RealTimeSignalCreationError::ExceedsMaximumSignal,
+                    "Unable to create the real-time signal SIGRTMIN + {} since it exceeds SIGRTMAX (SIGRTMIN + {}).",
+                    offset, max - min);
+            }
+        }
+    }
+
+    /// Returns the number of real-time signals the platform provides. Returns 0 when real-time
+    /// signals are not supported.
+    pub fn number_of_signals() -> usize {
+        let min = unsafe { posix::sigrtmin() };
+        let max = unsafe { posix::sigrtmax() };
+
+        if min < 0 || max < min {
+            0
+        } else {
+            (max - min) as usize + 1
+        }
+    }
+
+    /// Returns the offset to `SIGRTMIN`
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Returns the value of the signal
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+}
+
 enum_gen! { SignalWaitError
   mapping:
     NanosleepError,
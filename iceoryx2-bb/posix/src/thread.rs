@@ -214,7 +214,8 @@ impl ThreadBuilder {
         self
     }
 
-    /// Inherit the scheduling attributes of the calling thread.
+    /// Inherit the scheduling attributes of the calling thread. When set to `true` the values
+    /// of [`ThreadBuilder::priority()`] and [`ThreadBuilder::scheduler()`] are ignored.
     pub fn inherit_scheduling_attributes(mut self, value: bool) -> Self {
         self.inherit_scheduling_attributes = value;
         self
@@ -249,14 +250,23 @@ impl ThreadBuilder {
     /// to the scheduler dependent priority.
     /// For more details about scheduler priority granularity see:
     /// [`Scheduler::priority_granularity()`]
+    ///
+    /// The thread no longer inherits the scheduling attributes of the calling thread.
     pub fn priority(mut self, value: u8) -> Self {
         self.priority = value;
+        self.inherit_scheduling_attributes = false;
         self
     }
 
-    /// Sets the [`Scheduler`] used by the thread.
+    /// Sets the [`Scheduler`] used by the thread. Real-time schedulers like
+    /// [`Scheduler::Fifo`] or [`Scheduler::RoundRobin`] usually require elevated privileges,
+    /// otherwise the thread creation fails with
+    /// [`ThreadSpawnError::InsufficientPermissions`].
+    ///
+    /// The thread no longer inherits the scheduling attributes of the calling thread.
     pub fn scheduler(mut self, value: Scheduler) -> Self {
         self.scheduler = value;
+        self.inherit_scheduling_attributes = false;
         self
     }
 
@@ -0,0 +1,440 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`Timer`] expires once after a timeout or periodically. On Linux the [`Timer`] is based on
+//! a timerfd, a [`FileDescriptor`] that becomes readable when the [`Timer`] expires and can
+//! therefore be multiplexed together with other [`FileDescriptor`]s, see
+//! [`Timer::file_descriptor()`]. This allows cyclic loops to be driven by the same event loop
+//! that waits for incoming events. On all other platforms, or when the kernel does not support
+//! it, the expirations are computed from the clock.
+//!
+//! Like in [`crate::cyclic_wait::CyclicWait`] the expirations of a periodic [`Timer`] are
+//! multiples of the period starting at the time it was armed, so the [`Timer`] does not drift.
+//! Expirations that were not waited for are accumulated and reported by the next wait call.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::timer::*;
+//! use std::time::Duration;
+//!
+//! let timer = TimerBuilder::new().create().unwrap();
+//! timer.arm_periodic(Duration::from_millis(10)).unwrap();
+//!
+//! for _ in 0..3 {
+//!     let expirations = timer.blocking_wait().unwrap();
+//!     if expirations > 1 {
+//!         println!("missed {} cycles", expirations - 1);
+//!     }
+//! }
+//!
+//! timer.disarm().unwrap();
+//! ```
+
+use std::cell::Cell;
+use std::time::Duration;
+
+use crate::clock::{nanosleep_until, AsTimespec, ClockType, NanosleepError, Time};
+use crate::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use crate::file_descriptor_set::{
+    FileDescriptorSet, FileDescriptorSetWaitError, FileEvent, SynchronousMultiplexing,
+};
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::posix::Struct;
+use iceoryx2_pal_posix::*;
+
+// select does not support arbitrary long timeouts, a blocking wait waits in multiple rounds
+const BLOCKING_WAIT_INTERVAL: Duration = Duration::from_secs(60);
+
+enum_gen! { TimerCreateError
+  entry:
+    ClockTypeIsNotSupported,
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    InsufficientMemory,
+    UnknownError(i32)
+}
+
+enum_gen! { TimerArmError
+  entry:
+    InternalFailure,
+    UnknownError(i32)
+}
+
+enum_gen! { TimerWaitError
+  entry:
+    Interrupt,
+    InsufficientPermissions,
+    InternalFailure,
+    UnknownError(i32)
+}
+
+/// Creates a [`Timer`]. The default value for the clock is defined in [`ClockType::default()`].
+#[derive(Debug)]
+pub struct TimerBuilder {
+    clock_type: ClockType,
+}
+
+impl Default for TimerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimerBuilder {
+    pub fn new() -> TimerBuilder {
+        Self {
+            clock_type: ClockType::default(),
+        }
+    }
+
+    pub fn clock_type(mut self, clock_type: ClockType) -> Self {
+        self.clock_type = clock_type;
+        self
+    }
+
+    /// Creates a new disarmed [`Timer`].
+    pub fn create(self) -> Result<Timer, TimerCreateError> {
+        Timer::new(self)
+    }
+}
+
+// the file descriptor itself is not exposed as SynchronousMultiplexing object since writing
+// to it is meaningless
+struct Expiration<'a>(&'a FileDescriptor);
+
+impl FileDescriptorBased for Expiration<'_> {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.0
+    }
+}
+
+impl SynchronousMultiplexing for Expiration<'_> {}
+
+/// A one-shot or periodic timer, see the [module](crate::timer) documentation for an example.
+#[derive(Debug)]
+pub struct Timer {
+    clock_type: ClockType,
+    period: Cell<Option<Duration>>,
+    // the timerfd that becomes readable when the timer expires, if it is not available the
+    // expirations are computed from the next expiration time
+    timerfd: Option<FileDescriptor>,
+    next_expiration: Cell<Option<Time>>,
+}
+
+impl Timer {
+    fn new(config: TimerBuilder) -> Result<Self, TimerCreateError> {
+        let msg = "Unable to create timer";
+        let raw_fd = unsafe { posix::timerfd_create(config.clock_type as _, posix::TFD_NONBLOCK) };
+
+        if raw_fd != -1 {
+            let timerfd = match FileDescriptor::new(raw_fd) {
+                Some(fd) => fd,
+                None => {
+                    fatal_panic!(from config,
+                        "This should never happen! {} since the acquired file descriptor {} is invalid.", msg, raw_fd);
+                }
+            };
+
+            return Ok(Self {
+                clock_type: config.clock_type,
+                period: Cell::new(None),
+                timerfd: Some(timerfd),
+                next_expiration: Cell::new(None),
+            });
+        }
+
+        match Errno::get() {
+            // unsupported by the platform or the kernel, fall back to the clock
+            Errno::ENOSYS | Errno::ENODEV => {
+                fail!(from config, when Time::now_with_clock(config.clock_type),
+                    with TimerCreateError::ClockTypeIsNotSupported,
+                    "{} since the clock {:?} is not supported.", msg, config.clock_type);
+
+                Ok(Self {
+                    clock_type: config.clock_type,
+                    period: Cell::new(None),
+                    timerfd: None,
+                    next_expiration: Cell::new(None),
+                })
+            }
+            Errno::EINVAL => {
+                fail!(from config, with TimerCreateError::ClockTypeIsNotSupported,
+                    "{} since the clock {:?} is not supported.", msg, config.clock_type);
+            }
+            Errno::EMFILE => {
+                fail!(from config, with TimerCreateError::PerProcessFileHandleLimitReached,
+                    "{} since the processes file descriptor limit was reached.", msg);
+            }
+            Errno::ENFILE => {
+                fail!(from config, with TimerCreateError::SystemWideFileHandleLimitReached,
+                    "{} since the system wide file descriptor limit was reached.", msg);
+            }
+            Errno::ENOMEM => {
+                fail!(from config, with TimerCreateError::InsufficientMemory,
+                    "{} due to insufficient memory.", msg);
+            }
+            v => {
+                fail!(from config, with TimerCreateError::UnknownError(v as i32),
+                    "{} since an unknown error occurred ({}).", msg, v);
+            }
+        }
+    }
+
+    /// Returns the [`ClockType`] of the [`Timer`].
+    pub fn clock_type(&self) -> ClockType {
+        self.clock_type
+    }
+
+    /// Returns the period when the [`Timer`] was armed with [`Timer::arm_periodic()`],
+    /// otherwise [`None`].
+    pub fn period(&self) -> Option<Duration> {
+        self.period.get()
+    }
+
+    /// Returns true when the expirations are signaled by the operating system, otherwise they
+    /// are computed from the clock.
+    pub fn is_event_driven(&self) -> bool {
+        self.timerfd.is_some()
+    }
+
+    /// Returns the [`FileDescriptor`] that becomes readable when the [`Timer`] expires so that
+    /// it can be attached to an event loop. It stays readable until the expirations are
+    /// acquired with one of the wait calls. Returns [`None`] when the [`Timer`] is not event
+    /// driven.
+    pub fn file_descriptor(&self) -> Option<&FileDescriptor> {
+        self.timerfd.as_ref()
+    }
+
+    /// Arms the [`Timer`] so that it expires once after the provided timeout. A timeout of
+    /// zero is increased to one nanosecond. Expirations of a previous arming that were not
+    /// acquired yet are discarded.
+    pub fn arm_one_shot(&self, timeout: Duration) -> Result<(), TimerArmError> {
+        self.arm(timeout.max(Duration::from_nanos(1)), None)
+    }
+
+    /// Arms the [`Timer`] so that it expires every period, the first time one period after
+    /// the call. A period of zero is increased to one nanosecond. Expirations of a previous
+    /// arming that were not acquired yet are discarded.
+    pub fn arm_periodic(&self, period: Duration) -> Result<(), TimerArmError> {
+        let period = period.max(Duration::from_nanos(1));
+        self.arm(period, Some(period))
+    }
+
+    /// Disarms the [`Timer`] so that it does not expire anymore. Expirations that were not
+    /// acquired yet are discarded.
+    pub fn disarm(&self) -> Result<(), TimerArmError> {
+        self.arm(Duration::ZERO, None)
+    }
+
+    /// Returns the number of expirations since the last call without blocking, zero when the
+    /// [`Timer`] has not expired.
+    pub fn try_wait(&self) -> Result<u64, TimerWaitError> {
+        match &self.timerfd {
+            Some(fd) => self.read_expirations(fd),
+            None => self.compute_expirations(),
+        }
+    }
+
+    /// Waits until the [`Timer`] expires or the timeout has passed. Returns the number of
+    /// expirations since the last call, zero when the timeout has passed.
+    pub fn timed_wait(&self, timeout: Duration) -> Result<u64, TimerWaitError> {
+        match &self.timerfd {
+            Some(fd) => self.wait_for_expiration(fd, timeout),
+            None => self.sleep_until_expiration(timeout),
+        }
+    }
+
+    /// Blocks until the [`Timer`] expires and returns the number of expirations since the last
+    /// call. Blocks forever when the [`Timer`] is disarmed.
+    pub fn blocking_wait(&self) -> Result<u64, TimerWaitError> {
+        loop {
+            match self.timed_wait(BLOCKING_WAIT_INTERVAL)? {
+                0 => (),
+                n => return Ok(n),
+            }
+        }
+    }
+
+    // a zero timeout disarms the timer
+    fn arm(&self, timeout: Duration, period: Option<Duration>) -> Result<(), TimerArmError> {
+        let msg = "Unable to arm timer";
+        match &self.timerfd {
+            Some(fd) => {
+                let mut value = posix::itimerspec::new();
+                value.it_value = timeout.as_timespec();
+                value.it_interval = period.unwrap_or(Duration::ZERO).as_timespec();
+
+                if unsafe {
+                    posix::timerfd_settime(fd.native_handle(), 0, &value, core::ptr::null_mut())
+                } == -1
+                {
+                    let v = Errno::get();
+                    fail!(from self, with TimerArmError::UnknownError(v as i32),
+                        "{} since an unknown error occurred ({}).", msg, v);
+                }
+            }
+            None => {
+                let next_expiration = if timeout.is_zero() {
+                    None
+                } else {
+                    let now = fail!(from self, when Time::now_with_clock(self.clock_type),
+                        with TimerArmError::InternalFailure,
+                        "{} since the current time could not be acquired.", msg);
+                    Some(now.add(timeout))
+                };
+                self.next_expiration.set(next_expiration);
+            }
+        }
+
+        self.period.set(period);
+        Ok(())
+    }
+
+    fn read_expirations(&self, fd: &FileDescriptor) -> Result<u64, TimerWaitError> {
+        let msg = "Unable to acquire the expirations of the timer";
+        let mut expirations: u64 = 0;
+        let number_of_bytes = unsafe {
+            posix::read(
+                fd.native_handle(),
+                (&mut expirations as *mut u64).cast(),
+                core::mem::size_of::<u64>(),
+            )
+        };
+
+        if number_of_bytes == core::mem::size_of::<u64>() as _ {
+            return Ok(expirations);
+        }
+
+        match Errno::get() {
+            Errno::EAGAIN => Ok(0),
+            Errno::EINTR => {
+                fail!(from self, with TimerWaitError::Interrupt,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            v => {
+                fail!(from self, with TimerWaitError::UnknownError(v as i32),
+                    "{} since an unknown error occurred ({}).", msg, v);
+            }
+        }
+    }
+
+    fn wait_for_expiration(
+        &self,
+        fd: &FileDescriptor,
+        timeout: Duration,
+    ) -> Result<u64, TimerWaitError> {
+        let expirations = self.read_expirations(fd)?;
+        if expirations > 0 || timeout.is_zero() {
+            return Ok(expirations);
+        }
+
+        let msg = "Unable to wait for the timer expiration";
+        let fd_set = FileDescriptorSet::new();
+        let expiration = Expiration(fd);
+        let _guard = fatal_panic!(from self, when fd_set.add(&expiration),
+                        "This should never happen! {} since a single file descriptor exceeds the capacity of the file descriptor set.", msg);
+
+        match fd_set.timed_wait(timeout, FileEvent::Read, |_| ()) {
+            Ok(()) => self.read_expirations(fd),
+            Err(FileDescriptorSetWaitError::Interrupt) => {
+                fail!(from self, with TimerWaitError::Interrupt,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(FileDescriptorSetWaitError::InsufficientPermissions) => {
+                fail!(from self, with TimerWaitError::InsufficientPermissions,
+                    "{} due to insufficient permissions.", msg);
+            }
+            Err(FileDescriptorSetWaitError::TooManyAttachedFileDescriptors) => {
+                fail!(from self, with TimerWaitError::InternalFailure,
+                    "{} since the file descriptor could not be waited on.", msg);
+            }
+            Err(FileDescriptorSetWaitError::UnknownError(v)) => {
+                fail!(from self, with TimerWaitError::UnknownError(v),
+                    "{} since an unknown error occurred ({}).", msg, v);
+            }
+        }
+    }
+
+    fn now(&self, msg: &str) -> Result<Time, TimerWaitError> {
+        Ok(fail!(from self, when Time::now_with_clock(self.clock_type),
+            with TimerWaitError::InternalFailure,
+            "{} since the current time could not be acquired.", msg))
+    }
+
+    fn compute_expirations(&self) -> Result<u64, TimerWaitError> {
+        let next_expiration = match self.next_expiration.get() {
+            Some(next_expiration) => next_expiration,
+            None => return Ok(0),
+        };
+
+        let now = self
+            .now("Unable to acquire the expirations of the timer")?
+            .as_duration();
+        if now < next_expiration.as_duration() {
+            return Ok(0);
+        }
+
+        match self.period.get() {
+            None => {
+                self.next_expiration.set(None);
+                Ok(1)
+            }
+            Some(period) => {
+                let delay = (now - next_expiration.as_duration()).as_nanos();
+                let expirations = (delay / period.as_nanos()) as u64 + 1;
+                self.next_expiration
+                    .set(Some(next_expiration.add(period.saturating_mul(
+                        u32::try_from(expirations).unwrap_or(u32::MAX),
+                    ))));
+                Ok(expirations)
+            }
+        }
+    }
+
+    fn sleep_until_expiration(&self, timeout: Duration) -> Result<u64, TimerWaitError> {
+        let msg = "Unable to wait for the timer expiration";
+        let deadline = self.now(msg)?.add(timeout);
+
+        loop {
+            let expirations = self.compute_expirations()?;
+            if expirations > 0 {
+                return Ok(expirations);
+            }
+
+            if self.now(msg)?.as_duration() >= deadline.as_duration() {
+                return Ok(0);
+            }
+
+            let wake_up_time = match self.next_expiration.get() {
+                Some(next_expiration) if next_expiration.as_duration() < deadline.as_duration() => {
+                    next_expiration
+                }
+                _ => deadline,
+            };
+
+            match nanosleep_until(&wake_up_time) {
+                Ok(()) => (),
+                Err(NanosleepError::InterruptedBySignal(_)) => {
+                    fail!(from self, with TimerWaitError::Interrupt,
+                        "{} since an interrupt signal was received.", msg);
+                }
+                Err(e) => {
+                    fail!(from self, with TimerWaitError::InternalFailure,
+                        "{} since the underlying sleep failed ({:?}).", msg, e);
+                }
+            }
+        }
+    }
+}
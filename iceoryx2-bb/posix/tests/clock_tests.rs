@@ -33,6 +33,23 @@ fn clock_nanosleep_with_clock_sleeps_at_least_given_amount_of_time() {
     assert_that!(start.elapsed(), time_at_least TIMEOUT);
 }
 
+#[test]
+fn clock_nanosleep_until_sleeps_until_given_time() {
+    let start = Instant::now();
+    let wake_up_time = Time::now().unwrap().add(TIMEOUT);
+    assert_that!(nanosleep_until(&wake_up_time), is_ok);
+    assert_that!(start.elapsed(), time_at_least TIMEOUT);
+    assert_that!(Time::now().unwrap().as_duration(), ge wake_up_time.as_duration());
+}
+
+#[test]
+fn clock_nanosleep_until_time_in_the_past_returns_immediately() {
+    let start = Instant::now();
+    let time = TimeBuilder::new().seconds(1).create();
+    assert_that!(nanosleep_until(&time), is_ok);
+    assert_that!(start.elapsed(), lt TIMEOUT);
+}
+
 #[test]
 fn clock_timebuilder_default_values_are_set_correctly() {
     let time = TimeBuilder::new().create();
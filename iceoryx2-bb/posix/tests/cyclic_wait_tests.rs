@@ -0,0 +1,65 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_posix::clock::*;
+use iceoryx2_bb_posix::cyclic_wait::*;
+use iceoryx2_bb_testing::assert_that;
+use std::time::Duration;
+use std::time::Instant;
+
+const PERIOD: Duration = Duration::from_millis(20);
+
+#[test]
+fn cyclic_wait_wakes_up_at_multiples_of_the_period() {
+    let start = Instant::now();
+    let mut sut = CyclicWaitBuilder::new(PERIOD).create().unwrap();
+
+    for _ in 0..3 {
+        // the work inside the loop does not shift the wake up times
+        assert_that!(nanosleep(PERIOD / 2), is_ok);
+        assert_that!(sut.wait(), eq Ok(0));
+    }
+
+    let elapsed = start.elapsed();
+    assert_that!(elapsed, time_at_least PERIOD * 3);
+    assert_that!(sut.number_of_cycles(), eq 3);
+    assert_that!(sut.number_of_missed_cycles(), eq 0);
+}
+
+#[test]
+fn cyclic_wait_reports_and_skips_missed_cycles() {
+    let mut sut = CyclicWaitBuilder::new(PERIOD).create().unwrap();
+
+    assert_that!(nanosleep(PERIOD * 3 + PERIOD / 2), is_ok);
+    let missed_cycles = sut.wait().unwrap();
+    assert_that!(missed_cycles, ge 2);
+
+    let start = Instant::now();
+    assert_that!(sut.wait(), eq Ok(0));
+    assert_that!(start.elapsed(), lt PERIOD * 2);
+    assert_that!(sut.number_of_missed_cycles(), eq missed_cycles);
+}
+
+#[test]
+fn cyclic_wait_next_wake_up_time_advances_by_period() {
+    let mut sut = CyclicWaitBuilder::new(PERIOD)
+        .clock_type(ClockType::Realtime)
+        .create()
+        .unwrap();
+
+    let first = sut.next_wake_up_time();
+    assert_that!(sut.wait(), is_ok);
+    let second = sut.next_wake_up_time();
+
+    assert_that!(sut.clock_type(), eq ClockType::Realtime);
+    assert_that!(second.as_duration() - first.as_duration(), eq PERIOD);
+}
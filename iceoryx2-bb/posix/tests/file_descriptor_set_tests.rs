@@ -146,6 +146,45 @@ fn file_descriptor_set_timed_wait_works() {
     assert_that!(result[0], eq unsafe{sut_receiver.file_descriptor().native_handle()});
 }
 
+#[test]
+fn file_descriptor_set_blocking_wait_works() {
+    test_requires!(POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS);
+
+    let socket_name = generate_socket_name();
+
+    let sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+
+    let sut_sender = UnixDatagramSenderBuilder::new(&socket_name)
+        .create()
+        .unwrap();
+
+    let fd_set = FileDescriptorSet::new();
+    let _guard = fd_set.add(&sut_receiver).unwrap();
+
+    std::thread::scope(|s| {
+        s.spawn(move || {
+            std::thread::sleep(TIMEOUT);
+            let send_data: Vec<u8> = vec![4u8, 2u8];
+            sut_sender.blocking_send(send_data.as_slice()).unwrap();
+        });
+
+        let start = Instant::now();
+        let mut result = vec![];
+        fd_set
+            .blocking_wait(FileEvent::Read, |fd| {
+                result.push(unsafe { fd.native_handle() })
+            })
+            .unwrap();
+
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(result, len 1);
+        assert_that!(result[0], eq unsafe{sut_receiver.file_descriptor().native_handle()});
+    });
+}
+
 #[test]
 fn file_descriptor_guard_has_access_to_underlying_fd() {
     test_requires!(POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS);
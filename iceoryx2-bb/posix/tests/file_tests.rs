@@ -263,6 +263,33 @@ fn file_remove_returns_false_when_file_not_exists() -> Result<(), FileError> {
     Ok(())
 }
 
+#[test]
+fn file_rename_replaces_target_atomically() -> Result<(), FileError> {
+    let test = TestFixture::new();
+    let source = generate_file_name();
+    let mut source_file = test.create_file(&source);
+    source_file.write(b"new content")?;
+    let mut target_file = test.create_file(&test.file);
+    target_file.write(b"old content")?;
+
+    File::rename(&source, &test.file)?;
+
+    assert_that!(File::does_exist(&source)?, eq false);
+    let mut content = String::new();
+    test.open_file(&test.file).read_to_string(&mut content)?;
+    assert_that!(content, eq "new content");
+    Ok(())
+}
+
+#[test]
+fn file_rename_of_non_existing_file_fails() {
+    let test = TestFixture::new();
+    let source = generate_file_name();
+    test.create_file(&test.file);
+
+    assert_that!(File::rename(&source, &test.file), eq Err(FileRenameError::DoesNotExist));
+}
+
 #[test]
 fn file_newly_created_file_is_removed_when_it_has_ownership() -> Result<(), FileError> {
     let file_name = generate_file_name();
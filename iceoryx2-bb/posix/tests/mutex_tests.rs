@@ -430,3 +430,66 @@ fn mutex_in_unrecoverable_state_if_state_of_leaked_mutex_is_not_repaired() {
         );
     }
 }
+
+#[test]
+fn mutex_robust_interprocess_priority_inheritance_restores_consistency_when_owner_died() {
+    let _watchdog = Watchdog::new();
+    let handle = MutexHandle::<i32>::new();
+    let sut = MutexBuilder::new()
+        .robust_interprocess_priority_inheritance()
+        .create(111, &handle)
+        .unwrap();
+
+    let guard = sut.try_lock_with_recovery(|_| {
+        assert_that!(true, eq false);
+        MutexRecovery::Unrecoverable
+    });
+    assert_that!(guard, is_ok);
+    drop(guard);
+
+    thread::scope(|s| {
+        s.spawn(|| {
+            let mut guard = sut.lock().unwrap();
+            *guard = 222;
+            std::mem::forget(guard);
+        });
+    });
+
+    let mut recovered_value = 0;
+    let guard = sut.try_lock_with_recovery(|value| {
+        recovered_value = *value;
+        *value = 333;
+        MutexRecovery::Consistent
+    });
+    assert_that!(recovered_value, eq 222);
+    assert_that!(**guard.as_ref().unwrap().as_ref().unwrap(), eq 333);
+    drop(guard);
+
+    let guard = sut.timed_lock(TIMEOUT);
+    assert_that!(guard, is_ok);
+    assert_that!(guard.as_ref().unwrap(), is_some);
+}
+
+#[test]
+#[cfg(not(target_os = "macos"))]
+fn mutex_robust_interprocess_priority_inheritance_becomes_unrecoverable_when_not_repaired() {
+    let _watchdog = Watchdog::new();
+    let handle = MutexHandle::<i32>::new();
+    let sut = MutexBuilder::new()
+        .robust_interprocess_priority_inheritance()
+        .create(111, &handle)
+        .unwrap();
+
+    thread::scope(|s| {
+        s.spawn(|| {
+            let guard = sut.lock();
+            std::mem::forget(guard);
+        });
+    });
+
+    let guard = sut.lock_with_recovery(|_| MutexRecovery::Unrecoverable);
+    assert_that!(guard.err().unwrap(), eq MutexLockError::UnrecoverableState);
+
+    let guard = sut.try_lock();
+    assert_that!(guard.err().unwrap(), eq MutexLockError::UnrecoverableState);
+}
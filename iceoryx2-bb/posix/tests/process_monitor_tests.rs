@@ -0,0 +1,52 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::time::Duration;
+
+use iceoryx2_bb_posix::process::*;
+use iceoryx2_bb_posix::process_monitor::*;
+use iceoryx2_bb_testing::assert_that;
+
+const TIMEOUT: Duration = Duration::from_millis(25);
+
+#[test]
+pub fn process_monitor_detects_that_own_process_is_alive() {
+    let sut = ProcessMonitor::new(Process::from_self().id()).unwrap();
+
+    assert_that!(sut.pid(), eq Process::from_self().id());
+    assert_that!(sut.is_event_driven(), eq sut.file_descriptor().is_some());
+    assert_that!(sut.has_terminated(), eq Ok(false));
+    assert_that!(sut.timed_wait(TIMEOUT), eq Ok(false));
+}
+
+#[test]
+pub fn process_monitor_cannot_be_created_for_non_existing_process() {
+    let sut = ProcessMonitor::new(ProcessId::new(i32::MAX as _));
+
+    assert_that!(sut.err(), eq Some(ProcessMonitorCreateError::ProcessDoesNotExist));
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "freebsd", target_os = "macos"))]
+pub fn process_monitor_detects_terminated_process() {
+    let mut process = std::process::Command::new("sleep")
+        .arg("0.1")
+        .spawn()
+        .unwrap();
+    let sut = ProcessMonitor::new(ProcessId::new(process.id() as _)).unwrap();
+
+    assert_that!(sut.has_terminated(), eq Ok(false));
+
+    assert_that!(process.wait(), is_ok);
+    assert_that!(sut.timed_wait(Duration::from_secs(5)), eq Ok(true));
+    assert_that!(sut.blocking_wait(), is_ok);
+}
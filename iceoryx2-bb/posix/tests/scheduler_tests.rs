@@ -18,3 +18,9 @@ use iceoryx2_bb_testing::assert_that;
 fn scheduler_default_scheduler_set_correctly() {
     assert_that!(Scheduler::default(), eq DEFAULT_SCHEDULER)
 }
+
+#[test]
+fn scheduler_real_time_schedulers_have_a_priority_range() {
+    assert_that!(Scheduler::Fifo.priority_granularity(), gt 0);
+    assert_that!(Scheduler::RoundRobin.priority_granularity(), gt 0);
+}
@@ -0,0 +1,91 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_container::semantic_string::*;
+use iceoryx2_bb_elementary::math::ToB64;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
+use iceoryx2_bb_posix::sealed_shared_memory::*;
+use iceoryx2_bb_posix::shared_memory::{CreationMode, Permission, SharedMemoryBuilder};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_bb_testing::{assert_that, test_requires};
+use iceoryx2_pal_posix::posix::POSIX_SUPPORT_SEALED_SHARED_MEMORY;
+
+fn generate_name() -> FileName {
+    let mut file_name = FileName::new(b"sealed_shared_memory_tests_").unwrap();
+    file_name
+        .push_bytes(UniqueSystemId::new().unwrap().value().to_b64().as_bytes())
+        .unwrap();
+    file_name
+}
+
+#[test]
+fn sealed_shared_memory_create_and_open_works() {
+    test_requires!(POSIX_SUPPORT_SEALED_SHARED_MEMORY);
+
+    let name = generate_name();
+    let mut sut_create = SealedSharedMemoryBuilder::new(&name)
+        .size(1024)
+        .create()
+        .unwrap();
+    assert_that!(sut_create.size(), eq 1024);
+    assert_that!(sut_create.as_slice().iter().all(|v| *v == 0), eq true);
+
+    let sut_open = SealedSharedMemoryBuilder::new(&name)
+        .open_existing(sut_create.handle(), AccessMode::Read)
+        .unwrap();
+    assert_that!(sut_open.size(), eq 1024);
+
+    sut_create.as_mut_slice()[17] = 173;
+    assert_that!(sut_open.as_slice()[17], eq 173);
+}
+
+#[test]
+fn sealed_shared_memory_cannot_be_resized() {
+    test_requires!(POSIX_SUPPORT_SEALED_SHARED_MEMORY);
+
+    let name = generate_name();
+    let sut = SealedSharedMemoryBuilder::new(&name)
+        .size(4096)
+        .create()
+        .unwrap();
+
+    let mut sut_open = SealedSharedMemoryBuilder::new(&name)
+        .open_existing(sut.handle(), AccessMode::ReadWrite)
+        .unwrap();
+
+    assert_that!(sut_open.truncate(0), is_err);
+    assert_that!(sut_open.truncate(8192), is_err);
+    assert_that!(sut_open.metadata().unwrap().size(), eq 4096);
+}
+
+#[test]
+fn sealed_shared_memory_open_fails_when_memory_is_not_sealed() {
+    test_requires!(POSIX_SUPPORT_SEALED_SHARED_MEMORY);
+
+    let name = generate_name();
+    let shm = SharedMemoryBuilder::new(&name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .permission(Permission::OWNER_ALL)
+        .create()
+        .unwrap();
+
+    let handle = FilePath::new(format!("/dev/shm/{}", shm.name()).as_bytes()).unwrap();
+    let sut = SealedSharedMemoryBuilder::new(&name).open_existing(&handle, AccessMode::Read);
+    assert_that!(sut.err(), eq Some(SealedSharedMemoryCreationError::MissingSeals));
+
+    let handle = FilePath::new(b"/proc/self/fd/does_not_exist").unwrap();
+    let sut = SealedSharedMemoryBuilder::new(&name).open_existing(&handle, AccessMode::Read);
+    assert_that!(sut.err(), eq Some(SealedSharedMemoryCreationError::DoesNotExist));
+}
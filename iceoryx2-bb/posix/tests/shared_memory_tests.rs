@@ -12,7 +12,9 @@
 
 use iceoryx2_bb_container::semantic_string::*;
 use iceoryx2_bb_elementary::math::ToB64;
-use iceoryx2_bb_posix::{shared_memory::*, unique_system_id::UniqueSystemId};
+use iceoryx2_bb_posix::{
+    shared_memory::*, system_configuration::SystemInfo, unique_system_id::UniqueSystemId,
+};
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_testing::{assert_that, test_requires};
 use iceoryx2_pal_posix::posix::POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY;
@@ -214,6 +216,43 @@ fn shared_memory_create_without_ownership_works() {
     );
 }
 
+#[test]
+fn shared_memory_without_huge_pages_has_default_page_size() {
+    let shm_name = generate_shm_name();
+    let sut = SharedMemoryBuilder::new(&shm_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .create()
+        .unwrap();
+
+    assert_that!(sut.page_size(), eq SystemInfo::PageSize.value());
+}
+
+#[test]
+fn shared_memory_with_huge_pages_falls_back_to_available_page_size() {
+    const HUGE_PAGE_SIZE: usize = 2 * 1024 * 1024;
+    let shm_name = generate_shm_name();
+    let mut sut_create = SharedMemoryBuilder::new(&shm_name)
+        .huge_page_size(HUGE_PAGE_SIZE)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(HUGE_PAGE_SIZE)
+        .permission(Permission::OWNER_ALL)
+        .create()
+        .unwrap();
+
+    let sut_open = SharedMemoryBuilder::new(&shm_name)
+        .huge_page_size(HUGE_PAGE_SIZE)
+        .open_existing(AccessMode::Read)
+        .unwrap();
+
+    assert_that!(sut_create.page_size(), ge SystemInfo::PageSize.value());
+    assert_that!(sut_create.page_size(), le HUGE_PAGE_SIZE);
+    assert_that!(sut_open.page_size(), eq sut_create.page_size());
+
+    sut_create.as_mut_slice()[HUGE_PAGE_SIZE - 1] = 42;
+    assert_that!(sut_open.as_slice()[HUGE_PAGE_SIZE - 1], eq 42);
+}
+
 #[test]
 fn shared_memory_acquire_ownership_works() {
     test_requires!(POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY);
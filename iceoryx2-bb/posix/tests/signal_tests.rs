@@ -15,7 +15,9 @@ use iceoryx2_bb_posix::process::*;
 use iceoryx2_bb_posix::signal::*;
 use iceoryx2_bb_testing::assert_that;
 use iceoryx2_bb_testing::test_requires;
-use iceoryx2_pal_posix::posix::POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING;
+use iceoryx2_pal_posix::posix::{
+    Struct, POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING, POSIX_SUPPORT_REAL_TIME_SIGNALS,
+};
 use iceoryx2_pal_posix::*;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::{AtomicI32, Ordering};
@@ -300,3 +302,54 @@ fn signal_termination_requested_with_interrupt_works() {
     );
     assert_that!(SignalHandler::termination_requested(), eq false);
 }
+
+#[test]
+fn real_time_signal_can_be_created_for_all_available_signals() {
+    let number_of_signals = RealTimeSignal::number_of_signals();
+
+    if number_of_signals == 0 {
+        assert_that!(RealTimeSignal::new(0).err(), eq Some(RealTimeSignalCreationError::NotSupported));
+        return;
+    }
+
+    let first = RealTimeSignal::new(0).unwrap();
+    let last = RealTimeSignal::new(number_of_signals as u32 - 1).unwrap();
+    assert_that!(first.offset(), eq 0);
+    assert_that!(last.value() - first.value(), eq number_of_signals as i32 - 1);
+    assert_that!(
+        RealTimeSignal::new(number_of_signals as u32).err(),
+        eq Some(RealTimeSignalCreationError::ExceedsMaximumSignal)
+    );
+}
+
+static REAL_TIME_SIGNAL_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+extern "C" fn count_real_time_signal(_: posix::int) {
+    REAL_TIME_SIGNAL_COUNTER.fetch_add(1, Ordering::SeqCst);
+}
+
+#[test]
+fn sending_real_time_signal_delivers_every_signal() {
+    test_requires!(POSIX_SUPPORT_REAL_TIME_SIGNALS);
+    const NUMBER_OF_SIGNALS: usize = 5;
+
+    let _test = TestFixture::new();
+    let signal = RealTimeSignal::new(1).unwrap();
+
+    let mut action = posix::sigaction_t::new();
+    action.iox2_sa_handler = count_real_time_signal as posix::sighandler_t;
+    let mut previous_action = posix::sigaction_t::new();
+    assert_that!(unsafe { posix::sigaction(signal.value(), &action, &mut previous_action) }, eq 0);
+
+    REAL_TIME_SIGNAL_COUNTER.store(0, Ordering::SeqCst);
+    for _ in 0..NUMBER_OF_SIGNALS {
+        assert_that!(Process::from_self().send_real_time_signal(signal), is_ok);
+    }
+
+    assert_that!(
+        || { REAL_TIME_SIGNAL_COUNTER.load(Ordering::SeqCst) },
+        block_until NUMBER_OF_SIGNALS
+    );
+
+    unsafe { posix::sigaction(signal.value(), &previous_action, core::ptr::null_mut()) };
+}
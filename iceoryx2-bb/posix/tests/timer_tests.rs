@@ -0,0 +1,57 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::time::{Duration, Instant};
+
+use iceoryx2_bb_posix::timer::*;
+use iceoryx2_bb_testing::assert_that;
+
+const TIMEOUT: Duration = Duration::from_millis(25);
+
+#[test]
+pub fn timer_does_not_expire_when_disarmed() {
+    let sut = TimerBuilder::new().create().unwrap();
+
+    assert_that!(sut.is_event_driven(), eq sut.file_descriptor().is_some());
+    assert_that!(sut.period(), eq None);
+    assert_that!(sut.try_wait(), eq Ok(0));
+    assert_that!(sut.timed_wait(TIMEOUT), eq Ok(0));
+}
+
+#[test]
+pub fn timer_one_shot_expires_once_after_timeout() {
+    let sut = TimerBuilder::new().create().unwrap();
+
+    let start = Instant::now();
+    assert_that!(sut.arm_one_shot(TIMEOUT), is_ok);
+    assert_that!(sut.try_wait(), eq Ok(0));
+    assert_that!(sut.blocking_wait(), eq Ok(1));
+    assert_that!(start.elapsed(), ge TIMEOUT);
+
+    assert_that!(sut.timed_wait(TIMEOUT), eq Ok(0));
+}
+
+#[test]
+pub fn timer_periodic_accumulates_missed_expirations() {
+    let sut = TimerBuilder::new().create().unwrap();
+
+    assert_that!(sut.arm_periodic(TIMEOUT), is_ok);
+    assert_that!(sut.period(), eq Some(TIMEOUT));
+    assert_that!(sut.timed_wait(TIMEOUT * 10).unwrap(), ge 1);
+
+    std::thread::sleep(TIMEOUT * 3);
+    assert_that!(sut.try_wait().unwrap(), ge 3);
+
+    assert_that!(sut.disarm(), is_ok);
+    assert_that!(sut.period(), eq None);
+    assert_that!(sut.timed_wait(TIMEOUT * 2), eq Ok(0));
+}
@@ -13,7 +13,7 @@
 //! [CommunicationChannel] based on [`MessageQueueSender`] & [`MessageQueueReceiver`]. Can send and
 //! receive data without restrictions.
 
-use std::{fmt::Debug, marker::PhantomData};
+use std::{fmt::Debug, marker::PhantomData, time::Duration};
 
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_posix::directory::*;
@@ -461,6 +461,19 @@ impl<T: Debug> Receiver<T> {
     }
 }
 
+impl<T: Copy + Debug> Receiver<T> {
+    pub(crate) fn timed_receive(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<T>, MessageQueueTimedReceiveError> {
+        Ok(self.receiver().timed_receive(timeout)?.map(|v| v.value))
+    }
+
+    pub(crate) fn blocking_receive(&self) -> Result<T, MessageQueueReceiveError> {
+        Ok(self.receiver().blocking_receive()?.value)
+    }
+}
+
 impl<T: Copy + Debug> CommunicationChannelParticipant for Receiver<T> {
     fn does_enable_safe_overflow(&self) -> bool {
         unsafe { (*self.shared_config_ptr).has_safe_overflow }
@@ -58,6 +58,7 @@ use std::{fmt::Debug, time::Duration};
 
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::permission::Permission;
 use iceoryx2_bb_system_types::file_name::*;
 use tiny_fn::tiny_fn;
 
@@ -76,6 +77,7 @@ impl<T> Debug for Initializer<'_, T> {
 pub(crate) mod dynamic_storage_configuration;
 pub mod posix_shared_memory;
 pub mod process_local;
+pub mod sealed_shared_memory;
 
 /// Describes failures when creating a new [`DynamicStorage`]
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
@@ -119,6 +121,22 @@ pub trait DynamicStorageBuilder<'builder, T: Send + Sync, D: DynamicStorage<T>>:
     /// By default it is set to [`Duration::ZERO`] for no timeout.
     fn timeout(self, value: Duration) -> Self;
 
+    /// Defines if an opened [`DynamicStorage`] is mapped read-only so that it cannot be
+    /// modified by the opener. It is ignored when the implementation does not support it, see
+    /// [`DynamicStorage::does_support_read_only_mapping()`]. By default it is false.
+    fn read_only(self, value: bool) -> Self;
+
+    /// Requests that the [`DynamicStorage`] is backed by huge pages of the provided size. It
+    /// falls back to the default page size when huge pages are not available or not supported
+    /// by the implementation, see [`DynamicStorage::page_size()`]. By default huge pages are
+    /// disabled.
+    fn huge_page_size(self, value: usize) -> Self;
+
+    /// Defines the [`Permission`] of a newly created [`DynamicStorage`] after it is initialized.
+    /// It is ignored by implementations that are not backed by a file system resource. By
+    /// default only the owner has access.
+    fn permission(self, value: Permission) -> Self;
+
     /// Before the construction is finalized the initializer is called
     /// with a mutable reference to the new value and a mutable reference to a bump allocator
     /// which provides access to the supplementary memory. If the initialization failed it
@@ -149,6 +167,13 @@ pub trait DynamicStorage<T: Send + Sync>: Sized + Debug + NamedConceptMgmt + Nam
     /// resource remain even when every [`DynamicStorage`] instance in every process was removed.
     fn does_support_persistency() -> bool;
 
+    /// Returns true if the [`DynamicStorage`] can be opened read-only with
+    /// [`DynamicStorageBuilder::read_only()`] so that write accesses of the opener fault.
+    fn does_support_read_only_mapping() -> bool;
+
+    /// Returns the page size of the memory that backs the [`DynamicStorage`].
+    fn page_size(&self) -> usize;
+
     /// Returns true if the storage holds the ownership, otherwise false.
     fn has_ownership(&self) -> bool;
 
@@ -59,7 +59,7 @@ use std::sync::atomic::Ordering;
 
 use self::dynamic_storage_configuration::DynamicStorageConfiguration;
 
-const FINAL_PERMISSIONS: Permission = Permission::OWNER_ALL;
+const DEFAULT_PERMISSIONS: Permission = Permission::OWNER_ALL;
 
 /// The builder of [`Storage`].
 #[derive(Debug)]
@@ -69,6 +69,9 @@ pub struct Builder<'builder, T: Send + Sync + Debug> {
     has_ownership: bool,
     config: Configuration<T>,
     timeout: Duration,
+    read_only: bool,
+    huge_page_size: usize,
+    permission: Permission,
     initializer: Initializer<'builder, T>,
     _phantom_data: PhantomData<T>,
 }
@@ -156,6 +159,9 @@ impl<'builder, T: Send + Sync + Debug> NamedConceptBuilder<Storage<T>> for Build
             supplementary_size: 0,
             config: Configuration::default(),
             timeout: Duration::ZERO,
+            read_only: false,
+            huge_page_size: 0,
+            permission: DEFAULT_PERMISSIONS,
             initializer: Initializer::new(|_, _| true),
             _phantom_data: PhantomData,
         }
@@ -176,9 +182,18 @@ impl<'builder, T: Send + Sync + Debug> Builder<'builder, T> {
                                     with DynamicStorageOpenError::InternalError,
                                     "{} since the AdaptiveWait could not be initialized.", msg);
 
+        let access_mode = if self.read_only {
+            AccessMode::Read
+        } else {
+            AccessMode::ReadWrite
+        };
+
         let mut elapsed_time = Duration::ZERO;
         let shm = loop {
-            match SharedMemoryBuilder::new(&full_name).open_existing(AccessMode::ReadWrite) {
+            match SharedMemoryBuilder::new(&full_name)
+                .huge_page_size(self.huge_page_size)
+                .open_existing(access_mode)
+            {
                 Ok(v) => break v,
                 Err(SharedMemoryCreationError::DoesNotExist) => {
                     fail!(from self, with DynamicStorageOpenError::DoesNotExist,
@@ -241,6 +256,7 @@ impl<'builder, T: Send + Sync + Debug> Builder<'builder, T> {
 
         let full_name = self.config.path_for(&self.storage_name).file_name();
         let shm = match SharedMemoryBuilder::new(&full_name)
+            .huge_page_size(self.huge_page_size)
             .creation_mode(CreationMode::CreateExclusive)
             // posix shared memory is always aligned to the greatest possible value (PAGE_SIZE)
             // therefore we do not have to add additional alignment space for T
@@ -307,7 +323,7 @@ impl<'builder, T: Send + Sync + Debug> Builder<'builder, T> {
         //////////////////////////////////////////
         unsafe { (*version_ptr).store(PackageVersion::get().to_u64(), Ordering::SeqCst) };
 
-        if let Err(e) = shm.set_permission(FINAL_PERMISSIONS) {
+        if let Err(e) = shm.set_permission(self.permission) {
             fail!(from origin, with DynamicStorageCreateError::InternalError,
                 "{} since the final permissions could not be applied to the underlying shared memory ({:?}).",
                 msg, e);
@@ -342,6 +358,21 @@ impl<'builder, T: Send + Sync + Debug> DynamicStorageBuilder<'builder, T, Storag
         self
     }
 
+    fn read_only(mut self, value: bool) -> Self {
+        self.read_only = value;
+        self
+    }
+
+    fn huge_page_size(mut self, value: usize) -> Self {
+        self.huge_page_size = value;
+        self
+    }
+
+    fn permission(mut self, value: Permission) -> Self {
+        self.permission = value;
+        self
+    }
+
     fn supplementary_size(mut self, value: usize) -> Self {
         self.supplementary_size = value;
         self
@@ -479,6 +510,14 @@ impl<T: Send + Sync + Debug> DynamicStorage<T> for Storage<T> {
         SharedMemory::does_support_persistency()
     }
 
+    fn does_support_read_only_mapping() -> bool {
+        true
+    }
+
+    fn page_size(&self) -> usize {
+        self.shm.page_size()
+    }
+
     fn acquire_ownership(&self) {
         self.shm.acquire_ownership()
     }
@@ -41,6 +41,8 @@ use iceoryx2_bb_elementary::allocator::BaseAllocator;
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_bb_memory::heap_allocator::HeapAllocator;
 use iceoryx2_bb_posix::mutex::*;
+use iceoryx2_bb_posix::permission::Permission;
+use iceoryx2_bb_posix::system_configuration::SystemInfo;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_bb_system_types::path::Path;
@@ -283,6 +285,14 @@ impl<T: Send + Sync + Debug + 'static> DynamicStorage<T> for Storage<T> {
         true
     }
 
+    fn does_support_read_only_mapping() -> bool {
+        false
+    }
+
+    fn page_size(&self) -> usize {
+        SystemInfo::PageSize.value()
+    }
+
     fn acquire_ownership(&self) {
         self.has_ownership.store(true, Ordering::Relaxed);
     }
@@ -450,6 +460,18 @@ impl<'builder, T: Send + Sync + Debug + 'static> DynamicStorageBuilder<'builder,
         self
     }
 
+    fn read_only(self, _value: bool) -> Self {
+        self
+    }
+
+    fn huge_page_size(self, _value: usize) -> Self {
+        self
+    }
+
+    fn permission(self, _value: Permission) -> Self {
+        self
+    }
+
     fn supplementary_size(mut self, value: usize) -> Self {
         self.supplementary_size = value;
         self
@@ -0,0 +1,661 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`SealedSharedMemory`] based implementation of a [`DynamicStorage`]. The size of the
+//! underlying memory is sealed after its creation, no process can truncate or grow it and cause
+//! a `SIGBUS` in the processes that have it mapped.
+//!
+//! The memory itself has no name, the [`Storage`] creates a handle file under the path hint of
+//! the [`Configuration`] with which other processes can open it. The memory lives as long as the
+//! creating process holds it, therefore persistency is not supported.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_system_types::file_name::FileName;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//! use iceoryx2_cal::dynamic_storage::sealed_shared_memory::*;
+//! use iceoryx2_cal::named_concept::*;
+//! use iceoryx2_pal_posix::posix::POSIX_SUPPORT_SEALED_SHARED_MEMORY;
+//! use std::sync::atomic::{AtomicI64, Ordering};
+//!
+//! # if POSIX_SUPPORT_SEALED_SHARED_MEMORY {
+//! let storage_name = FileName::new(b"mySealedStorageName").unwrap();
+//! let owner = Builder::new(&storage_name)
+//!                 .create(AtomicI64::new(0)).unwrap();
+//! owner.get().store(123, Ordering::Relaxed);
+//!
+//! // usually a different process
+//! let storage = Builder::<AtomicI64>::new(&storage_name)
+//!                 .open().unwrap();
+//!
+//! println!("Initial value: {}", storage.get().load(Ordering::Relaxed));
+//! # }
+//! ```
+pub use crate::dynamic_storage::*;
+use crate::static_storage::file::NamedConceptConfiguration;
+use crate::static_storage::file::NamedConceptRemoveError;
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_elementary::package_version::PackageVersion;
+use iceoryx2_bb_log::{error, fail, trace, warn};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_bb_posix::directory::*;
+use iceoryx2_bb_posix::file::*;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
+use iceoryx2_bb_posix::sealed_shared_memory::*;
+use iceoryx2_bb_posix::system_configuration::SystemInfo;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_bb_system_types::path::Path;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+pub use std::ops::Deref;
+use std::ptr::NonNull;
+use std::sync::atomic::Ordering;
+
+use self::dynamic_storage_configuration::DynamicStorageConfiguration;
+
+const DEFAULT_PERMISSIONS: Permission = Permission::OWNER_ALL;
+
+/// The builder of [`Storage`].
+#[derive(Debug)]
+pub struct Builder<'builder, T: Send + Sync + Debug> {
+    storage_name: FileName,
+    supplementary_size: usize,
+    has_ownership: bool,
+    config: Configuration<T>,
+    timeout: Duration,
+    read_only: bool,
+    permission: Permission,
+    initializer: Initializer<'builder, T>,
+    _phantom_data: PhantomData<T>,
+}
+
+#[derive(Debug)]
+pub struct Configuration<T: Send + Sync + Debug> {
+    suffix: FileName,
+    prefix: FileName,
+    path: Path,
+    _data: PhantomData<T>,
+}
+
+impl<T: Send + Sync + Debug> Clone for Configuration<T> {
+    fn clone(&self) -> Self {
+        Self {
+            suffix: self.suffix,
+            prefix: self.prefix,
+            path: self.path,
+            _data: PhantomData,
+        }
+    }
+}
+
+#[repr(C)]
+struct Data<T: Send + Sync + Debug> {
+    version: IoxAtomicU64,
+    data: T,
+}
+
+impl<T: Send + Sync + Debug> Default for Configuration<T> {
+    fn default() -> Self {
+        Self {
+            path: Storage::<()>::default_path_hint(),
+            suffix: Storage::<()>::default_suffix(),
+            prefix: Storage::<()>::default_prefix(),
+            _data: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + Debug> DynamicStorageConfiguration<T> for Configuration<T> {}
+
+impl<T: Send + Sync + Debug> NamedConceptConfiguration for Configuration<T> {
+    fn prefix(mut self, value: FileName) -> Self {
+        self.prefix = value;
+        self
+    }
+
+    fn get_prefix(&self) -> &FileName {
+        &self.prefix
+    }
+
+    fn suffix(mut self, value: FileName) -> Self {
+        self.suffix = value;
+        self
+    }
+
+    fn path_hint(mut self, value: Path) -> Self {
+        self.path = value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path
+    }
+
+    fn path_for(&self, value: &FileName) -> FilePath {
+        self.path_for_with_type(value)
+    }
+
+    fn extract_name_from_file(&self, value: &FileName) -> Option<FileName> {
+        self.extract_name_from_file_with_type(value)
+    }
+}
+
+/// The file that contains the [`SealedSharedMemory::handle()`]. It is removed on drop when it
+/// is owned.
+#[derive(Debug)]
+struct HandleFile {
+    path: FilePath,
+    has_ownership: IoxAtomicBool,
+}
+
+impl Drop for HandleFile {
+    fn drop(&mut self) {
+        if self.has_ownership.load(Ordering::Relaxed) {
+            match File::remove(&self.path) {
+                Ok(_) => {
+                    trace!(from self, "delete");
+                }
+                Err(e) => {
+                    error!(from self, "Failed to remove the handle file ({:?}).", e);
+                }
+            }
+        }
+    }
+}
+
+enum HandleReadResult {
+    Handle(FilePath),
+    DoesNotExist,
+    NotYetWritten,
+}
+
+impl<'builder, T: Send + Sync + Debug> NamedConceptBuilder<Storage<T>> for Builder<'builder, T> {
+    fn new(storage_name: &FileName) -> Self {
+        Self {
+            has_ownership: true,
+            storage_name: *storage_name,
+            supplementary_size: 0,
+            config: Configuration::default(),
+            timeout: Duration::ZERO,
+            read_only: false,
+            permission: DEFAULT_PERMISSIONS,
+            initializer: Initializer::new(|_, _| true),
+            _phantom_data: PhantomData,
+        }
+    }
+
+    fn config(mut self, config: &Configuration<T>) -> Self {
+        self.config = config.clone();
+        self
+    }
+}
+
+impl<'builder, T: Send + Sync + Debug> Builder<'builder, T> {
+    fn read_handle(&self) -> Result<HandleReadResult, DynamicStorageOpenError> {
+        let msg = "Unable to read handle file";
+        let file = match FileBuilder::new(&self.config.path_for(&self.storage_name))
+            .open_existing(AccessMode::Read)
+        {
+            Ok(file) => file,
+            Err(FileOpenError::FileDoesNotExist) => return Ok(HandleReadResult::DoesNotExist),
+            Err(e) => {
+                fail!(from self, with DynamicStorageOpenError::InternalError,
+                    "{} since the file could not be opened ({:?}).", msg, e);
+            }
+        };
+
+        let mut content = String::new();
+        fail!(from self, when file.read_to_string(&mut content),
+            with DynamicStorageOpenError::InternalError,
+            "{} since the content could not be read.", msg);
+
+        if content.is_empty() {
+            return Ok(HandleReadResult::NotYetWritten);
+        }
+
+        Ok(HandleReadResult::Handle(
+            fail!(from self, when FilePath::new(content.as_bytes()),
+                with DynamicStorageOpenError::InternalError,
+                "{} since it contains the invalid handle \"{}\".", msg, content),
+        ))
+    }
+
+    fn open_impl(&self) -> Result<Storage<T>, DynamicStorageOpenError> {
+        let msg = "Failed to open ";
+
+        let mut wait_for_read_write_access = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                    with DynamicStorageOpenError::InternalError,
+                                    "{} since the AdaptiveWait could not be initialized.", msg);
+
+        let access_mode = if self.read_only {
+            AccessMode::Read
+        } else {
+            AccessMode::ReadWrite
+        };
+
+        let mut elapsed_time = Duration::ZERO;
+        let memory = loop {
+            let handle = match self.read_handle()? {
+                HandleReadResult::Handle(handle) => Some(handle),
+                HandleReadResult::DoesNotExist => {
+                    fail!(from self, with DynamicStorageOpenError::DoesNotExist,
+                    "{} since a sealed shared memory with that name does not exists.", msg);
+                }
+                HandleReadResult::NotYetWritten => None,
+            };
+
+            if let Some(handle) = handle {
+                match SealedSharedMemoryBuilder::new(&self.storage_name)
+                    .open_existing(&handle, access_mode)
+                {
+                    Ok(v) => break v,
+                    Err(SealedSharedMemoryCreationError::DoesNotExist) => {
+                        fail!(from self, with DynamicStorageOpenError::DoesNotExist,
+                        "{} since the process that created the sealed shared memory does no longer hold it.", msg);
+                    }
+                    Err(SealedSharedMemoryCreationError::InsufficientPermissions) => (),
+                    Err(e) => {
+                        fail!(from self, with DynamicStorageOpenError::InternalError,
+                            "{} since the underlying sealed shared memory could not be opened ({:?}).", msg, e);
+                    }
+                }
+            }
+
+            if elapsed_time >= self.timeout {
+                fail!(from self, with DynamicStorageOpenError::InitializationNotYetFinalized,
+                "{} since it is not yet readable - most likely since it is not finalized after {:?}.",
+                msg, self.timeout);
+            }
+
+            elapsed_time = fail!(from self, when wait_for_read_write_access.wait(),
+                                    with DynamicStorageOpenError::InternalError,
+                                    "{} since the adaptive wait call failed.", msg);
+        };
+
+        let required_size = std::mem::size_of::<Data<T>>() + self.supplementary_size;
+        if memory.size() < required_size {
+            fail!(from self, with DynamicStorageOpenError::InternalError,
+                "{} since the actual size {} does not match the required size of {}.", msg, memory.size(), required_size);
+        }
+
+        let init_state = memory.base_address().as_ptr() as *const Data<T>;
+
+        //////////////////////////////////////////
+        // SYNC POINT: read Data<T>::data
+        //////////////////////////////////////////
+        let package_version = unsafe { &(*init_state) }
+            .version
+            .load(std::sync::atomic::Ordering::SeqCst);
+
+        let package_version = PackageVersion::from_u64(package_version);
+        if package_version.to_u64() == 0 {
+            return Err(DynamicStorageOpenError::InitializationNotYetFinalized);
+        } else if package_version != PackageVersion::get() {
+            fail!(from self, with DynamicStorageOpenError::VersionMismatch,
+                "{} since the dynamic storage was created with version {} but this process requires version {}.",
+                msg, package_version, PackageVersion::get());
+        }
+
+        Ok(Storage {
+            memory,
+            handle_file: HandleFile {
+                path: self.config.path_for(&self.storage_name),
+                has_ownership: IoxAtomicBool::new(false),
+            },
+            name: self.storage_name,
+            _phantom_data: PhantomData,
+        })
+    }
+
+    fn create_impl(
+        &mut self,
+    ) -> Result<(SealedSharedMemory, HandleFile), DynamicStorageCreateError> {
+        let msg = "Failed to create dynamic_storage::SealedSharedMemory";
+
+        let directory_permission = Permission::OWNER_ALL | Permission::GROUP_ALL;
+        if !fail!(from self, when Directory::does_exist(&self.config.path),
+                with DynamicStorageCreateError::InternalError,
+                "{} since the system is unable to determine if the directory \"{}\" exists.", msg, self.config.path)
+        {
+            fail!(from self, when Directory::create(&self.config.path, directory_permission),
+                with DynamicStorageCreateError::InternalError,
+                "{} since the directory \"{}\" could not be created.", msg, self.config.path);
+        }
+
+        let handle_path = self.config.path_for(&self.storage_name);
+        let mut handle = match FileBuilder::new(&handle_path)
+            .creation_mode(CreationMode::CreateExclusive)
+            .permission(Permission::OWNER_ALL)
+            .create()
+        {
+            Ok(v) => v,
+            Err(FileCreationError::FileAlreadyExists) => {
+                fail!(from self, with DynamicStorageCreateError::AlreadyExists,
+                    "{} since a sealed shared memory with the name already exists.", msg);
+            }
+            Err(FileCreationError::InsufficientPermissions) => {
+                fail!(from self, with DynamicStorageCreateError::InsufficientPermissions,
+                    "{} due to insufficient permissions.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with DynamicStorageCreateError::InternalError,
+                    "{} since the handle file could not be created ({:?}).", msg, e);
+            }
+        };
+        let handle_file = HandleFile {
+            path: handle_path,
+            has_ownership: IoxAtomicBool::new(true),
+        };
+
+        let mut memory = match SealedSharedMemoryBuilder::new(&self.storage_name)
+            .size(std::mem::size_of::<Data<T>>() + self.supplementary_size)
+            .create()
+        {
+            Ok(v) => v,
+            Err(e) => {
+                fail!(from self, with DynamicStorageCreateError::InternalError,
+                    "{} since the underlying sealed shared memory could not be created ({:?}).", msg, e);
+            }
+        };
+
+        // openers fail with insufficient permissions until the initialization is finished
+        fail!(from self, when memory.set_permission(Permission::OWNER_WRITE),
+            with DynamicStorageCreateError::InternalError,
+            "{} since the initial permissions could not be applied to the sealed shared memory.", msg);
+
+        fail!(from self, when handle.write(memory.handle().as_bytes()),
+            with DynamicStorageCreateError::InternalError,
+            "{} since the handle could not be written into the handle file.", msg);
+
+        handle_file
+            .has_ownership
+            .store(self.has_ownership, Ordering::Relaxed);
+
+        Ok((memory, handle_file))
+    }
+
+    fn init_impl(
+        &mut self,
+        mut memory: SealedSharedMemory,
+        handle_file: HandleFile,
+        initial_value: T,
+    ) -> Result<Storage<T>, DynamicStorageCreateError> {
+        let msg = "Failed to init dynamic_storage::SealedSharedMemory";
+        let value = memory.base_address().as_ptr() as *mut Data<T>;
+        let version_ptr = unsafe { core::ptr::addr_of_mut!((*value).version) };
+        unsafe { version_ptr.write(IoxAtomicU64::new(0)) };
+
+        unsafe { core::ptr::addr_of_mut!((*value).data).write(initial_value) };
+
+        let supplementary_start =
+            (memory.base_address().as_ptr() as usize + std::mem::size_of::<Data<T>>()) as *mut u8;
+        let supplementary_len = memory.size() - std::mem::size_of::<Data<T>>();
+
+        let mut allocator = BumpAllocator::new(
+            unsafe { NonNull::new_unchecked(supplementary_start) },
+            supplementary_len,
+        );
+
+        let origin = format!("{:?}", self);
+        if !self
+            .initializer
+            .call(unsafe { &mut (*value).data }, &mut allocator)
+        {
+            fail!(from origin, with DynamicStorageCreateError::InitializationFailed,
+                "{} since the initialization of the underlying construct failed.", msg);
+        }
+
+        //////////////////////////////////////////
+        // SYNC POINT: write Data<T>::data
+        //////////////////////////////////////////
+        unsafe { (*version_ptr).store(PackageVersion::get().to_u64(), Ordering::SeqCst) };
+
+        if let Err(e) = memory.set_permission(self.permission) {
+            fail!(from origin, with DynamicStorageCreateError::InternalError,
+                "{} since the final permissions could not be applied to the underlying sealed shared memory ({:?}).",
+                msg, e);
+        }
+
+        Ok(Storage {
+            memory,
+            handle_file,
+            name: self.storage_name,
+            _phantom_data: PhantomData,
+        })
+    }
+}
+
+impl<'builder, T: Send + Sync + Debug> DynamicStorageBuilder<'builder, T, Storage<T>>
+    for Builder<'builder, T>
+{
+    fn has_ownership(mut self, value: bool) -> Self {
+        self.has_ownership = value;
+        self
+    }
+
+    fn initializer<F: FnMut(&mut T, &mut BumpAllocator) -> bool + 'builder>(
+        mut self,
+        value: F,
+    ) -> Self {
+        self.initializer = Initializer::new(value);
+        self
+    }
+
+    fn timeout(mut self, value: Duration) -> Self {
+        self.timeout = value;
+        self
+    }
+
+    fn read_only(mut self, value: bool) -> Self {
+        self.read_only = value;
+        self
+    }
+
+    /// Huge pages are not supported, the value is ignored.
+    fn huge_page_size(self, _value: usize) -> Self {
+        self
+    }
+
+    fn permission(mut self, value: Permission) -> Self {
+        self.permission = value;
+        self
+    }
+
+    fn supplementary_size(mut self, value: usize) -> Self {
+        self.supplementary_size = value;
+        self
+    }
+
+    fn create(mut self, initial_value: T) -> Result<Storage<T>, DynamicStorageCreateError> {
+        let (memory, handle_file) = self.create_impl()?;
+        self.init_impl(memory, handle_file, initial_value)
+    }
+
+    fn open(self) -> Result<Storage<T>, DynamicStorageOpenError> {
+        self.open_impl()
+    }
+
+    fn open_or_create(
+        mut self,
+        initial_value: T,
+    ) -> Result<Storage<T>, DynamicStorageOpenOrCreateError> {
+        loop {
+            match self.open_impl() {
+                Ok(storage) => return Ok(storage),
+                Err(DynamicStorageOpenError::DoesNotExist) => match self.create_impl() {
+                    Ok((memory, handle_file)) => {
+                        return Ok(self.init_impl(memory, handle_file, initial_value)?);
+                    }
+                    Err(DynamicStorageCreateError::AlreadyExists) => continue,
+                    Err(e) => return Err(e.into()),
+                },
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+/// Implements [`DynamicStorage`] for a [`SealedSharedMemory`]. It is built by [`Builder`].
+#[derive(Debug)]
+pub struct Storage<T: Debug + Send + Sync> {
+    memory: SealedSharedMemory,
+    handle_file: HandleFile,
+    name: FileName,
+    _phantom_data: PhantomData<T>,
+}
+
+impl<T: Debug + Send + Sync> Drop for Storage<T> {
+    fn drop(&mut self) {
+        if self.has_ownership() {
+            let data =
+                unsafe { &mut (*(self.memory.base_address().as_ptr() as *mut Data<T>)).data };
+            unsafe { core::ptr::drop_in_place(data) };
+        }
+    }
+}
+
+impl<T: Send + Sync + Debug> NamedConcept for Storage<T> {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl<T: Send + Sync + Debug> NamedConceptMgmt for Storage<T> {
+    type Configuration = Configuration<T>;
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptDoesExistError> {
+        let origin = "dynamic_storage::sealed_shared_memory::Storage::does_exist_cfg()";
+        let does_exist = fail!(from origin, when File::does_exist(&cfg.path_for(name)),
+                with crate::static_storage::file::NamedConceptDoesExistError::UnderlyingResourcesCorrupted,
+                "Unable to determine if the handle file of \"{}\" exists.", name);
+
+        Ok(does_exist)
+    }
+
+    fn list_cfg(
+        config: &Self::Configuration,
+    ) -> Result<Vec<FileName>, crate::static_storage::file::NamedConceptListError> {
+        let msg = "Unable to list all sealed shared memories";
+        let origin = "dynamic_storage::sealed_shared_memory::Storage::list_cfg()";
+        let directory = match Directory::new(&config.path) {
+            Ok(directory) => directory,
+            Err(DirectoryOpenError::DoesNotExist) => return Ok(vec![]),
+            Err(e) => {
+                fail!(from origin, with crate::static_storage::file::NamedConceptListError::InternalError,
+                    "{} since the directory \"{}\" could not be opened ({:?}).", msg, config.path, e);
+            }
+        };
+
+        let entries = fail!(from origin, when directory.contents(),
+            with crate::static_storage::file::NamedConceptListError::InternalError,
+            "{} since the contents of the directory \"{}\" could not be read.", msg, config.path);
+
+        Ok(entries
+            .iter()
+            .filter_map(|entry| config.extract_name_from_file(entry.name()))
+            .collect())
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptRemoveError> {
+        let full_name = cfg.path_for(name);
+        let msg = "Unable to remove dynamic_storage::sealed_shared_memory";
+        let origin = "dynamic_storage::sealed_shared_memory::Storage::remove_cfg()";
+
+        match Builder::<T>::new(name).config(cfg).open() {
+            Ok(s) => {
+                s.acquire_ownership();
+                Ok(true)
+            }
+            Err(DynamicStorageOpenError::DoesNotExist) => {
+                // the handle file of a memory whose creator is gone is removed as well
+                match File::remove(&full_name) {
+                    Ok(v) => Ok(v),
+                    Err(v) => {
+                        fail!(from origin, with NamedConceptRemoveError::InternalError,
+                            "{} \"{}\" since the stale handle file could not be removed ({:?}).", msg, name, v);
+                    }
+                }
+            }
+            Err(e) => {
+                warn!(from origin,
+                    "Removing DynamicStorage in broken state ({:?}) will not call drop of the underlying data type {:?}.",
+                    e, std::any::type_name::<T>());
+
+                match File::remove(&full_name) {
+                    Ok(v) => Ok(v),
+                    Err(FileRemoveError::InsufficientPermissions) => {
+                        fail!(from origin, with NamedConceptRemoveError::InsufficientPermissions,
+                                     "{} \"{}\" due to insufficient permissions.", msg, name);
+                    }
+                    Err(v) => {
+                        fail!(from origin, with NamedConceptRemoveError::InternalError,
+                                    "{} \"{}\" due to an internal failure ({:?}).", msg, name, v);
+                    }
+                }
+            }
+        }
+    }
+
+    fn remove_path_hint(
+        _value: &Path,
+    ) -> Result<(), crate::named_concept::NamedConceptPathHintRemoveError> {
+        Ok(())
+    }
+}
+
+impl<T: Send + Sync + Debug> DynamicStorage<T> for Storage<T> {
+    type Builder<'builder> = Builder<'builder, T>;
+
+    fn does_support_persistency() -> bool {
+        false
+    }
+
+    fn does_support_read_only_mapping() -> bool {
+        true
+    }
+
+    fn page_size(&self) -> usize {
+        SystemInfo::PageSize.value()
+    }
+
+    fn acquire_ownership(&self) {
+        self.handle_file
+            .has_ownership
+            .store(true, Ordering::Relaxed)
+    }
+
+    fn get(&self) -> &T {
+        unsafe { &(*(self.memory.base_address().as_ptr() as *const Data<T>)).data }
+    }
+
+    fn has_ownership(&self) -> bool {
+        self.handle_file.has_ownership.load(Ordering::Relaxed)
+    }
+
+    fn release_ownership(&self) {
+        self.handle_file
+            .has_ownership
+            .store(false, Ordering::Relaxed)
+    }
+}
@@ -0,0 +1,349 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`Event`](crate::event::Event) based on the posix message queue
+//! [`communication_channel::message_queue::Channel`]. An alternative to the
+//! [`unix_datagram_socket`](crate::event::unix_datagram_socket) for platforms like QNX where
+//! message queues are the preferred kernel primitive.
+
+pub use crate::event::*;
+
+use crate::communication_channel::{
+    self, CommunicationChannelConnector, CommunicationChannelCreateError,
+    CommunicationChannelCreator, CommunicationChannelOpenError, CommunicationChannelReceiver,
+    CommunicationChannelSendError, CommunicationChannelSender,
+};
+use crate::static_storage::file::NamedConceptConfiguration;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::message_queue::{MessageQueueReceiveError, MessageQueueTimedReceiveError};
+
+const MAX_BATCH_SIZE: usize = 512;
+
+type Channel = communication_channel::message_queue::Channel<TriggerId>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Configuration {
+    suffix: FileName,
+    prefix: FileName,
+    path: Path,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            path: EventImpl::default_path_hint(),
+            suffix: EventImpl::default_suffix(),
+            prefix: EventImpl::default_prefix(),
+        }
+    }
+}
+
+impl NamedConceptConfiguration for Configuration {
+    fn prefix(mut self, value: FileName) -> Self {
+        self.prefix = value;
+        self
+    }
+
+    fn get_prefix(&self) -> &FileName {
+        &self.prefix
+    }
+
+    fn suffix(mut self, value: FileName) -> Self {
+        self.suffix = value;
+        self
+    }
+
+    fn path_hint(mut self, value: Path) -> Self {
+        self.path = value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl From<Configuration> for communication_channel::message_queue::Configuration {
+    fn from(value: Configuration) -> Self {
+        Self::default()
+            .prefix(value.prefix)
+            .suffix(value.suffix)
+            .path_hint(value.path)
+    }
+}
+
+#[derive(Debug)]
+pub struct EventImpl {}
+
+impl NamedConceptMgmt for EventImpl {
+    type Configuration = Configuration;
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptDoesExistError> {
+        Channel::does_exist_cfg(name, &(*cfg).into())
+    }
+
+    fn list_cfg(
+        cfg: &Self::Configuration,
+    ) -> Result<Vec<FileName>, crate::static_storage::file::NamedConceptListError> {
+        Channel::list_cfg(&(*cfg).into())
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptRemoveError> {
+        Channel::remove_cfg(name, &(*cfg).into())
+    }
+
+    fn remove_path_hint(
+        value: &Path,
+    ) -> Result<(), crate::named_concept::NamedConceptPathHintRemoveError> {
+        Channel::remove_path_hint(value)
+    }
+}
+
+impl crate::event::Event for EventImpl {
+    type Notifier = Notifier;
+    type Listener = Listener;
+    type NotifierBuilder = NotifierBuilder;
+    type ListenerBuilder = ListenerBuilder;
+}
+
+#[derive(Debug)]
+pub struct Notifier {
+    sender: communication_channel::message_queue::Sender<TriggerId>,
+    name: FileName,
+}
+
+impl NamedConcept for Notifier {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl crate::event::Notifier for Notifier {
+    fn notify(&self, id: TriggerId) -> Result<(), NotifierNotifyError> {
+        let msg = "Failed to notify event::message_queue::Listener";
+        match self.sender.try_send(&id) {
+            Ok(_) => Ok(()),
+            Err(CommunicationChannelSendError::ReceiverCacheIsFull) => {
+                fail!(from self, with NotifierNotifyError::FailedToDeliverSignal,
+                        "{} since the signal could not be delivered.", msg);
+            }
+            Err(CommunicationChannelSendError::ConnectionBroken) => {
+                fail!(from self, with NotifierNotifyError::Disconnected,
+                        "{} since the notifier is no longer connected to the listener.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with NotifierNotifyError::InternalFailure,
+                        "{} due to an unknown failure ({:?}).", msg, v);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct NotifierBuilder {
+    name: FileName,
+    config: Configuration,
+}
+
+impl NamedConceptBuilder<EventImpl> for NotifierBuilder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            config: Configuration::default(),
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = *config;
+        self
+    }
+}
+
+impl crate::event::NotifierBuilder<EventImpl> for NotifierBuilder {
+    fn timeout(self, _timeout: Duration) -> Self {
+        self
+    }
+
+    fn open(self) -> Result<Notifier, NotifierCreateError> {
+        let msg = "Failed to open event::message_queue::Notifier";
+
+        match communication_channel::message_queue::Connector::new(&self.name)
+            .config(&self.config.into())
+            .try_open_sender()
+        {
+            Ok(sender) => Ok(Notifier {
+                sender,
+                name: self.name,
+            }),
+            Err(CommunicationChannelOpenError::DoesNotExist) => {
+                fail!(from self, with NotifierCreateError::DoesNotExist,
+                    "{} since the corresponding listener does not exist.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with NotifierCreateError::InternalFailure,
+                    "{} due to an unknown failure ({:?}).", msg, v);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Listener {
+    receiver: communication_channel::message_queue::Receiver<TriggerId>,
+    name: FileName,
+}
+
+impl NamedConcept for Listener {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl crate::event::Listener for Listener {
+    fn try_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        match self.receiver.receive() {
+            Ok(v) => Ok(v),
+            Err(v) => {
+                fail!(from self, with ListenerWaitError::InternalFailure,
+                    "Unable to try wait for signal on event::message_queue::Listener due to an unknown failure ({:?}).", v);
+            }
+        }
+    }
+
+    fn timed_wait_one(
+        &self,
+        timeout: std::time::Duration,
+    ) -> Result<Option<TriggerId>, ListenerWaitError> {
+        let msg = "Unable to wait for signal on event::message_queue::Listener";
+        match self.receiver.timed_receive(timeout) {
+            Ok(v) => Ok(v),
+            Err(MessageQueueTimedReceiveError::MessageQueueReceiveError(
+                MessageQueueReceiveError::Interrupt,
+            )) => {
+                fail!(from self, with ListenerWaitError::InterruptSignal,
+                    "{} with a timeout of {:?} since an interrupt signal was received.", msg, timeout);
+            }
+            Err(v) => {
+                fail!(from self, with ListenerWaitError::InternalFailure,
+                    "{} with a timeout of {:?} due to an unknown failure ({:?}).", msg, timeout, v);
+            }
+        }
+    }
+
+    fn blocking_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        let msg = "Unable to blocking wait for signal on event::message_queue::Listener";
+        match self.receiver.blocking_receive() {
+            Ok(v) => Ok(Some(v)),
+            Err(MessageQueueReceiveError::Interrupt) => {
+                fail!(from self, with ListenerWaitError::InterruptSignal,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with ListenerWaitError::InternalFailure,
+                    "{} due to an unknown failure ({:?}).", msg, v);
+            }
+        }
+    }
+
+    fn try_wait_all<F: FnMut(TriggerId)>(&self, mut callback: F) -> Result<(), ListenerWaitError> {
+        let mut counter = 0;
+        while let Some(id) = self.try_wait_one()? {
+            callback(id);
+
+            counter += 1;
+            if counter == MAX_BATCH_SIZE {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn timed_wait_all<F: FnMut(TriggerId)>(
+        &self,
+        mut callback: F,
+        timeout: Duration,
+    ) -> Result<(), ListenerWaitError> {
+        if let Some(id) = self.timed_wait_one(timeout)? {
+            callback(id);
+        }
+        self.try_wait_all(callback)
+    }
+
+    fn blocking_wait_all<F: FnMut(TriggerId)>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        if let Some(id) = self.blocking_wait_one()? {
+            callback(id);
+        }
+        self.try_wait_all(callback)
+    }
+}
+
+#[derive(Debug)]
+pub struct ListenerBuilder {
+    name: FileName,
+    config: Configuration,
+}
+
+impl NamedConceptBuilder<EventImpl> for ListenerBuilder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            config: Configuration::default(),
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = *config;
+        self
+    }
+}
+
+impl crate::event::ListenerBuilder<EventImpl> for ListenerBuilder {
+    fn trigger_id_max(self, _id: TriggerId) -> Self {
+        self
+    }
+
+    fn create(self) -> Result<Listener, ListenerCreateError> {
+        let msg = "Failed to create event::message_queue::Listener";
+
+        match communication_channel::message_queue::Creator::new(&self.name)
+            .config(&self.config.into())
+            .create_receiver()
+        {
+            Ok(receiver) => Ok(Listener {
+                receiver,
+                name: self.name,
+            }),
+            Err(CommunicationChannelCreateError::AlreadyExists) => {
+                fail!(from self, with ListenerCreateError::AlreadyExists,
+                    "{} since a listener with that name already exists.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with ListenerCreateError::InternalFailure,
+                    "{} due to an unknown failure ({:?}).", msg, v);
+            }
+        }
+    }
+}
@@ -12,7 +12,9 @@
 
 pub mod common;
 pub mod id_tracker;
+pub mod message_queue;
 pub mod process_local;
+pub mod selectable;
 pub mod sem_bitset_posix_shared_memory;
 pub mod sem_bitset_process_local;
 pub mod signal_mechanism;
@@ -21,6 +23,7 @@ pub mod unix_datagram_socket;
 use std::{fmt::Debug, time::Duration};
 
 pub use crate::named_concept::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
 pub use iceoryx2_bb_system_types::file_name::*;
 pub use iceoryx2_bb_system_types::path::Path;
 
@@ -124,6 +127,15 @@ pub trait Listener: NamedConcept + Debug {
         timeout: Duration,
     ) -> Result<(), ListenerWaitError>;
     fn blocking_wait_all<F: FnMut(TriggerId)>(&self, callback: F) -> Result<(), ListenerWaitError>;
+
+    /// Returns the [`Listener`] as [`SynchronousMultiplexing`] object whose file descriptor
+    /// becomes readable as soon as a [`TriggerId`] was notified, so that multiple
+    /// [`Listener`]s can be multiplexed with a
+    /// [`iceoryx2_bb_posix::file_descriptor_set::FileDescriptorSet`]. Returns [`None`] when
+    /// the [`Listener`] is not based on a file descriptor.
+    fn as_multiplexable(&self) -> Option<&dyn SynchronousMultiplexing> {
+        None
+    }
 }
 
 pub trait ListenerBuilder<T: Event>: NamedConceptBuilder<T> + Debug {
@@ -145,4 +157,13 @@ pub trait Event: Sized + NamedConceptMgmt + Debug {
     fn has_trigger_id_limit() -> bool {
         false
     }
+
+    /// Selects the [`selectable::Mechanism`] in the configuration. Events that are based on a
+    /// single mechanism return the configuration unchanged.
+    fn select_mechanism(
+        config: Self::Configuration,
+        _mechanism: selectable::Mechanism,
+    ) -> Self::Configuration {
+        config
+    }
 }
@@ -0,0 +1,347 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! An [`Event`](crate::event::Event) whose underlying kernel primitive is selected at runtime
+//! with the [`Mechanism`] of its [`Configuration`]. The [`Listener`] and all [`Notifier`]s of
+//! an event must use the same [`Mechanism`].
+
+pub use crate::event::*;
+
+use crate::event::{message_queue, unix_datagram_socket};
+use crate::event::{Listener as _, ListenerBuilder as _, Notifier as _, NotifierBuilder as _};
+use crate::static_storage::file::NamedConceptConfiguration;
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use serde::{Deserialize, Serialize};
+
+/// The kernel primitive the [`EventImpl`] is based on.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Mechanism {
+    /// Uses the [`unix_datagram_socket::EventImpl`]
+    #[default]
+    UnixDatagramSocket,
+    /// Uses the [`message_queue::EventImpl`]
+    MessageQueue,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Configuration {
+    suffix: FileName,
+    prefix: FileName,
+    path: Path,
+    mechanism: Mechanism,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            path: EventImpl::default_path_hint(),
+            suffix: EventImpl::default_suffix(),
+            prefix: EventImpl::default_prefix(),
+            mechanism: Mechanism::default(),
+        }
+    }
+}
+
+impl Configuration {
+    /// Sets the [`Mechanism`] of the event
+    pub fn mechanism(mut self, value: Mechanism) -> Self {
+        self.mechanism = value;
+        self
+    }
+
+    /// Returns the [`Mechanism`] of the event
+    pub fn get_mechanism(&self) -> Mechanism {
+        self.mechanism
+    }
+}
+
+impl NamedConceptConfiguration for Configuration {
+    fn prefix(mut self, value: FileName) -> Self {
+        self.prefix = value;
+        self
+    }
+
+    fn get_prefix(&self) -> &FileName {
+        &self.prefix
+    }
+
+    fn suffix(mut self, value: FileName) -> Self {
+        self.suffix = value;
+        self
+    }
+
+    fn path_hint(mut self, value: Path) -> Self {
+        self.path = value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl From<Configuration> for unix_datagram_socket::Configuration {
+    fn from(value: Configuration) -> Self {
+        Self::default()
+            .prefix(value.prefix)
+            .suffix(value.suffix)
+            .path_hint(value.path)
+    }
+}
+
+impl From<Configuration> for message_queue::Configuration {
+    fn from(value: Configuration) -> Self {
+        Self::default()
+            .prefix(value.prefix)
+            .suffix(value.suffix)
+            .path_hint(value.path)
+    }
+}
+
+#[derive(Debug)]
+pub struct EventImpl {}
+
+impl NamedConceptMgmt for EventImpl {
+    type Configuration = Configuration;
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptDoesExistError> {
+        match cfg.mechanism {
+            Mechanism::UnixDatagramSocket => {
+                unix_datagram_socket::EventImpl::does_exist_cfg(name, &(*cfg).into())
+            }
+            Mechanism::MessageQueue => {
+                message_queue::EventImpl::does_exist_cfg(name, &(*cfg).into())
+            }
+        }
+    }
+
+    fn list_cfg(
+        cfg: &Self::Configuration,
+    ) -> Result<Vec<FileName>, crate::static_storage::file::NamedConceptListError> {
+        match cfg.mechanism {
+            Mechanism::UnixDatagramSocket => {
+                unix_datagram_socket::EventImpl::list_cfg(&(*cfg).into())
+            }
+            Mechanism::MessageQueue => message_queue::EventImpl::list_cfg(&(*cfg).into()),
+        }
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptRemoveError> {
+        match cfg.mechanism {
+            Mechanism::UnixDatagramSocket => {
+                unix_datagram_socket::EventImpl::remove_cfg(name, &(*cfg).into())
+            }
+            Mechanism::MessageQueue => message_queue::EventImpl::remove_cfg(name, &(*cfg).into()),
+        }
+    }
+
+    fn remove_path_hint(
+        value: &Path,
+    ) -> Result<(), crate::named_concept::NamedConceptPathHintRemoveError> {
+        crate::named_concept::remove_path_hint(value)
+    }
+}
+
+impl crate::event::Event for EventImpl {
+    type Notifier = Notifier;
+    type Listener = Listener;
+    type NotifierBuilder = NotifierBuilder;
+    type ListenerBuilder = ListenerBuilder;
+
+    fn select_mechanism(config: Configuration, mechanism: Mechanism) -> Configuration {
+        config.mechanism(mechanism)
+    }
+}
+
+macro_rules! dispatch {
+    ($type:ident, $self:expr, $event:ident => $call:expr) => {
+        match $self {
+            $type::UnixDatagramSocket($event) => $call,
+            $type::MessageQueue($event) => $call,
+        }
+    };
+}
+
+#[derive(Debug)]
+pub enum Notifier {
+    UnixDatagramSocket(unix_datagram_socket::Notifier),
+    MessageQueue(message_queue::Notifier),
+}
+
+impl NamedConcept for Notifier {
+    fn name(&self) -> &FileName {
+        dispatch!(Notifier, self, n => n.name())
+    }
+}
+
+impl crate::event::Notifier for Notifier {
+    fn notify(&self, id: TriggerId) -> Result<(), NotifierNotifyError> {
+        dispatch!(Notifier, self, n => n.notify(id))
+    }
+}
+
+#[derive(Debug)]
+pub struct NotifierBuilder {
+    name: FileName,
+    config: Configuration,
+    timeout: Option<Duration>,
+}
+
+impl NamedConceptBuilder<EventImpl> for NotifierBuilder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            config: Configuration::default(),
+            timeout: None,
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = *config;
+        self
+    }
+}
+
+impl crate::event::NotifierBuilder<EventImpl> for NotifierBuilder {
+    fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    fn open(self) -> Result<Notifier, NotifierCreateError> {
+        match self.config.mechanism {
+            Mechanism::UnixDatagramSocket => {
+                let mut builder = unix_datagram_socket::NotifierBuilder::new(&self.name)
+                    .config(&self.config.into());
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                Ok(Notifier::UnixDatagramSocket(builder.open()?))
+            }
+            Mechanism::MessageQueue => {
+                let mut builder =
+                    message_queue::NotifierBuilder::new(&self.name).config(&self.config.into());
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                Ok(Notifier::MessageQueue(builder.open()?))
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Listener {
+    UnixDatagramSocket(unix_datagram_socket::Listener),
+    MessageQueue(message_queue::Listener),
+}
+
+impl NamedConcept for Listener {
+    fn name(&self) -> &FileName {
+        dispatch!(Listener, self, l => l.name())
+    }
+}
+
+impl crate::event::Listener for Listener {
+    fn try_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        dispatch!(Listener, self, l => l.try_wait_one())
+    }
+
+    fn timed_wait_one(&self, timeout: Duration) -> Result<Option<TriggerId>, ListenerWaitError> {
+        dispatch!(Listener, self, l => l.timed_wait_one(timeout))
+    }
+
+    fn blocking_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        dispatch!(Listener, self, l => l.blocking_wait_one())
+    }
+
+    fn try_wait_all<F: FnMut(TriggerId)>(&self, callback: F) -> Result<(), ListenerWaitError> {
+        dispatch!(Listener, self, l => l.try_wait_all(callback))
+    }
+
+    fn timed_wait_all<F: FnMut(TriggerId)>(
+        &self,
+        callback: F,
+        timeout: Duration,
+    ) -> Result<(), ListenerWaitError> {
+        dispatch!(Listener, self, l => l.timed_wait_all(callback, timeout))
+    }
+
+    fn blocking_wait_all<F: FnMut(TriggerId)>(&self, callback: F) -> Result<(), ListenerWaitError> {
+        dispatch!(Listener, self, l => l.blocking_wait_all(callback))
+    }
+
+    fn as_multiplexable(&self) -> Option<&dyn SynchronousMultiplexing> {
+        dispatch!(Listener, self, l => l.as_multiplexable())
+    }
+}
+
+#[derive(Debug)]
+pub struct ListenerBuilder {
+    name: FileName,
+    config: Configuration,
+    trigger_id_max: Option<TriggerId>,
+}
+
+impl NamedConceptBuilder<EventImpl> for ListenerBuilder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            config: Configuration::default(),
+            trigger_id_max: None,
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = *config;
+        self
+    }
+}
+
+impl crate::event::ListenerBuilder<EventImpl> for ListenerBuilder {
+    fn trigger_id_max(mut self, id: TriggerId) -> Self {
+        self.trigger_id_max = Some(id);
+        self
+    }
+
+    fn create(self) -> Result<Listener, ListenerCreateError> {
+        match self.config.mechanism {
+            Mechanism::UnixDatagramSocket => {
+                let mut builder = unix_datagram_socket::ListenerBuilder::new(&self.name)
+                    .config(&self.config.into());
+                if let Some(id) = self.trigger_id_max {
+                    builder = builder.trigger_id_max(id);
+                }
+                Ok(Listener::UnixDatagramSocket(builder.create()?))
+            }
+            Mechanism::MessageQueue => {
+                let mut builder =
+                    message_queue::ListenerBuilder::new(&self.name).config(&self.config.into());
+                if let Some(id) = self.trigger_id_max {
+                    builder = builder.trigger_id_max(id);
+                }
+                Ok(Listener::MessageQueue(builder.create()?))
+            }
+        }
+    }
+}
@@ -323,6 +323,10 @@ impl crate::event::Listener for Listener {
         }
         self.try_wait_all(callback)
     }
+
+    fn as_multiplexable(&self) -> Option<&dyn SynchronousMultiplexing> {
+        Some(self)
+    }
 }
 
 #[derive(Debug)]
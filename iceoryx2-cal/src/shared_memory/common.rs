@@ -18,6 +18,7 @@ use crate::dynamic_storage::*;
 pub use crate::shared_memory::*;
 use iceoryx2_bb_elementary::allocator::BaseAllocator;
 use iceoryx2_bb_log::{debug, fail};
+use iceoryx2_bb_posix::permission::Permission;
 use iceoryx2_bb_posix::system_configuration::SystemInfo;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::path::Path;
@@ -144,6 +145,9 @@ pub mod details {
         config: Configuration<Allocator, Storage>,
         timeout: Duration,
         has_ownership: bool,
+        read_only: bool,
+        huge_page_size: usize,
+        permission: Permission,
     }
 
     impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
@@ -156,6 +160,9 @@ pub mod details {
                 size: 0,
                 timeout: Duration::ZERO,
                 has_ownership: true,
+                read_only: false,
+                huge_page_size: 0,
+                permission: Permission::OWNER_ALL,
             }
         }
 
@@ -220,6 +227,21 @@ pub mod details {
             self
         }
 
+        fn read_only(mut self, value: bool) -> Self {
+            self.read_only = value;
+            self
+        }
+
+        fn huge_page_size(mut self, value: usize) -> Self {
+            self.huge_page_size = value;
+            self
+        }
+
+        fn permission(mut self, value: Permission) -> Self {
+            self.permission = value;
+            self
+        }
+
         fn create(
             self,
             allocator_config: &Allocator::Configuration,
@@ -237,6 +259,8 @@ pub mod details {
                 .config(&self.config.convert())
                 .supplementary_size(self.size + allocator_mgmt_size)
                 .has_ownership(self.has_ownership)
+                .huge_page_size(self.huge_page_size)
+                .permission(self.permission)
                 .initializer(|details, init_allocator| -> bool {
                     self.initialize(allocator_config, details, init_allocator)
                 })
@@ -281,6 +305,8 @@ pub mod details {
                 .config(&self.config.convert())
                 .has_ownership(false)
                 .timeout(self.timeout)
+                .read_only(self.read_only)
+                .huge_page_size(self.huge_page_size)
                 .open()
             {
                 Ok(s) => s,
@@ -397,6 +423,14 @@ pub mod details {
             Storage::does_support_persistency()
         }
 
+        fn does_support_read_only_mapping() -> bool {
+            Storage::does_support_read_only_mapping()
+        }
+
+        fn page_size(&self) -> usize {
+            self.storage.page_size()
+        }
+
         fn has_ownership(&self) -> bool {
             self.storage.has_ownership()
         }
@@ -438,5 +472,26 @@ pub mod details {
         fn payload_start_address(&self) -> usize {
             self.payload_start_address
         }
+
+        fn allocator_payload_size(&self) -> usize {
+            self.size()
+                - unsafe {
+                    self.storage
+                        .get()
+                        .allocator
+                        .assume_init_ref()
+                        .relative_start_address()
+                }
+        }
+
+        fn fragmentation_report(&self) -> FragmentationReport {
+            unsafe {
+                self.storage
+                    .get()
+                    .allocator
+                    .assume_init_ref()
+                    .fragmentation_report()
+            }
+        }
     }
 }
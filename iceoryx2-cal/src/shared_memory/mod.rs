@@ -55,13 +55,17 @@
 //! ```
 
 pub mod common;
+pub mod pinned;
 pub mod posix;
 pub mod process_local;
+pub mod sealed;
+pub mod selectable;
 
 use std::{fmt::Debug, time::Duration};
 
 pub use crate::shm_allocator::*;
 use crate::static_storage::file::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
+use iceoryx2_bb_posix::permission::Permission;
 use iceoryx2_bb_system_types::file_name::*;
 
 /// Failure returned by [`SharedMemoryBuilder::create()`]
@@ -112,6 +116,24 @@ pub trait SharedMemoryBuilder<Allocator: ShmAllocator, Shm: SharedMemory<Allocat
     /// timeout.
     fn timeout(self, value: Duration) -> Self;
 
+    /// Defines if an opened [`SharedMemory`] is mapped read-only so that it cannot be
+    /// modified by the opener. It is ignored when the implementation does not support it, see
+    /// [`SharedMemory::does_support_read_only_mapping()`]. By default it is false.
+    /// A read-only [`SharedMemory`] must not call [`SharedMemory::allocate()`] or
+    /// [`SharedMemory::deallocate()`].
+    fn read_only(self, value: bool) -> Self;
+
+    /// Requests that the [`SharedMemory`] is backed by huge pages of the provided size. It
+    /// falls back to the default page size when huge pages are not available or not supported
+    /// by the implementation, see [`SharedMemory::page_size()`]. By default huge pages are
+    /// disabled.
+    fn huge_page_size(self, value: usize) -> Self;
+
+    /// Defines the [`Permission`] of a newly created [`SharedMemory`]. It is ignored by
+    /// implementations that are not backed by a file system resource. By default only the
+    /// owner has access.
+    fn permission(self, value: Permission) -> Self;
+
     /// Creates new [`SharedMemory`]. If it already exists the method will fail.
     fn create(
         self,
@@ -140,6 +162,11 @@ pub trait SharedMemory<Allocator: ShmAllocator>:
     /// the actual memory position.
     fn payload_start_address(&self) -> usize;
 
+    /// Returns the number of bytes that are managed by the allocator, starting at
+    /// [`SharedMemory::payload_start_address()`]. It is smaller than [`SharedMemory::size()`]
+    /// when the allocator has to align the start of its payload.
+    fn allocator_payload_size(&self) -> usize;
+
     /// Allocates memory. The alignment in the layout must be smaller or equal
     /// [`SharedMemory::max_alignment()`] otherwise the method will fail.
     fn allocate(&self, layout: std::alloc::Layout) -> Result<ShmPointer, ShmAllocationError>;
@@ -153,10 +180,21 @@ pub trait SharedMemory<Allocator: ShmAllocator>:
     ///  * the layout must be identical to the one used in [`SharedMemory::allocate()`]
     unsafe fn deallocate(&self, offset: PointerOffset, layout: std::alloc::Layout);
 
+    /// Returns a [`FragmentationReport`] that describes the current utilization of the
+    /// allocator of the [`SharedMemory`].
+    fn fragmentation_report(&self) -> FragmentationReport;
+
     /// Returns if the [`SharedMemory`] supports persistency, meaning that the underlying OS
     /// resource remain even when every [`SharedMemory`] instance in every process was removed.
     fn does_support_persistency() -> bool;
 
+    /// Returns true if the [`SharedMemory`] can be opened read-only with
+    /// [`SharedMemoryBuilder::read_only()`].
+    fn does_support_read_only_mapping() -> bool;
+
+    /// Returns the page size of the memory that backs the [`SharedMemory`].
+    fn page_size(&self) -> usize;
+
     /// Returns true if the [`SharedMemory`] holds the ownership, otherwise false
     fn has_ownership(&self) -> bool;
 
@@ -168,6 +206,15 @@ pub trait SharedMemory<Allocator: ShmAllocator>:
     /// underlying resource will not be removed.
     fn release_ownership(&self);
 
+    /// Selects the [`selectable::Mechanism`] in the configuration. Shared memories that are
+    /// based on a single implementation return the configuration unchanged.
+    fn select_mechanism(
+        config: Self::Configuration,
+        _mechanism: selectable::Mechanism,
+    ) -> Self::Configuration {
+        config
+    }
+
     /// The default suffix of every shared memory
     fn default_suffix() -> FileName {
         unsafe { FileName::new_unchecked(b".shm") }
@@ -0,0 +1,292 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`SharedMemory`] based on [`posix::Memory`] whose payload is registered as pinned host
+//! memory with a [`HostMemoryRegistration`] in every process that creates or opens it. Devices
+//! like GPUs can then transfer the payload via DMA without staging it in a separate pinned
+//! buffer first.
+//!
+//! The registration is pluggable. [`PageLocked`] only excludes the payload from paging, a CUDA
+//! based implementation would call `cudaHostRegister` and `cudaHostUnregister` instead.
+//!
+//! ```
+//! use iceoryx2_cal::shared_memory::pinned::HostMemoryRegistration;
+//!
+//! #[derive(Debug)]
+//! struct CudaRegistration {
+//!     address: usize,
+//! }
+//!
+//! impl HostMemoryRegistration for CudaRegistration {
+//!     unsafe fn register(address: *mut u8, size: usize) -> Option<Self> {
+//!         // cudaHostRegister(address, size, cudaHostRegisterPortable) == cudaSuccess
+//!         Some(Self { address: address as usize })
+//!     }
+//! }
+//!
+//! impl Drop for CudaRegistration {
+//!     fn drop(&mut self) {
+//!         // cudaHostUnregister(self.address)
+//!     }
+//! }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+pub use crate::shared_memory::*;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::memory_lock::MemoryLock;
+use iceoryx2_bb_posix::permission::Permission;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::path::Path;
+
+use crate::static_storage::file::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
+
+/// Registers the payload memory of a [`Memory`] as pinned host memory. The registration lives
+/// as long as the returned object, dropping it must release the registration again.
+pub trait HostMemoryRegistration: Debug + Send + Sync + Sized + 'static {
+    /// Registers the memory region. Returns [`None`] when the region could not be registered.
+    ///
+    /// # Safety
+    ///
+    ///  * the memory range `[address, address + size)` must stay valid for the lifetime of the
+    ///    returned object
+    unsafe fn register(address: *mut u8, size: usize) -> Option<Self>;
+}
+
+/// The default [`HostMemoryRegistration`]. Locks the memory region so that it is never swapped
+/// out.
+#[derive(Debug)]
+pub struct PageLocked {
+    _lock: MemoryLock,
+}
+
+// the lock only stores the locked region to unlock it on drop and is not bound to a thread
+unsafe impl Send for PageLocked {}
+unsafe impl Sync for PageLocked {}
+
+impl HostMemoryRegistration for PageLocked {
+    unsafe fn register(address: *mut u8, size: usize) -> Option<Self> {
+        MemoryLock::new(address as *const _, size)
+            .ok()
+            .map(|lock| Self { _lock: lock })
+    }
+}
+
+type Configuration<Allocator> = <posix::Memory<Allocator> as NamedConceptMgmt>::Configuration;
+
+#[derive(Debug)]
+pub struct Builder<Allocator: ShmAllocator + Debug, Registration: HostMemoryRegistration> {
+    builder: <posix::Memory<Allocator> as SharedMemory<Allocator>>::Builder,
+    _registration: PhantomData<Registration>,
+}
+
+impl<Allocator: ShmAllocator + Debug, Registration: HostMemoryRegistration>
+    NamedConceptBuilder<Memory<Allocator, Registration>> for Builder<Allocator, Registration>
+{
+    fn new(name: &FileName) -> Self {
+        Self {
+            builder: <posix::Memory<Allocator> as SharedMemory<Allocator>>::Builder::new(name),
+            _registration: PhantomData,
+        }
+    }
+
+    fn config(mut self, config: &Configuration<Allocator>) -> Self {
+        self.builder = self.builder.config(config);
+        self
+    }
+}
+
+impl<Allocator: ShmAllocator + Debug, Registration: HostMemoryRegistration>
+    SharedMemoryBuilder<Allocator, Memory<Allocator, Registration>>
+    for Builder<Allocator, Registration>
+{
+    fn has_ownership(mut self, value: bool) -> Self {
+        self.builder = self.builder.has_ownership(value);
+        self
+    }
+
+    fn size(mut self, value: usize) -> Self {
+        self.builder = self.builder.size(value);
+        self
+    }
+
+    fn timeout(mut self, value: Duration) -> Self {
+        self.builder = self.builder.timeout(value);
+        self
+    }
+
+    fn read_only(mut self, value: bool) -> Self {
+        self.builder = self.builder.read_only(value);
+        self
+    }
+
+    fn huge_page_size(mut self, value: usize) -> Self {
+        self.builder = self.builder.huge_page_size(value);
+        self
+    }
+
+    fn permission(mut self, value: Permission) -> Self {
+        self.builder = self.builder.permission(value);
+        self
+    }
+
+    fn create(
+        self,
+        allocator_config: &Allocator::Configuration,
+    ) -> Result<Memory<Allocator, Registration>, SharedMemoryCreateError> {
+        let memory = self.builder.create(allocator_config)?;
+        match Memory::register(memory) {
+            Some(memory) => Ok(memory),
+            None => {
+                fail!(from "pinned::Builder::create()", with SharedMemoryCreateError::InternalError,
+                    "Unable to create shared memory since the payload memory could not be registered as pinned host memory.");
+            }
+        }
+    }
+
+    fn open(self) -> Result<Memory<Allocator, Registration>, SharedMemoryOpenError> {
+        let memory = self.builder.open()?;
+        match Memory::register(memory) {
+            Some(memory) => Ok(memory),
+            None => {
+                fail!(from "pinned::Builder::open()", with SharedMemoryOpenError::InternalError,
+                    "Unable to open shared memory since the payload memory could not be registered as pinned host memory.");
+            }
+        }
+    }
+}
+
+/// [`posix::Memory`] whose payload is registered with the [`HostMemoryRegistration`] as long
+/// as the object exists.
+#[derive(Debug)]
+pub struct Memory<Allocator: ShmAllocator + Debug, Registration: HostMemoryRegistration> {
+    // must be declared before the memory so that the registration is released before the
+    // memory is unmapped
+    _registration: Registration,
+    memory: posix::Memory<Allocator>,
+}
+
+impl<Allocator: ShmAllocator + Debug, Registration: HostMemoryRegistration>
+    Memory<Allocator, Registration>
+{
+    fn register(memory: posix::Memory<Allocator>) -> Option<Self> {
+        let registration = unsafe {
+            Registration::register(memory.payload_start_address() as *mut u8, memory.size())
+        }?;
+
+        Some(Self {
+            _registration: registration,
+            memory,
+        })
+    }
+}
+
+impl<Allocator: ShmAllocator + Debug, Registration: HostMemoryRegistration> NamedConcept
+    for Memory<Allocator, Registration>
+{
+    fn name(&self) -> &FileName {
+        self.memory.name()
+    }
+}
+
+impl<Allocator: ShmAllocator + Debug, Registration: HostMemoryRegistration> NamedConceptMgmt
+    for Memory<Allocator, Registration>
+{
+    type Configuration = Configuration<Allocator>;
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptDoesExistError> {
+        posix::Memory::<Allocator>::does_exist_cfg(name, cfg)
+    }
+
+    fn list_cfg(
+        cfg: &Self::Configuration,
+    ) -> Result<Vec<FileName>, crate::static_storage::file::NamedConceptListError> {
+        posix::Memory::<Allocator>::list_cfg(cfg)
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptRemoveError> {
+        posix::Memory::<Allocator>::remove_cfg(name, cfg)
+    }
+
+    fn remove_path_hint(
+        value: &Path,
+    ) -> Result<(), crate::named_concept::NamedConceptPathHintRemoveError> {
+        posix::Memory::<Allocator>::remove_path_hint(value)
+    }
+}
+
+impl<Allocator: ShmAllocator + Debug, Registration: HostMemoryRegistration> SharedMemory<Allocator>
+    for Memory<Allocator, Registration>
+{
+    type Builder = Builder<Allocator, Registration>;
+
+    fn does_support_persistency() -> bool {
+        posix::Memory::<Allocator>::does_support_persistency()
+    }
+
+    fn does_support_read_only_mapping() -> bool {
+        posix::Memory::<Allocator>::does_support_read_only_mapping()
+    }
+
+    fn page_size(&self) -> usize {
+        self.memory.page_size()
+    }
+
+    fn has_ownership(&self) -> bool {
+        self.memory.has_ownership()
+    }
+
+    fn acquire_ownership(&self) {
+        self.memory.acquire_ownership()
+    }
+
+    fn release_ownership(&self) {
+        self.memory.release_ownership()
+    }
+
+    fn size(&self) -> usize {
+        self.memory.size()
+    }
+
+    fn max_alignment(&self) -> usize {
+        self.memory.max_alignment()
+    }
+
+    fn allocate(&self, layout: std::alloc::Layout) -> Result<ShmPointer, ShmAllocationError> {
+        self.memory.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, offset: PointerOffset, layout: std::alloc::Layout) {
+        self.memory.deallocate(offset, layout)
+    }
+
+    fn payload_start_address(&self) -> usize {
+        self.memory.payload_start_address()
+    }
+
+    fn allocator_payload_size(&self) -> usize {
+        self.memory.allocator_payload_size()
+    }
+
+    fn fragmentation_report(&self) -> FragmentationReport {
+        self.memory.fragmentation_report()
+    }
+}
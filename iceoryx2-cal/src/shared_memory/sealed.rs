@@ -0,0 +1,22 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`SharedMemory`](crate::shared_memory::SharedMemory) whose size is sealed after its
+//! creation so that no process can truncate or grow it, see
+//! [`sealed_shared_memory`](crate::dynamic_storage::sealed_shared_memory).
+
+use super::common::details::AllocatorDetails;
+
+pub type Memory<Allocator> = crate::shared_memory::common::details::Memory<
+    Allocator,
+    crate::dynamic_storage::sealed_shared_memory::Storage<AllocatorDetails<Allocator>>,
+>;
@@ -0,0 +1,369 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`SharedMemory`] whose underlying implementation is selected at runtime with the
+//! [`Mechanism`] of its [`Configuration`]. The creator and all openers of a shared memory must
+//! use the same [`Mechanism`].
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+pub use crate::shared_memory::*;
+use crate::shared_memory::{posix, sealed};
+use crate::static_storage::file::{
+    NamedConcept, NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt,
+};
+use iceoryx2_bb_posix::permission::Permission;
+use iceoryx2_bb_system_types::path::Path;
+use serde::{Deserialize, Serialize};
+
+/// The implementation the [`Memory`] is based on.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Mechanism {
+    /// Uses the [`posix::Memory`]
+    #[default]
+    Posix,
+    /// Uses the [`sealed::Memory`] whose size cannot be changed after its creation. It is only
+    /// supported on Linux.
+    Sealed,
+}
+
+#[derive(Debug)]
+pub struct Configuration<Allocator: ShmAllocator + Debug> {
+    suffix: FileName,
+    prefix: FileName,
+    path: Path,
+    mechanism: Mechanism,
+    _allocator: PhantomData<Allocator>,
+}
+
+impl<Allocator: ShmAllocator + Debug> Clone for Configuration<Allocator> {
+    fn clone(&self) -> Self {
+        Self {
+            suffix: self.suffix,
+            prefix: self.prefix,
+            path: self.path,
+            mechanism: self.mechanism,
+            _allocator: PhantomData,
+        }
+    }
+}
+
+impl<Allocator: ShmAllocator + Debug> Default for Configuration<Allocator> {
+    fn default() -> Self {
+        Self {
+            path: Memory::<Allocator>::default_path_hint(),
+            suffix: Memory::<Allocator>::default_suffix(),
+            prefix: Memory::<Allocator>::default_prefix(),
+            mechanism: Mechanism::default(),
+            _allocator: PhantomData,
+        }
+    }
+}
+
+impl<Allocator: ShmAllocator + Debug> Configuration<Allocator> {
+    /// Sets the [`Mechanism`] of the shared memory
+    pub fn mechanism(mut self, value: Mechanism) -> Self {
+        self.mechanism = value;
+        self
+    }
+
+    /// Returns the [`Mechanism`] of the shared memory
+    pub fn get_mechanism(&self) -> Mechanism {
+        self.mechanism
+    }
+
+    fn convert<Target: NamedConceptConfiguration>(&self) -> Target {
+        Target::default()
+            .prefix(self.prefix)
+            .suffix(self.suffix)
+            .path_hint(self.path)
+    }
+}
+
+impl<Allocator: ShmAllocator + Debug> NamedConceptConfiguration for Configuration<Allocator> {
+    fn prefix(mut self, value: FileName) -> Self {
+        self.prefix = value;
+        self
+    }
+
+    fn get_prefix(&self) -> &FileName {
+        &self.prefix
+    }
+
+    fn suffix(mut self, value: FileName) -> Self {
+        self.suffix = value;
+        self
+    }
+
+    fn path_hint(mut self, value: Path) -> Self {
+        self.path = value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path
+    }
+}
+
+macro_rules! dispatch {
+    ($self:expr, $memory:ident => $call:expr) => {
+        match $self {
+            Memory::Posix($memory) => $call,
+            Memory::Sealed($memory) => $call,
+        }
+    };
+}
+
+#[derive(Debug)]
+pub struct Builder<Allocator: ShmAllocator + Debug> {
+    name: FileName,
+    config: Configuration<Allocator>,
+    has_ownership: Option<bool>,
+    size: Option<usize>,
+    timeout: Option<Duration>,
+    read_only: Option<bool>,
+    huge_page_size: Option<usize>,
+    permission: Option<Permission>,
+}
+
+impl<Allocator: ShmAllocator + Debug> NamedConceptBuilder<Memory<Allocator>>
+    for Builder<Allocator>
+{
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            config: Configuration::default(),
+            has_ownership: None,
+            size: None,
+            timeout: None,
+            read_only: None,
+            huge_page_size: None,
+            permission: None,
+        }
+    }
+
+    fn config(mut self, config: &Configuration<Allocator>) -> Self {
+        self.config = config.clone();
+        self
+    }
+}
+
+impl<Allocator: ShmAllocator + Debug> Builder<Allocator> {
+    fn apply<Shm: SharedMemory<Allocator>>(&self) -> Shm::Builder {
+        let mut builder = Shm::Builder::new(&self.name).config(&self.config.convert());
+        if let Some(value) = self.has_ownership {
+            builder = builder.has_ownership(value);
+        }
+        if let Some(value) = self.size {
+            builder = builder.size(value);
+        }
+        if let Some(value) = self.timeout {
+            builder = builder.timeout(value);
+        }
+        if let Some(value) = self.read_only {
+            builder = builder.read_only(value);
+        }
+        if let Some(value) = self.huge_page_size {
+            builder = builder.huge_page_size(value);
+        }
+        if let Some(value) = self.permission {
+            builder = builder.permission(value);
+        }
+        builder
+    }
+}
+
+impl<Allocator: ShmAllocator + Debug> SharedMemoryBuilder<Allocator, Memory<Allocator>>
+    for Builder<Allocator>
+{
+    fn has_ownership(mut self, value: bool) -> Self {
+        self.has_ownership = Some(value);
+        self
+    }
+
+    fn size(mut self, value: usize) -> Self {
+        self.size = Some(value);
+        self
+    }
+
+    fn timeout(mut self, value: Duration) -> Self {
+        self.timeout = Some(value);
+        self
+    }
+
+    fn read_only(mut self, value: bool) -> Self {
+        self.read_only = Some(value);
+        self
+    }
+
+    fn huge_page_size(mut self, value: usize) -> Self {
+        self.huge_page_size = Some(value);
+        self
+    }
+
+    fn permission(mut self, value: Permission) -> Self {
+        self.permission = Some(value);
+        self
+    }
+
+    fn create(
+        self,
+        allocator_config: &Allocator::Configuration,
+    ) -> Result<Memory<Allocator>, SharedMemoryCreateError> {
+        match self.config.mechanism {
+            Mechanism::Posix => Ok(Memory::Posix(
+                self.apply::<posix::Memory<Allocator>>()
+                    .create(allocator_config)?,
+            )),
+            Mechanism::Sealed => Ok(Memory::Sealed(
+                self.apply::<sealed::Memory<Allocator>>()
+                    .create(allocator_config)?,
+            )),
+        }
+    }
+
+    fn open(self) -> Result<Memory<Allocator>, SharedMemoryOpenError> {
+        match self.config.mechanism {
+            Mechanism::Posix => Ok(Memory::Posix(
+                self.apply::<posix::Memory<Allocator>>().open()?,
+            )),
+            Mechanism::Sealed => Ok(Memory::Sealed(
+                self.apply::<sealed::Memory<Allocator>>().open()?,
+            )),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Memory<Allocator: ShmAllocator + Debug> {
+    Posix(posix::Memory<Allocator>),
+    Sealed(sealed::Memory<Allocator>),
+}
+
+impl<Allocator: ShmAllocator + Debug> NamedConcept for Memory<Allocator> {
+    fn name(&self) -> &FileName {
+        dispatch!(self, m => m.name())
+    }
+}
+
+impl<Allocator: ShmAllocator + Debug> NamedConceptMgmt for Memory<Allocator> {
+    type Configuration = Configuration<Allocator>;
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptDoesExistError> {
+        match cfg.mechanism {
+            Mechanism::Posix => posix::Memory::<Allocator>::does_exist_cfg(name, &cfg.convert()),
+            Mechanism::Sealed => sealed::Memory::<Allocator>::does_exist_cfg(name, &cfg.convert()),
+        }
+    }
+
+    fn list_cfg(
+        cfg: &Self::Configuration,
+    ) -> Result<Vec<FileName>, crate::static_storage::file::NamedConceptListError> {
+        match cfg.mechanism {
+            Mechanism::Posix => posix::Memory::<Allocator>::list_cfg(&cfg.convert()),
+            Mechanism::Sealed => sealed::Memory::<Allocator>::list_cfg(&cfg.convert()),
+        }
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptRemoveError> {
+        match cfg.mechanism {
+            Mechanism::Posix => posix::Memory::<Allocator>::remove_cfg(name, &cfg.convert()),
+            Mechanism::Sealed => sealed::Memory::<Allocator>::remove_cfg(name, &cfg.convert()),
+        }
+    }
+
+    fn remove_path_hint(
+        _value: &Path,
+    ) -> Result<(), crate::named_concept::NamedConceptPathHintRemoveError> {
+        Ok(())
+    }
+}
+
+impl<Allocator: ShmAllocator + Debug> SharedMemory<Allocator> for Memory<Allocator> {
+    type Builder = Builder<Allocator>;
+
+    fn select_mechanism(
+        config: Configuration<Allocator>,
+        mechanism: Mechanism,
+    ) -> Configuration<Allocator> {
+        config.mechanism(mechanism)
+    }
+
+    /// Returns true only when every [`Mechanism`] supports persistency.
+    fn does_support_persistency() -> bool {
+        posix::Memory::<Allocator>::does_support_persistency()
+            && sealed::Memory::<Allocator>::does_support_persistency()
+    }
+
+    /// Returns true only when every [`Mechanism`] supports read-only mappings.
+    fn does_support_read_only_mapping() -> bool {
+        posix::Memory::<Allocator>::does_support_read_only_mapping()
+            && sealed::Memory::<Allocator>::does_support_read_only_mapping()
+    }
+
+    fn page_size(&self) -> usize {
+        dispatch!(self, m => m.page_size())
+    }
+
+    fn has_ownership(&self) -> bool {
+        dispatch!(self, m => m.has_ownership())
+    }
+
+    fn acquire_ownership(&self) {
+        dispatch!(self, m => m.acquire_ownership())
+    }
+
+    fn release_ownership(&self) {
+        dispatch!(self, m => m.release_ownership())
+    }
+
+    fn size(&self) -> usize {
+        dispatch!(self, m => m.size())
+    }
+
+    fn max_alignment(&self) -> usize {
+        dispatch!(self, m => m.max_alignment())
+    }
+
+    fn allocate(&self, layout: std::alloc::Layout) -> Result<ShmPointer, ShmAllocationError> {
+        dispatch!(self, m => m.allocate(layout))
+    }
+
+    unsafe fn deallocate(&self, offset: PointerOffset, layout: std::alloc::Layout) {
+        dispatch!(self, m => m.deallocate(offset, layout))
+    }
+
+    fn payload_start_address(&self) -> usize {
+        dispatch!(self, m => m.payload_start_address())
+    }
+
+    fn allocator_payload_size(&self) -> usize {
+        dispatch!(self, m => m.allocator_payload_size())
+    }
+
+    fn fragmentation_report(&self) -> FragmentationReport {
+        dispatch!(self, m => m.fragmentation_report())
+    }
+}
@@ -0,0 +1,292 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A best-fit [`ShmAllocator`] for variable sized allocations. The memory is divided into
+//! granules and every allocation occupies a contiguous range of granules. A new allocation is
+//! placed into the smallest free range that fits, which keeps fragmentation low when the
+//! allocated sizes vary a lot.
+
+use std::{alloc::Layout, ptr::NonNull, sync::atomic::Ordering};
+
+use crate::shm_allocator::{FragmentationReport, ShmAllocator, ShmAllocatorConfig};
+use iceoryx2_bb_elementary::allocator::{AllocationError, BaseAllocator};
+use iceoryx2_bb_elementary::math::align;
+use iceoryx2_bb_elementary::pointer_trait::PointerTrait;
+use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU32};
+
+use super::sanitizer::{poison_memory_region, unpoison_memory_region};
+use super::{PointerOffset, ShmAllocationError, ShmAllocatorInitError};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Config {
+    /// The smallest unit the allocator manages. Every allocation is rounded up to a multiple
+    /// of the granule size and the granule alignment is the max supported alignment.
+    pub granule_layout: Layout,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            granule_layout: unsafe { Layout::from_size_align_unchecked(64, 8) },
+        }
+    }
+}
+
+impl ShmAllocatorConfig for Config {}
+
+#[derive(Debug)]
+pub struct BestFitAllocator {
+    // stores for every granule that starts an allocation the number of granules of the
+    // allocation, otherwise 0
+    block_sizes: RelocatablePointer<IoxAtomicU32>,
+    number_of_granules: usize,
+    granule_size: usize,
+    granule_alignment: usize,
+    start: usize,
+    base_address: usize,
+    max_supported_alignment_by_memory: usize,
+    is_locked: IoxAtomicBool,
+    is_memory_initialized: IoxAtomicBool,
+}
+
+impl BestFitAllocator {
+    fn granule_size(layout: Layout) -> usize {
+        layout.pad_to_align().size().max(1)
+    }
+
+    fn calc_number_of_granules(layout: Layout, start: usize, end: usize) -> usize {
+        if end <= start {
+            0
+        } else {
+            (end - start) / Self::granule_size(layout)
+        }
+    }
+
+    unsafe fn block_size(&self, index: usize) -> &IoxAtomicU32 {
+        &*self.block_sizes.as_ptr().add(index)
+    }
+
+    fn lock(&self) {
+        while self
+            .is_locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            std::hint::spin_loop();
+        }
+    }
+
+    fn unlock(&self) {
+        self.is_locked.store(false, Ordering::Release);
+    }
+
+    /// Returns the size of a single granule.
+    pub fn granule(&self) -> usize {
+        self.granule_size
+    }
+
+    /// Returns the number of granules the allocator manages.
+    pub fn number_of_granules(&self) -> usize {
+        self.number_of_granules
+    }
+
+    // calls the callback with the start and the length of every contiguous free range of
+    // granules until the callback returns false
+    fn for_each_free_range<F: FnMut(usize, usize) -> bool>(&self, mut callback: F) {
+        let mut i = 0;
+        while i < self.number_of_granules {
+            let len = unsafe { self.block_size(i) }.load(Ordering::Relaxed) as usize;
+            if len != 0 {
+                i += len;
+                continue;
+            }
+
+            let range_start = i;
+            while i < self.number_of_granules
+                && unsafe { self.block_size(i) }.load(Ordering::Relaxed) == 0
+            {
+                i += 1;
+            }
+
+            if !callback(range_start, i - range_start) {
+                return;
+            }
+        }
+    }
+
+    fn find_best_fit(&self, required_granules: usize) -> Option<usize> {
+        let mut best_fit: Option<(usize, usize)> = None;
+        self.for_each_free_range(|range_start, range_len| {
+            if range_len >= required_granules
+                && best_fit.map_or(true, |(_, best_len)| range_len < best_len)
+            {
+                best_fit = Some((range_start, range_len));
+            }
+
+            range_len != required_granules
+        });
+
+        best_fit.map(|(start, _)| start)
+    }
+}
+
+impl ShmAllocator for BestFitAllocator {
+    type Configuration = Config;
+
+    fn management_size(memory_size: usize, config: &Self::Configuration) -> usize {
+        let number_of_granules = memory_size / Self::granule_size(config.granule_layout);
+        number_of_granules.max(1) * std::mem::size_of::<IoxAtomicU32>()
+            + std::mem::align_of::<IoxAtomicU32>()
+            - 1
+    }
+
+    fn relative_start_address(&self) -> usize {
+        self.start - self.base_address
+    }
+
+    unsafe fn new_uninit(
+        max_supported_alignment_by_memory: usize,
+        managed_memory: NonNull<[u8]>,
+        config: &Self::Configuration,
+    ) -> Self {
+        let base_address = (managed_memory.as_ptr() as *mut u8) as usize;
+        let start = align(base_address, config.granule_layout.align());
+
+        Self {
+            block_sizes: RelocatablePointer::new_uninit(),
+            number_of_granules: Self::calc_number_of_granules(
+                config.granule_layout,
+                start,
+                base_address + managed_memory.len(),
+            ),
+            granule_size: Self::granule_size(config.granule_layout),
+            granule_alignment: config.granule_layout.align(),
+            start,
+            base_address,
+            max_supported_alignment_by_memory,
+            is_locked: IoxAtomicBool::new(false),
+            is_memory_initialized: IoxAtomicBool::new(false),
+        }
+    }
+
+    fn max_alignment(&self) -> usize {
+        self.granule_alignment
+    }
+
+    unsafe fn init<Allocator: BaseAllocator>(
+        &self,
+        mgmt_allocator: &Allocator,
+    ) -> Result<(), ShmAllocatorInitError> {
+        let msg = "Unable to initialize allocator";
+        if self.is_memory_initialized.load(Ordering::Relaxed) {
+            fatal_panic!(from self,
+                "Memory already initialized. Initializing it twice may lead to undefined behavior.");
+        }
+
+        if self.max_supported_alignment_by_memory < self.max_alignment() {
+            fail!(from self, with ShmAllocatorInitError::MaxSupportedMemoryAlignmentInsufficient,
+                "{} since the required alignment {} exceeds the maximum supported alignment {} of the memory.",
+                msg, self.max_alignment(), self.max_supported_alignment_by_memory);
+        }
+
+        let memory = fail!(from self, when mgmt_allocator.allocate(Layout::from_size_align_unchecked(
+                    self.number_of_granules.max(1) * std::mem::size_of::<IoxAtomicU32>(),
+                    std::mem::align_of::<IoxAtomicU32>())),
+            with ShmAllocatorInitError::AllocationFailed,
+            "{} since the allocation of the allocator managment memory failed.", msg);
+
+        self.block_sizes.init(memory);
+        for i in 0..self.number_of_granules {
+            (self.block_sizes.as_ptr() as *mut IoxAtomicU32)
+                .add(i)
+                .write(IoxAtomicU32::new(0));
+        }
+
+        poison_memory_region(self.start, self.number_of_granules * self.granule_size);
+        self.is_memory_initialized.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn unique_id() -> u8 {
+        2
+    }
+
+    unsafe fn allocate(&self, layout: Layout) -> Result<PointerOffset, ShmAllocationError> {
+        let msg = "Unable to allocate memory";
+        if layout.align() > self.max_alignment() {
+            fail!(from self, with ShmAllocationError::ExceedsMaxSupportedAlignment,
+                "{} since an alignment of {} exceeds the maximum supported alignment of {}.",
+                msg, layout.align(), self.max_alignment());
+        }
+
+        if layout.size() == 0 {
+            fail!(from self, with ShmAllocationError::AllocationError(AllocationError::SizeIsZero),
+                "{} since the requested size was zero.", msg);
+        }
+
+        let required_granules = layout.size().div_ceil(self.granule_size);
+        if required_granules > u32::MAX as usize {
+            fail!(from self, with ShmAllocationError::AllocationError(AllocationError::SizeTooLarge),
+                "{} since the requested size of {} is too large.", msg, layout.size());
+        }
+
+        self.lock();
+        let start = match self.find_best_fit(required_granules) {
+            Some(start) => {
+                self.block_size(start)
+                    .store(required_granules as u32, Ordering::Relaxed);
+                start
+            }
+            None => {
+                self.unlock();
+                fail!(from self, with ShmAllocationError::AllocationError(AllocationError::OutOfMemory),
+                    "{} {:?} since there is no contiguous free memory range large enough.", msg, layout);
+            }
+        };
+        self.unlock();
+
+        unpoison_memory_region(
+            self.start + start * self.granule_size,
+            required_granules * self.granule_size,
+        );
+        Ok(PointerOffset::new(start * self.granule_size))
+    }
+
+    unsafe fn deallocate(&self, offset: PointerOffset, _layout: Layout) {
+        let index = offset.value() / self.granule_size;
+        debug_assert!(index < self.number_of_granules);
+
+        self.lock();
+        let number_of_granules = self.block_size(index).swap(0, Ordering::Relaxed) as usize;
+        self.unlock();
+
+        poison_memory_region(
+            self.start + offset.value(),
+            number_of_granules * self.granule_size,
+        );
+    }
+
+    unsafe fn fragmentation_report(&self) -> FragmentationReport {
+        let mut report = FragmentationReport::new(self.number_of_granules * self.granule_size);
+
+        self.lock();
+        self.for_each_free_range(|_, range_len| {
+            report.add_free_chunk(range_len * self.granule_size);
+            true
+        });
+        self.unlock();
+
+        report
+    }
+}
@@ -10,7 +10,9 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use crate::shm_allocator::sanitizer::{poison_memory_region, unpoison_memory_region};
 use crate::shm_allocator::*;
+use iceoryx2_bb_elementary::math::align;
 use iceoryx2_bb_log::fail;
 
 #[derive(Default, Clone, Copy)]
@@ -66,6 +68,7 @@ impl ShmAllocator for BumpAllocator {
                 msg, self.max_alignment(), self.max_supported_alignment_by_memory);
         }
 
+        poison_memory_region(self.allocator.start_address(), self.allocator.total_space());
         Ok(())
     }
 
@@ -83,15 +86,29 @@ impl ShmAllocator for BumpAllocator {
 
         let chunk = fail!(from self, when self.allocator.allocate(layout),
                                         "{}.", msg);
+        unpoison_memory_region(chunk.as_ptr() as *const u8 as usize, layout.size());
         Ok(PointerOffset::new(
             (chunk.as_ptr() as *const u8) as usize - self.base_address,
         ))
     }
 
     unsafe fn deallocate(&self, offset: PointerOffset, layout: Layout) {
+        // releases all allocations at once
+        poison_memory_region(self.allocator.start_address(), self.allocator.total_space());
         self.allocator.deallocate(
             NonNull::new_unchecked((offset.0 + self.base_address) as *mut u8),
             layout,
         );
     }
+
+    unsafe fn fragmentation_report(&self) -> FragmentationReport {
+        let mut report = FragmentationReport::new(self.allocator.total_space());
+        let end = self.allocator.start_address() + self.allocator.total_space();
+        let next_free = align(
+            self.allocator.start_address() + self.allocator.used_space(),
+            self.max_alignment(),
+        );
+        report.add_free_chunk(end.saturating_sub(next_free));
+        report
+    }
 }
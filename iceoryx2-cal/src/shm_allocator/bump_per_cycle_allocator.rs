@@ -0,0 +1,154 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A bump [`ShmAllocator`] that works in cycles. Allocations are placed one after another and
+//! the whole memory becomes available again as soon as the last outstanding allocation of the
+//! cycle was released. Well suited for producers that loan, send and release their samples in
+//! bursts.
+
+use std::{alloc::Layout, ptr::NonNull, sync::atomic::Ordering};
+
+use crate::shm_allocator::sanitizer::{poison_memory_region, unpoison_memory_region};
+use crate::shm_allocator::*;
+use iceoryx2_bb_elementary::math::align;
+use iceoryx2_bb_log::fail;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Config {
+    /// The max alignment an allocation can request.
+    pub max_alignment: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self { max_alignment: 8 }
+    }
+}
+
+impl ShmAllocatorConfig for Config {}
+
+#[derive(Debug)]
+pub struct BumpPerCycleAllocator {
+    allocator: iceoryx2_bb_memory::bump_allocator::BumpAllocator,
+    number_of_allocations: IoxAtomicUsize,
+    base_address: usize,
+    max_alignment: usize,
+    max_supported_alignment_by_memory: usize,
+}
+
+impl BumpPerCycleAllocator {
+    /// Returns the number of bytes that were already bumped in the current cycle.
+    pub fn used_space(&self) -> usize {
+        self.allocator.used_space()
+    }
+
+    /// Returns the number of bytes that are still available in the current cycle.
+    pub fn free_space(&self) -> usize {
+        self.allocator.free_space()
+    }
+}
+
+impl ShmAllocator for BumpPerCycleAllocator {
+    type Configuration = Config;
+
+    fn management_size(_memory_size: usize, _config: &Self::Configuration) -> usize {
+        0
+    }
+
+    fn relative_start_address(&self) -> usize {
+        self.allocator.start_address() - self.base_address
+    }
+
+    unsafe fn new_uninit(
+        max_supported_alignment_by_memory: usize,
+        managed_memory: NonNull<[u8]>,
+        config: &Self::Configuration,
+    ) -> Self {
+        Self {
+            allocator: iceoryx2_bb_memory::bump_allocator::BumpAllocator::new(
+                unsafe { NonNull::new_unchecked(managed_memory.as_ptr() as *mut u8) },
+                managed_memory.len(),
+            ),
+            number_of_allocations: IoxAtomicUsize::new(0),
+            base_address: (managed_memory.as_ptr() as *mut u8) as usize,
+            max_alignment: config.max_alignment,
+            max_supported_alignment_by_memory,
+        }
+    }
+
+    fn max_alignment(&self) -> usize {
+        self.max_alignment
+    }
+
+    unsafe fn init<Allocator: BaseAllocator>(
+        &self,
+        _mgmt_allocator: &Allocator,
+    ) -> Result<(), ShmAllocatorInitError> {
+        let msg = "Unable to initialize allocator";
+        if self.max_supported_alignment_by_memory < self.max_alignment() {
+            fail!(from self, with ShmAllocatorInitError::MaxSupportedMemoryAlignmentInsufficient,
+                "{} since the required alignment {} exceeds the maximum supported alignment {} of the memory.",
+                msg, self.max_alignment(), self.max_supported_alignment_by_memory);
+        }
+
+        poison_memory_region(self.allocator.start_address(), self.allocator.total_space());
+        Ok(())
+    }
+
+    fn unique_id() -> u8 {
+        3
+    }
+
+    unsafe fn allocate(&self, layout: Layout) -> Result<PointerOffset, ShmAllocationError> {
+        let msg = "Unable to allocate memory";
+        if layout.align() > self.max_alignment() {
+            fail!(from self, with ShmAllocationError::ExceedsMaxSupportedAlignment,
+                "{} since an alignment of {} exceeds the maximum supported alignment of {}.",
+                msg, layout.align(), self.max_alignment());
+        }
+
+        let chunk = fail!(from self, when self.allocator.allocate(layout),
+                                        "{}.", msg);
+        unpoison_memory_region(chunk.as_ptr() as *const u8 as usize, layout.size());
+        self.number_of_allocations.fetch_add(1, Ordering::Relaxed);
+
+        Ok(PointerOffset::new(
+            (chunk.as_ptr() as *const u8) as usize - self.allocator.start_address(),
+        ))
+    }
+
+    unsafe fn deallocate(&self, offset: PointerOffset, layout: Layout) {
+        // the cycle ends when the last outstanding allocation is returned, from then on the
+        // whole memory can be used again
+        if self.number_of_allocations.fetch_sub(1, Ordering::Relaxed) == 1 {
+            poison_memory_region(self.allocator.start_address(), self.allocator.total_space());
+            self.allocator.deallocate(
+                NonNull::new_unchecked(
+                    (offset.value() + self.allocator.start_address()) as *mut u8,
+                ),
+                layout,
+            );
+        }
+    }
+
+    unsafe fn fragmentation_report(&self) -> FragmentationReport {
+        let mut report = FragmentationReport::new(self.allocator.total_space());
+        let end = self.allocator.start_address() + self.allocator.total_space();
+        let next_free = align(
+            self.allocator.start_address() + self.allocator.used_space(),
+            self.max_alignment(),
+        );
+        report.add_free_chunk(end.saturating_sub(next_free));
+        report
+    }
+}
@@ -10,27 +10,95 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub mod best_fit_allocator;
 pub mod bump_allocator;
+pub mod bump_per_cycle_allocator;
+pub mod multi_pool_allocator;
 pub mod pool_allocator;
+pub mod sanitizer;
+pub mod selectable_allocator;
 
-use std::{alloc::Layout, ptr::NonNull};
+use std::{alloc::Layout, collections::BTreeMap, ptr::NonNull};
 
 pub use iceoryx2_bb_elementary::allocator::AllocationError;
 use iceoryx2_bb_elementary::{allocator::BaseAllocator, enum_gen};
+use iceoryx2_bb_log::fail;
 
 pub trait ShmAllocatorConfig: Copy + Default {}
 
+/// Failures that can occur when a [`PointerOffset`] is created with
+/// [`PointerOffset::from_offset_and_segment_id()`].
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum PointerOffsetError {
+    SegmentIdOutOfRange,
+    OffsetOutOfRange,
+}
+
+impl std::fmt::Display for PointerOffsetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "PointerOffsetError::{:?}", self)
+    }
+}
+
+impl std::error::Error for PointerOffsetError {}
+
+/// The offset of a chunk relative to the start of the shared memory segment that contains it.
+/// The upper bits store the id of the segment so that the chunks of multiple segments can be
+/// transferred through the same channel. For the segment `0` the value is equal to the offset.
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub struct PointerOffset(usize);
 
 impl PointerOffset {
+    const SEGMENT_ID_BITS: u32 = 8;
+    const OFFSET_BITS: u32 = usize::BITS - Self::SEGMENT_ID_BITS;
+    const OFFSET_MASK: usize = (1 << Self::OFFSET_BITS) - 1;
+
+    /// The maximum number of segments that can be distinguished by a [`PointerOffset`].
+    pub const MAX_NUMBER_OF_SEGMENTS: usize = 1 << Self::SEGMENT_ID_BITS;
+
+    /// Creates a new [`PointerOffset`] from its raw value, see [`PointerOffset::value()`].
     pub fn new(value: usize) -> PointerOffset {
         Self(value)
     }
 
+    /// Creates a new [`PointerOffset`] of the chunk at `offset` in the segment with the id
+    /// `segment_id`. Fails when the `segment_id` is not smaller than
+    /// [`PointerOffset::MAX_NUMBER_OF_SEGMENTS`] or when the `offset` does not fit into the
+    /// remaining bits.
+    pub fn from_offset_and_segment_id(
+        offset: usize,
+        segment_id: usize,
+    ) -> Result<PointerOffset, PointerOffsetError> {
+        let origin = "PointerOffset::from_offset_and_segment_id()";
+        if segment_id >= Self::MAX_NUMBER_OF_SEGMENTS {
+            fail!(from origin, with PointerOffsetError::SegmentIdOutOfRange,
+                "Unable to create pointer offset since the segment id {} exceeds the maximum supported segment id {}.",
+                segment_id, Self::MAX_NUMBER_OF_SEGMENTS - 1);
+        }
+
+        if offset > Self::OFFSET_MASK {
+            fail!(from origin, with PointerOffsetError::OffsetOutOfRange,
+                "Unable to create pointer offset since the offset {} exceeds the maximum supported offset {}.",
+                offset, Self::OFFSET_MASK);
+        }
+
+        Ok(Self((segment_id << Self::OFFSET_BITS) | offset))
+    }
+
+    /// Returns the raw value that contains the offset and the segment id.
     pub fn value(&self) -> usize {
         self.0
     }
+
+    /// Returns the offset of the chunk relative to the start of its segment.
+    pub fn offset(&self) -> usize {
+        self.0 & Self::OFFSET_MASK
+    }
+
+    /// Returns the id of the segment that contains the chunk.
+    pub fn segment_id(&self) -> usize {
+        self.0 >> Self::OFFSET_BITS
+    }
 }
 
 enum_gen! { ShmAllocationError
@@ -46,6 +114,96 @@ pub enum ShmAllocatorInitError {
     AllocationFailed,
 }
 
+/// Describes how the memory managed by a [`ShmAllocator`] is utilized and how fragmented the
+/// free memory is. Acquired with [`ShmAllocator::fragmentation_report()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FragmentationReport {
+    total_memory: usize,
+    free_memory: usize,
+    largest_free_chunk: usize,
+    free_chunks_per_size_class: BTreeMap<usize, usize>,
+}
+
+impl FragmentationReport {
+    /// Creates a new report of a memory with the size `total_memory` without any free chunk.
+    pub fn new(total_memory: usize) -> Self {
+        Self {
+            total_memory,
+            ..Default::default()
+        }
+    }
+
+    /// Adds a contiguous free chunk of memory with the provided size to the report.
+    pub fn add_free_chunk(&mut self, size: usize) {
+        if size == 0 {
+            return;
+        }
+
+        self.free_memory += size;
+        self.largest_free_chunk = self.largest_free_chunk.max(size);
+        *self
+            .free_chunks_per_size_class
+            .entry(Self::size_class(size))
+            .or_insert(0) += 1;
+    }
+
+    /// Adds `number_of_chunks` contiguous free chunks of memory with the provided size to the
+    /// report.
+    pub fn add_free_chunks(&mut self, size: usize, number_of_chunks: usize) {
+        if size == 0 || number_of_chunks == 0 {
+            return;
+        }
+
+        self.free_memory += size * number_of_chunks;
+        self.largest_free_chunk = self.largest_free_chunk.max(size);
+        *self
+            .free_chunks_per_size_class
+            .entry(Self::size_class(size))
+            .or_insert(0) += number_of_chunks;
+    }
+
+    fn size_class(size: usize) -> usize {
+        1 << (usize::BITS - 1 - size.leading_zeros())
+    }
+
+    /// Returns the size of the memory managed by the allocator.
+    pub fn total_memory(&self) -> usize {
+        self.total_memory
+    }
+
+    /// Returns the amount of memory that is currently in use.
+    pub fn used_memory(&self) -> usize {
+        self.total_memory - self.free_memory
+    }
+
+    /// Returns the amount of memory that is currently free.
+    pub fn free_memory(&self) -> usize {
+        self.free_memory
+    }
+
+    /// Returns the size of the largest contiguous free chunk. No allocation larger than this
+    /// can succeed.
+    pub fn largest_free_chunk(&self) -> usize {
+        self.largest_free_chunk
+    }
+
+    /// Returns the number of free chunks per size class. The size class `c` contains all
+    /// free chunks with a size in the range `[c, 2 * c)`.
+    pub fn free_chunks_per_size_class(&self) -> &BTreeMap<usize, usize> {
+        &self.free_chunks_per_size_class
+    }
+
+    /// Returns the fragmentation of the free memory in the range `[0.0, 1.0]`. `0.0` means
+    /// that all free memory is available in one contiguous chunk.
+    pub fn fragmentation(&self) -> f64 {
+        if self.free_memory == 0 {
+            return 0.0;
+        }
+
+        1.0 - self.largest_free_chunk as f64 / self.free_memory as f64
+    }
+}
+
 /// Every allocator implementation must be relocatable. The allocator itself must be stored either
 /// in the same shared memory segment or in a separate shared memory segment of a different type
 /// but accessible by all participating processes.
@@ -107,4 +265,13 @@ pub trait ShmAllocator: Send + Sync + 'static {
     /// * [`ShmAllocator::init()`] must have been called before using this method
     ///
     unsafe fn deallocate(&self, distance: PointerOffset, layout: Layout);
+
+    /// Returns a [`FragmentationReport`] that describes the current utilization of the managed
+    /// memory.
+    ///
+    /// # Safety
+    ///
+    /// * [`ShmAllocator::init()`] must have been called before using this method
+    ///
+    unsafe fn fragmentation_report(&self) -> FragmentationReport;
 }
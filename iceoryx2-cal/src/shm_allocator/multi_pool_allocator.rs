@@ -0,0 +1,288 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`ShmAllocator`] that divides the memory into multiple pools with different bucket sizes,
+//! like the mempool configuration of iceoryx. An allocation is served from the pool with the
+//! smallest bucket that fits and falls back to the pools with larger buckets when this pool
+//! is exhausted. Variable sized allocations occupy therefore only a bucket of roughly their
+//! size instead of a bucket of the maximum size.
+
+use std::{alloc::Layout, ptr::NonNull};
+
+use crate::shm_allocator::{FragmentationReport, ShmAllocator, ShmAllocatorConfig};
+use iceoryx2_bb_elementary::allocator::{AllocationError, BaseAllocator};
+use iceoryx2_bb_elementary::math::align;
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_memory::pool_allocator::PoolAllocator;
+
+use super::sanitizer::{poison_memory_region, unpoison_memory_region};
+use super::{PointerOffset, ShmAllocationError, ShmAllocatorInitError};
+
+/// The maximum number of pools a [`MultiPoolAllocator`] can manage.
+pub const MAX_NUMBER_OF_POOLS: usize = 8;
+
+/// The configuration of a single pool of the [`MultiPoolAllocator`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PoolConfig {
+    /// The size of every bucket of the pool. It is rounded up to a multiple of the
+    /// `bucket_alignment` of the [`Config`].
+    pub bucket_size: usize,
+    /// The number of buckets the pool provides.
+    pub number_of_buckets: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Config {
+    /// The alignment of every bucket, it is the max supported alignment.
+    pub bucket_alignment: usize,
+    pools: [PoolConfig; MAX_NUMBER_OF_POOLS],
+    number_of_pools: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        let mut config = Self::new(8);
+        config.add_pool(128, 8);
+        config.add_pool(1024, 2);
+        config
+    }
+}
+
+impl ShmAllocatorConfig for Config {}
+
+impl Config {
+    /// Creates a new [`Config`] without any pool.
+    pub fn new(bucket_alignment: usize) -> Self {
+        Self {
+            bucket_alignment,
+            pools: [PoolConfig::default(); MAX_NUMBER_OF_POOLS],
+            number_of_pools: 0,
+        }
+    }
+
+    /// Adds a pool with `number_of_buckets` buckets of `bucket_size` bytes. The pools are kept
+    /// sorted by their bucket size. Returns `false` when the bucket size or the number of
+    /// buckets is zero or when [`MAX_NUMBER_OF_POOLS`] pools were already added.
+    pub fn add_pool(&mut self, bucket_size: usize, number_of_buckets: usize) -> bool {
+        if bucket_size == 0 || number_of_buckets == 0 || self.number_of_pools == MAX_NUMBER_OF_POOLS
+        {
+            return false;
+        }
+
+        let position = self
+            .pools()
+            .iter()
+            .position(|pool| pool.bucket_size > bucket_size)
+            .unwrap_or(self.number_of_pools);
+        self.pools
+            .copy_within(position..self.number_of_pools, position + 1);
+        self.pools[position] = PoolConfig {
+            bucket_size,
+            number_of_buckets,
+        };
+        self.number_of_pools += 1;
+        true
+    }
+
+    /// Returns the configured pools sorted by their bucket size.
+    pub fn pools(&self) -> &[PoolConfig] {
+        &self.pools[..self.number_of_pools]
+    }
+
+    fn bucket_layout(&self, pool: &PoolConfig) -> Layout {
+        let alignment = self.bucket_alignment.max(1);
+        unsafe { Layout::from_size_align_unchecked(align(pool.bucket_size, alignment), alignment) }
+    }
+}
+
+#[derive(Debug)]
+pub struct MultiPoolAllocator {
+    // a pool is `None` when the managed memory was too small to provide a single bucket
+    pools: [Option<PoolAllocator>; MAX_NUMBER_OF_POOLS],
+    // the pools are placed one after another, all offsets are relative to this address
+    start: usize,
+    base_address: usize,
+    bucket_alignment: usize,
+    max_supported_alignment_by_memory: usize,
+}
+
+impl MultiPoolAllocator {
+    fn pools(&self) -> impl Iterator<Item = &PoolAllocator> {
+        self.pools.iter().flatten()
+    }
+
+    fn pool_size(pool: &PoolAllocator) -> usize {
+        pool.bucket_size() * pool.number_of_buckets() as usize
+    }
+
+    fn pool_of(&self, address: usize) -> Option<&PoolAllocator> {
+        self.pools().find(|pool| {
+            pool.start_address() <= address
+                && address < pool.start_address() + Self::pool_size(pool)
+        })
+    }
+
+    /// Returns the number of pools that provide at least one bucket.
+    pub fn number_of_pools(&self) -> usize {
+        self.pools().count()
+    }
+
+    /// Returns the bucket size and the number of buckets of every pool, sorted by the bucket
+    /// size.
+    pub fn pool_configs(&self) -> impl Iterator<Item = PoolConfig> + '_ {
+        self.pools().map(|pool| PoolConfig {
+            bucket_size: pool.bucket_size(),
+            number_of_buckets: pool.number_of_buckets() as usize,
+        })
+    }
+
+    /// Returns the number of buckets that are currently in use over all pools.
+    pub fn number_of_used_buckets(&self) -> usize {
+        self.pools().map(|pool| pool.number_of_used_buckets()).sum()
+    }
+}
+
+impl ShmAllocator for MultiPoolAllocator {
+    type Configuration = Config;
+
+    fn management_size(_memory_size: usize, config: &Self::Configuration) -> usize {
+        config
+            .pools()
+            .iter()
+            .map(|pool| {
+                let layout = config.bucket_layout(pool);
+                PoolAllocator::memory_size(layout, layout.size() * pool.number_of_buckets)
+            })
+            .sum()
+    }
+
+    fn relative_start_address(&self) -> usize {
+        self.start - self.base_address
+    }
+
+    unsafe fn new_uninit(
+        max_supported_alignment_by_memory: usize,
+        managed_memory: NonNull<[u8]>,
+        config: &Self::Configuration,
+    ) -> Self {
+        let base_address = (managed_memory.as_ptr() as *mut u8) as usize;
+        let end = base_address + managed_memory.len();
+        let start = align(base_address, config.bucket_alignment.max(1));
+
+        let mut position = start;
+        let mut pools: [Option<PoolAllocator>; MAX_NUMBER_OF_POOLS] =
+            core::array::from_fn(|_| None);
+        for (n, pool) in config.pools().iter().enumerate() {
+            let layout = config.bucket_layout(pool);
+            let available_buckets = end.saturating_sub(position) / layout.size();
+            let number_of_buckets = pool.number_of_buckets.min(available_buckets);
+            if number_of_buckets == 0 {
+                continue;
+            }
+
+            let size = number_of_buckets * layout.size();
+            pools[n] = Some(PoolAllocator::new_uninit(
+                layout,
+                NonNull::new_unchecked(position as *mut u8),
+                size,
+            ));
+            position += size;
+        }
+
+        Self {
+            pools,
+            start,
+            base_address,
+            bucket_alignment: config.bucket_alignment.max(1),
+            max_supported_alignment_by_memory,
+        }
+    }
+
+    fn max_alignment(&self) -> usize {
+        self.bucket_alignment
+    }
+
+    unsafe fn init<Allocator: BaseAllocator>(
+        &self,
+        mgmt_allocator: &Allocator,
+    ) -> Result<(), ShmAllocatorInitError> {
+        let msg = "Unable to initialize allocator";
+        if self.max_supported_alignment_by_memory < self.max_alignment() {
+            fail!(from self, with ShmAllocatorInitError::MaxSupportedMemoryAlignmentInsufficient,
+                "{} since the required alignment {} exceeds the maximum supported alignment {} of the memory.",
+                msg, self.max_alignment(), self.max_supported_alignment_by_memory);
+        }
+
+        for pool in self.pools() {
+            fail!(from self, when pool.init(mgmt_allocator),
+                with ShmAllocatorInitError::AllocationFailed,
+                "{} since the allocation of the pool managment memory failed.", msg);
+
+            poison_memory_region(pool.start_address(), Self::pool_size(pool));
+        }
+
+        Ok(())
+    }
+
+    fn unique_id() -> u8 {
+        5
+    }
+
+    unsafe fn allocate(&self, layout: Layout) -> Result<PointerOffset, ShmAllocationError> {
+        let msg = "Unable to allocate memory";
+        if layout.align() > self.max_alignment() {
+            fail!(from self, with ShmAllocationError::ExceedsMaxSupportedAlignment,
+                "{} since an alignment of {} exceeds the maximum supported alignment of {}.",
+                msg, layout.align(), self.max_alignment());
+        }
+
+        let mut has_fitting_pool = false;
+        for pool in self
+            .pools()
+            .filter(|pool| layout.size() <= pool.bucket_size())
+        {
+            has_fitting_pool = true;
+            if let Ok(chunk) = pool.allocate(layout) {
+                let address = chunk.as_ptr() as *const u8 as usize;
+                unpoison_memory_region(address, pool.bucket_size());
+                return Ok(PointerOffset::new(address - self.start));
+            }
+        }
+
+        if has_fitting_pool {
+            fail!(from self, with ShmAllocationError::AllocationError(AllocationError::OutOfMemory),
+                "{} since every pool that fits {} bytes is exhausted.", msg, layout.size());
+        }
+
+        fail!(from self, with ShmAllocationError::AllocationError(AllocationError::SizeTooLarge),
+            "{} since no pool provides buckets that fit {} bytes.", msg, layout.size());
+    }
+
+    unsafe fn deallocate(&self, offset: PointerOffset, layout: Layout) {
+        let address = offset.value() + self.start;
+        if let Some(pool) = self.pool_of(address) {
+            poison_memory_region(address, pool.bucket_size());
+            pool.deallocate(NonNull::new_unchecked(address as *mut u8), layout);
+        }
+    }
+
+    unsafe fn fragmentation_report(&self) -> FragmentationReport {
+        let mut report = FragmentationReport::new(self.pools().map(Self::pool_size).sum());
+        for pool in self.pools() {
+            report.add_free_chunks(
+                pool.bucket_size(),
+                pool.number_of_buckets() as usize - pool.number_of_used_buckets(),
+            );
+        }
+        report
+    }
+}
@@ -12,13 +12,14 @@
 
 use std::{alloc::Layout, ptr::NonNull};
 
-use crate::shm_allocator::{ShmAllocator, ShmAllocatorConfig};
+use crate::shm_allocator::{FragmentationReport, ShmAllocator, ShmAllocatorConfig};
 use iceoryx2_bb_elementary::allocator::BaseAllocator;
 use iceoryx2_bb_log::fail;
 
+use super::sanitizer::{poison_memory_region, unpoison_memory_region};
 use super::{PointerOffset, ShmAllocationError, ShmAllocatorInitError};
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub struct Config {
     pub bucket_layout: Layout,
 }
@@ -51,6 +52,10 @@ impl PoolAllocator {
     pub fn number_of_buckets(&self) -> u32 {
         self.allocator.number_of_buckets()
     }
+
+    pub fn number_of_used_buckets(&self) -> usize {
+        self.allocator.number_of_used_buckets()
+    }
 }
 
 impl ShmAllocator for PoolAllocator {
@@ -101,6 +106,11 @@ impl ShmAllocator for PoolAllocator {
         fail!(from self, when self.allocator.init(mgmt_allocator),
             with ShmAllocatorInitError::AllocationFailed,
             "{} since the allocation of the allocator managment memory failed.", msg);
+
+        poison_memory_region(
+            self.allocator.start_address(),
+            self.bucket_size() * self.number_of_buckets() as usize,
+        );
         Ok(())
     }
 
@@ -117,15 +127,26 @@ impl ShmAllocator for PoolAllocator {
         }
 
         let chunk = fail!(from self, when self.allocator.allocate(layout), "{}.", msg);
+        unpoison_memory_region(chunk.as_ptr() as *const u8 as usize, self.bucket_size());
         Ok(PointerOffset::new(
             (chunk.as_ptr() as *const u8) as usize - self.allocator.start_address(),
         ))
     }
 
     unsafe fn deallocate(&self, offset: PointerOffset, layout: Layout) {
-        self.allocator.deallocate(
-            NonNull::new_unchecked((offset.value() + self.allocator.start_address()) as *mut u8),
-            layout,
+        let address = offset.value() + self.allocator.start_address();
+        poison_memory_region(address, self.bucket_size());
+        self.allocator
+            .deallocate(NonNull::new_unchecked(address as *mut u8), layout);
+    }
+
+    unsafe fn fragmentation_report(&self) -> FragmentationReport {
+        let number_of_buckets = self.number_of_buckets() as usize;
+        let mut report = FragmentationReport::new(number_of_buckets * self.bucket_size());
+        report.add_free_chunks(
+            self.bucket_size(),
+            number_of_buckets - self.number_of_used_buckets(),
         );
+        report
     }
 }
@@ -0,0 +1,117 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Annotations that inform memory error detectors about the state of the memory managed by a
+//! [`crate::shm_allocator::ShmAllocator`]. Free memory is poisoned so that every access to a
+//! chunk that is not loaned is reported and allocated memory is unpoisoned again.
+//!
+//! * feature `sanitizer_asan` - annotates the memory for the AddressSanitizer, requires that
+//!   the binary is built with `-Zsanitizer=address`
+//! * feature `sanitizer_valgrind` - annotates the memory for Valgrinds memcheck, the
+//!   annotations are no-ops when the binary does not run under Valgrind
+//!
+//! Without any of the features all functions are empty.
+
+#[cfg(feature = "sanitizer_asan")]
+mod asan {
+    extern "C" {
+        fn __asan_poison_memory_region(address: *const core::ffi::c_void, size: usize);
+        fn __asan_unpoison_memory_region(address: *const core::ffi::c_void, size: usize);
+    }
+
+    pub(super) unsafe fn poison(address: usize, size: usize) {
+        __asan_poison_memory_region(address as *const core::ffi::c_void, size)
+    }
+
+    pub(super) unsafe fn unpoison(address: usize, size: usize) {
+        __asan_unpoison_memory_region(address as *const core::ffi::c_void, size)
+    }
+}
+
+#[cfg(feature = "sanitizer_valgrind")]
+mod valgrind {
+    // VG_USERREQ_TOOL_BASE('M', 'C') of valgrind/memcheck.h
+    const MEMCHECK_BASE: usize = ((b'M' as usize) << 24) | ((b'C' as usize) << 16);
+    const MAKE_MEM_NOACCESS: usize = MEMCHECK_BASE;
+    const MAKE_MEM_UNDEFINED: usize = MEMCHECK_BASE + 1;
+
+    // the magic instruction sequence of valgrind/valgrind.h, it is a no-op when the process
+    // does not run under valgrind
+    #[cfg(target_arch = "x86_64")]
+    unsafe fn client_request(request: usize, address: usize, size: usize) {
+        let args: [usize; 6] = [request, address, size, 0, 0, 0];
+        core::arch::asm!(
+            "rol rdi, 3",
+            "rol rdi, 13",
+            "rol rdi, 61",
+            "rol rdi, 51",
+            "xchg rbx, rbx",
+            inout("rdx") 0usize => _,
+            in("rax") args.as_ptr(),
+            inout("rdi") 0usize => _,
+            options(nostack)
+        );
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    unsafe fn client_request(request: usize, address: usize, size: usize) {
+        let args: [usize; 6] = [request, address, size, 0, 0, 0];
+        core::arch::asm!(
+            "ror x12, x12, #3",
+            "ror x12, x12, #13",
+            "ror x12, x12, #51",
+            "ror x12, x12, #61",
+            "orr x10, x10, x10",
+            inout("x3") 0usize => _,
+            in("x4") args.as_ptr(),
+            inout("x12") 0usize => _,
+            options(nostack)
+        );
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    unsafe fn client_request(_request: usize, _address: usize, _size: usize) {}
+
+    pub(super) unsafe fn poison(address: usize, size: usize) {
+        client_request(MAKE_MEM_NOACCESS, address, size)
+    }
+
+    pub(super) unsafe fn unpoison(address: usize, size: usize) {
+        client_request(MAKE_MEM_UNDEFINED, address, size)
+    }
+}
+
+/// Marks the memory range as inaccessible, every access is reported until it is unpoisoned.
+///
+/// # Safety
+///
+///  * the memory range must be mapped and must not be accessed until it is unpoisoned
+#[allow(unused_variables)]
+pub unsafe fn poison_memory_region(address: usize, size: usize) {
+    #[cfg(feature = "sanitizer_asan")]
+    asan::poison(address, size);
+    #[cfg(feature = "sanitizer_valgrind")]
+    valgrind::poison(address, size);
+}
+
+/// Marks the memory range as accessible again.
+///
+/// # Safety
+///
+///  * the memory range must be mapped
+#[allow(unused_variables)]
+pub unsafe fn unpoison_memory_region(address: usize, size: usize) {
+    #[cfg(feature = "sanitizer_asan")]
+    asan::unpoison(address, size);
+    #[cfg(feature = "sanitizer_valgrind")]
+    valgrind::unpoison(address, size);
+}
@@ -0,0 +1,136 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`ShmAllocator`] whose underlying allocation strategy is selected at runtime with its
+//! [`Config`]. It allows to use different allocators for shared memories of the same type.
+
+use std::{alloc::Layout, ptr::NonNull};
+
+use crate::shm_allocator::*;
+
+use super::{
+    best_fit_allocator::BestFitAllocator, bump_per_cycle_allocator::BumpPerCycleAllocator,
+    multi_pool_allocator::MultiPoolAllocator, pool_allocator::PoolAllocator,
+};
+
+/// Selects the allocation strategy and its configuration.
+#[derive(Clone, Copy, Debug)]
+pub enum Config {
+    /// Uses the [`PoolAllocator`]
+    Pool(pool_allocator::Config),
+    /// Uses the [`BumpPerCycleAllocator`]
+    BumpPerCycle(bump_per_cycle_allocator::Config),
+    /// Uses the [`BestFitAllocator`]
+    BestFit(best_fit_allocator::Config),
+    /// Uses the [`MultiPoolAllocator`]
+    MultiPool(multi_pool_allocator::Config),
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::Pool(pool_allocator::Config::default())
+    }
+}
+
+impl ShmAllocatorConfig for Config {}
+
+#[derive(Debug)]
+pub enum SelectableAllocator {
+    Pool(PoolAllocator),
+    BumpPerCycle(BumpPerCycleAllocator),
+    BestFit(BestFitAllocator),
+    MultiPool(MultiPoolAllocator),
+}
+
+macro_rules! dispatch {
+    ($self:expr, $allocator:ident => $call:expr) => {
+        match $self {
+            SelectableAllocator::Pool($allocator) => $call,
+            SelectableAllocator::BumpPerCycle($allocator) => $call,
+            SelectableAllocator::BestFit($allocator) => $call,
+            SelectableAllocator::MultiPool($allocator) => $call,
+        }
+    };
+}
+
+impl ShmAllocator for SelectableAllocator {
+    type Configuration = Config;
+
+    fn management_size(memory_size: usize, config: &Self::Configuration) -> usize {
+        match config {
+            Config::Pool(c) => PoolAllocator::management_size(memory_size, c),
+            Config::BumpPerCycle(c) => BumpPerCycleAllocator::management_size(memory_size, c),
+            Config::BestFit(c) => BestFitAllocator::management_size(memory_size, c),
+            Config::MultiPool(c) => MultiPoolAllocator::management_size(memory_size, c),
+        }
+    }
+
+    fn relative_start_address(&self) -> usize {
+        dispatch!(self, a => a.relative_start_address())
+    }
+
+    unsafe fn new_uninit(
+        max_supported_alignment_by_memory: usize,
+        managed_memory: NonNull<[u8]>,
+        config: &Self::Configuration,
+    ) -> Self {
+        match config {
+            Config::Pool(c) => Self::Pool(PoolAllocator::new_uninit(
+                max_supported_alignment_by_memory,
+                managed_memory,
+                c,
+            )),
+            Config::BumpPerCycle(c) => Self::BumpPerCycle(BumpPerCycleAllocator::new_uninit(
+                max_supported_alignment_by_memory,
+                managed_memory,
+                c,
+            )),
+            Config::BestFit(c) => Self::BestFit(BestFitAllocator::new_uninit(
+                max_supported_alignment_by_memory,
+                managed_memory,
+                c,
+            )),
+            Config::MultiPool(c) => Self::MultiPool(MultiPoolAllocator::new_uninit(
+                max_supported_alignment_by_memory,
+                managed_memory,
+                c,
+            )),
+        }
+    }
+
+    fn max_alignment(&self) -> usize {
+        dispatch!(self, a => a.max_alignment())
+    }
+
+    unsafe fn init<Allocator: BaseAllocator>(
+        &self,
+        mgmt_allocator: &Allocator,
+    ) -> Result<(), ShmAllocatorInitError> {
+        dispatch!(self, a => a.init(mgmt_allocator))
+    }
+
+    fn unique_id() -> u8 {
+        4
+    }
+
+    unsafe fn allocate(&self, layout: Layout) -> Result<PointerOffset, ShmAllocationError> {
+        dispatch!(self, a => a.allocate(layout))
+    }
+
+    unsafe fn deallocate(&self, offset: PointerOffset, layout: Layout) {
+        dispatch!(self, a => a.deallocate(offset, layout))
+    }
+
+    unsafe fn fragmentation_report(&self) -> FragmentationReport {
+        dispatch!(self, a => a.fragmentation_report())
+    }
+}
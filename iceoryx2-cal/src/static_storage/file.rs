@@ -53,6 +53,12 @@ use iceoryx2_bb_posix::{
 
 const FINAL_PERMISSIONS: Permission = Permission::OWNER_READ;
 
+// a storage is finalized as soon as the owner can no longer write it, the read permissions of
+// the group and others are defined by the creator
+fn is_finalized(permission: Permission) -> bool {
+    permission.has(Permission::OWNER_READ) && !permission.has(Permission::OWNER_WRITE)
+}
+
 /// The custom configuration of the [`Storage`].
 #[derive(Clone, Debug)]
 pub struct Configuration {
@@ -105,6 +111,7 @@ impl crate::static_storage::StaticStorageConfiguration for Configuration {}
 #[derive(Debug)]
 pub struct Locked {
     static_storage: Storage,
+    final_permission: Permission,
 }
 
 impl NamedConcept for Locked {
@@ -128,10 +135,10 @@ impl StaticStorageLocked<Storage> for Locked {
                 msg, contents.len(), bytes_written);
         }
 
-        fail!(from self, when self.static_storage.file.set_permission(FINAL_PERMISSIONS),
+        fail!(from self, when self.static_storage.file.set_permission(self.final_permission),
                 map FileSetPermissionError::InsufficientPermissions => StaticStorageUnlockError::InsufficientPermissions,
                 unmatched StaticStorageUnlockError::InternalError,
-                "{} due to a failure while updating the permissions to {}.", msg, FINAL_PERMISSIONS);
+                "{} due to a failure while updating the permissions to {}.", msg, self.final_permission);
 
         self.static_storage.len = contents.len() as u64;
 
@@ -238,7 +245,7 @@ impl crate::named_concept::NamedConceptMgmt for Storage {
             .iter()
             .filter(|entry| {
                 let metadata = entry.metadata();
-                metadata.file_type() == FileType::File && metadata.permission() == FINAL_PERMISSIONS
+                metadata.file_type() == FileType::File && is_finalized(metadata.permission())
             })
             .filter_map(|entry| config.extract_name_from_file(entry.name()))
             .collect())
@@ -277,7 +284,7 @@ impl crate::named_concept::NamedConceptMgmt for Storage {
         }
         let metadata = metadata.unwrap();
 
-        if metadata.file_type() == FileType::File && metadata.permission() == FINAL_PERMISSIONS {
+        if metadata.file_type() == FileType::File && is_finalized(metadata.permission()) {
             return Ok(true);
         }
 
@@ -341,6 +348,7 @@ impl crate::static_storage::StaticStorage for Storage {
 pub struct Builder {
     storage_name: FileName,
     has_ownership: bool,
+    permission: Permission,
     config: Configuration,
 }
 
@@ -349,6 +357,7 @@ impl crate::named_concept::NamedConceptBuilder<Storage> for Builder {
         Self {
             storage_name: *storage_name,
             has_ownership: true,
+            permission: FINAL_PERMISSIONS,
             config: <Configuration as Default>::default(),
         }
     }
@@ -365,6 +374,12 @@ impl crate::static_storage::StaticStorageBuilder<Storage> for Builder {
         self
     }
 
+    fn permission(mut self, value: Permission) -> Self {
+        self.permission =
+            FINAL_PERMISSIONS | (value & (Permission::GROUP_READ | Permission::OTHERS_READ));
+        self
+    }
+
     fn create_locked(self) -> Result<Locked, StaticStorageCreateError> {
         let directory_permission = Permission::OWNER_ALL | Permission::GROUP_ALL;
 
@@ -397,6 +412,7 @@ impl crate::static_storage::StaticStorageBuilder<Storage> for Builder {
                 file,
                 len: 0,
             },
+            final_permission: self.permission,
         })
     }
 
@@ -413,7 +429,7 @@ impl crate::static_storage::StaticStorageBuilder<Storage> for Builder {
             when file.metadata(), with StaticStorageOpenError::Read,
             "{} due to a failure while reading the files metadata.", msg);
 
-        if metadata.permission() != FINAL_PERMISSIONS {
+        if !is_finalized(metadata.permission()) {
             fail!(from origin, with StaticStorageOpenError::IsLocked,
                 "{} since the static storage is still being created (in locked state), try later.", msg);
         }
@@ -14,11 +14,13 @@
 //! identified by a name.
 
 pub mod file;
+pub mod persistent_file;
 pub mod process_local;
 
 use std::fmt::Debug;
 
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::permission::Permission;
 use iceoryx2_bb_system_types::file_name::*;
 
 use crate::named_concept::{
@@ -68,6 +70,12 @@ pub trait StaticStorageBuilder<T: StaticStorage>: Sized + NamedConceptBuilder<T>
     /// Defines if a newly created [`StaticStorage`] owns the underlying resources
     fn has_ownership(self, value: bool) -> Self;
 
+    /// Defines which read permissions a newly created [`StaticStorage`] grants when it is
+    /// finalized, a [`StaticStorage`] is never writable after it was created. It is ignored by
+    /// implementations that are not backed by a file system resource. By default only the
+    /// owner can read it.
+    fn permission(self, value: Permission) -> Self;
+
     /// Creates an owning [`StaticStorage`]. When its lifetime ends the underlying resources will
     /// be removed.
     fn create(self, contents: &[u8]) -> Result<T, StaticStorageCreateError> {
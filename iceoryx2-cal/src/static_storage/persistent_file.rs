@@ -0,0 +1,489 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! File based implementation of [`StaticStorage`] that updates its contents atomically.
+//!
+//! In contrast to [`crate::static_storage::file`] the contents are written into a temporary
+//! file first which is synced to the device and afterwards renamed into the final file. A
+//! reader therefore either sees a complete static storage or none at all, even when the
+//! creating process crashes or the system loses power during the creation.
+//!
+//! When the path hint points to a persistent file system and the storage is created without
+//! ownership, see [`StaticStorageBuilder::has_ownership()`], the static storage survives
+//! reboots. This allows systems to pre-provision their communication graph.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_system_types::file_name::FileName;
+//! use iceoryx2_bb_system_types::path::Path;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//! use iceoryx2_cal::static_storage::persistent_file::*;
+//!
+//! let custom_config = Configuration::default()
+//!                         .path_hint(Path::new(b"/tmp").unwrap());
+//!
+//! let storage_name = FileName::new(b"myPersistentStaticStorage").unwrap();
+//! let owner = Builder::new(&storage_name)
+//!                 .config(&custom_config)
+//!                 .create(b"some storage content").unwrap();
+//!
+//! // usually a different process
+//! let reader = Builder::new(&storage_name)
+//!                 .config(&custom_config)
+//!                 .open().unwrap();
+//!
+//! let mut content = vec![0u8; reader.len() as usize];
+//! reader.read(content.as_mut_slice()).unwrap();
+//! ```
+
+pub use crate::named_concept::*;
+pub use crate::static_storage::*;
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_log::{fail, fatal_panic, trace, warn};
+use iceoryx2_bb_posix::{
+    directory::*, file::*, file_descriptor::FileDescriptorManagement, file_type::FileType,
+};
+
+const FINAL_PERMISSIONS: Permission = Permission::OWNER_READ;
+const TEMPORARY_SUFFIX: &[u8] = b".tmp";
+
+// the final file is always finalized since the permissions are adjusted before it is renamed,
+// the check keeps locked storages of other implementations in the same directory out of the list
+fn is_finalized(permission: Permission) -> bool {
+    permission.has(Permission::OWNER_READ) && !permission.has(Permission::OWNER_WRITE)
+}
+
+// the temporary file carries an additional suffix so that it is never listed or opened as a
+// complete static storage
+fn temporary_path_for(storage_name: &FileName, config: &Configuration) -> FilePath {
+    let mut path = config.path_for(storage_name);
+    fatal_panic!(from config, when path.push_bytes(TEMPORARY_SUFFIX),
+        "The path \"{}\" in combination with the temporary suffix exceeds the maximum supported path length.", path);
+    path
+}
+
+/// The custom configuration of the [`Storage`].
+#[derive(Clone, Debug)]
+pub struct Configuration {
+    path: Path,
+    suffix: FileName,
+    prefix: FileName,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Configuration {
+            path: Storage::default_path_hint(),
+            suffix: Storage::default_suffix(),
+            prefix: Storage::default_prefix(),
+        }
+    }
+}
+
+impl crate::named_concept::NamedConceptConfiguration for Configuration {
+    fn prefix(mut self, value: FileName) -> Self {
+        self.prefix = value;
+        self
+    }
+
+    fn get_prefix(&self) -> &FileName {
+        &self.prefix
+    }
+
+    fn suffix(mut self, value: FileName) -> Self {
+        self.suffix = value;
+        self
+    }
+
+    fn path_hint(mut self, value: Path) -> Self {
+        self.path = value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl crate::static_storage::StaticStorageConfiguration for Configuration {}
+
+#[derive(Debug)]
+pub struct Locked {
+    name: FileName,
+    config: Configuration,
+    has_ownership: bool,
+    // owns the temporary file so that it is removed when the storage is never unlocked
+    temporary_file: File,
+    final_permission: Permission,
+}
+
+impl NamedConcept for Locked {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl StaticStorageLocked<Storage> for Locked {
+    fn unlock(mut self, contents: &[u8]) -> Result<Storage, StaticStorageUnlockError> {
+        let msg = "Failed to unlock storage";
+        let bytes_written = fail!(from self, when self.temporary_file.write(contents),
+            map FileWriteError::InsufficientPermissions => StaticStorageUnlockError::InsufficientPermissions;
+                FileWriteError::NoSpaceLeft => StaticStorageUnlockError::NoSpaceLeft,
+            unmatched StaticStorageUnlockError::InternalError,
+            "{} due to a failure while writing the contents.", msg);
+
+        if bytes_written != contents.len() as u64 {
+            fail!(from self, with StaticStorageUnlockError::NoSpaceLeft,
+                "{} since the contents length is {} bytes but only {} bytes could be written to the file.",
+                msg, contents.len(), bytes_written);
+        }
+
+        fail!(from self, when self.temporary_file.set_permission(self.final_permission),
+                map FileSetPermissionError::InsufficientPermissions => StaticStorageUnlockError::InsufficientPermissions,
+                unmatched StaticStorageUnlockError::InternalError,
+                "{} due to a failure while updating the permissions to {}.", msg, self.final_permission);
+
+        fail!(from self, when self.temporary_file.flush(),
+                with StaticStorageUnlockError::InternalError,
+                "{} since the contents could not be synced to the device.", msg);
+
+        let temporary_path = temporary_path_for(&self.name, &self.config);
+        fail!(from self, when File::rename(&temporary_path, &self.config.path_for(&self.name)),
+            map FileRenameError::InsufficientPermissions => StaticStorageUnlockError::InsufficientPermissions;
+                FileRenameError::NoSpaceLeft => StaticStorageUnlockError::NoSpaceLeft,
+            unmatched StaticStorageUnlockError::InternalError,
+            "{} since the temporary file could not be renamed into the final static storage.", msg);
+        self.temporary_file.release_ownership();
+
+        Ok(Storage {
+            name: self.name,
+            config: self.config,
+            has_ownership: self.has_ownership,
+            file: self.temporary_file,
+            len: contents.len() as u64,
+        })
+    }
+}
+
+/// Implements [`StaticStorage`] for a file that is updated atomically.
+#[derive(Debug)]
+pub struct Storage {
+    name: FileName,
+    config: Configuration,
+    has_ownership: bool,
+    file: File,
+    len: u64,
+}
+
+impl Drop for Storage {
+    fn drop(&mut self) {
+        if self.has_ownership {
+            match unsafe { Self::remove_cfg(&self.name, &self.config) } {
+                Ok(true) => (),
+                Ok(false) => {
+                    warn!(from self, "The static storage was already removed. This could be caused by a corrupted system.");
+                }
+                Err(v) => {
+                    warn!(from self, "Unable to remove owned static storage due to {:?}. This may cause a leak and subsequent failures.", v);
+                }
+            }
+        }
+    }
+}
+
+impl crate::named_concept::NamedConcept for Storage {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl crate::named_concept::NamedConceptMgmt for Storage {
+    type Configuration = Configuration;
+
+    unsafe fn remove_cfg(
+        storage_name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<bool, NamedConceptRemoveError> {
+        let msg = format!("Unable to release static storage \"{}\"", storage_name);
+        let origin = "static_storage::persistent_file::Storage::remove_cfg()";
+
+        let file_path = config.path_for(storage_name);
+
+        let mut file = match FileBuilder::new(&file_path).open_existing(AccessMode::Read) {
+            Ok(f) => f,
+            Err(FileOpenError::FileDoesNotExist) => return Ok(false),
+            Err(v) => {
+                fail!(from origin, with NamedConceptRemoveError::InternalError,
+                    "{} since the file could not be opened for permission adjustment ({:?}).", msg, v);
+            }
+        };
+
+        fail!(from origin, when file.set_permission(Permission::OWNER_ALL),
+                with NamedConceptRemoveError::InternalError,
+                "{} since the permissions could not be adjusted.", msg);
+
+        match File::remove(&file_path) {
+            Ok(v) => Ok(v),
+            Err(FileRemoveError::InsufficientPermissions)
+            | Err(FileRemoveError::PartOfReadOnlyFileSystem) => {
+                fail!(from origin, with NamedConceptRemoveError::InsufficientPermissions,
+                        "{} due to insufficient permissions.", msg);
+            }
+            Err(v) => {
+                fail!(from origin, with NamedConceptRemoveError::InternalError,
+                        "{} due to unknown failure ({:?}).", msg, v);
+            }
+        }
+    }
+
+    fn list_cfg(config: &Configuration) -> Result<Vec<FileName>, NamedConceptListError> {
+        let msg = "Unable to list all storages";
+        let origin = "static_storage::persistent_file::Storage::list_cfg()";
+        let directory = match Directory::new(&config.path) {
+            Ok(directory) => directory,
+            Err(DirectoryOpenError::InsufficientPermissions) => {
+                fail!(from origin, with NamedConceptListError::InsufficientPermissions,
+                    "{} due to insufficient permissions to read the storage directory.", msg);
+            }
+            Err(DirectoryOpenError::DoesNotExist) => {
+                return Ok(vec![]);
+            }
+            Err(v) => {
+                fail!(from origin, with NamedConceptListError::InternalError,
+                    "{} due to failure ({:?}) while reading the storage directory (\"{}\").", msg, v, config.path);
+            }
+        };
+
+        let entries = fail!(from origin,
+                            when directory.contents(),
+                            map DirectoryReadError::InsufficientPermissions => NamedConceptListError::InsufficientPermissions,
+                            unmatched NamedConceptListError::InternalError,
+                            "{} due to a failure while reading the storage directory (\"{}\") contents.", msg, config.path);
+
+        // temporary files are skipped since their name does not end with the configured suffix
+        Ok(entries
+            .iter()
+            .filter(|entry| {
+                let metadata = entry.metadata();
+                metadata.file_type() == FileType::File && is_finalized(metadata.permission())
+            })
+            .filter_map(|entry| config.extract_name_from_file(entry.name()))
+            .collect())
+    }
+
+    fn does_exist_cfg(
+        storage_name: &FileName,
+        config: &Configuration,
+    ) -> Result<bool, NamedConceptDoesExistError> {
+        let msg = format!("Unable to check if storage \"{}\" exists", storage_name);
+        let origin = "static_storage::persistent_file::Storage::does_exist_cfg()";
+
+        match File::does_exist(&config.path_for(storage_name)) {
+            Ok(true) => return Ok(true),
+            Ok(false) => (),
+            Err(v) => {
+                fail!(from origin, with NamedConceptDoesExistError::UnderlyingResourcesCorrupted,
+                    "{} due to an internal failure ({:?}), is the static storage in a corrupted state?", msg, v);
+            }
+        };
+
+        match File::does_exist(&temporary_path_for(storage_name, config)) {
+            Ok(false) => Ok(false),
+            Ok(true) => {
+                fail!(from origin, with NamedConceptDoesExistError::UnderlyingResourcesBeingSetUp,
+                    "{} since the underlying resources are currently being created or the creation process hangs.", msg);
+            }
+            Err(v) => {
+                fail!(from origin, with NamedConceptDoesExistError::UnderlyingResourcesCorrupted,
+                    "{} due to an internal failure ({:?}), is the static storage in a corrupted state?", msg, v);
+            }
+        }
+    }
+
+    fn remove_path_hint(value: &Path) -> Result<(), NamedConceptPathHintRemoveError> {
+        crate::named_concept::remove_path_hint(value)
+    }
+}
+
+impl crate::static_storage::StaticStorage for Storage {
+    type Builder = Builder;
+    type Locked = Locked;
+
+    fn release_ownership(&mut self) {
+        self.has_ownership = false
+    }
+
+    fn acquire_ownership(&mut self) {
+        self.has_ownership = true
+    }
+
+    fn len(&self) -> u64 {
+        self.len
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn read(&self, content: &mut [u8]) -> Result<(), StaticStorageReadError> {
+        let msg = "Unable to read from static storage";
+        let len = self.len();
+
+        if len > content.len() as u64 {
+            fail!(from self, with StaticStorageReadError::BufferTooSmall,
+                "{} since a buffer with a size of a least {} bytes is required to read the file but a buffer of size {} bytes was provided.",
+                msg, len, content.len());
+        }
+
+        let bytes_read = fail!(from self, when self.file.read(content),
+                                with StaticStorageReadError::ReadError,
+                                "{} due to a failure while reading the underlying file.", msg);
+
+        if bytes_read != len {
+            fail!(from self, with StaticStorageReadError::StaticStorageWasModified,
+                        "{} since the expected read size is {} bytes but {} bytes were read instead. Was the static storage file modified?",
+                        msg, len, bytes_read);
+        }
+
+        Ok(())
+    }
+}
+
+/// Creates [`Storage`] or [`Locked`], a static storage that is not yet set. When
+/// [`Builder::has_ownership()`] is set the constructs owns the static storage and removes it
+/// when it goes out of scope. To persist the static storage across reboots it must be created
+/// without ownership.
+#[derive(Debug)]
+pub struct Builder {
+    storage_name: FileName,
+    has_ownership: bool,
+    permission: Permission,
+    config: Configuration,
+}
+
+impl crate::named_concept::NamedConceptBuilder<Storage> for Builder {
+    fn new(storage_name: &FileName) -> Self {
+        Self {
+            storage_name: *storage_name,
+            has_ownership: true,
+            permission: FINAL_PERMISSIONS,
+            config: <Configuration as Default>::default(),
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = config.clone();
+        self
+    }
+}
+
+impl crate::static_storage::StaticStorageBuilder<Storage> for Builder {
+    fn has_ownership(mut self, value: bool) -> Self {
+        self.has_ownership = value;
+        self
+    }
+
+    fn permission(mut self, value: Permission) -> Self {
+        self.permission =
+            FINAL_PERMISSIONS | (value & (Permission::GROUP_READ | Permission::OTHERS_READ));
+        self
+    }
+
+    fn create_locked(self) -> Result<Locked, StaticStorageCreateError> {
+        let directory_permission = Permission::OWNER_ALL | Permission::GROUP_ALL;
+
+        let msg = format!("Unable to create target directory \"{}\"", self.config.path);
+        if !fail!(from self, when Directory::does_exist(&self.config.path),
+            with StaticStorageCreateError::Creation,
+               "{} since the system is unable to determine if the directory even exists.", msg)
+        {
+            fail!(from self, when Directory::create(&self.config.path, directory_permission ),
+                with StaticStorageCreateError::Creation,
+                "{} due to a failure while creating the service root directory.", msg);
+            trace!(from self, "Created service root directory \"{}\" since it did not exist before.", self.config.path);
+        }
+
+        let msg = format!("Unable to create static storage \"{}\"", self.storage_name);
+        let temporary_file = fail!(from self, when
+            FileBuilder::new(&temporary_path_for(&self.storage_name, &self.config))
+            .has_ownership(true)
+            .creation_mode(CreationMode::CreateExclusive)
+            .permission(Permission::OWNER_ALL)
+            .create(),
+            map FileCreationError::FileAlreadyExists => StaticStorageCreateError::AlreadyExists;
+                FileCreationError::InsufficientPermissions => StaticStorageCreateError::InsufficientPermissions,
+            unmatched StaticStorageCreateError::Creation,
+            "{} due to a failure while creating the temporary file.", msg);
+
+        // checked after the temporary file was created exclusively, otherwise a concurrent
+        // creator could finish in between and its storage would be replaced during unlock
+        if fail!(from self, when File::does_exist(&self.config.path_for(&self.storage_name)),
+            with StaticStorageCreateError::Creation,
+            "{} since the system is unable to determine if the static storage already exists.", msg)
+        {
+            fail!(from self, with StaticStorageCreateError::AlreadyExists,
+                "{} since the static storage already exists.", msg);
+        }
+
+        Ok(Locked {
+            name: self.storage_name,
+            config: self.config,
+            has_ownership: self.has_ownership,
+            temporary_file,
+            final_permission: self.permission,
+        })
+    }
+
+    fn open(self) -> Result<Storage, StaticStorageOpenError> {
+        let msg = "Unable to open static storage";
+        let origin = "static_storage::persistent_file::Builder::open()";
+
+        let file = match FileBuilder::new(&self.config.path_for(&self.storage_name))
+            .open_existing(AccessMode::Read)
+        {
+            Ok(file) => file,
+            Err(FileOpenError::FileDoesNotExist) => {
+                if File::does_exist(&temporary_path_for(&self.storage_name, &self.config))
+                    == Ok(true)
+                {
+                    fail!(from origin, with StaticStorageOpenError::IsLocked,
+                        "{} since the static storage is still being created (in locked state), try later.", msg);
+                }
+
+                fail!(from origin, with StaticStorageOpenError::DoesNotExist,
+                    "{} since the static storage does not exist.", msg);
+            }
+            Err(v) => {
+                fail!(from origin, with StaticStorageOpenError::Read,
+                    "{} due to a failure while opening the file ({:?}).", msg, v);
+            }
+        };
+
+        let metadata = fail!(from origin,
+            when file.metadata(), with StaticStorageOpenError::Read,
+            "{} due to a failure while reading the files metadata.", msg);
+
+        Ok(Storage {
+            name: self.storage_name,
+            config: self.config,
+            has_ownership: self.has_ownership,
+            file,
+            len: metadata.size(),
+        })
+    }
+}
@@ -40,6 +40,7 @@ pub use crate::named_concept::*;
 pub use crate::static_storage::*;
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_bb_posix::mutex::*;
+use iceoryx2_bb_posix::permission::Permission;
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -301,6 +302,10 @@ impl StaticStorageBuilder<Storage> for Builder {
         self
     }
 
+    fn permission(self, _value: Permission) -> Self {
+        self
+    }
+
     fn open(self) -> Result<Storage, StaticStorageOpenError> {
         let msg = "Failed to open static storage";
 
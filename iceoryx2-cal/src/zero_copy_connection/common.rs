@@ -12,25 +12,29 @@
 
 #[doc(hidden)]
 pub mod details {
-    use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicU8};
+    use iceoryx2_bb_container::vec::RelocatableVec;
+    use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicU8, IoxAtomicUsize};
     use std::cell::UnsafeCell;
     use std::fmt::Debug;
     use std::marker::PhantomData;
     use std::sync::atomic::Ordering;
+    use std::time::Duration;
 
     use crate::dynamic_storage::{
         DynamicStorage, DynamicStorageBuilder, DynamicStorageCreateError, DynamicStorageOpenError,
         DynamicStorageOpenOrCreateError,
     };
     use crate::named_concept::*;
+    use crate::shm_allocator::PointerOffsetError;
     pub use crate::zero_copy_connection::*;
     use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
     use iceoryx2_bb_lock_free::spsc::{
         index_queue::RelocatableIndexQueue,
         safely_overflowing_index_queue::RelocatableSafelyOverflowingIndexQueue,
     };
-    use iceoryx2_bb_log::{fail, fatal_panic};
+    use iceoryx2_bb_log::{fail, fatal_panic, warn};
     use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+    use iceoryx2_bb_posix::permission::Permission;
 
     use self::used_chunk_list::RelocatableUsedChunkList;
 
@@ -155,9 +159,13 @@ pub mod details {
         submission_channel: RelocatableSafelyOverflowingIndexQueue,
         completion_channel: RelocatableIndexQueue,
         used_chunk_list: RelocatableUsedChunkList,
+        // the sample size of every segment, it is set by the sender when it sends the first
+        // sample of a segment
+        segment_sample_sizes: RelocatableVec<IoxAtomicUsize>,
         max_borrowed_samples: usize,
         sample_size: usize,
         number_of_samples: usize,
+        number_of_segments: usize,
         state: IoxAtomicU8,
         init_state: IoxAtomicU64,
         enable_safe_overflow: bool,
@@ -171,6 +179,7 @@ pub mod details {
             max_borrowed_samples: usize,
             sample_size: usize,
             number_of_samples: usize,
+            number_of_segments: usize,
         ) -> Self {
             Self {
                 submission_channel: unsafe {
@@ -181,13 +190,17 @@ pub mod details {
                 completion_channel: unsafe {
                     RelocatableIndexQueue::new_uninit(completion_channel_buffer_capacity)
                 },
-                used_chunk_list: unsafe { RelocatableUsedChunkList::new_uninit(number_of_samples) },
+                used_chunk_list: unsafe {
+                    RelocatableUsedChunkList::new_uninit(number_of_samples * number_of_segments)
+                },
+                segment_sample_sizes: unsafe { RelocatableVec::new_uninit(number_of_segments) },
                 state: IoxAtomicU8::new(State::None.value()),
                 init_state: IoxAtomicU64::new(0),
                 enable_safe_overflow,
                 sample_size,
                 max_borrowed_samples,
                 number_of_samples,
+                number_of_segments,
             }
         }
 
@@ -195,12 +208,52 @@ pub mod details {
             submission_channel_buffer_capacity: usize,
             completion_channel_buffer_capacity: usize,
             number_of_samples: usize,
+            number_of_segments: usize,
         ) -> usize {
             RelocatableIndexQueue::const_memory_size(completion_channel_buffer_capacity)
                 + RelocatableSafelyOverflowingIndexQueue::const_memory_size(
                     submission_channel_buffer_capacity,
                 )
-                + RelocatableUsedChunkList::const_memory_size(number_of_samples)
+                + RelocatableUsedChunkList::const_memory_size(
+                    number_of_samples * number_of_segments,
+                )
+                + RelocatableVec::<IoxAtomicUsize>::const_memory_size(number_of_segments)
+        }
+
+        fn segment_id_and_offset(&self, ptr: PointerOffset) -> (usize, usize) {
+            // with a single segment the whole value is the offset so that the full range is
+            // available on platforms with a small pointer width
+            match self.number_of_segments {
+                1 => (0, ptr.value()),
+                _ => (ptr.segment_id(), ptr.offset()),
+            }
+        }
+
+        fn pointer_offset(
+            &self,
+            segment_id: usize,
+            offset: usize,
+        ) -> Result<PointerOffset, PointerOffsetError> {
+            match self.number_of_segments {
+                1 => Ok(PointerOffset::new(offset)),
+                _ => PointerOffset::from_offset_and_segment_id(offset, segment_id),
+            }
+        }
+
+        // the index of the chunk in the used chunk list, every segment owns a range of
+        // `number_of_samples` entries
+        fn used_chunk_index(&self, ptr: PointerOffset) -> Option<usize> {
+            let (segment_id, offset) = self.segment_id_and_offset(ptr);
+            let sample_size = self
+                .segment_sample_sizes
+                .get(segment_id)?
+                .load(Ordering::Relaxed);
+            let chunk = offset.checked_div(sample_size)?;
+
+            match chunk < self.number_of_samples {
+                true => Some(segment_id * self.number_of_samples + chunk),
+                false => None,
+            }
         }
     }
 
@@ -212,6 +265,8 @@ pub mod details {
         max_borrowed_samples: usize,
         sample_size: usize,
         number_of_samples: usize,
+        number_of_segments: usize,
+        permission: Permission,
         config: Configuration<Storage>,
     }
 
@@ -229,6 +284,7 @@ pub mod details {
                 self.submission_channel_size(),
                 self.completion_channel_size(),
                 self.number_of_samples,
+                self.number_of_segments,
             );
 
             let dynamic_storage_config = self.config.convert();
@@ -239,6 +295,7 @@ pub mod details {
         >>::new(&self.name)
         .config(&dynamic_storage_config)
         .supplementary_size(supplementary_size)
+        .permission(self.permission)
         .initializer(|data, allocator| {
             fatal_panic!(from self, when unsafe { data.submission_channel.init(allocator) },
                         "{} since the receive channel allocation failed. - This is an implementation bug!", msg);
@@ -246,6 +303,11 @@ pub mod details {
                         "{} since the retrieve channel allocation failed. - This is an implementation bug!", msg);
             fatal_panic!(from self, when unsafe { data.used_chunk_list.init(allocator) },
                         "{} since the used chunk list allocation failed. - This is an implementation bug!", msg);
+            fatal_panic!(from self, when unsafe { data.segment_sample_sizes.init(allocator) },
+                        "{} since the segment sample size allocation failed. - This is an implementation bug!", msg);
+            for _ in 0..self.number_of_segments {
+                unsafe { data.segment_sample_sizes.push(IoxAtomicUsize::new(self.sample_size)) };
+            }
 
             true
         })
@@ -257,6 +319,7 @@ pub mod details {
                                     self.max_borrowed_samples,
                                     self.sample_size,
                                     self.number_of_samples,
+                                    self.number_of_segments,
                                 )
             );
 
@@ -314,6 +377,12 @@ pub mod details {
                         "{} since the requested number of samples is set to {} but should be set to {}.",
                         msg, self.number_of_samples, storage.get().number_of_samples);
                 }
+
+                if storage.get().number_of_segments != self.number_of_segments {
+                    fail!(from self, with ZeroCopyCreationError::IncompatibleNumberOfSegments,
+                        "{} since the requested number of segments is set to {} but should be set to {}.",
+                        msg, self.number_of_segments, storage.get().number_of_segments);
+                }
             }
 
             Ok(storage)
@@ -363,6 +432,8 @@ pub mod details {
                 max_borrowed_samples: DEFAULT_MAX_BORROWED_SAMPLES,
                 sample_size: 0,
                 number_of_samples: 0,
+                number_of_segments: 1,
+                permission: Permission::OWNER_ALL,
                 config: Configuration::default(),
             }
         }
@@ -396,6 +467,16 @@ pub mod details {
             self
         }
 
+        fn max_supported_shared_memory_segments(mut self, value: usize) -> Self {
+            self.number_of_segments = value.clamp(1, PointerOffset::MAX_NUMBER_OF_SEGMENTS);
+            self
+        }
+
+        fn permission(mut self, value: Permission) -> Self {
+            self.permission = value;
+            self
+        }
+
         fn create_sender(
             mut self,
             sample_size: usize,
@@ -474,34 +555,49 @@ pub mod details {
     }
 
     impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopySender for Sender<Storage> {
-        fn try_send(&self, ptr: PointerOffset) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+        fn try_send(
+            &self,
+            ptr: PointerOffset,
+            sample_size: usize,
+        ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
             let msg = "Unable to send sample";
+            let storage = self.storage.get();
 
-            if !self.storage.get().enable_safe_overflow
-                && self.storage.get().submission_channel.is_full()
-            {
+            if !storage.enable_safe_overflow && storage.submission_channel.is_full() {
                 fail!(from self, with ZeroCopySendError::ReceiveBufferFull,
                              "{} since the receive buffer is full.", msg);
             }
 
-            if !self
-                .storage
-                .get()
-                .used_chunk_list
-                .insert(ptr.value() / self.storage.get().sample_size)
-            {
+            let (segment_id, _) = storage.segment_id_and_offset(ptr);
+            match storage.segment_sample_sizes.get(segment_id) {
+                Some(segment_sample_size) => {
+                    segment_sample_size.store(sample_size, Ordering::Relaxed)
+                }
+                None => {
+                    fail!(from self, with ZeroCopySendError::ConnectionCorrupted,
+                        "{} since the segment id {} exceeds the number of supported segments {}.",
+                        msg, segment_id, storage.number_of_segments);
+                }
+            }
+
+            let is_inserted = match storage.used_chunk_index(ptr) {
+                Some(index) => storage.used_chunk_list.insert(index),
+                None => false,
+            };
+
+            if !is_inserted {
                 fail!(from self, with ZeroCopySendError::UsedChunkListFull,
                     "{} since the used chunk list is full.", msg);
             }
 
-            match unsafe { self.storage.get().submission_channel.push(ptr.value()) } {
+            match unsafe { storage.submission_channel.push(ptr.value()) } {
                 Some(v) => {
-                    if !self
-                        .storage
-                        .get()
-                        .used_chunk_list
-                        .remove(v / self.storage.get().sample_size)
-                    {
+                    let is_removed = match storage.used_chunk_index(PointerOffset::new(v)) {
+                        Some(index) => storage.used_chunk_list.remove(index),
+                        None => false,
+                    };
+
+                    if !is_removed {
                         fail!(from self, with ZeroCopySendError::ConnectionCorrupted,
                         "{} since an invalid offset was returned on overflow.", msg);
                     }
@@ -515,28 +611,56 @@ pub mod details {
         fn blocking_send(
             &self,
             ptr: PointerOffset,
+            sample_size: usize,
+        ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+            if !self.storage.get().enable_safe_overflow {
+                let msg = "Unable to send sample";
+                let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                    with ZeroCopySendError::WaitFailed,
+                    "{} since the adaptive wait could not be created.", msg);
+                fail!(from self, when adaptive_wait.wait_while(|| self.storage.get().submission_channel.is_full()),
+                    with ZeroCopySendError::WaitFailed,
+                    "{} since the wait for a free slot in the receive buffer failed.", msg);
+            }
+
+            self.try_send(ptr, sample_size)
+        }
+
+        fn timed_send(
+            &self,
+            ptr: PointerOffset,
+            sample_size: usize,
+            timeout: Duration,
         ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
             if !self.storage.get().enable_safe_overflow {
-                AdaptiveWaitBuilder::new()
-                    .create()
-                    .unwrap()
-                    .wait_while(|| self.storage.get().submission_channel.is_full())
-                    .unwrap();
+                let msg = "Unable to send sample";
+                let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                    with ZeroCopySendError::WaitFailed,
+                    "{} since the adaptive wait could not be created.", msg);
+                while self.storage.get().submission_channel.is_full() {
+                    let elapsed = fail!(from self, when adaptive_wait.wait(),
+                        with ZeroCopySendError::WaitFailed,
+                        "{} since the wait for a free slot in the receive buffer failed.", msg);
+                    if elapsed >= timeout {
+                        return Err(ZeroCopySendError::ReceiveBufferFull);
+                    }
+                }
             }
 
-            self.try_send(ptr)
+            self.try_send(ptr, sample_size)
         }
 
         fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError> {
             match unsafe { self.storage.get().completion_channel.pop() } {
                 None => Ok(None),
                 Some(v) => {
-                    if !self
-                        .storage
-                        .get()
-                        .used_chunk_list
-                        .remove(v / self.storage.get().sample_size)
-                    {
+                    let storage = self.storage.get();
+                    let is_removed = match storage.used_chunk_index(PointerOffset::new(v)) {
+                        Some(index) => storage.used_chunk_list.remove(index),
+                        None => false,
+                    };
+
+                    if !is_removed {
                         fail!(from self, with ZeroCopyReclaimError::ReceiverReturnedCorruptedOffset,
                         "Unable to reclaim sample since the receiver returned the corrupted offset {}.", v);
                     }
@@ -545,12 +669,43 @@ pub mod details {
             }
         }
 
+        fn number_of_pending_samples(&self) -> usize {
+            self.storage.get().submission_channel.len()
+        }
+
         unsafe fn acquire_used_offsets<F: FnMut(PointerOffset)>(&self, mut callback: F) {
-            let sample_size = self.storage.get().sample_size;
-            self.storage
-                .get()
-                .used_chunk_list
-                .remove_all(|index| callback(PointerOffset::new(index * sample_size)));
+            let storage = self.storage.get();
+            storage.used_chunk_list.remove_all(|index| {
+                let segment_id = index / storage.number_of_samples;
+                let sample_size = match storage.segment_sample_sizes.get(segment_id) {
+                    Some(sample_size) => sample_size.load(Ordering::Relaxed),
+                    None => return,
+                };
+                let offset = (index % storage.number_of_samples) * sample_size;
+                match storage.pointer_offset(segment_id, offset) {
+                    Ok(ptr) => callback(ptr),
+                    Err(e) => {
+                        warn!(from self,
+                            "Unable to acquire the used chunk {} of segment {} since its offset cannot be represented ({:?}).",
+                            index, segment_id, e);
+                    }
+                }
+            });
+        }
+
+        unsafe fn reclaim_from_dead_receiver<F: FnMut(PointerOffset)>(&self, callback: F) {
+            let storage = self.storage.get();
+            // every returned and every pending chunk is still part of the used chunk list,
+            // the channels are emptied so that no chunk is reclaimed twice
+            while storage.completion_channel.pop().is_some() {}
+            while storage.submission_channel.pop().is_some() {}
+            self.acquire_used_offsets(callback);
+
+            // the receiver never releases its part of the connection, the sender takes over
+            // so that the connection is removed with the sender
+            storage
+                .state
+                .fetch_and(!State::Receiver.value(), Ordering::Relaxed);
         }
     }
 
@@ -632,6 +787,14 @@ pub mod details {
                 }
             }
         }
+
+        fn has_data(&self) -> bool {
+            !self.storage.get().submission_channel.is_empty()
+        }
+
+        fn number_of_pending_samples(&self) -> usize {
+            self.storage.get().submission_channel.len()
+        }
     }
 
     #[derive(Debug)]
@@ -10,15 +10,20 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+// the zero copy connection is part of the communication path and must never panic
+#![deny(clippy::unwrap_used, clippy::expect_used)]
+
 pub mod common;
 pub mod posix_shared_memory;
 pub mod process_local;
 pub mod used_chunk_list;
 
 use std::fmt::Debug;
+use std::time::Duration;
 
 pub use crate::shared_memory::PointerOffset;
 use crate::static_storage::file::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
+use iceoryx2_bb_posix::permission::Permission;
 pub use iceoryx2_bb_system_types::file_name::*;
 pub use iceoryx2_bb_system_types::path::Path;
 
@@ -35,6 +40,7 @@ pub enum ZeroCopyCreationError {
     IncompatibleOverflowSetting,
     IncompatibleSampleSize,
     IncompatibleNumberOfSamples,
+    IncompatibleNumberOfSegments,
 }
 
 impl std::fmt::Display for ZeroCopyCreationError {
@@ -50,6 +56,9 @@ pub enum ZeroCopySendError {
     ConnectionCorrupted,
     ReceiveBufferFull,
     UsedChunkListFull,
+    /// Waiting for a free slot in the receive buffer failed, for instance since the sleep was
+    /// interrupted by a signal.
+    WaitFailed,
 }
 
 impl std::fmt::Display for ZeroCopySendError {
@@ -108,6 +117,12 @@ pub trait ZeroCopyConnectionBuilder<C: ZeroCopyConnection>: NamedConceptBuilder<
     fn enable_safe_overflow(self, value: bool) -> Self;
     fn receiver_max_borrowed_samples(self, value: usize) -> Self;
     fn number_of_samples(self, value: usize) -> Self;
+    /// Defines the number of shared memory segments whose chunks can be transferred, see
+    /// [`PointerOffset::segment_id()`]. Every segment provides `number_of_samples` chunks.
+    fn max_supported_shared_memory_segments(self, value: usize) -> Self;
+    /// Defines the [`Permission`] of the underlying resources when the connection is created.
+    /// By default only the owner has access.
+    fn permission(self, value: Permission) -> Self;
 
     fn create_sender(self, sample_size: usize) -> Result<C::Sender, ZeroCopyCreationError>;
     fn create_receiver(self, sample_size: usize) -> Result<C::Receiver, ZeroCopyCreationError>;
@@ -121,13 +136,38 @@ pub trait ZeroCopyPortDetails {
 }
 
 pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept {
-    fn try_send(&self, ptr: PointerOffset) -> Result<Option<PointerOffset>, ZeroCopySendError>;
-
-    fn blocking_send(&self, ptr: PointerOffset)
-        -> Result<Option<PointerOffset>, ZeroCopySendError>;
+    /// Sends the chunk at `ptr`. The `sample_size` is the size of the chunks of the segment
+    /// the chunk belongs to.
+    fn try_send(
+        &self,
+        ptr: PointerOffset,
+        sample_size: usize,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
+
+    /// Sends the chunk at `ptr` and blocks while the receive buffer is full, see
+    /// [`ZeroCopySender::try_send()`].
+    fn blocking_send(
+        &self,
+        ptr: PointerOffset,
+        sample_size: usize,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
+
+    /// Sends the chunk at `ptr` and blocks at most `timeout` while the receive buffer is full,
+    /// see [`ZeroCopySender::blocking_send()`]. Fails with
+    /// [`ZeroCopySendError::ReceiveBufferFull`] when the buffer is still full after the
+    /// timeout has passed.
+    fn timed_send(
+        &self,
+        ptr: PointerOffset,
+        sample_size: usize,
+        timeout: Duration,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
 
     fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError>;
 
+    /// Returns the number of samples that were sent but not yet received by the receiver.
+    fn number_of_pending_samples(&self) -> usize;
+
     /// # Safety
     ///
     /// * must ensure that no receiver is still holding data, otherwise data races may occur on
@@ -135,11 +175,27 @@ pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept {
     /// * must ensure that [`ZeroCopySender::try_send()`] and [`ZeroCopySender::blocking_send()`]
     ///     are not called after using this method
     unsafe fn acquire_used_offsets<F: FnMut(PointerOffset)>(&self, callback: F);
+
+    /// Detaches a receiver that terminated without releasing the connection, for instance
+    /// since its process crashed. Every chunk the receiver still borrowed, did not yet receive
+    /// or returned without being reclaimed is handed to the callback. Afterwards the
+    /// connection is no longer connected and is removed when the sender goes out of scope.
+    ///
+    /// # Safety
+    ///
+    /// * the receiver must be dead, otherwise data races may occur on receiver side
+    unsafe fn reclaim_from_dead_receiver<F: FnMut(PointerOffset)>(&self, callback: F);
 }
 
 pub trait ZeroCopyReceiver: Debug + ZeroCopyPortDetails + NamedConcept {
     fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError>;
     fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError>;
+
+    /// Returns true when at least one sample is ready to be received, otherwise false.
+    fn has_data(&self) -> bool;
+
+    /// Returns the number of samples that are ready to be received.
+    fn number_of_pending_samples(&self) -> usize;
 }
 
 pub trait ZeroCopyConnection: Debug + Sized + NamedConceptMgmt {
@@ -75,6 +75,27 @@ mod dynamic_storage {
         assert_that!(sut2.get().value.load(Ordering::Relaxed), eq 456);
     }
 
+    #[test]
+    fn read_only_opened_storage_observes_changes<
+        Sut: DynamicStorage<TestData>,
+        WrongTypeSut: DynamicStorage<u64>,
+    >() {
+        let storage_name = generate_name();
+
+        let sut = Sut::Builder::new(&storage_name)
+            .create(TestData::new(789))
+            .unwrap();
+
+        let sut2 = Sut::Builder::new(&storage_name)
+            .read_only(true)
+            .open()
+            .unwrap();
+        assert_that!(sut2.get().value.load(Ordering::Relaxed), eq 789);
+
+        sut.get().value.store(1011, Ordering::Relaxed);
+        assert_that!(sut2.get().value.load(Ordering::Relaxed), eq 1011);
+    }
+
     #[test]
     fn open_non_existing_fails<Sut: DynamicStorage<TestData>, WrongTypeSut: DynamicStorage<u64>>() {
         let storage_name = generate_name();
@@ -680,4 +701,9 @@ mod dynamic_storage {
     #[instantiate_tests(<iceoryx2_cal::dynamic_storage::process_local::Storage<TestData>,
                          iceoryx2_cal::dynamic_storage::process_local::Storage<u64>>)]
     mod process_local {}
+
+    #[cfg(target_os = "linux")]
+    #[instantiate_tests(<iceoryx2_cal::dynamic_storage::sealed_shared_memory::Storage<TestData>,
+                         iceoryx2_cal::dynamic_storage::sealed_shared_memory::Storage<u64>>)]
+    mod sealed_shared_memory {}
 }
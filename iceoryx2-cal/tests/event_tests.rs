@@ -641,4 +641,11 @@ mod event {
     #[cfg(not(any(target_os = "macos", target_os = "windows")))]
     #[instantiate_tests(<iceoryx2_cal::event::sem_bitset_posix_shared_memory::Event>)]
     mod sem_bitset_posix_shared_memory {}
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    #[instantiate_tests(<iceoryx2_cal::event::message_queue::EventImpl>)]
+    mod message_queue {}
+
+    #[instantiate_tests(<iceoryx2_cal::event::selectable::EventImpl>)]
+    mod selectable {}
 }
@@ -10,6 +10,16 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+// registers nothing so that the tests do not depend on the memory lock limit of the system
+#[derive(Debug)]
+pub struct NoRegistration;
+
+impl iceoryx2_cal::shared_memory::pinned::HostMemoryRegistration for NoRegistration {
+    unsafe fn register(_address: *mut u8, _size: usize) -> Option<Self> {
+        Some(Self)
+    }
+}
+
 #[generic_tests::define]
 mod shared_memory {
     use std::alloc::Layout;
@@ -193,6 +203,26 @@ mod shared_memory {
         assert_that!(chunk, is_ok);
     }
 
+    #[test]
+    fn read_only_opened_shm_can_read_data_of_creator<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+
+        let sut_create = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .create(&SHM_CONFIG)
+            .unwrap();
+
+        let chunk = sut_create.allocate(DEFAULT_LAYOUT).unwrap();
+        unsafe { (chunk.data_ptr as *mut u64).write_unaligned(0xaffedeadbeef) };
+
+        let sut_open = Sut::Builder::new(&name).read_only(true).open().unwrap();
+        let value = unsafe {
+            ((sut_open.payload_start_address() + chunk.offset.value()) as *const u64)
+                .read_unaligned()
+        };
+        assert_that!(value, eq 0xaffedeadbeef);
+    }
+
     #[test]
     fn allocated_chunks_have_correct_alignment<Sut: SharedMemory<DefaultAllocator>>() {
         let name = generate_name();
@@ -382,4 +412,14 @@ mod shared_memory {
 
     #[instantiate_tests(<iceoryx2_cal::shared_memory::process_local::Memory<DefaultAllocator>>)]
     mod process_local {}
+
+    #[instantiate_tests(<iceoryx2_cal::shared_memory::pinned::Memory<DefaultAllocator, crate::NoRegistration>>)]
+    mod pinned {}
+
+    #[cfg(target_os = "linux")]
+    #[instantiate_tests(<iceoryx2_cal::shared_memory::sealed::Memory<DefaultAllocator>>)]
+    mod sealed {}
+
+    #[instantiate_tests(<iceoryx2_cal::shared_memory::selectable::Memory<DefaultAllocator>>)]
+    mod selectable {}
 }
@@ -0,0 +1,203 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod shm_allocator_best_fit_allocator {
+    use std::{alloc::Layout, ptr::NonNull};
+
+    use iceoryx2_bb_elementary::allocator::AllocationError;
+    use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::shm_allocator::{best_fit_allocator::*, ShmAllocationError, ShmAllocator};
+
+    const MAX_SUPPORTED_ALIGNMENT: usize = 4096;
+    const GRANULE_LAYOUT: Layout = unsafe { Layout::from_size_align_unchecked(32, 8) };
+    const MEM_SIZE: usize = 16384 * 10;
+    const PAYLOAD_SIZE: usize = 8192;
+
+    struct TestContext {
+        _payload_memory: Box<[u8; MEM_SIZE]>,
+        sut: Box<BestFitAllocator>,
+    }
+
+    impl TestContext {
+        fn new(granule_layout: Layout) -> Self {
+            let mut payload_memory = Box::new([0u8; MEM_SIZE]);
+            let base_address =
+                unsafe { NonNull::<[u8]>::new_unchecked(&mut payload_memory[0..PAYLOAD_SIZE]) };
+            let allocator = BumpAllocator::new(
+                unsafe { NonNull::new_unchecked(payload_memory[PAYLOAD_SIZE..].as_mut_ptr()) },
+                MEM_SIZE - PAYLOAD_SIZE,
+            );
+            let config = &Config { granule_layout };
+            let sut = Box::new(unsafe {
+                BestFitAllocator::new_uninit(MAX_SUPPORTED_ALIGNMENT, base_address, config)
+            });
+
+            unsafe { sut.init(&allocator).unwrap() };
+
+            Self {
+                _payload_memory: payload_memory,
+                sut,
+            }
+        }
+
+        fn layout(&self, number_of_granules: usize) -> Layout {
+            Layout::from_size_align(
+                number_of_granules * GRANULE_LAYOUT.size(),
+                GRANULE_LAYOUT.align(),
+            )
+            .unwrap()
+        }
+    }
+
+    #[test]
+    fn is_setup_correctly() {
+        let test_context = TestContext::new(GRANULE_LAYOUT);
+
+        assert_that!(test_context.sut.number_of_granules(), eq PAYLOAD_SIZE / GRANULE_LAYOUT.size());
+        assert_that!(test_context.sut.granule(), eq GRANULE_LAYOUT.size());
+        assert_that!(test_context.sut.max_alignment(), eq GRANULE_LAYOUT.align());
+    }
+
+    #[test]
+    fn allocate_whole_memory_in_one_chunk_works() {
+        let test_context = TestContext::new(GRANULE_LAYOUT);
+        let layout = test_context.layout(test_context.sut.number_of_granules());
+
+        let memory = unsafe { test_context.sut.allocate(layout) };
+        assert_that!(memory, is_ok);
+        assert_that!(unsafe { test_context.sut.allocate(GRANULE_LAYOUT) }, eq Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)));
+
+        unsafe { test_context.sut.deallocate(memory.unwrap(), layout) };
+        assert_that!(unsafe { test_context.sut.allocate(layout) }, is_ok);
+    }
+
+    #[test]
+    fn allocation_is_placed_into_smallest_fitting_hole() {
+        let test_context = TestContext::new(GRANULE_LAYOUT);
+        let n = test_context.sut.number_of_granules();
+
+        // layout: [4 granules][1 separator][2 granules][1 separator][rest]
+        let large_hole = unsafe { test_context.sut.allocate(test_context.layout(4)).unwrap() };
+        let _separator_1 = unsafe { test_context.sut.allocate(test_context.layout(1)).unwrap() };
+        let small_hole = unsafe { test_context.sut.allocate(test_context.layout(2)).unwrap() };
+        let _separator_2 = unsafe { test_context.sut.allocate(test_context.layout(1)).unwrap() };
+        let _rest = unsafe {
+            test_context
+                .sut
+                .allocate(test_context.layout(n - 8))
+                .unwrap()
+        };
+
+        unsafe {
+            test_context
+                .sut
+                .deallocate(large_hole, test_context.layout(4))
+        };
+        unsafe {
+            test_context
+                .sut
+                .deallocate(small_hole, test_context.layout(2))
+        };
+
+        let memory = unsafe { test_context.sut.allocate(test_context.layout(2)).unwrap() };
+        assert_that!(memory, eq small_hole);
+
+        let memory = unsafe { test_context.sut.allocate(test_context.layout(3)).unwrap() };
+        assert_that!(memory, eq large_hole);
+    }
+
+    #[test]
+    fn released_neighboring_chunks_can_be_allocated_as_one() {
+        let test_context = TestContext::new(GRANULE_LAYOUT);
+        let n = test_context.sut.number_of_granules();
+
+        let chunk_1 = unsafe {
+            test_context
+                .sut
+                .allocate(test_context.layout(n / 2))
+                .unwrap()
+        };
+        let chunk_2 = unsafe {
+            test_context
+                .sut
+                .allocate(test_context.layout(n - n / 2))
+                .unwrap()
+        };
+
+        unsafe {
+            test_context
+                .sut
+                .deallocate(chunk_1, test_context.layout(n / 2))
+        };
+        unsafe {
+            test_context
+                .sut
+                .deallocate(chunk_2, test_context.layout(n - n / 2))
+        };
+
+        assert_that!(
+            unsafe { test_context.sut.allocate(test_context.layout(n)) },
+            is_ok
+        );
+    }
+
+    #[test]
+    fn allocated_sizes_are_rounded_up_to_granule_size() {
+        let test_context = TestContext::new(GRANULE_LAYOUT);
+
+        let chunk_1 = unsafe {
+            test_context
+                .sut
+                .allocate(Layout::from_size_align(1, 1).unwrap())
+                .unwrap()
+        };
+        let chunk_2 = unsafe {
+            test_context
+                .sut
+                .allocate(Layout::from_size_align(GRANULE_LAYOUT.size() + 1, 1).unwrap())
+                .unwrap()
+        };
+        let chunk_3 = unsafe {
+            test_context
+                .sut
+                .allocate(Layout::from_size_align(1, 1).unwrap())
+                .unwrap()
+        };
+
+        assert_that!(chunk_2.value() - chunk_1.value(), eq GRANULE_LAYOUT.size());
+        assert_that!(chunk_3.value() - chunk_2.value(), eq 2 * GRANULE_LAYOUT.size());
+    }
+
+    #[test]
+    fn fragmentation_report_contains_free_ranges() {
+        let test_context = TestContext::new(GRANULE_LAYOUT);
+        let n = test_context.sut.number_of_granules();
+        let granule = GRANULE_LAYOUT.size();
+
+        let chunk_1 = unsafe { test_context.sut.allocate(test_context.layout(4)).unwrap() };
+        let _separator_1 = unsafe { test_context.sut.allocate(test_context.layout(1)).unwrap() };
+        let chunk_2 = unsafe { test_context.sut.allocate(test_context.layout(2)).unwrap() };
+        let _separator_2 = unsafe { test_context.sut.allocate(test_context.layout(1)).unwrap() };
+
+        unsafe { test_context.sut.deallocate(chunk_1, test_context.layout(4)) };
+        unsafe { test_context.sut.deallocate(chunk_2, test_context.layout(2)) };
+
+        let report = unsafe { test_context.sut.fragmentation_report() };
+        assert_that!(report.total_memory(), eq n * granule);
+        assert_that!(report.used_memory(), eq 2 * granule);
+        assert_that!(report.largest_free_chunk(), eq(n - 8) * granule);
+        assert_that!(report.free_chunks_per_size_class().get(&(4 * granule)), eq Some(&1));
+        assert_that!(report.free_chunks_per_size_class().get(&(2 * granule)), eq Some(&1));
+        assert_that!(report.fragmentation(), gt 0.0);
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod shm_allocator_multi_pool_allocator {
+    use std::{alloc::Layout, collections::HashSet, ptr::NonNull};
+
+    use iceoryx2_bb_elementary::allocator::AllocationError;
+    use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::{
+        shm_allocator::{multi_pool_allocator::*, ShmAllocationError, ShmAllocator},
+        zero_copy_connection::PointerOffset,
+    };
+
+    const MAX_SUPPORTED_ALIGNMENT: usize = 4096;
+    const BUCKET_ALIGNMENT: usize = 8;
+    const MEM_SIZE: usize = 16384 * 10;
+    const PAYLOAD_SIZE: usize = 8192;
+    const SMALL_BUCKET: Layout = unsafe { Layout::from_size_align_unchecked(32, 8) };
+    const LARGE_BUCKET: Layout = unsafe { Layout::from_size_align_unchecked(256, 8) };
+    const NUMBER_OF_SMALL_BUCKETS: usize = 10;
+    const NUMBER_OF_LARGE_BUCKETS: usize = 4;
+
+    struct TestContext {
+        _payload_memory: Box<[u8; MEM_SIZE]>,
+        _base_address: NonNull<[u8]>,
+        sut: Box<MultiPoolAllocator>,
+    }
+
+    impl TestContext {
+        fn new(config: &Config) -> Self {
+            let mut payload_memory = Box::new([0u8; MEM_SIZE]);
+            let base_address =
+                unsafe { NonNull::<[u8]>::new_unchecked(&mut payload_memory[0..PAYLOAD_SIZE]) };
+            let allocator = BumpAllocator::new(
+                unsafe { NonNull::new_unchecked(payload_memory[PAYLOAD_SIZE..].as_mut_ptr()) },
+                MEM_SIZE,
+            );
+            let sut = Box::new(unsafe {
+                MultiPoolAllocator::new_uninit(MAX_SUPPORTED_ALIGNMENT, base_address, config)
+            });
+
+            unsafe { sut.init(&allocator).unwrap() };
+
+            Self {
+                _payload_memory: payload_memory,
+                _base_address: base_address,
+                sut,
+            }
+        }
+    }
+
+    fn config() -> Config {
+        let mut config = Config::new(BUCKET_ALIGNMENT);
+        assert_that!(config.add_pool(LARGE_BUCKET.size(), NUMBER_OF_LARGE_BUCKETS), eq true);
+        assert_that!(config.add_pool(SMALL_BUCKET.size(), NUMBER_OF_SMALL_BUCKETS), eq true);
+        config
+    }
+
+    #[test]
+    fn config_keeps_pools_sorted_by_bucket_size() {
+        let sut = config();
+
+        assert_that!(sut.pools(), len 2);
+        assert_that!(sut.pools()[0].bucket_size, eq SMALL_BUCKET.size());
+        assert_that!(sut.pools()[0].number_of_buckets, eq NUMBER_OF_SMALL_BUCKETS);
+        assert_that!(sut.pools()[1].bucket_size, eq LARGE_BUCKET.size());
+        assert_that!(sut.pools()[1].number_of_buckets, eq NUMBER_OF_LARGE_BUCKETS);
+    }
+
+    #[test]
+    fn config_rejects_invalid_pools() {
+        let mut sut = Config::new(BUCKET_ALIGNMENT);
+
+        assert_that!(sut.add_pool(0, 1), eq false);
+        assert_that!(sut.add_pool(1, 0), eq false);
+        for n in 0..MAX_NUMBER_OF_POOLS {
+            assert_that!(sut.add_pool(n + 1, 1), eq true);
+        }
+        assert_that!(sut.add_pool(1, 1), eq false);
+        assert_that!(sut.pools(), len MAX_NUMBER_OF_POOLS);
+    }
+
+    #[test]
+    fn is_setup_correctly() {
+        let test_context = TestContext::new(&config());
+
+        assert_that!(test_context.sut.number_of_pools(), eq 2);
+        assert_that!(test_context.sut.max_alignment(), eq BUCKET_ALIGNMENT);
+        assert_that!(test_context.sut.number_of_used_buckets(), eq 0);
+
+        let pools: Vec<PoolConfig> = test_context.sut.pool_configs().collect();
+        assert_that!(pools, eq config().pools().to_vec());
+    }
+
+    #[test]
+    fn allocation_is_served_by_smallest_fitting_pool() {
+        let test_context = TestContext::new(&config());
+        let small_pool_size = SMALL_BUCKET.size() * NUMBER_OF_SMALL_BUCKETS;
+
+        let small = unsafe { test_context.sut.allocate(SMALL_BUCKET).unwrap() };
+        assert_that!(small.value(), lt small_pool_size);
+
+        let large = unsafe { test_context.sut.allocate(LARGE_BUCKET).unwrap() };
+        assert_that!(large.value(), ge small_pool_size);
+        assert_that!((large.value() - small_pool_size) % LARGE_BUCKET.size(), eq 0);
+
+        unsafe {
+            test_context.sut.deallocate(small, SMALL_BUCKET);
+            test_context.sut.deallocate(large, LARGE_BUCKET);
+        }
+        assert_that!(test_context.sut.number_of_used_buckets(), eq 0);
+    }
+
+    #[test]
+    fn exhausted_pool_falls_back_to_larger_pool() {
+        const REPETITIONS: usize = 10;
+        let test_context = TestContext::new(&config());
+
+        for _ in 0..REPETITIONS {
+            let mut mem_set = HashSet::new();
+            for _ in 0..NUMBER_OF_SMALL_BUCKETS + NUMBER_OF_LARGE_BUCKETS {
+                let memory = unsafe { test_context.sut.allocate(SMALL_BUCKET).unwrap() };
+                assert_that!(mem_set.insert(memory.value()), eq true);
+            }
+
+            assert_that!(unsafe { test_context.sut.allocate(SMALL_BUCKET) }, eq Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)));
+
+            for memory in mem_set {
+                unsafe {
+                    test_context
+                        .sut
+                        .deallocate(PointerOffset::new(memory), SMALL_BUCKET)
+                }
+            }
+            assert_that!(test_context.sut.number_of_used_buckets(), eq 0);
+        }
+    }
+
+    #[test]
+    fn allocation_larger_than_largest_bucket_fails() {
+        let test_context = TestContext::new(&config());
+
+        let layout = Layout::from_size_align(LARGE_BUCKET.size() + 1, 1).unwrap();
+        assert_that!(unsafe { test_context.sut.allocate(layout) }, eq Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge)));
+    }
+
+    #[test]
+    fn allocate_with_unsupported_alignment_fails() {
+        let test_context = TestContext::new(&config());
+
+        let layout = Layout::from_size_align(SMALL_BUCKET.size(), 2 * BUCKET_ALIGNMENT).unwrap();
+        assert_that!(unsafe { test_context.sut.allocate(layout) }, eq Err(ShmAllocationError::ExceedsMaxSupportedAlignment));
+    }
+
+    #[test]
+    fn pools_are_truncated_to_the_available_memory() {
+        let mut config = Config::new(BUCKET_ALIGNMENT);
+        config.add_pool(SMALL_BUCKET.size(), NUMBER_OF_SMALL_BUCKETS);
+        config.add_pool(PAYLOAD_SIZE / 2, 4);
+        config.add_pool(PAYLOAD_SIZE, 1);
+        let test_context = TestContext::new(&config);
+
+        let pools: Vec<PoolConfig> = test_context.sut.pool_configs().collect();
+        assert_that!(pools, len 2);
+        assert_that!(pools[0].number_of_buckets, eq NUMBER_OF_SMALL_BUCKETS);
+        assert_that!(pools[1].bucket_size, eq PAYLOAD_SIZE / 2);
+        assert_that!(pools[1].number_of_buckets, eq 1);
+    }
+
+    #[test]
+    fn fragmentation_report_contains_free_buckets_of_every_pool() {
+        let test_context = TestContext::new(&config());
+        let total_memory = SMALL_BUCKET.size() * NUMBER_OF_SMALL_BUCKETS
+            + LARGE_BUCKET.size() * NUMBER_OF_LARGE_BUCKETS;
+
+        let report = unsafe { test_context.sut.fragmentation_report() };
+        assert_that!(report.total_memory(), eq total_memory);
+        assert_that!(report.free_memory(), eq total_memory);
+        assert_that!(report.largest_free_chunk(), eq LARGE_BUCKET.size());
+
+        let mut memory = vec![];
+        for _ in 0..NUMBER_OF_LARGE_BUCKETS {
+            memory.push(unsafe { test_context.sut.allocate(LARGE_BUCKET).unwrap() });
+        }
+
+        let report = unsafe { test_context.sut.fragmentation_report() };
+        assert_that!(report.used_memory(), eq LARGE_BUCKET.size() * NUMBER_OF_LARGE_BUCKETS);
+        assert_that!(report.largest_free_chunk(), eq SMALL_BUCKET.size());
+
+        for m in memory {
+            unsafe { test_context.sut.deallocate(m, LARGE_BUCKET) };
+        }
+    }
+}
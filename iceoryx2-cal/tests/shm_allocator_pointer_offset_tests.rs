@@ -0,0 +1,42 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod shm_allocator_pointer_offset {
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::shm_allocator::{PointerOffset, PointerOffsetError};
+
+    #[test]
+    fn pointer_offset_stores_offset_and_segment_id() {
+        for segment_id in [0, 1, PointerOffset::MAX_NUMBER_OF_SEGMENTS - 1] {
+            let sut = PointerOffset::from_offset_and_segment_id(1234, segment_id).unwrap();
+
+            assert_that!(sut.offset(), eq 1234);
+            assert_that!(sut.segment_id(), eq segment_id);
+            assert_that!(PointerOffset::new(sut.value()), eq sut);
+        }
+    }
+
+    #[test]
+    fn pointer_offset_with_segment_id_out_of_range_fails() {
+        let sut =
+            PointerOffset::from_offset_and_segment_id(0, PointerOffset::MAX_NUMBER_OF_SEGMENTS);
+
+        assert_that!(sut.err(), eq Some(PointerOffsetError::SegmentIdOutOfRange));
+    }
+
+    #[test]
+    fn pointer_offset_with_offset_out_of_range_fails() {
+        let sut = PointerOffset::from_offset_and_segment_id(usize::MAX, 0);
+
+        assert_that!(sut.err(), eq Some(PointerOffsetError::OffsetOutOfRange));
+    }
+}
@@ -165,6 +165,30 @@ mod shm_allocator {
         );
     }
 
+    #[test]
+    fn fragmentation_report_tracks_allocations<Sut: ShmAllocator>() {
+        let mut test = TestFixture::<Sut>::new();
+        test.init();
+
+        let initial_report = unsafe { test.sut().fragmentation_report() };
+        assert_that!(initial_report.total_memory(), le MEMORY_SIZE);
+        assert_that!(initial_report.free_memory(), gt 0);
+        assert_that!(initial_report.largest_free_chunk(), le initial_report.free_memory());
+
+        let layout = unsafe { Layout::from_size_align_unchecked(CHUNK_SIZE, 1) };
+        let distance = unsafe { test.sut().allocate(layout).unwrap() };
+
+        let report = unsafe { test.sut().fragmentation_report() };
+        assert_that!(report.total_memory(), eq initial_report.total_memory());
+        assert_that!(report.used_memory(), ge CHUNK_SIZE);
+        assert_that!(report.free_memory(), le initial_report.free_memory() - CHUNK_SIZE);
+
+        unsafe { test.sut().deallocate(distance, layout) };
+
+        let report = unsafe { test.sut().fragmentation_report() };
+        assert_that!(report, eq initial_report);
+    }
+
     #[test]
     fn allocator_id_is_unique<Sut: ShmAllocator>() {
         lazy_static! {
@@ -182,4 +206,16 @@ mod shm_allocator {
 
     #[instantiate_tests(<iceoryx2_cal::shm_allocator::bump_allocator::BumpAllocator>)]
     mod bump_allocator {}
+
+    #[instantiate_tests(<iceoryx2_cal::shm_allocator::bump_per_cycle_allocator::BumpPerCycleAllocator>)]
+    mod bump_per_cycle_allocator {}
+
+    #[instantiate_tests(<iceoryx2_cal::shm_allocator::best_fit_allocator::BestFitAllocator>)]
+    mod best_fit_allocator {}
+
+    #[instantiate_tests(<iceoryx2_cal::shm_allocator::multi_pool_allocator::MultiPoolAllocator>)]
+    mod multi_pool_allocator {}
+
+    #[instantiate_tests(<iceoryx2_cal::shm_allocator::selectable_allocator::SelectableAllocator>)]
+    mod selectable_allocator {}
 }
@@ -510,6 +510,9 @@ mod static_storage {
     #[instantiate_tests(<iceoryx2_cal::static_storage::file::Storage>)]
     mod file {}
 
+    #[instantiate_tests(<iceoryx2_cal::static_storage::persistent_file::Storage>)]
+    mod persistent_file {}
+
     #[instantiate_tests(<iceoryx2_cal::static_storage::process_local::Storage>)]
     mod process_local {}
 }
@@ -294,6 +294,139 @@ mod zero_copy_connection {
         assert_that!(sut_receiver, is_err);
     }
 
+    #[test]
+    fn connecting_with_incompatible_number_of_segments_fails<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+
+        let _sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .max_supported_shared_memory_segments(4)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .max_supported_shared_memory_segments(2)
+            .create_receiver(SAMPLE_SIZE);
+
+        assert_that!(sut_receiver, is_err);
+        assert_that!(
+            sut_receiver.err().unwrap(), eq
+            ZeroCopyCreationError::IncompatibleNumberOfSegments
+        );
+    }
+
+    #[test]
+    fn send_receive_and_retrieval_of_multiple_segments_works<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        const NUMBER_OF_SEGMENTS: usize = 3;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(NUMBER_OF_SEGMENTS)
+            .receiver_max_borrowed_samples(NUMBER_OF_SEGMENTS)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .max_supported_shared_memory_segments(NUMBER_OF_SEGMENTS)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(NUMBER_OF_SEGMENTS)
+            .receiver_max_borrowed_samples(NUMBER_OF_SEGMENTS)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .max_supported_shared_memory_segments(NUMBER_OF_SEGMENTS)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        // every segment has its own sample size and the same chunk index
+        let offsets: Vec<PointerOffset> = (0..NUMBER_OF_SEGMENTS)
+            .map(|segment_id| {
+                PointerOffset::from_offset_and_segment_id(
+                    SAMPLE_SIZE * (segment_id + 1) * 5,
+                    segment_id,
+                )
+                .unwrap()
+            })
+            .collect();
+
+        for (segment_id, offset) in offsets.iter().enumerate() {
+            assert_that!(
+                sut_sender.try_send(*offset, SAMPLE_SIZE * (segment_id + 1)),
+                is_ok
+            );
+        }
+
+        for offset in &offsets {
+            let sample = sut_receiver.receive().unwrap();
+            assert_that!(sample, eq Some(*offset));
+            assert_that!(sut_receiver.release(sample.unwrap()), is_ok);
+        }
+
+        for offset in &offsets {
+            assert_that!(sut_sender.reclaim().unwrap(), eq Some(*offset));
+        }
+        assert_that!(sut_sender.reclaim().unwrap(), is_none);
+    }
+
+    #[test]
+    fn sending_to_segment_that_is_not_supported_fails<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .max_supported_shared_memory_segments(2)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+
+        let result = sut_sender.try_send(
+            PointerOffset::from_offset_and_segment_id(0, 2).unwrap(),
+            SAMPLE_SIZE,
+        );
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq ZeroCopySendError::ConnectionCorrupted);
+    }
+
+    #[test]
+    fn sender_and_receiver_report_pending_samples<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(2)
+            .receiver_max_borrowed_samples(2)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(2)
+            .receiver_max_borrowed_samples(2)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        assert_that!(sut_receiver.has_data(), eq false);
+        assert_that!(sut_receiver.number_of_pending_samples(), eq 0);
+        assert_that!(sut_sender.number_of_pending_samples(), eq 0);
+
+        assert_that!(
+            sut_sender.try_send(PointerOffset::new(0), SAMPLE_SIZE),
+            is_ok
+        );
+        assert_that!(
+            sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE), SAMPLE_SIZE),
+            is_ok
+        );
+        assert_that!(sut_receiver.has_data(), eq true);
+        assert_that!(sut_receiver.number_of_pending_samples(), eq 2);
+        assert_that!(sut_sender.number_of_pending_samples(), eq 2);
+
+        assert_that!(sut_receiver.receive().unwrap(), is_some);
+        assert_that!(sut_receiver.has_data(), eq true);
+        assert_that!(sut_receiver.number_of_pending_samples(), eq 1);
+        assert_that!(sut_sender.number_of_pending_samples(), eq 1);
+
+        assert_that!(sut_receiver.receive().unwrap(), is_some);
+        assert_that!(sut_receiver.has_data(), eq false);
+        assert_that!(sut_receiver.number_of_pending_samples(), eq 0);
+    }
+
     #[test]
     fn send_receive_and_retrieval_works<Sut: ZeroCopyConnection>() {
         let name = generate_name();
@@ -309,7 +442,7 @@ mod zero_copy_connection {
 
         let sample_offset = SAMPLE_SIZE * 2;
         assert_that!(
-            sut_sender.try_send(PointerOffset::new(sample_offset)),
+            sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
             is_ok
         );
         let sample = sut_receiver.receive().unwrap();
@@ -339,12 +472,12 @@ mod zero_copy_connection {
         for i in 0..BUFFER_SIZE {
             let sample_offset = SAMPLE_SIZE * i;
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
 
-        let result = sut_sender.try_send(PointerOffset::new(9));
+        let result = sut_sender.try_send(PointerOffset::new(9), SAMPLE_SIZE);
         assert_that!(result, is_err);
         assert_that!(result.err().unwrap(), eq ZeroCopySendError::ReceiveBufferFull);
     }
@@ -364,7 +497,7 @@ mod zero_copy_connection {
         for i in 0..BUFFER_SIZE {
             let sample_offset = SAMPLE_SIZE * i;
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -372,7 +505,7 @@ mod zero_copy_connection {
         for i in 0..BUFFER_SIZE {
             let overflow_sample_offset = SAMPLE_SIZE * i;
             let sample_offset = SAMPLE_SIZE * (BUFFER_SIZE + i);
-            let result = sut_sender.try_send(PointerOffset::new(sample_offset));
+            let result = sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE);
             assert_that!(result, is_ok);
             assert_that!(result.ok().unwrap().unwrap().value(), eq overflow_sample_offset);
         }
@@ -393,7 +526,7 @@ mod zero_copy_connection {
         for i in 0..BUFFER_SIZE {
             let sample_offset = SAMPLE_SIZE * i;
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -451,7 +584,7 @@ mod zero_copy_connection {
             for _ in 0..BUFFER_SIZE {
                 sample_offset += SAMPLE_SIZE;
                 assert_that!(
-                    sut_sender.try_send(PointerOffset::new(sample_offset)),
+                    sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                     is_ok
                 );
             }
@@ -521,17 +654,56 @@ mod zero_copy_connection {
             let now = Instant::now();
 
             assert_that!(
-                sut_sender.blocking_send(PointerOffset::new(sample_offset_1)),
+                sut_sender.blocking_send(PointerOffset::new(sample_offset_1), SAMPLE_SIZE),
                 is_ok
             );
             assert_that!(
-                sut_sender.blocking_send(PointerOffset::new(sample_offset_2)),
+                sut_sender.blocking_send(PointerOffset::new(sample_offset_2), SAMPLE_SIZE),
                 is_ok
             );
             assert_that!(now.elapsed(), time_at_least TIMEOUT);
         });
     }
 
+    #[test]
+    fn timed_send_fails_when_buffer_stays_full<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(1)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(1)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        let sample_offset_1 = SAMPLE_SIZE * 12;
+        let sample_offset_2 = SAMPLE_SIZE * 234;
+
+        assert_that!(
+            sut_sender.timed_send(PointerOffset::new(sample_offset_1), SAMPLE_SIZE, TIMEOUT),
+            is_ok
+        );
+
+        let now = Instant::now();
+        assert_that!(
+            sut_sender.timed_send(PointerOffset::new(sample_offset_2), SAMPLE_SIZE, TIMEOUT).err(),
+            eq Some(ZeroCopySendError::ReceiveBufferFull)
+        );
+        assert_that!(now.elapsed(), time_at_least TIMEOUT);
+
+        let sample = sut_receiver.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(sample.unwrap().value(), eq sample_offset_1);
+        assert_that!(
+            sut_sender.timed_send(PointerOffset::new(sample_offset_2), SAMPLE_SIZE, TIMEOUT),
+            is_ok
+        );
+    }
+
     #[test]
     fn send_samples_can_be_acquired<Sut: ZeroCopyConnection>() {
         const BUFFER_SIZE: usize = 10;
@@ -554,7 +726,7 @@ mod zero_copy_connection {
             let sample_offset = SAMPLE_SIZE * i;
             offsets.insert(sample_offset);
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -591,7 +763,7 @@ mod zero_copy_connection {
         for i in 0..BUFFER_SIZE {
             let sample_offset = SAMPLE_SIZE * i;
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -601,7 +773,7 @@ mod zero_copy_connection {
             let sample_offset = SAMPLE_SIZE * (i + BUFFER_SIZE);
             offsets.insert(sample_offset);
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -638,7 +810,7 @@ mod zero_copy_connection {
         for i in 0..BUFFER_SIZE {
             let sample_offset = SAMPLE_SIZE * i;
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -657,6 +829,56 @@ mod zero_copy_connection {
         assert_that!(sample_acquired, eq false);
     }
 
+    #[test]
+    fn samples_of_dead_receiver_can_be_reclaimed<Sut: ZeroCopyConnection>() {
+        const BUFFER_SIZE: usize = 10;
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples(BUFFER_SIZE)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples(BUFFER_SIZE)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        let mut offsets = HashSet::new();
+        for i in 0..BUFFER_SIZE {
+            let sample_offset = SAMPLE_SIZE * i;
+            offsets.insert(sample_offset);
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
+                is_ok
+            );
+        }
+
+        // borrowed, returned but not yet reclaimed and pending samples
+        for i in 0..BUFFER_SIZE / 2 {
+            let offset = sut_receiver.receive().unwrap().unwrap();
+            if i % 2 == 0 {
+                sut_receiver.release(offset).unwrap();
+            }
+        }
+        core::mem::forget(sut_receiver);
+
+        unsafe {
+            sut_sender.reclaim_from_dead_receiver(|offset| {
+                assert_that!(offsets.remove(&offset.value()), eq true);
+            })
+        };
+        assert_that!(offsets, is_empty);
+        assert_that!(sut_sender.is_connected(), eq false);
+        assert_that!(sut_sender.reclaim().unwrap(), is_none);
+
+        drop(sut_sender);
+        assert_that!(<Sut as NamedConceptMgmt>::does_exist(&name), eq Ok(false));
+    }
+
     #[test]
     fn send_samples_can_be_acquired_when_receiver_is_dropped<Sut: ZeroCopyConnection>() {
         const BUFFER_SIZE: usize = 10;
@@ -682,7 +904,7 @@ mod zero_copy_connection {
             let sample_offset = SAMPLE_SIZE * (i + BUFFER_SIZE);
             offsets.insert(sample_offset);
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -0,0 +1,479 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`Doctor`] checks the host for common problems that prevent iceoryx2 from working or
+//! that let it fail under load, like a tiny `/dev/shm`, restrictive message queue or
+//! semaphore limits, missing permissions or a transparent huge page setup that inflates every
+//! shared memory segment. Every problem comes with a hint how it can be fixed.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2::config::Config;
+//! use iceoryx2_cli::doctor::*;
+//!
+//! let findings = Doctor::new(Config::get_global_config()).diagnose();
+//! for finding in &findings {
+//!     println!("{}", finding);
+//! }
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use iceoryx2::config::Config;
+
+/// The smallest size of the shared memory file system in bytes that is considered sufficient.
+/// Container runtimes often mount a `/dev/shm` of only 64MB.
+pub const DEFAULT_MIN_SHARED_MEMORY_SIZE: u64 = 256 * 1024 * 1024;
+
+// the default limits of the Linux kernel
+const MIN_MESSAGE_QUEUES: u64 = 256;
+const MIN_MESSAGES_PER_QUEUE: u64 = 10;
+const MIN_MESSAGE_SIZE: u64 = 8192;
+const MIN_SEMAPHORE_LIMITS: [(&str, u64); 4] = [
+    ("SEMMSL", 250),
+    ("SEMMNS", 32000),
+    ("SEMOPM", 32),
+    ("SEMMNI", 128),
+];
+
+const PROBE_FILE_NAME: &str = ".iox2_doctor_probe";
+
+/// The result of a single check of the [`Doctor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The check passed.
+    Ok,
+    /// The check could not be performed on this host, for instance since `/proc` is not
+    /// available.
+    Skipped,
+    /// iceoryx2 works but may fail under load or wastes resources.
+    Warning,
+    /// iceoryx2 will not work.
+    Error,
+}
+
+impl Severity {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Ok => " OK  ",
+            Severity::Skipped => "SKIP ",
+            Severity::Warning => "WARN ",
+            Severity::Error => "ERROR",
+        }
+    }
+}
+
+/// The outcome of a single check of the [`Doctor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    check: &'static str,
+    severity: Severity,
+    message: String,
+    remediation: Option<String>,
+}
+
+impl Finding {
+    fn new(check: &'static str, severity: Severity, message: String) -> Self {
+        Self {
+            check,
+            severity,
+            message,
+            remediation: None,
+        }
+    }
+
+    fn with_remediation(mut self, remediation: String) -> Self {
+        self.remediation = Some(remediation);
+        self
+    }
+
+    /// Returns the name of the check, like `"shared memory"`.
+    pub fn check(&self) -> &str {
+        self.check
+    }
+
+    /// Returns the [`Severity`] of the [`Finding`].
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Returns a description of what was found.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns a hint how the problem can be fixed, if there is one.
+    pub fn remediation(&self) -> Option<&str> {
+        self.remediation.as_deref()
+    }
+}
+
+impl std::fmt::Display for Finding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(
+            f,
+            "[{}] {}: {}",
+            self.severity.as_str(),
+            self.check,
+            self.message
+        )?;
+        if let Some(remediation) = &self.remediation {
+            std::write!(f, "\n        hint: {}", remediation)?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks the host for common problems, see the [module documentation](crate::doctor). The
+/// locations of the system files can be adjusted, which is useful to diagnose a mounted image
+/// of another system.
+#[derive(Debug, Clone)]
+pub struct Doctor {
+    proc_path: PathBuf,
+    sys_path: PathBuf,
+    shm_path: PathBuf,
+    root_path: PathBuf,
+    min_shared_memory_size: u64,
+}
+
+impl Doctor {
+    /// Creates a new [`Doctor`] that checks the directories used by the provided [`Config`].
+    pub fn new(config: &Config) -> Self {
+        Self {
+            proc_path: PathBuf::from("/proc"),
+            sys_path: PathBuf::from("/sys"),
+            shm_path: PathBuf::from("/dev/shm"),
+            root_path: PathBuf::from(config.global.root_path().to_string()),
+            min_shared_memory_size: DEFAULT_MIN_SHARED_MEMORY_SIZE,
+        }
+    }
+
+    /// Defines where the proc file system is mounted, default is `/proc`.
+    pub fn proc_path<P: Into<PathBuf>>(mut self, value: P) -> Self {
+        self.proc_path = value.into();
+        self
+    }
+
+    /// Defines where the sys file system is mounted, default is `/sys`.
+    pub fn sys_path<P: Into<PathBuf>>(mut self, value: P) -> Self {
+        self.sys_path = value.into();
+        self
+    }
+
+    /// Defines where the shared memory file system is mounted, default is `/dev/shm`.
+    pub fn shm_path<P: Into<PathBuf>>(mut self, value: P) -> Self {
+        self.shm_path = value.into();
+        self
+    }
+
+    /// Defines the root directory of iceoryx2, default is
+    /// [`iceoryx2::config::Global::root_path()`].
+    pub fn root_path<P: Into<PathBuf>>(mut self, value: P) -> Self {
+        self.root_path = value.into();
+        self
+    }
+
+    /// Defines the smallest size of the shared memory file system in bytes that is considered
+    /// sufficient, default is [`DEFAULT_MIN_SHARED_MEMORY_SIZE`].
+    pub fn min_shared_memory_size(mut self, value: u64) -> Self {
+        self.min_shared_memory_size = value;
+        self
+    }
+
+    /// Performs all checks and returns their [`Finding`]s.
+    pub fn diagnose(&self) -> Vec<Finding> {
+        let mut findings = vec![self.check_shared_memory_size()];
+        findings.append(&mut self.check_permissions());
+        findings.push(self.check_message_queue_limits());
+        findings.push(self.check_semaphore_limits());
+        findings.push(self.check_huge_pages());
+        findings
+    }
+
+    fn read(&self, path: &Path) -> Option<String> {
+        fs::read_to_string(path).ok()
+    }
+
+    fn read_number(&self, path: &Path) -> Option<u64> {
+        self.read(path)?.trim().parse().ok()
+    }
+
+    fn check_shared_memory_size(&self) -> Finding {
+        const CHECK: &str = "shared memory";
+        let mounts = match self.read(&self.proc_path.join("mounts")) {
+            Some(mounts) => mounts,
+            None => {
+                return Finding::new(
+                    CHECK,
+                    Severity::Skipped,
+                    "the mounted file systems could not be read".to_string(),
+                )
+            }
+        };
+
+        let shm_path = self.shm_path.to_string_lossy();
+        let options = match mounts.lines().find_map(|line| {
+            let mut fields = line.split_whitespace();
+            let mount_point = fields.nth(1)?;
+            let options = fields.nth(1)?;
+            (mount_point == shm_path).then(|| options.to_string())
+        }) {
+            Some(options) => options,
+            None => {
+                return Finding::new(
+                    CHECK,
+                    Severity::Error,
+                    format!("{} is not mounted", shm_path),
+                )
+                .with_remediation(format!(
+                    "mount a tmpfs with \"mount -t tmpfs -o size=1G tmpfs {}\"",
+                    shm_path
+                ))
+            }
+        };
+
+        // without a size option tmpfs uses half of the physical memory
+        let size = options
+            .split(',')
+            .find_map(|option| option.strip_prefix("size="))
+            .and_then(parse_size);
+
+        match size {
+            Some(size) if size < self.min_shared_memory_size => Finding::new(
+                CHECK,
+                Severity::Warning,
+                format!(
+                    "{} has only {} bytes, less than the recommended {} bytes",
+                    shm_path, size, self.min_shared_memory_size
+                ),
+            )
+            .with_remediation(format!(
+                "increase it with \"mount -o remount,size=1G {}\" or, inside a container, with \"--shm-size=1g\"",
+                shm_path
+            )),
+            Some(size) => Finding::new(CHECK, Severity::Ok, format!("{} has {} bytes", shm_path, size)),
+            None => Finding::new(
+                CHECK,
+                Severity::Ok,
+                format!("{} has the default size of half the physical memory", shm_path),
+            ),
+        }
+    }
+
+    fn check_permissions(&self) -> Vec<Finding> {
+        const CHECK: &str = "permissions";
+        let mut findings = vec![];
+
+        for (path, create) in [(&self.root_path, true), (&self.shm_path, false)] {
+            let result = if create {
+                fs::create_dir_all(path).and_then(|_| probe_write_access(path))
+            } else {
+                probe_write_access(path)
+            };
+
+            findings.push(match result {
+                Ok(()) => Finding::new(
+                    CHECK,
+                    Severity::Ok,
+                    format!("{} is writable", path.display()),
+                ),
+                Err(e) => Finding::new(
+                    CHECK,
+                    Severity::Error,
+                    format!("{} is not writable ({})", path.display(), e),
+                )
+                .with_remediation(format!(
+                    "grant the user write access with \"chmod a+rwxt {}\" or run all iceoryx2 applications as the same user",
+                    path.display()
+                )),
+            });
+        }
+
+        findings
+    }
+
+    fn check_message_queue_limits(&self) -> Finding {
+        const CHECK: &str = "message queues";
+        let mqueue_path = self.proc_path.join("sys/fs/mqueue");
+        let limits = [
+            ("queues_max", MIN_MESSAGE_QUEUES),
+            ("msg_max", MIN_MESSAGES_PER_QUEUE),
+            ("msgsize_max", MIN_MESSAGE_SIZE),
+        ];
+
+        let mut too_small = vec![];
+        for (name, min_value) in limits {
+            match self.read_number(&mqueue_path.join(name)) {
+                Some(value) if value < min_value => too_small.push((name, value, min_value)),
+                Some(_) => (),
+                None => {
+                    return Finding::new(
+                        CHECK,
+                        Severity::Skipped,
+                        format!("the limit {} could not be read", name),
+                    )
+                }
+            }
+        }
+
+        limits_finding(CHECK, "fs.mqueue", &too_small)
+    }
+
+    fn check_semaphore_limits(&self) -> Finding {
+        const CHECK: &str = "semaphores";
+        let values: Vec<u64> = match self.read(&self.proc_path.join("sys/kernel/sem")) {
+            Some(content) => content
+                .split_whitespace()
+                .filter_map(|v| v.parse().ok())
+                .collect(),
+            None => vec![],
+        };
+
+        if values.len() != MIN_SEMAPHORE_LIMITS.len() {
+            return Finding::new(
+                CHECK,
+                Severity::Skipped,
+                "the semaphore limits could not be read".to_string(),
+            );
+        }
+
+        let too_small: Vec<_> = MIN_SEMAPHORE_LIMITS
+            .iter()
+            .zip(values)
+            .filter(|((_, min_value), value)| value < min_value)
+            .map(|((name, min_value), value)| (*name, value, *min_value))
+            .collect();
+
+        if too_small.is_empty() {
+            return Finding::new(
+                CHECK,
+                Severity::Ok,
+                "the semaphore limits are sufficient".to_string(),
+            );
+        }
+
+        Finding::new(
+            CHECK,
+            Severity::Warning,
+            format!("restrictive limits: {}", describe_limits(&too_small)),
+        )
+        .with_remediation(format!(
+            "raise them with \"sysctl -w kernel.sem=\\\"{}\\\"\"",
+            MIN_SEMAPHORE_LIMITS
+                .iter()
+                .map(|(_, v)| v.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        ))
+    }
+
+    fn check_huge_pages(&self) -> Finding {
+        const CHECK: &str = "huge pages";
+        let setting = match self.read(
+            &self
+                .sys_path
+                .join("kernel/mm/transparent_hugepage/shmem_enabled"),
+        ) {
+            Some(setting) => setting,
+            None => {
+                return Finding::new(
+                    CHECK,
+                    Severity::Skipped,
+                    "the transparent huge page setting for shared memory could not be read"
+                        .to_string(),
+                )
+            }
+        };
+
+        // the active setting is enclosed in brackets, e.g. "always within_size advise [never]"
+        let active = setting
+            .split_whitespace()
+            .find(|v| v.starts_with('['))
+            .map(|v| v.trim_matches(|c| c == '[' || c == ']'))
+            .unwrap_or("unknown");
+
+        match active {
+            "always" | "force" => Finding::new(
+                CHECK,
+                Severity::Warning,
+                format!(
+                    "transparent huge pages are used for every shared memory segment ({}), small segments occupy up to 2MB each",
+                    active
+                ),
+            )
+            .with_remediation(
+                "use \"echo advise > /sys/kernel/mm/transparent_hugepage/shmem_enabled\"".to_string(),
+            ),
+            _ => Finding::new(
+                CHECK,
+                Severity::Ok,
+                format!("transparent huge pages for shared memory are set to {}", active),
+            ),
+        }
+    }
+}
+
+fn probe_write_access(path: &Path) -> std::io::Result<()> {
+    let probe = path.join(PROBE_FILE_NAME);
+    fs::write(&probe, b"")?;
+    fs::remove_file(&probe)
+}
+
+fn describe_limits(limits: &[(&str, u64, u64)]) -> String {
+    limits
+        .iter()
+        .map(|(name, value, min_value)| format!("{} = {} (recommended {})", name, value, min_value))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn limits_finding(
+    check: &'static str,
+    sysctl_prefix: &str,
+    too_small: &[(&str, u64, u64)],
+) -> Finding {
+    if too_small.is_empty() {
+        return Finding::new(check, Severity::Ok, "the limits are sufficient".to_string());
+    }
+
+    Finding::new(
+        check,
+        Severity::Warning,
+        format!("restrictive limits: {}", describe_limits(too_small)),
+    )
+    .with_remediation(format!(
+        "raise them with {}",
+        too_small
+            .iter()
+            .map(|(name, _, min_value)| format!(
+                "\"sysctl -w {}.{}={}\"",
+                sysctl_prefix, name, min_value
+            ))
+            .collect::<Vec<_>>()
+            .join(" ")
+    ))
+}
+
+// parses the size option of a tmpfs, e.g. "65536k" or "1g", a relative size like "50%" is
+// not evaluated
+fn parse_size(value: &str) -> Option<u64> {
+    let (number, factor) = match value.chars().last()? {
+        'k' | 'K' => (&value[..value.len() - 1], 1024),
+        'm' | 'M' => (&value[..value.len() - 1], 1024 * 1024),
+        'g' | 'G' => (&value[..value.len() - 1], 1024 * 1024 * 1024),
+        _ => (value, 1),
+    };
+
+    number.parse::<u64>().ok().map(|v| v * factor)
+}
@@ -0,0 +1,159 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use iceoryx2::config::Config;
+use iceoryx2::port::flight_recorder::FlightRecording;
+use iceoryx2_bb_log::{set_log_level, LogLevel};
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_cli::doctor::{Doctor, Severity};
+
+#[derive(Parser, Debug)]
+#[clap(version, about, long_about = None)]
+struct Args {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Checks the host for common problems and prints hints how to fix them
+    Doctor {
+        /// The config file whose directories shall be checked
+        #[clap(short, long)]
+        config: Option<String>,
+    },
+    /// Prints the samples retained by the flight recorders of the publishers
+    FlightRecorder {
+        /// The config file whose prefix the recordings were created with
+        #[clap(short, long)]
+        config: Option<String>,
+        /// Only print the recordings of this service
+        #[clap(short, long)]
+        service: Option<String>,
+        /// Removes the recordings after they were printed
+        #[clap(short, long)]
+        remove: bool,
+    },
+}
+
+fn load_config(config_file: Option<String>) -> Option<Config> {
+    match config_file {
+        None => Some(Config::get_global_config().clone()),
+        Some(config_file) => {
+            let file_path = match FilePath::try_from(config_file.as_str()) {
+                Ok(v) => v,
+                Err(e) => {
+                    eprintln!("invalid config file path \"{}\" ({:?})", config_file, e);
+                    return None;
+                }
+            };
+
+            match Config::from_file(&file_path) {
+                Ok(v) => Some(v),
+                Err(e) => {
+                    eprintln!("unable to load config file \"{}\" ({:?})", config_file, e);
+                    None
+                }
+            }
+        }
+    }
+}
+
+fn doctor(config_file: Option<String>) -> ExitCode {
+    let config = match load_config(config_file) {
+        Some(v) => v,
+        None => return ExitCode::FAILURE,
+    };
+
+    let findings = Doctor::new(&config).diagnose();
+    for finding in &findings {
+        println!("{}", finding);
+    }
+
+    if findings.iter().any(|f| f.severity() == Severity::Error) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn flight_recorder(config_file: Option<String>, service: Option<String>, remove: bool) -> ExitCode {
+    let config = match load_config(config_file) {
+        Some(v) => v,
+        None => return ExitCode::FAILURE,
+    };
+
+    let mut result = ExitCode::SUCCESS;
+    for name in FlightRecording::list(&config) {
+        let recording = match FlightRecording::open(&name) {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("unable to open flight recording \"{}\" ({})", name, e);
+                result = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        if service
+            .as_ref()
+            .is_some_and(|s| s.as_str() != recording.service_name())
+        {
+            continue;
+        }
+
+        println!(
+            "service \"{}\", publisher {:?}, {} samples sent, capacity {}",
+            recording.service_name(),
+            recording.publisher_id(),
+            recording.number_of_sent_samples(),
+            recording.capacity()
+        );
+        for record in recording.records() {
+            let payload: Vec<String> = record
+                .payload()
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect();
+            println!(
+                "  #{} at {}.{:09}s, {} bytes: {}",
+                record.sequence_number(),
+                record.timestamp().as_secs(),
+                record.timestamp().subsec_nanos(),
+                record.payload_size(),
+                payload.join(" ")
+            );
+        }
+
+        if remove {
+            recording.remove();
+        }
+    }
+
+    result
+}
+
+fn main() -> ExitCode {
+    set_log_level(LogLevel::Error);
+    let args = Args::parse();
+
+    match args.command {
+        Command::Doctor { config } => doctor(config),
+        Command::FlightRecorder {
+            config,
+            service,
+            remove,
+        } => flight_recorder(config, service, remove),
+    }
+}
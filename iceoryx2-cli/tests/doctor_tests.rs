@@ -0,0 +1,209 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use iceoryx2::config::Config;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_cli::doctor::*;
+
+struct FakeSystem {
+    root: PathBuf,
+}
+
+impl FakeSystem {
+    fn new() -> Self {
+        let root = std::env::temp_dir().join(format!(
+            "doctor_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ));
+        fs::create_dir_all(root.join("shm")).unwrap();
+        fs::create_dir_all(root.join("iceoryx2")).unwrap();
+        Self { root }
+    }
+
+    fn write(&self, path: &str, content: &str) -> &Self {
+        let path = self.root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+        self
+    }
+
+    fn write_healthy_limits(&self) -> &Self {
+        self.write("proc/sys/fs/mqueue/queues_max", "256\n")
+            .write("proc/sys/fs/mqueue/msg_max", "10\n")
+            .write("proc/sys/fs/mqueue/msgsize_max", "8192\n")
+            .write("proc/sys/kernel/sem", "32000\t1024000000\t500\t32000\n")
+            .write(
+                "sys/kernel/mm/transparent_hugepage/shmem_enabled",
+                "always within_size advise [never] deny force\n",
+            )
+    }
+
+    fn mount_shm(&self, options: &str) -> &Self {
+        self.write(
+            "proc/mounts",
+            &format!(
+                "proc /proc proc rw 0 0\ntmpfs {} tmpfs {} 0 0\n",
+                self.path("shm").display(),
+                options
+            ),
+        )
+    }
+
+    fn path(&self, path: &str) -> PathBuf {
+        self.root.join(path)
+    }
+
+    fn doctor(&self) -> Doctor {
+        Doctor::new(&Config::default())
+            .proc_path(self.path("proc"))
+            .sys_path(self.path("sys"))
+            .shm_path(self.path("shm"))
+            .root_path(self.path("iceoryx2"))
+    }
+}
+
+impl Drop for FakeSystem {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+fn find<'a>(findings: &'a [Finding], check: &str) -> Vec<&'a Finding> {
+    findings.iter().filter(|f| f.check() == check).collect()
+}
+
+fn has_path(finding: &Finding, path: &Path) -> bool {
+    finding.message().contains(&path.display().to_string())
+}
+
+#[test]
+fn doctor_reports_no_problems_on_healthy_system() {
+    let system = FakeSystem::new();
+    system.write_healthy_limits().mount_shm("rw,nosuid,size=1g");
+
+    let findings = system.doctor().diagnose();
+
+    assert_that!(findings, len 6);
+    for finding in &findings {
+        assert_that!(finding.severity(), eq Severity::Ok);
+        assert_that!(finding.remediation(), is_none);
+    }
+}
+
+#[test]
+fn doctor_warns_about_small_shared_memory() {
+    let system = FakeSystem::new();
+    system
+        .write_healthy_limits()
+        .mount_shm("rw,nosuid,size=65536k");
+
+    let findings = system.doctor().diagnose();
+    let shm = find(&findings, "shared memory");
+
+    assert_that!(shm, len 1);
+    assert_that!(shm[0].severity(), eq Severity::Warning);
+    assert_that!(shm[0].message().contains("67108864"), eq true);
+    assert_that!(shm[0].remediation().unwrap().contains("--shm-size"), eq true);
+
+    let findings = system
+        .doctor()
+        .min_shared_memory_size(64 * 1024 * 1024)
+        .diagnose();
+    assert_that!(find(&findings, "shared memory")[0].severity(), eq Severity::Ok);
+}
+
+#[test]
+fn doctor_reports_missing_shared_memory_mount_as_error() {
+    let system = FakeSystem::new();
+    system
+        .write_healthy_limits()
+        .write("proc/mounts", "proc /proc proc rw 0 0\n");
+
+    let findings = system.doctor().diagnose();
+    let shm = find(&findings, "shared memory");
+
+    assert_that!(shm[0].severity(), eq Severity::Error);
+    assert_that!(has_path(shm[0], &system.path("shm")), eq true);
+}
+
+#[test]
+fn doctor_warns_about_restrictive_limits() {
+    let system = FakeSystem::new();
+    system
+        .write_healthy_limits()
+        .mount_shm("rw")
+        .write("proc/sys/fs/mqueue/msg_max", "4\n")
+        .write("proc/sys/kernel/sem", "250 32000 32 16\n");
+
+    let findings = system.doctor().diagnose();
+
+    let mqueue = find(&findings, "message queues");
+    assert_that!(mqueue[0].severity(), eq Severity::Warning);
+    assert_that!(mqueue[0].message().contains("msg_max = 4"), eq true);
+    assert_that!(mqueue[0].remediation().unwrap().contains("fs.mqueue.msg_max=10"), eq true);
+
+    let sem = find(&findings, "semaphores");
+    assert_that!(sem[0].severity(), eq Severity::Warning);
+    assert_that!(sem[0].message().contains("SEMMNI = 16"), eq true);
+    assert_that!(sem[0].message().contains("SEMMSL"), eq false);
+}
+
+#[test]
+fn doctor_warns_about_forced_huge_pages() {
+    let system = FakeSystem::new();
+    system.write_healthy_limits().mount_shm("rw").write(
+        "sys/kernel/mm/transparent_hugepage/shmem_enabled",
+        "[always] within_size advise never deny force\n",
+    );
+
+    let findings = system.doctor().diagnose();
+    let huge_pages = find(&findings, "huge pages");
+
+    assert_that!(huge_pages[0].severity(), eq Severity::Warning);
+    assert_that!(huge_pages[0].remediation(), is_some);
+}
+
+#[test]
+fn doctor_reports_missing_permissions_as_error() {
+    let system = FakeSystem::new();
+    system.write_healthy_limits().mount_shm("rw");
+    let not_a_directory = system.path("iceoryx2/file");
+    fs::write(&not_a_directory, "").unwrap();
+
+    let findings = system.doctor().root_path(&not_a_directory).diagnose();
+    let permissions = find(&findings, "permissions");
+
+    assert_that!(permissions, len 2);
+    assert_that!(permissions[0].severity(), eq Severity::Error);
+    assert_that!(has_path(permissions[0], &not_a_directory), eq true);
+    assert_that!(permissions[1].severity(), eq Severity::Ok);
+}
+
+#[test]
+fn doctor_skips_checks_when_system_files_are_missing() {
+    let system = FakeSystem::new();
+
+    let findings = system.doctor().diagnose();
+
+    for check in [
+        "shared memory",
+        "message queues",
+        "semaphores",
+        "huge pages",
+    ] {
+        assert_that!(find(&findings, check)[0].severity(), eq Severity::Skipped);
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A minimal HTTP endpoint that serves the metrics to a Prometheus scraper.
+
+use core::time::Duration;
+use std::io::{ErrorKind, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+
+use iceoryx2_bb_log::{fail, warn};
+
+use crate::ExporterError;
+
+const MAX_REQUEST_SIZE: usize = 8192;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The path under which the metrics are served.
+pub const METRICS_PATH: &str = "/metrics";
+
+/// A non-blocking HTTP endpoint that answers `GET` requests on [`METRICS_PATH`]. The
+/// requests are answered in [`Exporter::serve()`](crate::Exporter::serve).
+#[derive(Debug)]
+pub struct HttpEndpoint {
+    listener: TcpListener,
+}
+
+impl HttpEndpoint {
+    /// Binds the [`HttpEndpoint`] to the provided address, for instance `"0.0.0.0:9464"`.
+    pub fn new<A: ToSocketAddrs>(address: A) -> Result<Self, ExporterError> {
+        let msg = "Unable to create http endpoint";
+        let listener = fail!(from "HttpEndpoint::new()", when TcpListener::bind(address),
+            with ExporterError::UnableToCreateEndpoint,
+            "{} since the address could not be bound.", msg);
+
+        fail!(from "HttpEndpoint::new()", when listener.set_nonblocking(true),
+            with ExporterError::UnableToCreateEndpoint,
+            "{} since the socket could not be set to non-blocking.", msg);
+
+        Ok(Self { listener })
+    }
+
+    /// Returns the address the [`HttpEndpoint`] is bound to.
+    pub fn local_address(&self) -> Result<SocketAddr, ExporterError> {
+        Ok(fail!(from self, when self.listener.local_addr(),
+            with ExporterError::EndpointFailure,
+            "Unable to acquire the local address of the http endpoint."))
+    }
+
+    pub(crate) fn accept(&self) -> Result<Option<Request>, ExporterError> {
+        match self.listener.accept() {
+            Ok((stream, _)) => Ok(Some(Request { stream })),
+            Err(e) if e.kind() == ErrorKind::WouldBlock => Ok(None),
+            Err(e) => {
+                fail!(from self, with ExporterError::EndpointFailure,
+                    "Unable to accept a new connection ({:?}).", e);
+            }
+        }
+    }
+}
+
+pub(crate) struct Request {
+    stream: TcpStream,
+}
+
+impl Request {
+    /// Returns true when the request asks for the metrics
+    pub(crate) fn is_metrics_request(&mut self) -> bool {
+        let _ = self.stream.set_nonblocking(false);
+        let _ = self.stream.set_read_timeout(Some(REQUEST_TIMEOUT));
+
+        let mut request = vec![];
+        let mut buffer = [0u8; 1024];
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") && request.len() < MAX_REQUEST_SIZE {
+            match self.stream.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(n) => request.extend_from_slice(&buffer[..n]),
+                Err(e) => {
+                    warn!(from "Request::is_metrics_request()", "Unable to read the request ({:?}).", e);
+                    return false;
+                }
+            }
+        }
+
+        let request = String::from_utf8_lossy(&request);
+        let mut request_line = request.lines().next().unwrap_or("").split_whitespace();
+        let method = request_line.next();
+        let path = request_line
+            .next()
+            .map(|p| p.split('?').next().unwrap_or(p));
+
+        method == Some("GET") && path == Some(METRICS_PATH)
+    }
+
+    pub(crate) fn respond(mut self, status: &str, body: &str) {
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: text/plain; version=0.0.4; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+
+        if let Err(e) = self.stream.write_all(response.as_bytes()) {
+            warn!(from "Request::respond()", "Unable to send the response ({:?}).", e);
+        }
+    }
+}
@@ -0,0 +1,383 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::fmt::Debug;
+use core::time::Duration;
+use std::collections::BTreeMap;
+
+use iceoryx2::port::dead_letter::{DeadLetter, DeadLetterReason};
+use iceoryx2::port::subscriber::Subscriber;
+use iceoryx2::prelude::*;
+use iceoryx2::service;
+use iceoryx2::service::messaging_pattern::MessagingPattern;
+use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
+use iceoryx2_bb_log::{fail, warn};
+
+use crate::endpoint::HttpEndpoint;
+use crate::metrics::{encode, Metric, MetricType};
+
+/// The failures that can occur while the [`Exporter`] collects or exports the metrics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExporterError {
+    /// The nodes of the system could not be listed.
+    UnableToListNodes,
+    /// The services of the system could not be listed.
+    UnableToListServices,
+    /// The textfile could not be written, see [`Exporter::write_textfile()`].
+    UnableToWriteTextfile,
+    /// The [`HttpEndpoint`] could not be created.
+    UnableToCreateEndpoint,
+    /// The [`HttpEndpoint`] failed while accepting connections.
+    EndpointFailure,
+}
+
+impl std::fmt::Display for ExporterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ExporterError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ExporterError {}
+
+struct DeadLetterRoute<Service: service::Service> {
+    subscriber: Subscriber<Service, [u8]>,
+    _service: PortFactory<Service, [u8]>,
+}
+
+/// Collects the state of all nodes and services that share the config of the [`Node`] and
+/// exports it as Prometheus metrics, either over an [`HttpEndpoint`] or as textfile for the
+/// textfile collector of the node exporter.
+///
+/// | metric                                   | type    | labels                       |
+/// |------------------------------------------|---------|------------------------------|
+/// | `iceoryx2_nodes`                         | gauge   | `state`                      |
+/// | `iceoryx2_services`                      | gauge   | `messaging_pattern`          |
+/// | `iceoryx2_service_max_publishers`        | gauge   | `service`                    |
+/// | `iceoryx2_service_max_subscribers`       | gauge   | `service`                    |
+/// | `iceoryx2_service_subscriber_max_buffer_size` | gauge | `service`                  |
+/// | `iceoryx2_dead_letters_total`            | counter | `dead_letter_service`, `reason` |
+/// | `iceoryx2_node_resources`                | gauge   | `node`, `resource`           |
+///
+/// The `iceoryx2_node_resources` are the
+/// [`NodeResourceUsage`](iceoryx2::node::resource_usage::NodeResourceUsage) of the [`Node`]
+/// the [`Exporter`] was created with. An application that embeds the [`Exporter`] with its own
+/// [`Node`] exports therefore the resources it holds.
+///
+/// The lost samples are counted by subscribing to the dead-letter services of all services,
+/// see [`publish_subscribe::Builder::dead_letter_service()`](iceoryx2::service::builder::publish_subscribe::Builder::dead_letter_service).
+/// Only samples that were lost after the [`Exporter`] subscribed are counted and the
+/// [`Exporter`] must collect the metrics before the buffer of its dead-letter subscriber
+/// overflows.
+///
+/// ```no_run
+/// use iceoryx2::prelude::*;
+/// use iceoryx2_exporter::*;
+/// use core::time::Duration;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+/// let mut exporter = Exporter::new(&node);
+///
+/// let endpoint = HttpEndpoint::new("0.0.0.0:9464")?;
+/// exporter.run(&endpoint, Duration::from_millis(100))?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Exporter<'a, Service: service::Service> {
+    node: &'a Node<Service>,
+    dead_letter_routes: BTreeMap<String, DeadLetterRoute<Service>>,
+    dead_letters: BTreeMap<(String, &'static str), u64>,
+}
+
+impl<'a, Service: service::Service> Debug for Exporter<'a, Service> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Exporter<{}> {{ dead_letter_services: {:?}, dead_letters: {:?} }}",
+            core::any::type_name::<Service>(),
+            self.dead_letter_routes.keys().collect::<Vec<_>>(),
+            self.dead_letters
+        )
+    }
+}
+
+fn reason_label(reason: DeadLetterReason) -> &'static str {
+    match reason {
+        DeadLetterReason::Overflow => "overflow",
+        DeadLetterReason::Discarded => "discarded",
+    }
+}
+
+impl<'a, Service: service::Service> Exporter<'a, Service> {
+    /// Creates a new [`Exporter`] that uses the [`Node`] to access the system.
+    pub fn new(node: &'a Node<Service>) -> Self {
+        Self {
+            node,
+            dead_letter_routes: BTreeMap::new(),
+            dead_letters: BTreeMap::new(),
+        }
+    }
+
+    fn subscribe_to_dead_letter_service(&mut self, name: &ServiceName) {
+        if self.dead_letter_routes.contains_key(name.as_str()) {
+            return;
+        }
+
+        let service = match self
+            .node
+            .service_builder(name.clone())
+            .publish_subscribe::<[u8]>()
+            .open_or_create()
+        {
+            Ok(service) => service,
+            Err(e) => {
+                warn!(from self, "Unable to open the dead-letter service \"{}\" ({:?}).", name, e);
+                return;
+            }
+        };
+
+        // an observer does not take a subscriber slot of the dead-letter service away from the
+        // applications, a service without observer support is subscribed to instead
+        let subscriber = match service.observer_builder().create() {
+            Ok(observer) => Ok(observer),
+            Err(_) => service.subscriber_builder().create(),
+        };
+
+        match subscriber {
+            Ok(subscriber) => {
+                self.dead_letter_routes.insert(
+                    name.to_string(),
+                    DeadLetterRoute {
+                        subscriber,
+                        _service: service,
+                    },
+                );
+            }
+            Err(e) => {
+                warn!(from self, "Unable to subscribe to the dead-letter service \"{}\" ({:?}).", name, e);
+            }
+        }
+    }
+
+    fn count_dead_letters(&mut self) {
+        for (name, route) in &self.dead_letter_routes {
+            loop {
+                match route.subscriber.receive() {
+                    Ok(Some(sample)) => match DeadLetter::from_bytes(sample.payload()) {
+                        Some((letter, _)) => {
+                            *self
+                                .dead_letters
+                                .entry((name.clone(), reason_label(letter.reason())))
+                                .or_default() += 1;
+                        }
+                        None => {
+                            warn!(from "Exporter::count_dead_letters()",
+                                "Received an invalid dead letter from \"{}\".", name);
+                        }
+                    },
+                    Ok(None) => break,
+                    Err(e) => {
+                        warn!(from "Exporter::count_dead_letters()",
+                            "Unable to receive dead letters from \"{}\" ({:?}).", name, e);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Collects all [`Metric`]s of the system.
+    pub fn collect(&mut self) -> Result<Vec<Metric>, ExporterError> {
+        let msg = "Unable to collect metrics";
+        let config = self.node.config().clone();
+
+        let nodes = fail!(from self, when Node::<Service>::list(&config),
+            with ExporterError::UnableToListNodes,
+            "{} since the nodes could not be listed.", msg);
+        let services = fail!(from self, when Service::list(&config),
+            with ExporterError::UnableToListServices,
+            "{} since the services could not be listed.", msg);
+
+        let mut node_metric = Metric::new(
+            "iceoryx2_nodes",
+            "Number of nodes per state",
+            MetricType::Gauge,
+        );
+        let number_of_alive_nodes = nodes
+            .iter()
+            .filter(|n| matches!(n, NodeState::Alive(_)))
+            .count();
+        node_metric.add_sample(&[("state", "alive")], number_of_alive_nodes as u64);
+        node_metric.add_sample(
+            &[("state", "dead")],
+            (nodes.len() - number_of_alive_nodes) as u64,
+        );
+
+        let mut service_metric = Metric::new(
+            "iceoryx2_services",
+            "Number of services per messaging pattern",
+            MetricType::Gauge,
+        );
+        let mut max_publishers = Metric::new(
+            "iceoryx2_service_max_publishers",
+            "Maximum number of publishers of a publish-subscribe service",
+            MetricType::Gauge,
+        );
+        let mut max_subscribers = Metric::new(
+            "iceoryx2_service_max_subscribers",
+            "Maximum number of subscribers of a publish-subscribe service",
+            MetricType::Gauge,
+        );
+        let mut max_buffer_size = Metric::new(
+            "iceoryx2_service_subscriber_max_buffer_size",
+            "Maximum buffer size of the subscribers of a publish-subscribe service",
+            MetricType::Gauge,
+        );
+
+        let mut number_of_publish_subscribe_services = 0;
+        let mut number_of_event_services = 0;
+        for service in &services {
+            match service.messaging_pattern() {
+                MessagingPattern::PublishSubscribe(details) => {
+                    number_of_publish_subscribe_services += 1;
+                    let labels = [("service", service.name().as_str())];
+                    max_publishers.add_sample(&labels, details.max_supported_publishers() as u64);
+                    max_subscribers.add_sample(&labels, details.max_supported_subscribers() as u64);
+                    max_buffer_size
+                        .add_sample(&labels, details.subscriber_max_buffer_size() as u64);
+
+                    if let Some(name) = details.dead_letter_service() {
+                        self.subscribe_to_dead_letter_service(name);
+                    }
+                }
+                MessagingPattern::Event(_) => number_of_event_services += 1,
+                _ => (),
+            }
+        }
+        service_metric.add_sample(
+            &[("messaging_pattern", "publish_subscribe")],
+            number_of_publish_subscribe_services,
+        );
+        service_metric.add_sample(&[("messaging_pattern", "event")], number_of_event_services);
+
+        self.count_dead_letters();
+        let mut dead_letter_metric = Metric::new(
+            "iceoryx2_dead_letters",
+            "Number of samples that could not be delivered to a subscriber",
+            MetricType::Counter,
+        );
+        for ((name, reason), value) in &self.dead_letters {
+            dead_letter_metric.add_sample(
+                &[("dead_letter_service", name.as_str()), ("reason", reason)],
+                *value,
+            );
+        }
+
+        let usage = self.node.resource_usage();
+        let node_id = self.node.id().value().to_string();
+        let mut node_resources = Metric::new(
+            "iceoryx2_node_resources",
+            "Resources held by the node of the exporter",
+            MetricType::Gauge,
+        );
+        for (resource, value) in [
+            ("services", usage.number_of_services()),
+            ("publishers", usage.number_of_publishers()),
+            ("subscribers", usage.number_of_subscribers()),
+            ("notifiers", usage.number_of_notifiers()),
+            ("listeners", usage.number_of_listeners()),
+            ("connections", usage.number_of_connections()),
+            ("file_descriptors", usage.number_of_file_descriptors()),
+            ("shared_memory_bytes", usage.shared_memory_bytes()),
+        ] {
+            node_resources.add_sample(
+                &[("node", node_id.as_str()), ("resource", resource)],
+                value as u64,
+            );
+        }
+
+        Ok(vec![
+            node_metric,
+            service_metric,
+            max_publishers,
+            max_subscribers,
+            max_buffer_size,
+            dead_letter_metric,
+            node_resources,
+        ])
+    }
+
+    /// Collects all [`Metric`]s and encodes them in the Prometheus text exposition format.
+    pub fn render(&mut self) -> Result<String, ExporterError> {
+        Ok(encode(&self.collect()?))
+    }
+
+    /// Collects all [`Metric`]s and writes them into a textfile that can be read by the
+    /// textfile collector of the node exporter. The file is replaced atomically.
+    pub fn write_textfile(&mut self, path: &std::path::Path) -> Result<(), ExporterError> {
+        let msg = "Unable to write textfile";
+        let content = self.render()?;
+
+        let mut temporary_path = path.as_os_str().to_owned();
+        temporary_path.push(".tmp");
+
+        fail!(from self, when std::fs::write(&temporary_path, content),
+            with ExporterError::UnableToWriteTextfile,
+            "{} since the temporary file {:?} could not be written.", msg, temporary_path);
+
+        fail!(from self, when std::fs::rename(&temporary_path, path),
+            with ExporterError::UnableToWriteTextfile,
+            "{} since the temporary file {:?} could not be moved to {:?}.", msg, temporary_path, path);
+
+        Ok(())
+    }
+
+    /// Answers all pending requests of the [`HttpEndpoint`] and returns the number of requests
+    /// that were answered. Does not block when no request is pending.
+    pub fn serve(&mut self, endpoint: &HttpEndpoint) -> Result<usize, ExporterError> {
+        let mut number_of_requests = 0;
+        while let Some(mut request) = endpoint.accept()? {
+            number_of_requests += 1;
+            if !request.is_metrics_request() {
+                request.respond("404 Not Found", "");
+                continue;
+            }
+
+            match self.render() {
+                Ok(metrics) => request.respond("200 OK", &metrics),
+                Err(e) => {
+                    warn!(from self, "Unable to collect the metrics for the request ({:?}).", e);
+                    request.respond("500 Internal Server Error", "");
+                }
+            }
+        }
+
+        Ok(number_of_requests)
+    }
+
+    /// Calls [`Exporter::serve()`] every `cycle_time` until a termination request or an
+    /// interrupt signal was received.
+    pub fn run(
+        &mut self,
+        endpoint: &HttpEndpoint,
+        cycle_time: Duration,
+    ) -> Result<(), ExporterError> {
+        loop {
+            self.serve(endpoint)?;
+
+            match self.node.wait(cycle_time) {
+                NodeEvent::Tick => (),
+                NodeEvent::TerminationRequest | NodeEvent::InterruptSignal => return Ok(()),
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # iceoryx2-exporter
+//!
+//! Exports the state of an iceoryx2 system as Prometheus metrics so that standard fleet
+//! monitoring can alert on dead nodes and lost samples.
+//!
+//! * [`Exporter::serve()`] - answers the pending scrape requests of an [`HttpEndpoint`]
+//! * [`Exporter::run()`] - serves the [`HttpEndpoint`] cyclically until a termination request
+//!   was received
+//! * [`Exporter::write_textfile()`] - writes the metrics for the textfile collector of the
+//!   node exporter
+
+pub mod endpoint;
+mod exporter;
+pub mod metrics;
+
+pub use endpoint::HttpEndpoint;
+pub use exporter::{Exporter, ExporterError};
+pub use metrics::{Metric, MetricSample, MetricType};
@@ -0,0 +1,155 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`Metric`]s collected by the [`Exporter`](crate::Exporter) and their encoding into the
+//! Prometheus text exposition format.
+
+use core::fmt::Write;
+
+/// The type of a [`Metric`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MetricType {
+    /// A value that can go up and down, like the number of nodes.
+    Gauge,
+    /// A value that only increases, like the number of lost samples.
+    Counter,
+}
+
+impl MetricType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MetricType::Gauge => "gauge",
+            MetricType::Counter => "counter",
+        }
+    }
+}
+
+/// A single value of a [`Metric`] that is identified by its labels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetricSample {
+    labels: Vec<(String, String)>,
+    value: u64,
+}
+
+impl MetricSample {
+    /// Returns the labels as key-value pairs.
+    pub fn labels(&self) -> &[(String, String)] {
+        &self.labels
+    }
+
+    /// Returns the value of the label with the provided key.
+    pub fn label(&self, key: &str) -> Option<&str> {
+        self.labels
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+}
+
+/// A named metric with all its [`MetricSample`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Metric {
+    name: String,
+    help: String,
+    metric_type: MetricType,
+    samples: Vec<MetricSample>,
+}
+
+impl Metric {
+    /// Creates a new [`Metric`] without any [`MetricSample`]s. The name of a
+    /// [`MetricType::Counter`] must not contain the `_total` suffix, it is added when it is
+    /// encoded.
+    pub fn new(name: &str, help: &str, metric_type: MetricType) -> Self {
+        Self {
+            name: name.to_string(),
+            help: help.to_string(),
+            metric_type,
+            samples: vec![],
+        }
+    }
+
+    /// Adds a [`MetricSample`] with the provided labels.
+    pub fn add_sample(&mut self, labels: &[(&str, &str)], value: u64) {
+        self.samples.push(MetricSample {
+            labels: labels
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            value,
+        });
+    }
+
+    /// Returns the name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the help text.
+    pub fn help(&self) -> &str {
+        &self.help
+    }
+
+    /// Returns the [`MetricType`].
+    pub fn metric_type(&self) -> MetricType {
+        self.metric_type
+    }
+
+    /// Returns all [`MetricSample`]s.
+    pub fn samples(&self) -> &[MetricSample] {
+        &self.samples
+    }
+}
+
+fn escape(value: &str, escape_quotes: bool) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '"' if escape_quotes => escaped.push_str("\\\""),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Encodes the [`Metric`]s in the Prometheus text exposition format version 0.0.4.
+pub fn encode(metrics: &[Metric]) -> String {
+    let mut output = String::new();
+    for metric in metrics {
+        let name = match metric.metric_type {
+            MetricType::Gauge => metric.name.clone(),
+            MetricType::Counter => format!("{}_total", metric.name),
+        };
+
+        let _ = writeln!(output, "# HELP {} {}", name, escape(&metric.help, false));
+        let _ = writeln!(output, "# TYPE {} {}", name, metric.metric_type.as_str());
+        for sample in &metric.samples {
+            output.push_str(&name);
+            if !sample.labels.is_empty() {
+                let labels: Vec<String> = sample
+                    .labels
+                    .iter()
+                    .map(|(k, v)| format!("{}=\"{}\"", k, escape(v, true)))
+                    .collect();
+                let _ = write!(output, "{{{}}}", labels.join(","));
+            }
+            let _ = writeln!(output, " {}", sample.value);
+        }
+    }
+    output
+}
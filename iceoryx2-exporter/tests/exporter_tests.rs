@@ -0,0 +1,193 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod exporter {
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Service;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_exporter::*;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "exporter_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    // every test has its own prefix so that it sees only its own nodes and services
+    fn generate_isolated_config() -> Config {
+        let mut config = Config::default();
+        config.global.prefix =
+            FileName::new(format!("ex_{}_", UniqueSystemId::new().unwrap().value()).as_bytes())
+                .unwrap();
+        config
+    }
+
+    fn value_of(metrics: &[Metric], name: &str, label: (&str, &str)) -> Option<u64> {
+        metrics
+            .iter()
+            .find(|m| m.name() == name)?
+            .samples()
+            .iter()
+            .find(|s| s.label(label.0) == Some(label.1))
+            .map(|s| s.value())
+    }
+
+    #[test]
+    fn nodes_and_services_are_exported<S: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _node_2 = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let _pubsub = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .max_publishers(3)
+            .create()
+            .unwrap();
+        let _event = node
+            .service_builder(generate_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let mut sut = Exporter::new(&node);
+        let metrics = sut.collect().unwrap();
+
+        assert_that!(value_of(&metrics, "iceoryx2_nodes", ("state", "alive")), eq Some(2));
+        assert_that!(value_of(&metrics, "iceoryx2_nodes", ("state", "dead")), eq Some(0));
+        assert_that!(value_of(&metrics, "iceoryx2_services", ("messaging_pattern", "publish_subscribe")), eq Some(1));
+        assert_that!(value_of(&metrics, "iceoryx2_services", ("messaging_pattern", "event")), eq Some(1));
+        assert_that!(value_of(&metrics, "iceoryx2_service_max_publishers", ("service", service_name.as_str())), eq Some(3));
+
+        let rendered = sut.render().unwrap();
+        assert_that!(rendered.contains("iceoryx2_nodes{state=\"alive\"} 2"), eq true);
+    }
+
+    #[test]
+    fn resources_of_the_node_are_exported<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let pubsub = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let _publisher = pubsub.publisher_builder().create().unwrap();
+
+        let mut sut = Exporter::new(&node);
+        let metrics = sut.collect().unwrap();
+
+        assert_that!(value_of(&metrics, "iceoryx2_node_resources", ("resource", "services")), eq Some(1));
+        assert_that!(value_of(&metrics, "iceoryx2_node_resources", ("resource", "publishers")), eq Some(1));
+        assert_that!(value_of(&metrics, "iceoryx2_node_resources", ("resource", "subscribers")), eq Some(0));
+        assert_that!(value_of(&metrics, "iceoryx2_node_resources", ("resource", "shared_memory_bytes")),
+            eq Some(node.resource_usage().shared_memory_bytes() as u64));
+    }
+
+    #[test]
+    fn dead_letters_are_counted<S: Service>() {
+        let config = generate_isolated_config();
+        let dead_letter_name = generate_name();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let service = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(true)
+            .dead_letter_service(dead_letter_name.clone(), false)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let _subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut sut = Exporter::new(&node);
+        let label = ("dead_letter_service", dead_letter_name.as_str());
+        assert_that!(value_of(&sut.collect().unwrap(), "iceoryx2_dead_letters", label), eq None);
+
+        for i in 0..3 {
+            publisher.send_copy(i).unwrap();
+        }
+
+        let metrics = sut.collect().unwrap();
+        assert_that!(value_of(&metrics, "iceoryx2_dead_letters", label), eq Some(2));
+        assert_that!(value_of(&metrics, "iceoryx2_dead_letters", ("reason", "overflow")), eq Some(2));
+    }
+
+    #[test]
+    fn http_endpoint_serves_metrics<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let mut sut = Exporter::new(&node);
+        let endpoint = HttpEndpoint::new("127.0.0.1:0").unwrap();
+        let address = endpoint.local_address().unwrap();
+
+        let request = |path: &'static str| {
+            std::thread::spawn(move || {
+                let mut stream = TcpStream::connect(address).unwrap();
+                stream
+                    .write_all(
+                        format!("GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).as_bytes(),
+                    )
+                    .unwrap();
+                let mut response = String::new();
+                stream.read_to_string(&mut response).unwrap();
+                response
+            })
+        };
+
+        for (path, expected_status) in [("/metrics", "200 OK"), ("/other", "404 Not Found")] {
+            let client = request(path);
+            let mut number_of_requests = 0;
+            while number_of_requests == 0 {
+                number_of_requests = sut.serve(&endpoint).unwrap();
+            }
+
+            let response = client.join().unwrap();
+            assert_that!(response.starts_with(&format!("HTTP/1.1 {}", expected_status)), eq true);
+            assert_that!(response.contains("iceoryx2_nodes{state=\"alive\"} 1"), eq expected_status == "200 OK");
+        }
+    }
+
+    #[test]
+    fn textfile_contains_metrics<S: Service>() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let mut sut = Exporter::new(&node);
+        let path = std::env::temp_dir().join(format!(
+            "exporter_tests_{}.prom",
+            UniqueSystemId::new().unwrap().value()
+        ));
+
+        assert_that!(sut.write_textfile(&path), is_ok);
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_that!(content.contains("# TYPE iceoryx2_nodes gauge"), eq true);
+        assert_that!(content.contains("iceoryx2_nodes{state=\"alive\"} 1"), eq true);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
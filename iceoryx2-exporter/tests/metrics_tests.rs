@@ -0,0 +1,39 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_exporter::metrics::*;
+
+#[test]
+fn metrics_encode_gauge_with_labels() {
+    let mut sut = Metric::new("some_gauge", "a help text", MetricType::Gauge);
+    sut.add_sample(&[("a", "1"), ("b", "2")], 12);
+    sut.add_sample(&[], 13);
+
+    assert_that!(encode(&[sut]), eq "# HELP some_gauge a help text\n# TYPE some_gauge gauge\nsome_gauge{a=\"1\",b=\"2\"} 12\nsome_gauge 13\n");
+}
+
+#[test]
+fn metrics_encode_counter_with_total_suffix() {
+    let mut sut = Metric::new("some_counter", "help", MetricType::Counter);
+    sut.add_sample(&[("x", "y")], 5);
+
+    assert_that!(encode(&[sut]), eq "# HELP some_counter_total help\n# TYPE some_counter_total counter\nsome_counter_total{x=\"y\"} 5\n");
+}
+
+#[test]
+fn metrics_encode_escapes_label_values() {
+    let mut sut = Metric::new("escaped", "line\nbreak", MetricType::Gauge);
+    sut.add_sample(&[("path", "a\\b\"c\nd")], 1);
+
+    assert_that!(encode(&[sut]), eq "# HELP escaped line\\nbreak\n# TYPE escaped gauge\nescaped{path=\"a\\\\b\\\"c\\nd\"} 1\n");
+}
@@ -0,0 +1,60 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+
+#include "iox2/iceoryx2.h"
+#include "test.hpp"
+
+namespace {
+
+class Event : public TestWithParam<iox2_service_type_e> {
+  protected:
+    void SetUp() override {
+        ASSERT_THAT(iox2_node_create(GetParam(), nullptr, &m_node), Eq(IOX2_OK));
+        ASSERT_THAT(iox2_event_service_open_or_create(m_node, "c_tests/event", &m_service), Eq(IOX2_OK));
+    }
+
+    void TearDown() override {
+        iox2_event_service_drop(m_service);
+        iox2_node_drop(m_node);
+    }
+
+    iox2_node_t* m_node = nullptr;
+    iox2_event_service_t* m_service = nullptr;
+};
+
+TEST_P(Event, NotifiedEventIsReceived) {
+    iox2_notifier_t* notifier = nullptr;
+    iox2_listener_t* listener = nullptr;
+    ASSERT_THAT(iox2_listener_create(m_service, &listener), Eq(IOX2_OK));
+    ASSERT_THAT(iox2_notifier_create(m_service, &notifier), Eq(IOX2_OK));
+
+    size_t number_of_notified_listeners = 0;
+    ASSERT_THAT(iox2_notifier_notify(notifier, 12, &number_of_notified_listeners), Eq(IOX2_OK));
+    ASSERT_THAT(number_of_notified_listeners, Eq(1U));
+
+    bool has_received_event = false;
+    size_t event_id = 0;
+    ASSERT_THAT(iox2_listener_timed_wait_one(listener, 1, 0, &has_received_event, &event_id), Eq(IOX2_OK));
+    ASSERT_THAT(has_received_event, Eq(true));
+    ASSERT_THAT(event_id, Eq(12U));
+
+    ASSERT_THAT(iox2_listener_try_wait_one(listener, &has_received_event, &event_id), Eq(IOX2_OK));
+    ASSERT_THAT(has_received_event, Eq(false));
+
+    iox2_notifier_drop(notifier);
+    iox2_listener_drop(listener);
+}
+
+INSTANTIATE_TEST_SUITE_P(ServiceTypes, Event, Values(IOX2_SERVICE_TYPE_IPC, IOX2_SERVICE_TYPE_LOCAL));
+
+} // namespace
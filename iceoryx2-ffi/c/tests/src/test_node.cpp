@@ -10,12 +10,30 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+
+#include "iox2/iceoryx2.h"
 #include "test.hpp"
 
 namespace {
 
 TEST(Node, CreatingNodeWorks) {
-    ASSERT_THAT(true, Eq(true));
+    iox2_node_t* node = nullptr;
+    ASSERT_THAT(iox2_node_create(IOX2_SERVICE_TYPE_IPC, nullptr, &node), Eq(IOX2_OK));
+    ASSERT_THAT(node, Ne(nullptr));
+
+    iox2_node_drop(node);
+}
+
+TEST(Node, CreatingNodeWithNameWorks) {
+    iox2_node_t* node = nullptr;
+    ASSERT_THAT(iox2_node_create(IOX2_SERVICE_TYPE_LOCAL, "my_node", &node), Eq(IOX2_OK));
+    ASSERT_THAT(node, Ne(nullptr));
+
+    iox2_node_drop(node);
+}
+
+TEST(Node, CreatingNodeWithoutHandleFails) {
+    ASSERT_THAT(iox2_node_create(IOX2_SERVICE_TYPE_IPC, nullptr, nullptr), Eq(IOX2_INVALID_ARGUMENT));
 }
 
 } // namespace
@@ -0,0 +1,156 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+
+#include "iox2/iceoryx2.h"
+#include "test.hpp"
+
+#include <cstdint>
+#include <cstring>
+#include <string>
+#include <utility>
+#include <vector>
+
+namespace {
+
+class PublishSubscribe : public TestWithParam<iox2_service_type_e> {
+  protected:
+    void SetUp() override {
+        ASSERT_THAT(iox2_node_create(GetParam(), nullptr, &m_node), Eq(IOX2_OK));
+        ASSERT_THAT(iox2_publish_subscribe_service_open_or_create(m_node,
+                                                                  "c_tests/publish_subscribe",
+                                                                  "u64",
+                                                                  IOX2_TYPE_VARIANT_FIXED_SIZE,
+                                                                  sizeof(uint64_t),
+                                                                  alignof(uint64_t),
+                                                                  &m_service),
+                    Eq(IOX2_OK));
+    }
+
+    void TearDown() override {
+        iox2_publish_subscribe_service_drop(m_service);
+        iox2_node_drop(m_node);
+    }
+
+    iox2_node_t* m_node = nullptr;
+    iox2_publish_subscribe_service_t* m_service = nullptr;
+};
+
+TEST_P(PublishSubscribe, SentSampleIsReceived) {
+    iox2_publisher_t* publisher = nullptr;
+    iox2_subscriber_t* subscriber = nullptr;
+    ASSERT_THAT(iox2_publisher_create(m_service, 1, &publisher), Eq(IOX2_OK));
+    ASSERT_THAT(iox2_subscriber_create(m_service, &subscriber), Eq(IOX2_OK));
+
+    iox2_sample_mut_t* sample_mut = nullptr;
+    ASSERT_THAT(iox2_publisher_loan(publisher, 1, &sample_mut), Eq(IOX2_OK));
+    size_t payload_size = 0;
+    auto* payload = static_cast<uint64_t*>(iox2_sample_mut_payload_mut(sample_mut, &payload_size));
+    ASSERT_THAT(payload_size, Eq(sizeof(uint64_t)));
+    *payload = 1234;
+
+    size_t number_of_recipients = 0;
+    ASSERT_THAT(iox2_sample_mut_send(sample_mut, &number_of_recipients), Eq(IOX2_OK));
+    ASSERT_THAT(number_of_recipients, Eq(1U));
+
+    iox2_sample_t* sample = nullptr;
+    ASSERT_THAT(iox2_subscriber_receive(subscriber, &sample), Eq(IOX2_OK));
+    ASSERT_THAT(sample, Ne(nullptr));
+    ASSERT_THAT(*static_cast<const uint64_t*>(iox2_sample_payload(sample, &payload_size)), Eq(1234U));
+    ASSERT_THAT(payload_size, Eq(sizeof(uint64_t)));
+    iox2_sample_drop(sample);
+
+    ASSERT_THAT(iox2_subscriber_receive(subscriber, &sample), Eq(IOX2_OK));
+    ASSERT_THAT(sample, Eq(nullptr));
+
+    iox2_subscriber_drop(subscriber);
+    iox2_publisher_drop(publisher);
+}
+
+TEST_P(PublishSubscribe, OpeningServiceWithDifferentPayloadFails) {
+    iox2_publish_subscribe_service_t* service = nullptr;
+    ASSERT_THAT(iox2_publish_subscribe_service_open_or_create(m_node,
+                                                              "c_tests/publish_subscribe",
+                                                              "u32",
+                                                              IOX2_TYPE_VARIANT_FIXED_SIZE,
+                                                              sizeof(uint32_t),
+                                                              alignof(uint32_t),
+                                                              &service),
+                Eq(IOX2_SERVICE_OPEN_OR_CREATE_FAILED));
+}
+
+TEST_P(PublishSubscribe, SampleHeaderContainsPublisherGidAndSequenceNumbers) {
+    iox2_publisher_t* publisher = nullptr;
+    iox2_subscriber_t* subscriber = nullptr;
+    ASSERT_THAT(iox2_publisher_create(m_service, 1, &publisher), Eq(IOX2_OK));
+    ASSERT_THAT(iox2_subscriber_create(m_service, &subscriber), Eq(IOX2_OK));
+
+    uint8_t publisher_gid[IOX2_GID_SIZE] = {};
+    ASSERT_THAT(iox2_publisher_gid(publisher, publisher_gid), Eq(IOX2_OK));
+
+    constexpr uint64_t NUMBER_OF_SAMPLES = 2;
+    for (uint64_t i = 0; i < NUMBER_OF_SAMPLES; ++i) {
+        iox2_sample_mut_t* sample_mut = nullptr;
+        ASSERT_THAT(iox2_publisher_loan(publisher, 1, &sample_mut), Eq(IOX2_OK));
+        *static_cast<uint64_t*>(iox2_sample_mut_payload_mut(sample_mut, nullptr)) = i;
+        ASSERT_THAT(iox2_sample_mut_send(sample_mut, nullptr), Eq(IOX2_OK));
+    }
+
+    for (uint64_t i = 0; i < NUMBER_OF_SAMPLES; ++i) {
+        iox2_sample_t* sample = nullptr;
+        ASSERT_THAT(iox2_subscriber_receive(subscriber, &sample), Eq(IOX2_OK));
+        ASSERT_THAT(sample, Ne(nullptr));
+
+        iox2_sample_header_t header;
+        ASSERT_THAT(iox2_sample_header(sample, &header), Eq(IOX2_OK));
+        ASSERT_THAT(std::memcmp(header.publisher_gid, publisher_gid, IOX2_GID_SIZE), Eq(0));
+        ASSERT_THAT(header.publisher_sequence_number, Eq(i));
+        ASSERT_THAT(header.publish_timestamp_ns, Gt(0U));
+        iox2_sample_drop(sample);
+    }
+
+    iox2_subscriber_drop(subscriber);
+    iox2_publisher_drop(publisher);
+}
+
+TEST_P(PublishSubscribe, NumberOfPortsIsAvailable) {
+    ASSERT_THAT(iox2_publish_subscribe_service_number_of_publishers(m_service), Eq(0U));
+    ASSERT_THAT(iox2_publish_subscribe_service_number_of_subscribers(m_service), Eq(0U));
+
+    iox2_publisher_t* publisher = nullptr;
+    iox2_subscriber_t* subscriber = nullptr;
+    ASSERT_THAT(iox2_publisher_create(m_service, 1, &publisher), Eq(IOX2_OK));
+    ASSERT_THAT(iox2_subscriber_create(m_service, &subscriber), Eq(IOX2_OK));
+
+    ASSERT_THAT(iox2_publish_subscribe_service_number_of_publishers(m_service), Eq(1U));
+    ASSERT_THAT(iox2_publish_subscribe_service_number_of_subscribers(m_service), Eq(1U));
+
+    iox2_subscriber_drop(subscriber);
+    iox2_publisher_drop(publisher);
+}
+
+TEST_P(PublishSubscribe, ServiceListContainsService) {
+    using Entry = std::pair<std::string, std::string>;
+    std::vector<Entry> services;
+    auto callback = [](const char* service_name, const char* payload_type_name, void* context) {
+        static_cast<std::vector<Entry>*>(context)->emplace_back(service_name, payload_type_name);
+    };
+
+    ASSERT_THAT(iox2_publish_subscribe_service_list(m_node, callback, &services), Eq(IOX2_OK));
+    ASSERT_THAT(services, Contains(Entry("c_tests/publish_subscribe", "u64")));
+}
+
+INSTANTIATE_TEST_SUITE_P(ServiceTypes,
+                         PublishSubscribe,
+                         Values(IOX2_SERVICE_TYPE_IPC, IOX2_SERVICE_TYPE_LOCAL));
+
+} // namespace
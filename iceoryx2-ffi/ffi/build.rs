@@ -32,6 +32,10 @@ fn main() {
 
     let mut config = Config::default();
     config.language = cbindgen::Language::C;
+    config.cpp_compat = true;
+    // the C API functions return the error codes as int32_t, therefore the enum is not
+    // referenced by any signature and must be exported explicitly
+    config.export.include.push("iox2_error_e".to_string());
 
     cbindgen::Builder::new()
         .with_crate(crate_dir)
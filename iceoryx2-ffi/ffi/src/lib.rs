@@ -10,14 +10,80 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#![allow(non_camel_case_types)]
+
+use core::ffi::{c_char, CStr};
 use iceoryx2::prelude::*;
 use iceoryx2_bb_log::set_log_level;
 
+mod listener;
+mod node;
+mod notifier;
 mod publisher;
+mod service;
 mod subscriber;
+mod type_layout;
 
+pub use listener::*;
+pub use node::*;
+pub use notifier::*;
 pub use publisher::*;
+pub use service::*;
 pub use subscriber::*;
+pub use type_layout::*;
+
+/// The return values of the C API functions. Every function that can fail returns one of
+/// these values as `int32_t`, [`iox2_error_e::IOX2_OK`] signals success.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum iox2_error_e {
+    IOX2_OK = 0,
+    IOX2_INVALID_ARGUMENT,
+    IOX2_NODE_CREATION_FAILED,
+    IOX2_SERVICE_OPEN_OR_CREATE_FAILED,
+    IOX2_PORT_CREATION_FAILED,
+    IOX2_LOAN_FAILED,
+    IOX2_SEND_FAILED,
+    IOX2_RECEIVE_FAILED,
+    IOX2_NOTIFY_FAILED,
+    IOX2_WAIT_FAILED,
+    IOX2_SERVICE_LIST_FAILED,
+}
+
+/// Selects the service type of a node. All services and ports that are created from the node
+/// share its service type.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum iox2_service_type_e {
+    /// Inter-process communication via shared memory, see [`zero_copy::Service`].
+    IOX2_SERVICE_TYPE_IPC,
+    /// Communication between threads of the same process, see [`process_local::Service`].
+    IOX2_SERVICE_TYPE_LOCAL,
+}
+
+/// The size of the globally unique id of a publisher, see
+/// [`iox2_publisher_gid()`](crate::iox2_publisher_gid()).
+pub const IOX2_GID_SIZE: usize = 16;
+
+// Stores the object behind an opaque handle for both service types.
+pub(crate) enum ServiceTypeUnion<Ipc, Local> {
+    Ipc(Ipc),
+    Local(Local),
+}
+
+// Converts a null-terminated C string into a str, returns None when it is null or not UTF-8.
+pub(crate) unsafe fn str_from_c<'a>(value: *const c_char) -> Option<&'a str> {
+    if value.is_null() {
+        return None;
+    }
+
+    CStr::from_ptr(value).to_str().ok()
+}
+
+// Converts a port id into the globally unique id that is exposed via the C API.
+pub(crate) fn gid_from_id(value: u128) -> [u8; IOX2_GID_SIZE] {
+    value.to_le_bytes()
+}
 
 #[no_mangle]
 pub extern "C" fn zero_copy_service_list() -> i32 {
@@ -0,0 +1,174 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::port::listener::Listener;
+use iceoryx2::prelude::*;
+
+use crate::{iox2_error_e, iox2_event_service_t, ServiceTypeUnion};
+
+/// The opaque handle of a [`Listener`]. It is created with [`iox2_listener_create()`] and
+/// must be released with [`iox2_listener_drop()`].
+pub struct iox2_listener_t(
+    ServiceTypeUnion<Listener<zero_copy::Service>, Listener<process_local::Service>>,
+);
+
+/// Creates a listener for the provided event service and stores its handle in
+/// `listener_handle`.
+///
+/// # Safety
+///
+///  * `service_handle` must be a valid handle acquired with
+///    [`iox2_event_service_open_or_create()`](crate::iox2_event_service_open_or_create())
+///  * `listener_handle` must be a valid pointer
+#[no_mangle]
+pub unsafe extern "C" fn iox2_listener_create(
+    service_handle: *const iox2_event_service_t,
+    listener_handle: *mut *mut iox2_listener_t,
+) -> i32 {
+    if service_handle.is_null() || listener_handle.is_null() {
+        return iox2_error_e::IOX2_INVALID_ARGUMENT as i32;
+    }
+
+    let listener = match &(*service_handle).0 {
+        ServiceTypeUnion::Ipc(service) => service
+            .listener_builder()
+            .create()
+            .map(ServiceTypeUnion::Ipc),
+        ServiceTypeUnion::Local(service) => service
+            .listener_builder()
+            .create()
+            .map(ServiceTypeUnion::Local),
+    };
+
+    match listener {
+        Ok(listener) => {
+            *listener_handle = Box::into_raw(Box::new(iox2_listener_t(listener)));
+            iox2_error_e::IOX2_OK as i32
+        }
+        Err(_) => iox2_error_e::IOX2_PORT_CREATION_FAILED as i32,
+    }
+}
+
+/// Releases a listener that was created with [`iox2_listener_create()`].
+///
+/// # Safety
+///
+///  * `listener_handle` must be null or a handle acquired with [`iox2_listener_create()`]
+///    that was not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_listener_drop(listener_handle: *mut iox2_listener_t) {
+    if !listener_handle.is_null() {
+        drop(Box::from_raw(listener_handle));
+    }
+}
+
+unsafe fn wait_one<E, F: FnOnce(&iox2_listener_t) -> Result<Option<EventId>, E>>(
+    listener_handle: *const iox2_listener_t,
+    has_received_event: *mut bool,
+    event_id: *mut usize,
+    wait_call: F,
+) -> i32 {
+    if listener_handle.is_null() || has_received_event.is_null() || event_id.is_null() {
+        return iox2_error_e::IOX2_INVALID_ARGUMENT as i32;
+    }
+
+    match wait_call(&*listener_handle) {
+        Ok(Some(id)) => {
+            *has_received_event = true;
+            *event_id = id.as_value();
+            iox2_error_e::IOX2_OK as i32
+        }
+        Ok(None) => {
+            *has_received_event = false;
+            iox2_error_e::IOX2_OK as i32
+        }
+        Err(_) => iox2_error_e::IOX2_WAIT_FAILED as i32,
+    }
+}
+
+/// Non-blocking wait for a new event. When an event was received, `has_received_event` is set
+/// to true and its id is stored in `event_id`, otherwise `has_received_event` is set to false.
+///
+/// # Safety
+///
+///  * `listener_handle` must be a valid handle acquired with [`iox2_listener_create()`]
+///  * `has_received_event` and `event_id` must be valid pointers
+#[no_mangle]
+pub unsafe extern "C" fn iox2_listener_try_wait_one(
+    listener_handle: *const iox2_listener_t,
+    has_received_event: *mut bool,
+    event_id: *mut usize,
+) -> i32 {
+    wait_one(
+        listener_handle,
+        has_received_event,
+        event_id,
+        |listener| match &listener.0 {
+            ServiceTypeUnion::Ipc(listener) => listener.try_wait_one(),
+            ServiceTypeUnion::Local(listener) => listener.try_wait_one(),
+        },
+    )
+}
+
+/// Blocking wait for a new event until the timeout of `seconds` and `nanoseconds` has passed.
+/// When an event was received, `has_received_event` is set to true and its id is stored in
+/// `event_id`, otherwise `has_received_event` is set to false.
+///
+/// # Safety
+///
+///  * `listener_handle` must be a valid handle acquired with [`iox2_listener_create()`]
+///  * `has_received_event` and `event_id` must be valid pointers
+#[no_mangle]
+pub unsafe extern "C" fn iox2_listener_timed_wait_one(
+    listener_handle: *const iox2_listener_t,
+    seconds: u64,
+    nanoseconds: u32,
+    has_received_event: *mut bool,
+    event_id: *mut usize,
+) -> i32 {
+    let timeout = Duration::new(seconds, nanoseconds);
+    wait_one(
+        listener_handle,
+        has_received_event,
+        event_id,
+        |listener| match &listener.0 {
+            ServiceTypeUnion::Ipc(listener) => listener.timed_wait_one(timeout),
+            ServiceTypeUnion::Local(listener) => listener.timed_wait_one(timeout),
+        },
+    )
+}
+
+/// Blocks until a new event was received. When the wait was interrupted, e.g. by a signal,
+/// `has_received_event` is set to false, otherwise it is set to true and the id of the
+/// received event is stored in `event_id`.
+///
+/// # Safety
+///
+///  * `listener_handle` must be a valid handle acquired with [`iox2_listener_create()`]
+///  * `has_received_event` and `event_id` must be valid pointers
+#[no_mangle]
+pub unsafe extern "C" fn iox2_listener_blocking_wait_one(
+    listener_handle: *const iox2_listener_t,
+    has_received_event: *mut bool,
+    event_id: *mut usize,
+) -> i32 {
+    wait_one(
+        listener_handle,
+        has_received_event,
+        event_id,
+        |listener| match &listener.0 {
+            ServiceTypeUnion::Ipc(listener) => listener.blocking_wait_one(),
+            ServiceTypeUnion::Local(listener) => listener.blocking_wait_one(),
+        },
+    )
+}
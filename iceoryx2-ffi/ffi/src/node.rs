@@ -0,0 +1,78 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::ffi::c_char;
+use iceoryx2::prelude::*;
+
+use crate::{iox2_error_e, iox2_service_type_e, str_from_c, ServiceTypeUnion};
+
+/// The opaque handle of a [`Node`]. It is created with [`iox2_node_create()`] and must be
+/// released with [`iox2_node_drop()`].
+pub struct iox2_node_t(
+    pub(crate) ServiceTypeUnion<Node<zero_copy::Service>, Node<process_local::Service>>,
+);
+
+/// Creates a node with the provided service type and stores its handle in `node_handle`.
+/// When `node_name` is null the node is created without a name.
+///
+/// # Safety
+///
+///  * `node_name` must be null or a valid null-terminated string
+///  * `node_handle` must be a valid pointer
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_create(
+    service_type: iox2_service_type_e,
+    node_name: *const c_char,
+    node_handle: *mut *mut iox2_node_t,
+) -> i32 {
+    if node_handle.is_null() {
+        return iox2_error_e::IOX2_INVALID_ARGUMENT as i32;
+    }
+
+    let mut builder = NodeBuilder::new();
+    if !node_name.is_null() {
+        match str_from_c(node_name).map(NodeName::new) {
+            Some(Ok(name)) => builder = builder.name(name),
+            _ => return iox2_error_e::IOX2_INVALID_ARGUMENT as i32,
+        }
+    }
+
+    let node = match service_type {
+        iox2_service_type_e::IOX2_SERVICE_TYPE_IPC => builder
+            .create::<zero_copy::Service>()
+            .map(ServiceTypeUnion::Ipc),
+        iox2_service_type_e::IOX2_SERVICE_TYPE_LOCAL => builder
+            .create::<process_local::Service>()
+            .map(ServiceTypeUnion::Local),
+    };
+
+    match node {
+        Ok(node) => {
+            *node_handle = Box::into_raw(Box::new(iox2_node_t(node)));
+            iox2_error_e::IOX2_OK as i32
+        }
+        Err(_) => iox2_error_e::IOX2_NODE_CREATION_FAILED as i32,
+    }
+}
+
+/// Releases a node that was created with [`iox2_node_create()`].
+///
+/// # Safety
+///
+///  * `node_handle` must be null or a handle acquired with [`iox2_node_create()`] that was
+///    not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_drop(node_handle: *mut iox2_node_t) {
+    if !node_handle.is_null() {
+        drop(Box::from_raw(node_handle));
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::port::notifier::Notifier;
+use iceoryx2::prelude::*;
+
+use crate::{iox2_error_e, iox2_event_service_t, ServiceTypeUnion};
+
+/// The opaque handle of a [`Notifier`]. It is created with [`iox2_notifier_create()`] and
+/// must be released with [`iox2_notifier_drop()`].
+pub struct iox2_notifier_t(
+    ServiceTypeUnion<Notifier<zero_copy::Service>, Notifier<process_local::Service>>,
+);
+
+/// Creates a notifier for the provided event service and stores its handle in
+/// `notifier_handle`.
+///
+/// # Safety
+///
+///  * `service_handle` must be a valid handle acquired with
+///    [`iox2_event_service_open_or_create()`](crate::iox2_event_service_open_or_create())
+///  * `notifier_handle` must be a valid pointer
+#[no_mangle]
+pub unsafe extern "C" fn iox2_notifier_create(
+    service_handle: *const iox2_event_service_t,
+    notifier_handle: *mut *mut iox2_notifier_t,
+) -> i32 {
+    if service_handle.is_null() || notifier_handle.is_null() {
+        return iox2_error_e::IOX2_INVALID_ARGUMENT as i32;
+    }
+
+    let notifier = match &(*service_handle).0 {
+        ServiceTypeUnion::Ipc(service) => service
+            .notifier_builder()
+            .create()
+            .map(ServiceTypeUnion::Ipc),
+        ServiceTypeUnion::Local(service) => service
+            .notifier_builder()
+            .create()
+            .map(ServiceTypeUnion::Local),
+    };
+
+    match notifier {
+        Ok(notifier) => {
+            *notifier_handle = Box::into_raw(Box::new(iox2_notifier_t(notifier)));
+            iox2_error_e::IOX2_OK as i32
+        }
+        Err(_) => iox2_error_e::IOX2_PORT_CREATION_FAILED as i32,
+    }
+}
+
+/// Releases a notifier that was created with [`iox2_notifier_create()`].
+///
+/// # Safety
+///
+///  * `notifier_handle` must be null or a handle acquired with [`iox2_notifier_create()`]
+///    that was not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_notifier_drop(notifier_handle: *mut iox2_notifier_t) {
+    if !notifier_handle.is_null() {
+        drop(Box::from_raw(notifier_handle));
+    }
+}
+
+/// Notifies all connected listeners with the provided event id and stores the number of
+/// notified listeners in `number_of_notified_listeners` when it is not null.
+///
+/// # Safety
+///
+///  * `notifier_handle` must be a valid handle acquired with [`iox2_notifier_create()`]
+///  * `number_of_notified_listeners` must be null or a valid pointer
+#[no_mangle]
+pub unsafe extern "C" fn iox2_notifier_notify(
+    notifier_handle: *const iox2_notifier_t,
+    event_id: usize,
+    number_of_notified_listeners: *mut usize,
+) -> i32 {
+    if notifier_handle.is_null() {
+        return iox2_error_e::IOX2_INVALID_ARGUMENT as i32;
+    }
+
+    let result = match &(*notifier_handle).0 {
+        ServiceTypeUnion::Ipc(notifier) => {
+            notifier.notify_with_custom_event_id(EventId::new(event_id))
+        }
+        ServiceTypeUnion::Local(notifier) => {
+            notifier.notify_with_custom_event_id(EventId::new(event_id))
+        }
+    };
+
+    match result {
+        Ok(number_of_listeners) => {
+            if !number_of_notified_listeners.is_null() {
+                *number_of_notified_listeners = number_of_listeners;
+            }
+            iox2_error_e::IOX2_OK as i32
+        }
+        Err(_) => iox2_error_e::IOX2_NOTIFY_FAILED as i32,
+    }
+}
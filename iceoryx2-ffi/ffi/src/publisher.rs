@@ -10,10 +10,18 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::ffi::c_void;
+use core::mem::MaybeUninit;
 use core::time::Duration;
+use iceoryx2::port::publisher::Publisher;
 use iceoryx2::prelude::*;
+use iceoryx2::sample_mut::SampleMut;
 use iceoryx2_bb_log::set_log_level;
 
+use crate::{
+    gid_from_id, iox2_error_e, iox2_publish_subscribe_service_t, ServiceTypeUnion, IOX2_GID_SIZE,
+};
+
 const CYCLE_TIME: Duration = Duration::from_secs(1);
 
 #[no_mangle]
@@ -53,7 +61,7 @@ pub extern "C" fn run_publisher(seconds: u32) -> i32 {
 
     let mut remaining_seconds = seconds;
 
-    while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+    while let NodeEvent::Tick = node.wait(CYCLE_TIME) {
         counter += 1;
         let sample = publisher.loan_uninit();
 
@@ -81,3 +89,217 @@ pub extern "C" fn run_publisher(seconds: u32) -> i32 {
 
     0
 }
+
+/// The opaque handle of a [`Publisher`]. It is created with [`iox2_publisher_create()`] and
+/// must be released with [`iox2_publisher_drop()`].
+pub struct iox2_publisher_t(
+    ServiceTypeUnion<Publisher<zero_copy::Service, [u8]>, Publisher<process_local::Service, [u8]>>,
+);
+
+/// The opaque handle of a loaned [`SampleMut`]. It is created with [`iox2_publisher_loan()`]
+/// and is released with either [`iox2_sample_mut_send()`] or [`iox2_sample_mut_drop()`].
+pub struct iox2_sample_mut_t(
+    ServiceTypeUnion<UninitSampleMut<zero_copy::Service>, UninitSampleMut<process_local::Service>>,
+);
+
+type UninitSampleMut<Service> = SampleMut<[MaybeUninit<u8>], Service>;
+
+/// Creates a publisher for the provided publish-subscribe service and stores its handle in
+/// `publisher_handle`. `max_slice_len` defines the maximum number of elements a loaned sample
+/// can contain, it must be 1 for services with a fixed size payload.
+///
+/// # Safety
+///
+///  * `service_handle` must be a valid handle acquired with
+///    [`iox2_publish_subscribe_service_open_or_create()`](crate::iox2_publish_subscribe_service_open_or_create())
+///  * `publisher_handle` must be a valid pointer
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publisher_create(
+    service_handle: *const iox2_publish_subscribe_service_t,
+    max_slice_len: usize,
+    publisher_handle: *mut *mut iox2_publisher_t,
+) -> i32 {
+    if service_handle.is_null() || publisher_handle.is_null() {
+        return iox2_error_e::IOX2_INVALID_ARGUMENT as i32;
+    }
+
+    let publisher = match &(*service_handle).0 {
+        ServiceTypeUnion::Ipc(service) => service
+            .publisher_builder()
+            .max_slice_len(max_slice_len)
+            .create()
+            .map(ServiceTypeUnion::Ipc),
+        ServiceTypeUnion::Local(service) => service
+            .publisher_builder()
+            .max_slice_len(max_slice_len)
+            .create()
+            .map(ServiceTypeUnion::Local),
+    };
+
+    match publisher {
+        Ok(publisher) => {
+            *publisher_handle = Box::into_raw(Box::new(iox2_publisher_t(publisher)));
+            iox2_error_e::IOX2_OK as i32
+        }
+        Err(_) => iox2_error_e::IOX2_PORT_CREATION_FAILED as i32,
+    }
+}
+
+/// Releases a publisher that was created with [`iox2_publisher_create()`].
+///
+/// # Safety
+///
+///  * `publisher_handle` must be null or a handle acquired with [`iox2_publisher_create()`]
+///    that was not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publisher_drop(publisher_handle: *mut iox2_publisher_t) {
+    if !publisher_handle.is_null() {
+        drop(Box::from_raw(publisher_handle));
+    }
+}
+
+/// Stores the globally unique id of the publisher in `gid`. It matches the publisher gid of
+/// the header of every sample the publisher sends, see
+/// [`iox2_sample_header()`](crate::iox2_sample_header()).
+///
+/// # Safety
+///
+///  * `publisher_handle` must be a valid handle acquired with [`iox2_publisher_create()`]
+///  * `gid` must point to an array of at least [`IOX2_GID_SIZE`] bytes
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publisher_gid(
+    publisher_handle: *const iox2_publisher_t,
+    gid: *mut u8,
+) -> i32 {
+    if publisher_handle.is_null() || gid.is_null() {
+        return iox2_error_e::IOX2_INVALID_ARGUMENT as i32;
+    }
+
+    let id = match &(*publisher_handle).0 {
+        ServiceTypeUnion::Ipc(publisher) => publisher.id(),
+        ServiceTypeUnion::Local(publisher) => publisher.id(),
+    };
+
+    core::ptr::copy_nonoverlapping(gid_from_id(id.value()).as_ptr(), gid, IOX2_GID_SIZE);
+    iox2_error_e::IOX2_OK as i32
+}
+
+/// Loans a sample with `number_of_elements` uninitialized payload elements from the shared
+/// memory and stores its handle in `sample_handle`. For services with a fixed size payload
+/// `number_of_elements` must be 1.
+///
+/// # Safety
+///
+///  * `publisher_handle` must be a valid handle acquired with [`iox2_publisher_create()`]
+///  * `sample_handle` must be a valid pointer
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publisher_loan(
+    publisher_handle: *const iox2_publisher_t,
+    number_of_elements: usize,
+    sample_handle: *mut *mut iox2_sample_mut_t,
+) -> i32 {
+    if publisher_handle.is_null() || sample_handle.is_null() {
+        return iox2_error_e::IOX2_INVALID_ARGUMENT as i32;
+    }
+
+    let sample = match &(*publisher_handle).0 {
+        ServiceTypeUnion::Ipc(publisher) => publisher
+            .loan_slice_uninit(number_of_elements)
+            .map(ServiceTypeUnion::Ipc),
+        ServiceTypeUnion::Local(publisher) => publisher
+            .loan_slice_uninit(number_of_elements)
+            .map(ServiceTypeUnion::Local),
+    };
+
+    match sample {
+        Ok(sample) => {
+            *sample_handle = Box::into_raw(Box::new(iox2_sample_mut_t(sample)));
+            iox2_error_e::IOX2_OK as i32
+        }
+        Err(_) => iox2_error_e::IOX2_LOAN_FAILED as i32,
+    }
+}
+
+/// Returns a pointer to the payload of a loaned sample and stores its size in bytes in
+/// `payload_size` when it is not null. The payload is uninitialized and must be written
+/// before the sample is sent.
+///
+/// # Safety
+///
+///  * `sample_handle` must be a valid handle acquired with [`iox2_publisher_loan()`]
+///  * `payload_size` must be null or a valid pointer
+#[no_mangle]
+pub unsafe extern "C" fn iox2_sample_mut_payload_mut(
+    sample_handle: *mut iox2_sample_mut_t,
+    payload_size: *mut usize,
+) -> *mut c_void {
+    if sample_handle.is_null() {
+        return core::ptr::null_mut();
+    }
+
+    // the payload size is taken from the header since a slice element can be larger than the
+    // u8 the payload is stored as
+    let (payload, size) = match &mut (*sample_handle).0 {
+        ServiceTypeUnion::Ipc(sample) => (
+            sample.payload_mut().as_mut_ptr(),
+            sample.header().payload_type_layout().size(),
+        ),
+        ServiceTypeUnion::Local(sample) => (
+            sample.payload_mut().as_mut_ptr(),
+            sample.header().payload_type_layout().size(),
+        ),
+    };
+
+    if !payload_size.is_null() {
+        *payload_size = size;
+    }
+
+    payload.cast()
+}
+
+/// Sends a loaned sample to all connected subscribers and stores the number of recipients in
+/// `number_of_recipients` when it is not null. The sample handle is released in any case.
+///
+/// # Safety
+///
+///  * `sample_handle` must be a handle acquired with [`iox2_publisher_loan()`] that was not
+///    yet released
+///  * the payload must be initialized
+///  * `number_of_recipients` must be null or a valid pointer
+#[no_mangle]
+pub unsafe extern "C" fn iox2_sample_mut_send(
+    sample_handle: *mut iox2_sample_mut_t,
+    number_of_recipients: *mut usize,
+) -> i32 {
+    if sample_handle.is_null() {
+        return iox2_error_e::IOX2_INVALID_ARGUMENT as i32;
+    }
+
+    let result = match Box::from_raw(sample_handle).0 {
+        ServiceTypeUnion::Ipc(sample) => sample.assume_init().send(),
+        ServiceTypeUnion::Local(sample) => sample.assume_init().send(),
+    };
+
+    match result {
+        Ok(recipients) => {
+            if !number_of_recipients.is_null() {
+                *number_of_recipients = recipients;
+            }
+            iox2_error_e::IOX2_OK as i32
+        }
+        Err(_) => iox2_error_e::IOX2_SEND_FAILED as i32,
+    }
+}
+
+/// Releases a loaned sample without sending it.
+///
+/// # Safety
+///
+///  * `sample_handle` must be null or a handle acquired with [`iox2_publisher_loan()`] that
+///    was not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_sample_mut_drop(sample_handle: *mut iox2_sample_mut_t) {
+    if !sample_handle.is_null() {
+        drop(Box::from_raw(sample_handle));
+    }
+}
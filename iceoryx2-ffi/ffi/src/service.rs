@@ -0,0 +1,294 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::ffi::{c_char, c_void};
+use iceoryx2::prelude::*;
+use iceoryx2::service::messaging_pattern::MessagingPattern;
+use iceoryx2::service::port_factory::{event, publish_subscribe};
+use iceoryx2::service::static_config::type_details::TypeVariant;
+use std::ffi::CString;
+
+use crate::{iox2_error_e, iox2_node_t, str_from_c, ServiceTypeUnion};
+
+/// Defines if the payload of a publish-subscribe service is a single value of a fixed size or
+/// a slice of values whose length is defined when the sample is loaned.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum iox2_type_variant_e {
+    IOX2_TYPE_VARIANT_FIXED_SIZE,
+    IOX2_TYPE_VARIANT_DYNAMIC,
+}
+
+/// The opaque handle of a publish-subscribe service. It is created with
+/// [`iox2_publish_subscribe_service_open_or_create()`] and must be released with
+/// [`iox2_publish_subscribe_service_drop()`].
+pub struct iox2_publish_subscribe_service_t(
+    pub(crate)  ServiceTypeUnion<
+        publish_subscribe::PortFactory<zero_copy::Service, [u8]>,
+        publish_subscribe::PortFactory<process_local::Service, [u8]>,
+    >,
+);
+
+/// The opaque handle of an event service. It is created with
+/// [`iox2_event_service_open_or_create()`] and must be released with
+/// [`iox2_event_service_drop()`].
+pub struct iox2_event_service_t(
+    pub(crate)  ServiceTypeUnion<
+        event::PortFactory<zero_copy::Service>,
+        event::PortFactory<process_local::Service>,
+    >,
+);
+
+/// Opens the publish-subscribe service with the provided name or creates it when it does not
+/// exist and stores its handle in `service_handle`. The payload is described by its type name,
+/// size and alignment. To communicate with a Rust process they must match the Rust payload
+/// type, e.g. `"u64"` with a size and alignment of 8 for `u64`. For
+/// [`iox2_type_variant_e::IOX2_TYPE_VARIANT_DYNAMIC`] the size and alignment describe a single
+/// slice element.
+///
+/// # Safety
+///
+///  * `node_handle` must be a valid handle acquired with [`iox2_node_create()`](crate::iox2_node_create())
+///  * `service_name` and `payload_type_name` must be valid null-terminated strings
+///  * `service_handle` must be a valid pointer
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publish_subscribe_service_open_or_create(
+    node_handle: *const iox2_node_t,
+    service_name: *const c_char,
+    payload_type_name: *const c_char,
+    payload_type_variant: iox2_type_variant_e,
+    payload_size: usize,
+    payload_alignment: usize,
+    service_handle: *mut *mut iox2_publish_subscribe_service_t,
+) -> i32 {
+    if node_handle.is_null()
+        || service_handle.is_null()
+        || payload_size == 0
+        || !payload_alignment.is_power_of_two()
+    {
+        return iox2_error_e::IOX2_INVALID_ARGUMENT as i32;
+    }
+
+    let service_name = match str_from_c(service_name).map(ServiceName::new) {
+        Some(Ok(name)) => name,
+        _ => return iox2_error_e::IOX2_INVALID_ARGUMENT as i32,
+    };
+
+    let payload_type_name = match str_from_c(payload_type_name) {
+        Some(name) => name,
+        None => return iox2_error_e::IOX2_INVALID_ARGUMENT as i32,
+    };
+
+    let variant = match payload_type_variant {
+        iox2_type_variant_e::IOX2_TYPE_VARIANT_FIXED_SIZE => TypeVariant::FixedSize,
+        iox2_type_variant_e::IOX2_TYPE_VARIANT_DYNAMIC => TypeVariant::Dynamic,
+    };
+
+    let service = match &(*node_handle).0 {
+        ServiceTypeUnion::Ipc(node) => node
+            .service_builder(service_name)
+            .publish_subscribe::<[u8]>()
+            .payload_type_name(payload_type_name)
+            .__internal_set_payload_layout(variant, payload_size, payload_alignment)
+            .open_or_create()
+            .map(ServiceTypeUnion::Ipc),
+        ServiceTypeUnion::Local(node) => node
+            .service_builder(service_name)
+            .publish_subscribe::<[u8]>()
+            .payload_type_name(payload_type_name)
+            .__internal_set_payload_layout(variant, payload_size, payload_alignment)
+            .open_or_create()
+            .map(ServiceTypeUnion::Local),
+    };
+
+    match service {
+        Ok(service) => {
+            *service_handle = Box::into_raw(Box::new(iox2_publish_subscribe_service_t(service)));
+            iox2_error_e::IOX2_OK as i32
+        }
+        Err(_) => iox2_error_e::IOX2_SERVICE_OPEN_OR_CREATE_FAILED as i32,
+    }
+}
+
+/// Releases a publish-subscribe service that was created with
+/// [`iox2_publish_subscribe_service_open_or_create()`].
+///
+/// # Safety
+///
+///  * `service_handle` must be null or a handle acquired with
+///    [`iox2_publish_subscribe_service_open_or_create()`] that was not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publish_subscribe_service_drop(
+    service_handle: *mut iox2_publish_subscribe_service_t,
+) {
+    if !service_handle.is_null() {
+        drop(Box::from_raw(service_handle));
+    }
+}
+
+/// Returns the number of publishers that are currently connected to the publish-subscribe
+/// service. Returns 0 when `service_handle` is null.
+///
+/// # Safety
+///
+///  * `service_handle` must be null or a valid handle acquired with
+///    [`iox2_publish_subscribe_service_open_or_create()`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publish_subscribe_service_number_of_publishers(
+    service_handle: *const iox2_publish_subscribe_service_t,
+) -> usize {
+    if service_handle.is_null() {
+        return 0;
+    }
+
+    match &(*service_handle).0 {
+        ServiceTypeUnion::Ipc(service) => service.dynamic_config().number_of_publishers(),
+        ServiceTypeUnion::Local(service) => service.dynamic_config().number_of_publishers(),
+    }
+}
+
+/// Returns the number of subscribers that are currently connected to the publish-subscribe
+/// service. Returns 0 when `service_handle` is null.
+///
+/// # Safety
+///
+///  * `service_handle` must be null or a valid handle acquired with
+///    [`iox2_publish_subscribe_service_open_or_create()`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publish_subscribe_service_number_of_subscribers(
+    service_handle: *const iox2_publish_subscribe_service_t,
+) -> usize {
+    if service_handle.is_null() {
+        return 0;
+    }
+
+    match &(*service_handle).0 {
+        ServiceTypeUnion::Ipc(service) => service.dynamic_config().number_of_subscribers(),
+        ServiceTypeUnion::Local(service) => service.dynamic_config().number_of_subscribers(),
+    }
+}
+
+/// The callback of [`iox2_publish_subscribe_service_list()`]. It is called with the name and
+/// the payload type name of a service and the user provided context. The strings are only
+/// valid during the call.
+pub type iox2_service_list_callback = unsafe extern "C" fn(
+    service_name: *const c_char,
+    payload_type_name: *const c_char,
+    context: *mut c_void,
+);
+
+/// Calls `callback` for every publish-subscribe service that exists in the scope of the
+/// config of the node. It can be used to discover the topics of the system, e.g. for the
+/// graph introspection of a middleware layer like the ROS 2 rmw.
+///
+/// # Safety
+///
+///  * `node_handle` must be a valid handle acquired with [`iox2_node_create()`](crate::iox2_node_create())
+///  * `context` is passed unmodified to `callback` and can be null
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publish_subscribe_service_list(
+    node_handle: *const iox2_node_t,
+    callback: Option<iox2_service_list_callback>,
+    context: *mut c_void,
+) -> i32 {
+    let callback = match callback {
+        Some(callback) if !node_handle.is_null() => callback,
+        _ => return iox2_error_e::IOX2_INVALID_ARGUMENT as i32,
+    };
+
+    let services = match &(*node_handle).0 {
+        ServiceTypeUnion::Ipc(node) => zero_copy::Service::list(node.config()),
+        ServiceTypeUnion::Local(node) => process_local::Service::list(node.config()),
+    };
+
+    let services = match services {
+        Ok(services) => services,
+        Err(_) => return iox2_error_e::IOX2_SERVICE_LIST_FAILED as i32,
+    };
+
+    for service in services {
+        let payload_type_name = match service.messaging_pattern() {
+            MessagingPattern::PublishSubscribe(details) => {
+                details.type_details().payload_type_name.as_str()
+            }
+            _ => continue,
+        };
+
+        // names containing a null byte cannot be represented as C string
+        if let (Ok(service_name), Ok(payload_type_name)) = (
+            CString::new(service.name().as_str()),
+            CString::new(payload_type_name),
+        ) {
+            callback(service_name.as_ptr(), payload_type_name.as_ptr(), context);
+        }
+    }
+
+    iox2_error_e::IOX2_OK as i32
+}
+
+/// Opens the event service with the provided name or creates it when it does not exist and
+/// stores its handle in `service_handle`.
+///
+/// # Safety
+///
+///  * `node_handle` must be a valid handle acquired with [`iox2_node_create()`](crate::iox2_node_create())
+///  * `service_name` must be a valid null-terminated string
+///  * `service_handle` must be a valid pointer
+#[no_mangle]
+pub unsafe extern "C" fn iox2_event_service_open_or_create(
+    node_handle: *const iox2_node_t,
+    service_name: *const c_char,
+    service_handle: *mut *mut iox2_event_service_t,
+) -> i32 {
+    if node_handle.is_null() || service_handle.is_null() {
+        return iox2_error_e::IOX2_INVALID_ARGUMENT as i32;
+    }
+
+    let service_name = match str_from_c(service_name).map(ServiceName::new) {
+        Some(Ok(name)) => name,
+        _ => return iox2_error_e::IOX2_INVALID_ARGUMENT as i32,
+    };
+
+    let service = match &(*node_handle).0 {
+        ServiceTypeUnion::Ipc(node) => node
+            .service_builder(service_name)
+            .event()
+            .open_or_create()
+            .map(ServiceTypeUnion::Ipc),
+        ServiceTypeUnion::Local(node) => node
+            .service_builder(service_name)
+            .event()
+            .open_or_create()
+            .map(ServiceTypeUnion::Local),
+    };
+
+    match service {
+        Ok(service) => {
+            *service_handle = Box::into_raw(Box::new(iox2_event_service_t(service)));
+            iox2_error_e::IOX2_OK as i32
+        }
+        Err(_) => iox2_error_e::IOX2_SERVICE_OPEN_OR_CREATE_FAILED as i32,
+    }
+}
+
+/// Releases an event service that was created with [`iox2_event_service_open_or_create()`].
+///
+/// # Safety
+///
+///  * `service_handle` must be null or a handle acquired with
+///    [`iox2_event_service_open_or_create()`] that was not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_event_service_drop(service_handle: *mut iox2_event_service_t) {
+    if !service_handle.is_null() {
+        drop(Box::from_raw(service_handle));
+    }
+}
@@ -10,10 +10,17 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::ffi::c_void;
 use core::time::Duration;
+use iceoryx2::port::subscriber::Subscriber;
 use iceoryx2::prelude::*;
+use iceoryx2::sample::Sample;
 use iceoryx2_bb_log::set_log_level;
 
+use crate::{
+    gid_from_id, iox2_error_e, iox2_publish_subscribe_service_t, ServiceTypeUnion, IOX2_GID_SIZE,
+};
+
 const CYCLE_TIME: Duration = Duration::from_secs(1);
 
 #[no_mangle]
@@ -51,7 +58,7 @@ pub extern "C" fn run_subscriber(seconds: u32) -> i32 {
 
     let mut remaining_seconds = seconds;
 
-    while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+    while let NodeEvent::Tick = node.wait(CYCLE_TIME) {
         loop {
             match subscriber.receive() {
                 Ok(Some(sample)) => println!("received: {:?}", *sample),
@@ -70,3 +77,193 @@ pub extern "C" fn run_subscriber(seconds: u32) -> i32 {
 
     0
 }
+
+/// The opaque handle of a [`Subscriber`]. It is created with [`iox2_subscriber_create()`] and
+/// must be released with [`iox2_subscriber_drop()`].
+pub struct iox2_subscriber_t(
+    ServiceTypeUnion<
+        Subscriber<zero_copy::Service, [u8]>,
+        Subscriber<process_local::Service, [u8]>,
+    >,
+);
+
+/// The opaque handle of a received [`Sample`]. It is created with [`iox2_subscriber_receive()`]
+/// and must be released with [`iox2_sample_drop()`].
+pub struct iox2_sample_t(
+    ServiceTypeUnion<Sample<[u8], zero_copy::Service>, Sample<[u8], process_local::Service>>,
+);
+
+/// The header of a received sample. It contains everything that is required to fill the
+/// message info of a middleware layer like the ROS 2 rmw.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct iox2_sample_header_t {
+    /// The globally unique id of the publisher that sent the sample, see
+    /// [`iox2_publisher_gid()`](crate::iox2_publisher_gid()).
+    pub publisher_gid: [u8; IOX2_GID_SIZE],
+    /// The sequence number of the sample that is shared by all publishers of the service.
+    pub sequence_number: u64,
+    /// The sequence number of the sample that is counted per publisher.
+    pub publisher_sequence_number: u64,
+    /// The time in nanoseconds at which the sample was published, acquired from the steady
+    /// system clock.
+    pub publish_timestamp_ns: u64,
+}
+
+/// Creates a subscriber for the provided publish-subscribe service and stores its handle in
+/// `subscriber_handle`.
+///
+/// # Safety
+///
+///  * `service_handle` must be a valid handle acquired with
+///    [`iox2_publish_subscribe_service_open_or_create()`](crate::iox2_publish_subscribe_service_open_or_create())
+///  * `subscriber_handle` must be a valid pointer
+#[no_mangle]
+pub unsafe extern "C" fn iox2_subscriber_create(
+    service_handle: *const iox2_publish_subscribe_service_t,
+    subscriber_handle: *mut *mut iox2_subscriber_t,
+) -> i32 {
+    if service_handle.is_null() || subscriber_handle.is_null() {
+        return iox2_error_e::IOX2_INVALID_ARGUMENT as i32;
+    }
+
+    let subscriber = match &(*service_handle).0 {
+        ServiceTypeUnion::Ipc(service) => service
+            .subscriber_builder()
+            .create()
+            .map(ServiceTypeUnion::Ipc),
+        ServiceTypeUnion::Local(service) => service
+            .subscriber_builder()
+            .create()
+            .map(ServiceTypeUnion::Local),
+    };
+
+    match subscriber {
+        Ok(subscriber) => {
+            *subscriber_handle = Box::into_raw(Box::new(iox2_subscriber_t(subscriber)));
+            iox2_error_e::IOX2_OK as i32
+        }
+        Err(_) => iox2_error_e::IOX2_PORT_CREATION_FAILED as i32,
+    }
+}
+
+/// Releases a subscriber that was created with [`iox2_subscriber_create()`].
+///
+/// # Safety
+///
+///  * `subscriber_handle` must be null or a handle acquired with [`iox2_subscriber_create()`]
+///    that was not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_subscriber_drop(subscriber_handle: *mut iox2_subscriber_t) {
+    if !subscriber_handle.is_null() {
+        drop(Box::from_raw(subscriber_handle));
+    }
+}
+
+/// Receives the next sample and stores its handle in `sample_handle`. When no sample is
+/// available, `sample_handle` is set to null.
+///
+/// # Safety
+///
+///  * `subscriber_handle` must be a valid handle acquired with [`iox2_subscriber_create()`]
+///  * `sample_handle` must be a valid pointer
+#[no_mangle]
+pub unsafe extern "C" fn iox2_subscriber_receive(
+    subscriber_handle: *const iox2_subscriber_t,
+    sample_handle: *mut *mut iox2_sample_t,
+) -> i32 {
+    if subscriber_handle.is_null() || sample_handle.is_null() {
+        return iox2_error_e::IOX2_INVALID_ARGUMENT as i32;
+    }
+
+    let sample = match &(*subscriber_handle).0 {
+        ServiceTypeUnion::Ipc(subscriber) => {
+            subscriber.receive().map(|s| s.map(ServiceTypeUnion::Ipc))
+        }
+        ServiceTypeUnion::Local(subscriber) => {
+            subscriber.receive().map(|s| s.map(ServiceTypeUnion::Local))
+        }
+    };
+
+    match sample {
+        Ok(sample) => {
+            *sample_handle = match sample {
+                Some(sample) => Box::into_raw(Box::new(iox2_sample_t(sample))),
+                None => core::ptr::null_mut(),
+            };
+            iox2_error_e::IOX2_OK as i32
+        }
+        Err(_) => iox2_error_e::IOX2_RECEIVE_FAILED as i32,
+    }
+}
+
+/// Returns a pointer to the payload of a received sample and stores its size in bytes in
+/// `payload_size` when it is not null.
+///
+/// # Safety
+///
+///  * `sample_handle` must be a valid handle acquired with [`iox2_subscriber_receive()`]
+///  * `payload_size` must be null or a valid pointer
+#[no_mangle]
+pub unsafe extern "C" fn iox2_sample_payload(
+    sample_handle: *const iox2_sample_t,
+    payload_size: *mut usize,
+) -> *const c_void {
+    if sample_handle.is_null() {
+        return core::ptr::null();
+    }
+
+    let payload = match &(*sample_handle).0 {
+        ServiceTypeUnion::Ipc(sample) => sample.payload(),
+        ServiceTypeUnion::Local(sample) => sample.payload(),
+    };
+
+    if !payload_size.is_null() {
+        *payload_size = payload.len();
+    }
+
+    payload.as_ptr().cast()
+}
+
+/// Stores the header of a received sample in `header`.
+///
+/// # Safety
+///
+///  * `sample_handle` must be a valid handle acquired with [`iox2_subscriber_receive()`]
+///  * `header` must be a valid pointer
+#[no_mangle]
+pub unsafe extern "C" fn iox2_sample_header(
+    sample_handle: *const iox2_sample_t,
+    header: *mut iox2_sample_header_t,
+) -> i32 {
+    if sample_handle.is_null() || header.is_null() {
+        return iox2_error_e::IOX2_INVALID_ARGUMENT as i32;
+    }
+
+    let sample_header = match &(*sample_handle).0 {
+        ServiceTypeUnion::Ipc(sample) => sample.header(),
+        ServiceTypeUnion::Local(sample) => sample.header(),
+    };
+
+    *header = iox2_sample_header_t {
+        publisher_gid: gid_from_id(sample_header.publisher_id().value()),
+        sequence_number: sample_header.sequence_number(),
+        publisher_sequence_number: sample_header.publisher_sequence_number(),
+        publish_timestamp_ns: sample_header.publish_timestamp().as_nanos() as u64,
+    };
+
+    iox2_error_e::IOX2_OK as i32
+}
+
+/// Releases a sample that was received with [`iox2_subscriber_receive()`].
+///
+/// # Safety
+///
+///  * `sample_handle` must be null or a handle acquired with [`iox2_subscriber_receive()`]
+///    that was not yet released
+#[no_mangle]
+pub unsafe extern "C" fn iox2_sample_drop(sample_handle: *mut iox2_sample_t) {
+    if !sample_handle.is_null() {
+        drop(Box::from_raw(sample_handle));
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::ffi::{c_char, CStr};
+use iceoryx2::prelude::*;
+use iceoryx2::service::messaging_pattern::MessagingPattern;
+use iceoryx2::service::static_config::type_details;
+
+/// The memory layout of a single field of a C/C++ payload struct. It is usually acquired with
+/// `offsetof`, `sizeof` and `alignof` and converted into a [`type_details::FieldLayout`]
+/// when it enters the Rust side.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct iox2_field_layout_t {
+    pub offset: usize,
+    pub size: usize,
+    pub alignment: usize,
+}
+
+impl From<&iox2_field_layout_t> for type_details::FieldLayout {
+    fn from(value: &iox2_field_layout_t) -> Self {
+        Self {
+            offset: value.offset,
+            size: value.size,
+            alignment: value.alignment,
+        }
+    }
+}
+
+/// Verifies that the provided field layouts of a C/C++ payload struct match the field layouts
+/// the publish-subscribe service with the given name has stored. Returns 0 when they match,
+/// 1 when they do not match, 2 when the service did not store any layout and therefore nothing
+/// could be verified and -1 when the service does not exist or could not be read.
+///
+/// # Safety
+///
+///  * `service_name` must be a valid null-terminated string
+///  * `field_layouts` must point to an array with at least `number_of_fields` elements
+#[no_mangle]
+pub unsafe extern "C" fn zero_copy_service_verify_payload_layout(
+    service_name: *const c_char,
+    field_layouts: *const iox2_field_layout_t,
+    number_of_fields: usize,
+) -> i32 {
+    if service_name.is_null() || (field_layouts.is_null() && number_of_fields != 0) {
+        return -1;
+    }
+
+    let service_name = match CStr::from_ptr(service_name).to_str() {
+        Ok(name) => name,
+        Err(_) => return -1,
+    };
+
+    let field_layouts: Vec<type_details::FieldLayout> = if number_of_fields == 0 {
+        vec![]
+    } else {
+        core::slice::from_raw_parts(field_layouts, number_of_fields)
+            .iter()
+            .map(type_details::FieldLayout::from)
+            .collect()
+    };
+
+    let services = match zero_copy::Service::list(Config::get_global_config()) {
+        Ok(services) => services,
+        Err(_) => return -1,
+    };
+
+    for service in services {
+        if service.name().as_str() != service_name {
+            continue;
+        }
+
+        if let MessagingPattern::PublishSubscribe(config) = service.messaging_pattern() {
+            let stored_field_layouts = &config.type_details().payload_fields;
+            if stored_field_layouts.is_empty() {
+                return 2;
+            } else if *stored_field_layouts == field_layouts {
+                return 0;
+            } else {
+                return 1;
+            }
+        }
+    }
+
+    -1
+}
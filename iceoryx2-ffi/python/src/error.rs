@@ -0,0 +1,101 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::fmt::Debug;
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use pyo3::PyTypeInfo;
+
+create_exception!(
+    iceoryx2,
+    Iceoryx2Error,
+    PyException,
+    "The base class of all exceptions that are raised by iceoryx2."
+);
+create_exception!(
+    iceoryx2,
+    NodeCreationError,
+    Iceoryx2Error,
+    "Raised when a node could not be created."
+);
+create_exception!(
+    iceoryx2,
+    ServiceOpenOrCreateError,
+    Iceoryx2Error,
+    "Raised when a service could neither be opened nor created."
+);
+create_exception!(
+    iceoryx2,
+    PortCreationError,
+    Iceoryx2Error,
+    "Raised when a publisher, subscriber, notifier or listener could not be created."
+);
+create_exception!(
+    iceoryx2,
+    LoanError,
+    Iceoryx2Error,
+    "Raised when a sample could not be loaned from the shared memory."
+);
+create_exception!(
+    iceoryx2,
+    SendError,
+    Iceoryx2Error,
+    "Raised when a sample could not be sent."
+);
+create_exception!(
+    iceoryx2,
+    ReceiveError,
+    Iceoryx2Error,
+    "Raised when a sample could not be received."
+);
+create_exception!(
+    iceoryx2,
+    NotifyError,
+    Iceoryx2Error,
+    "Raised when the listeners could not be notified."
+);
+create_exception!(
+    iceoryx2,
+    WaitError,
+    Iceoryx2Error,
+    "Raised when waiting for an event failed."
+);
+
+// Converts an iceoryx2 error into the Python exception `T`, the message is the error variant.
+pub(crate) fn failure<T: PyTypeInfo, E: Debug>(error: E) -> PyErr {
+    PyErr::new::<T, _>(format!("{:?}", error))
+}
+
+pub(crate) fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    m.add("Iceoryx2Error", py.get_type_bound::<Iceoryx2Error>())?;
+    m.add(
+        "NodeCreationError",
+        py.get_type_bound::<NodeCreationError>(),
+    )?;
+    m.add(
+        "ServiceOpenOrCreateError",
+        py.get_type_bound::<ServiceOpenOrCreateError>(),
+    )?;
+    m.add(
+        "PortCreationError",
+        py.get_type_bound::<PortCreationError>(),
+    )?;
+    m.add("LoanError", py.get_type_bound::<LoanError>())?;
+    m.add("SendError", py.get_type_bound::<SendError>())?;
+    m.add("ReceiveError", py.get_type_bound::<ReceiveError>())?;
+    m.add("NotifyError", py.get_type_bound::<NotifyError>())?;
+    m.add("WaitError", py.get_type_bound::<WaitError>())?;
+    Ok(())
+}
@@ -0,0 +1,71 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Python bindings of iceoryx2. They expose nodes, publish-subscribe services whose payload is
+//! untyped and exchanged as `bytes` or numpy arrays, and event services.
+//!
+//! ```python
+//! import iceoryx2
+//! import numpy as np
+//!
+//! node = iceoryx2.Node()
+//! service = node.publish_subscribe("My/Funk/ServiceName", "f32", 4, 4)
+//!
+//! publisher = service.publisher(max_slice_len=16)
+//! subscriber = service.subscriber()
+//!
+//! publisher.send_array(np.arange(16, dtype=np.float32))
+//! payload = subscriber.receive_array().view(np.float32)
+//! ```
+//!
+//! All objects are bound to the thread that created them, like the underlying ports.
+
+mod error;
+mod listener;
+mod node;
+mod notifier;
+mod publisher;
+mod service;
+mod subscriber;
+
+use pyo3::prelude::*;
+
+/// Selects the service type of a node. All services and ports that are created from the node
+/// share its service type.
+#[pyclass(module = "iceoryx2")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceType {
+    /// Inter-process communication via shared memory, see `zero_copy::Service`.
+    Ipc,
+    /// Communication between threads of the same process, see `process_local::Service`.
+    Local,
+}
+
+// Stores the object behind a Python object for both service types.
+pub(crate) enum ServiceTypeUnion<Ipc, Local> {
+    Ipc(Ipc),
+    Local(Local),
+}
+
+#[pymodule]
+#[pyo3(name = "iceoryx2")]
+fn iceoryx2_python(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<ServiceType>()?;
+    m.add_class::<node::Node>()?;
+    m.add_class::<service::PublishSubscribeService>()?;
+    m.add_class::<service::EventService>()?;
+    m.add_class::<publisher::Publisher>()?;
+    m.add_class::<subscriber::Subscriber>()?;
+    m.add_class::<notifier::Notifier>()?;
+    m.add_class::<listener::Listener>()?;
+    error::register(m)
+}
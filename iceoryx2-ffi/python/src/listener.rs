@@ -0,0 +1,80 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2::prelude::{process_local, zero_copy};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::error::{failure, WaitError};
+use crate::ServiceTypeUnion;
+
+type ListenerUnion = ServiceTypeUnion<
+    iceoryx2::port::listener::Listener<zero_copy::Service>,
+    iceoryx2::port::listener::Listener<process_local::Service>,
+>;
+
+/// Waits for the notifications of an event service. It is created with
+/// `EventService.listener()`.
+///
+/// The blocking calls hold the global interpreter lock since the listener is bound to its
+/// thread, other Python threads are blocked while waiting.
+#[pyclass(unsendable, module = "iceoryx2")]
+pub struct Listener(ListenerUnion);
+
+impl Listener {
+    pub(crate) fn new(listener: ListenerUnion) -> Self {
+        Self(listener)
+    }
+}
+
+#[pymethods]
+impl Listener {
+    /// Non-blocking wait for a new event. Returns its id or `None` when no event was received.
+    fn try_wait_one(&self) -> PyResult<Option<usize>> {
+        let result = match &self.0 {
+            ServiceTypeUnion::Ipc(listener) => listener.try_wait_one(),
+            ServiceTypeUnion::Local(listener) => listener.try_wait_one(),
+        };
+
+        Ok(result
+            .map_err(failure::<WaitError, _>)?
+            .map(|id| id.as_value()))
+    }
+
+    /// Blocking wait for a new event until the timeout in seconds has passed. Returns its id
+    /// or `None` when no event was received.
+    fn timed_wait_one(&self, timeout: f64) -> PyResult<Option<usize>> {
+        let timeout = Duration::try_from_secs_f64(timeout).map_err(failure::<PyValueError, _>)?;
+        let result = match &self.0 {
+            ServiceTypeUnion::Ipc(listener) => listener.timed_wait_one(timeout),
+            ServiceTypeUnion::Local(listener) => listener.timed_wait_one(timeout),
+        };
+
+        Ok(result
+            .map_err(failure::<WaitError, _>)?
+            .map(|id| id.as_value()))
+    }
+
+    /// Blocks until a new event was received and returns its id. Returns `None` when the wait
+    /// was interrupted, e.g. by a signal.
+    fn blocking_wait_one(&self) -> PyResult<Option<usize>> {
+        let result = match &self.0 {
+            ServiceTypeUnion::Ipc(listener) => listener.blocking_wait_one(),
+            ServiceTypeUnion::Local(listener) => listener.blocking_wait_one(),
+        };
+
+        Ok(result
+            .map_err(failure::<WaitError, _>)?
+            .map(|id| id.as_value()))
+    }
+}
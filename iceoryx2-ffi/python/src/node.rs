@@ -0,0 +1,135 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::{process_local, zero_copy, NodeBuilder, NodeName, ServiceName};
+use iceoryx2::service::static_config::type_details::TypeVariant;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::error::{failure, NodeCreationError, ServiceOpenOrCreateError};
+use crate::service::{EventService, PublishSubscribeService};
+use crate::{ServiceType, ServiceTypeUnion};
+
+fn to_service_name(value: &str) -> PyResult<ServiceName> {
+    ServiceName::new(value).map_err(failure::<PyValueError, _>)
+}
+
+/// The entry point of iceoryx2. All services are opened or created from a node.
+#[pyclass(unsendable, module = "iceoryx2")]
+pub struct Node(
+    ServiceTypeUnion<
+        iceoryx2::node::Node<zero_copy::Service>,
+        iceoryx2::node::Node<process_local::Service>,
+    >,
+);
+
+#[pymethods]
+impl Node {
+    /// Creates a node with the provided service type. When `name` is `None` the node is created
+    /// without a name.
+    #[new]
+    #[pyo3(signature = (service_type = ServiceType::Ipc, name = None))]
+    fn new(service_type: ServiceType, name: Option<&str>) -> PyResult<Self> {
+        let mut builder = NodeBuilder::new();
+        if let Some(name) = name {
+            builder = builder.name(NodeName::new(name).map_err(failure::<PyValueError, _>)?);
+        }
+
+        let node = match service_type {
+            ServiceType::Ipc => builder
+                .create::<zero_copy::Service>()
+                .map(ServiceTypeUnion::Ipc),
+            ServiceType::Local => builder
+                .create::<process_local::Service>()
+                .map(ServiceTypeUnion::Local),
+        };
+
+        Ok(Self(node.map_err(failure::<NodeCreationError, _>)?))
+    }
+
+    /// Opens the publish-subscribe service with the provided name or creates it when it does
+    /// not exist. The payload is described by its type name, size and alignment. To
+    /// communicate with a Rust process they must match the Rust payload type, e.g. `"u64"` with
+    /// a size and alignment of 8 for `u64`. When `dynamic` is true the payload is a slice and
+    /// the size and alignment describe a single slice element.
+    #[pyo3(signature = (service_name, payload_type_name = "u8", payload_size = 1, payload_alignment = 1, dynamic = true))]
+    fn publish_subscribe(
+        &self,
+        service_name: &str,
+        payload_type_name: &str,
+        payload_size: usize,
+        payload_alignment: usize,
+        dynamic: bool,
+    ) -> PyResult<PublishSubscribeService> {
+        if payload_size == 0 || !payload_alignment.is_power_of_two() {
+            return Err(PyValueError::new_err(format!(
+                "The payload size must not be zero and the alignment must be a power of two but a size of {} and an alignment of {} were provided.",
+                payload_size, payload_alignment
+            )));
+        }
+
+        let name = to_service_name(service_name)?;
+        let variant = match dynamic {
+            true => TypeVariant::Dynamic,
+            false => TypeVariant::FixedSize,
+        };
+
+        // the payload is stored as u8 slice, the layout of the payload type is provided
+        // explicitly so that the service is compatible with typed ports of other languages
+        let service = match &self.0 {
+            ServiceTypeUnion::Ipc(node) => unsafe {
+                node.service_builder(name)
+                    .publish_subscribe::<[u8]>()
+                    .payload_type_name(payload_type_name)
+                    .__internal_set_payload_layout(variant, payload_size, payload_alignment)
+                    .open_or_create()
+                    .map(ServiceTypeUnion::Ipc)
+            },
+            ServiceTypeUnion::Local(node) => unsafe {
+                node.service_builder(name)
+                    .publish_subscribe::<[u8]>()
+                    .payload_type_name(payload_type_name)
+                    .__internal_set_payload_layout(variant, payload_size, payload_alignment)
+                    .open_or_create()
+                    .map(ServiceTypeUnion::Local)
+            },
+        };
+
+        Ok(PublishSubscribeService::new(
+            service.map_err(failure::<ServiceOpenOrCreateError, _>)?,
+            payload_size,
+            dynamic,
+        ))
+    }
+
+    /// Opens the event service with the provided name or creates it when it does not exist.
+    fn event(&self, service_name: &str) -> PyResult<EventService> {
+        let name = to_service_name(service_name)?;
+
+        let service = match &self.0 {
+            ServiceTypeUnion::Ipc(node) => node
+                .service_builder(name)
+                .event()
+                .open_or_create()
+                .map(ServiceTypeUnion::Ipc),
+            ServiceTypeUnion::Local(node) => node
+                .service_builder(name)
+                .event()
+                .open_or_create()
+                .map(ServiceTypeUnion::Local),
+        };
+
+        Ok(EventService::new(
+            service.map_err(failure::<ServiceOpenOrCreateError, _>)?,
+        ))
+    }
+}
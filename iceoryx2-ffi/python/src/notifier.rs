@@ -0,0 +1,53 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::{process_local, zero_copy, EventId};
+use pyo3::prelude::*;
+
+use crate::error::{failure, NotifyError};
+use crate::ServiceTypeUnion;
+
+type NotifierUnion = ServiceTypeUnion<
+    iceoryx2::port::notifier::Notifier<zero_copy::Service>,
+    iceoryx2::port::notifier::Notifier<process_local::Service>,
+>;
+
+/// Notifies all listeners of an event service. It is created with `EventService.notifier()`.
+#[pyclass(unsendable, module = "iceoryx2")]
+pub struct Notifier(NotifierUnion);
+
+impl Notifier {
+    pub(crate) fn new(notifier: NotifierUnion) -> Self {
+        Self(notifier)
+    }
+}
+
+#[pymethods]
+impl Notifier {
+    /// Notifies all connected listeners with the provided event id or the default event id
+    /// when it is `None`. Returns the number of notified listeners.
+    #[pyo3(signature = (event_id = None))]
+    fn notify(&self, event_id: Option<usize>) -> PyResult<usize> {
+        let result = match (&self.0, event_id) {
+            (ServiceTypeUnion::Ipc(notifier), None) => notifier.notify(),
+            (ServiceTypeUnion::Ipc(notifier), Some(id)) => {
+                notifier.notify_with_custom_event_id(EventId::new(id))
+            }
+            (ServiceTypeUnion::Local(notifier), None) => notifier.notify(),
+            (ServiceTypeUnion::Local(notifier), Some(id)) => {
+                notifier.notify_with_custom_event_id(EventId::new(id))
+            }
+        };
+
+        result.map_err(failure::<NotifyError, _>)
+    }
+}
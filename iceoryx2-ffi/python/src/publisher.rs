@@ -0,0 +1,122 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::{process_local, zero_copy};
+use numpy::{PyArrayDescrMethods, PyUntypedArray, PyUntypedArrayMethods};
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::error::{failure, LoanError, SendError};
+use crate::ServiceTypeUnion;
+
+type PublisherUnion = ServiceTypeUnion<
+    iceoryx2::port::publisher::Publisher<zero_copy::Service, [u8]>,
+    iceoryx2::port::publisher::Publisher<process_local::Service, [u8]>,
+>;
+
+fn send<Service: iceoryx2::service::Service>(
+    publisher: &iceoryx2::port::publisher::Publisher<Service, [u8]>,
+    data: &[u8],
+    number_of_elements: usize,
+) -> PyResult<usize> {
+    let mut sample = publisher
+        .loan_slice_uninit(number_of_elements)
+        .map_err(failure::<LoanError, _>)?;
+
+    // the payload size is taken from the header since a slice element can be larger than the
+    // u8 the payload is stored as
+    let payload_size = sample.header().payload_type_layout().size();
+    unsafe {
+        core::ptr::copy_nonoverlapping(
+            data.as_ptr(),
+            sample.payload_mut().as_mut_ptr().cast::<u8>(),
+            data.len().min(payload_size),
+        );
+        sample.assume_init()
+    }
+    .send()
+    .map_err(failure::<SendError, _>)
+}
+
+/// Sends copies of `bytes` or numpy arrays to all subscribers of a publish-subscribe
+/// service. It is created with `PublishSubscribeService.publisher()`.
+#[pyclass(unsendable, module = "iceoryx2")]
+pub struct Publisher {
+    publisher: PublisherUnion,
+    payload_size: usize,
+    is_dynamic: bool,
+}
+
+impl Publisher {
+    pub(crate) fn new(publisher: PublisherUnion, payload_size: usize, is_dynamic: bool) -> Self {
+        Self {
+            publisher,
+            payload_size,
+            is_dynamic,
+        }
+    }
+
+    fn send_bytes(&self, data: &[u8]) -> PyResult<usize> {
+        let has_valid_size = match self.is_dynamic {
+            true => data.len() % self.payload_size == 0,
+            false => data.len() == self.payload_size,
+        };
+
+        if !has_valid_size {
+            return Err(PyValueError::new_err(format!(
+                "Unable to send {} bytes since the payload consists of elements with a size of {} bytes.",
+                data.len(),
+                self.payload_size
+            )));
+        }
+
+        let number_of_elements = match self.is_dynamic {
+            true => data.len() / self.payload_size,
+            false => 1,
+        };
+
+        match &self.publisher {
+            ServiceTypeUnion::Ipc(publisher) => send(publisher, data, number_of_elements),
+            ServiceTypeUnion::Local(publisher) => send(publisher, data, number_of_elements),
+        }
+    }
+}
+
+#[pymethods]
+impl Publisher {
+    /// Copies the content of a bytes-like object, e.g. `bytes` or `bytearray`, into a loaned
+    /// sample and sends it. Returns the number of subscribers that received the sample.
+    fn send(&self, py: Python<'_>, data: PyBuffer<u8>) -> PyResult<usize> {
+        self.send_bytes(&data.to_vec(py)?)
+    }
+
+    /// Copies the content of a C contiguous numpy array of any dtype into a loaned sample and
+    /// sends it. Returns the number of subscribers that received the sample.
+    fn send_array(&self, array: &Bound<'_, PyUntypedArray>) -> PyResult<usize> {
+        if !array.is_c_contiguous() {
+            return Err(PyValueError::new_err(
+                "Unable to send the array since it is not C contiguous.",
+            ));
+        }
+
+        let size = array.len() * array.dtype().itemsize();
+        let data: &[u8] = match size {
+            0 => &[],
+            _ => unsafe {
+                core::slice::from_raw_parts((*array.as_array_ptr()).data as *const u8, size)
+            },
+        };
+
+        self.send_bytes(data)
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::{process_local, zero_copy};
+use iceoryx2::service::port_factory::{event, publish_subscribe};
+use pyo3::prelude::*;
+
+use crate::error::{failure, PortCreationError};
+use crate::listener::Listener;
+use crate::notifier::Notifier;
+use crate::publisher::Publisher;
+use crate::subscriber::Subscriber;
+use crate::ServiceTypeUnion;
+
+/// A publish-subscribe service whose payload is exchanged as raw bytes. It is created with
+/// `Node.publish_subscribe()`.
+#[pyclass(unsendable, module = "iceoryx2")]
+pub struct PublishSubscribeService {
+    service: ServiceTypeUnion<
+        publish_subscribe::PortFactory<zero_copy::Service, [u8]>,
+        publish_subscribe::PortFactory<process_local::Service, [u8]>,
+    >,
+    payload_size: usize,
+    is_dynamic: bool,
+}
+
+impl PublishSubscribeService {
+    pub(crate) fn new(
+        service: ServiceTypeUnion<
+            publish_subscribe::PortFactory<zero_copy::Service, [u8]>,
+            publish_subscribe::PortFactory<process_local::Service, [u8]>,
+        >,
+        payload_size: usize,
+        is_dynamic: bool,
+    ) -> Self {
+        Self {
+            service,
+            payload_size,
+            is_dynamic,
+        }
+    }
+}
+
+#[pymethods]
+impl PublishSubscribeService {
+    /// Creates a publisher. `max_slice_len` defines the maximum number of elements a sent
+    /// payload can contain, it must be 1 for services with a fixed size payload.
+    #[pyo3(signature = (max_slice_len = 1))]
+    fn publisher(&self, max_slice_len: usize) -> PyResult<Publisher> {
+        let publisher = match &self.service {
+            ServiceTypeUnion::Ipc(service) => service
+                .publisher_builder()
+                .max_slice_len(max_slice_len)
+                .create()
+                .map(ServiceTypeUnion::Ipc),
+            ServiceTypeUnion::Local(service) => service
+                .publisher_builder()
+                .max_slice_len(max_slice_len)
+                .create()
+                .map(ServiceTypeUnion::Local),
+        };
+
+        Ok(Publisher::new(
+            publisher.map_err(failure::<PortCreationError, _>)?,
+            self.payload_size,
+            self.is_dynamic,
+        ))
+    }
+
+    /// Creates a subscriber.
+    fn subscriber(&self) -> PyResult<Subscriber> {
+        let subscriber = match &self.service {
+            ServiceTypeUnion::Ipc(service) => service
+                .subscriber_builder()
+                .create()
+                .map(ServiceTypeUnion::Ipc),
+            ServiceTypeUnion::Local(service) => service
+                .subscriber_builder()
+                .create()
+                .map(ServiceTypeUnion::Local),
+        };
+
+        Ok(Subscriber::new(
+            subscriber.map_err(failure::<PortCreationError, _>)?,
+        ))
+    }
+}
+
+/// An event service. It is created with `Node.event()`.
+#[pyclass(unsendable, module = "iceoryx2")]
+pub struct EventService(
+    ServiceTypeUnion<
+        event::PortFactory<zero_copy::Service>,
+        event::PortFactory<process_local::Service>,
+    >,
+);
+
+impl EventService {
+    pub(crate) fn new(
+        service: ServiceTypeUnion<
+            event::PortFactory<zero_copy::Service>,
+            event::PortFactory<process_local::Service>,
+        >,
+    ) -> Self {
+        Self(service)
+    }
+}
+
+#[pymethods]
+impl EventService {
+    /// Creates a notifier.
+    fn notifier(&self) -> PyResult<Notifier> {
+        let notifier = match &self.0 {
+            ServiceTypeUnion::Ipc(service) => service
+                .notifier_builder()
+                .create()
+                .map(ServiceTypeUnion::Ipc),
+            ServiceTypeUnion::Local(service) => service
+                .notifier_builder()
+                .create()
+                .map(ServiceTypeUnion::Local),
+        };
+
+        Ok(Notifier::new(
+            notifier.map_err(failure::<PortCreationError, _>)?,
+        ))
+    }
+
+    /// Creates a listener.
+    fn listener(&self) -> PyResult<Listener> {
+        let listener = match &self.0 {
+            ServiceTypeUnion::Ipc(service) => service
+                .listener_builder()
+                .create()
+                .map(ServiceTypeUnion::Ipc),
+            ServiceTypeUnion::Local(service) => service
+                .listener_builder()
+                .create()
+                .map(ServiceTypeUnion::Local),
+        };
+
+        Ok(Listener::new(
+            listener.map_err(failure::<PortCreationError, _>)?,
+        ))
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2::prelude::{process_local, zero_copy};
+use numpy::PyArray1;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use crate::error::{failure, ReceiveError};
+use crate::ServiceTypeUnion;
+
+type SubscriberUnion = ServiceTypeUnion<
+    iceoryx2::port::subscriber::Subscriber<zero_copy::Service, [u8]>,
+    iceoryx2::port::subscriber::Subscriber<process_local::Service, [u8]>,
+>;
+
+/// Receives copies of the samples of a publish-subscribe service as `bytes` or numpy arrays.
+/// It is created with `PublishSubscribeService.subscriber()`.
+#[pyclass(unsendable, module = "iceoryx2")]
+pub struct Subscriber(SubscriberUnion);
+
+impl Subscriber {
+    pub(crate) fn new(subscriber: SubscriberUnion) -> Self {
+        Self(subscriber)
+    }
+
+    // receives the next sample and converts its payload, the sample is released afterwards
+    fn receive_with<T, F: FnOnce(&[u8]) -> T>(&self, convert: F) -> PyResult<Option<T>> {
+        let result = match &self.0 {
+            ServiceTypeUnion::Ipc(subscriber) => subscriber
+                .receive()
+                .map(|sample| sample.map(|s| convert(s.payload()))),
+            ServiceTypeUnion::Local(subscriber) => subscriber
+                .receive()
+                .map(|sample| sample.map(|s| convert(s.payload()))),
+        };
+
+        result.map_err(failure::<ReceiveError, _>)
+    }
+}
+
+#[pymethods]
+impl Subscriber {
+    /// Receives the next sample and returns a copy of its payload as `bytes`. Returns `None`
+    /// when no sample is available.
+    fn receive<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyBytes>>> {
+        self.receive_with(|payload| PyBytes::new_bound(py, payload))
+    }
+
+    /// Receives the next sample and returns a copy of its payload as one-dimensional `uint8`
+    /// numpy array that can be reinterpreted with `numpy.ndarray.view()`. Returns `None` when
+    /// no sample is available.
+    fn receive_array<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyArray1<u8>>>> {
+        self.receive_with(|payload| PyArray1::from_slice_bound(py, payload))
+    }
+}
@@ -0,0 +1,53 @@
+# Copyright (c) 2024 Contributors to the Eclipse Foundation
+#
+# See the NOTICE file(s) distributed with this work for additional
+# information regarding copyright ownership.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Apache Software License 2.0 which is available at
+# https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+# which is available at https://opensource.org/licenses/MIT.
+#
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+import uuid
+
+import iceoryx2
+import pytest
+
+SERVICE_TYPES = [iceoryx2.ServiceType.Ipc, iceoryx2.ServiceType.Local]
+
+
+def generate_name():
+    return "python_event_tests_{}".format(uuid.uuid4().hex)
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_try_wait_returns_none_without_notification(service_type):
+    node = iceoryx2.Node(service_type)
+    service = node.event(generate_name())
+    listener = service.listener()
+
+    assert listener.try_wait_one() is None
+    assert listener.timed_wait_one(0.01) is None
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_notification_is_received(service_type):
+    node = iceoryx2.Node(service_type)
+    service = node.event(generate_name())
+    listener = service.listener()
+    notifier = service.notifier()
+
+    assert notifier.notify(event_id=12) == 1
+    assert listener.timed_wait_one(1.0) == 12
+    assert listener.try_wait_one() is None
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_timed_wait_fails_with_negative_timeout(service_type):
+    node = iceoryx2.Node(service_type)
+    listener = node.event(generate_name()).listener()
+
+    with pytest.raises(ValueError):
+        listener.timed_wait_one(-1.0)
@@ -0,0 +1,85 @@
+# Copyright (c) 2024 Contributors to the Eclipse Foundation
+#
+# See the NOTICE file(s) distributed with this work for additional
+# information regarding copyright ownership.
+#
+# This program and the accompanying materials are made available under the
+# terms of the Apache Software License 2.0 which is available at
+# https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+# which is available at https://opensource.org/licenses/MIT.
+#
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+
+import uuid
+
+import iceoryx2
+import numpy as np
+import pytest
+
+SERVICE_TYPES = [iceoryx2.ServiceType.Ipc, iceoryx2.ServiceType.Local]
+
+
+def generate_name():
+    return "python_publish_subscribe_tests_{}".format(uuid.uuid4().hex)
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_receive_returns_none_when_nothing_was_sent(service_type):
+    node = iceoryx2.Node(service_type)
+    service = node.publish_subscribe(generate_name())
+    subscriber = service.subscriber()
+
+    assert subscriber.receive() is None
+    assert subscriber.receive_array() is None
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_send_and_receive_bytes_works(service_type):
+    node = iceoryx2.Node(service_type)
+    service = node.publish_subscribe(generate_name())
+    publisher = service.publisher(max_slice_len=16)
+    subscriber = service.subscriber()
+
+    assert publisher.send(b"hypnotoad") == 1
+    assert publisher.send(bytearray(b"all glory")) == 1
+
+    assert subscriber.receive() == b"hypnotoad"
+    assert subscriber.receive() == b"all glory"
+    assert subscriber.receive() is None
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_send_and_receive_array_works(service_type):
+    node = iceoryx2.Node(service_type)
+    service = node.publish_subscribe(generate_name(), "f32", 4, 4)
+    publisher = service.publisher(max_slice_len=8)
+    subscriber = service.subscriber()
+
+    data = np.arange(8, dtype=np.float32)
+    assert publisher.send_array(data) == 1
+
+    received = subscriber.receive_array()
+    assert received.dtype == np.uint8
+    assert np.array_equal(received.view(np.float32), data)
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_send_fails_when_size_does_not_match_payload_layout(service_type):
+    node = iceoryx2.Node(service_type)
+    service = node.publish_subscribe(generate_name(), "u64", 8, 8, dynamic=False)
+    publisher = service.publisher()
+
+    with pytest.raises(ValueError):
+        publisher.send(b"1234")
+
+    assert publisher.send_array(np.array([42], dtype=np.uint64)) == 0
+
+
+@pytest.mark.parametrize("service_type", SERVICE_TYPES)
+def test_open_fails_when_payload_layout_does_not_match(service_type):
+    node = iceoryx2.Node(service_type)
+    name = generate_name()
+    _service = node.publish_subscribe(name, "u64", 8, 8, dynamic=False)
+
+    with pytest.raises(iceoryx2.ServiceOpenOrCreateError):
+        node.publish_subscribe(name, "u32", 4, 4, dynamic=False)
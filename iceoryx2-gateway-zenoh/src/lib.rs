@@ -0,0 +1,32 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # iceoryx2-gateway-zenoh
+//!
+//! Bridges iceoryx2 publish-subscribe services to zenoh key expressions and back, so that
+//! participants on different hosts can communicate. Participants on the same host keep
+//! communicating zero-copy via iceoryx2, only the gateway copies the samples from and to the
+//! network. Run one gateway per host.
+//!
+//! The bridged services are defined in a TOML [`mapping::Mapping`] file that is passed to the
+//! `iox2-gateway-zenoh` binary:
+//!
+//! ```text
+//! iox2-gateway-zenoh --mapping gateway.toml
+//! ```
+//!
+//! * [`mapping::Mapping`] - the services, key expressions and zenoh settings
+//! * [`transport::ZenohTransport`] - the [`Transport`](iceoryx2_gateway::Transport) that
+//!   moves the samples over zenoh
+
+pub mod mapping;
+pub mod transport;
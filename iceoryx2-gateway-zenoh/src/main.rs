@@ -0,0 +1,96 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::process::ExitCode;
+
+use clap::Parser;
+use iceoryx2::prelude::*;
+use iceoryx2_bb_log::{set_log_level, LogLevel};
+use iceoryx2_gateway::Gateway;
+use iceoryx2_gateway_zenoh::mapping::{Direction, Mapping};
+use iceoryx2_gateway_zenoh::transport::ZenohTransport;
+
+#[derive(Parser, Debug)]
+#[clap(version, about, long_about = None)]
+struct Args {
+    /// The TOML file that maps the iceoryx2 services to zenoh key expressions
+    #[clap(short, long)]
+    mapping: String,
+}
+
+fn main() -> ExitCode {
+    set_log_level(LogLevel::Warn);
+    let args = Args::parse();
+
+    let mapping = match Mapping::from_file(&args.mapping) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("unable to load mapping file \"{}\" ({})", args.mapping, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let node = match NodeBuilder::new().create::<zero_copy::Service>() {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("unable to create node ({})", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let transport = match ZenohTransport::new(&mapping) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("unable to open zenoh session ({})", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut gateway = Gateway::new(&node, transport);
+    for route in &mapping.routes {
+        let service_name = match ServiceName::new(&route.service) {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!(
+                    "invalid service name \"{}\" in mapping file \"{}\" ({})",
+                    route.service, args.mapping, e
+                );
+                return ExitCode::FAILURE;
+            }
+        };
+        let type_descriptor = route.type_descriptor();
+        // the mapping file defines the payload type, the service creation fails when an
+        // existing service has another payload type
+        let result = unsafe {
+            match route.direction {
+                Direction::Forward => gateway.forward_with_type(&service_name, &type_descriptor),
+                Direction::Inject => gateway.inject_with_type(&service_name, &type_descriptor),
+            }
+        };
+
+        if let Err(e) = result {
+            eprintln!(
+                "unable to bridge service \"{}\" to key \"{}\" ({})",
+                route.service, route.key, e
+            );
+            return ExitCode::FAILURE;
+        }
+    }
+
+    match gateway.run(mapping.cycle_time()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("gateway stopped ({})", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,222 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The TOML mapping file that defines which iceoryx2 services are bridged to which zenoh key
+//! expressions.
+//!
+//! ```
+//! use iceoryx2_gateway_zenoh::mapping::*;
+//!
+//! let mapping = Mapping::from_toml(r#"
+//!     cycle_time_in_ms = 5
+//!
+//!     [zenoh]
+//!     mode = "peer"
+//!     connect = ["tcp/192.168.1.2:7447"]
+//!
+//!     [[route]]
+//!     service = "My/Funk/ServiceName"
+//!     key = "robot/odometry"
+//!     direction = "forward"
+//!     type_name = "Odometry"
+//!     size = 48
+//!     alignment = 8
+//! "#).unwrap();
+//!
+//! assert_eq!(mapping.routes.len(), 1);
+//! assert_eq!(mapping.routes[0].direction, Direction::Forward);
+//! ```
+
+use core::time::Duration;
+use std::collections::HashSet;
+
+use iceoryx2::prelude::ServiceName;
+use iceoryx2_bb_log::fail;
+use iceoryx2_gateway::TypeDescriptor;
+use serde::{Deserialize, Serialize};
+
+/// The default key expression that is used for the codec negotiation of the
+/// [`Gateway`](iceoryx2_gateway::Gateway)s.
+pub const DEFAULT_CONTROL_KEY: &str = "iceoryx2/gateway/control";
+
+/// The default cycle time of the gateway in milliseconds.
+pub const DEFAULT_CYCLE_TIME_IN_MS: u64 = 10;
+
+/// Failures occurring while loading a [`Mapping`] with [`Mapping::from_file()`] or
+/// [`Mapping::from_toml()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum MappingCreationError {
+    FailedToReadMappingFile,
+    UnableToDeserializeContents,
+    InvalidServiceName,
+    InvalidKey,
+    InvalidTypeLayout,
+    DuplicateRoute,
+}
+
+impl std::fmt::Display for MappingCreationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "MappingCreationError::{:?}", self)
+    }
+}
+
+impl std::error::Error for MappingCreationError {}
+
+/// Defines in which direction the samples of a [`Route`] are bridged.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    /// All samples published on the iceoryx2 service are put on the zenoh key expression.
+    Forward,
+    /// All samples that arrive on the zenoh key expression are published on the iceoryx2
+    /// service.
+    Inject,
+}
+
+/// Bridges a single iceoryx2 publish-subscribe service to a zenoh key expression. Since the
+/// gateway copies the payload bytewise, the payload type must be self-contained and must have
+/// the same layout on both hosts.
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Route {
+    /// The name of the iceoryx2 service
+    pub service: String,
+    /// The zenoh key expression
+    pub key: String,
+    /// The direction in which the samples are bridged
+    pub direction: Direction,
+    /// The payload type name of the service, see
+    /// [`TypeDetails::payload_type_name`](iceoryx2::service::static_config::type_details::TypeDetails::payload_type_name)
+    pub type_name: String,
+    /// The size of the payload type in bytes
+    pub size: usize,
+    /// The alignment of the payload type
+    pub alignment: usize,
+}
+
+impl Route {
+    /// Returns the [`TypeDescriptor`] of the payload type
+    pub fn type_descriptor(&self) -> TypeDescriptor {
+        TypeDescriptor {
+            type_name: self.type_name.clone(),
+            size: self.size,
+            alignment: self.alignment,
+        }
+    }
+}
+
+/// The settings of the zenoh session.
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Zenoh {
+    /// A zenoh config file that is loaded before the other settings are applied
+    pub config_file: Option<String>,
+    /// The zenoh mode, `peer`, `client` or `router`
+    pub mode: Option<String>,
+    /// The endpoints the session connects to, for instance `tcp/192.168.1.2:7447`
+    pub connect: Vec<String>,
+    /// The endpoints the session listens on
+    pub listen: Vec<String>,
+}
+
+/// The content of a mapping file.
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Mapping {
+    /// The time between two pump cycles of the gateway
+    #[serde(default = "default_cycle_time_in_ms")]
+    pub cycle_time_in_ms: u64,
+    /// The key expression that is used for the codec negotiation
+    #[serde(default = "default_control_key")]
+    pub control_key: String,
+    /// The settings of the zenoh session
+    #[serde(default)]
+    pub zenoh: Zenoh,
+    /// All bridged services
+    #[serde(default, rename = "route")]
+    pub routes: Vec<Route>,
+}
+
+fn default_cycle_time_in_ms() -> u64 {
+    DEFAULT_CYCLE_TIME_IN_MS
+}
+
+fn default_control_key() -> String {
+    DEFAULT_CONTROL_KEY.to_string()
+}
+
+impl Default for Mapping {
+    fn default() -> Self {
+        Self {
+            cycle_time_in_ms: DEFAULT_CYCLE_TIME_IN_MS,
+            control_key: DEFAULT_CONTROL_KEY.to_string(),
+            zenoh: Zenoh::default(),
+            routes: vec![],
+        }
+    }
+}
+
+impl Mapping {
+    /// Loads and verifies the [`Mapping`] from a TOML file.
+    pub fn from_file(mapping_file: &str) -> Result<Self, MappingCreationError> {
+        let contents = fail!(from "Mapping::from_file()", when std::fs::read_to_string(mapping_file),
+                with MappingCreationError::FailedToReadMappingFile,
+                "Failed to load mapping since the file \"{}\" could not be read.", mapping_file);
+
+        Self::from_toml(&contents)
+    }
+
+    /// Parses and verifies the [`Mapping`] from a TOML string.
+    pub fn from_toml(contents: &str) -> Result<Self, MappingCreationError> {
+        let msg = "Failed to load mapping";
+        let mapping: Self = match toml::from_str(contents) {
+            Ok(v) => v,
+            Err(e) => {
+                fail!(from "Mapping::from_toml()", with MappingCreationError::UnableToDeserializeContents,
+                    "{} since the contents could not be deserialized ({}).", msg, e);
+            }
+        };
+
+        let mut services = HashSet::new();
+        let mut keys = HashSet::new();
+        for route in &mapping.routes {
+            if ServiceName::new(&route.service).is_err() {
+                fail!(from mapping, with MappingCreationError::InvalidServiceName,
+                    "{} since \"{}\" is not a valid service name.", msg, route.service);
+            }
+
+            if route.key.is_empty() || route.key == mapping.control_key {
+                fail!(from mapping, with MappingCreationError::InvalidKey,
+                    "{} since the key \"{}\" of the service \"{}\" is empty or the control key.",
+                    msg, route.key, route.service);
+            }
+
+            if route.size == 0 || !route.alignment.is_power_of_two() {
+                fail!(from mapping, with MappingCreationError::InvalidTypeLayout,
+                    "{} since the service \"{}\" has an invalid payload layout (size: {}, alignment: {}).",
+                    msg, route.service, route.size, route.alignment);
+            }
+
+            // one route per service and direction, and every key belongs to exactly one service
+            if !services.insert((&route.service, route.direction)) || !keys.insert(&route.key) {
+                fail!(from mapping, with MappingCreationError::DuplicateRoute,
+                    "{} since the service \"{}\" or the key \"{}\" is mapped twice.",
+                    msg, route.service, route.key);
+            }
+        }
+
+        Ok(mapping)
+    }
+
+    /// Returns the time between two pump cycles of the gateway
+    pub fn cycle_time(&self) -> Duration {
+        Duration::from_millis(self.cycle_time_in_ms)
+    }
+}
@@ -0,0 +1,339 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`Transport`] that moves the [`Message`]s of the [`Gateway`](iceoryx2_gateway::Gateway)
+//! over zenoh.
+//!
+//! Every forwarded service is put on its key expression, the payload is sent as it is and the
+//! [`TypeDescriptor`] as well as the encoding travel in the attachment. Every injected service
+//! subscribes to its key expression. Only samples from remote zenoh sessions are received, so
+//! that local participants keep communicating zero-copy via iceoryx2 and the gateway never
+//! receives what it sent itself.
+
+use core::fmt::Debug;
+use std::collections::HashMap;
+
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_gateway::{Message, Transport, TransportError, TypeDescriptor};
+use zenoh::bytes::ZBytes;
+use zenoh::handlers::FifoChannelHandler;
+use zenoh::pubsub::{Publisher, Subscriber};
+use zenoh::sample::{Locality, Sample};
+use zenoh::{Session, Wait};
+
+use crate::mapping::{Direction, Mapping};
+
+const ATTACHMENT_SEPARATOR: u8 = 0;
+
+/// Failures that can occur when a [`ZenohTransport`] is created with [`ZenohTransport::new()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ZenohTransportCreateError {
+    /// The zenoh config file could not be loaded or the settings could not be applied.
+    InvalidZenohConfig,
+    /// The zenoh session could not be opened or the publishers and subscribers could not be
+    /// declared.
+    UnableToOpenSession,
+}
+
+impl std::fmt::Display for ZenohTransportCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ZenohTransportCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ZenohTransportCreateError {}
+
+struct Inbound {
+    service_name: String,
+    type_descriptor: TypeDescriptor,
+    subscriber: Subscriber<FifoChannelHandler<Sample>>,
+}
+
+struct Declarations {
+    publishers: HashMap<String, Publisher<'static>>,
+    inbound: Vec<Inbound>,
+    _session: Session,
+}
+
+/// A [`Transport`] that puts forwarded services on zenoh key expressions and injects the
+/// samples of subscribed key expressions, as defined in the [`Mapping`].
+pub struct ZenohTransport {
+    mapping: Mapping,
+    config: zenoh::Config,
+    declarations: Option<Declarations>,
+    next_inbound: usize,
+}
+
+impl Debug for ZenohTransport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ZenohTransport {{ mapping: {:?}, is_connected: {} }}",
+            self.mapping,
+            self.declarations.is_some()
+        )
+    }
+}
+
+impl ZenohTransport {
+    /// Opens a zenoh session with the settings of the [`Mapping`] and declares a publisher for
+    /// every forwarded and a subscriber for every injected service.
+    pub fn new(mapping: &Mapping) -> Result<Self, ZenohTransportCreateError> {
+        let msg = "Unable to create zenoh transport";
+        let origin = "ZenohTransport::new()";
+        let mut config = match &mapping.zenoh.config_file {
+            Some(file) => fail!(from origin, when zenoh::Config::from_file(file),
+                with ZenohTransportCreateError::InvalidZenohConfig,
+                "{} since the zenoh config file \"{}\" could not be loaded.", msg, file),
+            None => zenoh::Config::default(),
+        };
+
+        let mut settings = vec![];
+        if let Some(mode) = &mapping.zenoh.mode {
+            settings.push(("mode", format!("{:?}", mode)));
+        }
+        if !mapping.zenoh.connect.is_empty() {
+            settings.push(("connect/endpoints", format!("{:?}", mapping.zenoh.connect)));
+        }
+        if !mapping.zenoh.listen.is_empty() {
+            settings.push(("listen/endpoints", format!("{:?}", mapping.zenoh.listen)));
+        }
+        for (key, value) in settings {
+            fail!(from origin, when config.insert_json5(key, &value),
+                with ZenohTransportCreateError::InvalidZenohConfig,
+                "{} since the zenoh setting {} = {} could not be applied.", msg, key, value);
+        }
+
+        let mut new_self = Self {
+            mapping: mapping.clone(),
+            config,
+            declarations: None,
+            next_inbound: 0,
+        };
+
+        match new_self.declare() {
+            Some(declarations) => new_self.declarations = Some(declarations),
+            None => {
+                fail!(from origin, with ZenohTransportCreateError::UnableToOpenSession,
+                    "{} since the zenoh session could not be opened.", msg);
+            }
+        }
+
+        Ok(new_self)
+    }
+
+    fn declare(&self) -> Option<Declarations> {
+        let session = zenoh::open(self.config.clone()).wait().ok()?;
+
+        let mut publishers = HashMap::new();
+        let mut inbound = vec![];
+        let control = self.mapping.control_key.as_str();
+        // the codec negotiation messages have an empty service name and use the control key
+        let routes = self
+            .mapping
+            .routes
+            .iter()
+            .map(|r| {
+                (
+                    r.service.as_str(),
+                    r.key.as_str(),
+                    r.direction,
+                    r.type_descriptor(),
+                )
+            })
+            .chain(core::iter::once((
+                "",
+                control,
+                Direction::Forward,
+                control_type_descriptor(),
+            )))
+            .chain(core::iter::once((
+                "",
+                control,
+                Direction::Inject,
+                control_type_descriptor(),
+            )));
+
+        for (service_name, key, direction, type_descriptor) in routes {
+            match direction {
+                Direction::Forward => {
+                    let publisher = session
+                        .declare_publisher(key.to_string())
+                        .allowed_destination(Locality::Remote)
+                        .wait()
+                        .ok()?;
+                    publishers.insert(service_name.to_string(), publisher);
+                }
+                Direction::Inject => {
+                    let subscriber = session
+                        .declare_subscriber(key.to_string())
+                        .allowed_origin(Locality::Remote)
+                        .wait()
+                        .ok()?;
+                    inbound.push(Inbound {
+                        service_name: service_name.to_string(),
+                        type_descriptor,
+                        subscriber,
+                    });
+                }
+            }
+        }
+
+        Some(Declarations {
+            publishers,
+            inbound,
+            _session: session,
+        })
+    }
+}
+
+impl Transport for ZenohTransport {
+    fn send(&mut self, message: &Message) -> Result<(), TransportError> {
+        let declarations = match &self.declarations {
+            Some(v) => v,
+            None => {
+                fail!(from self, with TransportError::ConnectionLost,
+                    "Unable to send message since the zenoh session is not open.");
+            }
+        };
+
+        let publisher = match declarations.publishers.get(&message.service_name) {
+            Some(v) => v,
+            None => {
+                warn!(from self,
+                    "Discard message of service \"{}\" since no key is mapped to it.",
+                    message.service_name);
+                return Ok(());
+            }
+        };
+
+        fail!(from self, when publisher
+                .put(message.payload.clone())
+                .attachment(encode_attachment(message))
+                .wait(),
+            with TransportError::ConnectionLost,
+            "Unable to put message of service \"{}\".", message.service_name);
+
+        Ok(())
+    }
+
+    fn try_receive(&mut self) -> Result<Option<Message>, TransportError> {
+        let declarations = match &self.declarations {
+            Some(v) => v,
+            None => {
+                fail!(from self, with TransportError::ConnectionLost,
+                    "Unable to receive message since the zenoh session is not open.");
+            }
+        };
+
+        // round robin, so that a busy key expression cannot starve the others
+        let number_of_inbound = declarations.inbound.len();
+        for n in 0..number_of_inbound {
+            let index = (self.next_inbound + n) % number_of_inbound;
+            let inbound = &declarations.inbound[index];
+            let sample = fail!(from self, when inbound.subscriber.try_recv(),
+                with TransportError::ConnectionLost,
+                "Unable to receive message of service \"{}\".", inbound.service_name);
+
+            if let Some(sample) = sample {
+                self.next_inbound = index + 1;
+                let (type_descriptor, encoding) = match sample.attachment() {
+                    Some(attachment) => match decode_attachment(&attachment.to_bytes()) {
+                        Some(v) => v,
+                        None => {
+                            warn!(from self,
+                                "Discard message of service \"{}\" since it has a corrupted attachment.",
+                                inbound.service_name);
+                            return Ok(None);
+                        }
+                    },
+                    // remote zenoh applications that are not a gateway send plain payloads
+                    None => (inbound.type_descriptor.clone(), None),
+                };
+
+                return Ok(Some(Message {
+                    service_name: inbound.service_name.clone(),
+                    type_descriptor,
+                    payload: sample.payload().to_bytes().to_vec(),
+                    encoding,
+                }));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn reconnect(&mut self) -> Result<(), TransportError> {
+        self.declarations = None;
+        match self.declare() {
+            Some(declarations) => {
+                self.declarations = Some(declarations);
+                Ok(())
+            }
+            None => {
+                fail!(from self, with TransportError::ConnectionLost,
+                    "Unable to reopen the zenoh session.");
+            }
+        }
+    }
+}
+
+fn control_type_descriptor() -> TypeDescriptor {
+    TypeDescriptor {
+        type_name: String::new(),
+        size: 0,
+        alignment: 0,
+    }
+}
+
+// type_name \0 size \0 alignment \0 encoding
+fn encode_attachment(message: &Message) -> ZBytes {
+    let mut attachment = vec![];
+    for field in [
+        message.type_descriptor.type_name.clone(),
+        message.type_descriptor.size.to_string(),
+        message.type_descriptor.alignment.to_string(),
+        message.encoding.clone().unwrap_or_default(),
+    ] {
+        if !attachment.is_empty() {
+            attachment.push(ATTACHMENT_SEPARATOR);
+        }
+        attachment.extend_from_slice(field.as_bytes());
+    }
+
+    ZBytes::from(attachment)
+}
+
+fn decode_attachment(attachment: &[u8]) -> Option<(TypeDescriptor, Option<String>)> {
+    let fields: Vec<&str> = attachment
+        .split(|b| *b == ATTACHMENT_SEPARATOR)
+        .map(core::str::from_utf8)
+        .collect::<Result<_, _>>()
+        .ok()?;
+
+    if fields.len() != 4 {
+        return None;
+    }
+
+    Some((
+        TypeDescriptor {
+            type_name: fields[0].to_string(),
+            size: fields[1].parse().ok()?,
+            alignment: fields[2].parse().ok()?,
+        },
+        if fields[3].is_empty() {
+            None
+        } else {
+            Some(fields[3].to_string())
+        },
+    ))
+}
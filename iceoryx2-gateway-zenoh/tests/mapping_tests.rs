@@ -0,0 +1,114 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_gateway_zenoh::mapping::*;
+
+const ROUTE: &str = r#"
+    [[route]]
+    service = "camera/front"
+    key = "robot/camera/front"
+    direction = "forward"
+    type_name = "Image"
+    size = 1024
+    alignment = 8
+"#;
+
+#[test]
+fn mapping_without_optional_settings_uses_defaults() {
+    let sut = Mapping::from_toml(ROUTE).unwrap();
+
+    assert_that!(sut.cycle_time(), eq Duration::from_millis(DEFAULT_CYCLE_TIME_IN_MS));
+    assert_that!(sut.control_key, eq DEFAULT_CONTROL_KEY);
+    assert_that!(sut.zenoh, eq Zenoh::default());
+    assert_that!(sut.routes, len 1);
+    assert_that!(sut.routes[0].direction, eq Direction::Forward);
+    assert_that!(sut.routes[0].type_descriptor().size, eq 1024);
+}
+
+#[test]
+fn mapping_with_all_settings_can_be_parsed() {
+    let sut = Mapping::from_toml(&format!(
+        r#"
+        cycle_time_in_ms = 2
+        control_key = "fleet/control"
+
+        [zenoh]
+        mode = "client"
+        connect = ["tcp/10.0.0.1:7447", "tcp/10.0.0.2:7447"]
+        listen = ["tcp/0.0.0.0:7447"]
+
+        {}
+
+        [[route]]
+        service = "cmd_vel"
+        key = "robot/cmd_vel"
+        direction = "inject"
+        type_name = "Twist"
+        size = 48
+        alignment = 8
+        "#,
+        ROUTE
+    ))
+    .unwrap();
+
+    assert_that!(sut.cycle_time(), eq Duration::from_millis(2));
+    assert_that!(sut.control_key, eq "fleet/control");
+    assert_that!(sut.zenoh.mode, eq Some("client".to_string()));
+    assert_that!(sut.zenoh.connect, len 2);
+    assert_that!(sut.zenoh.listen, len 1);
+    assert_that!(sut.routes, len 2);
+    assert_that!(sut.routes[1].direction, eq Direction::Inject);
+}
+
+#[test]
+fn mapping_with_invalid_routes_fails() {
+    let invalid_routes = [
+        (
+            ROUTE.replace("camera/front\"", "\""),
+            MappingCreationError::InvalidServiceName,
+        ),
+        (
+            ROUTE.replace("robot/camera/front", ""),
+            MappingCreationError::InvalidKey,
+        ),
+        (
+            ROUTE.replace("alignment = 8", "alignment = 3"),
+            MappingCreationError::InvalidTypeLayout,
+        ),
+        (
+            ROUTE.replace("size = 1024", "size = 0"),
+            MappingCreationError::InvalidTypeLayout,
+        ),
+        (
+            ROUTE.replace("forward", "sideways"),
+            MappingCreationError::UnableToDeserializeContents,
+        ),
+        (
+            format!("{}{}", ROUTE, ROUTE),
+            MappingCreationError::DuplicateRoute,
+        ),
+    ];
+
+    for (contents, error) in invalid_routes {
+        assert_that!(Mapping::from_toml(&contents).err(), eq Some(error));
+    }
+}
+
+#[test]
+fn mapping_from_non_existing_file_fails() {
+    let sut = Mapping::from_file("/this/mapping/does/not/exist.toml");
+
+    assert_that!(sut.err(), eq Some(MappingCreationError::FailedToReadMappingFile));
+}
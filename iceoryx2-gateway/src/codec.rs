@@ -0,0 +1,243 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`Codec`] plugin trait that compresses the payload of [`Message`](crate::Message)s
+//! before they are sent over the [`Transport`](crate::Transport). The payload in shared memory
+//! is never compressed. The [`Gateway`](crate::Gateway) negotiates with the remote side which
+//! [`Codec`]s both sides support, see [`Gateway::add_codec()`](crate::Gateway::add_codec).
+//!
+//! The provided [`Codec`]s are enabled with cargo features:
+//!
+//!  * `lz4` (default) - `Lz4Codec` based on [lz4_flex](https://crates.io/crates/lz4_flex)
+//!  * `zstd` - `ZstdCodec` based on [zstd](https://crates.io/crates/zstd)
+//!
+//! Further algorithms can be added by implementing the [`Codec`] trait.
+
+use core::fmt::Debug;
+
+/// The failures a [`Codec`] can report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodecError {
+    /// The input could not be compressed.
+    CompressionFailed,
+    /// The input is not a valid compressed payload of the [`Codec`].
+    CorruptedInput,
+    /// The decompressed payload would exceed the maximum message size of the [`Codec`].
+    ExceedsMaxMessageSize,
+}
+
+impl std::fmt::Display for CodecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "CodecError::{:?}", self)
+    }
+}
+
+impl std::error::Error for CodecError {}
+
+/// The plugin trait of a compression algorithm. Both sides of the
+/// [`Transport`](crate::Transport) identify a [`Codec`] by its [`Codec::name()`].
+pub trait Codec: Debug {
+    /// The unique name of the [`Codec`], e.g. `"lz4"` or `"zstd"`.
+    fn name(&self) -> &str;
+
+    /// Appends the compressed `input` to `output`. On failure, `output` is left unchanged.
+    fn compress(&self, input: &[u8], output: &mut Vec<u8>) -> Result<(), CodecError>;
+
+    /// Appends the decompressed `input` to `output`. On failure, `output` is left unchanged.
+    fn decompress(&self, input: &[u8], output: &mut Vec<u8>) -> Result<(), CodecError>;
+}
+
+/// The maximum size in bytes of a decompressed payload of a default [`Codec`], see for instance
+/// `Lz4Codec::new()`.
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
+
+#[cfg(feature = "lz4")]
+pub use lz4::Lz4Codec;
+
+#[cfg(feature = "zstd")]
+pub use self::zstd::ZstdCodec;
+
+#[cfg(feature = "lz4")]
+mod lz4 {
+    use super::{Codec, CodecError, DEFAULT_MAX_MESSAGE_SIZE};
+
+    const SIZE_PREFIX_LEN: usize = core::mem::size_of::<u32>();
+
+    /// [`Codec`] that uses the LZ4 block format. The compressed payload is prefixed with the
+    /// uncompressed size as little endian `u32`. It is fast and reduces the size of payloads with
+    /// repeating content, like zero-initialized buffers or point clouds.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Lz4Codec {
+        max_message_size: usize,
+    }
+
+    impl Default for Lz4Codec {
+        fn default() -> Self {
+            Self::new(DEFAULT_MAX_MESSAGE_SIZE)
+        }
+    }
+
+    impl Lz4Codec {
+        /// The name of the [`Lz4Codec`].
+        pub const NAME: &'static str = "lz4";
+
+        /// Creates a new [`Lz4Codec`] that rejects every compressed payload whose uncompressed
+        /// size exceeds `max_message_size` bytes. The size is stated by the remote side and
+        /// must not be trusted, otherwise a single message could allocate arbitrary memory.
+        pub fn new(max_message_size: usize) -> Self {
+            Self { max_message_size }
+        }
+
+        /// Returns the maximum size in bytes of a decompressed payload.
+        pub fn max_message_size(&self) -> usize {
+            self.max_message_size
+        }
+    }
+
+    impl Codec for Lz4Codec {
+        fn name(&self) -> &str {
+            Self::NAME
+        }
+
+        fn compress(&self, input: &[u8], output: &mut Vec<u8>) -> Result<(), CodecError> {
+            let uncompressed_size =
+                u32::try_from(input.len()).map_err(|_| CodecError::CompressionFailed)?;
+
+            let start = output.len();
+            let block_start = start + SIZE_PREFIX_LEN;
+            output.extend_from_slice(&uncompressed_size.to_le_bytes());
+            output.resize(
+                block_start + lz4_flex::block::get_maximum_output_size(input.len()),
+                0,
+            );
+
+            match lz4_flex::block::compress_into(input, &mut output[block_start..]) {
+                Ok(len) => {
+                    output.truncate(block_start + len);
+                    Ok(())
+                }
+                Err(_) => {
+                    output.truncate(start);
+                    Err(CodecError::CompressionFailed)
+                }
+            }
+        }
+
+        fn decompress(&self, input: &[u8], output: &mut Vec<u8>) -> Result<(), CodecError> {
+            let (uncompressed_size, block) = lz4_flex::block::uncompressed_size(input)
+                .map_err(|_| CodecError::CorruptedInput)?;
+
+            if uncompressed_size > self.max_message_size {
+                return Err(CodecError::ExceedsMaxMessageSize);
+            }
+
+            let start = output.len();
+            output.resize(start + uncompressed_size, 0);
+
+            match lz4_flex::block::decompress_into(block, &mut output[start..]) {
+                Ok(len) if len == uncompressed_size => Ok(()),
+                _ => {
+                    output.truncate(start);
+                    Err(CodecError::CorruptedInput)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "zstd")]
+mod zstd {
+    use std::io::Read;
+
+    use super::{Codec, CodecError, DEFAULT_MAX_MESSAGE_SIZE};
+
+    /// [`Codec`] that uses the zstd frame format. It compresses better than the LZ4 block
+    /// format at the cost of a higher CPU load and is intended for transports with a low
+    /// bandwidth.
+    #[derive(Debug, Clone, Copy)]
+    pub struct ZstdCodec {
+        max_message_size: usize,
+        compression_level: i32,
+    }
+
+    impl Default for ZstdCodec {
+        fn default() -> Self {
+            Self::new(DEFAULT_MAX_MESSAGE_SIZE)
+        }
+    }
+
+    impl ZstdCodec {
+        /// The name of the [`ZstdCodec`].
+        pub const NAME: &'static str = "zstd";
+
+        /// Creates a new [`ZstdCodec`] with the default compression level that rejects every
+        /// compressed payload whose uncompressed size exceeds `max_message_size` bytes. The
+        /// payload is decompressed incrementally, therefore a forged frame cannot allocate more
+        /// than `max_message_size` bytes.
+        pub fn new(max_message_size: usize) -> Self {
+            Self {
+                max_message_size,
+                compression_level: ::zstd::DEFAULT_COMPRESSION_LEVEL,
+            }
+        }
+
+        /// Defines the compression level, see
+        /// [`zstd::compression_level_range()`](::zstd::compression_level_range). Higher levels
+        /// compress better but slower. The level does not need to match the remote side.
+        pub fn compression_level(mut self, value: i32) -> Self {
+            self.compression_level = value;
+            self
+        }
+
+        /// Returns the maximum size in bytes of a decompressed payload.
+        pub fn max_message_size(&self) -> usize {
+            self.max_message_size
+        }
+    }
+
+    impl Codec for ZstdCodec {
+        fn name(&self) -> &str {
+            Self::NAME
+        }
+
+        fn compress(&self, input: &[u8], output: &mut Vec<u8>) -> Result<(), CodecError> {
+            let start = output.len();
+            match ::zstd::stream::copy_encode(input, &mut *output, self.compression_level) {
+                Ok(()) => Ok(()),
+                Err(_) => {
+                    output.truncate(start);
+                    Err(CodecError::CompressionFailed)
+                }
+            }
+        }
+
+        fn decompress(&self, input: &[u8], output: &mut Vec<u8>) -> Result<(), CodecError> {
+            let decoder = ::zstd::stream::read::Decoder::with_buffer(input)
+                .map_err(|_| CodecError::CorruptedInput)?;
+
+            // read one byte more than allowed to detect payloads that exceed the limit
+            let limit = (self.max_message_size as u64).saturating_add(1);
+            let start = output.len();
+            match decoder.take(limit).read_to_end(output) {
+                Ok(len) if len > self.max_message_size => {
+                    output.truncate(start);
+                    Err(CodecError::ExceedsMaxMessageSize)
+                }
+                Ok(_) => Ok(()),
+                Err(_) => {
+                    output.truncate(start);
+                    Err(CodecError::CorruptedInput)
+                }
+            }
+        }
+    }
+}
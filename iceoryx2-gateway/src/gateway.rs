@@ -0,0 +1,684 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::fmt::Debug;
+use core::time::Duration;
+
+use iceoryx2::prelude::*;
+use iceoryx2::service;
+use iceoryx2::service::static_config::type_details::TypeVariant;
+use iceoryx2_bb_elementary::plain_old_data::PlainOldData;
+use iceoryx2_bb_log::{fail, warn};
+
+use crate::codec::Codec;
+use crate::transport::{Message, Transport, TransportError, TypeDescriptor};
+
+/// Payloads smaller than this are sent uncompressed, see [`Gateway::set_compression_threshold()`].
+pub const DEFAULT_COMPRESSION_THRESHOLD: usize = 1024;
+
+// the codec negotiation uses messages with an empty service name which is an invalid
+// service name and can therefore never collide with a route
+const NEGOTIATION_SERVICE_NAME: &str = "";
+const NEGOTIATION_REQUEST: u8 = 1;
+const NEGOTIATION_RESPONSE: u8 = 0;
+
+/// The failures that can occur when a route is added to the [`Gateway`] with
+/// [`Gateway::forward()`] or [`Gateway::inject()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GatewayRouteCreateError {
+    /// The service could not be opened or created, for instance since it exists with an
+    /// incompatible payload type.
+    UnableToOpenService,
+    /// The subscriber or publisher of the route could not be created.
+    UnableToCreatePort,
+    /// A route for the service in the same direction exists already.
+    RouteAlreadyExists,
+}
+
+impl std::fmt::Display for GatewayRouteCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "GatewayRouteCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for GatewayRouteCreateError {}
+
+/// The failures that can occur while the [`Gateway`] pumps samples with [`Gateway::pump()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GatewayPumpError {
+    /// A sample could not be received from a forwarded service.
+    ReceiveFailed,
+    /// A sample could not be loaned or sent to an injected service.
+    DeliveryFailed,
+    /// The [`Transport`] failed and could not be recovered with [`Transport::reconnect()`].
+    TransportFailure,
+}
+
+impl std::fmt::Display for GatewayPumpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "GatewayPumpError::{:?}", self)
+    }
+}
+
+impl std::error::Error for GatewayPumpError {}
+
+type OutboundRoute<'a> = Box<dyn FnMut(&mut Vec<Message>) -> Result<(), GatewayPumpError> + 'a>;
+type InboundRoute<'a> = Box<dyn FnMut(&Message) -> Result<(), GatewayPumpError> + 'a>;
+
+struct Route<R> {
+    service_name: String,
+    type_descriptor: TypeDescriptor,
+    pump: R,
+}
+
+/// Bridges iceoryx2 services over a [`Transport`]. Samples of forwarded services are received
+/// and sent over the [`Transport`] and [`Message`]s that arrive over the [`Transport`] are
+/// published into the injected services.
+///
+/// ```
+/// use iceoryx2::prelude::*;
+/// use iceoryx2_gateway::*;
+///
+/// #[derive(Debug, Default)]
+/// struct StdoutTransport;
+///
+/// impl Transport for StdoutTransport {
+///     fn send(&mut self, message: &Message) -> Result<(), TransportError> {
+///         println!("{}: {:?}", message.service_name, message.payload);
+///         Ok(())
+///     }
+///
+///     fn try_receive(&mut self) -> Result<Option<Message>, TransportError> {
+///         Ok(None)
+///     }
+///
+///     fn reconnect(&mut self) -> Result<(), TransportError> {
+///         Ok(())
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+/// let mut gateway = Gateway::new(&node, StdoutTransport);
+/// gateway.forward::<u64>(&"My/Funk/ServiceName".try_into()?)?;
+///
+/// gateway.pump()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Gateway<'a, Service: service::Service, T: Transport> {
+    node: &'a Node<Service>,
+    transport: T,
+    outbound_routes: Vec<Route<OutboundRoute<'a>>>,
+    inbound_routes: Vec<Route<InboundRoute<'a>>>,
+    outbound_messages: Vec<Message>,
+    codecs: Vec<Box<dyn Codec + 'a>>,
+    compression_threshold: usize,
+    negotiated_codec: Option<usize>,
+    codecs_announced: bool,
+}
+
+impl<'a, Service: service::Service, T: Transport> Debug for Gateway<'a, Service, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Gateway<{}> {{ transport: {:?}, forwarded_services: {:?}, injected_services: {:?}, codecs: {:?}, negotiated_codec: {:?} }}",
+            core::any::type_name::<Service>(),
+            self.transport,
+            self.outbound_routes
+                .iter()
+                .map(|r| &r.service_name)
+                .collect::<Vec<_>>(),
+            self.inbound_routes
+                .iter()
+                .map(|r| &r.service_name)
+                .collect::<Vec<_>>(),
+            self.codecs,
+            self.negotiated_codec()
+        )
+    }
+}
+
+impl<'a, Service: service::Service, T: Transport> Gateway<'a, Service, T> {
+    /// Creates a new [`Gateway`] without any route. All ports of the routes are created with
+    /// the provided [`Node`].
+    pub fn new(node: &'a Node<Service>, transport: T) -> Self {
+        Self {
+            node,
+            transport,
+            outbound_routes: vec![],
+            inbound_routes: vec![],
+            outbound_messages: vec![],
+            codecs: vec![],
+            compression_threshold: DEFAULT_COMPRESSION_THRESHOLD,
+            negotiated_codec: None,
+            codecs_announced: false,
+        }
+    }
+
+    /// Adds a [`Codec`] that compresses the payload of outgoing [`Message`]s and decompresses
+    /// incoming [`Message`]s. The [`Codec`]s are preferred in the order they were added. The
+    /// [`Gateway`] announces its [`Codec`]s to the remote side and uses the most preferred
+    /// [`Codec`] the remote side supports as well. As long as the negotiation is not
+    /// completed, or no common [`Codec`] exists, the payload is sent uncompressed.
+    pub fn add_codec<C: Codec + 'a>(&mut self, codec: C) {
+        self.codecs.push(Box::new(codec));
+        self.codecs_announced = false;
+    }
+
+    /// Defines the minimum payload size in bytes from which on the payload is compressed.
+    /// Small payloads are sent uncompressed since the compression would only add latency.
+    pub fn set_compression_threshold(&mut self, bytes: usize) {
+        self.compression_threshold = bytes;
+    }
+
+    /// Returns the minimum payload size in bytes from which on the payload is compressed.
+    pub fn compression_threshold(&self) -> usize {
+        self.compression_threshold
+    }
+
+    /// Returns the [`Codec::name()`] of the [`Codec`] that was negotiated with the remote side
+    /// to compress outgoing [`Message`]s. Returns [`None`] when the negotiation is not completed
+    /// or no common [`Codec`] exists.
+    pub fn negotiated_codec(&self) -> Option<&str> {
+        self.negotiated_codec.map(|index| self.codecs[index].name())
+    }
+
+    /// Returns a reference to the underlying [`Transport`].
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Returns a mutable reference to the underlying [`Transport`].
+    pub fn transport_mut(&mut self) -> &mut T {
+        &mut self.transport
+    }
+
+    /// Sends every sample that is published on the service over the [`Transport`].
+    pub fn forward<PayloadType: Debug + PlainOldData + 'a>(
+        &mut self,
+        service_name: &ServiceName,
+    ) -> Result<(), GatewayRouteCreateError> {
+        let msg = "Unable to forward service";
+        if self
+            .outbound_routes
+            .iter()
+            .any(|r| r.service_name == service_name.as_str())
+        {
+            fail!(from self, with GatewayRouteCreateError::RouteAlreadyExists,
+                "{} {} since it is already forwarded.", msg, service_name);
+        }
+
+        let service = fail!(from self, when self.node
+                .service_builder(service_name.clone())
+                .publish_subscribe::<PayloadType>()
+                .open_or_create(),
+            with GatewayRouteCreateError::UnableToOpenService,
+            "{} {} since the service could not be opened.", msg, service_name);
+        let subscriber = fail!(from self, when service.subscriber_builder().create(),
+            with GatewayRouteCreateError::UnableToCreatePort,
+            "{} {} since the subscriber could not be created.", msg, service_name);
+
+        let name = service_name.as_str().to_string();
+        let type_descriptor = TypeDescriptor::from(service.static_config().type_details());
+        let route_name = name.clone();
+        let route_type_descriptor = type_descriptor.clone();
+        self.outbound_routes.push(Route {
+            service_name: name,
+            type_descriptor,
+            pump: Box::new(move |messages| {
+                let _service = &service;
+                loop {
+                    match subscriber.receive() {
+                        Ok(Some(sample)) => messages.push(Message {
+                            service_name: route_name.clone(),
+                            type_descriptor: route_type_descriptor.clone(),
+                            payload: sample.payload_as_bytes().to_vec(),
+                            encoding: None,
+                        }),
+                        Ok(None) => return Ok(()),
+                        Err(e) => {
+                            fail!(from "Gateway::forward()", with GatewayPumpError::ReceiveFailed,
+                                "Unable to receive sample from service {} ({:?}).", route_name, e);
+                        }
+                    }
+                }
+            }),
+        });
+
+        Ok(())
+    }
+
+    /// Publishes the payload of every [`Message`] that arrives over the [`Transport`] for the
+    /// service. [`Message`]s whose [`TypeDescriptor`] does not match the payload type of the
+    /// service are discarded.
+    ///
+    /// # Safety
+    ///
+    ///  * every bit pattern of the size of `PayloadType` must be a valid `PayloadType`, since
+    ///    the payload is copied bytewise from the [`Message`]
+    ///
+    pub unsafe fn inject<PayloadType: Debug + Copy + PlainOldData + 'a>(
+        &mut self,
+        service_name: &ServiceName,
+    ) -> Result<(), GatewayRouteCreateError> {
+        let msg = "Unable to inject into service";
+        if self
+            .inbound_routes
+            .iter()
+            .any(|r| r.service_name == service_name.as_str())
+        {
+            fail!(from self, with GatewayRouteCreateError::RouteAlreadyExists,
+                "{} {} since it is already injected.", msg, service_name);
+        }
+
+        let service = fail!(from self, when self.node
+                .service_builder(service_name.clone())
+                .publish_subscribe::<PayloadType>()
+                .open_or_create(),
+            with GatewayRouteCreateError::UnableToOpenService,
+            "{} {} since the service could not be opened.", msg, service_name);
+        let publisher = fail!(from self, when service.publisher_builder().create(),
+            with GatewayRouteCreateError::UnableToCreatePort,
+            "{} {} since the publisher could not be created.", msg, service_name);
+
+        let name = service_name.as_str().to_string();
+        let type_descriptor = TypeDescriptor::from(service.static_config().type_details());
+        let route_name = name.clone();
+        self.inbound_routes.push(Route {
+            service_name: name,
+            type_descriptor,
+            pump: Box::new(move |message| {
+                let _service = &service;
+                let mut sample = fail!(from "Gateway::inject()", when publisher.loan_uninit(),
+                    with GatewayPumpError::DeliveryFailed,
+                    "Unable to loan sample for service {}.", route_name);
+                sample
+                    .payload_as_bytes_mut()
+                    .copy_from_slice(&message.payload);
+                let sample = sample.assume_init();
+                fail!(from "Gateway::inject()", when sample.send(),
+                    with GatewayPumpError::DeliveryFailed,
+                    "Unable to send sample to service {}.", route_name);
+                Ok(())
+            }),
+        });
+
+        Ok(())
+    }
+
+    /// Sends every sample that is published on the service over the [`Transport`]. In contrast
+    /// to [`Gateway::forward()`] the payload type is only known at runtime, for instance from a
+    /// mapping file, and is described by the [`TypeDescriptor`].
+    ///
+    /// # Safety
+    ///
+    ///  * the [`TypeDescriptor`] must describe the payload type of the service
+    ///
+    pub unsafe fn forward_with_type(
+        &mut self,
+        service_name: &ServiceName,
+        type_descriptor: &TypeDescriptor,
+    ) -> Result<(), GatewayRouteCreateError> {
+        let msg = "Unable to forward service";
+        if self
+            .outbound_routes
+            .iter()
+            .any(|r| r.service_name == service_name.as_str())
+        {
+            fail!(from self, with GatewayRouteCreateError::RouteAlreadyExists,
+                "{} {} since it is already forwarded.", msg, service_name);
+        }
+
+        let service = fail!(from self, when self.node
+                .service_builder(service_name.clone())
+                .publish_subscribe::<[u8]>()
+                .payload_type_name(&type_descriptor.type_name)
+                .__internal_set_payload_layout(
+                    TypeVariant::FixedSize,
+                    type_descriptor.size,
+                    type_descriptor.alignment,
+                )
+                .open_or_create(),
+            with GatewayRouteCreateError::UnableToOpenService,
+            "{} {} since the service could not be opened.", msg, service_name);
+        let subscriber = fail!(from self, when service.subscriber_builder().create(),
+            with GatewayRouteCreateError::UnableToCreatePort,
+            "{} {} since the subscriber could not be created.", msg, service_name);
+
+        let name = service_name.as_str().to_string();
+        let type_descriptor = TypeDescriptor::from(service.static_config().type_details());
+        let route_name = name.clone();
+        let route_type_descriptor = type_descriptor.clone();
+        self.outbound_routes.push(Route {
+            service_name: name,
+            type_descriptor,
+            pump: Box::new(move |messages| {
+                let _service = &service;
+                loop {
+                    match subscriber.receive() {
+                        Ok(Some(sample)) => messages.push(Message {
+                            service_name: route_name.clone(),
+                            type_descriptor: route_type_descriptor.clone(),
+                            payload: sample.payload_as_bytes().to_vec(),
+                            encoding: None,
+                        }),
+                        Ok(None) => return Ok(()),
+                        Err(e) => {
+                            fail!(from "Gateway::forward_with_type()", with GatewayPumpError::ReceiveFailed,
+                                "Unable to receive sample from service {} ({:?}).", route_name, e);
+                        }
+                    }
+                }
+            }),
+        });
+
+        Ok(())
+    }
+
+    /// Publishes the payload of every [`Message`] that arrives over the [`Transport`] for the
+    /// service. In contrast to [`Gateway::inject()`] the payload type is only known at runtime,
+    /// for instance from a mapping file, and is described by the [`TypeDescriptor`].
+    /// [`Message`]s whose [`TypeDescriptor`] does not match are discarded.
+    ///
+    /// # Safety
+    ///
+    ///  * the [`TypeDescriptor`] must describe the payload type of the service
+    ///  * every bit pattern of the size of the payload type must be a valid payload, since
+    ///    the payload is copied bytewise from the [`Message`]
+    ///
+    pub unsafe fn inject_with_type(
+        &mut self,
+        service_name: &ServiceName,
+        type_descriptor: &TypeDescriptor,
+    ) -> Result<(), GatewayRouteCreateError> {
+        let msg = "Unable to inject into service";
+        if self
+            .inbound_routes
+            .iter()
+            .any(|r| r.service_name == service_name.as_str())
+        {
+            fail!(from self, with GatewayRouteCreateError::RouteAlreadyExists,
+                "{} {} since it is already injected.", msg, service_name);
+        }
+
+        let service = fail!(from self, when self.node
+                .service_builder(service_name.clone())
+                .publish_subscribe::<[u8]>()
+                .payload_type_name(&type_descriptor.type_name)
+                .__internal_set_payload_layout(
+                    TypeVariant::FixedSize,
+                    type_descriptor.size,
+                    type_descriptor.alignment,
+                )
+                .open_or_create(),
+            with GatewayRouteCreateError::UnableToOpenService,
+            "{} {} since the service could not be opened.", msg, service_name);
+        let publisher = fail!(from self, when service.publisher_builder().max_slice_len(1).create(),
+            with GatewayRouteCreateError::UnableToCreatePort,
+            "{} {} since the publisher could not be created.", msg, service_name);
+
+        let name = service_name.as_str().to_string();
+        let type_descriptor = TypeDescriptor::from(service.static_config().type_details());
+        let route_name = name.clone();
+        self.inbound_routes.push(Route {
+            service_name: name,
+            type_descriptor,
+            pump: Box::new(move |message| {
+                let _service = &service;
+                let mut sample = fail!(from "Gateway::inject_with_type()", when publisher.loan_slice_uninit(1),
+                    with GatewayPumpError::DeliveryFailed,
+                    "Unable to loan sample for service {}.", route_name);
+                // the payload is stored as a single slice element of the size of the payload type
+                let payload = sample.payload_mut().as_mut_ptr() as *mut u8;
+                core::ptr::copy_nonoverlapping(
+                    message.payload.as_ptr(),
+                    payload,
+                    message.payload.len(),
+                );
+                let sample = sample.assume_init();
+                fail!(from "Gateway::inject_with_type()", when sample.send(),
+                    with GatewayPumpError::DeliveryFailed,
+                    "Unable to send sample to service {}.", route_name);
+                Ok(())
+            }),
+        });
+
+        Ok(())
+    }
+
+    /// Sends all samples of the forwarded services over the [`Transport`] and injects all
+    /// [`Message`]s that arrived over the [`Transport`] into the injected services. When the
+    /// [`Transport`] lost the connection, [`Transport::reconnect()`] is called once.
+    pub fn pump(&mut self) -> Result<(), GatewayPumpError> {
+        if !self.codecs_announced && !self.codecs.is_empty() {
+            self.announce_codecs(NEGOTIATION_REQUEST)?;
+        }
+
+        let mut messages = core::mem::take(&mut self.outbound_messages);
+        for route in &mut self.outbound_routes {
+            (route.pump)(&mut messages)?;
+        }
+
+        for message in messages.drain(..) {
+            let message = self.compress(message);
+            self.send(&message)?;
+        }
+        self.outbound_messages = messages;
+
+        while let Some(message) = self.try_receive()? {
+            if message.service_name == NEGOTIATION_SERVICE_NAME {
+                self.negotiate(&message)?;
+            } else {
+                self.deliver(&message)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Calls [`Gateway::pump()`] every `cycle_time` until a termination request or an interrupt
+    /// signal was received.
+    pub fn run(&mut self, cycle_time: Duration) -> Result<(), GatewayPumpError> {
+        loop {
+            self.pump()?;
+
+            match self.node.wait(cycle_time) {
+                NodeEvent::Tick => (),
+                NodeEvent::TerminationRequest | NodeEvent::InterruptSignal => return Ok(()),
+            }
+        }
+    }
+
+    fn reconnect(&mut self) -> Result<(), GatewayPumpError> {
+        fail!(from self, when self.transport.reconnect(),
+            with GatewayPumpError::TransportFailure,
+            "Unable to reestablish the connection of the transport.");
+
+        // the remote side may have changed, therefore the codecs are negotiated again
+        self.negotiated_codec = None;
+        self.codecs_announced = false;
+        Ok(())
+    }
+
+    fn announce_codecs(&mut self, kind: u8) -> Result<(), GatewayPumpError> {
+        let mut payload = vec![kind];
+        payload.extend_from_slice(
+            self.codecs
+                .iter()
+                .map(|c| c.name())
+                .collect::<Vec<_>>()
+                .join("\n")
+                .as_bytes(),
+        );
+
+        let message = Message {
+            service_name: NEGOTIATION_SERVICE_NAME.to_string(),
+            type_descriptor: TypeDescriptor {
+                type_name: String::new(),
+                size: 0,
+                alignment: 0,
+            },
+            payload,
+            encoding: None,
+        };
+
+        // a reconnect within send resets the announcement, it must be set afterwards
+        self.send(&message)?;
+        self.codecs_announced = true;
+        Ok(())
+    }
+
+    fn negotiate(&mut self, message: &Message) -> Result<(), GatewayPumpError> {
+        let (kind, names) = match message.payload.split_first() {
+            Some((kind, names)) => (*kind, String::from_utf8_lossy(names)),
+            None => {
+                warn!(from self, "Discard malformed codec negotiation message.");
+                return Ok(());
+            }
+        };
+
+        let remote_codecs: Vec<&str> = names.split('\n').collect();
+        self.negotiated_codec = self
+            .codecs
+            .iter()
+            .position(|c| remote_codecs.contains(&c.name()));
+
+        if kind == NEGOTIATION_REQUEST {
+            self.announce_codecs(NEGOTIATION_RESPONSE)?;
+        }
+
+        Ok(())
+    }
+
+    fn compress(&self, mut message: Message) -> Message {
+        let codec = match self.negotiated_codec {
+            Some(index) if message.payload.len() >= self.compression_threshold => {
+                &self.codecs[index]
+            }
+            _ => return message,
+        };
+
+        let mut payload = vec![];
+        match codec.compress(&message.payload, &mut payload) {
+            // incompressible payloads are sent as they are
+            Ok(()) if payload.len() < message.payload.len() => {
+                message.payload = payload;
+                message.encoding = Some(codec.name().to_string());
+            }
+            Ok(()) => (),
+            Err(e) => {
+                warn!(from self,
+                    "Send message of service {} uncompressed since the codec {} failed ({:?}).",
+                    message.service_name, codec.name(), e);
+            }
+        }
+
+        message
+    }
+
+    fn send(&mut self, message: &Message) -> Result<(), GatewayPumpError> {
+        let msg = "Unable to send message";
+        let result = match self.transport.send(message) {
+            Err(TransportError::ConnectionLost) => {
+                self.reconnect()?;
+                self.transport.send(message)
+            }
+            result => result,
+        };
+
+        if let Err(e) = result {
+            fail!(from self, with GatewayPumpError::TransportFailure,
+                "{} of service {} over the transport ({:?}).", msg, message.service_name, e);
+        }
+
+        Ok(())
+    }
+
+    fn try_receive(&mut self) -> Result<Option<Message>, GatewayPumpError> {
+        let msg = "Unable to receive message";
+        let result = match self.transport.try_receive() {
+            Err(TransportError::ConnectionLost) => {
+                self.reconnect()?;
+                self.transport.try_receive()
+            }
+            result => result,
+        };
+
+        match result {
+            Ok(message) => Ok(message),
+            Err(e) => {
+                fail!(from self, with GatewayPumpError::TransportFailure,
+                    "{} from the transport ({:?}).", msg, e);
+            }
+        }
+    }
+
+    fn deliver(&mut self, message: &Message) -> Result<(), GatewayPumpError> {
+        let msg = "Discard message";
+        let decompressed;
+        let message = match &message.encoding {
+            None => message,
+            Some(encoding) => {
+                let codec = match self.codecs.iter().find(|c| c.name() == encoding) {
+                    Some(codec) => codec,
+                    None => {
+                        warn!(from "Gateway::deliver()",
+                            "{} for service {} since the codec {} is not supported.",
+                            msg, message.service_name, encoding);
+                        return Ok(());
+                    }
+                };
+
+                let mut payload = vec![];
+                if let Err(e) = codec.decompress(&message.payload, &mut payload) {
+                    warn!(from "Gateway::deliver()",
+                        "{} for service {} since the payload could not be decompressed with the codec {} ({:?}).",
+                        msg, message.service_name, encoding, e);
+                    return Ok(());
+                }
+
+                decompressed = Message {
+                    service_name: message.service_name.clone(),
+                    type_descriptor: message.type_descriptor.clone(),
+                    payload,
+                    encoding: None,
+                };
+                &decompressed
+            }
+        };
+
+        let route = match self
+            .inbound_routes
+            .iter_mut()
+            .find(|r| r.service_name == message.service_name)
+        {
+            Some(route) => route,
+            None => {
+                warn!(from "Gateway::deliver()",
+                    "{} since the service {} is not injected.", msg, message.service_name);
+                return Ok(());
+            }
+        };
+
+        if route.type_descriptor != message.type_descriptor
+            || route.type_descriptor.size != message.payload.len()
+        {
+            warn!(from "Gateway::deliver()",
+                "{} for service {} since its type {:?} is not compatible to {:?}.",
+                msg, message.service_name, message.type_descriptor, route.type_descriptor);
+            return Ok(());
+        }
+
+        (route.pump)(message)
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # iceoryx2-gateway
+//!
+//! A framework to bridge iceoryx2 services over an arbitrary medium, like a proprietary bus,
+//! CAN or a serial line. The [`Gateway`] takes care of opening the services, describing the
+//! payload types, pumping the samples and reconnecting the medium. A custom bridge only has to
+//! implement the [`Transport`] plugin trait that moves [`Message`]s.
+//!
+//! * [`Gateway::forward()`] - sends all samples of a service over the [`Transport`]
+//! * [`Gateway::inject()`] - publishes all [`Message`]s of a service that arrive over the
+//!   [`Transport`]
+//! * [`Gateway::forward_with_type()`], [`Gateway::inject_with_type()`] - the same for payload
+//!   types that are only known at runtime, for instance from a mapping file
+//! * [`Gateway::pump()`] - transfers all pending samples and [`Message`]s once
+//! * [`Gateway::run()`] - pumps cyclically until a termination request was received
+//! * [`Gateway::add_codec()`] - compresses large payloads on the [`Transport`] with a
+//!   [`Codec`](codec::Codec) that both sides support
+
+pub mod codec;
+mod gateway;
+pub mod transport;
+
+pub use gateway::{
+    Gateway, GatewayPumpError, GatewayRouteCreateError, DEFAULT_COMPRESSION_THRESHOLD,
+};
+pub use transport::{Message, Transport, TransportError, TypeDescriptor};
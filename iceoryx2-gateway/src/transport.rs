@@ -0,0 +1,89 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`Transport`] plugin trait that connects a [`Gateway`](crate::Gateway) with an
+//! arbitrary medium like a proprietary bus, CAN or a serial line.
+
+use core::fmt::Debug;
+
+use iceoryx2::service::static_config::type_details::TypeDetails;
+
+/// Describes the payload type of a [`Message`] so that the receiving side can verify that it
+/// injects the payload into a service with the same type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypeDescriptor {
+    /// The name of the payload type, see
+    /// [`TypeDetails::payload_type_name`]
+    pub type_name: String,
+    /// The size of the payload type in bytes
+    pub size: usize,
+    /// The alignment of the payload type
+    pub alignment: usize,
+}
+
+impl From<&TypeDetails> for TypeDescriptor {
+    fn from(value: &TypeDetails) -> Self {
+        Self {
+            type_name: value.payload_type_name.clone(),
+            size: value.payload_size,
+            alignment: value.payload_alignment,
+        }
+    }
+}
+
+/// A single sample that is transferred over the [`Transport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    /// The name of the service the sample belongs to
+    pub service_name: String,
+    /// The type of the payload
+    pub type_descriptor: TypeDescriptor,
+    /// The raw bytes of the payload
+    pub payload: Vec<u8>,
+    /// The [`Codec::name()`](crate::codec::Codec::name) of the [`Codec`](crate::codec::Codec)
+    /// that compressed the payload or [`None`] when the payload is uncompressed
+    pub encoding: Option<String>,
+}
+
+/// The failures a [`Transport`] can report to the [`Gateway`](crate::Gateway).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportError {
+    /// The connection to the remote side was lost. The [`Gateway`](crate::Gateway) tries to
+    /// reestablish it with [`Transport::reconnect()`].
+    ConnectionLost,
+    /// An unrecoverable failure occurred.
+    InternalError,
+}
+
+impl std::fmt::Display for TransportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "TransportError::{:?}", self)
+    }
+}
+
+impl std::error::Error for TransportError {}
+
+/// The plugin trait that must be implemented to bridge iceoryx2 services over a custom medium.
+/// The [`Gateway`](crate::Gateway) takes care of the service handling, the type verification
+/// and the sample pump loop, the [`Transport`] only has to move [`Message`]s.
+pub trait Transport: Debug {
+    /// Sends a [`Message`] to the remote side.
+    fn send(&mut self, message: &Message) -> Result<(), TransportError>;
+
+    /// Receives the next [`Message`] from the remote side. Returns [`None`] when no
+    /// [`Message`] is available. Must not block.
+    fn try_receive(&mut self) -> Result<Option<Message>, TransportError>;
+
+    /// Is called after the [`Transport`] reported [`TransportError::ConnectionLost`] and shall
+    /// reestablish the connection.
+    fn reconnect(&mut self) -> Result<(), TransportError>;
+}
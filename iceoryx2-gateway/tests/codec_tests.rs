@@ -0,0 +1,211 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(any(feature = "lz4", feature = "zstd"))]
+
+use iceoryx2_bb_testing::assert_that;
+#[cfg(feature = "lz4")]
+use iceoryx2_gateway::codec::Lz4Codec;
+#[cfg(feature = "zstd")]
+use iceoryx2_gateway::codec::ZstdCodec;
+use iceoryx2_gateway::codec::{Codec, CodecError};
+
+fn roundtrip<C: Codec>(sut: &C, input: &[u8]) -> Vec<u8> {
+    let mut compressed = vec![];
+    assert_that!(sut.compress(input, &mut compressed), is_ok);
+
+    let mut decompressed = vec![];
+    assert_that!(sut.decompress(&compressed, &mut decompressed), is_ok);
+    assert_that!(decompressed, eq input);
+    compressed
+}
+
+#[cfg(feature = "lz4")]
+#[test]
+fn lz4_codec_roundtrip_of_small_inputs_works() {
+    let sut = Lz4Codec::default();
+    roundtrip(&sut, &[]);
+    roundtrip(&sut, &[42]);
+    roundtrip(&sut, b"hello world");
+}
+
+#[cfg(feature = "lz4")]
+#[test]
+fn lz4_codec_compresses_repeating_content() {
+    let input = vec![7u8; 64 * 1024];
+    let compressed = roundtrip(&Lz4Codec::default(), &input);
+
+    assert_that!(compressed.len(), lt input.len() / 100);
+}
+
+#[cfg(feature = "lz4")]
+#[test]
+fn lz4_codec_roundtrip_of_mixed_content_works() {
+    let mut state: u32 = 12345;
+    let mut input = vec![];
+    for i in 0..20000u32 {
+        state = state.wrapping_mul(1103515245).wrapping_add(12345);
+        // alternate between noise and repeating patterns with long and overlapping matches
+        match (i / 1000) % 3 {
+            0 => input.push((state >> 16) as u8),
+            1 => input.push((i % 3) as u8),
+            _ => input.extend_from_slice(b"abcdefgh"),
+        }
+    }
+
+    roundtrip(&Lz4Codec::default(), &input);
+}
+
+#[cfg(feature = "lz4")]
+#[test]
+fn lz4_codec_decompression_of_corrupted_input_fails() {
+    let sut = Lz4Codec::default();
+    let mut compressed = vec![];
+    assert_that!(sut.compress(&vec![3u8; 1024], &mut compressed), is_ok);
+
+    let mut output = vec![];
+    assert_that!(sut.decompress(&compressed[..compressed.len() - 2], &mut output), eq Err(CodecError::CorruptedInput));
+    output.clear();
+    assert_that!(sut.decompress(&[1, 2], &mut output), eq Err(CodecError::CorruptedInput));
+
+    let mut wrong_size = compressed.clone();
+    wrong_size[0] = wrong_size[0].wrapping_add(1);
+    output.clear();
+    assert_that!(sut.decompress(&wrong_size, &mut output), eq Err(CodecError::CorruptedInput));
+}
+
+#[cfg(feature = "lz4")]
+#[test]
+fn lz4_codec_rejects_payloads_that_exceed_max_message_size() {
+    const MAX_MESSAGE_SIZE: usize = 1024;
+    let sut = Lz4Codec::new(MAX_MESSAGE_SIZE);
+    assert_that!(sut.max_message_size(), eq MAX_MESSAGE_SIZE);
+
+    let mut compressed = vec![];
+    assert_that!(
+        sut.compress(&vec![5u8; MAX_MESSAGE_SIZE], &mut compressed),
+        is_ok
+    );
+    let mut output = vec![];
+    assert_that!(sut.decompress(&compressed, &mut output), is_ok);
+
+    compressed.clear();
+    assert_that!(
+        sut.compress(&vec![5u8; MAX_MESSAGE_SIZE + 1], &mut compressed),
+        is_ok
+    );
+    let mut output = vec![];
+    assert_that!(sut.decompress(&compressed, &mut output), eq Err(CodecError::ExceedsMaxMessageSize));
+
+    // the size prefix is not trusted and nothing is reserved for it
+    let forged_size = [0xff, 0xff, 0xff, 0xff, 0x00];
+    assert_that!(sut.decompress(&forged_size, &mut output), eq Err(CodecError::ExceedsMaxMessageSize));
+    assert_that!(output.capacity(), eq 0);
+}
+
+#[cfg(feature = "lz4")]
+#[test]
+fn lz4_codec_failed_decompression_leaves_output_unchanged() {
+    let sut = Lz4Codec::default();
+    let mut compressed = vec![];
+    assert_that!(sut.compress(&vec![3u8; 1024], &mut compressed), is_ok);
+
+    let mut output = vec![1, 2, 3];
+    assert_that!(sut.decompress(&compressed[..compressed.len() - 2], &mut output), eq Err(CodecError::CorruptedInput));
+    assert_that!(output, eq vec![1, 2, 3]);
+
+    let mut wrong_size = compressed.clone();
+    wrong_size[0] = wrong_size[0].wrapping_add(1);
+    assert_that!(sut.decompress(&wrong_size, &mut output), eq Err(CodecError::CorruptedInput));
+    assert_that!(output, eq vec![1, 2, 3]);
+
+    assert_that!(sut.decompress(&compressed, &mut output), is_ok);
+    assert_that!(output.len(), eq 3 + 1024);
+    assert_that!(output[..3], eq [1, 2, 3]);
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn zstd_codec_roundtrip_of_small_inputs_works() {
+    let sut = ZstdCodec::default();
+    roundtrip(&sut, &[]);
+    roundtrip(&sut, &[42]);
+    roundtrip(&sut, b"hello world");
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn zstd_codec_compresses_repeating_content() {
+    let input = vec![7u8; 64 * 1024];
+    let compressed = roundtrip(&ZstdCodec::default(), &input);
+
+    assert_that!(compressed.len(), lt input.len() / 100);
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn zstd_codec_with_custom_compression_level_is_compatible_with_default_level() {
+    let input = b"abcdefgh".repeat(1000);
+    let sut = ZstdCodec::default().compression_level(19);
+    let mut compressed = vec![];
+    assert_that!(sut.compress(&input, &mut compressed), is_ok);
+
+    let mut decompressed = vec![];
+    assert_that!(
+        ZstdCodec::default().decompress(&compressed, &mut decompressed),
+        is_ok
+    );
+    assert_that!(decompressed, eq input);
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn zstd_codec_failed_decompression_leaves_output_unchanged() {
+    let sut = ZstdCodec::default();
+    let mut compressed = vec![];
+    assert_that!(
+        sut.compress(&b"abcdefgh".repeat(1000), &mut compressed),
+        is_ok
+    );
+
+    let mut output = vec![1, 2, 3];
+    assert_that!(sut.decompress(&compressed[..compressed.len() - 2], &mut output), eq Err(CodecError::CorruptedInput));
+    assert_that!(output, eq vec![1, 2, 3]);
+
+    assert_that!(sut.decompress(&[1, 2], &mut output), eq Err(CodecError::CorruptedInput));
+    assert_that!(output, eq vec![1, 2, 3]);
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn zstd_codec_rejects_payloads_that_exceed_max_message_size() {
+    const MAX_MESSAGE_SIZE: usize = 1024;
+    let sut = ZstdCodec::new(MAX_MESSAGE_SIZE);
+    assert_that!(sut.max_message_size(), eq MAX_MESSAGE_SIZE);
+
+    let mut compressed = vec![];
+    assert_that!(
+        sut.compress(&vec![5u8; MAX_MESSAGE_SIZE], &mut compressed),
+        is_ok
+    );
+    let mut output = vec![];
+    assert_that!(sut.decompress(&compressed, &mut output), is_ok);
+
+    compressed.clear();
+    assert_that!(
+        sut.compress(&vec![5u8; MAX_MESSAGE_SIZE + 1], &mut compressed),
+        is_ok
+    );
+    let mut output = vec![1, 2, 3];
+    assert_that!(sut.decompress(&compressed, &mut output), eq Err(CodecError::ExceedsMaxMessageSize));
+    assert_that!(output, eq vec![1, 2, 3]);
+}
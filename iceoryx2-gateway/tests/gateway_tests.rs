@@ -0,0 +1,401 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod gateway {
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+
+    use iceoryx2::config::Config;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Service;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_gateway::*;
+
+    type Channel = Rc<RefCell<VecDeque<Message>>>;
+
+    #[derive(Debug, Default)]
+    pub(crate) struct LoopbackTransport {
+        pub(crate) outbox: Channel,
+        pub(crate) inbox: Channel,
+        pub(crate) lose_connection_on_send: bool,
+        pub(crate) number_of_reconnects: usize,
+    }
+
+    impl LoopbackTransport {
+        pub(crate) fn pair() -> (Self, Self) {
+            let a_to_b = Channel::default();
+            let b_to_a = Channel::default();
+            (
+                Self {
+                    outbox: a_to_b.clone(),
+                    inbox: b_to_a.clone(),
+                    ..Default::default()
+                },
+                Self {
+                    outbox: b_to_a,
+                    inbox: a_to_b,
+                    ..Default::default()
+                },
+            )
+        }
+    }
+
+    impl Transport for LoopbackTransport {
+        fn send(&mut self, message: &Message) -> Result<(), TransportError> {
+            if self.lose_connection_on_send {
+                return Err(TransportError::ConnectionLost);
+            }
+            self.outbox.borrow_mut().push_back(message.clone());
+            Ok(())
+        }
+
+        fn try_receive(&mut self) -> Result<Option<Message>, TransportError> {
+            Ok(self.inbox.borrow_mut().pop_front())
+        }
+
+        fn reconnect(&mut self) -> Result<(), TransportError> {
+            self.number_of_reconnects += 1;
+            self.lose_connection_on_send = false;
+            Ok(())
+        }
+    }
+
+    pub(crate) fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "gateway_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    // every host has its own prefix so that the gateways bridge two separate iceoryx2 worlds
+    pub(crate) fn generate_host_config() -> Config {
+        let mut config = Config::default();
+        config.global.prefix =
+            FileName::new(format!("gw_{}_", UniqueSystemId::new().unwrap().value()).as_bytes())
+                .unwrap();
+        config
+    }
+
+    #[test]
+    fn forwarded_samples_are_injected_on_remote_side<S: Service>() {
+        let service_name = generate_name();
+        let node_a = NodeBuilder::new()
+            .config(&generate_host_config())
+            .create::<S>()
+            .unwrap();
+        let node_b = NodeBuilder::new()
+            .config(&generate_host_config())
+            .create::<S>()
+            .unwrap();
+        let (transport_a, transport_b) = LoopbackTransport::pair();
+
+        let mut gateway_a = Gateway::new(&node_a, transport_a);
+        assert_that!(gateway_a.forward::<u64>(&service_name), is_ok);
+        let mut gateway_b = Gateway::new(&node_b, transport_b);
+        assert_that!(unsafe { gateway_b.inject::<u64>(&service_name) }, is_ok);
+
+        let service_a = node_a
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let publisher = service_a.publisher_builder().create().unwrap();
+        let service_b = node_b
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let subscriber = service_b.subscriber_builder().create().unwrap();
+
+        for value in 0..3 {
+            assert_that!(publisher.send_copy(value * 11), is_ok);
+            assert_that!(gateway_a.pump(), is_ok);
+            assert_that!(gateway_b.pump(), is_ok);
+
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq value * 11);
+            assert_that!(subscriber.receive().unwrap(), is_none);
+        }
+    }
+
+    #[test]
+    fn runtime_typed_routes_bridge_services_with_compile_time_types<S: Service>() {
+        let service_name = generate_name();
+        let node_a = NodeBuilder::new()
+            .config(&generate_host_config())
+            .create::<S>()
+            .unwrap();
+        let node_b = NodeBuilder::new()
+            .config(&generate_host_config())
+            .create::<S>()
+            .unwrap();
+        let (transport_a, transport_b) = LoopbackTransport::pair();
+        let type_descriptor = TypeDescriptor {
+            type_name: core::any::type_name::<u64>().to_string(),
+            size: core::mem::size_of::<u64>(),
+            alignment: core::mem::align_of::<u64>(),
+        };
+
+        let mut gateway_a = Gateway::new(&node_a, transport_a);
+        assert_that!(
+            unsafe { gateway_a.forward_with_type(&service_name, &type_descriptor) },
+            is_ok
+        );
+        let mut gateway_b = Gateway::new(&node_b, transport_b);
+        assert_that!(
+            unsafe { gateway_b.inject_with_type(&service_name, &type_descriptor) },
+            is_ok
+        );
+
+        let service_a = node_a
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let publisher = service_a.publisher_builder().create().unwrap();
+        let service_b = node_b
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let subscriber = service_b.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(9182736), is_ok);
+        assert_that!(gateway_a.pump(), is_ok);
+        assert_that!(gateway_b.pump(), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 9182736);
+    }
+
+    #[test]
+    fn messages_with_incompatible_type_are_discarded<S: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&generate_host_config())
+            .create::<S>()
+            .unwrap();
+        let (mut remote, transport) = LoopbackTransport::pair();
+
+        let mut sut = Gateway::new(&node, transport);
+        assert_that!(unsafe { sut.inject::<u64>(&service_name) }, is_ok);
+        let service = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let message = Message {
+            service_name: service_name.as_str().to_string(),
+            type_descriptor: TypeDescriptor {
+                type_name: "u32".to_string(),
+                size: 4,
+                alignment: 4,
+            },
+            encoding: None,
+            payload: vec![0; 4],
+        };
+        assert_that!(remote.send(&message), is_ok);
+
+        assert_that!(sut.pump(), is_ok);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn forwarding_the_same_service_twice_fails<S: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&generate_host_config())
+            .create::<S>()
+            .unwrap();
+        let mut sut = Gateway::new(&node, LoopbackTransport::default());
+
+        assert_that!(sut.forward::<u64>(&service_name), is_ok);
+        assert_that!(sut.forward::<u64>(&service_name).err(), eq Some(GatewayRouteCreateError::RouteAlreadyExists));
+    }
+
+    #[test]
+    fn lost_connection_is_reestablished<S: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&generate_host_config())
+            .create::<S>()
+            .unwrap();
+        let (transport, remote) = LoopbackTransport::pair();
+
+        let mut sut = Gateway::new(&node, transport);
+        assert_that!(sut.forward::<u64>(&service_name), is_ok);
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        sut.transport_mut().lose_connection_on_send = true;
+        assert_that!(publisher.send_copy(1234), is_ok);
+        assert_that!(sut.pump(), is_ok);
+
+        assert_that!(sut.transport().number_of_reconnects, eq 1);
+        let message = remote.inbox.borrow_mut().pop_front().unwrap();
+        assert_that!(message.payload, eq 1234u64.to_ne_bytes().to_vec());
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
+
+#[cfg(feature = "lz4")]
+#[generic_tests::define]
+mod gateway_codec {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Service;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_gateway::codec::Lz4Codec;
+    use iceoryx2_gateway::*;
+
+    use super::gateway::{generate_host_config, generate_name, LoopbackTransport};
+
+    #[test]
+    fn large_payloads_are_compressed_with_negotiated_codec<S: Service>() {
+        type Payload = [u64; 512];
+        let service_name = generate_name();
+        let node_a = NodeBuilder::new()
+            .config(&generate_host_config())
+            .create::<S>()
+            .unwrap();
+        let node_b = NodeBuilder::new()
+            .config(&generate_host_config())
+            .create::<S>()
+            .unwrap();
+        let (transport_a, transport_b) = LoopbackTransport::pair();
+
+        let mut gateway_a = Gateway::new(&node_a, transport_a);
+        gateway_a.add_codec(Lz4Codec::default());
+        assert_that!(gateway_a.forward::<Payload>(&service_name), is_ok);
+        let mut gateway_b = Gateway::new(&node_b, transport_b);
+        gateway_b.add_codec(Lz4Codec::default());
+        assert_that!(unsafe { gateway_b.inject::<Payload>(&service_name) }, is_ok);
+
+        assert_that!(gateway_a.negotiated_codec(), is_none);
+        assert_that!(gateway_a.pump(), is_ok);
+        assert_that!(gateway_b.pump(), is_ok);
+        assert_that!(gateway_a.pump(), is_ok);
+        assert_that!(gateway_b.pump(), is_ok);
+        assert_that!(gateway_a.negotiated_codec(), eq Some(Lz4Codec::NAME));
+        assert_that!(gateway_b.negotiated_codec(), eq Some(Lz4Codec::NAME));
+
+        let service_a = node_a
+            .service_builder(service_name.clone())
+            .publish_subscribe::<Payload>()
+            .open()
+            .unwrap();
+        let publisher = service_a.publisher_builder().create().unwrap();
+        let service_b = node_b
+            .service_builder(service_name)
+            .publish_subscribe::<Payload>()
+            .open()
+            .unwrap();
+        let subscriber = service_b.subscriber_builder().create().unwrap();
+
+        let mut payload = [0u64; 512];
+        payload[7] = 1234;
+        assert_that!(publisher.send_copy(payload), is_ok);
+        assert_that!(gateway_a.pump(), is_ok);
+
+        {
+            let inbox = gateway_b.transport().inbox.borrow();
+            let message = inbox.front().unwrap();
+            assert_that!(message.encoding, eq Some(Lz4Codec::NAME.to_string()));
+            assert_that!(message.payload.len(), lt core::mem::size_of::<Payload>());
+        }
+
+        assert_that!(gateway_b.pump(), is_ok);
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq payload);
+    }
+
+    #[test]
+    fn no_codec_is_negotiated_when_remote_side_supports_none<S: Service>() {
+        let node_a = NodeBuilder::new()
+            .config(&generate_host_config())
+            .create::<S>()
+            .unwrap();
+        let node_b = NodeBuilder::new()
+            .config(&generate_host_config())
+            .create::<S>()
+            .unwrap();
+        let (transport_a, transport_b) = LoopbackTransport::pair();
+
+        let mut gateway_a = Gateway::new(&node_a, transport_a);
+        gateway_a.add_codec(Lz4Codec::default());
+        let mut gateway_b = Gateway::new(&node_b, transport_b);
+
+        assert_that!(gateway_a.pump(), is_ok);
+        assert_that!(gateway_b.pump(), is_ok);
+        assert_that!(gateway_a.pump(), is_ok);
+
+        assert_that!(gateway_a.negotiated_codec(), is_none);
+        assert_that!(gateway_b.negotiated_codec(), is_none);
+    }
+
+    #[test]
+    fn messages_compressed_with_unsupported_codec_are_discarded<S: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new()
+            .config(&generate_host_config())
+            .create::<S>()
+            .unwrap();
+        let (transport, mut remote) = LoopbackTransport::pair();
+
+        let mut sut = Gateway::new(&node, transport);
+        sut.add_codec(Lz4Codec::default());
+        assert_that!(unsafe { sut.inject::<u64>(&service_name) }, is_ok);
+        let service = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let message = Message {
+            service_name: service_name.as_str().to_string(),
+            type_descriptor: TypeDescriptor {
+                type_name: core::any::type_name::<u64>().to_string(),
+                size: 8,
+                alignment: 8,
+            },
+            encoding: Some("zstd".to_string()),
+            payload: vec![0; 8],
+        };
+        assert_that!(remote.send(&message), is_ok);
+
+        assert_that!(sut.pump(), is_ok);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
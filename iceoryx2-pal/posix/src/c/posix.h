@@ -45,6 +45,10 @@
 #include <unistd.h>
 #endif
 
+#if defined(__APPLE__) || defined(__FreeBSD__)
+#include <sys/event.h>
+#endif
+
 #include <errno.h>
 #include <fcntl.h>
 #include <signal.h>
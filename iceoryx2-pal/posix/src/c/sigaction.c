@@ -44,4 +44,22 @@ int iox2_sigaction_func(int sig, const struct iox2_sigaction *restrict act,
 
     return ret_val;
 }
+
+// SIGRTMIN and SIGRTMAX are not necessarily compile time constants, glibc for instance
+// reserves some real-time signals for its own threading implementation
+int iox2_sigrtmin(void) {
+#if defined(SIGRTMIN)
+    return SIGRTMIN;
+#else
+    return -1;
+#endif
+}
+
+int iox2_sigrtmax(void) {
+#if defined(SIGRTMAX)
+    return SIGRTMAX;
+#else
+    return -1;
+#endif
+}
 #endif
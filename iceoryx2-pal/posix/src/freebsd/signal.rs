@@ -13,6 +13,7 @@
 #![allow(non_camel_case_types)]
 #![allow(clippy::missing_safety_doc)]
 use crate::posix::types::*;
+use crate::posix::{close, Errno};
 
 pub unsafe fn sigaction(sig: int, act: *const sigaction_t, oact: *mut sigaction_t) -> int {
     internal::iox2_sigaction_func(sig, act, oact)
@@ -22,6 +23,45 @@ pub unsafe fn kill(pid: pid_t, sig: int) -> int {
     crate::internal::kill(pid, sig)
 }
 
+pub unsafe fn sigrtmin() -> int {
+    internal::iox2_sigrtmin()
+}
+
+pub unsafe fn sigrtmax() -> int {
+    internal::iox2_sigrtmax()
+}
+
+// emulates the Linux pidfd with a kqueue that becomes readable when the process exits
+pub unsafe fn pidfd_open(pid: pid_t, _flags: uint) -> int {
+    let kqueue = crate::internal::kqueue();
+    if kqueue == -1 {
+        return -1;
+    }
+
+    let mut event: crate::internal::kevent = core::mem::zeroed();
+    event.ident = pid as _;
+    event.filter = crate::internal::EVFILT_PROC as _;
+    event.flags = (crate::internal::EV_ADD | crate::internal::EV_ONESHOT) as _;
+    event.fflags = crate::internal::NOTE_EXIT as _;
+
+    if crate::internal::kevent(
+        kqueue,
+        &event,
+        1,
+        core::ptr::null_mut(),
+        0,
+        core::ptr::null(),
+    ) == -1
+    {
+        let errno = Errno::get();
+        close(kqueue);
+        Errno::set(errno);
+        return -1;
+    }
+
+    kqueue
+}
+
 mod internal {
     use super::*;
 
@@ -31,5 +71,8 @@ mod internal {
             act: *const sigaction_t,
             oact: *mut sigaction_t,
         ) -> int;
+
+        pub(super) fn iox2_sigrtmin() -> int;
+        pub(super) fn iox2_sigrtmax() -> int;
     }
 }
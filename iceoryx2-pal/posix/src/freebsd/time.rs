@@ -13,7 +13,9 @@
 #![allow(non_camel_case_types)]
 #![allow(clippy::missing_safety_doc)]
 
+use crate::posix::itimerspec;
 use crate::posix::types::*;
+use crate::posix::Errno;
 
 pub unsafe fn clock_gettime(clock_id: clockid_t, tp: *mut timespec) -> int {
     crate::internal::clock_gettime(clock_id, tp)
@@ -31,3 +33,19 @@ pub unsafe fn clock_nanosleep(
 ) -> int {
     crate::internal::clock_nanosleep(clock_id, flags, rqtp, rmtp)
 }
+
+// timerfd is not supported, the caller has to fall back to polling the clock
+pub unsafe fn timerfd_create(_clock_id: clockid_t, _flags: int) -> int {
+    Errno::set(Errno::ENOSYS);
+    -1
+}
+
+pub unsafe fn timerfd_settime(
+    _fd: int,
+    _flags: int,
+    _new_value: *const itimerspec,
+    _old_value: *mut itimerspec,
+) -> int {
+    Errno::set(Errno::ENOSYS);
+    -1
+}
@@ -57,6 +57,13 @@ pub mod posix {
     }
     impl Struct for cpu_set_t {}
 
+    #[repr(C)]
+    pub struct itimerspec {
+        pub it_interval: timespec,
+        pub it_value: timespec,
+    }
+    impl Struct for itimerspec {}
+
     pub trait SockAddrIn {
         fn set_s_addr(&mut self, value: u32);
         fn get_s_addr(&self) -> u32;
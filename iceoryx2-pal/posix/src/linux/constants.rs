@@ -48,6 +48,15 @@ pub const MCL_CURRENT: int = crate::internal::MCL_CURRENT as _;
 pub const MCL_FUTURE: int = crate::internal::MCL_FUTURE as _;
 pub const MAP_SHARED: int = crate::internal::MAP_SHARED as _;
 pub const MAP_FAILED: *mut void = u64::MAX as *mut void;
+pub const MADV_NORMAL: int = crate::internal::MADV_NORMAL as _;
+pub const MADV_HUGEPAGE: int = crate::internal::MADV_HUGEPAGE as _;
+pub const MFD_CLOEXEC: uint = 0x0001;
+pub const MFD_ALLOW_SEALING: uint = 0x0002;
+pub const F_ADD_SEALS: int = 1033;
+pub const F_GET_SEALS: int = 1034;
+pub const F_SEAL_SEAL: int = 0x0001;
+pub const F_SEAL_SHRINK: int = 0x0002;
+pub const F_SEAL_GROW: int = 0x0004;
 
 pub const PTHREAD_BARRIER_SERIAL_THREAD: int = crate::internal::PTHREAD_BARRIER_SERIAL_THREAD as _;
 pub const PTHREAD_EXPLICIT_SCHED: int = crate::internal::PTHREAD_EXPLICIT_SCHED as _;
@@ -191,6 +200,7 @@ pub const SOCK_STREAM: int = crate::internal::__socket_type_SOCK_STREAM as _;
 pub const SOCK_DGRAM: int = crate::internal::__socket_type_SOCK_DGRAM as _;
 pub const IPPROTO_UDP: int = crate::internal::IPPROTO_UDP as _;
 pub const SOCK_NONBLOCK: int = O_NONBLOCK;
+pub const TFD_NONBLOCK: int = O_NONBLOCK;
 pub const MSG_PEEK: int = crate::internal::MSG_PEEK as _;
 pub const SCM_MAX_FD: u32 = 253;
 pub const SCM_RIGHTS: int = crate::internal::SCM_RIGHTS as _;
@@ -63,6 +63,10 @@ pub unsafe fn shm_list() -> Vec<[i8; 256]> {
     result
 }
 
+pub unsafe fn memfd_create(name: *const c_char, flags: uint) -> int {
+    internal::memfd_create(name, flags)
+}
+
 pub unsafe fn mmap(
     addr: *mut void,
     len: size_t,
@@ -81,3 +85,15 @@ pub unsafe fn munmap(addr: *mut void, len: size_t) -> int {
 pub unsafe fn mprotect(addr: *mut void, len: size_t, prot: int) -> int {
     crate::internal::mprotect(addr, len, prot)
 }
+
+pub unsafe fn madvise(addr: *mut void, len: size_t, advice: int) -> int {
+    crate::internal::madvise(addr, len, advice)
+}
+
+mod internal {
+    use super::*;
+
+    extern "C" {
+        pub(super) fn memfd_create(name: *const c_char, flags: uint) -> int;
+    }
+}
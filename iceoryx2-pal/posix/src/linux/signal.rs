@@ -22,6 +22,22 @@ pub unsafe fn kill(pid: pid_t, sig: int) -> int {
     crate::internal::kill(pid, sig)
 }
 
+pub unsafe fn sigrtmin() -> int {
+    internal::iox2_sigrtmin()
+}
+
+pub unsafe fn sigrtmax() -> int {
+    internal::iox2_sigrtmax()
+}
+
+// older libc versions do not provide a wrapper, the system call number is the same on all
+// supported architectures
+const SYS_PIDFD_OPEN: long = 434;
+
+pub unsafe fn pidfd_open(pid: pid_t, flags: uint) -> int {
+    internal::syscall(SYS_PIDFD_OPEN, pid, flags) as int
+}
+
 mod internal {
     use super::*;
 
@@ -31,5 +47,10 @@ mod internal {
             act: *const sigaction_t,
             oact: *mut sigaction_t,
         ) -> int;
+
+        pub(super) fn iox2_sigrtmin() -> int;
+        pub(super) fn iox2_sigrtmax() -> int;
+
+        pub(super) fn syscall(number: long, ...) -> long;
     }
 }
@@ -18,3 +18,7 @@ use crate::posix::types::*;
 pub unsafe fn remove(pathname: *const c_char) -> int {
     crate::internal::remove(pathname)
 }
+
+pub unsafe fn rename(old: *const c_char, new: *const c_char) -> int {
+    crate::internal::rename(old, new)
+}
@@ -13,6 +13,7 @@
 #![allow(non_camel_case_types)]
 #![allow(clippy::missing_safety_doc)]
 
+use crate::posix::itimerspec;
 use crate::posix::types::*;
 
 pub unsafe fn clock_gettime(clock_id: clockid_t, tp: *mut timespec) -> int {
@@ -31,3 +32,30 @@ pub unsafe fn clock_nanosleep(
 ) -> int {
     crate::internal::clock_nanosleep(clock_id, flags, rqtp, rmtp)
 }
+
+pub unsafe fn timerfd_create(clock_id: clockid_t, flags: int) -> int {
+    internal::timerfd_create(clock_id, flags)
+}
+
+pub unsafe fn timerfd_settime(
+    fd: int,
+    flags: int,
+    new_value: *const itimerspec,
+    old_value: *mut itimerspec,
+) -> int {
+    internal::timerfd_settime(fd, flags, new_value, old_value)
+}
+
+mod internal {
+    use super::*;
+
+    extern "C" {
+        pub(super) fn timerfd_create(clock_id: clockid_t, flags: int) -> int;
+        pub(super) fn timerfd_settime(
+            fd: int,
+            flags: int,
+            new_value: *const itimerspec,
+            old_value: *mut itimerspec,
+        ) -> int;
+    }
+}
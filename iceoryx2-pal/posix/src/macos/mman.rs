@@ -187,6 +187,11 @@ pub unsafe fn shm_unlink(name: *const c_char) -> int {
     -1
 }
 
+pub unsafe fn memfd_create(_name: *const c_char, _flags: uint) -> int {
+    Errno::set(Errno::ENOSYS);
+    -1
+}
+
 pub unsafe fn mmap(
     addr: *mut void,
     len: size_t,
@@ -206,6 +211,16 @@ pub unsafe fn mprotect(addr: *mut void, len: size_t, prot: int) -> int {
     crate::internal::mprotect(addr, len, prot)
 }
 
+pub unsafe fn madvise(addr: *mut void, len: size_t, advice: int) -> int {
+    // transparent huge pages are not supported
+    if advice == MADV_HUGEPAGE {
+        Errno::set(Errno::EINVAL);
+        return -1;
+    }
+
+    crate::internal::madvise(addr, len, advice)
+}
+
 unsafe fn trim_ascii(value: &[i8]) -> &[u8] {
     for i in 0..value.len() {
         if value[i] == 0 {
@@ -49,6 +49,16 @@ pub const MCL_CURRENT: int = 16;
 pub const MCL_FUTURE: int = 32;
 pub const MAP_SHARED: int = 64;
 pub const MAP_FAILED: *mut void = u64::MAX as *mut void;
+pub const MADV_NORMAL: int = 0;
+pub const MADV_HUGEPAGE: int = 14;
+// not supported, the values are only used to reject the memfd and sealing calls
+pub const MFD_CLOEXEC: uint = 0x0001;
+pub const MFD_ALLOW_SEALING: uint = 0x0002;
+pub const F_ADD_SEALS: int = 1033;
+pub const F_GET_SEALS: int = 1034;
+pub const F_SEAL_SEAL: int = 0x0001;
+pub const F_SEAL_SHRINK: int = 0x0002;
+pub const F_SEAL_GROW: int = 0x0004;
 pub const MQ_INVALID: mqd_t = mqd_t::MAX;
 
 pub const PTHREAD_MUTEX_NORMAL: int = 1;
@@ -147,6 +157,7 @@ pub const SO_SNDTIMEO: int = windows_sys::Win32::Networking::WinSock::SO_SNDTIME
 pub const SOCK_STREAM: int = windows_sys::Win32::Networking::WinSock::SOCK_STREAM as _;
 pub const SOCK_DGRAM: int = windows_sys::Win32::Networking::WinSock::SOCK_DGRAM as _;
 pub const SOCK_NONBLOCK: int = O_NONBLOCK;
+pub const TFD_NONBLOCK: int = O_NONBLOCK;
 pub const IPPROTO_UDP: int = windows_sys::Win32::Networking::WinSock::IPPROTO_UDP as _;
 pub const MSG_PEEK: int = windows_sys::Win32::Networking::WinSock::MSG_PEEK as _;
 pub const SCM_MAX_FD: u32 = 253;
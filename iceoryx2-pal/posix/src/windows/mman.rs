@@ -40,21 +40,63 @@ use super::win32_handle_translator::{FdHandleEntry, FileHandle, HandleTranslator
 const MAX_SUPPORTED_SHM_SIZE: u64 = 1024 * 1024 * 1024;
 
 pub unsafe fn mlock(addr: *const void, len: size_t) -> int {
-    -1
+    let (has_locked, _) = win32call! { VirtualLock(addr, len) };
+    if has_locked == FALSE {
+        Errno::set(Errno::ENOMEM);
+        return -1;
+    }
+    0
 }
 
 pub unsafe fn munlock(addr: *const void, len: size_t) -> int {
-    -1
+    let (has_unlocked, _) = win32call! { VirtualUnlock(addr, len) };
+    if has_unlocked == FALSE {
+        Errno::set(Errno::ENOMEM);
+        return -1;
+    }
+    0
 }
 
 pub unsafe fn mlockall(flags: int) -> int {
+    // windows has no process wide memory locking, every mapping must be locked with mlock
+    Errno::set(Errno::ENOSYS);
     -1
 }
 
 pub unsafe fn munlockall() -> int {
+    Errno::set(Errno::ENOSYS);
     -1
 }
 
+fn prot_to_page_protection(prot: int) -> u32 {
+    match (
+        prot & PROT_READ != 0,
+        prot & PROT_WRITE != 0,
+        prot & PROT_EXEC != 0,
+    ) {
+        (_, true, true) => PAGE_EXECUTE_READWRITE,
+        (true, false, true) => PAGE_EXECUTE_READ,
+        (false, false, true) => PAGE_EXECUTE,
+        (_, true, false) => PAGE_READWRITE,
+        (true, false, false) => PAGE_READONLY,
+        (false, false, false) => PAGE_NOACCESS,
+    }
+}
+
+fn prot_to_file_map_access(prot: int) -> u32 {
+    let access = if prot & PROT_WRITE != 0 {
+        FILE_MAP_WRITE
+    } else {
+        FILE_MAP_READ
+    };
+
+    if prot & PROT_EXEC != 0 {
+        access | FILE_MAP_EXECUTE
+    } else {
+        access
+    }
+}
+
 unsafe fn remove_leading_path_separator(value: *const c_char) -> *const c_char {
     if *value as u8 == PATH_SEPARATOR {
         value.offset(1)
@@ -169,9 +211,15 @@ pub unsafe fn shm_open(name: *const c_char, oflag: int, mode: mode_t) -> int {
             return -1;
         }
 
+        let access = if oflag & O_RDONLY != 0 {
+            FILE_MAP_READ
+        } else {
+            FILE_MAP_ALL_ACCESS
+        };
+
         let last_mapping_error;
         (shm_handle, last_mapping_error) =
-            win32call! {OpenFileMappingA(FILE_MAP_ALL_ACCESS, false as i32, name as *const u8)};
+            win32call! {OpenFileMappingA(access, false as i32, name as *const u8)};
 
         if shm_handle == 0 {
             Errno::set(Errno::ENOENT);
@@ -307,6 +355,11 @@ pub unsafe fn shm_unlink(name: *const c_char) -> int {
     0
 }
 
+pub unsafe fn memfd_create(_name: *const c_char, _flags: uint) -> int {
+    Errno::set(Errno::ENOSYS);
+    -1
+}
+
 pub unsafe fn mmap(
     addr: *mut void,
     len: size_t,
@@ -328,15 +381,30 @@ pub unsafe fn mmap(
         }
     };
 
-    let (map_result, _) =
-        win32call! { MapViewOfFile(win_handle.handle.handle, FILE_MAP_ALL_ACCESS, 0, 0, len)};
+    let (map_result, _) = win32call! { MapViewOfFile(
+        win_handle.handle.handle,
+        prot_to_file_map_access(prot),
+        0,
+        0,
+        len,
+    )};
     match map_result {
         0 => {
             Errno::set(Errno::ENOMEM);
             core::ptr::null_mut::<void>()
         }
         lpaddress => {
-            if VirtualAlloc(lpaddress as *const void, len, MEM_COMMIT, PAGE_READWRITE).is_null() {
+            // the mapping was created with SEC_RESERVE, the pages must be committed with the
+            // protection of the view before they can be accessed
+            if VirtualAlloc(
+                lpaddress as *const void,
+                len,
+                MEM_COMMIT,
+                prot_to_page_protection(prot),
+            )
+            .is_null()
+            {
+                win32call! { UnmapViewOfFile(lpaddress as _) };
                 Errno::set(Errno::ENOMEM);
                 return core::ptr::null_mut::<void>();
             }
@@ -355,5 +423,26 @@ pub unsafe fn munmap(addr: *mut void, len: size_t) -> int {
 }
 
 pub unsafe fn mprotect(addr: *mut void, len: size_t, prot: int) -> int {
-    -1
+    let mut old_protection = 0;
+    let (has_changed_protection, _) = win32call! { VirtualProtect(
+        addr,
+        len,
+        prot_to_page_protection(prot),
+        &mut old_protection,
+    )};
+    if has_changed_protection == FALSE {
+        Errno::set(Errno::EACCES);
+        return -1;
+    }
+    0
+}
+
+pub unsafe fn madvise(addr: *mut void, len: size_t, advice: int) -> int {
+    // windows supports large pages only for private memory and requires a privilege
+    if advice == MADV_HUGEPAGE {
+        Errno::set(Errno::EINVAL);
+        return -1;
+    }
+
+    0
 }
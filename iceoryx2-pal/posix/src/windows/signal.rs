@@ -144,3 +144,17 @@ pub unsafe fn kill(pid: pid_t, sig: int) -> int {
         }
     }
 }
+
+// real-time signals are not supported
+pub unsafe fn sigrtmin() -> int {
+    -1
+}
+
+pub unsafe fn sigrtmax() -> int {
+    -1
+}
+
+pub unsafe fn pidfd_open(_pid: pid_t, _flags: uint) -> int {
+    Errno::set(Errno::ENOSYS);
+    -1
+}
@@ -15,7 +15,9 @@
 #![allow(unused_variables)]
 
 use windows_sys::Win32::Foundation::{ERROR_ACCESS_DENIED, ERROR_FILE_NOT_FOUND, FALSE};
-use windows_sys::Win32::Storage::FileSystem::DeleteFileA;
+use windows_sys::Win32::Storage::FileSystem::{
+    DeleteFileA, MoveFileExA, MOVEFILE_REPLACE_EXISTING, MOVEFILE_WRITE_THROUGH,
+};
 
 use crate::posix::types::*;
 
@@ -35,3 +37,12 @@ pub unsafe fn remove(pathname: *const c_char) -> int {
 
     0
 }
+
+pub unsafe fn rename(old: *const c_char, new: *const c_char) -> int {
+    let (has_moved, _) = win32call! { MoveFileExA(old as *const u8, new as *const u8, MOVEFILE_REPLACE_EXISTING | MOVEFILE_WRITE_THROUGH), ignore ERROR_FILE_NOT_FOUND, ERROR_ACCESS_DENIED };
+    if has_moved == FALSE {
+        return -1;
+    }
+
+    0
+}
@@ -17,6 +17,7 @@
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use crate::{
+    posix::itimerspec,
     posix::CLOCK_REALTIME,
     posix::{types::*, Errno},
 };
@@ -70,3 +71,19 @@ pub unsafe fn clock_nanosleep(
 
     Errno::ESUCCES as _
 }
+
+// timerfd is not supported, the caller has to fall back to polling the clock
+pub unsafe fn timerfd_create(_clock_id: clockid_t, _flags: int) -> int {
+    Errno::set(Errno::ENOSYS);
+    -1
+}
+
+pub unsafe fn timerfd_settime(
+    _fd: int,
+    _flags: int,
+    _new_value: *const itimerspec,
+    _old_value: *mut itimerspec,
+) -> int {
+    Errno::set(Errno::ENOSYS);
+    -1
+}
@@ -68,23 +68,59 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Override Entries With Environment Variables
+//!
+//! Every entry can be overridden with an environment variable that starts with
+//! [`ENVIRONMENT_VARIABLE_PREFIX`], followed by the path of the entry in upper case, for instance
+//! `IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_PUBLISHERS=8`. The global config applies them
+//! automatically, so the precedence is: environment variables, then the config file, then the
+//! default values.
+//!
+//! ```no_run
+//! use iceoryx2::config::Config;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut custom_config = Config::from_file(
+//!     &FilePath::new(b"my/custom/config/file.toml")?)?;
+//! custom_config.apply_environment_overrides()?;
+//!
+//! // stores the resulting config so that it can be inspected or reused
+//! custom_config.save(&FilePath::new(b"my/resulting/config/file.toml")?)?;
+//! # Ok(())
+//! # }
+//! ```
 
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_elementary::lazy_singleton::*;
-use iceoryx2_bb_posix::{file::FileBuilder, shared_memory::AccessMode};
+use iceoryx2_bb_posix::{
+    file::{CreationMode, FileBuilder},
+    permission::Permission,
+    shared_memory::AccessMode,
+};
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_bb_system_types::path::Path;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::time::Duration;
 
 use iceoryx2_bb_log::{fail, fatal_panic, trace, warn};
 
-use crate::service::port_factory::publisher::UnableToDeliverStrategy;
+use crate::service::port_factory::publisher::{UnableToDeliverStrategy, ZeroingPolicy};
+
+pub use iceoryx2_cal::event::selectable::Mechanism as EventMechanism;
+pub use iceoryx2_cal::shared_memory::selectable::Mechanism as SharedMemoryMechanism;
 
 /// Path to the default config file
 pub const DEFAULT_CONFIG_FILE: &[u8] = b"config/iceoryx2.toml";
 
+/// Prefix of the environment variables that override entries of the [`Config`], see
+/// [`Config::apply_environment_overrides()`].
+pub const ENVIRONMENT_VARIABLE_PREFIX: &str = "IOX2_";
+
 /// Failures occurring while creating a new [`Config`] object with [`Config::from_file()`] or
 /// [`Config::setup_global_config_from_file()`]
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
@@ -92,6 +128,7 @@ pub enum ConfigCreationError {
     FailedToOpenConfigFile,
     FailedToReadConfigFileContents,
     UnableToDeserializeContents,
+    InvalidEnvironmentVariable,
 }
 
 impl std::fmt::Display for ConfigCreationError {
@@ -102,6 +139,22 @@ impl std::fmt::Display for ConfigCreationError {
 
 impl std::error::Error for ConfigCreationError {}
 
+/// Failures occurring while storing a [`Config`] with [`Config::save()`]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ConfigSaveError {
+    UnableToSerializeContents,
+    FailedToCreateConfigFile,
+    FailedToWriteConfigFile,
+}
+
+impl std::fmt::Display for ConfigSaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ConfigSaveError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ConfigSaveError {}
+
 /// All configurable settings of a [`crate::service::Service`].
 #[non_exhaustive]
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
@@ -119,6 +172,34 @@ pub struct Service {
     pub creation_timeout: Duration,
     /// The suffix of a one-to-one connection
     pub connection_suffix: FileName,
+    /// The kernel primitive the events are based on, for instance
+    /// [`EventMechanism::MessageQueue`] on platforms like QNX where message queues are
+    /// preferred. All processes must use the same mechanism. Only
+    /// [`crate::service::Service`]s with a selectable event mechanism, like
+    /// [`crate::service::zero_copy::Service`], use it.
+    #[serde(default)]
+    pub event_mechanism: EventMechanism,
+    /// The implementation of the publisher data segments, for instance
+    /// [`SharedMemoryMechanism::Sealed`] in hardened deployments where a misbehaving process
+    /// must not be able to resize a data segment that is mapped by other processes. All
+    /// processes must use the same mechanism. Only [`crate::service::Service`]s with a
+    /// selectable shared memory, like [`crate::service::zero_copy::Service`], use it.
+    #[serde(default)]
+    pub shared_memory_mechanism: SharedMemoryMechanism,
+    /// The maximum length of a [`crate::service::service_name::ServiceName`]. The creation
+    /// of a [`crate::service::Service`] with a longer name fails. `0` disables the limit.
+    #[serde(default)]
+    pub max_name_length: usize,
+    /// The maximum number of [`crate::service::attribute::Attribute`]s a
+    /// [`crate::service::Service`] can have. The creation of a [`crate::service::Service`]
+    /// with more attributes fails. `0` disables the limit.
+    #[serde(default)]
+    pub max_attributes: usize,
+    /// The maximum number of [`crate::service::Service`]s that can exist at the same time.
+    /// The creation of a [`crate::service::Service`] that would exceed it fails. `0` disables
+    /// the limit.
+    #[serde(default)]
+    pub max_services: usize,
 }
 
 /// All configurable settings of a [`crate::node::Node`].
@@ -131,6 +212,28 @@ pub struct Node {
     pub monitor_suffix: FileName,
     /// The suffix of the files where the node configuration is stored.
     pub static_config_suffix: FileName,
+    /// The maximum amount of shared memory in bytes the data segments of all
+    /// [`crate::port::publisher::Publisher`]s of a [`crate::node::Node`] can consume together.
+    /// The creation of a [`crate::port::publisher::Publisher`] that would exceed it fails. `0`
+    /// disables the quota.
+    #[serde(default)]
+    pub shared_memory_quota: usize,
+    /// The maximum number of alive [`crate::node::Node`]s. The creation of a
+    /// [`crate::node::Node`] that would exceed it fails. `0` disables the limit.
+    #[serde(default)]
+    pub max_nodes: usize,
+}
+
+/// All configurable settings of the shared memory that backs the data segments of the
+/// [`crate::port::publisher::Publisher`]s.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, Default)]
+pub struct SharedMemory {
+    /// The size of the huge pages that shall back the data segments, for instance `2097152`
+    /// for 2 MiB or `1073741824` for 1 GiB huge pages. When they are not available the
+    /// default page size is used instead, see
+    /// [`crate::port::publisher::DataSegmentReport::page_size()`]. `0` disables huge pages.
+    pub huge_page_size: usize,
 }
 
 /// The global settings
@@ -141,10 +244,18 @@ pub struct Global {
     root_path_windows: Path,
     /// Prefix used for all files created during runtime
     pub prefix: FileName,
+    /// The domain of the iceoryx2 instance. [`crate::node::Node`]s and
+    /// [`crate::service::Service`]s of different domains never see each other, even when they
+    /// share the same [`Global::prefix`] and root path. `None` is the default domain.
+    #[serde(default)]
+    pub domain: Option<FileName>,
     /// [`crate::service::Service`] settings
     pub service: Service,
     /// [`crate::node::Node`] settings
     pub node: Node,
+    /// Shared memory settings
+    #[serde(default)]
+    pub shared_memory: SharedMemory,
 }
 
 impl Global {
@@ -162,19 +273,42 @@ impl Global {
         path
     }
 
-    /// The path under which all other directories or files will be created
+    /// The path under which all other directories or files will be created. When a
+    /// [`Global::domain`] is set, it is a subdirectory of the configured root path.
     pub fn root_path(&self) -> Path {
         #[cfg(target_os = "windows")]
-        {
-            fatal_panic!(from "Global::root_path_windows",
+        let mut path = fatal_panic!(from "Global::root_path_windows",
                 when Path::new(self.root_path_windows.as_bytes()),
-                "Unable to initialize config since the internal root_path_windows \"{}\" is not a valid directory.", self.root_path_windows)
-        }
+                "Unable to initialize config since the internal root_path_windows \"{}\" is not a valid directory.", self.root_path_windows);
         #[cfg(not(target_os = "windows"))]
-        {
-            fatal_panic!(from "Global::root_path_unix",
+        let mut path = fatal_panic!(from "Global::root_path_unix",
                 when Path::new(self.root_path_unix.as_bytes()),
-                "Unable to initialize config since the internal root_path_unix \"{}\" is not a valid directory.", self.root_path_unix)
+                "Unable to initialize config since the internal root_path_unix \"{}\" is not a valid directory.", self.root_path_unix);
+
+        if let Some(domain) = &self.domain {
+            fatal_panic!(from "Global::root_path",
+                when path.add_path_entry(&domain.into()),
+                "Unable to initialize config since the domain \"{}\" results in an invalid root path.", domain);
+        }
+
+        path
+    }
+
+    /// The prefix of all files created during runtime in the [`Global::domain`]. In the
+    /// default domain it is the [`Global::prefix`], otherwise the [`Global::prefix`] is
+    /// preceded by the domain name so that the files of different domains never share a
+    /// common prefix with the default domain.
+    pub fn domain_prefix(&self) -> FileName {
+        match &self.domain {
+            None => self.prefix,
+            Some(domain) => {
+                let mut prefix = *domain;
+                fatal_panic!(from "Global::domain_prefix",
+                    when prefix.push(b'_').and_then(|_| prefix.push_bytes(self.prefix.as_bytes())),
+                    "Unable to initialize config since the domain \"{}\" in combination with the prefix \"{}\" exceeds the maximum file name length.",
+                    domain, self.prefix);
+                prefix
+            }
         }
     }
 }
@@ -188,6 +322,9 @@ pub struct Defaults {
     pub publish_subscribe: PublishSubscribe,
     /// Default settings for the messaging pattern event
     pub event: Event,
+    /// Default settings for the messaging pattern blackboard
+    #[serde(default)]
+    pub blackboard: Blackboard,
 }
 
 /// Default settings for the publish-subscribe messaging pattern. These settings are used unless
@@ -197,6 +334,10 @@ pub struct Defaults {
 pub struct PublishSubscribe {
     /// The maximum amount of supported [`crate::port::subscriber::Subscriber`]
     pub max_subscribers: usize,
+    /// The maximum amount of supported observers, passive
+    /// [`crate::port::subscriber::Subscriber`]s for tooling that do not count against
+    /// [`PublishSubscribe::max_subscribers`]
+    pub max_observers: usize,
     /// The maximum amount of supported [`crate::port::publisher::Publisher`]
     pub max_publishers: usize,
     /// The maximum buffer size a [`crate::port::subscriber::Subscriber`] can have
@@ -218,6 +359,62 @@ pub struct PublishSubscribe {
     /// [`crate::port::publisher::Publisher`] when the [`crate::port::subscriber::Subscriber`]s
     /// buffer is full.
     pub unable_to_deliver_strategy: UnableToDeliverStrategy,
+    /// Defines when the data segment of a [`crate::port::publisher::Publisher`] is zeroed.
+    pub publisher_zeroing_policy: ZeroingPolicy,
+    /// The maximum amount of shared memory in bytes the data segments of all
+    /// [`crate::port::publisher::Publisher`]s of a service can consume together. The creation of
+    /// a [`crate::port::publisher::Publisher`], or the growth of its data segment, that would
+    /// exceed it fails. The budget is stored in the static config of the service by its
+    /// creator. `0` disables the budget.
+    pub service_memory_budget: usize,
+    /// Defines if the [`crate::port::subscriber::Subscriber`] maps the data segments of the
+    /// [`crate::port::publisher::Publisher`]s read-only, so that it cannot corrupt the samples
+    /// other [`crate::port::subscriber::Subscriber`]s are reading. It is ignored on platforms
+    /// that do not support it.
+    pub subscriber_read_only_data_segments: bool,
+    /// The maximum number of flight recordings, see [`crate::port::flight_recorder`], that
+    /// are retained. When a new recording would exceed it, the oldest recordings of
+    /// [`crate::port::publisher::Publisher`]s that no longer exist are removed. `0` disables
+    /// the limit.
+    #[serde(default = "default_flight_recorder_max_recordings")]
+    pub flight_recorder_max_recordings: usize,
+}
+
+fn default_flight_recorder_max_recordings() -> usize {
+    16
+}
+
+/// A named set of QoS settings that is defined centrally in [`Config::qos_profiles`] and
+/// applied with [`crate::service::builder::publish_subscribe::Builder::qos_profile()`] or
+/// [`crate::service::builder::event::Builder::qos_profile()`]. Every builder applies only the
+/// settings of its messaging pattern, every setting that is not defined keeps the value of
+/// [`PublishSubscribe`] or [`Event`] respectively.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, Eq, PartialEq)]
+pub struct QosProfile {
+    /// The maximum amount of supported [`crate::port::subscriber::Subscriber`]
+    pub max_subscribers: Option<usize>,
+    /// The maximum amount of supported [`crate::port::publisher::Publisher`]
+    pub max_publishers: Option<usize>,
+    /// The maximum buffer size a [`crate::port::subscriber::Subscriber`] can have
+    pub subscriber_max_buffer_size: Option<usize>,
+    /// The maximum amount of [`crate::sample::Sample`]s a [`crate::port::subscriber::Subscriber`] can
+    /// hold in parallel.
+    pub subscriber_max_borrowed_samples: Option<usize>,
+    /// The maximum history size a [`crate::port::subscriber::Subscriber`] can request from a
+    /// [`crate::port::publisher::Publisher`].
+    pub publisher_history_size: Option<usize>,
+    /// Defines how the [`crate::port::subscriber::Subscriber`] buffer behaves when it is
+    /// full, see [`PublishSubscribe::enable_safe_overflow`].
+    pub enable_safe_overflow: Option<bool>,
+    /// The maximum amount of supported [`crate::port::listener::Listener`]
+    pub max_listeners: Option<usize>,
+    /// The maximum amount of supported [`crate::port::notifier::Notifier`]
+    pub max_notifiers: Option<usize>,
+    /// The largest event id supported by the event service
+    pub event_id_max_value: Option<usize>,
+    /// The deadline of the service, applied to publish-subscribe and event services
+    pub deadline: Option<Duration>,
 }
 
 /// Default settings for the event messaging pattern. These settings are used unless
@@ -233,6 +430,26 @@ pub struct Event {
     pub event_id_max_value: usize,
 }
 
+/// Default settings for the blackboard messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct Blackboard {
+    /// The maximum amount of supported [`crate::port::reader::Reader`]
+    pub max_readers: usize,
+    /// The maximum amount of supported [`crate::port::writer::Writer`]
+    pub max_writers: usize,
+}
+
+impl Default for Blackboard {
+    fn default() -> Self {
+        Self {
+            max_readers: 8,
+            max_writers: 1,
+        }
+    }
+}
+
 /// Represents the configuration that iceoryx2 will utilize. It is divided into two sections:
 /// the [Global] settings, which must align with the iceoryx2 instance the application intends to
 /// join, and the [Defaults] for communication within that iceoryx2 instance. The user has the
@@ -244,6 +461,11 @@ pub struct Config {
     pub global: Global,
     /// Default settings
     pub defaults: Defaults,
+    /// Named QoS profiles that can be referenced by the service builders, see
+    /// [`crate::service::builder::publish_subscribe::Builder::qos_profile()`] and
+    /// [`crate::service::builder::event::Builder::qos_profile()`]
+    #[serde(default)]
+    pub qos_profiles: BTreeMap<String, QosProfile>,
 }
 
 static ICEORYX2_CONFIG: LazySingleton<Config> = LazySingleton::<Config>::new();
@@ -255,6 +477,7 @@ impl Default for Config {
                 root_path_unix: Path::new(b"/tmp/iceoryx2/").unwrap(),
                 root_path_windows: Path::new(b"C:\\Temp\\iceoryx2\\").unwrap(),
                 prefix: FileName::new(b"iox2_").unwrap(),
+                domain: None,
                 service: Service {
                     directory: Path::new(b"services").unwrap(),
                     publisher_data_segment_suffix: FileName::new(b".publisher_data").unwrap(),
@@ -262,16 +485,25 @@ impl Default for Config {
                     dynamic_config_storage_suffix: FileName::new(b".dynamic").unwrap(),
                     creation_timeout: Duration::from_millis(500),
                     connection_suffix: FileName::new(b".connection").unwrap(),
+                    event_mechanism: EventMechanism::UnixDatagramSocket,
+                    shared_memory_mechanism: SharedMemoryMechanism::Posix,
+                    max_name_length: 0,
+                    max_attributes: 0,
+                    max_services: 0,
                 },
                 node: Node {
                     directory: Path::new(b"nodes").unwrap(),
                     monitor_suffix: FileName::new(b".node_monitor").unwrap(),
                     static_config_suffix: FileName::new(b".details").unwrap(),
+                    shared_memory_quota: 0,
+                    max_nodes: 0,
                 },
+                shared_memory: SharedMemory { huge_page_size: 0 },
             },
             defaults: Defaults {
                 publish_subscribe: PublishSubscribe {
                     max_subscribers: 8,
+                    max_observers: 0,
                     max_publishers: 2,
                     publisher_history_size: 1,
                     subscriber_max_buffer_size: 2,
@@ -279,13 +511,19 @@ impl Default for Config {
                     publisher_max_loaned_samples: 2,
                     enable_safe_overflow: true,
                     unable_to_deliver_strategy: UnableToDeliverStrategy::Block,
+                    publisher_zeroing_policy: ZeroingPolicy::Never,
+                    service_memory_budget: 0,
+                    subscriber_read_only_data_segments: true,
+                    flight_recorder_max_recordings: default_flight_recorder_max_recordings(),
                 },
                 event: Event {
                     max_listeners: 1,
                     max_notifiers: 16,
                     event_id_max_value: 32,
                 },
+                blackboard: Blackboard::default(),
             },
+            qos_profiles: BTreeMap::new(),
         }
     }
 }
@@ -318,7 +556,136 @@ impl Config {
         Ok(new_config)
     }
 
-    /// Sets up the global configuration from a file. If the global configuration was already setup
+    /// Stores the [`Config`] as TOML in the provided file so that it can be loaded again with
+    /// [`Config::from_file()`]. An existing file is overwritten.
+    pub fn save(&self, config_file: &FilePath) -> Result<(), ConfigSaveError> {
+        let msg = "Failed to save config";
+        let contents = match toml::to_string(self) {
+            Ok(v) => v,
+            Err(e) => {
+                fail!(from self, with ConfigSaveError::UnableToSerializeContents,
+                    "{} since the contents could not be serialized ({}).", msg, e);
+            }
+        };
+
+        let mut file = fail!(from self, when FileBuilder::new(config_file)
+                .creation_mode(CreationMode::PurgeAndCreate)
+                .permission(Permission::OWNER_READ | Permission::OWNER_WRITE | Permission::GROUP_READ | Permission::OTHERS_READ)
+                .create(),
+            with ConfigSaveError::FailedToCreateConfigFile,
+            "{} since the config file could not be created.", msg);
+
+        fail!(from self, when file.write(contents.as_bytes()),
+            with ConfigSaveError::FailedToWriteConfigFile,
+            "{} since the contents could not be written to the config file.", msg);
+
+        trace!(from self, "Saved.");
+        Ok(())
+    }
+
+    /// Overrides entries with the values of all environment variables that start with
+    /// [`ENVIRONMENT_VARIABLE_PREFIX`]. The rest of the variable name is the path of the entry in
+    /// upper case with every section and key separated by `_`, for instance
+    /// `IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_PUBLISHERS=8` overrides
+    /// `defaults.publish_subscribe.max_publishers`. The value is written in TOML syntax, strings
+    /// can also be written without quotes. Optional entries can only be overridden when they are
+    /// set. Variables that do not correspond to an entry are ignored with a warning. When a value does not match the type of its entry
+    /// [`ConfigCreationError::InvalidEnvironmentVariable`] is returned and the [`Config`]
+    /// stays unchanged.
+    pub fn apply_environment_overrides(&mut self) -> Result<(), ConfigCreationError> {
+        let msg = "Failed to apply the environment overrides";
+        let mut config = match toml::Value::try_from(&*self) {
+            Ok(v) => v,
+            Err(e) => {
+                fatal_panic!(from self,
+                    "This should never happen! {} since the config could not be serialized ({}).", msg, e);
+            }
+        };
+
+        let variables: BTreeMap<String, String> = std::env::vars()
+            .filter(|(name, _)| name.starts_with(ENVIRONMENT_VARIABLE_PREFIX))
+            .collect();
+
+        for (name, value) in &variables {
+            let entry_name = &name[ENVIRONMENT_VARIABLE_PREFIX.len()..];
+            let path = match Self::entry_path(&config, entry_name) {
+                Some(path) => path,
+                None => {
+                    warn!(from self, "Ignoring the environment variable {} since it does not correspond to a config entry.", name);
+                    continue;
+                }
+            };
+
+            if let Some(entry) = path
+                .iter()
+                .try_fold(&mut config, |entry, key| entry.get_mut(key.as_str()))
+            {
+                match Self::parse_environment_value(entry, value) {
+                    Some(value) => *entry = value,
+                    None => {
+                        fail!(from self, with ConfigCreationError::InvalidEnvironmentVariable,
+                            "{} since the value \"{}\" of {} does not match the type of the config entry.",
+                            msg, value, name);
+                    }
+                }
+            }
+        }
+
+        match config.try_into() {
+            Ok(v) => *self = v,
+            Err(e) => {
+                fail!(from self, with ConfigCreationError::InvalidEnvironmentVariable,
+                    "{} since the resulting config is invalid ({}).", msg, e);
+            }
+        }
+
+        trace!(from self, "Applied environment overrides.");
+        Ok(())
+    }
+
+    // keys contain '_' as well, therefore every key that matches the beginning of the name is
+    // tried until the whole name corresponds to an entry
+    fn entry_path(value: &toml::Value, name: &str) -> Option<Vec<String>> {
+        if name.is_empty() {
+            return Some(vec![]);
+        }
+
+        for (key, entry) in value.as_table()? {
+            let rest = match name.strip_prefix(key.to_uppercase().as_str()) {
+                Some("") => "",
+                Some(rest) => match rest.strip_prefix('_') {
+                    Some(rest) if !rest.is_empty() => rest,
+                    _ => continue,
+                },
+                None => continue,
+            };
+
+            if let Some(mut path) = Self::entry_path(entry, rest) {
+                path.insert(0, key.clone());
+                return Some(path);
+            }
+        }
+
+        None
+    }
+
+    // the type of the entry must be preserved, otherwise the config cannot be deserialized
+    fn parse_environment_value(entry: &toml::Value, value: &str) -> Option<toml::Value> {
+        let parsed = match toml::from_str::<toml::Table>(&format!("value = {}", value)) {
+            Ok(mut table) => table.remove("value"),
+            Err(_) if entry.is_str() => Some(toml::Value::String(value.to_string())),
+            Err(_) => None,
+        }?;
+
+        match parsed.same_type(entry) {
+            true => Some(parsed),
+            false if entry.is_str() => Some(toml::Value::String(value.to_string())),
+            false => None,
+        }
+    }
+
+    /// Sets up the global configuration from a file and applies the environment overrides, see
+    /// [`Config::apply_environment_overrides()`]. If the global configuration was already setup
     /// it will print a warning and does not load the file. It returns the [`Config`] when the file
     /// could be successfully loaded otherwise a [`ConfigCreationError`] describing the error.
     pub fn setup_global_config_from_file(
@@ -328,7 +695,10 @@ impl Config {
             return Ok(ICEORYX2_CONFIG.get());
         }
 
-        if !ICEORYX2_CONFIG.set_value(Config::from_file(config_file)?) {
+        let mut config = Config::from_file(config_file)?;
+        config.apply_environment_overrides()?;
+
+        if !ICEORYX2_CONFIG.set_value(config) {
             warn!(
                 from ICEORYX2_CONFIG.get(),
                 "Configuration already loaded and set up, cannot load another one. This may happen when this function is called from multiple threads."
@@ -353,7 +723,12 @@ impl Config {
             .is_err()
         {
             warn!(from "Config::get_global_config()", "Unable to load default config file, populate config with default values.");
-            ICEORYX2_CONFIG.set_value(Config::default());
+            let mut config = Config::default();
+            if config.apply_environment_overrides().is_err() {
+                warn!(from "Config::get_global_config()", "Unable to apply the environment overrides, populate config with default values.");
+                config = Config::default();
+            }
+            ICEORYX2_CONFIG.set_value(config);
         }
 
         ICEORYX2_CONFIG.get()
@@ -73,7 +73,7 @@
 //!
 //! let subscriber = service.subscriber_builder().create()?;
 //!
-//! while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+//! while let NodeEvent::Tick = node.wait(CYCLE_TIME) {
 //!     while let Some(sample) = subscriber.receive()? {
 //!         println!("received: {:?}", *sample);
 //!     }
@@ -100,7 +100,7 @@
 //!
 //! let publisher = service.publisher_builder().create()?;
 //!
-//! while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+//! while let NodeEvent::Tick = node.wait(CYCLE_TIME) {
 //!     let sample = publisher.loan_uninit()?;
 //!     let sample = sample.write_payload(1234);
 //!     sample.send()?;
@@ -133,7 +133,7 @@
 //!
 //! let mut listener = event.listener_builder().create()?;
 //!
-//! while let Iox2Event::Tick = Iox2::wait(Duration::ZERO) {
+//! while let NodeEvent::Tick = node.wait(Duration::ZERO) {
 //!     if let Ok(Some(event_id)) = listener.timed_wait_one(CYCLE_TIME) {
 //!         println!("event was triggered with id: {:?}", event_id);
 //!     }
@@ -161,7 +161,7 @@
 //! let notifier = event.notifier_builder().create()?;
 //!
 //! let mut counter: usize = 0;
-//! while let Iox2Event::Tick = Iox2::wait(CYCLE_TIME) {
+//! while let NodeEvent::Tick = node.wait(CYCLE_TIME) {
 //!     counter += 1;
 //!     notifier.notify_with_custom_event_id(EventId::new(counter))?;
 //!
@@ -272,6 +272,15 @@
 //!     32-bit and 64-bit applications to communicate but at the expense of the lock-free
 //!     guarantee. Enabling the feature can cause a deadlock of the whole system when one
 //!     application crashes at the wrong time.
+//!  * `safety` - Restricts the communication path, loaning, sending, receiving and releasing
+//!     samples, to a subset that returns an error instead of panicking when an internal
+//!     inconsistency is detected and that does not allocate heap memory as long as no port
+//!     connects or disconnects. The subset is verified with clippy lints that deny panicking
+//!     operations and with a test that counts the heap allocations. Acknowledgments,
+//!     dead-letter services and flight recorders are not part of the subset.
+//!  * `async` - Provides [`Subscriber::receive_async()`](crate::port::subscriber::Subscriber)
+//!     and [`Listener::wait_async()`](crate::port::listener::Listener) which return futures
+//!     that can be awaited on any executor, like tokio or async-std.
 //!
 //! # Custom Configuration
 //!
@@ -287,9 +296,6 @@ mod compiletests;
 /// Handles iceoryx2s global configuration
 pub mod config;
 
-/// Central instance that handles all incoming events, the event loop
-pub mod iox2;
-
 /// Central instance that owns all service entities and can handle incoming event in an event loop
 pub mod node;
 
@@ -298,12 +304,17 @@ pub mod port;
 
 pub(crate) mod raw_sample;
 
+pub(crate) mod safety;
+
 /// The payload that is received by a [`Subscriber`](crate::port::subscriber::Subscriber).
 pub mod sample;
 
 /// The payload that is sent by a [`Publisher`](crate::port::publisher::Publisher).
 pub mod sample_mut;
 
+/// Multiple payloads that are sent together by a [`Publisher`](crate::port::publisher::Publisher).
+pub mod sample_mut_batch;
+
 /// The foundation of communication the service with its
 /// [`MessagingPattern`](crate::service::messaging_pattern::MessagingPattern)
 pub mod service;
@@ -0,0 +1,108 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let monitor = node.dead_node_monitor("DeadNodes".try_into()?)?;
+//!
+//! // somewhere else, interested parties listen to the event service "DeadNodes"
+//! let event = node.service_builder("DeadNodes".try_into()?)
+//!     .event()
+//!     .open_or_create()?;
+//! let listener = event.listener_builder().create()?;
+//!
+//! let cleanup_state = monitor.cleanup();
+//! println!("removed the stale resources of {} dead nodes", cleanup_state.cleanups);
+//!
+//! if cleanup_state.cleanups != 0 {
+//!     assert!(listener.try_wait_one()?.is_some());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::{fail, warn};
+
+use super::{CleanupState, Node};
+use crate::config::Config;
+use crate::port::notifier::{Notifier, NotifierCreateError};
+use crate::service;
+use crate::service::builder::event::EventOpenOrCreateError;
+use crate::service::port_factory::event;
+use crate::service::service_name::ServiceName;
+
+enum_gen! {
+    /// Failures that can occur when a [`DeadNodeMonitor`] is created with
+    /// [`Node::dead_node_monitor()`].
+    DeadNodeMonitorCreateError
+  mapping:
+    EventOpenOrCreateError,
+    NotifierCreateError
+}
+
+impl std::fmt::Display for DeadNodeMonitorCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "DeadNodeMonitorCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for DeadNodeMonitorCreateError {}
+
+/// Detects [`Node`]s whose process has crashed, removes their stale resources with
+/// [`Node::cleanup_dead_nodes()`] and notifies all
+/// [`Listener`](crate::port::listener::Listener)s of an event service whenever dead [`Node`]s
+/// were cleaned up.
+#[derive(Debug)]
+pub struct DeadNodeMonitor<Service: service::Service> {
+    config: Config,
+    notifier: Notifier<Service>,
+    _event: event::PortFactory<Service>,
+}
+
+impl<Service: service::Service> DeadNodeMonitor<Service> {
+    pub(crate) fn new(
+        node: &Node<Service>,
+        service_name: ServiceName,
+    ) -> Result<Self, DeadNodeMonitorCreateError> {
+        let msg = "Unable to create dead node monitor";
+        let event = fail!(from node, when node.service_builder(service_name.clone()).event().open_or_create(),
+            "{} since the event service {} could not be opened or created.", msg, service_name);
+        let notifier = fail!(from node, when event.notifier_builder().create(),
+            "{} since the notifier for the event service {} could not be created.", msg, service_name);
+
+        Ok(Self {
+            config: node.config().clone(),
+            notifier,
+            _event: event,
+        })
+    }
+
+    /// Removes the stale resources of all dead [`Node`]s, see [`Node::cleanup_dead_nodes()`].
+    /// When at least one dead [`Node`] was cleaned up, all
+    /// [`Listener`](crate::port::listener::Listener)s of the event service are notified.
+    pub fn cleanup(&self) -> CleanupState {
+        let cleanup_state = Node::<Service>::cleanup_dead_nodes(&self.config);
+
+        if cleanup_state.cleanups != 0 {
+            warn!(from self, when self.notifier.notify(),
+                "Unable to notify the listeners about {} cleaned up dead nodes.", cleanup_state.cleanups);
+        }
+
+        cleanup_state
+    }
+}
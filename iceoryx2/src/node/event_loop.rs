@@ -0,0 +1,519 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`Callbacks`] are registered at a [`Node`](crate::node::Node) and are called from within
+//! [`Node::run()`](crate::node::Node::run()) whenever a [`Subscriber`] received a
+//! [`Sample`], a [`Listener`] received an [`EventId`] or a timer expired.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::node::event_loop::{Callbacks, CallbackProgression};
+//! use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//! let subscriber = service.subscriber_builder().create()?;
+//!
+//! let mut number_of_ticks = 0;
+//! let mut callbacks = Callbacks::new()
+//!     .on_sample(&subscriber, |sample| {
+//!         println!("received: {:?}", *sample);
+//!         CallbackProgression::Continue
+//!     })
+//!     .on_timer(Duration::from_millis(1), || {
+//!         number_of_ticks += 1;
+//!         if number_of_ticks == 3 {
+//!             CallbackProgression::Stop
+//!         } else {
+//!             CallbackProgression::Continue
+//!         }
+//!     });
+//!
+//! node.run(&mut callbacks, Duration::from_millis(10))?;
+//! # Ok(())
+//! # }
+//! ```
+
+use core::fmt::Debug;
+use core::time::Duration;
+use std::time::Instant;
+
+use crate::port::event_id::EventId;
+use crate::port::listener::Listener;
+use crate::port::subscriber::{Subscriber, SubscriberReceiveError};
+use crate::port::waitset::{WaitSet, WaitSetAttachmentId, WaitSetWaitError};
+use crate::sample::Sample;
+use crate::service;
+use iceoryx2_cal::event::ListenerWaitError;
+
+/// Defines if the [`Node::run()`](crate::node::Node::run()) loop shall continue after a callback
+/// was called.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CallbackProgression {
+    Continue,
+    Stop,
+}
+
+/// The reason why [`Node::run()`](crate::node::Node::run()) has returned.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NodeRunEvent {
+    /// A callback returned [`CallbackProgression::Stop`].
+    Stopped,
+    /// The process received a termination request, e.g. SIGTERM or SIGINT.
+    TerminationRequest,
+    /// The wait was interrupted by a signal.
+    InterruptSignal,
+}
+
+/// The failures that can occur in [`Node::run()`](crate::node::Node::run()).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NodeRunFailure {
+    SubscriberReceiveError(SubscriberReceiveError),
+    ListenerWaitError(ListenerWaitError),
+    WaitSetWaitError(WaitSetWaitError),
+}
+
+impl From<SubscriberReceiveError> for NodeRunFailure {
+    fn from(value: SubscriberReceiveError) -> Self {
+        NodeRunFailure::SubscriberReceiveError(value)
+    }
+}
+
+impl From<ListenerWaitError> for NodeRunFailure {
+    fn from(value: ListenerWaitError) -> Self {
+        NodeRunFailure::ListenerWaitError(value)
+    }
+}
+
+impl From<WaitSetWaitError> for NodeRunFailure {
+    fn from(value: WaitSetWaitError) -> Self {
+        NodeRunFailure::WaitSetWaitError(value)
+    }
+}
+
+impl std::fmt::Display for NodeRunFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "NodeRunFailure::{:?}", self)
+    }
+}
+
+impl std::error::Error for NodeRunFailure {}
+
+type SampleDispatcher<'a> = Box<dyn FnMut() -> Result<CallbackProgression, NodeRunFailure> + 'a>;
+type SamplePoller<'a> = Box<dyn Fn() -> Result<bool, NodeRunFailure> + 'a>;
+type SampleArrival<'a, Service> =
+    Box<dyn Fn() -> Result<&'a Listener<Service>, NodeRunFailure> + 'a>;
+type HoldBack<'a> = Box<dyn Fn() -> Option<Duration> + 'a>;
+type EventCallback<'a> = Box<dyn FnMut(EventId) -> CallbackProgression + 'a>;
+type TimerCallback<'a> = Box<dyn FnMut() -> CallbackProgression + 'a>;
+
+/// The priority of a callback when no priority was set with [`Callbacks::priority()`].
+pub const DEFAULT_PRIORITY: u8 = 0;
+
+struct SampleEntry<'a, Service: service::Service> {
+    priority: u8,
+    has_samples: SamplePoller<'a>,
+    // the listener that wakes up the event loop whenever a publisher delivered samples
+    sample_arrival: SampleArrival<'a, Service>,
+    // the time until the samples that are held back for the total order can be received
+    hold_back: HoldBack<'a>,
+    dispatcher: SampleDispatcher<'a>,
+}
+
+struct EventEntry<'a> {
+    priority: u8,
+    // [`None`] when the callback is called for every event id
+    event_id: Option<EventId>,
+    // received event ids that were not yet handed to the callback
+    pending_event_ids: Vec<EventId>,
+    callback: EventCallback<'a>,
+}
+
+impl<'a> EventEntry<'a> {
+    fn add_pending_event_ids(callbacks: &mut [EventEntry<'a>], id: EventId) {
+        for callback in callbacks.iter_mut() {
+            if callback.event_id.map_or(true, |event_id| event_id == id) {
+                callback.pending_event_ids.push(id);
+            }
+        }
+    }
+}
+
+struct ListenerEntry<'a, Service: service::Service> {
+    listener: &'a Listener<Service>,
+    callbacks: Vec<EventEntry<'a>>,
+}
+
+struct Timer<'a> {
+    priority: u8,
+    interval: Duration,
+    next_expiration: Instant,
+    callback: TimerCallback<'a>,
+}
+
+/// The [`WaitSet`] on which [`Node::run()`](crate::node::Node::run()) blocks between two
+/// cycles. It contains the [`Listener`]s of all event callbacks and the sample arrival
+/// listeners of all [`Subscriber`]s.
+pub(crate) struct CallbackWaitSet<'a, Service: service::Service> {
+    waitset: WaitSet<'a, Service>,
+    // maps the attachments to the index of their listener entry, the attachments of the
+    // subscribers have no entry since their events only wake up the loop
+    listener_entries: Vec<(WaitSetAttachmentId, usize)>,
+}
+
+#[derive(Clone, Copy)]
+enum ActiveCallback {
+    Sample(usize),
+    Event(usize, usize),
+    Timer(usize),
+}
+
+/// Collection of callbacks that are driven by [`Node::run()`](crate::node::Node::run()).
+/// Every callback can stop the loop by returning [`CallbackProgression::Stop`].
+///
+/// When multiple callbacks are ready in the same cycle they are called in the order of their
+/// priority, see [`Callbacks::priority()`]. Callbacks with the same priority are called in the
+/// order of their registration.
+pub struct Callbacks<'a, Service: service::Service> {
+    sample_dispatchers: Vec<SampleEntry<'a, Service>>,
+    listeners: Vec<ListenerEntry<'a, Service>>,
+    timers: Vec<Timer<'a>>,
+    priority: u8,
+    dispatch_only_highest_priority: bool,
+    has_deferred_callbacks: bool,
+}
+
+impl<'a, Service: service::Service> Debug for Callbacks<'a, Service> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Callbacks<{}> {{ number_of_subscribers: {}, number_of_listeners: {}, number_of_timers: {}, dispatch_only_highest_priority: {} }}",
+            core::any::type_name::<Service>(),
+            self.sample_dispatchers.len(),
+            self.listeners.len(),
+            self.timers.len(),
+            self.dispatch_only_highest_priority
+        )
+    }
+}
+
+impl<'a, Service: service::Service> Default for Callbacks<'a, Service> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Service: service::Service> Callbacks<'a, Service> {
+    /// Creates an empty set of callbacks.
+    pub fn new() -> Self {
+        Self {
+            sample_dispatchers: vec![],
+            listeners: vec![],
+            timers: vec![],
+            priority: DEFAULT_PRIORITY,
+            dispatch_only_highest_priority: false,
+            has_deferred_callbacks: false,
+        }
+    }
+
+    /// Sets the priority of all callbacks that are registered afterwards. When multiple
+    /// callbacks are ready in the same cycle, the ones with a higher priority are called first.
+    /// It allows control-path events to preempt the processing of bulk data.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::node::event_loop::{Callbacks, CallbackProgression};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let bulk_data = node.service_builder("BulkData".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    /// let control = node.service_builder("Control".try_into()?)
+    ///     .event()
+    ///     .open_or_create()?;
+    /// let subscriber = bulk_data.subscriber_builder().create()?;
+    /// let listener = control.listener_builder().create()?;
+    ///
+    /// let mut callbacks = Callbacks::new()
+    ///     .on_sample(&subscriber, |_| CallbackProgression::Continue)
+    ///     .priority(10)
+    ///     .on_any_event(&listener, |_| CallbackProgression::Stop);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn priority(mut self, value: u8) -> Self {
+        self.priority = value;
+        self
+    }
+
+    /// When enabled only the ready callbacks with the highest priority are called in a cycle.
+    /// The remaining callbacks stay ready and are called in one of the next cycles, without
+    /// waiting for the cycle time in between.
+    pub fn dispatch_only_highest_priority(mut self, value: bool) -> Self {
+        self.dispatch_only_highest_priority = value;
+        self
+    }
+
+    /// Calls the callback for every [`Sample`] the [`Subscriber`] receives.
+    pub fn on_sample<PayloadType: Debug, UserHeader: Debug, F>(
+        mut self,
+        subscriber: &'a Subscriber<Service, PayloadType, UserHeader>,
+        mut callback: F,
+    ) -> Self
+    where
+        F: FnMut(Sample<PayloadType, Service, UserHeader>) -> CallbackProgression + 'a,
+    {
+        self.sample_dispatchers.push(SampleEntry {
+            priority: self.priority,
+            has_samples: Box::new(move || {
+                subscriber
+                    .has_samples()
+                    .map_err(|e| SubscriberReceiveError::ConnectionFailure(e).into())
+            }),
+            sample_arrival: Box::new(move || Ok(subscriber.sample_arrival_listener()?.listener())),
+            hold_back: Box::new(move || subscriber.remaining_hold_back()),
+            dispatcher: Box::new(move || {
+                while let Some(sample) = subscriber.receive()? {
+                    if callback(sample) == CallbackProgression::Stop {
+                        return Ok(CallbackProgression::Stop);
+                    }
+                }
+                Ok(CallbackProgression::Continue)
+            }),
+        });
+        self
+    }
+
+    /// Calls the callback whenever the [`Listener`] receives the provided [`EventId`].
+    pub fn on_event<F: FnMut(EventId) -> CallbackProgression + 'a>(
+        self,
+        listener: &'a Listener<Service>,
+        event_id: EventId,
+        callback: F,
+    ) -> Self {
+        self.add_event_callback(listener, Some(event_id), Box::new(callback))
+    }
+
+    /// Calls the callback for every [`EventId`] the [`Listener`] receives.
+    pub fn on_any_event<F: FnMut(EventId) -> CallbackProgression + 'a>(
+        self,
+        listener: &'a Listener<Service>,
+        callback: F,
+    ) -> Self {
+        self.add_event_callback(listener, None, Box::new(callback))
+    }
+
+    /// Calls the callback periodically with the provided interval. The precision of the timer
+    /// is limited by the time the other callbacks require.
+    pub fn on_timer<F: FnMut() -> CallbackProgression + 'a>(
+        mut self,
+        interval: Duration,
+        callback: F,
+    ) -> Self {
+        self.timers.push(Timer {
+            priority: self.priority,
+            interval,
+            next_expiration: Instant::now() + interval,
+            callback: Box::new(callback),
+        });
+        self
+    }
+
+    fn add_event_callback(
+        mut self,
+        listener: &'a Listener<Service>,
+        event_id: Option<EventId>,
+        callback: EventCallback<'a>,
+    ) -> Self {
+        let entry = EventEntry {
+            priority: self.priority,
+            event_id,
+            pending_event_ids: vec![],
+            callback,
+        };
+
+        match self
+            .listeners
+            .iter_mut()
+            .find(|entry| core::ptr::eq(entry.listener, listener))
+        {
+            Some(listener_entry) => listener_entry.callbacks.push(entry),
+            None => self.listeners.push(ListenerEntry {
+                listener,
+                callbacks: vec![entry],
+            }),
+        }
+        self
+    }
+
+    fn collect_active_callbacks(
+        &mut self,
+        now: Instant,
+    ) -> Result<Vec<(u8, ActiveCallback)>, NodeRunFailure> {
+        let mut active_callbacks = vec![];
+        for (n, entry) in self.sample_dispatchers.iter().enumerate() {
+            if (entry.has_samples)()? {
+                active_callbacks.push((entry.priority, ActiveCallback::Sample(n)));
+            }
+        }
+
+        for (n, entry) in self.listeners.iter_mut().enumerate() {
+            let callbacks = &mut entry.callbacks;
+            entry
+                .listener
+                .try_wait_all(|id| EventEntry::add_pending_event_ids(callbacks, id))?;
+
+            for (k, callback) in entry.callbacks.iter().enumerate() {
+                if !callback.pending_event_ids.is_empty() {
+                    active_callbacks.push((callback.priority, ActiveCallback::Event(n, k)));
+                }
+            }
+        }
+
+        for (n, timer) in self.timers.iter().enumerate() {
+            if timer.next_expiration <= now {
+                active_callbacks.push((timer.priority, ActiveCallback::Timer(n)));
+            }
+        }
+
+        // stable sort, callbacks with the same priority keep their registration order
+        active_callbacks.sort_by_key(|(priority, _)| core::cmp::Reverse(*priority));
+        Ok(active_callbacks)
+    }
+
+    fn call(
+        &mut self,
+        active_callback: ActiveCallback,
+        now: Instant,
+    ) -> Result<CallbackProgression, NodeRunFailure> {
+        match active_callback {
+            ActiveCallback::Sample(n) => (self.sample_dispatchers[n].dispatcher)(),
+            ActiveCallback::Event(n, k) => {
+                let entry = &mut self.listeners[n].callbacks[k];
+                while !entry.pending_event_ids.is_empty() {
+                    let id = entry.pending_event_ids.remove(0);
+                    if (entry.callback)(id) == CallbackProgression::Stop {
+                        return Ok(CallbackProgression::Stop);
+                    }
+                }
+                Ok(CallbackProgression::Continue)
+            }
+            ActiveCallback::Timer(n) => {
+                let timer = &mut self.timers[n];
+                timer.next_expiration = now + timer.interval;
+                Ok((timer.callback)())
+            }
+        }
+    }
+
+    pub(crate) fn dispatch(&mut self) -> Result<CallbackProgression, NodeRunFailure> {
+        let now = Instant::now();
+        let active_callbacks = self.collect_active_callbacks(now)?;
+        let highest_priority = active_callbacks.first().map(|(priority, _)| *priority);
+
+        self.has_deferred_callbacks = false;
+        for (priority, active_callback) in active_callbacks {
+            if self.dispatch_only_highest_priority && Some(priority) != highest_priority {
+                self.has_deferred_callbacks = true;
+                break;
+            }
+
+            if self.call(active_callback, now)? == CallbackProgression::Stop {
+                return Ok(CallbackProgression::Stop);
+            }
+        }
+
+        Ok(CallbackProgression::Continue)
+    }
+
+    /// Returns the time until the next cycle shall start, it is at most `cycle_time`.
+    pub(crate) fn time_until_next_cycle(&self, cycle_time: Duration) -> Duration {
+        if self.has_deferred_callbacks {
+            return Duration::ZERO;
+        }
+
+        let now = Instant::now();
+        self.timers
+            .iter()
+            .map(|timer| timer.next_expiration.saturating_duration_since(now))
+            .chain(
+                self.sample_dispatchers
+                    .iter()
+                    .flat_map(|entry| (entry.hold_back)()),
+            )
+            .fold(cycle_time, Duration::min)
+    }
+
+    /// Creates the [`CallbackWaitSet`] on which [`Callbacks::wait()`] blocks.
+    pub(crate) fn create_waitset(&self) -> Result<CallbackWaitSet<'a, Service>, NodeRunFailure> {
+        let mut waitset = WaitSet::new();
+        let mut listener_entries = vec![];
+
+        for (n, entry) in self.listeners.iter().enumerate() {
+            let priority = entry
+                .callbacks
+                .iter()
+                .map(|callback| callback.priority)
+                .max()
+                .unwrap_or(DEFAULT_PRIORITY);
+            // every listener has exactly one entry, therefore it cannot be attached twice
+            if let Ok(id) = waitset.attach_with_priority(entry.listener, priority) {
+                listener_entries.push((id, n));
+            }
+        }
+
+        for entry in &self.sample_dispatchers {
+            // a subscriber with multiple callbacks shares one sample arrival listener and is
+            // already attached
+            let _ = waitset.attach_with_priority((entry.sample_arrival)()?, entry.priority);
+        }
+
+        Ok(CallbackWaitSet {
+            waitset,
+            listener_entries,
+        })
+    }
+
+    /// Blocks on the [`CallbackWaitSet`] until a [`Listener`] received an [`EventId`], a
+    /// [`Subscriber`] received a [`Sample`] or the next cycle shall start, see
+    /// [`Callbacks::time_until_next_cycle()`]. The received [`EventId`]s are handed to the
+    /// callbacks in the next [`Callbacks::dispatch()`].
+    pub(crate) fn wait(
+        &mut self,
+        waitset: &CallbackWaitSet<'a, Service>,
+        cycle_time: Duration,
+    ) -> Result<(), WaitSetWaitError> {
+        let timeout = self.time_until_next_cycle(cycle_time);
+        let listeners = &mut self.listeners;
+
+        waitset.waitset.timed_wait(
+            |attachment_id, id| {
+                if let Some((_, n)) = waitset
+                    .listener_entries
+                    .iter()
+                    .find(|(listener_id, _)| *listener_id == attachment_id)
+                {
+                    EventEntry::add_pending_event_ids(&mut listeners[*n].callbacks, id);
+                }
+            },
+            timeout,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//! let publisher = service.publisher_builder().create()?;
+//! publisher.send_copy(1234)?;
+//!
+//! // other processes can acquire the same metrics with AliveNodeView::metrics()
+//! let metrics = node.metrics()?;
+//! println!("{}", metrics);
+//! assert_eq!(metrics.number_of_sent_samples(), 1);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::port::metrics::PublisherMetrics;
+
+/// A snapshot of the runtime metrics of all ports of a [`Node`](crate::node::Node). Acquired
+/// with [`Node::metrics()`](crate::node::Node::metrics) or, from another process, with
+/// [`AliveNodeView::metrics()`](crate::node::AliveNodeView::metrics).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct NodeMetrics {
+    publishers: Vec<PublisherMetrics>,
+}
+
+impl NodeMetrics {
+    pub(crate) fn new(publishers: Vec<PublisherMetrics>) -> Self {
+        Self { publishers }
+    }
+
+    /// Returns the [`PublisherMetrics`] of every [`Publisher`](crate::port::publisher::Publisher)
+    /// of the [`Node`](crate::node::Node).
+    pub fn publishers(&self) -> &[PublisherMetrics] {
+        &self.publishers
+    }
+
+    /// Returns the number of samples that were sent by all
+    /// [`Publisher`](crate::port::publisher::Publisher)s.
+    pub fn number_of_sent_samples(&self) -> u64 {
+        self.publishers
+            .iter()
+            .map(|p| p.number_of_sent_samples())
+            .sum()
+    }
+
+    /// Returns the number of samples that all [`Publisher`](crate::port::publisher::Publisher)s
+    /// dropped.
+    pub fn number_of_dropped_samples(&self) -> u64 {
+        self.publishers
+            .iter()
+            .map(|p| p.number_of_dropped_samples())
+            .sum()
+    }
+
+    /// Returns the number of failed loans of all
+    /// [`Publisher`](crate::port::publisher::Publisher)s.
+    pub fn number_of_loan_failures(&self) -> u64 {
+        self.publishers
+            .iter()
+            .map(|p| p.number_of_loan_failures())
+            .sum()
+    }
+
+    /// Returns the number of samples that are currently in use in the data segments of all
+    /// [`Publisher`](crate::port::publisher::Publisher)s.
+    pub fn number_of_samples_in_use(&self) -> usize {
+        self.publishers
+            .iter()
+            .map(|p| p.number_of_samples_in_use())
+            .sum()
+    }
+}
+
+impl std::fmt::Display for NodeMetrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} publishers, {} sent samples, {} dropped samples, {} loan failures, {} samples in use",
+            self.publishers.len(),
+            self.number_of_sent_samples(),
+            self.number_of_dropped_samples(),
+            self.number_of_loan_failures(),
+            self.number_of_samples_in_use()
+        )
+    }
+}
@@ -61,36 +61,104 @@
 //!         view.remove_stale_resources()?;
 //!     }
 //! }
+//!
+//! // or all at once
+//! let cleanup_state = Node::<zero_copy::Service>::cleanup_dead_nodes(Config::get_global_config());
+//! println!("cleaned up {} dead nodes", cleanup_state.cleanups);
 //! # Ok(())
 //! # }
 //! ```
 
+/// Detection of crashed processes and cleanup of the stale resources of their [`Node`]s.
+pub mod dead_node_monitor;
+
+/// Callbacks that are driven by [`Node::run()`].
+pub mod event_loop;
+
+/// Runtime metrics of the ports of a node that are stored in shared memory.
+pub mod metrics;
+
 /// The name for a node.
 pub mod node_name;
 
+/// Accounting of the resources held by a node.
+pub mod resource_usage;
+
 #[doc(hidden)]
 pub mod testing;
 
+use crate::node::dead_node_monitor::{DeadNodeMonitor, DeadNodeMonitorCreateError};
+use crate::node::event_loop::{CallbackProgression, Callbacks, NodeRunEvent, NodeRunFailure};
+use crate::node::metrics::NodeMetrics;
 use crate::node::node_name::NodeName;
+use crate::node::resource_usage::{NodeResourceCounters, NodeResourceUsage};
+use crate::port::dead_letter::DeadLetterPublisher;
+use crate::port::waitset::WaitSetWaitError;
 use crate::service;
-use crate::service::builder::Builder;
-use crate::service::config_scheme::{node_details_path, node_monitoring_config};
+use crate::service::builder::{wait_for_service, Builder};
+use crate::service::config_scheme::{
+    connection_config, data_segment_config, dynamic_config_storage_config, event_config,
+    node_details_path, node_monitoring_config, static_config_storage_config,
+};
+use crate::service::dynamic_config::{
+    DecrementReferenceCounterResult, DynamicConfig, StalePortResource,
+};
+use crate::service::naming_scheme::{
+    connection_name, data_segment_name, event_concept_name, service_tag_name,
+    static_config_storage_name, uuid_from_service_tag_name,
+};
 use crate::service::service_name::ServiceName;
+use crate::service::ServiceDoesExistError;
 use crate::{config::Config, service::config_scheme::node_details_config};
+use core::time::Duration;
 use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::clock::{nanosleep, NanosleepError};
+use iceoryx2_bb_posix::signal::SignalHandler;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_cal::dynamic_storage::{
+    DynamicStorage, DynamicStorageBuilder, DynamicStorageOpenError,
+};
 use iceoryx2_cal::named_concept::{NamedConceptPathHintRemoveError, NamedConceptRemoveError};
 use iceoryx2_cal::{
     monitoring::*, named_concept::NamedConceptListError, serialize::*, static_storage::*,
 };
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
 use std::cell::UnsafeCell;
+use std::collections::{BTreeMap, BTreeSet};
 use std::marker::PhantomData;
-use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex, Weak};
+
+// the monitoring cannot detect a node as alive from within the process that owns it, therefore
+// the ids of all alive nodes of this process are tracked
+static ALIVE_NODES_OF_THIS_PROCESS: Mutex<BTreeSet<u128>> = Mutex::new(BTreeSet::new());
+
+fn set_alive_in_this_process(id: UniqueSystemId, is_alive: bool) {
+    let mut nodes = ALIVE_NODES_OF_THIS_PROCESS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    if is_alive {
+        nodes.insert(id.value());
+    } else {
+        nodes.remove(&id.value());
+    }
+}
+
+fn is_alive_in_this_process(id: UniqueSystemId) -> bool {
+    ALIVE_NODES_OF_THIS_PROCESS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .contains(&id.value())
+}
 
 /// The failures that can occur when a [`Node`] is created with the [`NodeBuilder`].
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum NodeCreationFailure {
     InsufficientPermissions,
+    /// The [`Node`] that shall be reattached with [`NodeBuilder::reattach()`] is still alive.
+    NodeStillAlive,
+    /// The [`Node`] would exceed [`Node::max_nodes`](crate::config::Node::max_nodes).
+    ExceedsMaxNumberOfNodes,
     InternalError,
 }
 
@@ -135,6 +203,45 @@ impl std::fmt::Display for NodeCleanupFailure {
 
 impl std::error::Error for NodeCleanupFailure {}
 
+/// Failures of [`Node::metrics()`] and [`AliveNodeView::metrics()`] that occur when the
+/// metrics of a [`Node`] are acquired.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NodeMetricsFailure {
+    /// The [`NodeDetails`] of the [`Node`] are not available, see [`NodeView::details()`].
+    NodeDetailsUnavailable,
+    InsufficientPermissions,
+    InternalError,
+}
+
+impl std::fmt::Display for NodeMetricsFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "NodeMetricsFailure::{:?}", self)
+    }
+}
+
+impl std::error::Error for NodeMetricsFailure {}
+
+/// Returned by [`Node::cleanup_dead_nodes()`]. Contains the number of dead [`Node`]s whose
+/// stale resources were removed and the number of dead [`Node`]s that could not be cleaned up.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct CleanupState {
+    /// The number of dead [`Node`]s that were cleaned up.
+    pub cleanups: usize,
+    /// The number of dead [`Node`]s whose stale resources could not be removed.
+    pub failed_cleanups: usize,
+}
+
+/// The events that can occur while waiting in the main loop of a process, see [`Node::wait()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NodeEvent {
+    /// The `cycle_time` has passed.
+    Tick,
+    /// A termination signal, for instance CTRL+c, was received.
+    TerminationRequest,
+    /// The wait was interrupted by a signal that does not request the termination.
+    InterruptSignal,
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 enum NodeReadStorageFailure {
     ReadError,
@@ -197,6 +304,20 @@ impl<Service: service::Service> NodeView for AliveNodeView<Service> {
     }
 }
 
+impl<Service: service::Service> AliveNodeView<Service> {
+    /// Returns the [`NodeMetrics`] of the [`Node`]. Since the metrics are stored in shared
+    /// memory they can be acquired from any process that has sufficient access permissions.
+    pub fn metrics(&self) -> Result<NodeMetrics, NodeMetricsFailure> {
+        match &self.details {
+            Some(details) => acquire_node_metrics::<Service>(self.id, details.config()),
+            None => {
+                fail!(from self, with NodeMetricsFailure::NodeDetailsUnavailable,
+                    "Unable to acquire the metrics since the node details are not available.");
+            }
+        }
+    }
+}
+
 /// All the informations and management operations belonging to a dead [`Node`].
 #[derive(Debug, Clone)]
 pub struct DeadNodeView<Service: service::Service>(AliveNodeView<Service>);
@@ -271,6 +392,64 @@ fn acquire_all_node_detail_storages<Service: service::Service>(
     }
 }
 
+fn acquire_node_metrics<Service: service::Service>(
+    id: UniqueSystemId,
+    config: &Config,
+) -> Result<NodeMetrics, NodeMetricsFailure> {
+    let origin = format!(
+        "acquire_node_metrics<{}>({:?})",
+        core::any::type_name::<Service>(),
+        id
+    );
+    let msg = "Unable to acquire node metrics";
+    let monitor_name = fatal_panic!(from origin, when FileName::new(id.value().to_string().as_bytes()),
+                                "This should never happen! {msg} since the UniqueSystemId is not a valid file name.");
+
+    let details_config = node_details_config::<Service>(config, &monitor_name);
+    let detail_storages = <Service::StaticStorage as NamedConceptMgmt>::list_cfg(&details_config);
+    let detail_storages = match detail_storages {
+        Ok(v) => v,
+        Err(NamedConceptListError::InsufficientPermissions) => {
+            fail!(from origin, with NodeMetricsFailure::InsufficientPermissions,
+                "{} since the node details could not be listed due to insufficient permissions.", msg);
+        }
+        Err(NamedConceptListError::InternalError) => {
+            fail!(from origin, with NodeMetricsFailure::InternalError,
+                "{} since the node details could not be listed due to an internal error.", msg);
+        }
+    };
+
+    // a service is tagged once for every service state of the node
+    let uuids: BTreeSet<String> = detail_storages
+        .iter()
+        .filter_map(uuid_from_service_tag_name)
+        .collect();
+
+    let mut publishers = vec![];
+    for uuid in uuids {
+        let dynamic_storage = match <<Service::DynamicStorage as DynamicStorage<DynamicConfig>>::Builder<'_> as NamedConceptBuilder<
+            Service::DynamicStorage,
+        >>::new(&static_config_storage_name(&uuid))
+        .config(&dynamic_config_storage_config::<Service>(config))
+        .has_ownership(false)
+        .open()
+        {
+            Ok(storage) => storage,
+            // the service was removed or is not yet created, it has no ports of the node
+            Err(DynamicStorageOpenError::DoesNotExist)
+            | Err(DynamicStorageOpenError::InitializationNotYetFinalized) => continue,
+            Err(e) => {
+                fail!(from origin, with NodeMetricsFailure::InternalError,
+                    "{} since the dynamic config of the service {} could not be opened ({:?}).", msg, uuid, e);
+            }
+        };
+
+        publishers.append(&mut dynamic_storage.get().publisher_metrics_of_node(&id));
+    }
+
+    Ok(NodeMetrics::new(publishers))
+}
+
 fn remove_detail_storages<Service: service::Service>(
     origin: &str,
     storages: Vec<FileName>,
@@ -317,6 +496,107 @@ fn remove_node_details_directory<Service: service::Service>(
     }
 }
 
+fn release_tagged_services<Service: service::Service>(
+    origin: &str,
+    id: UniqueSystemId,
+    config: &Config,
+    detail_storages: &[FileName],
+) -> Result<(), NodeCleanupFailure> {
+    // every service state of the node has its own tag and holds one reference to the service
+    let mut service_references = BTreeMap::<String, usize>::new();
+    for uuid in detail_storages
+        .iter()
+        .filter_map(uuid_from_service_tag_name)
+    {
+        *service_references.entry(uuid).or_default() += 1;
+    }
+
+    for (uuid, number_of_references) in service_references {
+        release_service::<Service>(origin, id, config, &uuid, number_of_references)?;
+    }
+
+    Ok(())
+}
+
+fn release_service<Service: service::Service>(
+    origin: &str,
+    id: UniqueSystemId,
+    config: &Config,
+    uuid: &str,
+    number_of_references: usize,
+) -> Result<(), NodeCleanupFailure> {
+    let msg = "Unable to release service";
+    // the dynamic config storage is named like the static config storage after the service uuid
+    let storage_name = static_config_storage_name(uuid);
+    let dynamic_storage = match <<Service::DynamicStorage as DynamicStorage<DynamicConfig>>::Builder<'_> as NamedConceptBuilder<
+        Service::DynamicStorage,
+    >>::new(&storage_name)
+    .config(&dynamic_config_storage_config::<Service>(config))
+    .has_ownership(false)
+    .open()
+    {
+        Ok(storage) => storage,
+        Err(DynamicStorageOpenError::DoesNotExist) => return Ok(()),
+        Err(e) => {
+            fail!(from origin, with NodeCleanupFailure::InternalError,
+                "{} {} since its dynamic config could not be opened ({:?}).", msg, uuid, e);
+        }
+    };
+
+    dynamic_storage.get().remove_dead_node_id(&id, |resource| {
+        remove_stale_port_resource::<Service>(origin, config, resource)
+    });
+
+    for _ in 0..number_of_references {
+        if dynamic_storage.get().decrement_reference_counter()
+            == DecrementReferenceCounterResult::NoMoreOwners
+        {
+            warn!(from origin, when unsafe {
+                <Service::StaticStorage as NamedConceptMgmt>::remove_cfg(
+                    &storage_name,
+                    &static_config_storage_config::<Service>(config),
+                ) },
+                "{} {} since its static config could not be removed.", msg, uuid);
+            dynamic_storage.acquire_ownership();
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+fn remove_stale_port_resource<Service: service::Service>(
+    origin: &str,
+    config: &Config,
+    resource: StalePortResource,
+) {
+    let msg = "Unable to remove stale port resource";
+    let result = match resource {
+        StalePortResource::DataSegment(publisher_id, segment_id) => unsafe {
+            <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
+                &data_segment_name(publisher_id, segment_id),
+                &data_segment_config::<Service>(config),
+            )
+        },
+        StalePortResource::Connection(publisher_id, subscriber_id) => unsafe {
+            <Service::Connection as NamedConceptMgmt>::remove_cfg(
+                &connection_name(publisher_id, subscriber_id),
+                &connection_config::<Service>(config),
+            )
+        },
+        StalePortResource::Event(listener_id) => unsafe {
+            <Service::Event as NamedConceptMgmt>::remove_cfg(
+                &event_concept_name(&listener_id),
+                &event_config::<Service>(config),
+            )
+        },
+    };
+
+    if let Err(e) = result {
+        warn!(from origin, "{} {:?} ({:?}).", msg, resource, e);
+    }
+}
+
 fn remove_node<Service: service::Service>(
     id: UniqueSystemId,
     details: &NodeDetails,
@@ -332,6 +612,7 @@ fn remove_node<Service: service::Service>(
 
     let details_config = node_details_config::<Service>(&details.config, &monitor_name);
     let detail_storages = acquire_all_node_detail_storages::<Service>(&origin, &details_config)?;
+    release_tagged_services::<Service>(&origin, id, details.config(), &detail_storages)?;
     remove_detail_storages::<Service>(&origin, detail_storages, &details_config)?;
     remove_node_details_directory::<Service>(details.config(), &monitor_name)?;
 
@@ -342,7 +623,11 @@ fn remove_node<Service: service::Service>(
 pub(crate) struct SharedNode<Service: service::Service> {
     id: UniqueSystemId,
     details: NodeDetails,
+    service_prefix: Option<ServiceName>,
     monitoring_token: UnsafeCell<Option<<Service::Monitoring as Monitoring>::Token>>,
+    shared_memory_usage: IoxAtomicUsize,
+    resources: NodeResourceCounters,
+    dead_letter_publishers: Mutex<Vec<Weak<DeadLetterPublisher<Service>>>>,
     _details_storage: Service::StaticStorage,
 }
 
@@ -350,10 +635,93 @@ impl<Service: service::Service> SharedNode<Service> {
     pub(crate) fn config(&self) -> &Config {
         &self.details.config
     }
+
+    pub(crate) fn id(&self) -> &UniqueSystemId {
+        &self.id
+    }
+
+    /// The dead-letter publishers of the node. They are shared by all publishers of the node
+    /// that route their lost samples into the same dead-letter service.
+    pub(crate) fn dead_letter_publishers(&self) -> &Mutex<Vec<Weak<DeadLetterPublisher<Service>>>> {
+        &self.dead_letter_publishers
+    }
+
+    /// Tags the service with the provided uuid as used by the node so that the service can be
+    /// released when the node dies. The tag is removed when the returned storage goes out of
+    /// scope.
+    pub(crate) fn create_service_tag(&self, uuid: &str) -> Option<Service::StaticStorage> {
+        let msg = "Unable to create service tag";
+        let tag_id = match UniqueSystemId::new() {
+            Ok(tag_id) => tag_id,
+            Err(e) => {
+                warn!(from self, "{} for {} since no unique id could be generated ({:?}). The service cannot be released when the node dies.", msg, uuid, e);
+                return None;
+            }
+        };
+
+        let monitor_name = fatal_panic!(from self, when FileName::new(self.id.value().to_string().as_bytes()),
+                                "This should never happen! {msg} since the UniqueSystemId is not a valid file name.");
+        match <Service::StaticStorage as StaticStorage>::Builder::new(&service_tag_name(
+            uuid, &tag_id,
+        ))
+        .config(&node_details_config::<Service>(
+            self.config(),
+            &monitor_name,
+        ))
+        .has_ownership(true)
+        .create(uuid.as_bytes())
+        {
+            Ok(tag) => Some(tag),
+            Err(e) => {
+                warn!(from self, "{} for {} ({:?}). The service cannot be released when the node dies.", msg, uuid, e);
+                None
+            }
+        }
+    }
+
+    /// Reserves `size` bytes of the shared memory quota of the node. Returns the bytes that
+    /// are already in use when the reservation would exceed the quota.
+    pub(crate) fn reserve_shared_memory(
+        self: &Arc<Self>,
+        size: usize,
+    ) -> Result<SharedMemoryReservation<Service>, usize> {
+        let quota = self.config().global.node.shared_memory_quota;
+        match self
+            .shared_memory_usage
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                match used.checked_add(size) {
+                    Some(v) if quota == 0 || v <= quota => Some(v),
+                    _ => None,
+                }
+            }) {
+            Ok(_) => Ok(SharedMemoryReservation {
+                node: self.clone(),
+                size,
+            }),
+            Err(used) => Err(used),
+        }
+    }
+}
+
+/// Shared memory that is accounted to the quota of a [`Node`]. The memory is released from
+/// the quota when the [`SharedMemoryReservation`] goes out of scope.
+#[derive(Debug)]
+pub(crate) struct SharedMemoryReservation<Service: service::Service> {
+    node: Arc<SharedNode<Service>>,
+    size: usize,
+}
+
+impl<Service: service::Service> Drop for SharedMemoryReservation<Service> {
+    fn drop(&mut self) {
+        self.node
+            .shared_memory_usage
+            .fetch_sub(self.size, Ordering::Relaxed);
+    }
 }
 
 impl<Service: service::Service> Drop for SharedNode<Service> {
     fn drop(&mut self) {
+        set_alive_in_this_process(self.id, false);
         if self.monitoring_token.get_mut().is_some() {
             warn!(from self, when remove_node::<Service>(self.id, &self.details),
                 "Unable to remove node resources.");
@@ -391,8 +759,174 @@ impl<Service: service::Service> Node<Service> {
         &self.shared.id
     }
 
+    /// Returns the [`NodeResourceUsage`], the services, ports, connections, file descriptors
+    /// and shared memory that are currently held by the [`Node`] and all entities that were
+    /// created with it.
+    pub fn resource_usage(&self) -> NodeResourceUsage {
+        self.shared
+            .resources
+            .usage(self.shared.shared_memory_usage.load(Ordering::Relaxed))
+    }
+
+    /// Returns the [`NodeMetrics`], the runtime metrics of all ports that were created with
+    /// the [`Node`]. The metrics are stored in shared memory so that monitoring processes can
+    /// acquire them with [`AliveNodeView::metrics()`].
+    pub fn metrics(&self) -> Result<NodeMetrics, NodeMetricsFailure> {
+        acquire_node_metrics::<Service>(self.shared.id, self.config())
+    }
+
+    /// Returns the prefix that is prepended to the [`ServiceName`] of every
+    /// [`Service`](crate::service::Service) that is created or opened via the [`Node`], see
+    /// [`NodeBuilder::service_prefix()`].
+    pub fn service_prefix(&self) -> Option<&ServiceName> {
+        self.shared.service_prefix.as_ref()
+    }
+
     pub fn service_builder(&self, name: ServiceName) -> Builder<Service> {
-        Builder::new(name, self.shared.clone())
+        Builder::new(self.scoped_service_name(name), self.shared.clone())
+    }
+
+    fn scoped_service_name(&self, name: ServiceName) -> ServiceName {
+        match self.shared.service_prefix {
+            Some(ref prefix) => {
+                fatal_panic!(from self, when ServiceName::new(&format!("{}/{}", prefix, name)),
+                    "This should never happen! The prefixed service name of \"{}\" is not a valid service name.", name)
+            }
+            None => name,
+        }
+    }
+
+    /// Waits until a [`Service`](crate::service::Service) with the provided [`ServiceName`] was
+    /// created by another instance. Returns true when the service exists and false when it did
+    /// not appear within the provided timeout.
+    ///
+    /// ```no_run
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let service_name = ServiceName::new("My/Funk/ServiceName")?;
+    ///
+    /// if node.wait_for_service(&service_name, Duration::from_secs(5))? {
+    ///     let service = node
+    ///         .service_builder(service_name)
+    ///         .publish_subscribe::<u64>()
+    ///         .open()?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_for_service(
+        &self,
+        service_name: &ServiceName,
+        timeout: Duration,
+    ) -> Result<bool, ServiceDoesExistError> {
+        let service_name = &self.scoped_service_name(service_name.clone());
+        wait_for_service(self, service_name, timeout, || {
+            Service::does_exist(service_name, self.config())
+        })
+    }
+
+    /// Creates a [`DeadNodeMonitor`] that removes the stale resources of dead [`Node`]s and
+    /// notifies the [`Listener`](crate::port::listener::Listener)s of the event service with the
+    /// provided [`ServiceName`] whenever dead [`Node`]s were cleaned up.
+    pub fn dead_node_monitor(
+        &self,
+        service_name: ServiceName,
+    ) -> Result<DeadNodeMonitor<Service>, DeadNodeMonitorCreateError> {
+        DeadNodeMonitor::new(self, service_name)
+    }
+
+    /// Waits until an event was received. It returns [`NodeEvent::Tick`] when the `cycle_time`
+    /// has passed, otherwise the event that occurred. The first call installs the handlers for
+    /// SIGINT and SIGTERM so that the main loop can exit cleanly and the [`Node`] can release
+    /// its resources.
+    ///
+    /// ```no_run
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// const CYCLE_TIME: Duration = Duration::from_secs(1);
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    ///
+    /// loop {
+    ///     match node.wait(CYCLE_TIME) {
+    ///         NodeEvent::Tick => println!("entered next cycle"),
+    ///         NodeEvent::TerminationRequest => {
+    ///             println!("User pressed CTRL+c, terminating");
+    ///             break;
+    ///         }
+    ///         NodeEvent::InterruptSignal => println!("Someone send an interrupt signal ..."),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait(&self, cycle_time: Duration) -> NodeEvent {
+        if SignalHandler::termination_requested() {
+            return NodeEvent::TerminationRequest;
+        }
+
+        match nanosleep(cycle_time) {
+            Ok(()) => {
+                if SignalHandler::termination_requested() {
+                    NodeEvent::TerminationRequest
+                } else {
+                    NodeEvent::Tick
+                }
+            }
+            Err(NanosleepError::InterruptedBySignal(_)) => NodeEvent::InterruptSignal,
+            Err(v) => {
+                fatal_panic!(from self,
+                    "Failed to wait with cycle time {:?} in main event loop, caused by ({:?}).",
+                    cycle_time, v);
+            }
+        }
+    }
+
+    /// Drives the registered [`Callbacks`] until either a callback returns
+    /// [`CallbackProgression::Stop`], a termination request was received or the wait was
+    /// interrupted by a signal. All [`Listener`](crate::port::listener::Listener)s and
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s are attached to a
+    /// [`WaitSet`](crate::port::waitset::WaitSet), the [`Node`] blocks on it and calls the
+    /// callbacks as soon as an event or a sample arrives. Timers are called when they expired.
+    /// Without any activity the [`Node`] wakes up at least every `cycle_time`.
+    pub fn run(
+        &self,
+        callbacks: &mut Callbacks<'_, Service>,
+        cycle_time: Duration,
+    ) -> Result<NodeRunEvent, NodeRunFailure> {
+        let waitset = fail!(from self, when callbacks.create_waitset(),
+            "Failed to create the waitset of the node event loop.");
+
+        loop {
+            if fail!(from self, when callbacks.dispatch(),
+                "Failed to dispatch the callbacks in the node event loop.")
+                == CallbackProgression::Stop
+            {
+                return Ok(NodeRunEvent::Stopped);
+            }
+
+            if SignalHandler::termination_requested() {
+                return Ok(NodeRunEvent::TerminationRequest);
+            }
+
+            match callbacks.wait(&waitset, cycle_time) {
+                Ok(()) => (),
+                Err(WaitSetWaitError::InterruptSignal) => {
+                    if SignalHandler::termination_requested() {
+                        return Ok(NodeRunEvent::TerminationRequest);
+                    }
+                    return Ok(NodeRunEvent::InterruptSignal);
+                }
+                Err(e) => {
+                    fail!(from self, with e.into(),
+                        "Failed to wait for the callbacks in the node event loop ({:?}).", e);
+                }
+            }
+        }
     }
 
     /// Returns a list of [`NodeState`] of all [`Node`]s in the system under a given [`Config`].
@@ -418,6 +952,9 @@ impl<Service: service::Service> Node<Service> {
             match Self::get_node_state(&monitoring_config, node_name)? {
                 State::DoesNotExist => (),
                 State::Alive => nodes.push(NodeState::Alive(node_view)),
+                State::Dead if is_alive_in_this_process(node_view.id) => {
+                    nodes.push(NodeState::Alive(node_view))
+                }
                 State::Dead => nodes.push(NodeState::Dead(DeadNodeView(node_view))),
             };
         }
@@ -425,12 +962,72 @@ impl<Service: service::Service> Node<Service> {
         Ok(nodes)
     }
 
+    /// Removes the stale resources of all dead [`Node`]s under a given [`Config`]. The ports of
+    /// the dead [`Node`]s are removed from all services they were connected to, so that the
+    /// remaining ports reclaim the samples the dead [`Node`]s were holding, and the services
+    /// that were only kept alive by the dead [`Node`]s are removed.
+    pub fn cleanup_dead_nodes(config: &Config) -> CleanupState {
+        let mut cleanup_state = CleanupState::default();
+        let node_list = match Self::list(config) {
+            Ok(node_list) => node_list,
+            Err(e) => {
+                warn!(from "Node::cleanup_dead_nodes()",
+                    "Unable to cleanup dead nodes since the nodes could not be listed ({:?}).", e);
+                return cleanup_state;
+            }
+        };
+
+        for node_state in node_list {
+            if let NodeState::Dead(view) = node_state {
+                let id = *view.id();
+                match view.remove_stale_resources() {
+                    Ok(true) => cleanup_state.cleanups += 1,
+                    Ok(false) => (),
+                    Err(e) => {
+                        warn!(from "Node::cleanup_dead_nodes()",
+                            "Unable to cleanup the stale resources of the dead node {:?} ({:?}).", id, e);
+                        cleanup_state.failed_cleanups += 1;
+                    }
+                }
+            }
+        }
+
+        cleanup_state
+    }
+
+    // Returns true when the monitoring reports the node with the given id as dead. When the
+    // state cannot be acquired the node is considered alive, so that its resources are never
+    // reclaimed while they may still be in use.
+    pub(crate) fn is_dead(config: &Config, id: UniqueSystemId) -> bool {
+        if is_alive_in_this_process(id) {
+            return false;
+        }
+
+        let node_name = match FileName::new(id.value().to_string().as_bytes()) {
+            Ok(v) => v,
+            Err(_) => return false,
+        };
+
+        matches!(
+            Self::get_node_state(&node_monitoring_config::<Service>(config), &node_name),
+            Ok(State::Dead)
+        )
+    }
+
+    // Returns the details of the node with the given id, None when they are not available,
+    // for instance due to insufficient permissions or since the node no longer exists.
+    pub(crate) fn details_of(config: &Config, id: UniqueSystemId) -> Option<NodeDetails> {
+        let node_name = FileName::new(id.value().to_string().as_bytes()).ok()?;
+        Self::get_node_details(config, &node_name).ok().flatten()
+    }
+
     /// # Safety
     ///
     ///  * only for internal testing purposes
     ///  * shall be called at most once
     ///
     pub(crate) unsafe fn staged_death(&mut self) -> <Service::Monitoring as Monitoring>::Token {
+        set_alive_in_this_process(self.shared.id, false);
         (*self.shared.monitoring_token.get()).take().unwrap()
     }
 
@@ -598,6 +1195,9 @@ impl<Service: service::Service> Node<Service> {
 pub struct NodeBuilder {
     name: Option<NodeName>,
     config: Option<Config>,
+    domain: Option<FileName>,
+    reattach_id: Option<UniqueSystemId>,
+    service_prefix: Option<ServiceName>,
 }
 
 impl NodeBuilder {
@@ -619,35 +1219,221 @@ impl NodeBuilder {
         self
     }
 
+    /// Sets the domain of the [`Node`] and overrides the [`crate::config::Global::domain`] of
+    /// the config. The [`Node`] and all of its entities are isolated from the [`Node`]s and
+    /// [`crate::service::Service`]s of other domains.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new()
+    ///     .domain("my_domain".try_into()?)
+    ///     .create::<zero_copy::Service>()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn domain(mut self, value: FileName) -> Self {
+        self.domain = Some(value);
+        self
+    }
+
+    /// Sets a prefix that is transparently prepended, separated by a `/`, to the [`ServiceName`]
+    /// of every [`Service`](crate::service::Service) that is created or opened via the
+    /// [`Node`]. Multiple instances of the same application can be isolated from each other,
+    /// for instance in a multi-robot simulation, without changing the hard-coded service names.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new()
+    ///     .service_prefix("robot1".try_into()?)
+    ///     .create::<zero_copy::Service>()?;
+    ///
+    /// let service = node
+    ///     .service_builder("Pose".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    /// assert_eq!(service.name().as_str(), "robot1/Pose");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn service_prefix(mut self, value: ServiceName) -> Self {
+        self.service_prefix = Some(value);
+        self
+    }
+
+    /// Reattaches the [`Node`] to the [`UniqueSystemId`] of a previous [`Node`] that died, for
+    /// instance since its process crashed and was restarted. The stale resources of the dead
+    /// [`Node`] are removed and the new [`Node`] is created with the same [`UniqueSystemId`] so
+    /// that other processes see a restarted [`Node`] instead of a dead and an unrelated new one.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// // persist the id so that it is still available after a restart
+    /// let persisted_id = node.id().value();
+    /// drop(node);
+    ///
+    /// let node = NodeBuilder::new()
+    ///     .reattach(UniqueSystemId::from(persisted_id))
+    ///     .create::<zero_copy::Service>()?;
+    /// assert_eq!(node.id().value(), persisted_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reattach(mut self, id: UniqueSystemId) -> Self {
+        self.reattach_id = Some(id);
+        self
+    }
+
     /// Creates a new [`Node`] for a specific [`service::Service`]. All entities owned by the
     /// [`Node`] will have the same [`service::Service`].
     pub fn create<Service: service::Service>(self) -> Result<Node<Service>, NodeCreationFailure> {
         let msg = "Unable to create node";
-        let node_id = fail!(from self, when UniqueSystemId::new(),
-                                with NodeCreationFailure::InternalError,
-                                "{msg} since the unique node id could not be generated.");
-        let monitor_name = fatal_panic!(from self, when FileName::new(node_id.value().to_string().as_bytes()),
-                                "This should never happen! {msg} since the UniqueSystemId is not a valid file name.");
-        let config = if let Some(ref config) = self.config {
+        let mut config = if let Some(ref config) = self.config {
             config.clone()
         } else {
             Config::get_global_config().clone()
         };
+        if let Some(domain) = self.domain {
+            config.global.domain = Some(domain);
+        }
+        let node_id = match self.reattach_id {
+            Some(node_id) => node_id,
+            None => fail!(from self, when UniqueSystemId::new(),
+                                with NodeCreationFailure::InternalError,
+                                "{msg} since the unique node id could not be generated."),
+        };
+        let monitor_name = fatal_panic!(from self, when FileName::new(node_id.value().to_string().as_bytes()),
+                                "This should never happen! {msg} since the UniqueSystemId is not a valid file name.");
+
+        if self.reattach_id.is_some() {
+            self.remove_previous_node::<Service>(&config, node_id, &monitor_name)?;
+        }
+
+        self.verify_max_number_of_nodes::<Service>(&config)?;
 
         let (details_storage, details) =
             self.create_node_details_storage::<Service>(&config, &monitor_name)?;
         let monitoring_token = self.create_token::<Service>(&config, &monitor_name)?;
 
+        set_alive_in_this_process(node_id, true);
         Ok(Node {
             shared: Arc::new(SharedNode {
                 id: node_id,
                 monitoring_token: UnsafeCell::new(Some(monitoring_token)),
+                shared_memory_usage: IoxAtomicUsize::new(0),
+                resources: NodeResourceCounters::default(),
+                dead_letter_publishers: Mutex::new(Vec::new()),
                 _details_storage: details_storage,
                 details,
+                service_prefix: self.service_prefix,
             }),
         })
     }
 
+    fn verify_max_number_of_nodes<Service: service::Service>(
+        &self,
+        config: &Config,
+    ) -> Result<(), NodeCreationFailure> {
+        let max_nodes = config.global.node.max_nodes;
+        if max_nodes == 0 {
+            return Ok(());
+        }
+
+        let msg = "Unable to create node";
+        let nodes = fail!(from self, when Node::<Service>::list(config),
+                        with NodeCreationFailure::InternalError,
+                        "{} since the existing nodes could not be listed.", msg);
+        let number_of_alive_nodes = nodes
+            .iter()
+            .filter(|node| matches!(node, NodeState::Alive(_)))
+            .count();
+
+        if number_of_alive_nodes >= max_nodes {
+            fail!(from self, with NodeCreationFailure::ExceedsMaxNumberOfNodes,
+                "{} since already {} nodes are alive but at most {} are allowed.",
+                msg, number_of_alive_nodes, max_nodes);
+        }
+
+        Ok(())
+    }
+
+    fn remove_previous_node<Service: service::Service>(
+        &self,
+        config: &Config,
+        node_id: UniqueSystemId,
+        monitor_name: &FileName,
+    ) -> Result<(), NodeCreationFailure> {
+        let msg = "Unable to reattach to the previous node";
+        let monitoring_config = node_monitoring_config::<Service>(config);
+
+        if is_alive_in_this_process(node_id) {
+            fail!(from self, with NodeCreationFailure::NodeStillAlive,
+                "{msg} {:?} since the node is still alive in this process.", node_id);
+        }
+
+        match Node::<Service>::get_node_state(&monitoring_config, monitor_name) {
+            Ok(State::Alive) => {
+                fail!(from self, with NodeCreationFailure::NodeStillAlive,
+                    "{msg} {:?} since the node is still alive.", node_id);
+            }
+            Ok(State::DoesNotExist) => return Ok(()),
+            Ok(State::Dead) => (),
+            Err(NodeListFailure::InsufficientPermissions) => {
+                fail!(from self, with NodeCreationFailure::InsufficientPermissions,
+                    "{msg} {:?} due to insufficient permissions to acquire its state.", node_id);
+            }
+            Err(e) => {
+                fail!(from self, with NodeCreationFailure::InternalError,
+                    "{msg} {:?} since its state could not be acquired ({:?}).", node_id, e);
+            }
+        }
+
+        let _cleaner = match <Service::Monitoring as Monitoring>::Builder::new(monitor_name)
+            .config(&monitoring_config)
+            .cleaner()
+        {
+            Ok(cleaner) => cleaner,
+            // the previous node was already cleaned up, only the id is reused
+            Err(MonitoringCreateCleanerError::DoesNotExist) => return Ok(()),
+            Err(MonitoringCreateCleanerError::InstanceStillAlive) => {
+                fail!(from self, with NodeCreationFailure::NodeStillAlive,
+                    "{msg} {:?} since the node is still alive.", node_id);
+            }
+            Err(MonitoringCreateCleanerError::AlreadyOwnedByAnotherInstance) => {
+                fail!(from self, with NodeCreationFailure::InternalError,
+                    "{msg} {:?} since its stale resources are currently removed by another instance.", node_id);
+            }
+            Err(MonitoringCreateCleanerError::Interrupt)
+            | Err(MonitoringCreateCleanerError::InternalError) => {
+                fail!(from self, with NodeCreationFailure::InternalError,
+                    "{msg} {:?} since the monitoring cleaner could not be acquired.", node_id);
+            }
+        };
+
+        if let Ok(Some(details)) = Node::<Service>::get_node_details(config, monitor_name) {
+            match remove_node::<Service>(node_id, &details) {
+                Ok(_) => (),
+                Err(NodeCleanupFailure::InsufficientPermissions) => {
+                    fail!(from self, with NodeCreationFailure::InsufficientPermissions,
+                        "{msg} {:?} due to insufficient permissions to remove its stale resources.", node_id);
+                }
+                Err(e) => {
+                    fail!(from self, with NodeCreationFailure::InternalError,
+                        "{msg} {:?} since its stale resources could not be removed ({:?}).", node_id, e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     fn create_token<Service: service::Service>(
         &self,
         config: &Config,
@@ -0,0 +1,219 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//! let publisher = service.publisher_builder().create()?;
+//!
+//! let usage = node.resource_usage();
+//! println!("{}", usage);
+//! assert_eq!(usage.number_of_publishers(), 1);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+
+use super::SharedNode;
+use crate::service;
+
+/// The entities of a [`Node`](crate::node::Node) that are accounted in its
+/// [`NodeResourceUsage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NodeResource {
+    Service,
+    Publisher,
+    Subscriber,
+    Notifier,
+    Listener,
+    Connection,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct NodeResourceCounters {
+    services: IoxAtomicUsize,
+    publishers: IoxAtomicUsize,
+    subscribers: IoxAtomicUsize,
+    notifiers: IoxAtomicUsize,
+    listeners: IoxAtomicUsize,
+    connections: IoxAtomicUsize,
+    file_descriptors: IoxAtomicUsize,
+}
+
+impl NodeResourceCounters {
+    fn counter(&self, resource: NodeResource) -> &IoxAtomicUsize {
+        match resource {
+            NodeResource::Service => &self.services,
+            NodeResource::Publisher => &self.publishers,
+            NodeResource::Subscriber => &self.subscribers,
+            NodeResource::Notifier => &self.notifiers,
+            NodeResource::Listener => &self.listeners,
+            NodeResource::Connection => &self.connections,
+        }
+    }
+
+    pub(crate) fn usage(&self, shared_memory_bytes: usize) -> NodeResourceUsage {
+        NodeResourceUsage {
+            services: self.services.load(Ordering::Relaxed),
+            publishers: self.publishers.load(Ordering::Relaxed),
+            subscribers: self.subscribers.load(Ordering::Relaxed),
+            notifiers: self.notifiers.load(Ordering::Relaxed),
+            listeners: self.listeners.load(Ordering::Relaxed),
+            connections: self.connections.load(Ordering::Relaxed),
+            file_descriptors: self.file_descriptors.load(Ordering::Relaxed),
+            shared_memory_bytes,
+        }
+    }
+}
+
+/// A resource that is accounted to a [`Node`](crate::node::Node) as long as the
+/// [`NodeResourceRegistration`] exists.
+#[derive(Debug)]
+pub(crate) struct NodeResourceRegistration<Service: service::Service> {
+    node: Arc<SharedNode<Service>>,
+    resource: NodeResource,
+    file_descriptors: IoxAtomicUsize,
+}
+
+impl<Service: service::Service> NodeResourceRegistration<Service> {
+    pub(crate) fn new(
+        node: Arc<SharedNode<Service>>,
+        resource: NodeResource,
+        file_descriptors: usize,
+    ) -> Self {
+        node.resources
+            .counter(resource)
+            .fetch_add(1, Ordering::Relaxed);
+        node.resources
+            .file_descriptors
+            .fetch_add(file_descriptors, Ordering::Relaxed);
+
+        Self {
+            node,
+            resource,
+            file_descriptors: IoxAtomicUsize::new(file_descriptors),
+        }
+    }
+
+    /// Accounts additional file descriptors that the resource acquired after its creation.
+    pub(crate) fn add_file_descriptors(&self, value: usize) {
+        self.node
+            .resources
+            .file_descriptors
+            .fetch_add(value, Ordering::Relaxed);
+        self.file_descriptors.fetch_add(value, Ordering::Relaxed);
+    }
+}
+
+impl<Service: service::Service> Drop for NodeResourceRegistration<Service> {
+    fn drop(&mut self) {
+        self.node
+            .resources
+            .counter(self.resource)
+            .fetch_sub(1, Ordering::Relaxed);
+        self.node.resources.file_descriptors.fetch_sub(
+            self.file_descriptors.load(Ordering::Relaxed),
+            Ordering::Relaxed,
+        );
+    }
+}
+
+/// A snapshot of the resources that are currently held by a [`Node`](crate::node::Node) and
+/// all entities that were created with it. Acquired with
+/// [`Node::resource_usage()`](crate::node::Node::resource_usage).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NodeResourceUsage {
+    services: usize,
+    publishers: usize,
+    subscribers: usize,
+    notifiers: usize,
+    listeners: usize,
+    connections: usize,
+    file_descriptors: usize,
+    shared_memory_bytes: usize,
+}
+
+impl NodeResourceUsage {
+    /// Returns the number of opened [`Service`](crate::service::Service)s. Every
+    /// [`PortFactory`](crate::service::port_factory) counts as one open service.
+    pub fn number_of_services(&self) -> usize {
+        self.services
+    }
+
+    /// Returns the number of [`Publisher`](crate::port::publisher::Publisher)s.
+    pub fn number_of_publishers(&self) -> usize {
+        self.publishers
+    }
+
+    /// Returns the number of [`Subscriber`](crate::port::subscriber::Subscriber)s, including
+    /// the observers.
+    pub fn number_of_subscribers(&self) -> usize {
+        self.subscribers
+    }
+
+    /// Returns the number of [`Notifier`](crate::port::notifier::Notifier)s.
+    pub fn number_of_notifiers(&self) -> usize {
+        self.notifiers
+    }
+
+    /// Returns the number of [`Listener`](crate::port::listener::Listener)s.
+    pub fn number_of_listeners(&self) -> usize {
+        self.listeners
+    }
+
+    /// Returns the number of connections the ports have established to their counterparts.
+    pub fn number_of_connections(&self) -> usize {
+        self.connections
+    }
+
+    /// Returns the number of file descriptors that are held by the services, ports and
+    /// connections. Communication variants that are not based on file descriptors, like
+    /// [`process_local`](crate::service::process_local), report the handles they hold instead.
+    pub fn number_of_file_descriptors(&self) -> usize {
+        self.file_descriptors
+    }
+
+    /// Returns the size in bytes of the data segments of all
+    /// [`Publisher`](crate::port::publisher::Publisher)s, see
+    /// [`crate::config::Node::shared_memory_quota`].
+    pub fn shared_memory_bytes(&self) -> usize {
+        self.shared_memory_bytes
+    }
+}
+
+impl std::fmt::Display for NodeResourceUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} services, {} publishers, {} subscribers, {} notifiers, {} listeners, {} connections, {} file descriptors, {} bytes shared memory",
+            self.services,
+            self.publishers,
+            self.subscribers,
+            self.notifiers,
+            self.listeners,
+            self.connections,
+            self.file_descriptors,
+            self.shared_memory_bytes
+        )
+    }
+}
@@ -0,0 +1,205 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A [`crate::port::publisher::Publisher`] with enabled acknowledgments tracks which
+//! [`crate::port::subscriber::Subscriber`] has processed a [`crate::sample::Sample`]. A
+//! [`crate::port::subscriber::Subscriber`] acknowledges a [`crate::sample::Sample`] by releasing
+//! it, i.e. when the [`crate::sample::Sample`] goes out of scope.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher_builder()
+//!     .enable_acknowledgments(true)
+//!     .create()?;
+//! let subscriber = service.subscriber_builder().create()?;
+//!
+//! let sample = publisher.loan_uninit()?.write_payload(1234);
+//! let sample_id = sample.send_acknowledged()?;
+//!
+//! // processing the sample acknowledges it
+//! drop(subscriber.receive()?);
+//!
+//! if publisher.timed_wait_for_acknowledgments(sample_id, Duration::from_millis(10))? {
+//!     let report = publisher.acknowledgment_report(sample_id).unwrap();
+//!     println!("acknowledged by {:?}", report.acknowledged_by());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+
+use crate::port::port_identifiers::UniqueSubscriberId;
+
+/// The number of [`AcknowledgmentReport`]s a [`crate::port::publisher::Publisher`] keeps. When
+/// more [`crate::sample::Sample`]s are sent, the report of the oldest one is discarded.
+pub const MAX_ACKNOWLEDGMENT_REPORTS: usize = 1024;
+
+/// Identifies a [`crate::sample::Sample`] that was sent with
+/// [`crate::sample_mut::SampleMut::send_acknowledged()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SampleId(u64);
+
+impl SampleId {
+    /// Returns the underlying value of the [`SampleId`]. The values are increasing for every
+    /// [`crate::sample::Sample`] of the same [`crate::port::publisher::Publisher`].
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Describes the failures that can occur when a [`crate::port::publisher::Publisher`] waits for
+/// the acknowledgments of a [`crate::sample::Sample`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum AcknowledgmentWaitError {
+    /// The [`SampleId`] is not tracked, either the acknowledgments are not enabled or its
+    /// [`AcknowledgmentReport`] was already discarded.
+    UnknownSampleId,
+    InternalFailure,
+}
+
+impl std::fmt::Display for AcknowledgmentWaitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "AcknowledgmentWaitError::{:?}", self)
+    }
+}
+
+impl std::error::Error for AcknowledgmentWaitError {}
+
+/// Describes which [`crate::port::subscriber::Subscriber`]s have processed a
+/// [`crate::sample::Sample`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AcknowledgmentReport {
+    sample_id: SampleId,
+    acknowledged_by: Vec<UniqueSubscriberId>,
+    pending: Vec<UniqueSubscriberId>,
+    lost: Vec<UniqueSubscriberId>,
+}
+
+impl AcknowledgmentReport {
+    /// Returns the [`SampleId`] of the [`crate::sample::Sample`].
+    pub fn sample_id(&self) -> SampleId {
+        self.sample_id
+    }
+
+    /// Returns the [`crate::port::subscriber::Subscriber`]s that have received and released the
+    /// [`crate::sample::Sample`].
+    pub fn acknowledged_by(&self) -> &[UniqueSubscriberId] {
+        &self.acknowledged_by
+    }
+
+    /// Returns the [`crate::port::subscriber::Subscriber`]s that have the
+    /// [`crate::sample::Sample`] still in their buffer or are still holding it.
+    pub fn pending(&self) -> &[UniqueSubscriberId] {
+        &self.pending
+    }
+
+    /// Returns the [`crate::port::subscriber::Subscriber`]s that never processed the
+    /// [`crate::sample::Sample`] since it was removed from their buffer by an overflow or since
+    /// they were disconnected.
+    pub fn lost(&self) -> &[UniqueSubscriberId] {
+        &self.lost
+    }
+
+    /// Returns true when no acknowledgment is pending anymore.
+    pub fn is_complete(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct AcknowledgmentTracker {
+    next_sample_id: u64,
+    // the offset of the delivered chunk and the recipient of all unacknowledged deliveries
+    in_flight: Vec<(usize, UniqueSubscriberId, SampleId)>,
+    reports: VecDeque<AcknowledgmentReport>,
+}
+
+impl AcknowledgmentTracker {
+    pub(crate) fn begin_sample(&mut self) -> SampleId {
+        let sample_id = SampleId(self.next_sample_id);
+        self.next_sample_id += 1;
+
+        if self.reports.len() == MAX_ACKNOWLEDGMENT_REPORTS {
+            if let Some(oldest) = self.reports.pop_front() {
+                self.in_flight.retain(|(_, _, id)| *id != oldest.sample_id);
+            }
+        }
+
+        self.reports.push_back(AcknowledgmentReport {
+            sample_id,
+            acknowledged_by: vec![],
+            pending: vec![],
+            lost: vec![],
+        });
+        sample_id
+    }
+
+    pub(crate) fn last_sample_id(&self) -> Option<SampleId> {
+        self.reports.back().map(|report| report.sample_id)
+    }
+
+    pub(crate) fn add_recipient(&mut self, offset: usize, subscriber_id: UniqueSubscriberId) {
+        if let Some(report) = self.reports.back_mut() {
+            report.pending.push(subscriber_id);
+            self.in_flight
+                .push((offset, subscriber_id, report.sample_id));
+        }
+    }
+
+    pub(crate) fn acknowledge(&mut self, offset: usize, subscriber_id: UniqueSubscriberId) {
+        if let Some(report) = self.complete(offset, subscriber_id) {
+            report.acknowledged_by.push(subscriber_id);
+        }
+    }
+
+    pub(crate) fn lose(&mut self, offset: usize, subscriber_id: UniqueSubscriberId) {
+        if let Some(report) = self.complete(offset, subscriber_id) {
+            report.lost.push(subscriber_id);
+        }
+    }
+
+    pub(crate) fn report(&self, sample_id: SampleId) -> Option<&AcknowledgmentReport> {
+        self.reports
+            .iter()
+            .find(|report| report.sample_id == sample_id)
+    }
+
+    fn complete(
+        &mut self,
+        offset: usize,
+        subscriber_id: UniqueSubscriberId,
+    ) -> Option<&mut AcknowledgmentReport> {
+        let index = self
+            .in_flight
+            .iter()
+            .position(|(o, s, _)| *o == offset && *s == subscriber_id)?;
+        let (_, _, sample_id) = self.in_flight.swap_remove(index);
+
+        let report = self
+            .reports
+            .iter_mut()
+            .find(|report| report.sample_id == sample_id)?;
+        report.pending.retain(|s| *s != subscriber_id);
+        Some(report)
+    }
+}
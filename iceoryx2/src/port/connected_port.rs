@@ -0,0 +1,86 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Describes the ports a [`Publisher`](crate::port::publisher::Publisher) or
+//! [`Subscriber`](crate::port::subscriber::Subscriber) is connected to. Helps to debug
+//! partially connected systems.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new()
+//!     .name("my_node".try_into()?)
+//!     .create::<zero_copy::Service>()?;
+//! let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//! let publisher = service.publisher_builder().create()?;
+//! let subscriber = service.subscriber_builder().create()?;
+//!
+//! for port in subscriber.connected_publishers() {
+//!     println!("publisher {:?} of node {:?}", port.port_id(), port.node_name());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+
+use crate::node::node_name::NodeName;
+use crate::node::Node;
+use crate::service;
+
+/// A port that is connected to a [`Publisher`](crate::port::publisher::Publisher) or
+/// [`Subscriber`](crate::port::subscriber::Subscriber), acquired with
+/// [`Subscriber::connected_publishers()`](crate::port::subscriber::Subscriber::connected_publishers())
+/// or
+/// [`Publisher::connected_subscribers()`](crate::port::publisher::Publisher::connected_subscribers()).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectedPort<PortId> {
+    port_id: PortId,
+    node_id: UniqueSystemId,
+    node_name: Option<NodeName>,
+}
+
+impl<PortId: Copy> ConnectedPort<PortId> {
+    pub(crate) fn new<Service: service::Service>(
+        config: &crate::config::Config,
+        port_id: PortId,
+        node_id: UniqueSystemId,
+    ) -> Self {
+        Self {
+            port_id,
+            node_id,
+            node_name: Node::<Service>::details_of(config, node_id).map(|d| d.name().clone()),
+        }
+    }
+
+    /// Returns the unique id of the connected port.
+    pub fn port_id(&self) -> PortId {
+        self.port_id
+    }
+
+    /// Returns the [`UniqueSystemId`] of the [`Node`] that owns the connected port.
+    pub fn node_id(&self) -> &UniqueSystemId {
+        &self.node_id
+    }
+
+    /// Returns the [`NodeName`] of the [`Node`] that owns the connected port. Returns [`None`]
+    /// when the details of the [`Node`] cannot be read, for instance due to insufficient
+    /// permissions.
+    pub fn node_name(&self) -> Option<&NodeName> {
+        self.node_name.as_ref()
+    }
+}
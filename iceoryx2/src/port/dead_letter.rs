@@ -0,0 +1,295 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::dead_letter::DeadLetter;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .dead_letter_service("My/Funk/DeadLetters".try_into()?, true)
+//!     .open_or_create()?;
+//!
+//! let dead_letters = node.service_builder("My/Funk/DeadLetters".try_into()?)
+//!     .publish_subscribe::<[u8]>()
+//!     .open_or_create()?;
+//! let auditor = dead_letters.subscriber_builder().create()?;
+//!
+//! let publisher = service.publisher_builder().create()?;
+//! publisher.send_copy(1234)?;
+//!
+//! while let Some(sample) = auditor.receive()? {
+//!     if let Some((letter, payload)) = DeadLetter::from_bytes(sample.payload()) {
+//!         println!("lost sample: {:?} with payload {:?}", letter, payload);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+
+use crate::node::SharedNode;
+use crate::port::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+use crate::port::publisher::{Publisher, PublisherCreateError};
+use crate::service;
+use crate::service::builder;
+use crate::service::port_factory::publish_subscribe::PortFactory;
+use crate::service::port_factory::publisher::UnableToDeliverStrategy;
+use crate::service::service_name::ServiceName;
+
+/// Describes why a sample was routed into the dead-letter service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum DeadLetterReason {
+    /// The buffer of the [`Subscriber`](crate::port::subscriber::Subscriber) was full and the
+    /// oldest sample was removed to make room for a new one. Only happens in services with
+    /// safe overflow.
+    Overflow = 1,
+    /// The buffer of the [`Subscriber`](crate::port::subscriber::Subscriber) was full and the
+    /// new sample was discarded, see
+    /// [`UnableToDeliverStrategy::DiscardSample`].
+    Discarded = 2,
+}
+
+impl DeadLetterReason {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(DeadLetterReason::Overflow),
+            2 => Some(DeadLetterReason::Discarded),
+            _ => None,
+        }
+    }
+}
+
+/// The metadata of a sample that could not be delivered to a
+/// [`Subscriber`](crate::port::subscriber::Subscriber). Every sample of the dead-letter service
+/// starts with a serialized [`DeadLetter`] followed by the payload of the lost sample, when the
+/// service was configured to include it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeadLetter {
+    publisher_id: UniquePublisherId,
+    subscriber_id: UniqueSubscriberId,
+    reason: DeadLetterReason,
+    payload_size: usize,
+}
+
+impl DeadLetter {
+    /// The size in bytes of a serialized [`DeadLetter`].
+    pub const SIZE: usize = 16 + 16 + 8 + 1;
+
+    pub(crate) fn new(
+        publisher_id: UniquePublisherId,
+        subscriber_id: UniqueSubscriberId,
+        reason: DeadLetterReason,
+        payload_size: usize,
+    ) -> Self {
+        Self {
+            publisher_id,
+            subscriber_id,
+            reason,
+            payload_size,
+        }
+    }
+
+    /// Returns the [`UniquePublisherId`] of the [`Publisher`] that sent the lost sample.
+    pub fn publisher_id(&self) -> UniquePublisherId {
+        self.publisher_id
+    }
+
+    /// Returns the [`UniqueSubscriberId`] of the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) that did not receive the sample.
+    pub fn subscriber_id(&self) -> UniqueSubscriberId {
+        self.subscriber_id
+    }
+
+    /// Returns the [`DeadLetterReason`] why the sample was lost.
+    pub fn reason(&self) -> DeadLetterReason {
+        self.reason
+    }
+
+    /// Returns the size in bytes of the payload of the lost sample. It is also provided when
+    /// the payload itself is not part of the dead letter.
+    pub fn payload_size(&self) -> usize {
+        self.payload_size
+    }
+
+    fn write_to(&self, bytes: &mut [u8]) {
+        bytes[0..16].copy_from_slice(&self.publisher_id.0.value().to_le_bytes());
+        bytes[16..32].copy_from_slice(&self.subscriber_id.0.value().to_le_bytes());
+        bytes[32..40].copy_from_slice(&(self.payload_size as u64).to_le_bytes());
+        bytes[40] = self.reason as u8;
+    }
+
+    /// Deserializes the [`DeadLetter`] from the payload of a dead-letter service sample and
+    /// returns it together with the attached payload of the lost sample. The payload is empty
+    /// when the dead-letter service does not include it. Returns [`None`] when the bytes do not
+    /// contain a valid [`DeadLetter`].
+    pub fn from_bytes(bytes: &[u8]) -> Option<(DeadLetter, &[u8])> {
+        if bytes.len() < Self::SIZE {
+            return None;
+        }
+
+        let reason = DeadLetterReason::from_u8(bytes[40])?;
+        let publisher_id = u128::from_le_bytes(bytes[0..16].try_into().ok()?);
+        let subscriber_id = u128::from_le_bytes(bytes[16..32].try_into().ok()?);
+        let payload_size = u64::from_le_bytes(bytes[32..40].try_into().ok()?) as usize;
+
+        Some((
+            DeadLetter {
+                publisher_id: UniquePublisherId(UniqueSystemId::from(publisher_id)),
+                subscriber_id: UniqueSubscriberId(UniqueSystemId::from(subscriber_id)),
+                reason,
+                payload_size,
+            },
+            &bytes[Self::SIZE..],
+        ))
+    }
+}
+
+/// The [`Publisher`] of a dead-letter service. It is shared by all publishers of a node
+/// that route their lost samples into the same dead-letter service.
+#[derive(Debug)]
+pub(crate) struct DeadLetterPublisher<Service: service::Service> {
+    name: ServiceName,
+    max_slice_len: usize,
+    publisher: Mutex<Publisher<Service, [u8]>>,
+    _service: PortFactory<Service, [u8]>,
+}
+
+impl<Service: service::Service> DeadLetterPublisher<Service> {
+    fn create(
+        shared_node: Arc<SharedNode<Service>>,
+        name: &ServiceName,
+        max_slice_len: usize,
+    ) -> Result<Self, PublisherCreateError> {
+        let msg = "Unable to create dead-letter channel";
+        let service = fail!(from "DeadLetterPublisher::create()",
+                when builder::Builder::new(name.clone(), shared_node)
+                    .publish_subscribe::<[u8]>()
+                    .open_or_create(),
+                with PublisherCreateError::UnableToCreateDeadLetterChannel,
+                "{} since the dead-letter service \"{}\" could not be opened.", msg, name);
+
+        // a dead-letter service that routes into another one could form a cycle where every
+        // publisher creates the dead-letter publisher of the next service
+        if let Some(next) = service.static_config().dead_letter_service() {
+            fail!(from "DeadLetterPublisher::create()",
+                with PublisherCreateError::UnableToCreateDeadLetterChannel,
+                "{} since the dead-letter service \"{}\" routes its own lost samples into \"{}\".",
+                msg, name, next);
+        }
+
+        let publisher = fail!(from "DeadLetterPublisher::create()",
+                when service
+                    .publisher_builder()
+                    .max_slice_len(max_slice_len)
+                    .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+                    .create(),
+                with PublisherCreateError::UnableToCreateDeadLetterChannel,
+                "{} since the publisher of the dead-letter service \"{}\" could not be created.", msg, name);
+
+        Ok(Self {
+            name: name.clone(),
+            max_slice_len,
+            publisher: Mutex::new(publisher),
+            _service: service,
+        })
+    }
+}
+
+/// Routes the lost samples of a [`Publisher`] into the dead-letter service.
+#[derive(Debug)]
+pub(crate) struct DeadLetterChannel<Service: service::Service> {
+    publisher: Arc<DeadLetterPublisher<Service>>,
+    include_payload: bool,
+}
+
+impl<Service: service::Service> DeadLetterChannel<Service> {
+    pub(crate) fn new(
+        shared_node: Arc<SharedNode<Service>>,
+        name: &ServiceName,
+        include_payload: bool,
+        max_payload_size: usize,
+    ) -> Result<Self, PublisherCreateError> {
+        let max_slice_len = match include_payload {
+            true => DeadLetter::SIZE + max_payload_size,
+            false => DeadLetter::SIZE,
+        };
+
+        let node = shared_node.clone();
+        let mut publishers = match node.dead_letter_publishers().lock() {
+            Ok(publishers) => publishers,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        publishers.retain(|p| p.strong_count() > 0);
+
+        let shared_publisher = publishers
+            .iter()
+            .filter_map(|p| p.upgrade())
+            .find(|p| p.name == *name && p.max_slice_len >= max_slice_len);
+
+        let publisher = match shared_publisher {
+            Some(publisher) => publisher,
+            None => {
+                let publisher = Arc::new(DeadLetterPublisher::create(
+                    shared_node,
+                    name,
+                    max_slice_len,
+                )?);
+                publishers.push(Arc::downgrade(&publisher));
+                publisher
+            }
+        };
+
+        Ok(Self {
+            publisher,
+            include_payload,
+        })
+    }
+
+    pub(crate) fn include_payload(&self) -> bool {
+        self.include_payload
+    }
+
+    pub(crate) fn send(&self, letter: DeadLetter, payload: &[u8]) {
+        let publisher = match self.publisher.publisher.lock() {
+            Ok(publisher) => publisher,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        let len = DeadLetter::SIZE + payload.len();
+        let mut sample = match publisher.loan_slice(len) {
+            Ok(sample) => sample,
+            Err(e) => {
+                warn!(from self, "Unable to route {:?} into the dead-letter service since no sample could be loaned ({:?}).", letter, e);
+                return;
+            }
+        };
+
+        let bytes = sample.payload_mut();
+        letter.write_to(&mut bytes[..DeadLetter::SIZE]);
+        bytes[DeadLetter::SIZE..].copy_from_slice(payload);
+
+        if let Err(e) = sample.send() {
+            warn!(from self, "Unable to route {:?} into the dead-letter service ({:?}).", letter, e);
+        }
+    }
+}
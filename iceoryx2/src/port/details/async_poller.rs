@@ -0,0 +1,360 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A reactor that wakes up pending futures of ports. Every pending future registers the file
+//! descriptor of its port, a background thread blocks on all registered file descriptors and
+//! wakes only the futures whose file descriptor became readable.
+
+use core::task::Waker;
+use core::time::Duration;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use std::time::Instant;
+
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::{
+    FileDescriptorSet, FileDescriptorSetGuard, FileDescriptorSetWaitError, FileEvent,
+    SynchronousMultiplexing,
+};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_cal::event::unix_datagram_socket::{
+    EventImpl, Listener, ListenerBuilder, Notifier, NotifierBuilder,
+};
+use iceoryx2_cal::event::{
+    FileName, Listener as _, ListenerBuilder as _, NamedConceptBuilder, NamedConceptMgmt,
+    Notifier as _, NotifierBuilder as _, TriggerId,
+};
+
+// Not every communication mechanism provides a file descriptor, the process local services
+// for instance do not. Pending futures of those ports are woken up in this interval so that the
+// executor polls the port again.
+const FALLBACK_POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ReactorCreateError {
+    UnableToCreateWakeUpEvent,
+    UnableToStartThread,
+}
+
+impl std::fmt::Display for ReactorCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ReactorCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ReactorCreateError {}
+
+// makes a raw file descriptor of a registration attachable to the [`FileDescriptorSet`]
+#[derive(Debug)]
+struct RegisteredFileDescriptor(FileDescriptor);
+
+impl FileDescriptorBased for RegisteredFileDescriptor {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.0
+    }
+}
+
+impl SynchronousMultiplexing for RegisteredFileDescriptor {}
+
+#[derive(Debug)]
+struct PendingWaker {
+    id: u64,
+    file_descriptor: Option<i32>,
+    deadline: Option<Instant>,
+    waker: Waker,
+}
+
+#[derive(Debug)]
+struct Reactor {
+    pending_wakers: Mutex<Vec<PendingWaker>>,
+    // held by the reactor thread while it waits on the file descriptors, so that a deregistered
+    // file descriptor is no longer in use when the deregistration returns and the port can
+    // close it
+    is_waiting: Mutex<()>,
+    // interrupts the wait of the reactor thread whenever the registered file descriptors change
+    wake_up: Notifier,
+    next_id: AtomicU64,
+}
+
+static REACTOR: OnceLock<Result<Reactor, ReactorCreateError>> = OnceLock::new();
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // the wakers stay valid even when another thread panicked while holding the lock
+    match mutex.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+impl Reactor {
+    fn get() -> Result<&'static Reactor, ReactorCreateError> {
+        match REACTOR.get_or_init(Self::create) {
+            Ok(reactor) => Ok(reactor),
+            Err(e) => Err(*e),
+        }
+    }
+
+    fn create() -> Result<Reactor, ReactorCreateError> {
+        let msg = "Unable to create the reactor that wakes up pending futures";
+        let origin = "async_poller::Reactor::create()";
+        let mut name = fail!(from origin, when FileName::new(b"iox2_async_reactor_"),
+            with ReactorCreateError::UnableToCreateWakeUpEvent,
+            "{} since the name of the wake up event is invalid.", msg);
+        let id = fail!(from origin, when UniqueSystemId::new(),
+            with ReactorCreateError::UnableToCreateWakeUpEvent,
+            "{} since no unique name for the wake up event could be generated.", msg);
+        fail!(from origin, when name.push_bytes(id.value().to_string().as_bytes()),
+            with ReactorCreateError::UnableToCreateWakeUpEvent,
+            "{} since the name of the wake up event is invalid.", msg);
+
+        let listener = fail!(from origin, when ListenerBuilder::new(&name).create(),
+            with ReactorCreateError::UnableToCreateWakeUpEvent,
+            "{} since the listener of the wake up event could not be created.", msg);
+        let wake_up = fail!(from origin, when NotifierBuilder::new(&name).open(),
+            with ReactorCreateError::UnableToCreateWakeUpEvent,
+            "{} since the notifier of the wake up event could not be opened.", msg);
+
+        // the notifier is connected, the socket file is no longer required and would otherwise
+        // outlive the process since the reactor is never dropped
+        if let Err(e) = unsafe { EventImpl::remove(&name) } {
+            warn!(from origin, "Unable to remove the socket file of the wake up event ({:?}).", e);
+        }
+
+        fail!(from origin, when std::thread::Builder::new()
+                .name("iox2_async_reactor".to_string())
+                .spawn(move || Self::get().map(|reactor| reactor.run(listener))),
+            with ReactorCreateError::UnableToStartThread,
+            "{} since the background thread could not be started.", msg);
+
+        Ok(Reactor {
+            pending_wakers: Mutex::new(Vec::new()),
+            is_waiting: Mutex::new(()),
+            wake_up,
+            next_id: AtomicU64::new(0),
+        })
+    }
+
+    fn interrupt_wait(&self) {
+        if let Err(e) = self.wake_up.notify(TriggerId::new(0)) {
+            warn!(from self, "Unable to interrupt the wait of the reactor thread ({:?}), newly registered futures may be woken up late.", e);
+        }
+    }
+
+    fn register(
+        &self,
+        id: u64,
+        file_descriptor: Option<i32>,
+        deadline: Option<Instant>,
+        waker: &Waker,
+    ) {
+        let mut pending_wakers = lock(&self.pending_wakers);
+        match pending_wakers.iter_mut().find(|p| p.id == id) {
+            Some(pending) => {
+                let needs_interrupt =
+                    pending.file_descriptor != file_descriptor || pending.deadline != deadline;
+                pending.file_descriptor = file_descriptor;
+                pending.deadline = deadline;
+                if !pending.waker.will_wake(waker) {
+                    pending.waker = waker.clone();
+                }
+                drop(pending_wakers);
+
+                if needs_interrupt {
+                    self.interrupt_wait();
+                }
+            }
+            None => {
+                pending_wakers.push(PendingWaker {
+                    id,
+                    file_descriptor,
+                    deadline,
+                    waker: waker.clone(),
+                });
+                drop(pending_wakers);
+                self.interrupt_wait();
+            }
+        }
+    }
+
+    fn deregister(&self, id: u64) {
+        let mut pending_wakers = lock(&self.pending_wakers);
+        let file_descriptor = match pending_wakers.iter().position(|p| p.id == id) {
+            Some(index) => pending_wakers.swap_remove(index).file_descriptor,
+            None => None,
+        };
+        drop(pending_wakers);
+
+        if file_descriptor.is_some() {
+            self.interrupt_wait();
+            // waits until the reactor thread no longer waits on the file descriptor
+            drop(lock(&self.is_waiting));
+        }
+    }
+
+    fn run(&self, wake_up: Listener) {
+        let wake_up_fd = match wake_up.as_multiplexable() {
+            Some(fd) => fd,
+            None => {
+                warn!(from self, "The wake up event of the reactor provides no file descriptor, pending futures are only polled again when the executor decides to.");
+                return;
+            }
+        };
+
+        loop {
+            let is_waiting = lock(&self.is_waiting);
+            let (file_descriptors, deadline) = {
+                let pending_wakers = lock(&self.pending_wakers);
+                let file_descriptors: Vec<(i32, RegisteredFileDescriptor)> = pending_wakers
+                    .iter()
+                    .filter_map(|p| p.file_descriptor)
+                    .filter_map(|v| FileDescriptor::non_owning_new(v).map(|fd| (v, fd)))
+                    .map(|(v, fd)| (v, RegisteredFileDescriptor(fd)))
+                    .collect();
+                let deadline = pending_wakers.iter().filter_map(|p| p.deadline).min();
+                (file_descriptors, deadline)
+            };
+
+            let fd_set = FileDescriptorSet::new();
+            let mut attachments: Vec<FileDescriptorSetGuard<'_, '_>> = vec![];
+            let mut unattached_fds = vec![];
+            match fd_set.add(wake_up_fd) {
+                Ok(guard) => attachments.push(guard),
+                Err(e) => {
+                    warn!(from self, "Unable to attach the wake up event of the reactor ({:?}).", e);
+                }
+            }
+            for (value, fd) in &file_descriptors {
+                match fd_set.add(fd) {
+                    Ok(guard) => attachments.push(guard),
+                    Err(e) => {
+                        warn!(from self,
+                            "Unable to attach the file descriptor {} ({:?}), the future is polled in an interval instead.",
+                            value, e);
+                        unattached_fds.push(*value);
+                    }
+                }
+            }
+
+            let fallback_deadline = Instant::now() + FALLBACK_POLL_INTERVAL;
+            let deadline = match unattached_fds.is_empty() {
+                true => deadline,
+                false => Some(deadline.map_or(fallback_deadline, |d| d.min(fallback_deadline))),
+            };
+
+            let mut triggered_fds = vec![];
+            let result = match deadline {
+                Some(deadline) => fd_set.timed_wait(
+                    deadline.saturating_duration_since(Instant::now()),
+                    FileEvent::Read,
+                    |fd| triggered_fds.push(unsafe { fd.native_handle() }),
+                ),
+                None => fd_set.blocking_wait(FileEvent::Read, |fd| {
+                    triggered_fds.push(unsafe { fd.native_handle() })
+                }),
+            };
+            drop(attachments);
+
+            let wake_all = match result {
+                Ok(()) | Err(FileDescriptorSetWaitError::Interrupt) => false,
+                Err(e) => {
+                    warn!(from self, "Failure while waiting on the file descriptors of the pending futures ({:?}), all pending futures are woken up.", e);
+                    true
+                }
+            };
+
+            if let Err(e) = wake_up.try_wait_all(|_| {}) {
+                warn!(from self, "Unable to consume the notifications of the wake up event ({:?}).", e);
+            }
+
+            let now = Instant::now();
+            let mut wakers = vec![];
+            lock(&self.pending_wakers).retain(|p| {
+                let is_triggered = wake_all
+                    || p.deadline.is_some_and(|d| d <= now)
+                    || p.file_descriptor.is_some_and(|fd| {
+                        triggered_fds.contains(&fd) || unattached_fds.contains(&fd)
+                    });
+                if is_triggered {
+                    wakers.push(p.waker.clone());
+                }
+                !is_triggered
+            });
+
+            // a woken future may be polled or dropped right away in this thread and register
+            // or deregister itself, therefore no lock must be held
+            drop(is_waiting);
+            for waker in wakers {
+                waker.wake();
+            }
+
+            if wake_all {
+                std::thread::sleep(FALLBACK_POLL_INTERVAL);
+            }
+        }
+    }
+}
+
+/// Owned by a future of a port. Registers the future at the reactor that wakes it up as soon
+/// as the file descriptor of the port becomes readable. The registration is removed when it
+/// goes out of scope.
+#[derive(Debug, Default)]
+pub(crate) struct AsyncRegistration {
+    id: Option<u64>,
+}
+
+impl AsyncRegistration {
+    /// Wakes the task of the provided waker once the file descriptor becomes readable or at the
+    /// latest after the timeout has passed. When no file descriptor is provided the task is
+    /// woken up after a short poll interval.
+    pub(crate) fn wake_when_readable(
+        &mut self,
+        file_descriptor: Option<&dyn SynchronousMultiplexing>,
+        timeout: Option<Duration>,
+        waker: &Waker,
+    ) {
+        let reactor = match Reactor::get() {
+            Ok(reactor) => reactor,
+            Err(e) => {
+                warn!(from "AsyncRegistration::wake_when_readable()",
+                    "The reactor is not available ({:?}), the task is woken up immediately.", e);
+                waker.wake_by_ref();
+                return;
+            }
+        };
+
+        let id = *self
+            .id
+            .get_or_insert_with(|| reactor.next_id.fetch_add(1, Ordering::Relaxed));
+        let file_descriptor =
+            file_descriptor.map(|fd| unsafe { fd.file_descriptor().native_handle() });
+        let timeout = match file_descriptor {
+            Some(_) => timeout,
+            None => Some(timeout.map_or(FALLBACK_POLL_INTERVAL, |t| t.min(FALLBACK_POLL_INTERVAL))),
+        };
+
+        reactor.register(
+            id,
+            file_descriptor,
+            timeout.map(|t| Instant::now() + t),
+            waker,
+        );
+    }
+}
+
+impl Drop for AsyncRegistration {
+    fn drop(&mut self) {
+        if let (Some(id), Some(Ok(reactor))) = (self.id, REACTOR.get()) {
+            reactor.deregister(id);
+        }
+    }
+}
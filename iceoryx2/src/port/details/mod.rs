@@ -10,5 +10,8 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#[cfg(feature = "async")]
+pub(crate) mod async_poller;
 pub(crate) mod publisher_connections;
+pub(crate) mod sample_arrival;
 pub(crate) mod subscriber_connections;
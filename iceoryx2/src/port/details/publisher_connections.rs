@@ -10,10 +10,24 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#![cfg_attr(
+    feature = "safety",
+    deny(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::indexing_slicing,
+        clippy::unreachable
+    )
+)]
+
 use std::{cell::UnsafeCell, sync::Arc};
 
 use crate::{
-    node::SharedNode,
+    node::{
+        resource_usage::{NodeResource, NodeResourceRegistration},
+        SharedNode,
+    },
     port::port_identifiers::{UniquePublisherId, UniqueSubscriberId},
     service::{
         self,
@@ -26,17 +40,26 @@ use crate::{
 
 use crate::port::update_connections::ConnectionFailure;
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::permission::Permission;
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::{
-    shared_memory::SharedMemory, shared_memory::SharedMemoryBuilder,
-    shm_allocator::pool_allocator::PoolAllocator, zero_copy_connection::*,
+    shared_memory::SharedMemory,
+    shared_memory::{SharedMemoryBuilder, SharedMemoryOpenError},
+    shm_allocator::{selectable_allocator::SelectableAllocator, PointerOffset},
+    zero_copy_connection::*,
 };
 
 #[derive(Debug)]
 pub(crate) struct Connection<Service: service::Service> {
     pub(crate) receiver: <Service::Connection as ZeroCopyConnection>::Receiver,
-    pub(crate) data_segment: Service::SharedMemory,
+    // the segments of the publishers data segment, the first one is mapped when the connection
+    // is established and every other one when its first sample is received
+    data_segments: Vec<Option<Service::SharedMemory>>,
     pub(crate) publisher_id: UniquePublisherId,
+    // a sample that was received while looking for the history of the publisher, it is
+    // returned by the next regular receive call
+    pub(crate) pending_sample: Option<PointerOffset>,
+    resource_registration: NodeResourceRegistration<Service>,
 }
 
 impl<Service: service::Service> Connection<Service> {
@@ -55,24 +78,99 @@ impl<Service: service::Service> Connection<Service> {
                                     .config(&connection_config::<Service>(this.shared_node.config()))
                                     .buffer_size(this.buffer_size)
                                     .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
-                                    .enable_safe_overflow(this.static_config.enable_safe_overflow)
+                                    .enable_safe_overflow(this.enable_safe_overflow)
                                     .number_of_samples(details.number_of_samples)
-                                    .create_receiver(this.static_config.type_details().sample_layout(details.max_slice_len).size()),
+                                    .max_supported_shared_memory_segments(details.number_of_segments)
+                                    .permission(this.permission)
+                                    .create_receiver(details.chunk_size),
                         "{} since the zero copy connection could not be established.", msg);
 
         let data_segment = fail!(from this,
-                            when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
-                                Builder::new(&data_segment_name(details.publisher_id))
-                                .config(&data_segment_config::<Service>(this.shared_node.config()))
-                                .open(),
+                            when Self::open_data_segment(this, details.publisher_id, 0),
                             "{} since the publishers data segment could not be mapped into the process.", msg);
 
+        let data_segments = core::iter::once(Some(data_segment))
+            .chain((1..details.number_of_segments).map(|_| None))
+            .collect();
+
         Ok(Self {
             receiver,
-            data_segment,
+            data_segments,
             publisher_id: details.publisher_id,
+            pending_sample: None,
+            // the receiver and the mapped data segment of the publisher
+            resource_registration: NodeResourceRegistration::new(
+                this.shared_node.clone(),
+                NodeResource::Connection,
+                2,
+            ),
         })
     }
+
+    fn open_data_segment(
+        this: &PublisherConnections<Service>,
+        publisher_id: UniquePublisherId,
+        segment_id: usize,
+    ) -> Result<Service::SharedMemory, SharedMemoryOpenError> {
+        <Service::SharedMemory as SharedMemory<SelectableAllocator>>::Builder::new(
+            &data_segment_name(publisher_id, segment_id),
+        )
+        .config(&data_segment_config::<Service>(this.shared_node.config()))
+        .read_only(
+            this.shared_node
+                .config()
+                .defaults
+                .publish_subscribe
+                .subscriber_read_only_data_segments,
+        )
+        .huge_page_size(
+            this.shared_node
+                .config()
+                .global
+                .shared_memory
+                .huge_page_size,
+        )
+        .open()
+    }
+
+    /// Returns the absolute address of the chunk at `offset`. When the chunk is part of a
+    /// segment that was added to the data segment of the publisher after the connection was
+    /// established, the segment is mapped into the process first.
+    pub(crate) fn chunk_address(
+        &mut self,
+        this: &PublisherConnections<Service>,
+        offset: PointerOffset,
+    ) -> Result<usize, ConnectionFailure> {
+        let msg = "Unable to acquire the address of the received sample";
+        // with a single segment the whole value is the offset, see the zero copy connection
+        let (segment_id, offset) = match self.data_segments.len() {
+            1 => (0, offset.value()),
+            _ => (offset.segment_id(), offset.offset()),
+        };
+
+        let data_segment = match self.data_segments.get_mut(segment_id) {
+            Some(data_segment) => data_segment,
+            None => {
+                fail!(from this, with ConnectionFailure::UnableToMapPublishersDataSegment(SharedMemoryOpenError::DoesNotExist),
+                    "{} since the segment id {} exceeds the number of segments {} of publisher {:?}.",
+                    msg, segment_id, self.data_segments.len(), self.publisher_id);
+            }
+        };
+
+        let data_segment = match data_segment {
+            Some(data_segment) => data_segment,
+            None => {
+                let new_segment = fail!(from this,
+                    when Self::open_data_segment(this, self.publisher_id, segment_id),
+                    "{} since the segment {} of the publishers data segment could not be mapped into the process.",
+                    msg, segment_id);
+                self.resource_registration.add_file_descriptors(1);
+                data_segment.insert(new_segment)
+            }
+        };
+
+        Ok(data_segment.payload_start_address() + offset)
+    }
 }
 #[derive(Debug)]
 pub(crate) struct PublisherConnections<Service: service::Service> {
@@ -80,7 +178,9 @@ pub(crate) struct PublisherConnections<Service: service::Service> {
     subscriber_id: UniqueSubscriberId,
     shared_node: Arc<SharedNode<Service>>,
     pub(crate) static_config: StaticConfig,
+    permission: Permission,
     pub(crate) buffer_size: usize,
+    enable_safe_overflow: bool,
 }
 
 impl<Service: service::Service> PublisherConnections<Service> {
@@ -88,15 +188,18 @@ impl<Service: service::Service> PublisherConnections<Service> {
         capacity: usize,
         subscriber_id: UniqueSubscriberId,
         shared_node: Arc<SharedNode<Service>>,
-        static_config: &StaticConfig,
+        service_config: &service::static_config::StaticConfig,
         buffer_size: usize,
+        enable_safe_overflow: bool,
     ) -> Self {
         Self {
             connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
             subscriber_id,
             shared_node,
-            static_config: static_config.clone(),
+            static_config: service_config.publish_subscribe().clone(),
+            permission: service_config.participant_permission(),
             buffer_size,
+            enable_safe_overflow,
         }
     }
 
@@ -105,15 +208,20 @@ impl<Service: service::Service> PublisherConnections<Service> {
     }
 
     pub(crate) fn get(&self, index: usize) -> &Option<Connection<Service>> {
-        unsafe { &*self.connections[index].get() }
+        match self.connections.get(index) {
+            Some(connection) => unsafe { &*connection.get() },
+            None => &None,
+        }
     }
 
     // only used internally as convinience function
     #[allow(clippy::mut_from_ref)]
-    pub(crate) fn get_mut(&self, index: usize) -> &mut Option<Connection<Service>> {
+    pub(crate) fn get_mut(&self, index: usize) -> Option<&mut Option<Connection<Service>>> {
         #[deny(clippy::mut_from_ref)]
         unsafe {
-            &mut *self.connections[index].get()
+            self.connections
+                .get(index)
+                .map(|connection| &mut *connection.get())
         }
     }
 
@@ -122,13 +230,18 @@ impl<Service: service::Service> PublisherConnections<Service> {
         index: usize,
         details: &PublisherDetails,
     ) -> Result<(), ConnectionFailure> {
-        *self.get_mut(index) = Some(Connection::new(self, details)?);
+        let new_connection = Connection::new(self, details)?;
+        if let Some(connection) = self.get_mut(index) {
+            *connection = Some(new_connection);
+        }
 
         Ok(())
     }
 
     pub(crate) fn remove(&self, index: usize) {
-        *self.get_mut(index) = None;
+        if let Some(connection) = self.get_mut(index) {
+            *connection = None;
+        }
     }
 
     pub(crate) fn len(&self) -> usize {
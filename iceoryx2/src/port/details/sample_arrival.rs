@@ -0,0 +1,161 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Couples a publish-subscribe service to an event service so that a
+//! [`crate::port::subscriber::Subscriber`] can block until a sample arrives. The event service
+//! is created on demand when the first subscriber blocks, from then on every
+//! [`crate::port::publisher::Publisher`] notifies it after it delivered samples.
+
+use core::time::Duration;
+use std::sync::Arc;
+
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+#[cfg(feature = "async")]
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use iceoryx2_cal::event::ListenerWaitError;
+
+use crate::node::SharedNode;
+use crate::port::listener::Listener;
+use crate::port::notifier::Notifier;
+use crate::service;
+use crate::service::builder;
+use crate::service::port_factory::event::PortFactory;
+use crate::service::service_name::ServiceName;
+use crate::service::static_config::StaticConfig;
+
+// combined with the uuid of the publish-subscribe service to acquire the name of the event
+// service, the uuid has a fixed length so that the name does not grow with the user's name
+const SAMPLE_ARRIVAL_PREFIX: &str = "__iceoryx2_sample_arrival__/";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum SampleArrivalError {
+    UnableToOpenEventService,
+    UnableToCreatePort,
+}
+
+impl std::fmt::Display for SampleArrivalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "SampleArrivalError::{:?}", self)
+    }
+}
+
+impl std::error::Error for SampleArrivalError {}
+
+fn open_or_create_service<Service: service::Service>(
+    shared_node: &Arc<SharedNode<Service>>,
+    static_config: &StaticConfig,
+) -> Result<PortFactory<Service>, SampleArrivalError> {
+    let name = fatal_panic!(from "sample_arrival::open_or_create_service()",
+        when ServiceName::new(&format!("{}{}", SAMPLE_ARRIVAL_PREFIX, static_config.uuid())),
+        "This should never happen! The name of the sample arrival service is never empty.");
+    let pubsub = static_config.publish_subscribe();
+
+    Ok(fail!(from "sample_arrival::open_or_create_service()",
+        when builder::Builder::new(name.clone(), shared_node.clone())
+            .permissions(static_config.permission())
+            .internal()
+            .event()
+            .max_notifiers(pubsub.max_publishers)
+            .max_listeners(pubsub.max_subscribers + pubsub.max_observers)
+            .open_or_create(),
+        with SampleArrivalError::UnableToOpenEventService,
+        "Unable to open the sample arrival service \"{}\".", name))
+}
+
+/// Notifies every [`SampleArrivalListener`] of the service. Owned by the data segment of a
+/// [`crate::port::publisher::Publisher`].
+#[derive(Debug)]
+pub(crate) struct SampleArrivalNotifier<Service: service::Service> {
+    notifier: Notifier<Service>,
+    _service: PortFactory<Service>,
+}
+
+impl<Service: service::Service> SampleArrivalNotifier<Service> {
+    pub(crate) fn new(
+        shared_node: &Arc<SharedNode<Service>>,
+        static_config: &StaticConfig,
+    ) -> Result<Self, SampleArrivalError> {
+        let service = open_or_create_service(shared_node, static_config)?;
+        let notifier = fail!(from "SampleArrivalNotifier::new()",
+            when service.notifier_builder().create(),
+            with SampleArrivalError::UnableToCreatePort,
+            "Unable to create the notifier of the sample arrival service.");
+
+        Ok(Self {
+            notifier,
+            _service: service,
+        })
+    }
+
+    pub(crate) fn notify(&self) {
+        if let Err(e) = self.notifier.notify() {
+            warn!(from self, "Unable to notify the subscribers about the sample arrival ({:?}).", e);
+        }
+    }
+}
+
+/// Wakes up a blocked [`crate::port::subscriber::Subscriber`] whenever a
+/// [`crate::port::publisher::Publisher`] delivered samples.
+#[derive(Debug)]
+pub(crate) struct SampleArrivalListener<Service: service::Service> {
+    listener: Listener<Service>,
+    _service: PortFactory<Service>,
+}
+
+impl<Service: service::Service> SampleArrivalListener<Service> {
+    pub(crate) fn new(
+        shared_node: &Arc<SharedNode<Service>>,
+        static_config: &StaticConfig,
+    ) -> Result<Self, SampleArrivalError> {
+        let service = open_or_create_service(shared_node, static_config)?;
+        let listener = fail!(from "SampleArrivalListener::new()",
+            when service.listener_builder().create(),
+            with SampleArrivalError::UnableToCreatePort,
+            "Unable to create the listener of the sample arrival service.");
+
+        Ok(Self {
+            listener,
+            _service: service,
+        })
+    }
+
+    // waits until a publisher delivered samples or the timeout has passed, all pending
+    // notifications are consumed so that they do not cause spurious wake ups later
+    pub(crate) fn timed_wait(&self, timeout: Duration) -> Result<(), ListenerWaitError> {
+        self.listener.timed_wait_all(|_| {}, timeout)?;
+        Ok(())
+    }
+
+    // waits until a publisher delivered samples, all pending notifications are consumed
+    pub(crate) fn blocking_wait(&self) -> Result<(), ListenerWaitError> {
+        self.listener.blocking_wait_all(|_| {})?;
+        Ok(())
+    }
+
+    // the listener that is woken up with every delivery, used to attach the subscriber to a
+    // waitset
+    pub(crate) fn listener(&self) -> &Listener<Service> {
+        &self.listener
+    }
+
+    // consumes all pending notifications without blocking, afterwards the file descriptor of
+    // the listener becomes readable again with the next delivery of a publisher
+    #[cfg(feature = "async")]
+    pub(crate) fn consume_notifications(&self) -> Result<(), ListenerWaitError> {
+        self.listener.try_wait_all(|_| {})
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) fn as_multiplexable(&self) -> Option<&dyn SynchronousMultiplexing> {
+        self.listener.as_multiplexable()
+    }
+}
@@ -10,18 +10,35 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use std::cell::UnsafeCell;
+#![cfg_attr(
+    feature = "safety",
+    deny(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::indexing_slicing,
+        clippy::unreachable
+    )
+)]
+
+use std::cell::{Cell, UnsafeCell};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::permission::Permission;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::zero_copy_connection::{
     ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyCreationError,
 };
 
+use crate::node::resource_usage::{NodeResource, NodeResourceRegistration};
 use crate::node::SharedNode;
+use crate::port::publisher::ConnectionBlockingStatistics;
 use crate::service::config_scheme::connection_config;
 use crate::service::dynamic_config::publish_subscribe::SubscriberDetails;
+use crate::service::port_factory::subscriber::{Decimation, OverflowStrategy};
 use crate::{
     port::port_identifiers::{UniquePublisherId, UniqueSubscriberId},
     service,
@@ -32,14 +49,32 @@ use crate::{
 pub(crate) struct Connection<Service: service::Service> {
     pub(crate) sender: <Service::Connection as ZeroCopyConnection>::Sender,
     pub(crate) subscriber_id: UniqueSubscriberId,
+    pub(crate) group: Option<u64>,
+    pub(crate) overflow_strategy: Option<OverflowStrategy>,
+    // the index of the subscriber in the dynamic config of the service
+    pub(crate) index: usize,
+    pub(crate) node_id: UniqueSystemId,
+    // set when the samples of the dead subscriber were reclaimed, the connection is no longer
+    // served until the subscriber is removed from the service
+    is_subscriber_dead: Cell<bool>,
+    decimation: Decimation,
+    // the number of samples offered to the subscriber and the time of the last delivery
+    decimation_state: Cell<(usize, Option<Instant>)>,
+    blocking_statistics: Cell<ConnectionBlockingStatistics>,
+    // set when the publisher disconnected the slow subscriber, it no longer receives samples
+    // from this publisher
+    is_disconnected: Cell<bool>,
+    _resource_registration: NodeResourceRegistration<Service>,
 }
 
 impl<Service: service::Service> Connection<Service> {
     fn new(
         this: &SubscriberConnections<Service>,
+        index: usize,
         subscriber_details: SubscriberDetails,
         number_of_samples: usize,
-        max_slice_len: usize,
+        chunk_size: usize,
+        number_of_segments: usize,
     ) -> Result<Self, ZeroCopyCreationError> {
         let msg = format!(
             "Unable to establish connection to subscriber {:?} from publisher {:?}",
@@ -51,21 +86,95 @@ impl<Service: service::Service> Connection<Service> {
                 msg, subscriber_details.buffer_size, this.static_config.subscriber_max_buffer_size);
         }
 
+        let enable_safe_overflow = OverflowStrategy::enables_safe_overflow(
+            subscriber_details.overflow_strategy,
+            this.static_config.enable_safe_overflow,
+        );
         let sender = fail!(from this, when <Service::Connection as ZeroCopyConnection>::
                         Builder::new( &connection_name(this.port_id, subscriber_details.port_id))
                                 .config(&connection_config::<Service>(this.shared_node.config()))
                                 .buffer_size(subscriber_details.buffer_size)
                                 .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
-                                .enable_safe_overflow(this.static_config.enable_safe_overflow)
+                                .enable_safe_overflow(enable_safe_overflow)
                                 .number_of_samples(number_of_samples)
-                                .create_sender(this.static_config.type_details().sample_layout(max_slice_len).size()),
+                                .max_supported_shared_memory_segments(number_of_segments)
+                                .permission(this.permission)
+                                .create_sender(chunk_size),
                         "{}.", msg);
 
         Ok(Self {
             sender,
             subscriber_id: subscriber_details.port_id,
+            group: subscriber_details.group,
+            overflow_strategy: subscriber_details.overflow_strategy,
+            index,
+            node_id: subscriber_details.node_id,
+            is_subscriber_dead: Cell::new(false),
+            decimation: subscriber_details.decimation,
+            decimation_state: Cell::new((0, None)),
+            blocking_statistics: Cell::new(ConnectionBlockingStatistics::new(
+                subscriber_details.port_id,
+            )),
+            is_disconnected: Cell::new(false),
+            _resource_registration: NodeResourceRegistration::new(
+                this.shared_node.clone(),
+                NodeResource::Connection,
+                1,
+            ),
         })
     }
+
+    /// Returns true when the subscriber died and its samples were reclaimed.
+    pub(crate) fn is_subscriber_dead(&self) -> bool {
+        self.is_subscriber_dead.get()
+    }
+
+    pub(crate) fn mark_subscriber_as_dead(&self) {
+        self.is_subscriber_dead.set(true);
+    }
+
+    /// Returns true when the publisher disconnected the subscriber.
+    pub(crate) fn is_disconnected(&self) -> bool {
+        self.is_disconnected.get()
+    }
+
+    pub(crate) fn disconnect(&self) {
+        self.is_disconnected.set(true);
+    }
+
+    pub(crate) fn blocking_statistics(&self) -> ConnectionBlockingStatistics {
+        self.blocking_statistics.get()
+    }
+
+    /// Adds a delivery that blocked for `blocking_time` to the blocking statistics.
+    pub(crate) fn record_blocking(&self, blocking_time: Duration, has_timed_out: bool) {
+        let mut statistics = self.blocking_statistics.get();
+        statistics.record(blocking_time, has_timed_out);
+        self.blocking_statistics.set(statistics);
+    }
+
+    /// Returns true when the next sample shall be delivered to the subscriber according to
+    /// its [`Decimation`], otherwise the sample is dropped for this subscriber.
+    pub(crate) fn accepts_next_sample(&self) -> bool {
+        let (offered_samples, last_delivery) = self.decimation_state.get();
+        let accept = match self.decimation {
+            Decimation::Disabled => true,
+            Decimation::EveryNth(n) => offered_samples % n == 0,
+            Decimation::MinInterval(interval) => match last_delivery {
+                None => true,
+                Some(last_delivery) => last_delivery.elapsed() >= interval,
+            },
+        };
+
+        let last_delivery = match (accept, self.decimation) {
+            (true, Decimation::MinInterval(_)) => Some(Instant::now()),
+            _ => last_delivery,
+        };
+        self.decimation_state
+            .set((offered_samples.wrapping_add(1), last_delivery));
+
+        accept
+    }
 }
 
 #[derive(Debug)]
@@ -74,7 +183,10 @@ pub(crate) struct SubscriberConnections<Service: service::Service> {
     port_id: UniquePublisherId,
     shared_node: Arc<SharedNode<Service>>,
     pub(crate) static_config: StaticConfig,
+    permission: Permission,
     number_of_samples: usize,
+    chunk_size: usize,
+    number_of_segments: usize,
 }
 
 impl<Service: service::Service> SubscriberConnections<Service> {
@@ -82,47 +194,64 @@ impl<Service: service::Service> SubscriberConnections<Service> {
         capacity: usize,
         shared_node: Arc<SharedNode<Service>>,
         port_id: UniquePublisherId,
-        static_config: &StaticConfig,
+        service_config: &service::static_config::StaticConfig,
         number_of_samples: usize,
+        chunk_size: usize,
+        number_of_segments: usize,
     ) -> Self {
         Self {
             connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
             shared_node,
             port_id,
-            static_config: static_config.clone(),
+            static_config: service_config.publish_subscribe().clone(),
+            permission: service_config.participant_permission(),
             number_of_samples,
+            chunk_size,
+            number_of_segments,
         }
     }
 
     pub(crate) fn get(&self, index: usize) -> &Option<Connection<Service>> {
-        unsafe { &(*self.connections[index].get()) }
+        match self.connections.get(index) {
+            Some(connection) => unsafe { &(*connection.get()) },
+            None => &None,
+        }
     }
 
     // only used internally as convinience function
     #[allow(clippy::mut_from_ref)]
-    fn get_mut(&self, index: usize) -> &mut Option<Connection<Service>> {
+    fn get_mut(&self, index: usize) -> Option<&mut Option<Connection<Service>>> {
         #[deny(clippy::mut_from_ref)]
         unsafe {
-            &mut (*self.connections[index].get())
+            self.connections
+                .get(index)
+                .map(|connection| &mut (*connection.get()))
         }
     }
 
     pub(crate) fn remove(&self, index: usize) {
-        *self.get_mut(index) = None
+        if let Some(connection) = self.get_mut(index) {
+            *connection = None
+        }
     }
 
     pub(crate) fn create(
         &self,
         index: usize,
         subscriber_details: SubscriberDetails,
-        max_slice_len: usize,
     ) -> Result<(), ZeroCopyCreationError> {
-        *self.get_mut(index) = Some(Connection::new(
+        let new_connection = Connection::new(
             self,
+            index,
             subscriber_details,
             self.number_of_samples,
-            max_slice_len,
-        )?);
+            self.chunk_size,
+            self.number_of_segments,
+        )?;
+
+        if let Some(connection) = self.get_mut(index) {
+            *connection = Some(new_connection);
+        }
 
         Ok(())
     }
@@ -0,0 +1,224 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .max_publishers(128)
+//!     .open_or_create()?;
+//!
+//! let fan_in = service.fan_in_builder()
+//!     .max_samples_per_publisher(4)
+//!     .create()?;
+//!
+//! // waits for the samples of all publishers at once
+//! if fan_in.timed_wait(Duration::from_millis(10))? {
+//!     while let Some(sample) = fan_in.receive()? {
+//!         println!("received: {:?}", *sample);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::time::Duration;
+use std::fmt::Debug;
+use std::time::Instant;
+
+use iceoryx2_bb_log::fail;
+
+use crate::port::port_identifiers::UniqueSubscriberId;
+use crate::port::subscriber::{Subscriber, SubscriberReceiveError};
+use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::sample::Sample;
+use crate::service;
+
+/// Defines the failures that can occur when a [`FanIn`] waits for
+/// [`crate::sample::Sample`]s.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum FanInWaitError {
+    ConnectionFailure(ConnectionFailure),
+    InternalFailure,
+}
+
+impl std::fmt::Display for FanInWaitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "FanInWaitError::{:?}", self)
+    }
+}
+
+impl std::error::Error for FanInWaitError {}
+
+/// Receiving endpoint of a publish-subscribe communication that merges the
+/// [`crate::sample::Sample`]s of many [`crate::port::publisher::Publisher`]s. In contrast to the
+/// [`Subscriber`], which always prefers the first connected
+/// [`crate::port::publisher::Publisher`]s, the [`FanIn`] serves the
+/// [`crate::port::publisher::Publisher`]s in turns and receives at most
+/// [`crate::service::port_factory::fan_in::PortFactoryFanIn::max_samples_per_publisher()`]
+/// [`crate::sample::Sample`]s in a row from the same [`crate::port::publisher::Publisher`].
+/// A single [`FanIn::timed_wait()`] or [`FanIn::blocking_wait()`] call waits for all
+/// [`crate::port::publisher::Publisher`]s.
+///
+/// Like the [`Subscriber`], the [`FanIn`] owns one connection per
+/// [`crate::port::publisher::Publisher`]. Keep the
+/// [`crate::service::port_factory::fan_in::PortFactoryFanIn::buffer_size()`] small when many
+/// [`crate::port::publisher::Publisher`]s are connected.
+#[derive(Debug)]
+pub struct FanIn<Service: service::Service, PayloadType: Debug + ?Sized, UserHeader: Debug = ()> {
+    subscriber: Subscriber<Service, PayloadType, UserHeader>,
+}
+
+impl<Service: service::Service, PayloadType: Debug + ?Sized, UserHeader: Debug>
+    FanIn<Service, PayloadType, UserHeader>
+{
+    pub(crate) fn new(subscriber: Subscriber<Service, PayloadType, UserHeader>) -> Self {
+        Self { subscriber }
+    }
+
+    /// Returns the [`UniqueSubscriberId`] of the [`FanIn`]
+    pub fn id(&self) -> UniqueSubscriberId {
+        self.subscriber.id()
+    }
+
+    /// Returns the buffer size of the [`FanIn`] per connected
+    /// [`crate::port::publisher::Publisher`].
+    pub fn buffer_size(&self) -> usize {
+        self.subscriber.buffer_size()
+    }
+
+    /// Returns true when at least one [`crate::sample::Sample`] of any
+    /// [`crate::port::publisher::Publisher`] can be received, otherwise false.
+    pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
+        self.subscriber.has_samples()
+    }
+
+    /// Returns the number of [`crate::sample::Sample`]s of all connected
+    /// [`crate::port::publisher::Publisher`]s that are ready to be received.
+    pub fn number_of_pending_samples(&self) -> Result<usize, ConnectionFailure> {
+        self.subscriber.number_of_pending_samples()
+    }
+
+    /// Waits until a [`crate::sample::Sample`] of any [`crate::port::publisher::Publisher`] can
+    /// be received or the timeout has passed. Returns true when a [`crate::sample::Sample`] can
+    /// be received, otherwise false.
+    pub fn timed_wait(&self, timeout: Duration) -> Result<bool, FanInWaitError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if self.has_samples_or_fail()? {
+                return Ok(true);
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Ok(false);
+            }
+
+            self.wait_for_sample_arrival(Some(remaining))?;
+        }
+    }
+
+    /// Blocks until a [`crate::sample::Sample`] of any [`crate::port::publisher::Publisher`]
+    /// can be received.
+    pub fn blocking_wait(&self) -> Result<(), FanInWaitError> {
+        loop {
+            if self.has_samples_or_fail()? {
+                return Ok(());
+            }
+
+            self.wait_for_sample_arrival(None)?;
+        }
+    }
+
+    fn has_samples_or_fail(&self) -> Result<bool, FanInWaitError> {
+        match self.has_samples() {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                fail!(from self, with FanInWaitError::ConnectionFailure(e),
+                    "Unable to wait for samples since the connections could not be updated ({:?}).", e);
+            }
+        }
+    }
+
+    // blocks until a publisher delivered samples or the timeout has passed
+    fn wait_for_sample_arrival(&self, timeout: Option<Duration>) -> Result<(), FanInWaitError> {
+        let msg = "Unable to wait for samples";
+        let listener = fail!(from self, when self.subscriber.sample_arrival_listener(),
+            with FanInWaitError::InternalFailure,
+            "{} since the sample arrival listener is not available.", msg);
+
+        // a held back sample becomes receivable without the arrival of a new sample
+        let timeout = match (timeout, self.subscriber.remaining_hold_back()) {
+            (Some(timeout), Some(hold_back)) => Some(timeout.min(hold_back)),
+            (timeout, hold_back) => timeout.or(hold_back),
+        };
+
+        let result = match timeout {
+            Some(timeout) => listener.timed_wait(timeout),
+            None => listener.blocking_wait(),
+        };
+        fail!(from self, when result,
+            with FanInWaitError::InternalFailure,
+            "{} since the sample arrival listener failed.", msg);
+        Ok(())
+    }
+}
+
+impl<Service: service::Service, PayloadType: Debug + ?Sized, UserHeader: Debug> UpdateConnections
+    for FanIn<Service, PayloadType, UserHeader>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.subscriber.update_connections()
+    }
+}
+
+impl<Service: service::Service, PayloadType: Debug, UserHeader: Debug>
+    FanIn<Service, PayloadType, UserHeader>
+{
+    /// Receives a [`crate::sample::Sample`] from the next [`crate::port::publisher::Publisher`]
+    /// in turn. If no sample could be received [`None`] is returned. If a failure occurs
+    /// [`SubscriberReceiveError`] is returned.
+    pub fn receive(
+        &self,
+    ) -> Result<Option<Sample<PayloadType, Service, UserHeader>>, SubscriberReceiveError> {
+        self.subscriber.receive()
+    }
+}
+
+impl<Service: service::Service, PayloadType: Debug + Copy, UserHeader: Debug>
+    FanIn<Service, PayloadType, UserHeader>
+{
+    /// Receives a [`crate::sample::Sample`], returns a copy of its payload and releases the
+    /// [`crate::sample::Sample`] immediately. If no sample could be received [`None`] is returned.
+    pub fn receive_copy(&self) -> Result<Option<PayloadType>, SubscriberReceiveError> {
+        self.subscriber.receive_copy()
+    }
+}
+
+impl<Service: service::Service, PayloadType: Debug, UserHeader: Debug>
+    FanIn<Service, [PayloadType], UserHeader>
+{
+    /// Receives a [`crate::sample::Sample`] from the next [`crate::port::publisher::Publisher`]
+    /// in turn. If no sample could be received [`None`] is returned. If a failure occurs
+    /// [`SubscriberReceiveError`] is returned.
+    pub fn receive(
+        &self,
+    ) -> Result<Option<Sample<[PayloadType], Service, UserHeader>>, SubscriberReceiveError> {
+        self.subscriber.receive()
+    }
+}
@@ -0,0 +1,479 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! When a [`Service`](crate::service::Service) is created with
+//! [`Builder::flight_recorder()`](crate::service::builder::publish_subscribe::Builder::flight_recorder)
+//! every [`Publisher`](crate::port::publisher::Publisher) copies the last samples it has sent
+//! into a ring buffer that is stored in a persistent POSIX shared memory. When the process of
+//! the [`Publisher`](crate::port::publisher::Publisher) crashes the recording remains and can
+//! be extracted with [`FlightRecording`] to analyze which data led to the failure. When the
+//! [`Publisher`](crate::port::publisher::Publisher) goes out of scope regularly the recording
+//! is removed. The number of retained recordings is limited by
+//! [`config::PublishSubscribe::flight_recorder_max_recordings`], when a new recording would
+//! exceed it the oldest recordings of publishers that no longer exist are removed.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::flight_recorder::FlightRecording;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .flight_recorder(16)
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher_builder().create()?;
+//! publisher.send_copy(1234)?;
+//!
+//! for name in FlightRecording::list(node.config()) {
+//!     let recording = FlightRecording::open(&name)?;
+//!     println!("recording of {:?} from service {}", recording.publisher_id(), recording.service_name());
+//!     for record in recording.records() {
+//!         println!("  #{} at {:?}: {:?}", record.sequence_number(), record.timestamp(), record.payload());
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_log::{debug, fail, warn};
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+use iceoryx2_bb_posix::creation_mode::CreationMode;
+use iceoryx2_bb_posix::permission::Permission;
+use iceoryx2_bb_posix::process::Process;
+use iceoryx2_bb_posix::shared_memory::{
+    AccessMode, SharedMemory, SharedMemoryBuilder, SharedMemoryCreationError,
+};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_system_types::file_name::FileName;
+
+use crate::config;
+use crate::port::port_identifiers::UniquePublisherId;
+use crate::service::service_name::ServiceName;
+
+const MAGIC: &[u8; 8] = b"IOX2FLR1";
+const MARKER: &[u8] = b"flight_recorder_";
+
+// layout of the recording header, every number is stored in little endian
+const CAPACITY_OFFSET: usize = 8;
+const MAX_PAYLOAD_SIZE_OFFSET: usize = 16;
+const WRITE_COUNTER_OFFSET: usize = 24;
+const PUBLISHER_ID_OFFSET: usize = 32;
+const SERVICE_NAME_LEN_OFFSET: usize = 48;
+const SERVICE_NAME_OFFSET: usize = 56;
+
+// layout of every slot: sequence number, timestamp in nanoseconds, payload size, payload
+const SLOT_HEADER_SIZE: usize = 24;
+
+/// Defines a failure that can occur when a [`FlightRecording`] is opened with
+/// [`FlightRecording::open()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum FlightRecordingOpenError {
+    DoesNotExist,
+    InsufficientPermissions,
+    Corrupted,
+    InternalError,
+}
+
+impl std::fmt::Display for FlightRecordingOpenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "FlightRecordingOpenError::{:?}", self)
+    }
+}
+
+impl std::error::Error for FlightRecordingOpenError {}
+
+fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    let mut value = [0u8; 8];
+    value.copy_from_slice(&bytes[offset..offset + 8]);
+    u64::from_le_bytes(value)
+}
+
+fn write_u64(bytes: &mut [u8], offset: usize, value: u64) {
+    bytes[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+}
+
+// the values are read from a recording that can be corrupted, therefore every calculation
+// is checked
+fn slots_offset(service_name_len: usize) -> Option<usize> {
+    SERVICE_NAME_OFFSET
+        .checked_add(service_name_len)?
+        .checked_next_multiple_of(8)
+}
+
+fn slot_size(max_payload_size: usize) -> Option<usize> {
+    SLOT_HEADER_SIZE
+        .checked_add(max_payload_size)?
+        .checked_next_multiple_of(8)
+}
+
+fn recording_size(
+    service_name_len: usize,
+    capacity: usize,
+    max_payload_size: usize,
+) -> Option<usize> {
+    slot_size(max_payload_size)?
+        .checked_mul(capacity)?
+        .checked_add(slots_offset(service_name_len)?)
+}
+
+fn recording_name(
+    config: &config::Config,
+    publisher_id: UniquePublisherId,
+) -> Result<FileName, SharedMemoryCreationError> {
+    let origin = "flight_recorder::recording_name()";
+    let msg = "The system does not support the required file name length for the flight recorder";
+    let mut name = config.global.domain_prefix();
+    fail!(from origin, when name.push_bytes(MARKER),
+        with SharedMemoryCreationError::NameTooLong, "{}.", msg);
+    fail!(from origin, when name.push_bytes(publisher_id.0.pid().to_string().as_bytes()),
+        with SharedMemoryCreationError::NameTooLong, "{}.", msg);
+    fail!(from origin, when name.push(b'_'),
+        with SharedMemoryCreationError::NameTooLong, "{}.", msg);
+    fail!(from origin, when name.push_bytes(publisher_id.0.value().to_string().as_bytes()),
+        with SharedMemoryCreationError::NameTooLong, "{}.", msg);
+    Ok(name)
+}
+
+// Removes the recordings of publishers that no longer exist, starting with the oldest one,
+// until a new recording fits into the limit. Recordings of living publishers are removed
+// when the publisher goes out of scope.
+fn remove_outdated_recordings(config: &config::Config) {
+    let max_recordings = config
+        .defaults
+        .publish_subscribe
+        .flight_recorder_max_recordings;
+    if max_recordings == 0 {
+        return;
+    }
+
+    let recordings: Vec<FlightRecording> = FlightRecording::list(config)
+        .iter()
+        .filter_map(|name| FlightRecording::open(name).ok())
+        .collect();
+    if recordings.len() < max_recordings {
+        return;
+    }
+
+    let number_of_removals = recordings.len() + 1 - max_recordings;
+    let mut outdated_recordings: Vec<FlightRecording> = recordings
+        .into_iter()
+        .filter(|recording| !Process::from_pid(recording.publisher_id().0.pid()).is_alive())
+        .collect();
+    outdated_recordings
+        .sort_by_key(|recording| recording.publisher_id().0.creation_time().as_duration());
+
+    for recording in outdated_recordings.into_iter().take(number_of_removals) {
+        debug!(from "flight_recorder::remove_outdated_recordings()",
+            "Remove the flight recording \"{}\" since the maximum number of {} recordings is reached.",
+            recording.name(), max_recordings);
+        recording.remove();
+    }
+}
+
+/// Writes the samples a [`Publisher`](crate::port::publisher::Publisher) sends into its
+/// persistent recording.
+#[derive(Debug)]
+pub(crate) struct FlightRecorder {
+    memory: SharedMemory,
+    capacity: usize,
+    max_payload_size: usize,
+    slots_offset: usize,
+    slot_size: usize,
+    write_counter: u64,
+}
+
+impl FlightRecorder {
+    pub(crate) fn new(
+        config: &config::Config,
+        service_name: &ServiceName,
+        publisher_id: UniquePublisherId,
+        capacity: usize,
+        max_payload_size: usize,
+    ) -> Result<Self, SharedMemoryCreationError> {
+        let msg = "Unable to create flight recorder";
+        let origin = "FlightRecorder::new()";
+        let name = recording_name(config, publisher_id)?;
+        let service_name = service_name.as_str().as_bytes();
+        let (slots_offset, slot_size, size) = match (
+            slots_offset(service_name.len()),
+            slot_size(max_payload_size),
+            recording_size(service_name.len(), capacity, max_payload_size),
+        ) {
+            (Some(slots_offset), Some(slot_size), Some(size)) => (slots_offset, slot_size, size),
+            _ => {
+                fail!(from origin, with SharedMemoryCreationError::SizeDoesNotFit,
+                    "{} since a recording of {} samples with up to {} bytes exceeds the supported size.",
+                    msg, capacity, max_payload_size);
+            }
+        };
+
+        remove_outdated_recordings(config);
+
+        // the recording is not owned so that it survives a crash of the process, it is removed
+        // explicitly when the recorder is dropped
+        let mut memory = fail!(from origin,
+            when SharedMemoryBuilder::new(&name)
+                .creation_mode(CreationMode::PurgeAndCreate)
+                .size(size)
+                .permission(Permission::OWNER_READ | Permission::OWNER_WRITE)
+                .zero_memory(true)
+                .has_ownership(false)
+                .create(),
+            "{} since the shared memory \"{}\" could not be created.", msg, name);
+
+        let bytes = memory.as_mut_slice();
+        write_u64(bytes, CAPACITY_OFFSET, capacity as u64);
+        write_u64(bytes, MAX_PAYLOAD_SIZE_OFFSET, max_payload_size as u64);
+        write_u64(bytes, WRITE_COUNTER_OFFSET, 0);
+        bytes[PUBLISHER_ID_OFFSET..PUBLISHER_ID_OFFSET + 16]
+            .copy_from_slice(&publisher_id.0.value().to_le_bytes());
+        write_u64(bytes, SERVICE_NAME_LEN_OFFSET, service_name.len() as u64);
+        bytes[SERVICE_NAME_OFFSET..SERVICE_NAME_OFFSET + service_name.len()]
+            .copy_from_slice(service_name);
+        // the magic is written last so that a partially initialized recording is never opened
+        bytes[0..MAGIC.len()].copy_from_slice(MAGIC);
+
+        Ok(Self {
+            memory,
+            capacity,
+            max_payload_size,
+            slots_offset,
+            slot_size,
+            write_counter: 0,
+        })
+    }
+
+    pub(crate) fn record(&mut self, payload: &[u8]) {
+        let timestamp = match Time::now_with_clock(ClockType::Realtime) {
+            Ok(time) => time.as_duration(),
+            Err(e) => {
+                warn!(from self, "Unable to acquire the timestamp of the recorded sample ({:?}).", e);
+                Duration::ZERO
+            }
+        };
+
+        let recorded_size = payload.len().min(self.max_payload_size);
+        let slot_size = self.slot_size;
+        let slot_start =
+            self.slots_offset + (self.write_counter as usize % self.capacity) * slot_size;
+        self.write_counter += 1;
+
+        let bytes = self.memory.as_mut_slice();
+        let slot = &mut bytes[slot_start..slot_start + slot_size];
+        // a slot with sequence number 0 is incomplete, when the process crashes while the slot
+        // is written it is skipped by the reader
+        write_u64(slot, 0, 0);
+        write_u64(slot, 8, timestamp.as_nanos() as u64);
+        write_u64(slot, 16, payload.len() as u64);
+        slot[SLOT_HEADER_SIZE..SLOT_HEADER_SIZE + recorded_size]
+            .copy_from_slice(&payload[..recorded_size]);
+        write_u64(slot, 0, self.write_counter);
+        write_u64(bytes, WRITE_COUNTER_OFFSET, self.write_counter);
+    }
+}
+
+impl Drop for FlightRecorder {
+    fn drop(&mut self) {
+        self.memory.acquire_ownership();
+    }
+}
+
+/// A sample that was recorded by the flight recorder of a
+/// [`Publisher`](crate::port::publisher::Publisher).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlightRecord {
+    sequence_number: u64,
+    timestamp: Duration,
+    payload_size: usize,
+    payload: Vec<u8>,
+}
+
+impl FlightRecord {
+    /// Returns the position of the sample in the sequence of all samples the
+    /// [`Publisher`](crate::port::publisher::Publisher) has sent, starting with 1.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    /// Returns the system time, relative to the unix epoch, when the sample was sent.
+    pub fn timestamp(&self) -> Duration {
+        self.timestamp
+    }
+
+    /// Returns the size in bytes of the payload of the sent sample.
+    pub fn payload_size(&self) -> usize {
+        self.payload_size
+    }
+
+    /// Returns the recorded payload of the sample.
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+}
+
+/// Read access to the persistent recording of a
+/// [`Publisher`](crate::port::publisher::Publisher), see the
+/// [module documentation](crate::port::flight_recorder).
+#[derive(Debug)]
+pub struct FlightRecording {
+    memory: SharedMemory,
+    slots_offset: usize,
+    slot_size: usize,
+}
+
+impl FlightRecording {
+    /// Returns the names of all flight recordings that exist under the prefix and in the domain
+    /// of the provided [`config::Config`].
+    pub fn list(config: &config::Config) -> Vec<FileName> {
+        let mut prefix = config.global.domain_prefix().as_bytes().to_vec();
+        prefix.extend_from_slice(MARKER);
+
+        SharedMemory::list()
+            .into_iter()
+            .filter(|name| name.as_bytes().starts_with(&prefix))
+            .collect()
+    }
+
+    /// Opens the flight recording with the provided name, see [`FlightRecording::list()`].
+    pub fn open(name: &FileName) -> Result<Self, FlightRecordingOpenError> {
+        let msg = "Unable to open flight recording";
+        let origin = "FlightRecording::open()";
+        let memory = match SharedMemoryBuilder::new(name).open_existing(AccessMode::Read) {
+            Ok(memory) => memory,
+            Err(SharedMemoryCreationError::DoesNotExist) => {
+                fail!(from origin, with FlightRecordingOpenError::DoesNotExist,
+                    "{} since \"{}\" does not exist.", msg, name);
+            }
+            Err(SharedMemoryCreationError::InsufficientPermissions) => {
+                fail!(from origin, with FlightRecordingOpenError::InsufficientPermissions,
+                    "{} since the permissions to open \"{}\" are insufficient.", msg, name);
+            }
+            Err(e) => {
+                fail!(from origin, with FlightRecordingOpenError::InternalError,
+                    "{} since an internal failure occurred while opening \"{}\" ({:?}).", msg, name, e);
+            }
+        };
+
+        match Self::verify_layout(memory.as_slice()) {
+            Some((slots_offset, slot_size)) => Ok(Self {
+                memory,
+                slots_offset,
+                slot_size,
+            }),
+            None => {
+                fail!(from origin, with FlightRecordingOpenError::Corrupted,
+                    "{} since \"{}\" is not a valid flight recording.", msg, name);
+            }
+        }
+    }
+
+    // returns the offset of the first slot and the size of a slot when the header describes a
+    // recording that fits into the shared memory
+    fn verify_layout(bytes: &[u8]) -> Option<(usize, usize)> {
+        if bytes.len() < SERVICE_NAME_OFFSET || &bytes[0..MAGIC.len()] != MAGIC {
+            return None;
+        }
+
+        let service_name_len = usize::try_from(read_u64(bytes, SERVICE_NAME_LEN_OFFSET)).ok()?;
+        let capacity = usize::try_from(read_u64(bytes, CAPACITY_OFFSET)).ok()?;
+        let max_payload_size = usize::try_from(read_u64(bytes, MAX_PAYLOAD_SIZE_OFFSET)).ok()?;
+        if recording_size(service_name_len, capacity, max_payload_size)? > bytes.len() {
+            return None;
+        }
+
+        std::str::from_utf8(&bytes[SERVICE_NAME_OFFSET..SERVICE_NAME_OFFSET + service_name_len])
+            .ok()?;
+
+        Some((
+            slots_offset(service_name_len)?,
+            slot_size(max_payload_size)?,
+        ))
+    }
+
+    fn service_name_bytes(&self) -> &[u8] {
+        let bytes = self.memory.as_slice();
+        let len = read_u64(bytes, SERVICE_NAME_LEN_OFFSET) as usize;
+        &bytes[SERVICE_NAME_OFFSET..SERVICE_NAME_OFFSET + len]
+    }
+
+    /// Returns the name of the recording.
+    pub fn name(&self) -> &FileName {
+        self.memory.name()
+    }
+
+    /// Returns the name of the [`Service`](crate::service::Service) whose samples were recorded.
+    pub fn service_name(&self) -> &str {
+        std::str::from_utf8(self.service_name_bytes()).unwrap_or_default()
+    }
+
+    /// Returns the [`UniquePublisherId`] of the [`Publisher`](crate::port::publisher::Publisher)
+    /// that recorded the samples.
+    pub fn publisher_id(&self) -> UniquePublisherId {
+        let bytes = self.memory.as_slice();
+        let mut value = [0u8; 16];
+        value.copy_from_slice(&bytes[PUBLISHER_ID_OFFSET..PUBLISHER_ID_OFFSET + 16]);
+        UniquePublisherId(UniqueSystemId::from(u128::from_le_bytes(value)))
+    }
+
+    /// Returns how many of the most recent samples the recording retains.
+    pub fn capacity(&self) -> usize {
+        read_u64(self.memory.as_slice(), CAPACITY_OFFSET) as usize
+    }
+
+    /// Returns the maximum payload size in bytes that is recorded of every sample.
+    pub fn max_payload_size(&self) -> usize {
+        read_u64(self.memory.as_slice(), MAX_PAYLOAD_SIZE_OFFSET) as usize
+    }
+
+    /// Returns how many samples were sent in total since the recording was created.
+    pub fn number_of_sent_samples(&self) -> u64 {
+        read_u64(self.memory.as_slice(), WRITE_COUNTER_OFFSET)
+    }
+
+    /// Returns the retained samples ordered from the oldest to the newest one.
+    pub fn records(&self) -> Vec<FlightRecord> {
+        let bytes = self.memory.as_slice();
+        let max_payload_size = self.max_payload_size();
+        let slot_size = self.slot_size;
+        let slots_offset = self.slots_offset;
+
+        let mut records: Vec<FlightRecord> = (0..self.capacity())
+            .map(|i| &bytes[slots_offset + i * slot_size..slots_offset + (i + 1) * slot_size])
+            .filter(|slot| read_u64(slot, 0) != 0)
+            .map(|slot| {
+                let payload_size = read_u64(slot, 16) as usize;
+                let recorded_size = payload_size.min(max_payload_size);
+                FlightRecord {
+                    sequence_number: read_u64(slot, 0),
+                    timestamp: Duration::from_nanos(read_u64(slot, 8)),
+                    payload_size,
+                    payload: slot[SLOT_HEADER_SIZE..SLOT_HEADER_SIZE + recorded_size].to_vec(),
+                }
+            })
+            .collect();
+
+        records.sort_by_key(|record| record.sequence_number);
+        records
+    }
+
+    /// Removes the recording from the system. It can no longer be opened afterwards.
+    pub fn remove(self) {
+        self.memory.acquire_ownership();
+    }
+}
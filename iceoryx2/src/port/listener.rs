@@ -60,15 +60,23 @@
 
 use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::clock::{nanosleep, NanosleepError};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::event::{ListenerBuilder, ListenerWaitError, TriggerId};
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 
+use crate::node::resource_usage::{NodeResource, NodeResourceRegistration};
+use crate::node::{Node, SharedNode};
+use crate::port::port_identifiers::UniqueNotifierId;
+use crate::service::config_scheme::event_config;
+use crate::service::dynamic_config::event::ListenerDetails;
+use crate::service::dynamic_config::ActivityTimestamp;
 use crate::service::naming_scheme::event_concept_name;
 use crate::{port::port_identifiers::UniqueListenerId, service};
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use super::event_id::EventId;
 
@@ -88,6 +96,15 @@ impl std::fmt::Display for ListenerCreateError {
 
 impl std::error::Error for ListenerCreateError {}
 
+/// Describes why [`Listener::timed_wait_all()`] returned successfully.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum TimedWaitResult {
+    /// At least one [`EventId`] was received and provided to the callback.
+    EventsReceived,
+    /// The timeout has passed without receiving any [`EventId`].
+    TimeoutElapsed,
+}
+
 /// Represents the receiving endpoint of an event based communication.
 #[derive(Debug)]
 pub struct Listener<Service: service::Service> {
@@ -95,6 +112,11 @@ pub struct Listener<Service: service::Service> {
     listener: <Service::Event as iceoryx2_cal::event::Event>::Listener,
     dynamic_storage: Arc<Service::DynamicStorage>,
     port_id: UniqueListenerId,
+    coalescing_window: Duration,
+    deadline: Option<Duration>,
+    notifier_liveliness: Option<Duration>,
+    shared_node: Arc<SharedNode<Service>>,
+    _resource_registration: NodeResourceRegistration<Service>,
 }
 
 impl<Service: service::Service> Drop for Listener<Service> {
@@ -109,7 +131,10 @@ impl<Service: service::Service> Drop for Listener<Service> {
 }
 
 impl<Service: service::Service> Listener<Service> {
-    pub(crate) fn new(service: &Service) -> Result<Self, ListenerCreateError> {
+    pub(crate) fn new(
+        service: &Service,
+        coalescing_window: Duration,
+    ) -> Result<Self, ListenerCreateError> {
         let msg = "Failed to create listener";
         let origin = "Listener::new()";
         let port_id = UniqueListenerId::new();
@@ -119,6 +144,7 @@ impl<Service: service::Service> Listener<Service> {
 
         let listener = fail!(from origin,
                              when <Service::Event as iceoryx2_cal::event::Event>::ListenerBuilder::new(&event_name)
+                                .config(&event_config::<Service>(service.state().shared_node.config()))
                                 .trigger_id_max(TriggerId::new(service.state().static_config.event().event_id_max_value))
                                 .create(),
                              with ListenerCreateError::ResourceCreationFailed,
@@ -129,6 +155,15 @@ impl<Service: service::Service> Listener<Service> {
             dynamic_listener_handle: None,
             listener,
             port_id,
+            coalescing_window,
+            deadline: service.state().static_config.event().deadline,
+            notifier_liveliness: service.state().static_config.event().notifier_liveliness,
+            shared_node: service.state().shared_node.clone(),
+            _resource_registration: NodeResourceRegistration::new(
+                service.state().shared_node.clone(),
+                NodeResource::Listener,
+                1,
+            ),
         };
 
         std::sync::atomic::compiler_fence(Ordering::SeqCst);
@@ -140,8 +175,10 @@ impl<Service: service::Service> Listener<Service> {
             .dynamic_storage
             .get()
             .event()
-            .add_listener_id(port_id)
-        {
+            .add_listener_id(ListenerDetails {
+                listener_id: port_id,
+                node_id: *service.state().shared_node.id(),
+            }) {
             Some(unique_index) => unique_index,
             None => {
                 fail!(from origin, with ListenerCreateError::ExceedsMaxSupportedListeners,
@@ -172,16 +209,40 @@ impl<Service: service::Service> Listener<Service> {
     /// currently available [`EventId`]s in buffer.
     /// For every received [`EventId`] the provided callback is called with the [`EventId`] as
     /// input argument.
+    /// Interrupt signals and spurious wakeups do not end the wait, it returns
+    /// [`TimedWaitResult::TimeoutElapsed`] only when the full timeout has passed without
+    /// receiving an [`EventId`].
     pub fn timed_wait_all<F: FnMut(EventId)>(
         &self,
-        callback: F,
+        mut callback: F,
         timeout: Duration,
-    ) -> Result<(), ListenerWaitError> {
+    ) -> Result<TimedWaitResult, ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
-        Ok(
-            fail!(from self, when self.listener.timed_wait_all(callback, timeout),
-            "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout),
-        )
+        let start = Instant::now();
+        let mut has_received_events = false;
+        while !has_received_events {
+            let remaining_time = timeout.saturating_sub(start.elapsed());
+            match self.listener.timed_wait_all(
+                |id| {
+                    has_received_events = true;
+                    callback(id)
+                },
+                remaining_time,
+            ) {
+                Ok(()) | Err(ListenerWaitError::InterruptSignal) => {
+                    if !has_received_events && timeout <= start.elapsed() {
+                        return Ok(TimedWaitResult::TimeoutElapsed);
+                    }
+                }
+                Err(e) => {
+                    fail!(from self, with e,
+                        "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout);
+                }
+            }
+        }
+
+        self.collect_coalesced_events(has_received_events, callback)?;
+        Ok(TimedWaitResult::EventsReceived)
     }
 
     /// Blocking wait for new [`EventId`]s. Collects either
@@ -192,13 +253,48 @@ impl<Service: service::Service> Listener<Service> {
     /// input argument.
     pub fn blocking_wait_all<F: FnMut(EventId)>(
         &self,
-        callback: F,
+        mut callback: F,
     ) -> Result<(), ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
-        Ok(
-            fail!(from self, when self.listener.blocking_wait_all(callback),
-            "Failed to while calling blocking_wait on underlying event::Listener"),
-        )
+        let mut has_received_events = false;
+        fail!(from self, when self.listener.blocking_wait_all(|id| {
+                has_received_events = true;
+                callback(id)
+            }),
+            "Failed to while calling blocking_wait on underlying event::Listener");
+
+        self.collect_coalesced_events(has_received_events, callback)
+    }
+
+    fn collect_coalesced_events<F: FnMut(EventId)>(
+        &self,
+        has_received_events: bool,
+        callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        if !has_received_events || self.coalescing_window.is_zero() {
+            return Ok(());
+        }
+
+        let start = Instant::now();
+        loop {
+            let remaining_window = self.coalescing_window.saturating_sub(start.elapsed());
+            if remaining_window.is_zero() {
+                break;
+            }
+
+            match nanosleep(remaining_window) {
+                Ok(()) => break,
+                // an interrupt signal must not shorten the window, sleep for the remaining time
+                Err(NanosleepError::InterruptedBySignal(_)) => continue,
+                Err(e) => {
+                    fail!(from self, with ListenerWaitError::InternalFailure,
+                        "Failed to wait for the coalescing window of {:?} since the sleep failed ({:?}).",
+                        self.coalescing_window, e);
+                }
+            }
+        }
+
+        self.try_wait_all(callback)
     }
 
     /// Non-blocking wait for a new [`EventId`]. If no [`EventId`] was notified it returns [`None`].
@@ -211,13 +307,28 @@ impl<Service: service::Service> Listener<Service> {
     }
 
     /// Blocking wait for a new [`EventId`] until either an [`EventId`] was received or the timeout
-    /// has passed. If no [`EventId`] was notified it returns [`None`].
+    /// has passed. Interrupt signals and spurious wakeups do not end the wait, it returns [`None`]
+    /// only when the full timeout has passed without receiving an [`EventId`].
     /// On error it returns [`ListenerWaitError`] is returned which describes the error
     /// in detail.
     pub fn timed_wait_one(&self, timeout: Duration) -> Result<Option<EventId>, ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
-        Ok(fail!(from self, when self.listener.timed_wait_one(timeout),
-            "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout))
+        let start = Instant::now();
+        loop {
+            let remaining_time = timeout.saturating_sub(start.elapsed());
+            match self.listener.timed_wait_one(remaining_time) {
+                Ok(Some(id)) => return Ok(Some(id)),
+                Ok(None) | Err(ListenerWaitError::InterruptSignal) => {
+                    if timeout <= start.elapsed() {
+                        return Ok(None);
+                    }
+                }
+                Err(e) => {
+                    fail!(from self, with e,
+                        "Failed to while calling timed_wait({:?}) on underlying event::Listener", timeout);
+                }
+            }
+        }
     }
 
     /// Blocking wait for a new [`EventId`].
@@ -230,8 +341,140 @@ impl<Service: service::Service> Listener<Service> {
             "Failed to while calling blocking_wait on underlying event::Listener"))
     }
 
+    /// Returns a [`Future`](core::future::Future) that resolves to the next received
+    /// [`EventId`] or to a [`ListenerWaitError`]. The future does not depend on a specific
+    /// executor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::port::listener::Listener;
+    ///
+    /// async fn print_next_event(
+    ///     listener: &Listener<zero_copy::Service>,
+    /// ) -> Result<(), Box<dyn std::error::Error>> {
+    ///     let event_id = listener.wait_async().await?;
+    ///     println!("event was triggered with id: {:?}", event_id);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn wait_async(&self) -> ListenerWaitFuture<'_, Service> {
+        ListenerWaitFuture {
+            listener: self,
+            registration: super::details::async_poller::AsyncRegistration::default(),
+        }
+    }
+
     /// Returns the [`UniqueListenerId`] of the [`Listener`]
     pub fn id(&self) -> UniqueListenerId {
         self.port_id
     }
+
+    /// Returns the coalescing window of the [`Listener`]. See
+    /// [`crate::service::port_factory::listener::PortFactoryListener::coalescing_window()`].
+    pub fn coalescing_window(&self) -> Duration {
+        self.coalescing_window
+    }
+
+    /// Returns the number of notifications that were not delivered to the [`Listener`]s of
+    /// the service since they were coalesced by their [`crate::port::notifier::Notifier`], see
+    /// [`crate::service::port_factory::notifier::PortFactoryNotifier::coalescing_window()`].
+    pub fn number_of_coalesced_notifications(&self) -> u64 {
+        self.dynamic_storage
+            .get()
+            .event()
+            .number_of_coalesced_notifications()
+    }
+
+    /// Returns the deadline of the service, see
+    /// [`crate::service::static_config::event::StaticConfig::deadline()`].
+    pub fn deadline(&self) -> Option<Duration> {
+        self.deadline
+    }
+
+    /// Returns true when the service has a deadline and no
+    /// [`crate::port::notifier::Notifier`] notified within it, otherwise false.
+    pub fn has_missed_deadline(&self) -> bool {
+        match self.deadline {
+            Some(deadline) => self.last_notification().elapsed() > deadline,
+            None => false,
+        }
+    }
+
+    /// Returns the interval in which every [`crate::port::notifier::Notifier`] must show a
+    /// sign of life, see
+    /// [`crate::service::static_config::event::StaticConfig::notifier_liveliness()`].
+    pub fn notifier_liveliness(&self) -> Option<Duration> {
+        self.notifier_liveliness
+    }
+
+    /// Returns the [`UniqueNotifierId`]s of all [`crate::port::notifier::Notifier`]s that lost
+    /// their liveliness since they did not show a sign of life within
+    /// [`Listener::notifier_liveliness()`] or since the process of their [`Node`] died.
+    /// Returns an empty list when the service does not track the liveliness of the notifiers.
+    pub fn notifiers_with_lost_liveliness(&self) -> Vec<UniqueNotifierId> {
+        let interval = match self.notifier_liveliness {
+            Some(interval) => interval,
+            None => return vec![],
+        };
+
+        let mut notifiers = vec![];
+        self.dynamic_storage
+            .get()
+            .event()
+            .for_each_notifier_heartbeat(|details, elapsed| {
+                if elapsed > interval
+                    || Node::<Service>::is_dead(self.shared_node.config(), details.node_id)
+                {
+                    notifiers.push(details.notifier_id);
+                }
+            });
+        notifiers
+    }
+
+    pub(crate) fn last_notification(&self) -> &ActivityTimestamp {
+        self.dynamic_storage.get().event().last_notification()
+    }
+
+    /// Returns the underlying event concept as [`SynchronousMultiplexing`] object when it is
+    /// based on a file descriptor.
+    pub(crate) fn as_multiplexable(&self) -> Option<&dyn SynchronousMultiplexing> {
+        use iceoryx2_cal::event::Listener;
+        self.listener.as_multiplexable()
+    }
+}
+
+/// The [`Future`](core::future::Future) returned by [`Listener::wait_async()`]. It resolves as
+/// soon as an [`EventId`] was received.
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub struct ListenerWaitFuture<'listener, Service: service::Service> {
+    listener: &'listener Listener<Service>,
+    registration: super::details::async_poller::AsyncRegistration,
+}
+
+#[cfg(feature = "async")]
+impl<Service: service::Service> core::future::Future for ListenerWaitFuture<'_, Service> {
+    type Output = Result<EventId, ListenerWaitError>;
+
+    fn poll(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Self::Output> {
+        let this = self.get_mut();
+        match this.listener.try_wait_one() {
+            Ok(Some(event_id)) => core::task::Poll::Ready(Ok(event_id)),
+            Ok(None) => {
+                this.registration.wake_when_readable(
+                    this.listener.as_multiplexable(),
+                    None,
+                    cx.waker(),
+                );
+                core::task::Poll::Pending
+            }
+            Err(e) => core::task::Poll::Ready(Err(e)),
+        }
+    }
 }
@@ -0,0 +1,144 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//! let publisher = service.publisher_builder().create()?;
+//!
+//! publisher.send_copy(1234)?;
+//!
+//! let metrics = publisher.metrics();
+//! println!("{}", metrics);
+//! assert_eq!(metrics.number_of_sent_samples(), 1);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::port::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+
+/// The metrics of the connection from a [`Publisher`](crate::port::publisher::Publisher) to a
+/// [`Subscriber`](crate::port::subscriber::Subscriber).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectionMetrics {
+    subscriber_id: UniqueSubscriberId,
+    dropped_samples: u64,
+}
+
+impl ConnectionMetrics {
+    pub(crate) fn new(subscriber_id: UniqueSubscriberId, dropped_samples: u64) -> Self {
+        Self {
+            subscriber_id,
+            dropped_samples,
+        }
+    }
+
+    /// Returns the [`UniqueSubscriberId`] of the connected
+    /// [`Subscriber`](crate::port::subscriber::Subscriber).
+    pub fn subscriber_id(&self) -> UniqueSubscriberId {
+        self.subscriber_id
+    }
+
+    /// Returns the number of samples that were dropped for the
+    /// [`Subscriber`](crate::port::subscriber::Subscriber), either since its buffer overflowed
+    /// or since the sample was discarded.
+    pub fn number_of_dropped_samples(&self) -> u64 {
+        self.dropped_samples
+    }
+}
+
+/// A snapshot of the runtime metrics of a [`Publisher`](crate::port::publisher::Publisher).
+/// The metrics are stored in the shared memory of the service and are therefore also available
+/// to other processes, see [`Node::metrics()`](crate::node::Node::metrics) and
+/// [`AliveNodeView::metrics()`](crate::node::AliveNodeView::metrics). Acquired with
+/// [`Publisher::metrics()`](crate::port::publisher::Publisher::metrics).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublisherMetrics {
+    pub(crate) publisher_id: UniquePublisherId,
+    pub(crate) sent_samples: u64,
+    pub(crate) dropped_samples: u64,
+    pub(crate) loan_failures: u64,
+    pub(crate) samples_in_use: usize,
+    pub(crate) sample_capacity: usize,
+    pub(crate) connections: Vec<ConnectionMetrics>,
+}
+
+impl PublisherMetrics {
+    /// Returns the [`UniquePublisherId`] of the [`Publisher`](crate::port::publisher::Publisher).
+    pub fn publisher_id(&self) -> UniquePublisherId {
+        self.publisher_id
+    }
+
+    /// Returns the number of samples that were sent.
+    pub fn number_of_sent_samples(&self) -> u64 {
+        self.sent_samples
+    }
+
+    /// Returns the number of samples that were dropped over all connections, including the
+    /// connections to observers.
+    pub fn number_of_dropped_samples(&self) -> u64 {
+        self.dropped_samples
+    }
+
+    /// Returns the number of loans that failed, for instance since the data segment was out of
+    /// memory or since the maximum number of loaned samples was exceeded.
+    pub fn number_of_loan_failures(&self) -> u64 {
+        self.loan_failures
+    }
+
+    /// Returns the number of samples in the data segment that are currently loaned or held
+    /// by subscribers.
+    pub fn number_of_samples_in_use(&self) -> usize {
+        self.samples_in_use
+    }
+
+    /// Returns the number of samples the data segment was dimensioned for.
+    pub fn sample_capacity(&self) -> usize {
+        self.sample_capacity
+    }
+
+    /// Returns the ratio of the samples in use to the sample capacity, a value between `0.0`
+    /// and `1.0` for a data segment that did not grow.
+    pub fn segment_utilization(&self) -> f64 {
+        match self.sample_capacity {
+            0 => 0.0,
+            capacity => self.samples_in_use as f64 / capacity as f64,
+        }
+    }
+
+    /// Returns the [`ConnectionMetrics`] of every connected
+    /// [`Subscriber`](crate::port::subscriber::Subscriber).
+    pub fn connections(&self) -> &[ConnectionMetrics] {
+        &self.connections
+    }
+}
+
+impl std::fmt::Display for PublisherMetrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} sent samples, {} dropped samples, {} loan failures, {} of {} samples in use",
+            self.sent_samples,
+            self.dropped_samples,
+            self.loan_failures,
+            self.samples_in_use,
+            self.sample_capacity
+        )
+    }
+}
@@ -16,24 +16,43 @@ use tiny_fn::tiny_fn;
 
 pub(crate) mod details;
 
+/// Tracks which subscribers have processed the samples of a publisher.
+pub mod acknowledgment;
+pub mod connected_port;
+/// Metadata of samples that could not be delivered and were routed into a dead-letter service.
+pub mod dead_letter;
 /// Defines the event id used to identify the source of an event.
 pub mod event_id;
+/// Receiving endpoint (port) that merges the samples of many publishers fairly
+pub mod fan_in;
+/// Persistent recording of the most recent samples of a publisher for post-mortem analysis.
+pub mod flight_recorder;
 /// Receiving endpoint (port) for event based communication
 pub mod listener;
+/// Runtime metrics of the ports that are stored in the shared memory of the service.
+pub mod metrics;
 /// Sending endpoint (port) for event based communication
 pub mod notifier;
 /// Defines port specific unique ids. Used to identify source/destination while communicating.
 pub mod port_identifiers;
 /// Sending endpoint (port) for publish-subscribe based communication
 pub mod publisher;
+/// Receiving endpoint (port) for blackboard based communication
+pub mod reader;
+/// Raises signals in the current process for received events
+pub mod signal_bridge;
 /// Receiving endpoint (port) for publish-subscribe based communication
 pub mod subscriber;
 /// Interface to perform cyclic updates to the ports. Required to deliver history to new
 /// participants or to perform other management tasks.
 pub mod update_connections;
+pub mod waitset;
+/// Sending endpoint (port) for blackboard based communication
+pub mod writer;
 
 use crate::port::port_identifiers::*;
 use crate::service;
+use crate::service::header::publish_subscribe::Header;
 
 /// Defines the action a port shall take when an internal failure occurs. Can happen when the
 /// system is corrupted and files are modified by non-iceoryx2 instances. Is used as return value of
@@ -58,3 +77,24 @@ impl<'a> Debug for DegrationCallback<'a> {
         write!(f, "")
     }
 }
+
+/// Is called with the [`Header`] of every [`crate::sample::Sample`] that passes a port, see
+/// [`crate::service::port_factory::publisher::PortFactoryPublisher::set_send_hook()`] and
+/// [`crate::service::port_factory::subscriber::PortFactorySubscriber::set_receive_hook()`].
+pub(crate) struct SampleHook(Box<dyn Fn(&Header)>);
+
+impl SampleHook {
+    pub(crate) fn new<F: Fn(&Header) + 'static>(hook: F) -> Self {
+        Self(Box::new(hook))
+    }
+
+    pub(crate) fn call(&self, header: &Header) {
+        (self.0)(header)
+    }
+}
+
+impl Debug for SampleHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SampleHook")
+    }
+}
@@ -37,8 +37,17 @@
 
 use super::{event_id::EventId, port_identifiers::UniqueListenerId};
 use crate::{
+    node::{
+        resource_usage::{NodeResource, NodeResourceRegistration},
+        SharedNode,
+    },
     port::port_identifiers::UniqueNotifierId,
-    service::{self, naming_scheme::event_concept_name},
+    service::{
+        self,
+        config_scheme::event_config,
+        dynamic_config::event::{ListenerDetails, NotifierDetails},
+        naming_scheme::event_concept_name,
+    },
 };
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{debug, fail, warn};
@@ -46,12 +55,14 @@ use iceoryx2_cal::{dynamic_storage::DynamicStorage, event::NotifierBuilder};
 use iceoryx2_cal::{event::Event, named_concept::NamedConceptBuilder};
 use std::{
     cell::UnsafeCell,
+    collections::HashMap,
     sync::{atomic::Ordering, Arc},
+    time::{Duration, Instant},
 };
 
 /// Failures that can occur when a new [`Notifier`] is created with the
 /// [`crate::service::port_factory::notifier::PortFactoryNotifier`].
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
 pub enum NotifierCreateError {
     ExceedsMaxSupportedNotifiers,
 }
@@ -68,6 +79,9 @@ impl std::error::Error for NotifierCreateError {}
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum NotifierNotifyError {
     EventIdOutOfBounds,
+    /// The notification was delivered but the previous notification of the service is longer
+    /// ago than the deadline of the service.
+    MissedDeadline,
 }
 
 impl std::fmt::Display for NotifierNotifyError {
@@ -82,18 +96,21 @@ impl std::error::Error for NotifierNotifyError {}
 struct Connection<Service: service::Service> {
     notifier: <Service::Event as Event>::Notifier,
     listener_id: UniqueListenerId,
+    _resource_registration: NodeResourceRegistration<Service>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 struct ListenerConnections<Service: service::Service> {
     #[allow(clippy::type_complexity)]
     connections: Vec<UnsafeCell<Option<Connection<Service>>>>,
+    shared_node: Arc<SharedNode<Service>>,
 }
 
 impl<Service: service::Service> ListenerConnections<Service> {
-    fn new(size: usize) -> Self {
+    fn new(size: usize, shared_node: Arc<SharedNode<Service>>) -> Self {
         let mut new_self = Self {
             connections: vec![],
+            shared_node,
         };
 
         new_self.connections.reserve(size);
@@ -109,12 +126,18 @@ impl<Service: service::Service> ListenerConnections<Service> {
         let event_name = event_concept_name(&listener_id);
         if self.get(index).is_none() {
             match <Service::Event as iceoryx2_cal::event::Event>::NotifierBuilder::new(&event_name)
+                .config(&event_config::<Service>(self.shared_node.config()))
                 .open()
             {
                 Ok(notifier) => {
                     *self.get_mut(index) = Some(Connection {
                         notifier,
                         listener_id,
+                        _resource_registration: NodeResourceRegistration::new(
+                            self.shared_node.clone(),
+                            NodeResource::Connection,
+                            1,
+                        ),
                     });
                 }
                 Err(
@@ -158,12 +181,18 @@ impl<Service: service::Service> ListenerConnections<Service> {
 #[derive(Debug)]
 pub struct Notifier<Service: service::Service> {
     listener_connections: ListenerConnections<Service>,
-    listener_list_state: UnsafeCell<ContainerState<UniqueListenerId>>,
+    listener_list_state: UnsafeCell<ContainerState<ListenerDetails>>,
     default_event_id: EventId,
+    coalescing_window: Duration,
+    // the time of the last delivered notification of every EventId, used for the coalescing
+    last_delivery: UnsafeCell<HashMap<EventId, Instant>>,
     event_id_max_value: usize,
+    deadline: Option<Duration>,
+    notifier_liveliness: Option<Duration>,
     dynamic_storage: Arc<Service::DynamicStorage>,
     dynamic_notifier_handle: Option<ContainerHandle>,
     port_id: UniqueNotifierId,
+    _resource_registration: NodeResourceRegistration<Service>,
 }
 
 impl<Service: service::Service> Drop for Notifier<Service> {
@@ -181,6 +210,7 @@ impl<Service: service::Service> Notifier<Service> {
     pub(crate) fn new(
         service: &Service,
         default_event_id: EventId,
+        coalescing_window: Duration,
     ) -> Result<Self, NotifierCreateError> {
         let msg = "Unable to create Notifier port";
         let origin = "Notifier::new()";
@@ -190,13 +220,25 @@ impl<Service: service::Service> Notifier<Service> {
         let dynamic_storage = Arc::clone(&service.state().dynamic_storage);
 
         let mut new_self = Self {
-            listener_connections: ListenerConnections::new(listener_list.capacity()),
+            listener_connections: ListenerConnections::new(
+                listener_list.capacity(),
+                service.state().shared_node.clone(),
+            ),
             default_event_id,
+            coalescing_window,
+            last_delivery: UnsafeCell::new(HashMap::new()),
             listener_list_state: unsafe { UnsafeCell::new(listener_list.get_state()) },
             dynamic_storage,
             event_id_max_value: service.state().static_config.event().event_id_max_value,
+            deadline: service.state().static_config.event().deadline,
+            notifier_liveliness: service.state().static_config.event().notifier_liveliness,
             dynamic_notifier_handle: None,
             port_id,
+            _resource_registration: NodeResourceRegistration::new(
+                service.state().shared_node.clone(),
+                NodeResource::Notifier,
+                0,
+            ),
         };
 
         new_self.populate_listener_channels();
@@ -205,12 +247,12 @@ impl<Service: service::Service> Notifier<Service> {
 
         // !MUST! be the last task otherwise a notifier is added to the dynamic config without
         // the creation of all required channels
-        let dynamic_notifier_handle = match new_self
-            .dynamic_storage
-            .get()
-            .event()
-            .add_notifier_id(port_id)
-        {
+        let dynamic_notifier_handle = match new_self.dynamic_storage.get().event().add_notifier_id(
+            NotifierDetails {
+                notifier_id: port_id,
+                node_id: *service.state().shared_node.id(),
+            },
+        ) {
             Some(handle) => handle,
             None => {
                 fail!(from origin, with NotifierCreateError::ExceedsMaxSupportedNotifiers,
@@ -240,8 +282,8 @@ impl<Service: service::Service> Notifier<Service> {
         visited_indices.resize(self.listener_connections.len(), None);
 
         unsafe {
-            (*self.listener_list_state.get()).for_each(|index, listener_id| {
-                visited_indices[index as usize] = Some(*listener_id);
+            (*self.listener_list_state.get()).for_each(|index, details| {
+                visited_indices[index as usize] = Some(details.listener_id);
             })
         };
 
@@ -273,6 +315,48 @@ impl<Service: service::Service> Notifier<Service> {
         self.port_id
     }
 
+    /// Returns the coalescing window of the [`Notifier`]. See
+    /// [`crate::service::port_factory::notifier::PortFactoryNotifier::coalescing_window()`].
+    pub fn coalescing_window(&self) -> Duration {
+        self.coalescing_window
+    }
+
+    // returns true when a notification with the same EventId was delivered within the
+    // coalescing window, otherwise the current time is stored as the last delivery
+    fn is_coalesced(&self, value: EventId) -> bool {
+        if self.coalescing_window.is_zero() {
+            return false;
+        }
+
+        let now = Instant::now();
+        let last_delivery = unsafe { &mut *self.last_delivery.get() };
+        match last_delivery.get(&value) {
+            Some(t) if now.saturating_duration_since(*t) < self.coalescing_window => true,
+            _ => {
+                last_delivery.insert(value, now);
+                false
+            }
+        }
+    }
+
+    /// Shows a sign of life without notifying the [`crate::port::listener::Listener`]s. When
+    /// the service tracks the liveliness of the notifiers, see
+    /// [`crate::service::static_config::event::StaticConfig::notifier_liveliness()`], a
+    /// [`Notifier`] that notifies less frequently than the interval must call it regularly,
+    /// otherwise it loses its liveliness.
+    pub fn assert_liveliness(&self) {
+        if self.notifier_liveliness.is_none() {
+            return;
+        }
+
+        if let Some(handle) = self.dynamic_notifier_handle {
+            self.dynamic_storage
+                .get()
+                .event()
+                .update_notifier_heartbeat(handle.index());
+        }
+    }
+
     /// Notifies all [`crate::port::listener::Listener`] connected to the service with the default
     /// event id provided on creation.
     /// On success the number of
@@ -286,13 +370,19 @@ impl<Service: service::Service> Notifier<Service> {
     /// [`EventId`].
     /// On success the number of
     /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
-    /// [`NotifierNotifyError`].
+    /// [`NotifierNotifyError`]. When the service has a deadline and the previous notification
+    /// is longer ago than the deadline, the [`crate::port::listener::Listener`]s are notified
+    /// and [`NotifierNotifyError::MissedDeadline`] is returned. Every notification is also a
+    /// sign of life, see [`Notifier::assert_liveliness()`]. A notification that is coalesced,
+    /// see [`Notifier::coalescing_window()`], notifies no
+    /// [`crate::port::listener::Listener`] and returns 0.
     pub fn notify_with_custom_event_id(
         &self,
         value: EventId,
     ) -> Result<usize, NotifierNotifyError> {
         let msg = "Unable to notify event";
         self.update_connections();
+        self.assert_liveliness();
 
         use iceoryx2_cal::event::Notifier;
         let mut number_of_triggered_listeners = 0;
@@ -303,6 +393,14 @@ impl<Service: service::Service> Notifier<Service> {
                             msg, value, self.event_id_max_value);
         }
 
+        if self.is_coalesced(value) {
+            self.dynamic_storage
+                .get()
+                .event()
+                .increment_coalesced_notifications();
+            return Ok(0);
+        }
+
         for i in 0..self.listener_connections.len() {
             match self.listener_connections.get(i) {
                 Some(ref connection) => match connection.notifier.notify(value) {
@@ -321,6 +419,20 @@ impl<Service: service::Service> Notifier<Service> {
             }
         }
 
+        if let Some(deadline) = self.deadline {
+            let elapsed = self
+                .dynamic_storage
+                .get()
+                .event()
+                .last_notification()
+                .update();
+            if elapsed > deadline {
+                fail!(from self, with NotifierNotifyError::MissedDeadline,
+                    "{} in time since the previous notification was {:?} ago but the deadline is {:?}.",
+                    msg, elapsed, deadline);
+            }
+        }
+
         Ok(number_of_triggered_listeners)
     }
 }
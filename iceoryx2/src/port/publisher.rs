@@ -101,39 +101,73 @@
 //! # }
 //! ```
 
-use super::port_identifiers::UniquePublisherId;
+#![cfg_attr(
+    feature = "safety",
+    deny(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::indexing_slicing,
+        clippy::unreachable
+    )
+)]
+
+use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+use crate::node::resource_usage::{NodeResource, NodeResourceRegistration};
+use crate::node::{Node, SharedMemoryReservation, SharedNode};
+use crate::port::acknowledgment::{
+    AcknowledgmentReport, AcknowledgmentTracker, AcknowledgmentWaitError, SampleId,
+};
+use crate::port::connected_port::ConnectedPort;
+use crate::port::dead_letter::{DeadLetter, DeadLetterChannel, DeadLetterReason};
+use crate::port::details::sample_arrival::SampleArrivalNotifier;
 use crate::port::details::subscriber_connections::*;
+use crate::port::flight_recorder::FlightRecorder;
+use crate::port::metrics::PublisherMetrics;
 use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
 use crate::port::DegrationAction;
 use crate::raw_sample::RawSampleMut;
+use crate::safety::{fatal_panic_or_error, fatal_panic_or_fail};
 use crate::service;
 use crate::service::config_scheme::data_segment_config;
-use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
+use crate::service::dynamic_config::publish_subscribe::{
+    PublisherDetails, PublisherMetric, PublisherSlotError, SubscriberDetails,
+};
+use crate::service::dynamic_config::ActivityTimestamp;
 use crate::service::header::publish_subscribe::Header;
 use crate::service::naming_scheme::data_segment_name;
-use crate::service::port_factory::publisher::{LocalPublisherConfig, UnableToDeliverStrategy};
+use crate::service::port_factory::publisher::{
+    AllocationStrategy, DataSegmentAllocator, LocalPublisherConfig, UnableToDeliverStrategy,
+    ZeroingPolicy, MAX_NUMBER_OF_MEM_POOLS,
+};
+use crate::service::port_factory::subscriber::{GroupDeliveryStrategy, OverflowStrategy};
 use crate::service::static_config::publish_subscribe::{self};
-use crate::{config, sample_mut::SampleMut};
+use crate::{config, sample_mut::SampleMut, sample_mut_batch::SampleMutBatch};
 use iceoryx2_bb_container::queue::Queue;
 use iceoryx2_bb_elementary::allocator::AllocationError;
 use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_elementary::math::greatest_common_divisor;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{error, fail, fatal_panic, warn};
+use iceoryx2_bb_posix::adaptive_wait::{AdaptiveTimedWaitWhileError, AdaptiveWaitBuilder};
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
 use iceoryx2_cal::shared_memory::{
     SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError, ShmPointer,
 };
-use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
-use iceoryx2_cal::shm_allocator::{self, PointerOffset, ShmAllocationError};
+use iceoryx2_cal::shm_allocator::sanitizer::{poison_memory_region, unpoison_memory_region};
+use iceoryx2_cal::shm_allocator::selectable_allocator::SelectableAllocator;
+pub use iceoryx2_cal::shm_allocator::FragmentationReport;
+use iceoryx2_cal::shm_allocator::{self, PointerOffset, PointerOffsetError, ShmAllocationError};
 use iceoryx2_cal::zero_copy_connection::{
-    ZeroCopyConnection, ZeroCopyCreationError, ZeroCopySendError, ZeroCopySender,
+    ZeroCopyCreationError, ZeroCopySendError, ZeroCopySender,
 };
 use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, IoxAtomicUsize};
-use std::cell::UnsafeCell;
+use std::cell::{OnceCell, UnsafeCell};
 use std::fmt::Debug;
 use std::sync::atomic::Ordering;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use std::{alloc::Layout, marker::PhantomData, mem::MaybeUninit};
 
 /// Defines a failure that can occur when a [`Publisher`] is created with
@@ -141,7 +175,16 @@ use std::{alloc::Layout, marker::PhantomData, mem::MaybeUninit};
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum PublisherCreateError {
     ExceedsMaxSupportedPublishers,
+    ExceedsServiceMemoryBudget,
+    ExceedsNodeSharedMemoryQuota,
     UnableToCreateDataSegment,
+    UnableToCreateDeadLetterChannel,
+    UnableToCreateFlightRecorder,
+    /// The memory pools of the
+    /// [`DataSegmentAllocator::MultiPool`](crate::service::port_factory::publisher::DataSegmentAllocator::MultiPool)
+    /// are invalid, see
+    /// [`PortFactoryPublisher::mem_pool()`](crate::service::port_factory::publisher::PortFactoryPublisher::mem_pool).
+    InvalidMemPoolConfig,
 }
 
 impl std::fmt::Display for PublisherCreateError {
@@ -152,13 +195,206 @@ impl std::fmt::Display for PublisherCreateError {
 
 impl std::error::Error for PublisherCreateError {}
 
+/// Describes how the size of the data segment of a [`Publisher`] is composed, so that one can
+/// see which setting has to be reduced when the creation fails with
+/// [`PublisherCreateError::ExceedsServiceMemoryBudget`],
+/// [`PublisherCreateError::ExceedsNodeSharedMemoryQuota`] or
+/// [`PublisherCreateError::UnableToCreateDataSegment`]. It can be acquired before the
+/// [`Publisher`] is created with
+/// [`PortFactoryPublisher::memory_breakdown()`](crate::service::port_factory::publisher::PortFactoryPublisher::memory_breakdown).
+///
+/// The data segment must provide a sample for every slot a sample can be stored in:
+///  * the buffer and the borrowed samples of every [`crate::port::subscriber::Subscriber`]
+///  * the buffer and the borrowed samples of every observer
+///  * the history
+///  * the loaned samples of the [`Publisher`] plus the sample that is currently delivered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublisherMemoryBreakdown {
+    sample_size: usize,
+    subscriber_queue_samples: usize,
+    observer_queue_samples: usize,
+    history_samples: usize,
+    loan_samples: usize,
+    data_segment_size: usize,
+}
+
+impl PublisherMemoryBreakdown {
+    pub(crate) fn new(
+        static_config: &publish_subscribe::StaticConfig,
+        config: &LocalPublisherConfig,
+    ) -> Self {
+        Self::with_max_slice_len(static_config, config, config.max_slice_len)
+    }
+
+    // the breakdown of a segment that was added to a growing data segment
+    fn with_max_slice_len(
+        static_config: &publish_subscribe::StaticConfig,
+        config: &LocalPublisherConfig,
+        max_slice_len: usize,
+    ) -> Self {
+        let sample_layout = static_config.type_details().sample_layout(max_slice_len);
+        let canary_size = if config.enable_canaries {
+            CANARY_SIZE
+        } else {
+            0
+        };
+        let samples_per_subscriber = static_config.subscriber_max_buffer_size
+            + static_config.subscriber_max_borrowed_samples;
+        let subscriber_queue_samples = static_config.max_subscribers * samples_per_subscriber;
+        let observer_queue_samples = static_config.max_observers * samples_per_subscriber;
+        let history_samples = static_config.history_size;
+        let loan_samples = config.max_loaned_samples + 1;
+        let number_of_samples =
+            subscriber_queue_samples + observer_queue_samples + history_samples + loan_samples;
+
+        let (sample_size, data_size) = match config.data_segment_allocator {
+            DataSegmentAllocator::Pool => {
+                let sample_size = Self::bucket_size(static_config, config, max_slice_len);
+                (sample_size, sample_size * number_of_samples)
+            }
+            DataSegmentAllocator::BumpPerCycle | DataSegmentAllocator::BestFit => {
+                let sample_size = sample_layout.size() + canary_size;
+                (sample_size, sample_size * number_of_samples)
+            }
+            DataSegmentAllocator::MultiPool => {
+                let buckets =
+                    Self::mem_pool_buckets(static_config, config, max_slice_len, number_of_samples);
+                (
+                    buckets.last().map_or(0, |(bucket_size, _)| *bucket_size),
+                    buckets
+                        .iter()
+                        .map(|(bucket_size, number_of_buckets)| bucket_size * number_of_buckets)
+                        .sum(),
+                )
+            }
+        };
+
+        Self {
+            sample_size,
+            subscriber_queue_samples,
+            observer_queue_samples,
+            history_samples,
+            loan_samples,
+            data_segment_size: data_size + sample_layout.align() - 1,
+        }
+    }
+
+    // the size of a sample with `max_slice_len` elements including the canary, rounded up to
+    // the alignment so that the samples can be placed one after another
+    fn bucket_size(
+        static_config: &publish_subscribe::StaticConfig,
+        config: &LocalPublisherConfig,
+        max_slice_len: usize,
+    ) -> usize {
+        let sample_layout = static_config.type_details().sample_layout(max_slice_len);
+        let canary_size = if config.enable_canaries {
+            CANARY_SIZE
+        } else {
+            0
+        };
+
+        (sample_layout.size() + canary_size).next_multiple_of(sample_layout.align())
+    }
+
+    // the bucket size and the number of buckets of every memory pool of the
+    // [`DataSegmentAllocator::MultiPool`] sorted by the bucket size
+    fn mem_pool_buckets(
+        static_config: &publish_subscribe::StaticConfig,
+        config: &LocalPublisherConfig,
+        max_slice_len: usize,
+        number_of_samples: usize,
+    ) -> Vec<(usize, usize)> {
+        config
+            .mem_pools(max_slice_len, number_of_samples)
+            .iter()
+            .map(|pool| {
+                (
+                    Self::bucket_size(static_config, config, pool.max_slice_len),
+                    pool.number_of_samples,
+                )
+            })
+            .collect()
+    }
+
+    /// The number of bytes a single sample occupies in the data segment, including its header,
+    /// the alignment and the canaries. For the
+    /// [`DataSegmentAllocator::MultiPool`] it is the size of a sample of the largest memory pool.
+    pub fn sample_size(&self) -> usize {
+        self.sample_size
+    }
+
+    /// The number of samples the data segment provides.
+    pub fn number_of_samples(&self) -> usize {
+        self.subscriber_queue_samples
+            + self.observer_queue_samples
+            + self.history_samples
+            + self.loan_samples
+    }
+
+    /// The memory in bytes required for the buffers and borrowed samples of all
+    /// [`crate::port::subscriber::Subscriber`]s, it grows with `max_subscribers`,
+    /// `subscriber_max_buffer_size` and `subscriber_max_borrowed_samples`.
+    pub fn subscriber_queue_memory(&self) -> usize {
+        self.subscriber_queue_samples * self.sample_size
+    }
+
+    /// The memory in bytes required for the buffers and borrowed samples of all observers, it
+    /// grows with `max_observers`, `subscriber_max_buffer_size` and
+    /// `subscriber_max_borrowed_samples`.
+    pub fn observer_queue_memory(&self) -> usize {
+        self.observer_queue_samples * self.sample_size
+    }
+
+    /// The memory in bytes required for the history, it grows with `history_size`.
+    pub fn history_memory(&self) -> usize {
+        self.history_samples * self.sample_size
+    }
+
+    /// The memory in bytes required for the loans of the [`Publisher`], it grows with
+    /// `max_loaned_samples`.
+    pub fn loan_memory(&self) -> usize {
+        self.loan_samples * self.sample_size
+    }
+
+    /// The total size of the data segment in bytes.
+    pub fn data_segment_size(&self) -> usize {
+        self.data_segment_size
+    }
+}
+
+impl std::fmt::Display for PublisherMemoryBreakdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(
+            f,
+            "data segment of {} bytes for {} samples of {} bytes: subscriber queues {} bytes ({} samples, max_subscribers * (subscriber_max_buffer_size + subscriber_max_borrowed_samples)), observer queues {} bytes ({} samples, max_observers * (subscriber_max_buffer_size + subscriber_max_borrowed_samples)), history {} bytes ({} samples, history_size), loans {} bytes ({} samples, max_loaned_samples + 1)",
+            self.data_segment_size,
+            self.number_of_samples(),
+            self.sample_size,
+            self.subscriber_queue_memory(),
+            self.subscriber_queue_samples,
+            self.observer_queue_memory(),
+            self.observer_queue_samples,
+            self.history_memory(),
+            self.history_samples,
+            self.loan_memory(),
+            self.loan_samples
+        )
+    }
+}
+
 /// Defines a failure that can occur in [`Publisher::loan()`] and [`Publisher::loan_uninit()`]
-/// or is part of [`PublisherSendError`] emitted in [`Publisher::send_copy()`].
+/// or is part of [`PublisherSendError`] emitted in [`Publisher::send_copy()`] and
+/// [`Publisher::send_slice_copy()`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
 pub enum PublisherLoanError {
     OutOfMemory,
-    ExceedsMaxLoanedChunks,
+    /// The maximum number of parallel loans, see [`Publisher::max_loaned_samples()`], is
+    /// reached. Send or drop a loaned sample to loan another one.
+    ExceedsMaxLoans,
     ExceedsMaxLoanSize,
+    /// The data segment would have to grow beyond the memory budget of the service, see
+    /// [`crate::config::PublishSubscribe::service_memory_budget`].
+    ExceedsServiceMemoryBudget,
     InternalFailure,
 }
 
@@ -175,7 +411,10 @@ enum_gen! {
     PublisherSendError
   entry:
     ConnectionBrokenSincePublisherNoLongerExists,
-    ConnectionCorrupted
+    ConnectionCorrupted,
+    AcknowledgmentsNotEnabled,
+    PayloadWrittenOutOfBounds,
+    InternalFailure
   mapping:
     PublisherLoanError to LoanError,
     ConnectionFailure to ConnectionError
@@ -189,215 +428,1422 @@ impl std::fmt::Display for PublisherSendError {
 
 impl std::error::Error for PublisherSendError {}
 
+/// Describes the utilization and fragmentation of the data segment of a [`Publisher`]. Can be
+/// acquired with [`Publisher::data_segment_report()`].
+#[derive(Debug, Clone)]
+pub struct DataSegmentReport {
+    memory: FragmentationReport,
+    largest_allocatable_payload: usize,
+    page_size: usize,
+}
+
+impl DataSegmentReport {
+    /// Returns the [`FragmentationReport`] of the underlying memory that contains the free
+    /// chunks per size class.
+    pub fn memory(&self) -> &FragmentationReport {
+        &self.memory
+    }
+
+    /// Returns the size in bytes of the largest payload that can be loaned right now. If it is
+    /// zero every loan fails with [`PublisherLoanError::OutOfMemory`] even when the total free
+    /// memory seems to be sufficient.
+    pub fn largest_allocatable_payload(&self) -> usize {
+        self.largest_allocatable_payload
+    }
+
+    /// Returns the page size of the memory that backs the data segment. It is the huge page
+    /// size when huge pages are requested in [`crate::config::SharedMemory`] and available,
+    /// otherwise the default page size.
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+}
+
+/// The statistics of the deliveries to a single [`crate::port::subscriber::Subscriber`] that
+/// blocked the [`Publisher`] since the buffer of the subscriber was full and the
+/// [`UnableToDeliverStrategy::Block`] was active. Can be acquired with
+/// [`Publisher::blocking_statistics()`] to identify slow subscribers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectionBlockingStatistics {
+    subscriber_id: UniqueSubscriberId,
+    number_of_blocked_deliveries: u64,
+    number_of_timeouts: u64,
+    total_blocking_time: Duration,
+    max_blocking_time: Duration,
+}
+
+impl ConnectionBlockingStatistics {
+    pub(crate) fn new(subscriber_id: UniqueSubscriberId) -> Self {
+        Self {
+            subscriber_id,
+            number_of_blocked_deliveries: 0,
+            number_of_timeouts: 0,
+            total_blocking_time: Duration::ZERO,
+            max_blocking_time: Duration::ZERO,
+        }
+    }
+
+    pub(crate) fn record(&mut self, blocking_time: Duration, has_timed_out: bool) {
+        self.number_of_blocked_deliveries += 1;
+        self.number_of_timeouts += u64::from(has_timed_out);
+        self.total_blocking_time = self.total_blocking_time.saturating_add(blocking_time);
+        self.max_blocking_time = self.max_blocking_time.max(blocking_time);
+    }
+
+    /// Returns the [`UniqueSubscriberId`] of the subscriber the statistics belong to.
+    pub fn subscriber_id(&self) -> UniqueSubscriberId {
+        self.subscriber_id
+    }
+
+    /// Returns the number of deliveries that had to wait since the buffer of the subscriber
+    /// was full.
+    pub fn number_of_blocked_deliveries(&self) -> u64 {
+        self.number_of_blocked_deliveries
+    }
+
+    /// Returns the number of blocked deliveries that were abandoned since the timeout of
+    /// [`SampleMut::send_with_timeout()`] has passed.
+    pub fn number_of_timeouts(&self) -> u64 {
+        self.number_of_timeouts
+    }
+
+    /// Returns the accumulated time the [`Publisher`] was blocked by the subscriber.
+    pub fn total_blocking_time(&self) -> Duration {
+        self.total_blocking_time
+    }
+
+    /// Returns the longest time a single delivery was blocked by the subscriber.
+    pub fn max_blocking_time(&self) -> Duration {
+        self.max_blocking_time
+    }
+}
+
+/// The result of [`SampleMut::send_with_timeout()`]. Contains the
+/// [`crate::port::subscriber::Subscriber`]s that did not receive the sample since their buffer
+/// stayed full until the timeout has passed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimedSendReport {
+    number_of_recipients: usize,
+    timed_out_subscribers: Vec<UniqueSubscriberId>,
+}
+
+impl TimedSendReport {
+    /// Returns the number of subscribers that received the sample.
+    pub fn number_of_recipients(&self) -> usize {
+        self.number_of_recipients
+    }
+
+    /// Returns the [`UniqueSubscriberId`]s of the subscribers that caused the timeout. They
+    /// can be disconnected with [`Publisher::disconnect_subscriber()`].
+    pub fn timed_out_subscribers(&self) -> &[UniqueSubscriberId] {
+        &self.timed_out_subscribers
+    }
+
+    /// Returns true when at least one subscriber caused a timeout.
+    pub fn has_timed_out(&self) -> bool {
+        !self.timed_out_subscribers.is_empty()
+    }
+}
+
+// the outcome of the delivery of a sample to a single subscriber
+enum Delivery {
+    Delivered,
+    Discarded,
+    // the buffer of a blocking subscriber stayed full until the send deadline has passed
+    TimedOut,
+    Failed,
+}
+
+// size and pattern of the canary region behind every payload when canaries are enabled
+const CANARY_SIZE: usize = 64;
+const CANARY_PATTERN: u8 = 0xCA;
+
+// lists every publisher that consumes memory of the service memory budget
+fn memory_consumers(
+    dynamic_config: &crate::service::dynamic_config::publish_subscribe::DynamicConfig,
+) -> String {
+    dynamic_config
+        .publisher_memory_usage()
+        .iter()
+        .map(|(publisher_id, node_id, size)| {
+            format!(
+                "publisher {} of node {}: {} bytes",
+                publisher_id.value(),
+                node_id.value(),
+                size
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// the maximum number of segments a growing data segment can consist of, every connection
+// tracks the chunks of all segments
+const MAX_NUMBER_OF_SEGMENTS: usize = 16;
+
+// A shared memory segment of the data segment. A data segment with a [`AllocationStrategy`]
+// other than [`AllocationStrategy::Static`] acquires an additional segment whenever a slice
+// is loaned that exceeds the maximum slice length of the current segment.
 #[derive(Debug)]
-pub(crate) struct DataSegment<Service: service::Service> {
-    sample_reference_counter: Vec<IoxAtomicU64>,
+struct Segment<Service: service::Service> {
     memory: Service::SharedMemory,
-    payload_size: usize,
+    max_slice_len: usize,
+    chunk_size: usize,
     payload_type_layout: Layout,
+    sample_reference_counter: Vec<IoxAtomicU64>,
+    // the start and end offset of the canary of every chunk, empty when canaries are disabled
+    canary_ranges: Vec<(IoxAtomicUsize, IoxAtomicUsize)>,
+    _shared_memory_reservation: SharedMemoryReservation<Service>,
+}
+
+impl<Service: service::Service> Segment<Service> {
+    // Creates the segment with the id `segment_id`. Every additional segment must not have more
+    // chunks than the first one, given by `max_number_of_chunks`, since the connections track
+    // the chunks of every segment with the same capacity.
+    fn create(
+        port_id: UniquePublisherId,
+        shared_node: &Arc<SharedNode<Service>>,
+        service_config: &crate::service::static_config::StaticConfig,
+        config: &LocalPublisherConfig,
+        segment_id: usize,
+        max_slice_len: usize,
+        max_number_of_chunks: Option<usize>,
+    ) -> Result<Self, PublisherCreateError> {
+        let msg = "Unable to create the data segment";
+        let origin = "Segment::create()";
+        let static_config = service_config.publish_subscribe();
+        let memory_breakdown =
+            PublisherMemoryBreakdown::with_max_slice_len(static_config, config, max_slice_len);
+        let data_segment_size = memory_breakdown.data_segment_size();
+        let (chunk_size, number_of_chunks) = Self::chunk_layout(
+            static_config,
+            config,
+            &memory_breakdown,
+            max_slice_len,
+            max_number_of_chunks,
+        );
+        let allocator_config = Self::allocator_config(
+            static_config,
+            config,
+            &memory_breakdown,
+            max_slice_len,
+            chunk_size,
+        );
+
+        let shared_memory_reservation = match shared_node.reserve_shared_memory(data_segment_size) {
+            Ok(reservation) => reservation,
+            Err(used_memory) => {
+                fail!(from origin, with PublisherCreateError::ExceedsNodeSharedMemoryQuota,
+                    "{} since its {} would exceed the shared memory quota of the node of {} bytes ({} bytes in use).",
+                    msg, memory_breakdown, shared_node.config().global.node.shared_memory_quota, used_memory);
+            }
+        };
+
+        let memory = fail!(from origin,
+                when Self::create_memory(port_id, shared_node.config(), segment_id, data_segment_size, &allocator_config, service_config, config),
+                with PublisherCreateError::UnableToCreateDataSegment,
+                "{} since the {} could not be acquired.", msg, memory_breakdown);
+
+        Ok(Self {
+            memory,
+            max_slice_len,
+            chunk_size,
+            payload_type_layout: static_config.type_details().payload_layout(max_slice_len),
+            sample_reference_counter: (0..number_of_chunks)
+                .map(|_| IoxAtomicU64::new(0))
+                .collect(),
+            canary_ranges: if config.enable_canaries {
+                (0..number_of_chunks)
+                    .map(|_| (IoxAtomicUsize::new(0), IoxAtomicUsize::new(0)))
+                    .collect()
+            } else {
+                vec![]
+            },
+            _shared_memory_reservation: shared_memory_reservation,
+        })
+    }
+
+    // The granularity in which the samples are tracked in the segment and the number of chunks.
+    // Every sample starts in its own chunk so that the offset of a sample identifies it uniquely.
+    fn chunk_layout(
+        static_config: &publish_subscribe::StaticConfig,
+        config: &LocalPublisherConfig,
+        memory_breakdown: &PublisherMemoryBreakdown,
+        max_slice_len: usize,
+        max_number_of_chunks: Option<usize>,
+    ) -> (usize, usize) {
+        match config.data_segment_allocator {
+            DataSegmentAllocator::Pool => (
+                memory_breakdown.sample_size(),
+                memory_breakdown.number_of_samples(),
+            ),
+            DataSegmentAllocator::BumpPerCycle | DataSegmentAllocator::BestFit => {
+                let granularity = static_config.type_details.sample_layout(1).size();
+                let data_segment_size = memory_breakdown.data_segment_size();
+                // larger segments use larger chunks to stay within the number of chunks
+                let chunk_size = match max_number_of_chunks {
+                    Some(max_number_of_chunks) => data_segment_size
+                        .div_ceil(max_number_of_chunks.max(1))
+                        .max(granularity)
+                        .next_multiple_of(granularity),
+                    None => granularity,
+                };
+                (chunk_size, data_segment_size.div_ceil(chunk_size))
+            }
+            DataSegmentAllocator::MultiPool => {
+                // every bucket size is a multiple of the chunk size, therefore every bucket
+                // starts at the beginning of a chunk
+                let buckets = PublisherMemoryBreakdown::mem_pool_buckets(
+                    static_config,
+                    config,
+                    max_slice_len,
+                    memory_breakdown.number_of_samples(),
+                );
+                let chunk_size = buckets
+                    .iter()
+                    .fold(0, |gcd, (bucket_size, _)| {
+                        greatest_common_divisor(gcd, *bucket_size)
+                    })
+                    .max(1);
+                let pool_size: usize = buckets
+                    .iter()
+                    .map(|(bucket_size, number_of_buckets)| bucket_size * number_of_buckets)
+                    .sum();
+                (chunk_size, pool_size / chunk_size)
+            }
+        }
+    }
+
+    fn allocator_config(
+        static_config: &publish_subscribe::StaticConfig,
+        config: &LocalPublisherConfig,
+        memory_breakdown: &PublisherMemoryBreakdown,
+        max_slice_len: usize,
+        chunk_size: usize,
+    ) -> shm_allocator::selectable_allocator::Config {
+        let chunk_layout = unsafe {
+            Layout::from_size_align_unchecked(
+                chunk_size,
+                static_config.type_details.sample_layout(1).align(),
+            )
+        };
+
+        match config.data_segment_allocator {
+            DataSegmentAllocator::Pool => shm_allocator::selectable_allocator::Config::Pool(
+                shm_allocator::pool_allocator::Config {
+                    bucket_layout: chunk_layout,
+                },
+            ),
+            DataSegmentAllocator::BumpPerCycle => {
+                shm_allocator::selectable_allocator::Config::BumpPerCycle(
+                    shm_allocator::bump_per_cycle_allocator::Config {
+                        max_alignment: chunk_layout.align(),
+                    },
+                )
+            }
+            DataSegmentAllocator::BestFit => shm_allocator::selectable_allocator::Config::BestFit(
+                shm_allocator::best_fit_allocator::Config {
+                    granule_layout: chunk_layout,
+                },
+            ),
+            DataSegmentAllocator::MultiPool => {
+                let mut allocator_config =
+                    shm_allocator::multi_pool_allocator::Config::new(chunk_layout.align());
+                for (bucket_size, number_of_buckets) in PublisherMemoryBreakdown::mem_pool_buckets(
+                    static_config,
+                    config,
+                    max_slice_len,
+                    memory_breakdown.number_of_samples(),
+                ) {
+                    // the number of memory pools was verified when the publisher was created
+                    allocator_config.add_pool(bucket_size, number_of_buckets);
+                }
+                shm_allocator::selectable_allocator::Config::MultiPool(allocator_config)
+            }
+        }
+    }
+
+    fn create_memory(
+        port_id: UniquePublisherId,
+        global_config: &config::Config,
+        segment_id: usize,
+        data_segment_size: usize,
+        allocator_config: &shm_allocator::selectable_allocator::Config,
+        service_config: &crate::service::static_config::StaticConfig,
+        config: &LocalPublisherConfig,
+    ) -> Result<Service::SharedMemory, SharedMemoryCreateError> {
+        let memory = fail!(from "Segment::create_memory()",
+            when <<Service::SharedMemory as SharedMemory<SelectableAllocator>>::Builder as NamedConceptBuilder<
+            Service::SharedMemory,
+                >>::new(&data_segment_name(port_id, segment_id))
+                .config(&data_segment_config::<Service>(global_config))
+                .size(data_segment_size)
+                .huge_page_size(global_config.global.shared_memory.huge_page_size)
+                .permission(service_config.permission())
+                .create(allocator_config),
+            "Unable to create the data segment.");
+
+        if config.zeroing_policy == ZeroingPolicy::OnCreate {
+            let start = memory.payload_start_address();
+            let size = memory.allocator_payload_size();
+            // # SAFETY: the memory was just created and is not yet shared with anyone else. The
+            //           allocator has poisoned its free memory, so it is unpoisoned for the
+            //           zeroing and poisoned again afterwards since no chunk is loaned yet.
+            unsafe {
+                unpoison_memory_region(start, size);
+                core::ptr::write_bytes(start as *mut u8, 0, size);
+                poison_memory_region(start, size);
+            };
+        }
+
+        Ok(memory)
+    }
+
+    fn sample_index(&self, offset: usize) -> usize {
+        offset / self.chunk_size
+    }
+
+    fn number_of_chunks(&self) -> usize {
+        self.sample_reference_counter.len()
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct DataSegment<Service: service::Service> {
+    // the slots for all segments the data segment can grow to, they are preallocated so that
+    // adding a segment never moves the existing ones
+    segments: Vec<UnsafeCell<Option<Segment<Service>>>>,
+    // the segment new samples are allocated from, it is always the last added segment
+    current_segment: IoxAtomicUsize,
     port_id: UniquePublisherId,
     config: LocalPublisherConfig,
     dynamic_storage: Arc<Service::DynamicStorage>,
+    shared_node: Arc<SharedNode<Service>>,
 
     subscriber_connections: SubscriberConnections<Service>,
     subscriber_list_state: UnsafeCell<ContainerState<SubscriberDetails>>,
+    observer_connections: SubscriberConnections<Service>,
+    observer_list_state: UnsafeCell<ContainerState<SubscriberDetails>>,
     history: Option<UnsafeCell<Queue<usize>>>,
     static_config: crate::service::static_config::StaticConfig,
     loan_counter: IoxAtomicUsize,
     is_active: IoxAtomicBool,
+    dead_letter_channel: Option<DeadLetterChannel<Service>>,
+    group_delivery_counter: IoxAtomicUsize,
+    // scratch buffer for the connection indices of a group, preallocated so that the delivery
+    // does not allocate
+    group_members: UnsafeCell<Vec<usize>>,
+    // scratch buffer for the subscribers that caused a timeout in the current
+    // SampleMut::send_with_timeout(), preallocated so that the delivery does not allocate
+    timed_out_subscribers: UnsafeCell<Vec<UniqueSubscriberId>>,
+    acknowledgments: Option<Mutex<AcknowledgmentTracker>>,
+    flight_recorder: Option<Mutex<FlightRecorder>>,
+    // created when the first subscriber blocks in Subscriber::blocking_receive()
+    sample_arrival: OnceCell<SampleArrivalNotifier<Service>>,
+    resource_registration: NodeResourceRegistration<Service>,
+    // the index of the publisher in the dynamic config, it is only valid while the publisher
+    // is registered, otherwise the metrics of another publisher would be modified
+    metrics_index: IoxAtomicUsize,
+    next_publisher_sequence_number: IoxAtomicU64,
 }
 
+const NO_METRICS_INDEX: usize = usize::MAX;
+
 impl<Service: service::Service> DataSegment<Service> {
-    fn sample_index(&self, distance_to_chunk: usize) -> usize {
-        distance_to_chunk / self.payload_size
+    fn add_metric(&self, metric: PublisherMetric, value: u64) {
+        let index = self.metrics_index.load(Ordering::Relaxed);
+        if index != NO_METRICS_INDEX {
+            self.dynamic_storage
+                .get()
+                .publish_subscribe()
+                .add_publisher_metric(index as u32, metric, value);
+        }
+    }
+
+    fn remove_metric(&self, metric: PublisherMetric, value: u64) {
+        let index = self.metrics_index.load(Ordering::Relaxed);
+        if index != NO_METRICS_INDEX {
+            self.dynamic_storage
+                .get()
+                .publish_subscribe()
+                .remove_publisher_metric(index as u32, metric, value);
+        }
+    }
+
+    fn segment(&self, segment_id: usize) -> Option<&Segment<Service>> {
+        match self.segments.get(segment_id) {
+            Some(segment) => unsafe { (*segment.get()).as_ref() },
+            None => None,
+        }
+    }
+
+    // is only None when the data segment is inconsistent, the callers report it with
+    // fatal_panic_or_fail!/fatal_panic_or_error! so that the send path stays panic-free
+    fn current_segment(&self) -> Option<&Segment<Service>> {
+        self.segment(self.current_segment.load(Ordering::Relaxed))
+    }
+
+    // with a single segment the whole distance is the offset, see the zero copy connection
+    fn distance_to_chunk(
+        &self,
+        segment_id: usize,
+        offset: usize,
+    ) -> Result<usize, PointerOffsetError> {
+        match self.segments.len() {
+            1 => Ok(offset),
+            _ => Ok(PointerOffset::from_offset_and_segment_id(offset, segment_id)?.value()),
+        }
+    }
+
+    // returns the segment that contains the chunk and the offset of the chunk in the segment
+    fn segment_of(&self, distance_to_chunk: usize) -> Option<(&Segment<Service>, usize)> {
+        let (segment_id, offset) = match self.segments.len() {
+            1 => (0, distance_to_chunk),
+            _ => {
+                let ptr = PointerOffset::new(distance_to_chunk);
+                (ptr.segment_id(), ptr.offset())
+            }
+        };
+
+        self.segment(segment_id).map(|segment| (segment, offset))
+    }
+
+    fn reference_counter(&self, distance_to_chunk: usize) -> Option<&IoxAtomicU64> {
+        let (segment, offset) = self.segment_of(distance_to_chunk)?;
+        segment
+            .sample_reference_counter
+            .get(segment.sample_index(offset))
+    }
+
+    fn chunk_address(&self, distance_to_chunk: usize) -> Result<usize, PublisherSendError> {
+        match self.segment_of(distance_to_chunk) {
+            Some((segment, offset)) => Ok(segment.memory.payload_start_address() + offset),
+            None => {
+                fatal_panic_or_fail!(from self, with PublisherSendError::InternalFailure,
+                    "This should never happen! The sample at offset {} is not part of the data segment.",
+                    distance_to_chunk);
+            }
+        }
+    }
+
+    fn chunk_size_of(&self, distance_to_chunk: usize) -> usize {
+        match self.segment_of(distance_to_chunk) {
+            Some((segment, _)) => segment.chunk_size,
+            None => self
+                .current_segment()
+                .map_or(0, |segment| segment.chunk_size),
+        }
+    }
+
+    fn canary_size(config: &LocalPublisherConfig) -> usize {
+        if config.enable_canaries {
+            CANARY_SIZE
+        } else {
+            0
+        }
+    }
+
+    // Adds a segment that fits slices with `slice_len` elements according to the
+    // [`AllocationStrategy`], all following samples are allocated from it.
+    fn grow(&self, slice_len: usize) -> Result<(), PublisherLoanError> {
+        let msg = "Unable to grow the data segment";
+        let max_slice_len = match self.config.allocation_strategy {
+            AllocationStrategy::Static => {
+                fail!(from self, with PublisherLoanError::ExceedsMaxLoanSize,
+                    "{} for a slice with {} elements since the allocation strategy is static.",
+                    msg, slice_len);
+            }
+            AllocationStrategy::BestFit => slice_len,
+            AllocationStrategy::PowerOfTwo => {
+                slice_len.checked_next_power_of_two().unwrap_or(slice_len)
+            }
+        };
+
+        let number_of_chunks = match self.current_segment() {
+            Some(segment) => segment.number_of_chunks(),
+            None => {
+                fatal_panic_or_fail!(from self, with PublisherLoanError::InternalFailure,
+                    "This should never happen! {} since the current segment does not exist.", msg);
+            }
+        };
+
+        let segment_id = self.current_segment.load(Ordering::Relaxed) + 1;
+        let slot = match self.segments.get(segment_id) {
+            Some(slot) => slot,
+            None => {
+                fail!(from self, with PublisherLoanError::ExceedsMaxLoanSize,
+                    "{} for a slice with {} elements since the maximum number of {} segments is reached.",
+                    msg, slice_len, self.segments.len());
+            }
+        };
+
+        let publisher_index = self.metrics_index.load(Ordering::Relaxed);
+        if publisher_index == NO_METRICS_INDEX {
+            fail!(from self, with PublisherLoanError::InternalFailure,
+                "{} for a slice with {} elements since the publisher is not registered in the service.",
+                msg, slice_len);
+        }
+
+        // the segment is accounted to the memory budget of the service before it is created so
+        // that concurrently growing publishers cannot exceed the budget together
+        let dynamic_config = self.dynamic_storage.get().publish_subscribe();
+        let segment_size = PublisherMemoryBreakdown::with_max_slice_len(
+            self.static_config.publish_subscribe(),
+            &self.config,
+            max_slice_len,
+        )
+        .data_segment_size();
+        if dynamic_config
+            .reserve_additional_segment(publisher_index as u32, segment_size)
+            .is_err()
+        {
+            fail!(from self, with PublisherLoanError::ExceedsServiceMemoryBudget,
+                "{} for a slice with {} elements since the new segment of {} bytes would exceed the service memory budget of {} bytes, {} bytes are already in use by [{}].",
+                msg, slice_len, segment_size, self.static_config.publish_subscribe().memory_budget(),
+                dynamic_config.shared_memory_bytes(), memory_consumers(dynamic_config));
+        }
+
+        let segment = match Segment::create(
+            self.port_id,
+            &self.shared_node,
+            &self.static_config,
+            &self.config,
+            segment_id,
+            max_slice_len,
+            Some(number_of_chunks),
+        ) {
+            Ok(segment) => segment,
+            Err(e) => {
+                dynamic_config.release_additional_segment(publisher_index as u32, segment_size);
+                fail!(from self, with PublisherLoanError::OutOfMemory,
+                    "{} for a slice with {} elements since the new segment could not be created ({:?}).",
+                    msg, slice_len, e);
+            }
+        };
+
+        // # SAFETY: the slot was never used since the segments are added in order and are
+        //           never removed
+        unsafe { *slot.get() = Some(segment) };
+        self.current_segment.store(segment_id, Ordering::Relaxed);
+        self.resource_registration.add_file_descriptors(1);
+
+        Ok(())
     }
 
     fn allocate(&self, layout: Layout) -> Result<ShmPointer, ShmAllocationError> {
         self.retrieve_returned_samples();
 
         let msg = "Unable to allocate Sample";
-        let ptr = self.memory.allocate(layout)?;
-        if self.sample_reference_counter[self.sample_index(ptr.offset.value())]
-            .fetch_add(1, Ordering::Relaxed)
-            != 0
+        let segment_id = self.current_segment.load(Ordering::Relaxed);
+        let segment = match self.current_segment() {
+            Some(segment) => segment,
+            None => {
+                fatal_panic_or_fail!(from self,
+                    with ShmAllocationError::AllocationError(AllocationError::InternalError),
+                    "This should never happen! {} since the current segment does not exist.", msg);
+            }
+        };
+        // every allocation must span at least one chunk so that two samples never share the
+        // same reference counter
+        let chunk_layout = unsafe {
+            Layout::from_size_align_unchecked(
+                (layout.size() + Self::canary_size(&self.config))
+                    .max(1)
+                    .next_multiple_of(segment.chunk_size),
+                layout.align(),
+            )
+        };
+        let ptr = segment.memory.allocate(chunk_layout)?;
+        let distance_to_chunk = match self.distance_to_chunk(segment_id, ptr.offset.value()) {
+            Ok(v) => v,
+            Err(e) => {
+                unsafe {
+                    segment
+                        .memory
+                        .deallocate(ptr.offset, segment.payload_type_layout)
+                };
+                fail!(from self,
+                    with ShmAllocationError::AllocationError(AllocationError::InternalError),
+                    "{} since the chunk at offset {} of segment {} cannot be addressed ({:?}).",
+                    msg, ptr.offset.value(), segment_id, e);
+            }
+        };
+        let is_in_use = match segment
+            .sample_reference_counter
+            .get(segment.sample_index(ptr.offset.value()))
         {
-            fatal_panic!(from self,
+            Some(counter) => counter.fetch_add(1, Ordering::Relaxed) != 0,
+            None => true,
+        };
+        if is_in_use {
+            fatal_panic_or_fail!(from self,
+                with ShmAllocationError::AllocationError(AllocationError::InternalError),
                 "{} since the allocated sample is already in use! This should never happen!", msg);
         }
+        self.add_metric(PublisherMetric::SamplesInUse, 1);
+
+        Ok(ShmPointer {
+            offset: PointerOffset::new(distance_to_chunk),
+            data_ptr: ptr.data_ptr,
+        })
+    }
+
+    // fills everything from the end of the payload up to the end of the sample plus the
+    // additional canary region with the canary pattern
+    fn place_canary(&self, chunk: &ShmPointer, sample_layout: Layout, payload_end: usize) {
+        if !self.config.enable_canaries {
+            return;
+        }
+
+        let (segment, offset) = match self.segment_of(chunk.offset.value()) {
+            Some(v) => v,
+            None => return,
+        };
+        let start = payload_end - segment.memory.payload_start_address();
+        let end = offset + sample_layout.size() + CANARY_SIZE;
+        if let Some((canary_start, canary_end)) =
+            segment.canary_ranges.get(segment.sample_index(offset))
+        {
+            canary_start.store(start, Ordering::Relaxed);
+            canary_end.store(end, Ordering::Relaxed);
+            unsafe { core::ptr::write_bytes(payload_end as *mut u8, CANARY_PATTERN, end - start) };
+        }
+    }
+
+    fn verify_canary(&self, distance_to_chunk: usize) -> Result<(), PublisherSendError> {
+        // when the canary was already reported the sample is released while unwinding, a
+        // second panic would abort the process
+        if !self.config.enable_canaries || std::thread::panicking() {
+            return Ok(());
+        }
+
+        let (segment, offset) = match self.segment_of(distance_to_chunk) {
+            Some(v) => v,
+            None => return Ok(()),
+        };
+        let (canary_start, canary_end) =
+            match segment.canary_ranges.get(segment.sample_index(offset)) {
+                Some(range) => range,
+                None => return Ok(()),
+            };
+        let start = canary_start.load(Ordering::Relaxed);
+        let end = canary_end.load(Ordering::Relaxed);
+        let canary = unsafe {
+            core::slice::from_raw_parts(
+                (segment.memory.payload_start_address() + start) as *const u8,
+                end - start,
+            )
+        };
+
+        if let Some(position) = canary.iter().position(|v| *v != CANARY_PATTERN) {
+            fatal_panic_or_fail!(from self, with PublisherSendError::PayloadWrittenOutOfBounds,
+                "The canary of the sample at offset {} was overwritten {} bytes behind the end of the payload. The payload was written out of bounds!",
+                distance_to_chunk, position);
+        }
 
-        Ok(ptr)
+        Ok(())
+    }
+
+    fn report(&self) -> DataSegmentReport {
+        self.retrieve_returned_samples();
+
+        let segment = match self.current_segment() {
+            Some(segment) => segment,
+            None => {
+                fatal_panic_or_error!(from self,
+                    "This should never happen! Unable to report the data segment since the current segment does not exist.");
+                return DataSegmentReport {
+                    memory: FragmentationReport::default(),
+                    largest_allocatable_payload: 0,
+                    page_size: 0,
+                };
+            }
+        };
+        let memory = segment.memory.fragmentation_report();
+        let type_details = &self.subscriber_connections.static_config.type_details;
+        let fits = |number_of_elements: usize| {
+            (type_details.sample_layout(number_of_elements).size()
+                + Self::canary_size(&self.config))
+            .next_multiple_of(segment.chunk_size)
+                <= memory.largest_free_chunk()
+        };
+
+        let largest_allocatable_payload = if fits(1) {
+            // binary search for the largest slice length that still fits into the largest
+            // free chunk
+            let mut lower = 1;
+            let mut upper = segment.max_slice_len.max(1);
+            while lower < upper {
+                let mid = lower + (upper - lower).div_ceil(2);
+                if fits(mid) {
+                    lower = mid;
+                } else {
+                    upper = mid - 1;
+                }
+            }
+            type_details.payload_layout(lower).size()
+        } else {
+            0
+        };
+
+        DataSegmentReport {
+            memory,
+            largest_allocatable_payload,
+            page_size: segment.memory.page_size(),
+        }
     }
 
     fn borrow_sample(&self, distance_to_chunk: usize) {
-        self.sample_reference_counter[self.sample_index(distance_to_chunk)]
-            .fetch_add(1, Ordering::Relaxed);
+        match self.reference_counter(distance_to_chunk) {
+            Some(counter) => {
+                counter.fetch_add(1, Ordering::Relaxed);
+            }
+            None => {
+                fatal_panic_or_error!(from self,
+                    "This should never happen! Unable to borrow the sample at offset {} since it is not part of the data segment.",
+                    distance_to_chunk);
+            }
+        }
     }
 
     fn release_sample(&self, distance_to_chunk: PointerOffset) {
-        if self.sample_reference_counter[self.sample_index(distance_to_chunk.value())]
-            .fetch_sub(1, Ordering::Relaxed)
-            == 1
-        {
-            unsafe {
-                self.memory
-                    .deallocate(distance_to_chunk, self.payload_type_layout);
+        match self.reference_counter(distance_to_chunk.value()) {
+            Some(counter) => {
+                if counter.fetch_sub(1, Ordering::Relaxed) == 1 {
+                    // the violation is already reported, the sample is released nevertheless
+                    let _ = self.verify_canary(distance_to_chunk.value());
+                    if let Some((segment, offset)) = self.segment_of(distance_to_chunk.value()) {
+                        unsafe {
+                            segment.memory.deallocate(
+                                PointerOffset::new(offset),
+                                segment.payload_type_layout,
+                            );
+                        }
+                        self.remove_metric(PublisherMetric::SamplesInUse, 1);
+                    }
+                }
+            }
+            None => {
+                fatal_panic_or_error!(from self,
+                    "This should never happen! Unable to release the sample at offset {} since it is not part of the data segment.",
+                    distance_to_chunk.value());
             }
         }
     }
 
     fn retrieve_returned_samples(&self) {
+        self.retrieve_returned_samples_from(&self.subscriber_connections);
+        self.retrieve_returned_samples_from(&self.observer_connections);
+    }
+
+    fn retrieve_returned_samples_from(&self, connections: &SubscriberConnections<Service>) {
+        for i in 0..connections.len() {
+            match connections.get(i) {
+                Some(ref connection) => loop {
+                    match connection.sender.reclaim() {
+                        Ok(Some(ptr_dist)) => {
+                            self.track_acknowledgment(|tracker| {
+                                tracker.acknowledge(ptr_dist.value(), connection.subscriber_id)
+                            });
+                            self.release_sample(ptr_dist);
+                        }
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim samples from connection {:?} due to {:?}. This may lead to a situation where no more samples will be delivered to this connection.", connection, e)
+                        }
+                    }
+                },
+                None => (),
+            }
+        }
+    }
+
+    fn remove_connection(&self, connections: &SubscriberConnections<Service>, i: usize) {
+        if let Some(connection) = connections.get(i) {
+            // # SAFETY: the receiver no longer exist, therefore we can
+            //           reacquire all delivered samples
+            unsafe {
+                connection.sender.acquire_used_offsets(|offset| {
+                    self.track_acknowledgment(|tracker| {
+                        tracker.lose(offset.value(), connection.subscriber_id)
+                    });
+                    self.release_sample(offset)
+                })
+            };
+
+            connections.remove(i);
+        }
+    }
+
+    fn reclaim_samples_of_dead_subscribers(&self) -> usize {
+        self.reclaim_samples_of_dead_subscribers_from(&self.subscriber_connections)
+            + self.reclaim_samples_of_dead_subscribers_from(&self.observer_connections)
+    }
+
+    fn reclaim_samples_of_dead_subscribers_from(
+        &self,
+        connections: &SubscriberConnections<Service>,
+    ) -> usize {
+        let mut number_of_reclaimed_samples = 0;
+        for i in 0..connections.len() {
+            let connection = match connections.get(i) {
+                Some(connection) => connection,
+                None => continue,
+            };
+
+            if connection.is_subscriber_dead()
+                || !Node::<Service>::is_dead(self.shared_node.config(), connection.node_id)
+            {
+                continue;
+            }
+
+            let mut number_of_samples = 0;
+            // # SAFETY: the node of the subscriber is dead, it can no longer access the samples
+            unsafe {
+                connection.sender.reclaim_from_dead_receiver(|offset| {
+                    self.track_acknowledgment(|tracker| {
+                        tracker.lose(offset.value(), connection.subscriber_id)
+                    });
+                    self.release_sample(offset);
+                    number_of_samples += 1;
+                })
+            };
+            connection.mark_subscriber_as_dead();
+
+            warn!(from self, "Reclaimed {} samples of the dead subscriber {:?}.",
+                number_of_samples, connection.subscriber_id);
+            number_of_reclaimed_samples += number_of_samples;
+        }
+
+        number_of_reclaimed_samples
+    }
+
+    pub(crate) fn return_loaned_sample(&self, distance_to_chunk: PointerOffset) {
+        self.release_sample(distance_to_chunk);
+        self.loan_counter.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    fn add_sample_to_history(&self, address_to_chunk: usize) {
+        match &self.history {
+            None => (),
+            Some(history) => {
+                let history = unsafe { &mut *history.get() };
+                self.borrow_sample(address_to_chunk);
+                match history.push_with_overflow(address_to_chunk) {
+                    None => (),
+                    Some(old) => self.release_sample(PointerOffset::new(old)),
+                }
+            }
+        }
+    }
+
+    fn route_to_dead_letter_channel(
+        &self,
+        distance_to_chunk: usize,
+        subscriber_id: UniqueSubscriberId,
+        reason: DeadLetterReason,
+    ) {
+        if let Some(channel) = &self.dead_letter_channel {
+            // an inconsistent data segment was already reported
+            let payload = match self.payload_of(distance_to_chunk) {
+                Ok(payload) => payload,
+                Err(_) => return,
+            };
+            let payload_size = payload.len();
+            let payload = match channel.include_payload() {
+                true => payload,
+                false => &[],
+            };
+
+            channel.send(
+                DeadLetter::new(self.port_id, subscriber_id, reason, payload_size),
+                payload,
+            );
+        }
+    }
+
+    fn count_dropped_sample(&self, connection: &Connection<Service>) {
+        let dynamic_config = self.dynamic_storage.get().publish_subscribe();
+        dynamic_config.add_dropped_sample(connection.index as u32);
+        self.add_metric(PublisherMetric::DroppedSamples, 1);
+
+        // the connection metrics are indexed like the subscribers, observers are only counted
+        // in the total
+        let index = self.metrics_index.load(Ordering::Relaxed);
+        let is_subscriber = match self.subscriber_connections.get(connection.index) {
+            Some(c) => core::ptr::eq(c, connection),
+            None => false,
+        };
+        if index != NO_METRICS_INDEX && is_subscriber {
+            dynamic_config.add_dropped_sample_of_connection(index as u32, connection.index as u32);
+        }
+    }
+
+    fn payload_of(&self, distance_to_chunk: usize) -> Result<&[u8], PublisherSendError> {
+        let header = self.chunk_address(distance_to_chunk)? as *const Header;
+        Ok(unsafe {
+            core::slice::from_raw_parts(
+                self.subscriber_connections
+                    .static_config
+                    .type_details
+                    .payload_ptr_from_header(header.cast())
+                    .cast(),
+                (*header).payload_type_layout().size(),
+            )
+        })
+    }
+
+    fn stamp_header(&self, distance_to_chunk: usize) -> Result<(), PublisherSendError> {
+        let header = self.chunk_address(distance_to_chunk)? as *mut Header;
+        let sequence_number = self
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .acquire_sequence_number();
+        let publisher_sequence_number = self
+            .next_publisher_sequence_number
+            .fetch_add(1, Ordering::Relaxed);
+        unsafe {
+            (*header).set_sequence_number(sequence_number);
+            (*header).set_publisher_sequence_number(publisher_sequence_number);
+            (*header).set_publish_timestamp(ActivityTimestamp::now());
+        }
+
+        Ok(())
+    }
+
+    fn update_last_send(&self) {
+        if self.subscriber_connections.static_config.deadline.is_some() {
+            self.dynamic_storage
+                .get()
+                .publish_subscribe()
+                .last_send()
+                .update();
+        }
+    }
+
+    fn call_send_hook(&self, distance_to_chunk: usize) -> Result<(), PublisherSendError> {
+        if let Some(hook) = &self.config.send_hook {
+            let header = self.chunk_address(distance_to_chunk)? as *const Header;
+            hook.call(unsafe { &*header });
+        }
+
+        Ok(())
+    }
+
+    fn record_sample(&self, distance_to_chunk: usize) -> Result<(), PublisherSendError> {
+        if let Some(recorder) = &self.flight_recorder {
+            let payload = self.payload_of(distance_to_chunk)?;
+            // the recorder is always consistent, even when another thread panicked while holding it
+            let mut recorder = match recorder.lock() {
+                Ok(recorder) => recorder,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            recorder.record(payload);
+        }
+
+        Ok(())
+    }
+
+    // Tries to deliver without blocking first so that only the deliveries that actually had to
+    // wait are accounted in the blocking statistics of the connection.
+    fn deliver_blocking(
+        &self,
+        connection: &Connection<Service>,
+        address_to_chunk: usize,
+        deadline: Option<Instant>,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+        let ptr = PointerOffset::new(address_to_chunk);
+        let sample_size = self.chunk_size_of(address_to_chunk);
+        match connection.sender.try_send(ptr, sample_size) {
+            Err(ZeroCopySendError::ReceiveBufferFull) => (),
+            result => return result,
+        }
+
+        let start = Instant::now();
+        let result = match deadline {
+            None => connection.sender.blocking_send(ptr, sample_size),
+            Some(deadline) => connection.sender.timed_send(
+                ptr,
+                sample_size,
+                deadline.saturating_duration_since(start),
+            ),
+        };
+        connection.record_blocking(
+            start.elapsed(),
+            matches!(result, Err(ZeroCopySendError::ReceiveBufferFull)),
+        );
+        result
+    }
+
+    fn deliver_to(
+        &self,
+        connection: &Connection<Service>,
+        address_to_chunk: usize,
+        deadline: Option<Instant>,
+    ) -> Result<Delivery, PublisherSendError> {
+        if connection.is_subscriber_dead() || connection.is_disconnected() {
+            return Ok(Delivery::Failed);
+        }
+
+        // the strategy of the subscriber takes precedence over the one of the publisher
+        let unable_to_deliver_strategy = match connection.overflow_strategy {
+            Some(OverflowStrategy::Block) => UnableToDeliverStrategy::Block,
+            Some(_) => UnableToDeliverStrategy::DiscardSample,
+            None => self.config.unable_to_deliver_strategy,
+        };
+
+        let result = match unable_to_deliver_strategy {
+            UnableToDeliverStrategy::Block => {
+                self.deliver_blocking(connection, address_to_chunk, deadline)
+            }
+            UnableToDeliverStrategy::DiscardSample => connection.sender.try_send(
+                PointerOffset::new(address_to_chunk),
+                self.chunk_size_of(address_to_chunk),
+            ),
+        };
+
+        match result {
+            Err(ZeroCopySendError::ReceiveBufferFull)
+                if unable_to_deliver_strategy == UnableToDeliverStrategy::Block =>
+            {
+                // only the timed send returns while the buffer is full
+                Ok(Delivery::TimedOut)
+            }
+            Err(ZeroCopySendError::ReceiveBufferFull)
+            | Err(ZeroCopySendError::UsedChunkListFull) => {
+                /* causes no problem
+                 *   try_send => we tried and expect that the buffer is full
+                 * */
+                Ok(Delivery::Discarded)
+            }
+            Err(ZeroCopySendError::ConnectionCorrupted) => {
+                match &self.config.degration_callback {
+                    Some(c) => match c.call(
+                        self.static_config.clone(),
+                        self.port_id,
+                        connection.subscriber_id,
+                    ) {
+                        DegrationAction::Ignore => (),
+                        DegrationAction::Warn => {
+                            error!(from self,
+                                "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
+                                address_to_chunk, connection.subscriber_id);
+                        }
+                        DegrationAction::Fail => {
+                            fail!(from self, with PublisherSendError::ConnectionCorrupted,
+                                "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
+                                address_to_chunk, connection.subscriber_id);
+                        }
+                    },
+                    None => {
+                        error!(from self,
+                            "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
+                            address_to_chunk, connection.subscriber_id);
+                    }
+                }
+                Ok(Delivery::Failed)
+            }
+            Err(ZeroCopySendError::WaitFailed) => {
+                warn!(from self,
+                    "While delivering the sample: {:?} the wait for subscriber {:?} to free a slot in its buffer failed.",
+                    address_to_chunk, connection.subscriber_id);
+                Ok(Delivery::Failed)
+            }
+            Ok(overflow) => {
+                self.borrow_sample(address_to_chunk);
+                self.track_acknowledgment(|tracker| {
+                    tracker.add_recipient(address_to_chunk, connection.subscriber_id)
+                });
+
+                if let Some(old) = overflow {
+                    self.count_dropped_sample(connection);
+                    self.track_acknowledgment(|tracker| {
+                        tracker.lose(old.value(), connection.subscriber_id)
+                    });
+                    self.route_to_dead_letter_channel(
+                        old.value(),
+                        connection.subscriber_id,
+                        DeadLetterReason::Overflow,
+                    );
+                    self.release_sample(old)
+                }
+                Ok(Delivery::Delivered)
+            }
+        }
+    }
+
+    // Sorts the connection indices of the group members into the order in which the delivery
+    // shall be tried.
+    fn order_group_members(&self, members: &mut [usize]) {
+        match self
+            .static_config
+            .publish_subscribe()
+            .group_delivery_strategy
+        {
+            GroupDeliveryStrategy::RoundRobin => {
+                let start = self.group_delivery_counter.load(Ordering::Relaxed) % members.len();
+                members.rotate_left(start);
+            }
+            GroupDeliveryStrategy::LeastLoaded => {
+                // the index is part of the key so that the unstable sort, which does not
+                // allocate, keeps the order of equally loaded members
+                members.sort_unstable_by_key(|index| {
+                    match self.subscriber_connections.get(*index) {
+                        Some(connection) => (connection.sender.number_of_pending_samples(), *index),
+                        None => (usize::MAX, *index),
+                    }
+                });
+            }
+        }
+    }
+
+    fn is_member_of(&self, index: usize, group: u64) -> bool {
+        matches!(self.subscriber_connections.get(index), Some(connection) if connection.group == Some(group))
+    }
+
+    // Delivers the sample to exactly one member of the group, when the selected member is
+    // unable to receive it the next member is tried.
+    fn deliver_to_group(
+        &self,
+        group: u64,
+        address_to_chunk: usize,
+        deadline: Option<Instant>,
+    ) -> Result<usize, PublisherSendError> {
+        // the buffer has the capacity of all connections, collecting the members never allocates
+        let members = unsafe { &mut *self.group_members.get() };
+        members.clear();
+        members.extend(
+            (0..self.subscriber_connections.len()).filter(|i| self.is_member_of(*i, group)),
+        );
+        self.order_group_members(members);
+
+        let mut discarded_by = None;
+        for i in members.iter() {
+            if let Some(ref connection) = self.subscriber_connections.get(*i) {
+                match self.deliver_to(connection, address_to_chunk, deadline)? {
+                    Delivery::Delivered => return Ok(1),
+                    Delivery::Discarded => discarded_by = Some(connection),
+                    Delivery::TimedOut => {
+                        self.add_timed_out_subscriber(connection);
+                        discarded_by = Some(connection);
+                    }
+                    Delivery::Failed => (),
+                }
+            }
+        }
+
+        if let Some(connection) = discarded_by {
+            self.count_dropped_sample(connection);
+            self.route_to_dead_letter_channel(
+                address_to_chunk,
+                connection.subscriber_id,
+                DeadLetterReason::Discarded,
+            );
+        }
+
+        Ok(0)
+    }
+
+    fn add_timed_out_subscriber(&self, connection: &Connection<Service>) {
+        unsafe { &mut *self.timed_out_subscribers.get() }.push(connection.subscriber_id);
+    }
+
+    fn deliver_to_subscriber(
+        &self,
+        connection: &Connection<Service>,
+        address_to_chunk: usize,
+        deadline: Option<Instant>,
+    ) -> Result<usize, PublisherSendError> {
+        if !connection.accepts_next_sample() {
+            return Ok(0);
+        }
+
+        let delivery = self.deliver_to(connection, address_to_chunk, deadline)?;
+        if let Delivery::TimedOut = delivery {
+            self.add_timed_out_subscriber(connection);
+        }
+
+        match delivery {
+            Delivery::Delivered => Ok(1),
+            Delivery::Discarded | Delivery::TimedOut => {
+                self.count_dropped_sample(connection);
+                self.route_to_dead_letter_channel(
+                    address_to_chunk,
+                    connection.subscriber_id,
+                    DeadLetterReason::Discarded,
+                );
+                Ok(0)
+            }
+            Delivery::Failed => Ok(0),
+        }
+    }
+
+    // Every connection receives all samples in a row before the next connection is served, so
+    // that a batch is delivered in a single pass over the connections.
+    // The blocking deliveries wait at most until the deadline, without deadline they wait until
+    // the subscribers have space in their buffer again.
+    fn deliver_samples(
+        &self,
+        addresses_to_chunks: &[usize],
+        deadline: Option<Instant>,
+    ) -> Result<usize, PublisherSendError> {
+        self.retrieve_returned_samples();
+
+        let mut number_of_recipients = 0;
+        let mut has_groups = false;
         for i in 0..self.subscriber_connections.len() {
             match self.subscriber_connections.get(i) {
-                Some(ref connection) => loop {
-                    match connection.sender.reclaim() {
-                        Ok(Some(ptr_dist)) => {
-                            self.release_sample(ptr_dist);
-                        }
-                        Ok(None) => break,
-                        Err(e) => {
-                            warn!(from self, "Unable to reclaim samples from connection {:?} due to {:?}. This may lead to a situation where no more samples will be delivered to this connection.", connection, e)
+                Some(ref connection) => match connection.group {
+                    Some(_) => has_groups = true,
+                    None => {
+                        for address_to_chunk in addresses_to_chunks {
+                            number_of_recipients += self.deliver_to_subscriber(
+                                connection,
+                                *address_to_chunk,
+                                deadline,
+                            )?;
                         }
                     }
                 },
                 None => (),
             }
         }
-    }
 
-    fn remove_connection(&self, i: usize) {
-        if let Some(connection) = self.subscriber_connections.get(i) {
-            // # SAFETY: the receiver no longer exist, therefore we can
-            //           reacquire all delivered samples
-            unsafe {
-                connection
-                    .sender
-                    .acquire_used_offsets(|offset| self.release_sample(offset))
-            };
+        // every group receives the sample exactly once, the groups are served in the order of
+        // their first member
+        for address_to_chunk in addresses_to_chunks {
+            if has_groups {
+                for i in 0..self.subscriber_connections.len() {
+                    let group = match self.subscriber_connections.get(i) {
+                        Some(connection) => connection.group,
+                        None => None,
+                    };
 
-            self.subscriber_connections.remove(i);
+                    if let Some(group) = group {
+                        if !(0..i).any(|j| self.is_member_of(j, group)) {
+                            number_of_recipients +=
+                                self.deliver_to_group(group, *address_to_chunk, deadline)?;
+                        }
+                    }
+                }
+            }
+            self.group_delivery_counter.fetch_add(1, Ordering::Relaxed);
         }
-    }
-
-    pub(crate) fn return_loaned_sample(&self, distance_to_chunk: PointerOffset) {
-        self.release_sample(distance_to_chunk);
-        self.loan_counter.fetch_sub(1, Ordering::Relaxed);
-    }
 
-    fn add_sample_to_history(&self, address_to_chunk: usize) {
-        match &self.history {
-            None => (),
-            Some(history) => {
-                let history = unsafe { &mut *history.get() };
-                self.borrow_sample(address_to_chunk);
-                match history.push_with_overflow(address_to_chunk) {
-                    None => (),
-                    Some(old) => self.release_sample(PointerOffset::new(old)),
+        for i in 0..self.observer_connections.len() {
+            if let Some(ref connection) = self.observer_connections.get(i) {
+                for address_to_chunk in addresses_to_chunks {
+                    if connection.accepts_next_sample() {
+                        self.deliver_to_observer(connection, *address_to_chunk);
+                    }
                 }
             }
         }
+
+        self.notify_sample_arrival();
+
+        Ok(number_of_recipients)
     }
 
-    fn deliver_sample(&self, address_to_chunk: usize) -> Result<usize, PublisherSendError> {
-        self.retrieve_returned_samples();
+    fn notify_sample_arrival(&self) {
+        if !self
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .has_sample_arrival_listeners()
+        {
+            return;
+        }
 
-        let deliver_call = match self.config.unable_to_deliver_strategy {
-            UnableToDeliverStrategy::Block => {
-                <Service::Connection as ZeroCopyConnection>::Sender::blocking_send
-            }
-            UnableToDeliverStrategy::DiscardSample => {
-                <Service::Connection as ZeroCopyConnection>::Sender::try_send
+        if self.sample_arrival.get().is_none() {
+            // when it fails the publisher retries with the next delivery
+            match SampleArrivalNotifier::new(&self.shared_node, &self.static_config) {
+                Ok(notifier) => {
+                    let _ = self.sample_arrival.set(notifier);
+                }
+                Err(e) => {
+                    warn!(from self, "Unable to wake up the blocked subscribers since the sample arrival notifier could not be created ({:?}).", e);
+                    return;
+                }
             }
-        };
+        }
 
-        let mut number_of_recipients = 0;
-        for i in 0..self.subscriber_connections.len() {
-            match self.subscriber_connections.get(i) {
-                Some(ref connection) => {
-                    match deliver_call(&connection.sender, PointerOffset::new(address_to_chunk)) {
-                        Err(ZeroCopySendError::ReceiveBufferFull)
-                        | Err(ZeroCopySendError::UsedChunkListFull) => {
-                            /* causes no problem
-                             *   blocking_send => can never happen
-                             *   try_send => we tried and expect that the buffer is full
-                             * */
-                        }
-                        Err(ZeroCopySendError::ConnectionCorrupted) => {
-                            match &self.config.degration_callback {
-                                Some(c) => match c.call(
-                                    self.static_config.clone(),
-                                    self.port_id,
-                                    connection.subscriber_id,
-                                ) {
-                                    DegrationAction::Ignore => (),
-                                    DegrationAction::Warn => {
-                                        error!(from self,
-                                            "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
-                                            address_to_chunk, connection.subscriber_id);
-                                    }
-                                    DegrationAction::Fail => {
-                                        fail!(from self, with PublisherSendError::ConnectionCorrupted,
-                                            "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
-                                            address_to_chunk, connection.subscriber_id);
-                                    }
-                                },
-                                None => {
-                                    error!(from self,
-                                        "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
-                                        address_to_chunk, connection.subscriber_id);
-                                }
-                            }
-                        }
-                        Ok(overflow) => {
-                            self.borrow_sample(address_to_chunk);
-                            number_of_recipients += 1;
+        if let Some(notifier) = self.sample_arrival.get() {
+            notifier.notify();
+        }
+    }
 
-                            if let Some(old) = overflow {
-                                self.release_sample(old)
-                            }
-                        }
-                    }
+    // Observers never block the publisher, are not counted as recipients and are neither
+    // tracked by acknowledgments nor by the dead-letter channel.
+    fn deliver_to_observer(&self, connection: &Connection<Service>, address_to_chunk: usize) {
+        if connection.is_subscriber_dead() {
+            return;
+        }
+
+        match connection.sender.try_send(
+            PointerOffset::new(address_to_chunk),
+            self.chunk_size_of(address_to_chunk),
+        ) {
+            Ok(overflow) => {
+                self.borrow_sample(address_to_chunk);
+                if let Some(old) = overflow {
+                    self.release_sample(old);
                 }
-                None => (),
             }
+            Err(ZeroCopySendError::ConnectionCorrupted) => {
+                warn!(from self,
+                    "While delivering the sample: {:?} a corrupted connection was detected with observer {:?}.",
+                    address_to_chunk, connection.subscriber_id);
+            }
+            Err(ZeroCopySendError::ReceiveBufferFull)
+            | Err(ZeroCopySendError::UsedChunkListFull)
+            | Err(ZeroCopySendError::WaitFailed) => (),
         }
-        Ok(number_of_recipients)
     }
 
     fn populate_subscriber_channels(&self) -> Result<(), ZeroCopyCreationError> {
+        self.populate_channels(&self.subscriber_connections, &self.subscriber_list_state)?;
+        self.populate_channels(&self.observer_connections, &self.observer_list_state)
+    }
+
+    fn populate_channels(
+        &self,
+        connections: &SubscriberConnections<Service>,
+        list_state: &UnsafeCell<ContainerState<SubscriberDetails>>,
+    ) -> Result<(), ZeroCopyCreationError> {
         let mut visited_indices = vec![];
-        visited_indices.resize(self.subscriber_connections.capacity(), None);
+        visited_indices.resize(connections.capacity(), None);
 
         unsafe {
-            (*self.subscriber_list_state.get()).for_each(|index, subscriber_id| {
-                visited_indices[index as usize] = Some(*subscriber_id);
+            (*list_state.get()).for_each(|index, subscriber_id| {
+                if let Some(entry) = visited_indices.get_mut(index as usize) {
+                    *entry = Some(*subscriber_id);
+                }
             })
         };
 
         for (i, index) in visited_indices.iter().enumerate() {
             match index {
                 Some(subscriber_details) => {
-                    let create_connection = match self.subscriber_connections.get(i) {
+                    let create_connection = match connections.get(i) {
                         None => true,
                         Some(connection) => {
                             let is_connected =
                                 connection.subscriber_id != subscriber_details.port_id;
                             if is_connected {
-                                self.remove_connection(i);
+                                self.remove_connection(connections, i);
                             }
                             is_connected
                         }
                     };
 
                     if create_connection {
-                        match self.subscriber_connections.create(
-                            i,
-                            *subscriber_details,
-                            self.config.max_slice_len,
-                        ) {
-                            Ok(()) => match &self.subscriber_connections.get(i) {
+                        match connections.create(i, *subscriber_details) {
+                            Ok(()) => match &connections.get(i) {
                                 Some(connection) => self.deliver_sample_history(connection),
                                 None => {
-                                    fatal_panic!(from self, "This should never happen! Unable to acquire previously created subscriber connection.")
+                                    fatal_panic_or_error!(from self, "This should never happen! Unable to acquire previously created subscriber connection.")
                                 }
                             },
                             Err(e) => match &self.config.degration_callback {
@@ -427,7 +1873,7 @@ impl<Service: service::Service> DataSegment<Service> {
                         }
                     }
                 }
-                None => self.remove_connection(i),
+                None => self.remove_connection(connections, i),
             }
         }
 
@@ -435,15 +1881,23 @@ impl<Service: service::Service> DataSegment<Service> {
     }
 
     fn update_connections(&self) -> Result<(), ConnectionFailure> {
-        if unsafe {
-            self.dynamic_storage
-                .get()
-                .publish_subscribe()
+        let dynamic_config = self.dynamic_storage.get().publish_subscribe();
+        let subscribers_changed = unsafe {
+            dynamic_config
                 .subscribers
                 .update_state(&mut *self.subscriber_list_state.get())
-        } {
+        };
+        let observers_changed = unsafe {
+            dynamic_config
+                .observers
+                .update_state(&mut *self.observer_list_state.get())
+        };
+
+        if subscribers_changed || observers_changed {
             fail!(from self, when self.populate_subscriber_channels(),
                 "Connections were updated only partially since at least one connection to a Subscriber port failed.");
+            // a crashed subscriber is often restarted, which changes the subscribers as well
+            self.reclaim_samples_of_dead_subscribers();
         }
 
         Ok(())
@@ -457,7 +1911,10 @@ impl<Service: service::Service> DataSegment<Service> {
                 for i in 0..history.len() {
                     let ptr_distance = unsafe { history.get_unchecked(i) };
 
-                    match connection.sender.try_send(PointerOffset::new(ptr_distance)) {
+                    match connection.sender.try_send(
+                        PointerOffset::new(ptr_distance),
+                        self.chunk_size_of(ptr_distance),
+                    ) {
                         Ok(_) => self.borrow_sample(ptr_distance),
                         Err(e) => {
                             warn!(from self, "Failed to deliver history to new subscriber via {:?} due to {:?}", connection, e);
@@ -468,8 +1925,19 @@ impl<Service: service::Service> DataSegment<Service> {
         }
     }
 
-    pub(crate) fn send_sample(&self, address_to_chunk: usize) -> Result<usize, PublisherSendError> {
-        let msg = "Unable to send sample";
+    fn prepare_sample(&self, address_to_chunk: usize) -> Result<(), PublisherSendError> {
+        self.stamp_header(address_to_chunk)?;
+        self.call_send_hook(address_to_chunk)?;
+        self.record_sample(address_to_chunk)?;
+        self.add_sample_to_history(address_to_chunk);
+        self.track_acknowledgment(|tracker| {
+            tracker.begin_sample();
+        });
+
+        Ok(())
+    }
+
+    fn verify_ready_to_send(&self, msg: &str) -> Result<(), PublisherSendError> {
         if !self.is_active.load(Ordering::Relaxed) {
             fail!(from self, with PublisherSendError::ConnectionBrokenSincePublisherNoLongerExists,
                 "{} since the connections could not be updated.", msg);
@@ -478,24 +1946,143 @@ impl<Service: service::Service> DataSegment<Service> {
         fail!(from self, when self.update_connections(),
             "{} since the connections could not be updated.", msg);
 
-        self.add_sample_to_history(address_to_chunk);
-        self.deliver_sample(address_to_chunk)
+        Ok(())
+    }
+
+    pub(crate) fn send_sample(&self, address_to_chunk: usize) -> Result<usize, PublisherSendError> {
+        self.send_sample_until(address_to_chunk, None)
+    }
+
+    pub(crate) fn send_sample_with_timeout(
+        &self,
+        address_to_chunk: usize,
+        timeout: Duration,
+    ) -> Result<TimedSendReport, PublisherSendError> {
+        unsafe { &mut *self.timed_out_subscribers.get() }.clear();
+        // a timeout that cannot be represented as deadline blocks like SampleMut::send()
+        let number_of_recipients =
+            self.send_sample_until(address_to_chunk, Instant::now().checked_add(timeout))?;
+
+        Ok(TimedSendReport {
+            number_of_recipients,
+            timed_out_subscribers: unsafe { &*self.timed_out_subscribers.get() }.clone(),
+        })
+    }
+
+    fn send_sample_until(
+        &self,
+        address_to_chunk: usize,
+        deadline: Option<Instant>,
+    ) -> Result<usize, PublisherSendError> {
+        self.verify_ready_to_send("Unable to send sample")?;
+        self.verify_canary(address_to_chunk)?;
+        self.prepare_sample(address_to_chunk)?;
+        self.update_last_send();
+        let number_of_recipients =
+            self.deliver_samples(core::slice::from_ref(&address_to_chunk), deadline)?;
+        self.add_metric(PublisherMetric::SentSamples, 1);
+        Ok(number_of_recipients)
+    }
+
+    pub(crate) fn send_samples(
+        &self,
+        addresses_to_chunks: &[usize],
+    ) -> Result<usize, PublisherSendError> {
+        self.verify_ready_to_send("Unable to send batch")?;
+        // either the whole batch is sent or none of its samples
+        for address_to_chunk in addresses_to_chunks {
+            self.verify_canary(*address_to_chunk)?;
+        }
+        self.update_last_send();
+
+        // the acknowledgment tracker attributes every recipient to the last begun sample,
+        // therefore the samples must be delivered one after another
+        if self.acknowledgments.is_some() {
+            let mut number_of_recipients = 0;
+            for address_to_chunk in addresses_to_chunks {
+                self.prepare_sample(*address_to_chunk)?;
+                number_of_recipients +=
+                    self.deliver_samples(core::slice::from_ref(address_to_chunk), None)?;
+                self.add_metric(PublisherMetric::SentSamples, 1);
+            }
+            return Ok(number_of_recipients);
+        }
+
+        for address_to_chunk in addresses_to_chunks {
+            self.prepare_sample(*address_to_chunk)?;
+        }
+        let number_of_recipients = self.deliver_samples(addresses_to_chunks, None)?;
+        self.add_metric(
+            PublisherMetric::SentSamples,
+            addresses_to_chunks.len() as u64,
+        );
+        Ok(number_of_recipients)
+    }
+
+    pub(crate) fn send_acknowledged_sample(
+        &self,
+        address_to_chunk: usize,
+    ) -> Result<SampleId, PublisherSendError> {
+        if self.acknowledgments.is_none() {
+            fail!(from self, with PublisherSendError::AcknowledgmentsNotEnabled,
+                "Unable to send sample with acknowledgment since the acknowledgments are not enabled.");
+        }
+
+        self.send_sample(address_to_chunk)?;
+        match self.track_acknowledgment(|tracker| tracker.last_sample_id()) {
+            Some(Some(sample_id)) => Ok(sample_id),
+            _ => {
+                fatal_panic_or_fail!(from self, with PublisherSendError::InternalFailure,
+                    "This should never happen! The sample was sent without acquiring a sample id.");
+            }
+        }
+    }
+
+    fn track_acknowledgment<R, F: FnOnce(&mut AcknowledgmentTracker) -> R>(
+        &self,
+        f: F,
+    ) -> Option<R> {
+        self.acknowledgments.as_ref().map(|tracker| {
+            // the tracker is always consistent, even when another thread panicked while holding it
+            let mut tracker = match tracker.lock() {
+                Ok(tracker) => tracker,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            f(&mut tracker)
+        })
+    }
+
+    fn acknowledgment_report(&self, sample_id: SampleId) -> Option<AcknowledgmentReport> {
+        self.retrieve_returned_samples();
+        if let Err(e) = self.update_connections() {
+            warn!(from self, "Acknowledgments of disconnected subscribers may not be reported ({:?}).", e);
+        }
+
+        self.track_acknowledgment(|tracker| tracker.report(sample_id).cloned())
+            .flatten()
     }
 }
 
 /// Sending endpoint of a publish-subscriber based communication.
 #[derive(Debug)]
-pub struct Publisher<Service: service::Service, PayloadType: Debug + ?Sized> {
+pub struct Publisher<Service: service::Service, PayloadType: Debug + ?Sized, UserHeader: Debug = ()>
+{
     pub(crate) data_segment: Arc<DataSegment<Service>>,
     dynamic_publisher_handle: Option<ContainerHandle>,
     _phantom_payload_type: PhantomData<PayloadType>,
+    _phantom_user_header: PhantomData<UserHeader>,
 }
 
-impl<Service: service::Service, PayloadType: Debug + ?Sized> Drop
-    for Publisher<Service, PayloadType>
+impl<Service: service::Service, PayloadType: Debug + ?Sized, UserHeader: Debug> Drop
+    for Publisher<Service, PayloadType, UserHeader>
 {
     fn drop(&mut self) {
         if let Some(handle) = self.dynamic_publisher_handle {
+            // samples that are released afterwards must not modify the metrics of the next
+            // publisher at this index
+            self.data_segment
+                .metrics_index
+                .store(NO_METRICS_INDEX, Ordering::Relaxed);
             self.data_segment
                 .dynamic_storage
                 .get()
@@ -505,14 +2092,23 @@ impl<Service: service::Service, PayloadType: Debug + ?Sized> Drop
     }
 }
 
-impl<Service: service::Service, PayloadType: Debug + ?Sized> Publisher<Service, PayloadType> {
+impl<Service: service::Service, PayloadType: Debug + ?Sized, UserHeader: Debug>
+    Publisher<Service, PayloadType, UserHeader>
+{
     pub(crate) fn new(
         service: &Service,
-        static_config: &publish_subscribe::StaticConfig,
-        config: LocalPublisherConfig,
+        mut config: LocalPublisherConfig,
     ) -> Result<Self, PublisherCreateError> {
         let msg = "Unable to create Publisher port";
         let origin = "Publisher::new()";
+        let service_static_config = config.static_config_of(&service.state().static_config);
+        let static_config = service_static_config.publish_subscribe();
+        if config.data_segment_allocator == DataSegmentAllocator::MultiPool {
+            Self::verify_mem_pools(&config)?;
+            if let Some(max_slice_len) = config.mem_pools.iter().map(|p| p.max_slice_len).max() {
+                config.max_slice_len = max_slice_len;
+            }
+        }
         let port_id = UniquePublisherId::new();
         let subscriber_list = &service
             .state()
@@ -520,127 +2116,476 @@ impl<Service: service::Service, PayloadType: Debug + ?Sized> Publisher<Service,
             .get()
             .publish_subscribe()
             .subscribers;
+        let observer_list = &service
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .observers;
 
         let dynamic_storage = Arc::clone(&service.state().dynamic_storage);
-        let number_of_samples = service
+        let memory_breakdown = PublisherMemoryBreakdown::new(static_config, &config);
+        let data_segment_size = memory_breakdown.data_segment_size();
+        let number_of_segments = match config.allocation_strategy {
+            AllocationStrategy::Static => 1,
+            AllocationStrategy::BestFit | AllocationStrategy::PowerOfTwo => MAX_NUMBER_OF_SEGMENTS,
+        };
+
+        let first_segment = fail!(from origin,
+                when Segment::create(port_id, &service.state().shared_node, &service_static_config, &config, 0, config.max_slice_len, None),
+                "{} since the data segment could not be created.", msg);
+        let number_of_chunks = first_segment.number_of_chunks();
+        let chunk_size = first_segment.chunk_size;
+
+        let dead_letter_channel = match static_config.dead_letter_service() {
+            Some(name) => Some(fail!(from origin,
+                when DeadLetterChannel::new(
+                    service.state().shared_node.clone(),
+                    name,
+                    static_config.dead_letter_includes_payload(),
+                    static_config.type_details().payload_layout(config.max_slice_len).size(),
+                ),
+                "{} since the dead-letter channel could not be created.", msg)),
+            None => None,
+        };
+
+        let flight_recorder = match static_config.flight_recorder_capacity() {
+            0 => None,
+            capacity => Some(Mutex::new(fail!(from origin,
+                when FlightRecorder::new(
+                    service.state().shared_node.config(),
+                    service.state().static_config.name(),
+                    port_id,
+                    capacity,
+                    static_config.type_details().payload_layout(config.max_slice_len).size(),
+                ),
+                with PublisherCreateError::UnableToCreateFlightRecorder,
+                "{} since the flight recorder could not be created.", msg))),
+        };
+
+        let acknowledgments = match config.enable_acknowledgments {
+            true => Some(Mutex::new(AcknowledgmentTracker::default())),
+            false => None,
+        };
+
+        let data_segment = Arc::new(DataSegment {
+            is_active: IoxAtomicBool::new(true),
+            dead_letter_channel,
+            segments: core::iter::once(Some(first_segment))
+                .chain((1..number_of_segments).map(|_| None))
+                .map(UnsafeCell::new)
+                .collect(),
+            current_segment: IoxAtomicUsize::new(0),
+            dynamic_storage,
+            shared_node: service.state().shared_node.clone(),
+            port_id,
+            subscriber_connections: SubscriberConnections::new(
+                subscriber_list.capacity(),
+                service.state().shared_node.clone(),
+                port_id,
+                &service_static_config,
+                number_of_chunks,
+                chunk_size,
+                number_of_segments,
+            ),
+            observer_connections: SubscriberConnections::new(
+                observer_list.capacity(),
+                service.state().shared_node.clone(),
+                port_id,
+                &service_static_config,
+                number_of_chunks,
+                chunk_size,
+                number_of_segments,
+            ),
+            config,
+            subscriber_list_state: unsafe { UnsafeCell::new(subscriber_list.get_state()) },
+            observer_list_state: unsafe { UnsafeCell::new(observer_list.get_state()) },
+            history: match static_config.history_size == 0 {
+                true => None,
+                false => Some(UnsafeCell::new(Queue::new(static_config.history_size))),
+            },
+            static_config: service_static_config.clone(),
+            loan_counter: IoxAtomicUsize::new(0),
+            group_delivery_counter: IoxAtomicUsize::new(0),
+            group_members: UnsafeCell::new(Vec::with_capacity(subscriber_list.capacity())),
+            timed_out_subscribers: UnsafeCell::new(Vec::with_capacity(subscriber_list.capacity())),
+            acknowledgments,
+            flight_recorder,
+            sample_arrival: OnceCell::new(),
+            // the first segment and the optional flight recorder, every additional segment
+            // is registered when it is created
+            resource_registration: NodeResourceRegistration::new(
+                service.state().shared_node.clone(),
+                NodeResource::Publisher,
+                1 + usize::from(static_config.flight_recorder_capacity() > 0),
+            ),
+            metrics_index: IoxAtomicUsize::new(NO_METRICS_INDEX),
+            next_publisher_sequence_number: IoxAtomicU64::new(0),
+        });
+
+        let mut new_self = Self {
+            data_segment,
+            dynamic_publisher_handle: None,
+            _phantom_payload_type: PhantomData,
+            _phantom_user_header: PhantomData,
+        };
+
+        if let Err(e) = new_self.data_segment.populate_subscriber_channels() {
+            warn!(from new_self, "The new Publisher port is unable to connect to every Subscriber port, caused by {:?}.", e);
+        }
+
+        std::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a publisher is added to the dynamic config without the
+        // creation of all required resources
+        let dynamic_publisher_handle = match service
             .state()
-            .static_config
-            .messaging_pattern
-            .required_amount_of_samples_per_data_segment(config.max_loaned_samples);
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .add_publisher_id(PublisherDetails {
+                publisher_id: port_id,
+                node_id: *service.state().shared_node.id(),
+                number_of_samples: number_of_chunks,
+                chunk_size,
+                data_segment_size,
+                number_of_segments,
+                sample_capacity: memory_breakdown.number_of_samples(),
+            }) {
+            Ok(unique_index) => unique_index,
+            Err(PublisherSlotError::ExceedsMaxNumberOfPublishers) => {
+                fail!(from origin, with PublisherCreateError::ExceedsMaxSupportedPublishers,
+                            "{} since it would exceed the maximum supported amount of publishers of {}.",
+                            msg, service.state().static_config.publish_subscribe().max_publishers);
+            }
+            Err(PublisherSlotError::ExceedsMemoryBudget) => {
+                let dynamic_config = service.state().dynamic_storage.get().publish_subscribe();
+                fail!(from origin, with PublisherCreateError::ExceedsServiceMemoryBudget,
+                    "{} since its {} would exceed the service memory budget of {} bytes, {} bytes are already in use by [{}].",
+                    msg, memory_breakdown, static_config.memory_budget(),
+                    dynamic_config.shared_memory_bytes(), memory_consumers(dynamic_config));
+            }
+        };
+
+        new_self
+            .data_segment
+            .metrics_index
+            .store(dynamic_publisher_handle.index() as usize, Ordering::Relaxed);
+        new_self.dynamic_publisher_handle = Some(dynamic_publisher_handle);
+
+        Ok(new_self)
+    }
+
+    fn verify_mem_pools(config: &LocalPublisherConfig) -> Result<(), PublisherCreateError> {
+        let msg = "Unable to create Publisher port with the memory pools";
+        let origin = "Publisher::verify_mem_pools()";
+        if config.mem_pools.len() > MAX_NUMBER_OF_MEM_POOLS {
+            fail!(from origin, with PublisherCreateError::InvalidMemPoolConfig,
+                "{} {:?} since at most {} memory pools are supported.",
+                msg, config.mem_pools, MAX_NUMBER_OF_MEM_POOLS);
+        }
+
+        if config.mem_pools.iter().any(|p| p.number_of_samples == 0) {
+            fail!(from origin, with PublisherCreateError::InvalidMemPoolConfig,
+                "{} {:?} since every memory pool must provide at least one sample.",
+                msg, config.mem_pools);
+        }
+
+        if config.allocation_strategy != AllocationStrategy::Static {
+            fail!(from origin, with PublisherCreateError::InvalidMemPoolConfig,
+                "{} {:?} since the data segment cannot grow with the allocation strategy {:?}.",
+                msg, config.mem_pools, config.allocation_strategy);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the [`UniquePublisherId`] of the [`Publisher`]
+    pub fn id(&self) -> UniquePublisherId {
+        self.data_segment.port_id
+    }
+
+    /// Returns the [`ConnectedPort`]s of all [`crate::port::subscriber::Subscriber`]s that are
+    /// currently connected to the service, including the name of the
+    /// [`crate::node::Node`] they belong to. Observers are not included.
+    pub fn connected_subscribers(&self) -> Vec<ConnectedPort<UniqueSubscriberId>> {
+        let config = self.data_segment.shared_node.config();
+        self.data_segment
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .subscriber_ids()
+            .into_iter()
+            .map(|(port_id, node_id)| ConnectedPort::new::<Service>(config, port_id, node_id))
+            .collect()
+    }
+
+    /// Returns the [`ConnectionBlockingStatistics`] of all connected
+    /// [`crate::port::subscriber::Subscriber`]s. Only deliveries with the
+    /// [`UnableToDeliverStrategy::Block`] can block, the statistics of the other subscribers
+    /// stay empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let publisher = service
+    ///     .publisher_builder()
+    ///     .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+    ///     .create()?;
+    ///
+    /// for statistics in publisher.blocking_statistics() {
+    ///     println!("subscriber {:?} blocked for {:?}",
+    ///         statistics.subscriber_id(), statistics.total_blocking_time());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn blocking_statistics(&self) -> Vec<ConnectionBlockingStatistics> {
+        let connections = &self.data_segment.subscriber_connections;
+        (0..connections.len())
+            .filter_map(|i| connections.get(i).as_ref())
+            .map(|connection| connection.blocking_statistics())
+            .collect()
+    }
+
+    /// Stops the delivery of samples to the [`crate::port::subscriber::Subscriber`] with the
+    /// provided [`UniqueSubscriberId`], so that a slow subscriber can no longer block the
+    /// [`Publisher`]. The subscriber stays connected to the service and can still return the
+    /// samples it holds. Returns false when no such subscriber is connected.
+    pub fn disconnect_subscriber(&self, subscriber_id: UniqueSubscriberId) -> bool {
+        let connections = &self.data_segment.subscriber_connections;
+        match (0..connections.len())
+            .filter_map(|i| connections.get(i).as_ref())
+            .find(|connection| connection.subscriber_id == subscriber_id)
+        {
+            Some(connection) => {
+                connection.disconnect();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns a [`DataSegmentReport`] that describes the current utilization and fragmentation
+    /// of the data segment. Helps to diagnose why a loan fails with
+    /// [`PublisherLoanError::OutOfMemory`] although enough free memory seems to be available.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u8]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let publisher = service.publisher_builder().max_slice_len(1024).create()?;
+    ///
+    /// let report = publisher.data_segment_report();
+    /// println!("free memory: {}", report.memory().free_memory());
+    /// println!("largest loanable payload: {}", report.largest_allocatable_payload());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn data_segment_report(&self) -> DataSegmentReport {
+        self.data_segment.report()
+    }
 
-        let data_segment = fail!(from origin,
-                when Self::create_data_segment(port_id, service.state().shared_node.config(), number_of_samples, static_config, &config),
-                with PublisherCreateError::UnableToCreateDataSegment,
-                "{} since the data segment could not be acquired.", msg);
+    /// Returns the [`PublisherMetrics`], the number of sent and dropped samples, the loan
+    /// failures and the utilization of the data segment. The metrics are stored in the shared
+    /// memory of the service so that they can also be read by other processes with
+    /// [`crate::node::AliveNodeView::metrics()`].
+    pub fn metrics(&self) -> PublisherMetrics {
+        self.data_segment.retrieve_returned_samples();
+        let dynamic_config = self.data_segment.dynamic_storage.get().publish_subscribe();
+        match dynamic_config.publisher_metrics(self.data_segment.port_id) {
+            Some(metrics) => metrics,
+            None => {
+                fatal_panic!(from self,
+                    "This should never happen! The publisher is not registered in the dynamic config of the service.");
+            }
+        }
+    }
 
-        let max_slice_len = config.max_slice_len;
-        let data_segment = Arc::new(DataSegment {
-            is_active: IoxAtomicBool::new(true),
-            memory: data_segment,
-            payload_size: static_config
-                .type_details()
-                .sample_layout(config.max_slice_len)
-                .size(),
-            payload_type_layout: static_config
-                .type_details()
-                .payload_layout(config.max_slice_len),
-            sample_reference_counter: {
-                let mut v = Vec::with_capacity(number_of_samples);
-                for _ in 0..number_of_samples {
-                    v.push(IoxAtomicU64::new(0));
-                }
-                v
-            },
-            dynamic_storage,
-            port_id,
-            subscriber_connections: SubscriberConnections::new(
-                subscriber_list.capacity(),
-                service.state().shared_node.clone(),
-                port_id,
-                static_config,
-                number_of_samples,
-            ),
-            config,
-            subscriber_list_state: unsafe { UnsafeCell::new(subscriber_list.get_state()) },
-            history: match static_config.history_size == 0 {
-                true => None,
-                false => Some(UnsafeCell::new(Queue::new(static_config.history_size))),
-            },
-            static_config: service.state().static_config.clone(),
-            loan_counter: IoxAtomicUsize::new(0),
-        });
+    /// Returns the [`AcknowledgmentReport`] of a [`crate::sample::Sample`] that was sent with
+    /// [`crate::sample_mut::SampleMut::send_acknowledged()`]. Returns [`None`] when the
+    /// acknowledgments are not enabled or when the report was already discarded, see
+    /// [`crate::port::acknowledgment::MAX_ACKNOWLEDGMENT_REPORTS`].
+    pub fn acknowledgment_report(&self, sample_id: SampleId) -> Option<AcknowledgmentReport> {
+        self.data_segment.acknowledgment_report(sample_id)
+    }
 
-        let mut new_self = Self {
-            data_segment,
-            dynamic_publisher_handle: None,
-            _phantom_payload_type: PhantomData,
+    /// Waits until every [`crate::port::subscriber::Subscriber`] that received the
+    /// [`crate::sample::Sample`] either acknowledged or lost it, or until the timeout has
+    /// passed. Returns true when no acknowledgment is pending anymore, otherwise false.
+    pub fn timed_wait_for_acknowledgments(
+        &self,
+        sample_id: SampleId,
+        timeout: Duration,
+    ) -> Result<bool, AcknowledgmentWaitError> {
+        let msg = "Unable to wait for acknowledgments";
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+            with AcknowledgmentWaitError::InternalFailure,
+            "{} since the adaptive wait could not be created.", msg);
+
+        let is_pending = || match self.acknowledgment_report(sample_id) {
+            Some(report) => Ok(!report.is_complete()),
+            None => Err(AcknowledgmentWaitError::UnknownSampleId),
         };
 
-        if let Err(e) = new_self.data_segment.populate_subscriber_channels() {
-            warn!(from new_self, "The new Publisher port is unable to connect to every Subscriber port, caused by {:?}.", e);
+        match adaptive_wait.timed_wait_while(is_pending, timeout) {
+            Ok(v) => Ok(v),
+            Err(AdaptiveTimedWaitWhileError::PredicateFailure(e)) => {
+                fail!(from self, with e,
+                    "{} of sample {:?} since the sample is not tracked.", msg, sample_id);
+            }
+            Err(AdaptiveTimedWaitWhileError::AdaptiveWaitError(e)) => {
+                fail!(from self, with AcknowledgmentWaitError::InternalFailure,
+                    "{} due to an internal failure ({:?}).", msg, e);
+            }
         }
+    }
 
-        std::sync::atomic::compiler_fence(Ordering::SeqCst);
+    /// Returns the number of samples that are currently loaned and were neither sent nor
+    /// dropped. A number that keeps growing hints to a leak of loaned samples. At most
+    /// [`Publisher::max_loaned_samples()`] samples can be loaned in parallel, every further
+    /// loan fails with [`PublisherLoanError::ExceedsMaxLoans`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let publisher = service.publisher_builder().max_loaned_samples(4).create()?;
+    ///
+    /// let sample = publisher.loan_uninit()?;
+    /// assert_eq!(publisher.loaned_samples(), 1);
+    ///
+    /// sample.write_payload(1234).send()?;
+    /// assert_eq!(publisher.loaned_samples(), 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loaned_samples(&self) -> usize {
+        self.data_segment.loan_counter.load(Ordering::Relaxed)
+    }
 
-        // !MUST! be the last task otherwise a publisher is added to the dynamic config without the
-        // creation of all required resources
-        let dynamic_publisher_handle = match service
-            .state()
-            .dynamic_storage
-            .get()
-            .publish_subscribe()
-            .add_publisher_id(PublisherDetails {
-                publisher_id: port_id,
-                number_of_samples,
-                max_slice_len,
-            }) {
-            Some(unique_index) => unique_index,
+    /// Returns the maximum number of samples that can be loaned in parallel, see
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::max_loaned_samples()`].
+    pub fn max_loaned_samples(&self) -> usize {
+        self.data_segment.config.max_loaned_samples
+    }
+
+    /// Returns the number of samples with the maximum slice length that can be loaned right
+    /// now before either the maximum number of loaned samples is reached or the data segment
+    /// runs out of memory. Allows producers to throttle before loans start failing. With an
+    /// [`DataSegmentAllocator`] other than [`DataSegmentAllocator::Pool`] fragmentation can lead
+    /// to fewer successful loans.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// let publisher = service.publisher_builder().create()?;
+    ///
+    /// if publisher.available_loans() > 0 {
+    ///     publisher.send_copy(1234)?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn available_loans(&self) -> usize {
+        let remaining_loans = self
+            .data_segment
+            .config
+            .max_loaned_samples
+            .saturating_sub(self.data_segment.loan_counter.load(Ordering::Relaxed));
+        let segment = match self.data_segment.current_segment() {
+            Some(segment) => segment,
             None => {
-                fail!(from origin, with PublisherCreateError::ExceedsMaxSupportedPublishers,
-                            "{} since it would exceed the maximum supported amount of publishers of {}.",
-                            msg, service.state().static_config.publish_subscribe().max_publishers);
+                fatal_panic_or_error!(from self,
+                    "This should never happen! Unable to acquire the available loans since the current segment does not exist.");
+                return 0;
             }
         };
+        let sample_size = (self.sample_layout(segment.max_slice_len).size()
+            + DataSegment::<Service>::canary_size(&self.data_segment.config))
+        .next_multiple_of(segment.chunk_size);
 
-        new_self.dynamic_publisher_handle = Some(dynamic_publisher_handle);
-
-        Ok(new_self)
+        remaining_loans.min(self.available_bytes() / sample_size)
     }
 
-    fn create_data_segment(
-        port_id: UniquePublisherId,
-        global_config: &config::Config,
-        number_of_samples: usize,
-        static_config: &publish_subscribe::StaticConfig,
-        config: &LocalPublisherConfig,
-    ) -> Result<Service::SharedMemory, SharedMemoryCreateError> {
-        let l = static_config
-            .type_details
-            .sample_layout(config.max_slice_len);
-        let allocator_config = shm_allocator::pool_allocator::Config { bucket_layout: l };
+    /// Returns the number of bytes that are currently not in use in the data segment. When the
+    /// data segment has grown, only the most recently added segment is considered since all new
+    /// samples are loaned from it.
+    pub fn available_bytes(&self) -> usize {
+        self.data_segment.retrieve_returned_samples();
+        match self.data_segment.current_segment() {
+            Some(segment) => segment.memory.fragmentation_report().free_memory(),
+            None => {
+                fatal_panic_or_error!(from self,
+                    "This should never happen! Unable to acquire the available bytes since the current segment does not exist.");
+                0
+            }
+        }
+    }
 
-        Ok(fail!(from "Publisher::create_data_segment()",
-            when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
-            Service::SharedMemory,
-                >>::new(&data_segment_name(port_id))
-                .config(&data_segment_config::<Service>(global_config))
-                .size(l.size() * number_of_samples + l.align() - 1)
-                .create(&allocator_config),
-            "Unable to create the data segment."))
+    /// Reclaims all samples that are held by [`Subscriber`](crate::port::subscriber::Subscriber)s
+    /// whose [`Node`] died without releasing them, for instance since its process crashed.
+    /// Afterwards no more samples are delivered to these
+    /// [`Subscriber`](crate::port::subscriber::Subscriber)s. Returns the number of reclaimed
+    /// samples.
+    ///
+    /// It is called automatically when the [`Publisher`] runs out of memory and when the
+    /// connected [`Subscriber`](crate::port::subscriber::Subscriber)s change, so that a dead
+    /// [`Subscriber`](crate::port::subscriber::Subscriber) cannot exhaust the data segment
+    /// until its stale resources are removed with [`Node::cleanup_dead_nodes()`].
+    pub fn reclaim_samples_of_dead_subscribers(&self) -> usize {
+        self.data_segment.retrieve_returned_samples();
+        self.data_segment.reclaim_samples_of_dead_subscribers()
     }
 
-    /// Returns the [`UniquePublisherId`] of the [`Publisher`]
-    pub fn id(&self) -> UniquePublisherId {
-        self.data_segment.port_id
+    fn allocate(&self, number_of_elements: usize) -> Result<ShmPointer, PublisherLoanError> {
+        let result = self.allocate_chunk(number_of_elements);
+        if result.is_err() {
+            self.data_segment
+                .add_metric(PublisherMetric::LoanFailures, 1);
+        }
+        result
     }
 
-    fn allocate(&self, layout: Layout) -> Result<ShmPointer, PublisherLoanError> {
+    fn allocate_chunk(&self, number_of_elements: usize) -> Result<ShmPointer, PublisherLoanError> {
         let msg = "Unable to allocate Sample with";
+        let layout = self.sample_layout(number_of_elements);
 
         if self.data_segment.loan_counter.load(Ordering::Relaxed)
             >= self.data_segment.config.max_loaned_samples
         {
-            fail!(from self, with PublisherLoanError::ExceedsMaxLoanedChunks,
+            fail!(from self, with PublisherLoanError::ExceedsMaxLoans,
                 "{} {:?} since already {} samples were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned sample to loan another sample.",
                 msg, layout, self.data_segment.loan_counter.load(Ordering::Relaxed), self.data_segment.config.max_loaned_samples);
         }
@@ -650,15 +2595,31 @@ impl<Service: service::Service, PayloadType: Debug + ?Sized> Publisher<Service,
                 self.data_segment
                     .loan_counter
                     .fetch_add(1, Ordering::Relaxed);
+
+                if self.data_segment.config.zeroing_policy == ZeroingPolicy::OnLoan {
+                    unsafe { core::ptr::write_bytes(chunk.data_ptr, 0, layout.size()) };
+                }
+
+                let payload_end = self.payload_ptr(chunk.data_ptr as *const Header) as usize
+                    + self.payload_layout(number_of_elements).size();
+                self.data_segment.place_canary(&chunk, layout, payload_end);
+
                 Ok(chunk)
             }
             Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                // the liveness of the subscribers is only checked on demand since it is costly,
+                // the retry terminates since the samples of a dead subscriber are reclaimed once
+                if self.data_segment.reclaim_samples_of_dead_subscribers() > 0 {
+                    return self.allocate_chunk(number_of_elements);
+                }
+
                 fail!(from self, with PublisherLoanError::OutOfMemory,
                     "{} {:?} since the underlying shared memory is out of memory.", msg, layout);
             }
             Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
             | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
-                fatal_panic!(from self, "{} {:?} since the system seems to be corrupted.", msg, layout);
+                fatal_panic_or_fail!(from self, with PublisherLoanError::InternalFailure,
+                    "{} {:?} since the system seems to be corrupted.", msg, layout);
             }
             Err(v) => {
                 fail!(from self, with PublisherLoanError::InternalFailure,
@@ -683,6 +2644,15 @@ impl<Service: service::Service, PayloadType: Debug + ?Sized> Publisher<Service,
             .payload_layout(number_of_elements)
     }
 
+    fn user_header_ptr(&self, header: *const Header) -> *const u8 {
+        self.data_segment
+            .subscriber_connections
+            .static_config
+            .type_details
+            .user_header_ptr_from_header(header.cast())
+            .cast()
+    }
+
     fn payload_ptr(&self, header: *const Header) -> *const u8 {
         self.data_segment
             .subscriber_connections
@@ -696,7 +2666,9 @@ impl<Service: service::Service, PayloadType: Debug + ?Sized> Publisher<Service,
 ////////////////////////
 // BEGIN: typed API
 ////////////////////////
-impl<Service: service::Service, PayloadType: Debug + Sized> Publisher<Service, PayloadType> {
+impl<Service: service::Service, PayloadType: Debug + Sized, UserHeader: Debug + Default>
+    Publisher<Service, PayloadType, UserHeader>
+{
     /// Copies the input `value` into a [`crate::sample_mut::SampleMut`] and delivers it.
     /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
     /// the data, otherwise a [`PublisherSendError`] describing the failure.
@@ -760,29 +2732,37 @@ impl<Service: service::Service, PayloadType: Debug + Sized> Publisher<Service, P
     /// ```
     pub fn loan_uninit(
         &self,
-    ) -> Result<SampleMut<MaybeUninit<PayloadType>, Service>, PublisherLoanError> {
-        let chunk = self.allocate(self.sample_layout(1))?;
+    ) -> Result<SampleMut<MaybeUninit<PayloadType>, Service, UserHeader>, PublisherLoanError> {
+        let chunk = self.allocate(1)?;
         let header_ptr = chunk.data_ptr as *mut Header;
+        let user_header_ptr = self.user_header_ptr(header_ptr) as *mut UserHeader;
         let payload_ptr = self.payload_ptr(header_ptr) as *mut MaybeUninit<PayloadType>;
 
         unsafe {
             header_ptr.write(Header::new(
                 self.data_segment.port_id,
                 Layout::new::<PayloadType>(),
-            ))
+            ));
+            user_header_ptr.write(UserHeader::default());
         };
 
-        let sample = unsafe { RawSampleMut::new_unchecked(header_ptr, payload_ptr) };
-        Ok(SampleMut::<MaybeUninit<PayloadType>, Service>::new(
-            &self.data_segment,
-            sample,
-            chunk.offset,
-        ))
+        let sample =
+            unsafe { RawSampleMut::new_unchecked(header_ptr, user_header_ptr, payload_ptr) };
+        Ok(
+            SampleMut::<MaybeUninit<PayloadType>, Service, UserHeader>::new(
+                &self.data_segment,
+                sample,
+                chunk.offset,
+            ),
+        )
     }
 }
 
-impl<Service: service::Service, PayloadType: Default + Debug + Sized>
-    Publisher<Service, PayloadType>
+impl<
+        Service: service::Service,
+        PayloadType: Default + Debug + Sized,
+        UserHeader: Debug + Default,
+    > Publisher<Service, PayloadType, UserHeader>
 {
     /// Loans/allocates a [`crate::sample_mut::SampleMut`] from the underlying data segment of the [`Publisher`]
     /// and initialize it with the default value. This can be a performance hit and [`Publisher::loan_uninit`]
@@ -811,9 +2791,53 @@ impl<Service: service::Service, PayloadType: Default + Debug + Sized>
     /// # Ok(())
     /// # }
     /// ```
-    pub fn loan(&self) -> Result<SampleMut<PayloadType, Service>, PublisherLoanError> {
+    pub fn loan(&self) -> Result<SampleMut<PayloadType, Service, UserHeader>, PublisherLoanError> {
         Ok(self.loan_uninit()?.write_payload(PayloadType::default()))
     }
+
+    /// Loans `number_of_samples` [`crate::sample_mut::SampleMut`]s at once, initialized with
+    /// the default value, that are sent together with
+    /// [`crate::sample_mut_batch::SampleMutBatch::send_all()`]. Sending a batch updates the
+    /// connections once and delivers all samples in a single pass, which reduces the overhead
+    /// for high-frequency small messages.
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure and all samples
+    /// that were already loaned are released.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().max_loaned_samples(3).create()?;
+    ///
+    /// let mut batch = publisher.loan_batch(3)?;
+    /// for sample in batch.iter_mut() {
+    ///     *sample.payload_mut() = 42;
+    /// }
+    ///
+    /// batch.send_all()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_batch(
+        &self,
+        number_of_samples: usize,
+    ) -> Result<SampleMutBatch<PayloadType, Service, UserHeader>, PublisherLoanError> {
+        let mut samples = Vec::with_capacity(number_of_samples);
+        for _ in 0..number_of_samples {
+            samples.push(self.loan()?);
+        }
+
+        Ok(SampleMutBatch::new(samples))
+    }
 }
 ////////////////////////
 // END: typed API
@@ -822,7 +2846,9 @@ impl<Service: service::Service, PayloadType: Default + Debug + Sized>
 ////////////////////////
 // BEGIN: sliced API
 ////////////////////////
-impl<Service: service::Service, PayloadType: Default + Debug> Publisher<Service, [PayloadType]> {
+impl<Service: service::Service, PayloadType: Default + Debug, UserHeader: Debug + Default>
+    Publisher<Service, [PayloadType], UserHeader>
+{
     /// Loans/allocates a [`crate::sample_mut::SampleMut`] from the underlying data segment of the [`Publisher`]
     /// and initializes all slice elements with the default value. This can be a performance hit
     /// and [`Publisher::loan_slice_uninit()`] can be used to loan a slice of
@@ -857,13 +2883,15 @@ impl<Service: service::Service, PayloadType: Default + Debug> Publisher<Service,
     pub fn loan_slice(
         &self,
         number_of_elements: usize,
-    ) -> Result<SampleMut<[PayloadType], Service>, PublisherLoanError> {
+    ) -> Result<SampleMut<[PayloadType], Service, UserHeader>, PublisherLoanError> {
         let sample = self.loan_slice_uninit(number_of_elements)?;
         Ok(sample.write_from_fn(|_| PayloadType::default()))
     }
 }
 
-impl<Service: service::Service, PayloadType: Debug> Publisher<Service, [PayloadType]> {
+impl<Service: service::Service, PayloadType: Debug, UserHeader: Debug + Default>
+    Publisher<Service, [PayloadType], UserHeader>
+{
     /// Loans/allocates a [`crate::sample_mut::SampleMut`] from the underlying data segment of the [`Publisher`].
     /// The user has to initialize the payload before it can be sent.
     ///
@@ -894,45 +2922,213 @@ impl<Service: service::Service, PayloadType: Debug> Publisher<Service, [PayloadT
     pub fn loan_slice_uninit(
         &self,
         slice_len: usize,
-    ) -> Result<SampleMut<[MaybeUninit<PayloadType>], Service>, PublisherLoanError> {
-        let max_slice_len = self.data_segment.config.max_slice_len;
+    ) -> Result<SampleMut<[MaybeUninit<PayloadType>], Service, UserHeader>, PublisherLoanError>
+    {
+        self.loan_slice_uninit_impl(slice_len, slice_len)
+    }
+
+    // loans a sample with `slice_len` payload elements of the service and provides them as
+    // `number_of_elements` elements of the PayloadType, they differ when the payload layout
+    // was overridden
+    fn loan_slice_uninit_impl(
+        &self,
+        slice_len: usize,
+        number_of_elements: usize,
+    ) -> Result<SampleMut<[MaybeUninit<PayloadType>], Service, UserHeader>, PublisherLoanError>
+    {
+        let max_slice_len = match self.data_segment.current_segment() {
+            Some(segment) => segment.max_slice_len,
+            None => {
+                fatal_panic_or_fail!(from self, with PublisherLoanError::InternalFailure,
+                    "This should never happen! Unable to loan slice with {} elements since the current segment does not exist.",
+                    slice_len);
+            }
+        };
         if max_slice_len < slice_len {
-            fail!(from self, with PublisherLoanError::ExceedsMaxLoanSize,
-                "Unable to loan slice with {} elements since it would exceed the max supported slice length of {}.",
-                slice_len, max_slice_len);
+            if let Err(e) = self.data_segment.grow(slice_len) {
+                self.data_segment
+                    .add_metric(PublisherMetric::LoanFailures, 1);
+                fail!(from self, with e,
+                    "Unable to loan slice with {} elements since it would exceed the max supported slice length of {}.",
+                    slice_len, max_slice_len);
+            }
         }
 
-        let chunk = self.allocate(self.sample_layout(slice_len))?;
+        let chunk = self.allocate(slice_len)?;
         let header_ptr = chunk.data_ptr as *mut Header;
+        let user_header_ptr = self.user_header_ptr(header_ptr) as *mut UserHeader;
         let payload_ptr = self.payload_ptr(header_ptr) as *mut MaybeUninit<PayloadType>;
 
         unsafe {
             header_ptr.write(Header::new(
                 self.data_segment.port_id,
                 self.payload_layout(slice_len),
-            ))
+            ));
+            user_header_ptr.write(UserHeader::default());
         };
 
         let sample = unsafe {
             RawSampleMut::new_unchecked(
                 header_ptr,
-                core::slice::from_raw_parts_mut(payload_ptr, slice_len),
+                user_header_ptr,
+                core::slice::from_raw_parts_mut(payload_ptr, number_of_elements),
             )
         };
 
-        Ok(SampleMut::<[MaybeUninit<PayloadType>], Service>::new(
-            &self.data_segment,
-            sample,
-            chunk.offset,
-        ))
+        Ok(
+            SampleMut::<[MaybeUninit<PayloadType>], Service, UserHeader>::new(
+                &self.data_segment,
+                sample,
+                chunk.offset,
+            ),
+        )
+    }
+}
+
+impl<Service: service::Service, PayloadType: Debug + Copy, UserHeader: Debug + Default>
+    Publisher<Service, [PayloadType], UserHeader>
+{
+    /// Copies the input `value` slice into a [`crate::sample_mut::SampleMut`] and delivers it.
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the data, otherwise a [`PublisherSendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u64]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    ///                          .max_slice_len(16)
+    ///                          .create()?;
+    ///
+    /// let buffer = [1, 2, 3, 4];
+    /// publisher.send_slice_copy(&buffer)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_slice_copy(&self, value: &[PayloadType]) -> Result<usize, PublisherSendError> {
+        let msg = "Unable to send copy of slice payload";
+        let sample = fail!(from self, when self.loan_slice_uninit(value.len()),
+                                    "{} since the loan of a sample with {} elements failed.", msg, value.len());
+
+        let sample = sample.write_from_fn(|n| value[n]);
+        Ok(
+            fail!(from self, when self.data_segment.send_sample(sample.offset_to_chunk.value()),
+            "{} since the underlying send operation failed.", msg),
+        )
+    }
+
+    /// Sends the input `value` slice like [`Publisher::send_slice_copy()`] but splits it into
+    /// fragments when it exceeds the max slice length of the data segment, so that occasional
+    /// oversized messages, like maps or calibration blobs, can be sent without increasing the
+    /// size of every sample. Every fragment is sent as a separate
+    /// [`crate::sample::Sample`], the [`crate::port::subscriber::Subscriber`] reassembles them
+    /// with [`Subscriber::receive_reassembled()`](crate::port::subscriber::Subscriber::receive_reassembled()).
+    /// The buffer of the [`crate::port::subscriber::Subscriber`] must be able to hold all
+    /// fragments unless they are received concurrently, a message with a lost fragment is
+    /// discarded. On success it returns the number of
+    /// [`crate::port::subscriber::Subscriber`]s that received the last fragment, otherwise a
+    /// [`PublisherSendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u8]>()
+    /// #     .subscriber_max_buffer_size(8)
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder()
+    ///                          .max_slice_len(1024)
+    ///                          .create()?;
+    ///
+    /// let calibration_blob = vec![0u8; 4096];
+    /// publisher.send_slice_copy_fragmented(&calibration_blob)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_slice_copy_fragmented(
+        &self,
+        value: &[PayloadType],
+    ) -> Result<usize, PublisherSendError> {
+        let msg = "Unable to send fragmented copy of slice payload";
+        let max_fragment_len = match self.data_segment.current_segment() {
+            Some(segment) => segment.max_slice_len.max(1),
+            None => {
+                fatal_panic_or_fail!(from self, with PublisherSendError::InternalFailure,
+                    "This should never happen! {} since the current segment does not exist.", msg);
+            }
+        };
+        if value.len() <= max_fragment_len {
+            return self.send_slice_copy(value);
+        }
+
+        let mut number_of_recipients = 0;
+        for (n, fragment) in value.chunks(max_fragment_len).enumerate() {
+            let sample = fail!(from self, when self.loan_slice_uninit(fragment.len()),
+                "{} since the loan of the fragment {} with {} elements failed.", msg, n, fragment.len());
+
+            let mut sample = sample.write_from_fn(|i| fragment[i]);
+            sample.set_fragment((n * max_fragment_len) as u64, value.len() as u64);
+            number_of_recipients = fail!(from self, when self.data_segment.send_sample(sample.offset_to_chunk.value()),
+                "{} since the underlying send operation of the fragment {} failed.", msg, n);
+        }
+
+        Ok(number_of_recipients)
+    }
+}
+
+impl<Service: service::Service, UserHeader: Debug + Default> Publisher<Service, [u8], UserHeader> {
+    /// Loans/allocates a [`crate::sample_mut::SampleMut`] whose payload is provided as raw bytes.
+    /// It is intended for [`Service`](crate::service::Service)s whose payload layout is only
+    /// known at runtime, see
+    /// [`crate::service::builder::Builder::publish_subscribe_untyped()`]. The payload consists
+    /// of all bytes of the payload type of the [`Service`](crate::service::Service). The user
+    /// has to initialize the payload before it can be sent.
+    ///
+    /// On failure it returns [`PublisherLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe_untyped(8, Alignment::new(8).unwrap())
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let sample = publisher.loan_untyped_uninit()?;
+    /// let sample = sample.write_from_fn(|n| n as u8);
+    ///
+    /// sample.send()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn loan_untyped_uninit(
+        &self,
+    ) -> Result<SampleMut<[MaybeUninit<u8>], Service, UserHeader>, PublisherLoanError> {
+        self.loan_slice_uninit_impl(1, self.payload_layout(1).size())
     }
 }
 ////////////////////////
 // END: sliced API
 ////////////////////////
 
-impl<Service: service::Service, PayloadType: Debug> UpdateConnections
-    for Publisher<Service, PayloadType>
+impl<Service: service::Service, PayloadType: Debug, UserHeader: Debug> UpdateConnections
+    for Publisher<Service, PayloadType, UserHeader>
 {
     fn update_connections(&self) -> Result<(), ConnectionFailure> {
         self.data_segment.update_connections()
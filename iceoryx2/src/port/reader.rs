@@ -0,0 +1,206 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let blackboard = node.service_builder("MyBlackboardName".try_into()?)
+//!     .blackboard::<u64>()
+//!     .add::<f32>(0, 1.5)
+//!     .open_or_create()?;
+//!
+//! let reader = blackboard.reader_builder().create()?;
+//!
+//! let entry = reader.entry::<f32>(&0)?;
+//! println!("value: {}, changes are signaled with {:?}", entry.get(), entry.entry_id());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
+use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::UnrestrictedAtomic;
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use super::event_id::EventId;
+use super::port_identifiers::UniqueReaderId;
+use crate::service;
+use crate::service::dynamic_config::blackboard::ReaderDetails;
+use crate::service::static_config::blackboard::{EntryTypeDetails, StaticConfig};
+
+/// Failures that can occur when a new [`Reader`] is created with the
+/// [`crate::service::port_factory::reader::PortFactoryReader`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum ReaderCreateError {
+    ExceedsMaxSupportedReaders,
+}
+
+impl std::fmt::Display for ReaderCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ReaderCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ReaderCreateError {}
+
+/// Failures that can occur when an [`EntryHandle`] is acquired with [`Reader::entry()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum EntryHandleError {
+    EntryDoesNotExist,
+    IncompatibleValueType,
+}
+
+impl std::fmt::Display for EntryHandleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "EntryHandleError::{:?}", self)
+    }
+}
+
+impl std::error::Error for EntryHandleError {}
+
+/// Provides read access to the value of a single entry of the blackboard. The value is read
+/// directly from the shared memory of the service.
+pub struct EntryHandle<'reader, ValueType: Copy> {
+    atomic: &'reader UnrestrictedAtomic<ValueType>,
+    entry_id: EventId,
+}
+
+impl<ValueType: Copy + Debug> Debug for EntryHandle<'_, ValueType> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "EntryHandle<{}> {{ entry_id: {:?}, value: {:?} }}",
+            core::any::type_name::<ValueType>(),
+            self.entry_id,
+            self.atomic.load()
+        )
+    }
+}
+
+impl<ValueType: Copy> EntryHandle<'_, ValueType> {
+    /// Returns a copy of the latest value of the entry.
+    pub fn get(&self) -> ValueType {
+        self.atomic.load()
+    }
+
+    /// Returns the [`EventId`] that identifies the entry. It can be used with an
+    /// [`Event`](crate::service::messaging_pattern::MessagingPattern::Event) service to signal
+    /// and wait for changes of the entry.
+    pub fn entry_id(&self) -> EventId {
+        self.entry_id
+    }
+}
+
+/// The reading endpoint of a blackboard based communication.
+#[derive(Debug)]
+pub struct Reader<Service: service::Service, KeyType: Copy + Eq + Debug> {
+    dynamic_storage: Arc<Service::DynamicStorage>,
+    static_config: StaticConfig,
+    dynamic_reader_handle: Option<ContainerHandle>,
+    port_id: UniqueReaderId,
+    _key: PhantomData<KeyType>,
+}
+
+impl<Service: service::Service, KeyType: Copy + Eq + Debug> Drop for Reader<Service, KeyType> {
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_reader_handle {
+            self.dynamic_storage
+                .get()
+                .blackboard()
+                .release_reader_handle(handle)
+        }
+    }
+}
+
+impl<Service: service::Service, KeyType: Copy + Eq + Debug> Reader<Service, KeyType> {
+    pub(crate) fn new(service: &Service) -> Result<Self, ReaderCreateError> {
+        let msg = "Unable to create Reader port";
+        let origin = "Reader::new()";
+        let port_id = UniqueReaderId::new();
+        let static_config = service.state().static_config.blackboard().clone();
+
+        let mut new_self = Self {
+            dynamic_storage: Arc::clone(&service.state().dynamic_storage),
+            static_config,
+            dynamic_reader_handle: None,
+            port_id,
+            _key: PhantomData,
+        };
+
+        let dynamic_reader_handle =
+            match new_self
+                .dynamic_storage
+                .get()
+                .blackboard()
+                .add_reader_id(ReaderDetails {
+                    reader_id: port_id,
+                    node_id: *service.state().shared_node.id(),
+                }) {
+                Some(handle) => handle,
+                None => {
+                    fail!(from origin, with ReaderCreateError::ExceedsMaxSupportedReaders,
+                    "{} since it would exceed the maximum supported amount of readers of {}.",
+                    msg, new_self.static_config.max_readers);
+                }
+            };
+        new_self.dynamic_reader_handle = Some(dynamic_reader_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueReaderId`] of the [`Reader`]
+    pub fn id(&self) -> UniqueReaderId {
+        self.port_id
+    }
+
+    /// Returns an [`EntryHandle`] to read the value of the entry with the provided key. Fails
+    /// when no entry with the key exists or when the entry stores a value of another type.
+    pub fn entry<ValueType: Copy>(
+        &self,
+        key: &KeyType,
+    ) -> Result<EntryHandle<'_, ValueType>, EntryHandleError> {
+        let msg = "Unable to acquire entry handle";
+        let dynamic_config = self.dynamic_storage.get().blackboard();
+
+        // the key type was verified when the service was opened
+        let index = match unsafe { dynamic_config.entry_index(key) } {
+            Some(index) => index,
+            None => {
+                fail!(from self, with EntryHandleError::EntryDoesNotExist,
+                    "{} since no entry with the key {:?} exists.", msg, key);
+            }
+        };
+
+        let entry = &self.static_config.entries[index];
+        if entry.value_type_details != EntryTypeDetails::from::<ValueType>() {
+            fail!(from self, with EntryHandleError::IncompatibleValueType,
+                "{} since the entry with the key {:?} stores a value of type {:?} but {:?} was requested.",
+                msg, key, entry.value_type_details, EntryTypeDetails::from::<ValueType>());
+        }
+
+        Ok(EntryHandle {
+            atomic: unsafe {
+                &*(dynamic_config.entries_ptr().add(entry.offset)
+                    as *const UnrestrictedAtomic<ValueType>)
+            },
+            entry_id: EventId::new(index),
+        })
+    }
+}
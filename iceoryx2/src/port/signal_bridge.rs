@@ -0,0 +1,202 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_bb_posix::signal::{RealTimeSignal, Signal};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let event = node.service_builder("MyEventName".try_into()?)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! // raises SIGUSR2 in this process whenever the event id 3 is notified
+//! let mut bridge = event.signal_bridge_builder()
+//!     .map(EventId::new(3), Signal::UserDefined2);
+//!
+//! // raises SIGRTMIN + 1 for every notification of event id 4, real-time signals are queued
+//! // and not merged when the same signal is raised again before it was handled
+//! if let Ok(signal) = RealTimeSignal::new(1) {
+//!     bridge = bridge.map(EventId::new(4), signal);
+//! }
+//!
+//! let bridge = bridge.create()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::process::Process;
+use iceoryx2_bb_posix::signal::{RealTimeSignal, Signal};
+use iceoryx2_bb_posix::thread::{Thread, ThreadBuilder};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+
+use crate::port::event_id::EventId;
+use crate::port::listener::ListenerCreateError;
+use crate::service;
+use crate::service::port_factory::event::PortFactory;
+
+/// Failures that can occur when a [`SignalBridge`] is created with
+/// [`crate::service::port_factory::signal_bridge::PortFactorySignalBridge::create()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SignalBridgeCreateError {
+    /// The [`crate::port::listener::Listener`] of the [`SignalBridge`] could not be created.
+    UnableToCreateListener(ListenerCreateError),
+    /// The background thread that waits for the events could not be started.
+    UnableToStartThread,
+}
+
+impl std::fmt::Display for SignalBridgeCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "SignalBridgeCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for SignalBridgeCreateError {}
+
+/// The signal a [`SignalBridge`] raises for a mapped [`EventId`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum BridgeSignal {
+    /// A standard [`Signal`]. When it is raised again before the process handled it, both
+    /// instances are merged into one.
+    Standard(Signal),
+    /// A [`RealTimeSignal`], every raised instance is delivered to the process.
+    RealTime(RealTimeSignal),
+}
+
+impl From<Signal> for BridgeSignal {
+    fn from(value: Signal) -> Self {
+        BridgeSignal::Standard(value)
+    }
+}
+
+impl From<RealTimeSignal> for BridgeSignal {
+    fn from(value: RealTimeSignal) -> Self {
+        BridgeSignal::RealTime(value)
+    }
+}
+
+/// Waits in a background thread with its own [`crate::port::listener::Listener`] for events
+/// and raises the mapped [`BridgeSignal`] in the current process for every received
+/// [`EventId`]. It allows to integrate legacy applications whose main loop is driven by
+/// signals. The background thread is stopped and joined when the [`SignalBridge`] goes out of
+/// scope.
+///
+/// Use [`RealTimeSignal`]s when every notification must be handled, standard [`Signal`]s that
+/// are raised in quick succession are merged into one by the operating system.
+///
+/// The [`crate::port::listener::Listener`] of the [`SignalBridge`] occupies one of the
+/// [`crate::service::static_config::event::StaticConfig::max_supported_listeners()`] slots of
+/// the service as long as the [`SignalBridge`] exists.
+pub struct SignalBridge<'factory> {
+    keep_running: Arc<IoxAtomicBool>,
+    _thread: Thread<'factory>,
+}
+
+impl Debug for SignalBridge<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SignalBridge {{ keep_running: {} }}",
+            self.keep_running.load(Ordering::Relaxed)
+        )
+    }
+}
+
+impl Drop for SignalBridge<'_> {
+    fn drop(&mut self) {
+        self.keep_running.store(false, Ordering::Relaxed);
+    }
+}
+
+impl<'factory> SignalBridge<'factory> {
+    pub(crate) fn new<Service: service::Service>(
+        factory: &'factory PortFactory<Service>,
+        mapping: Vec<(EventId, BridgeSignal)>,
+        cycle_time: Duration,
+    ) -> Result<Self, SignalBridgeCreateError> {
+        let msg = "Unable to create SignalBridge";
+        let origin = "SignalBridge::new()";
+        let keep_running = Arc::new(IoxAtomicBool::new(true));
+        let (creation_result_tx, creation_result_rx) = std::sync::mpsc::sync_channel(1);
+
+        let thread_keep_running = keep_running.clone();
+        let thread = fail!(from origin,
+            when ThreadBuilder::new().spawn(move || {
+                // the listener is created inside the thread since it cannot be moved between
+                // threads
+                let listener = match factory.listener_builder().create() {
+                    Ok(listener) => {
+                        let _ = creation_result_tx.send(Ok(()));
+                        listener
+                    }
+                    Err(e) => {
+                        let _ = creation_result_tx.send(Err(e));
+                        return;
+                    }
+                };
+
+                let process = Process::from_self();
+                while thread_keep_running.load(Ordering::Relaxed) {
+                    let result = listener.timed_wait_all(
+                        |event_id| {
+                            for (_, signal) in mapping.iter().filter(|(id, _)| *id == event_id) {
+                                let result = match signal {
+                                    BridgeSignal::Standard(signal) => process.send_signal(*signal),
+                                    BridgeSignal::RealTime(signal) => {
+                                        process.send_real_time_signal(*signal)
+                                    }
+                                };
+
+                                if let Err(e) = result {
+                                    warn!(from "SignalBridge", "Unable to raise signal {:?} for event id {:?} ({:?}).",
+                                        signal, event_id, e);
+                                }
+                            }
+                        },
+                        cycle_time,
+                    );
+
+                    if let Err(e) = result {
+                        warn!(from "SignalBridge", "Unable to wait for events ({:?}).", e);
+                    }
+                }
+            }),
+            with SignalBridgeCreateError::UnableToStartThread,
+            "{} since the background thread could not be started.", msg);
+
+        match creation_result_rx.recv() {
+            Ok(Ok(())) => (),
+            Ok(Err(e)) => {
+                fail!(from origin, with SignalBridgeCreateError::UnableToCreateListener(e),
+                    "{} since the listener could not be created ({:?}).", msg, e);
+            }
+            Err(_) => {
+                fail!(from origin, with SignalBridgeCreateError::UnableToStartThread,
+                    "{} since the background thread terminated unexpectedly.", msg);
+            }
+        }
+
+        Ok(Self {
+            keep_running,
+            _thread: thread,
+        })
+    }
+}
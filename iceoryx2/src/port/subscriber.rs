@@ -31,35 +31,61 @@
 //! # }
 //! ```
 
-use std::cell::UnsafeCell;
+#![cfg_attr(
+    feature = "safety",
+    deny(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::indexing_slicing,
+        clippy::unreachable
+    )
+)]
+
+#[cfg(not(feature = "safety"))]
+use std::cell::RefCell;
+use std::cell::{Cell, OnceCell, UnsafeCell};
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{fail, warn};
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
-use iceoryx2_cal::{shared_memory::*, zero_copy_connection::*};
+use iceoryx2_cal::{shm_allocator::PointerOffset, zero_copy_connection::*};
 
-use crate::port::DegrationAction;
+use crate::node::resource_usage::{NodeResource, NodeResourceRegistration};
+use crate::node::SharedNode;
+use crate::port::{DegrationAction, SampleHook};
 use crate::sample::SampleDetails;
 use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
 use crate::service::header::publish_subscribe::Header;
-use crate::service::port_factory::subscriber::SubscriberConfig;
+use crate::service::port_factory::subscriber::{
+    OverflowStrategy, ReceiveOrder, SampleFilter, SubscriberConfig,
+};
 use crate::service::static_config::publish_subscribe::StaticConfig;
 use crate::{raw_sample::RawSample, sample::Sample, service};
 
+use super::connected_port::ConnectedPort;
+#[cfg(feature = "async")]
+use super::details::async_poller::AsyncRegistration;
 use super::details::publisher_connections::{Connection, PublisherConnections};
-use super::port_identifiers::UniqueSubscriberId;
+use super::details::sample_arrival::SampleArrivalListener;
+use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
 use super::update_connections::{ConnectionFailure, UpdateConnections};
 use super::DegrationCallback;
 
-/// Defines the failure that can occur when receiving data with [`Subscriber::receive()`].
+/// Defines the failure that can occur when receiving data with [`Subscriber::receive()`] or
+/// [`Subscriber::blocking_receive()`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum SubscriberReceiveError {
     ExceedsMaxBorrowedSamples,
+    InsufficientBufferSize,
     ConnectionFailure(ConnectionFailure),
+    UnableToWaitForSamples,
 }
 
 impl std::fmt::Display for SubscriberReceiveError {
@@ -75,6 +101,7 @@ impl std::error::Error for SubscriberReceiveError {}
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum SubscriberCreateError {
     ExceedsMaxSupportedSubscribers,
+    ExceedsMaxSupportedObservers,
     BufferSizeExceedsMaxSupportedBufferSizeOfService,
 }
 
@@ -86,33 +113,77 @@ impl std::fmt::Display for SubscriberCreateError {
 
 impl std::error::Error for SubscriberCreateError {}
 
+// a fragmented message of a publisher whose fragments are received one after another, see
+// Subscriber::receive_reassembled()
+#[cfg(not(feature = "safety"))]
+#[derive(Debug)]
+struct PendingMessage {
+    publisher_id: UniquePublisherId,
+    next_publisher_sequence_number: u64,
+    payload: Vec<u8>,
+}
+
 /// The receiving endpoint of a publish-subscribe communication.
 #[derive(Debug)]
-pub struct Subscriber<Service: service::Service, PayloadType: Debug + ?Sized> {
+pub struct Subscriber<
+    Service: service::Service,
+    PayloadType: Debug + ?Sized,
+    UserHeader: Debug = (),
+> {
     dynamic_subscriber_handle: Option<ContainerHandle>,
     publisher_connections: Arc<PublisherConnections<Service>>,
     dynamic_storage: Arc<Service::DynamicStorage>,
     static_config: crate::service::static_config::StaticConfig,
     degration_callback: Option<DegrationCallback<'static>>,
+    filter: Option<SampleFilter>,
+    receive_hook: Option<SampleHook>,
 
     publisher_list_state: UnsafeCell<ContainerState<PublisherDetails>>,
+    // when set, at most this many samples are received in a row from the same publisher
+    max_samples_per_publisher: Option<usize>,
+    // the connection that is served next and how many samples were received from it in a row
+    receive_cursor: Cell<(usize, usize)>,
+    // samples with a smaller sequence number were sent before the subscriber was created
+    history_sequence_number: u64,
+    // used by ReceiveOrder::TotalOrder, the sequence number that is received next and since
+    // when the oldest pending sample is held back because a sample before it is missing
+    next_sequence_number_in_order: Cell<u64>,
+    held_back_since: Cell<Option<Instant>>,
+    #[cfg(not(feature = "safety"))]
+    pending_messages: RefCell<Vec<PendingMessage>>,
+    is_observer: bool,
+    shared_node: Arc<SharedNode<Service>>,
+    // created with the first call to Subscriber::blocking_receive()
+    sample_arrival: OnceCell<SampleArrivalListener<Service>>,
+    _resource_registration: NodeResourceRegistration<Service>,
     _phantom_payload_type: PhantomData<PayloadType>,
+    _phantom_user_header: PhantomData<UserHeader>,
 }
 
-impl<Service: service::Service, PayloadType: Debug + ?Sized> Drop
-    for Subscriber<Service, PayloadType>
+impl<Service: service::Service, PayloadType: Debug + ?Sized, UserHeader: Debug> Drop
+    for Subscriber<Service, PayloadType, UserHeader>
 {
     fn drop(&mut self) {
-        if let Some(handle) = self.dynamic_subscriber_handle {
+        if self.sample_arrival.get().is_some() {
             self.dynamic_storage
                 .get()
                 .publish_subscribe()
-                .release_subscriber_handle(handle)
+                .remove_sample_arrival_listener();
+        }
+
+        if let Some(handle) = self.dynamic_subscriber_handle {
+            let dynamic_config = self.dynamic_storage.get().publish_subscribe();
+            match self.is_observer {
+                true => dynamic_config.release_observer_handle(handle),
+                false => dynamic_config.release_subscriber_handle(handle),
+            }
         }
     }
 }
 
-impl<Service: service::Service, PayloadType: Debug + ?Sized> Subscriber<Service, PayloadType> {
+impl<Service: service::Service, PayloadType: Debug + ?Sized, UserHeader: Debug>
+    Subscriber<Service, PayloadType, UserHeader>
+{
     pub(crate) fn new(
         service: &Service,
         static_config: &StaticConfig,
@@ -131,6 +202,11 @@ impl<Service: service::Service, PayloadType: Debug + ?Sized> Subscriber<Service,
 
         let dynamic_storage = Arc::clone(&service.state().dynamic_storage);
 
+        if config.is_observer && static_config.max_observers == 0 {
+            fail!(from origin, with SubscriberCreateError::ExceedsMaxSupportedObservers,
+                "{} since the service does not support observers.", msg);
+        }
+
         let buffer_size = match config.buffer_size {
             Some(buffer_size) => {
                 if static_config.subscriber_max_buffer_size < buffer_size {
@@ -147,18 +223,47 @@ impl<Service: service::Service, PayloadType: Debug + ?Sized> Subscriber<Service,
             publisher_list.capacity(),
             port_id,
             service.state().shared_node.clone(),
-            static_config,
+            &service.state().static_config,
             buffer_size,
+            OverflowStrategy::enables_safe_overflow(
+                config.overflow_strategy,
+                static_config.enable_safe_overflow,
+            ),
         ));
 
+        let history_sequence_number = service
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .next_sequence_number();
+
         let mut new_self = Self {
             degration_callback: config.degration_callback,
+            filter: config.filter,
+            receive_hook: config.receive_hook,
             publisher_connections,
             dynamic_storage,
             publisher_list_state: UnsafeCell::new(unsafe { publisher_list.get_state() }),
             dynamic_subscriber_handle: None,
             static_config: service.state().static_config.clone(),
+            max_samples_per_publisher: config.max_samples_per_publisher,
+            receive_cursor: Cell::new((0, 0)),
+            history_sequence_number,
+            next_sequence_number_in_order: Cell::new(history_sequence_number),
+            held_back_since: Cell::new(None),
+            #[cfg(not(feature = "safety"))]
+            pending_messages: RefCell::new(vec![]),
+            is_observer: config.is_observer,
+            shared_node: service.state().shared_node.clone(),
+            sample_arrival: OnceCell::new(),
+            _resource_registration: NodeResourceRegistration::new(
+                service.state().shared_node.clone(),
+                NodeResource::Subscriber,
+                0,
+            ),
             _phantom_payload_type: PhantomData,
+            _phantom_user_header: PhantomData,
         };
 
         if let Err(e) = new_self.populate_publisher_channels() {
@@ -169,21 +274,38 @@ impl<Service: service::Service, PayloadType: Debug + ?Sized> Subscriber<Service,
 
         // !MUST! be the last task otherwise a subscriber is added to the dynamic config without
         // the creation of all required channels
-        let dynamic_subscriber_handle = match service
-            .state()
-            .dynamic_storage
-            .get()
-            .publish_subscribe()
-            .add_subscriber_id(SubscriberDetails {
+        let dynamic_config = service.state().dynamic_storage.get().publish_subscribe();
+        let dynamic_subscriber_handle = match config.is_observer {
+            true => match dynamic_config.add_observer_id(SubscriberDetails {
                 port_id,
+                node_id: *service.state().shared_node.id(),
                 buffer_size,
+                group: None,
+                decimation: config.decimation,
+                overflow_strategy: None,
             }) {
-            Some(unique_index) => unique_index,
-            None => {
-                fail!(from new_self, with SubscriberCreateError::ExceedsMaxSupportedSubscribers,
+                Some(unique_index) => unique_index,
+                None => {
+                    fail!(from new_self, with SubscriberCreateError::ExceedsMaxSupportedObservers,
+                                "{} since it would exceed the maximum supported amount of observers of {}.",
+                                msg, static_config.max_observers);
+                }
+            },
+            false => match dynamic_config.add_subscriber_id(SubscriberDetails {
+                port_id,
+                node_id: *service.state().shared_node.id(),
+                buffer_size,
+                group: config.group,
+                decimation: config.decimation,
+                overflow_strategy: config.overflow_strategy,
+            }) {
+                Some(unique_index) => unique_index,
+                None => {
+                    fail!(from new_self, with SubscriberCreateError::ExceedsMaxSupportedSubscribers,
                                 "{} since it would exceed the maximum supported amount of subscribers of {}.",
-                                msg, service.state().static_config.publish_subscribe().max_subscribers);
-            }
+                                msg, static_config.max_subscribers);
+                }
+            },
         };
 
         new_self.dynamic_subscriber_handle = Some(dynamic_subscriber_handle);
@@ -197,7 +319,9 @@ impl<Service: service::Service, PayloadType: Debug + ?Sized> Subscriber<Service,
 
         unsafe {
             (*self.publisher_list_state.get()).for_each(|index, details| {
-                visited_indices[index as usize] = Some(*details);
+                if let Some(entry) = visited_indices.get_mut(index as usize) {
+                    *entry = Some(*details);
+                }
             })
         };
 
@@ -245,35 +369,140 @@ impl<Service: service::Service, PayloadType: Debug + ?Sized> Subscriber<Service,
         Ok(())
     }
 
+    fn receive_offset(
+        &self,
+        connection: &mut Connection<Service>,
+    ) -> Result<Option<PointerOffset>, SubscriberReceiveError> {
+        if let Some(offset) = connection.pending_sample.take() {
+            return Ok(Some(offset));
+        }
+
+        match connection.receiver.receive() {
+            Ok(offset) => Ok(offset),
+            Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                fail!(from self, with SubscriberReceiveError::ExceedsMaxBorrowedSamples,
+                    "Unable to receive another sample since it would exceed the maximum {} of borrowed samples.",
+                    connection.receiver.max_borrowed_samples());
+            }
+        }
+    }
+
+    fn chunk_address(
+        &self,
+        connection: &mut Connection<Service>,
+        offset: PointerOffset,
+    ) -> Result<usize, SubscriberReceiveError> {
+        match connection.chunk_address(&self.publisher_connections, offset) {
+            Ok(address) => Ok(address),
+            Err(e) => {
+                // the sample cannot be accessed, it is returned so that the publisher can reuse it
+                if let Err(release_error) = connection.receiver.release(offset) {
+                    warn!(from self, "Unable to return the inaccessible sample {:?} to publisher {:?} ({:?}).",
+                        offset, connection.publisher_id, release_error);
+                }
+                fail!(from self, with SubscriberReceiveError::ConnectionFailure(e),
+                    "Unable to receive the sample {:?} of publisher {:?} since its data segment is not accessible.",
+                    offset, connection.publisher_id);
+            }
+        }
+    }
+
+    fn sample_details(
+        &self,
+        channel_id: usize,
+        connection: &mut Connection<Service>,
+        offset: PointerOffset,
+    ) -> Result<(SampleDetails<Service>, usize), SubscriberReceiveError> {
+        let absolute_address = self.chunk_address(connection, offset)?;
+
+        let details = SampleDetails {
+            publisher_connections: Arc::clone(&self.publisher_connections),
+            channel_id,
+            offset,
+            origin: connection.publisher_id,
+        };
+
+        Ok((details, absolute_address))
+    }
+
+    fn is_accepted(&self, absolute_address: usize) -> bool {
+        match &self.filter {
+            None => true,
+            Some(filter) => {
+                let header = absolute_address as *const Header;
+                filter.accepts(unsafe { &*header }, self.payload_ptr(header))
+            }
+        }
+    }
+
+    fn call_receive_hook(&self, absolute_address: usize) {
+        if let Some(hook) = &self.receive_hook {
+            hook.call(unsafe { &*(absolute_address as *const Header) });
+        }
+    }
+
+    fn release_filtered_sample(&self, connection: &Connection<Service>, offset: PointerOffset) {
+        if let Err(e) = connection.receiver.release(offset) {
+            warn!(from self, "Unable to return the filtered sample {:?} to publisher {:?} ({:?}).",
+                offset, connection.publisher_id, e);
+        }
+    }
+
     fn receive_from_connection(
         &self,
         channel_id: usize,
         connection: &mut Connection<Service>,
     ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
-        let msg = "Unable to receive another sample";
-        match connection.receiver.receive() {
-            Ok(data) => match data {
-                None => Ok(None),
-                Some(offset) => {
-                    let absolute_address =
-                        offset.value() + connection.data_segment.payload_start_address();
-
-                    let details = SampleDetails {
-                        publisher_connections: Arc::clone(&self.publisher_connections),
-                        channel_id,
-                        offset,
-                        origin: connection.publisher_id,
-                    };
+        while let Some(offset) = self.receive_offset(connection)? {
+            let (details, absolute_address) =
+                self.sample_details(channel_id, connection, offset)?;
+            if self.is_accepted(absolute_address) {
+                self.call_receive_hook(absolute_address);
+                return Ok(Some((details, absolute_address)));
+            }
 
-                    Ok(Some((details, absolute_address)))
+            self.release_filtered_sample(connection, offset);
+        }
+
+        Ok(None)
+    }
+
+    fn receive_history_impl(
+        &self,
+    ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
+        if let Err(e) = self.update_connections() {
+            fail!(from self,
+                with SubscriberReceiveError::ConnectionFailure(e),
+                "Some history samples are not being received since not all connections to publishers could be established.");
+        }
+
+        for id in 0..self.publisher_connections.len() {
+            if let Some(Some(ref mut connection)) = self.publisher_connections.get_mut(id) {
+                // the history is delivered before any other sample, a pending sample marks
+                // the end of the history of this connection
+                if connection.pending_sample.is_some() {
+                    continue;
+                }
+
+                while let Some(offset) = self.receive_offset(connection)? {
+                    let absolute_address = self.chunk_address(connection, offset)?;
+                    let header = absolute_address as *const Header;
+                    if unsafe { (*header).sequence_number() } >= self.history_sequence_number {
+                        connection.pending_sample = Some(offset);
+                        break;
+                    }
+
+                    if self.is_accepted(absolute_address) {
+                        self.call_receive_hook(absolute_address);
+                        return Ok(Some(self.sample_details(id, connection, offset)?));
+                    }
+
+                    self.release_filtered_sample(connection, offset);
                 }
-            },
-            Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
-                fail!(from self, with SubscriberReceiveError::ExceedsMaxBorrowedSamples,
-                    "{} since it would exceed the maximum {} of borrowed samples.",
-                    msg, connection.receiver.max_borrowed_samples());
             }
         }
+
+        Ok(None)
     }
 
     /// Returns the [`UniqueSubscriberId`] of the [`Subscriber`]
@@ -281,11 +510,105 @@ impl<Service: service::Service, PayloadType: Debug + ?Sized> Subscriber<Service,
         self.publisher_connections.subscriber_id()
     }
 
+    /// Returns the [`ConnectedPort`]s of all [`crate::port::publisher::Publisher`]s that are
+    /// currently connected to the service, including the name of the
+    /// [`crate::node::Node`] they belong to.
+    pub fn connected_publishers(&self) -> Vec<ConnectedPort<UniquePublisherId>> {
+        let config = self.shared_node.config();
+        self.dynamic_storage
+            .get()
+            .publish_subscribe()
+            .publisher_ids()
+            .into_iter()
+            .map(|(port_id, node_id)| ConnectedPort::new::<Service>(config, port_id, node_id))
+            .collect()
+    }
+
+    /// Returns true when the [`Subscriber`] is an observer, see
+    /// [`crate::service::port_factory::publish_subscribe::PortFactory::observer_builder()`].
+    pub fn is_observer(&self) -> bool {
+        self.is_observer
+    }
+
     /// Returns the internal buffer size of the [`Subscriber`].
     pub fn buffer_size(&self) -> usize {
         self.publisher_connections.buffer_size
     }
 
+    /// Returns how many [`crate::sample::Sample`]s the [`crate::port::publisher::Publisher`]s
+    /// dropped for this [`Subscriber`] since it was created, either since its buffer was full or
+    /// since the oldest [`crate::sample::Sample`] was replaced, see
+    /// [`crate::service::port_factory::subscriber::OverflowStrategy`]. Observers are not
+    /// tracked and always return 0.
+    pub fn number_of_dropped_samples(&self) -> u64 {
+        match (self.is_observer, self.dynamic_subscriber_handle) {
+            (false, Some(handle)) => self
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .number_of_dropped_samples(handle.index()),
+            _ => 0,
+        }
+    }
+
+    /// Returns the deadline of the service, see
+    /// [`crate::service::static_config::publish_subscribe::StaticConfig::deadline()`].
+    pub fn deadline(&self) -> Option<Duration> {
+        self.publisher_connections.static_config.deadline
+    }
+
+    /// Returns true when the service has a deadline and no
+    /// [`crate::port::publisher::Publisher`] sent a [`crate::sample::Sample`] within it,
+    /// otherwise false. Safety-critical consumers use it to detect silent producers.
+    pub fn has_missed_deadline(&self) -> bool {
+        match self.deadline() {
+            Some(deadline) => {
+                self.dynamic_storage
+                    .get()
+                    .publish_subscribe()
+                    .last_send()
+                    .elapsed()
+                    > deadline
+            }
+            None => false,
+        }
+    }
+
+    /// Returns true when at least one [`crate::sample::Sample`] can be received, otherwise
+    /// false. Allows poll-style consumers to decide whether to process a cycle without
+    /// receiving speculatively.
+    pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
+        fail!(from self, when self.update_connections(),
+            "Some samples are not being received since not all connections to publishers could be established.");
+
+        for id in 0..self.publisher_connections.len() {
+            if let Some(ref connection) = &self.publisher_connections.get(id) {
+                if connection.pending_sample.is_some() || connection.receiver.has_data() {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Returns the number of [`crate::sample::Sample`]s of all connected
+    /// [`crate::port::publisher::Publisher`]s that are ready to be received.
+    pub fn number_of_pending_samples(&self) -> Result<usize, ConnectionFailure> {
+        fail!(from self, when self.update_connections(),
+            "Some samples are not being received since not all connections to publishers could be established.");
+
+        let mut number_of_pending_samples = 0;
+        for id in 0..self.publisher_connections.len() {
+            if let Some(ref connection) = &self.publisher_connections.get(id) {
+                number_of_pending_samples += connection.receiver.number_of_pending_samples()
+                    + connection.pending_sample.is_some() as usize;
+            }
+        }
+
+        Ok(number_of_pending_samples)
+    }
+
     fn receive_impl(
         &self,
     ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
@@ -295,33 +618,302 @@ impl<Service: service::Service, PayloadType: Debug + ?Sized> Subscriber<Service,
                 "Some samples are not being received since not all connections to publishers could be established.");
         }
 
+        if self.max_samples_per_publisher.is_none() {
+            match self.publisher_connections.static_config.receive_order {
+                ReceiveOrder::RoundRobin => (),
+                ReceiveOrder::SendOrder => return self.receive_in_send_order(None),
+                ReceiveOrder::TotalOrder(hold_back) => {
+                    return self.receive_in_send_order(Some(hold_back))
+                }
+            }
+        }
+
+        // without a limit every publisher provides one sample per turn
+        let max_samples_per_publisher = self.max_samples_per_publisher.unwrap_or(1);
+        let number_of_connections = self.publisher_connections.len();
+        let (start, received_in_a_row) = match self.receive_cursor.get() {
+            (current, received_in_a_row) if received_in_a_row >= max_samples_per_publisher => {
+                ((current + 1) % number_of_connections.max(1), 0)
+            }
+            cursor => cursor,
+        };
+
+        for n in 0..number_of_connections {
+            let id = (start + n) % number_of_connections;
+            if let Some(Some(ref mut connection)) = self.publisher_connections.get_mut(id) {
+                if let Some((details, absolute_address)) =
+                    self.receive_from_connection(id, connection)?
+                {
+                    let received_in_a_row = match n {
+                        0 => received_in_a_row + 1,
+                        _ => 1,
+                    };
+                    self.receive_cursor.set((id, received_in_a_row));
+                    return Ok(Some((details, absolute_address)));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    // receives all pending samples and keeps only the latest one of every key, a superseded
+    // sample is released as soon as its successor was received. When a connection exceeds the
+    // max borrowed samples the remaining samples stay pending for the next call
+    fn receive_latest_per_key_impl<T, F: Fn(SampleDetails<Service>, usize) -> T>(
+        &self,
+        create_sample: F,
+    ) -> Result<BTreeMap<u64, T>, SubscriberReceiveError> {
+        let mut latest_per_key = BTreeMap::new();
+        loop {
+            match self.receive_impl() {
+                Ok(Some((details, absolute_address))) => {
+                    let key = unsafe { (*(absolute_address as *const Header)).key() };
+                    latest_per_key.insert(key, create_sample(details, absolute_address));
+                }
+                Ok(None) => return Ok(latest_per_key),
+                Err(SubscriberReceiveError::ExceedsMaxBorrowedSamples)
+                    if !latest_per_key.is_empty() =>
+                {
+                    return Ok(latest_per_key)
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    // peeks at the oldest acceptable sample of every connection and receives the one that was
+    // sent first, the other peeked samples stay pending in their connection. With a hold back
+    // duration the oldest sample stays pending as well as long as a sample before it is missing
+    fn receive_in_send_order(
+        &self,
+        hold_back: Option<Duration>,
+    ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
+        let mut oldest: Option<(usize, u64)> = None;
         for id in 0..self.publisher_connections.len() {
-            match &mut self.publisher_connections.get_mut(id) {
-                Some(ref mut connection) => {
-                    if let Some((details, absolute_address)) =
-                        self.receive_from_connection(id, connection)?
+            if let Some(Some(ref mut connection)) = self.publisher_connections.get_mut(id) {
+                while let Some(offset) = self.receive_offset(connection)? {
+                    let absolute_address = self.chunk_address(connection, offset)?;
+                    if self.is_accepted(absolute_address) {
+                        connection.pending_sample = Some(offset);
+                        let sequence_number =
+                            unsafe { (*(absolute_address as *const Header)).sequence_number() };
+                        let is_older = match oldest {
+                            None => true,
+                            Some((_, oldest)) => sequence_number < oldest,
+                        };
+                        if is_older {
+                            oldest = Some((id, sequence_number));
+                        }
+                        break;
+                    }
+
+                    // a filtered sample is not missing, the samples after it are not held back
+                    let sequence_number =
+                        unsafe { (*(absolute_address as *const Header)).sequence_number() };
+                    if hold_back.is_some()
+                        && sequence_number == self.next_sequence_number_in_order.get()
                     {
-                        return Ok(Some((details, absolute_address)));
+                        self.next_sequence_number_in_order.set(sequence_number + 1);
                     }
+                    self.release_filtered_sample(connection, offset);
                 }
-                None => (),
             }
         }
 
-        Ok(None)
+        let id = match oldest {
+            Some((id, sequence_number)) => {
+                if let Some(hold_back) = hold_back {
+                    if !self.is_next_in_total_order(sequence_number, hold_back) {
+                        return Ok(None);
+                    }
+                }
+                id
+            }
+            None => return Ok(None),
+        };
+
+        match self.publisher_connections.get_mut(id) {
+            Some(Some(ref mut connection)) => match connection.pending_sample.take() {
+                Some(offset) => {
+                    let (details, absolute_address) =
+                        self.sample_details(id, connection, offset)?;
+                    self.call_receive_hook(absolute_address);
+                    Ok(Some((details, absolute_address)))
+                }
+                None => Ok(None),
+            },
+            _ => Ok(None),
+        }
+    }
+
+    // a sample is next when no sample before it is missing or when it was held back for the
+    // whole hold back duration
+    fn is_next_in_total_order(&self, sequence_number: u64, hold_back: Duration) -> bool {
+        if sequence_number > self.next_sequence_number_in_order.get() {
+            let now = Instant::now();
+            match self.held_back_since.get() {
+                None => {
+                    self.held_back_since.set(Some(now));
+                    if !hold_back.is_zero() {
+                        return false;
+                    }
+                }
+                Some(since) if now.saturating_duration_since(since) < hold_back => return false,
+                Some(_) => (),
+            }
+        }
+
+        self.held_back_since.set(None);
+        self.next_sequence_number_in_order.set(
+            self.next_sequence_number_in_order
+                .get()
+                .max(sequence_number + 1),
+        );
+        true
+    }
+
+    // the remaining time until a held back sample is received even though a sample before it
+    // is still missing
+    pub(crate) fn remaining_hold_back(&self) -> Option<Duration> {
+        match (
+            self.publisher_connections.static_config.receive_order,
+            self.held_back_since.get(),
+        ) {
+            (ReceiveOrder::TotalOrder(hold_back), Some(since)) => {
+                Some(hold_back.saturating_sub(since.elapsed()))
+            }
+            _ => None,
+        }
+    }
+
+    fn blocking_receive_impl(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<(SampleDetails<Service>, usize)>, SubscriberReceiveError> {
+        let deadline = Instant::now() + timeout;
+        let listener = self.sample_arrival_listener()?;
+
+        loop {
+            if let Some(v) = self.receive_impl()? {
+                return Ok(Some(v));
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+
+            // a held back sample becomes receivable without the arrival of a new sample
+            let remaining = match self.remaining_hold_back() {
+                Some(hold_back) => remaining.min(hold_back),
+                None => remaining,
+            };
+
+            if let Err(e) = listener.timed_wait(remaining) {
+                fail!(from self, with SubscriberReceiveError::UnableToWaitForSamples,
+                    "Unable to wait for samples since the sample arrival listener failed ({:?}).", e);
+            }
+        }
+    }
+
+    #[cfg(feature = "async")]
+    fn consume_sample_arrivals(
+        &self,
+    ) -> Result<&SampleArrivalListener<Service>, SubscriberReceiveError> {
+        let listener = self.sample_arrival_listener()?;
+        if let Err(e) = listener.consume_notifications() {
+            fail!(from self, with SubscriberReceiveError::UnableToWaitForSamples,
+                "Unable to wait for samples since the notifications of the sample arrival listener could not be consumed ({:?}).", e);
+        }
+        Ok(listener)
+    }
+
+    pub(crate) fn sample_arrival_listener(
+        &self,
+    ) -> Result<&SampleArrivalListener<Service>, SubscriberReceiveError> {
+        if let Some(listener) = self.sample_arrival.get() {
+            return Ok(listener);
+        }
+
+        let listener = fail!(from self,
+            when SampleArrivalListener::new(&self.shared_node, &self.static_config),
+            with SubscriberReceiveError::UnableToWaitForSamples,
+            "Unable to wait for samples since the sample arrival listener could not be created.");
+
+        // the listener must exist before the publishers see the registration, otherwise a
+        // notification could get lost
+        let listener = self.sample_arrival.get_or_init(|| listener);
+        self.dynamic_storage
+            .get()
+            .publish_subscribe()
+            .add_sample_arrival_listener();
+
+        Ok(listener)
+    }
+
+    /// Receives a [`crate::sample::Sample`], copies its payload into the provided buffer and
+    /// releases the [`crate::sample::Sample`] immediately. On success the number of copied
+    /// bytes is returned. If no sample could be received [`None`] is returned. When the buffer
+    /// is smaller than the payload, the [`crate::sample::Sample`] is discarded and
+    /// [`SubscriberReceiveError::InsufficientBufferSize`] is returned.
+    pub fn receive_into(&self, buffer: &mut [u8]) -> Result<Option<usize>, SubscriberReceiveError> {
+        let (details, absolute_address) = match self.receive_impl()? {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+
+        let header_ptr = absolute_address as *const Header;
+        let user_header_ptr = self.user_header_ptr(header_ptr).cast();
+        let payload_ptr = self.payload_ptr(header_ptr);
+        let payload_size = unsafe { (*header_ptr).payload_type_layout() }.size();
+
+        let sample = Sample::<[u8], Service, UserHeader> {
+            details,
+            ptr: unsafe {
+                RawSample::<Header, UserHeader, [u8]>::new_slice_unchecked(
+                    header_ptr,
+                    user_header_ptr,
+                    core::ptr::slice_from_raw_parts(payload_ptr, payload_size),
+                )
+            },
+        };
+
+        let buffer_size = buffer.len();
+        match buffer.get_mut(..payload_size) {
+            Some(target) => {
+                target.copy_from_slice(sample.payload());
+                Ok(Some(payload_size))
+            }
+            None => {
+                fail!(from self, with SubscriberReceiveError::InsufficientBufferSize,
+                    "Unable to receive sample into buffer since the buffer size of {} bytes is smaller than the payload size of {} bytes. The sample is discarded.",
+                    buffer_size, payload_size);
+            }
+        }
+    }
+
+    fn user_header_ptr(&self, header: *const Header) -> *const u8 {
+        self.publisher_connections
+            .static_config
+            .type_details
+            .user_header_ptr_from_header(header.cast())
+            .cast()
     }
 
     fn payload_ptr(&self, header: *const Header) -> *const u8 {
         self.publisher_connections
             .static_config
             .type_details
-            .payload_ptr_from_header(header.cast())
+            .payload_ptr_from_header_with_alignment(header.cast(), unsafe {
+                (*header).payload_type_layout().align()
+            })
             .cast()
     }
 }
 
-impl<Service: service::Service, PayloadType: Debug + ?Sized> UpdateConnections
-    for Subscriber<Service, PayloadType>
+impl<Service: service::Service, PayloadType: Debug + ?Sized, UserHeader: Debug> UpdateConnections
+    for Subscriber<Service, PayloadType, UserHeader>
 {
     fn update_connections(&self) -> Result<(), ConnectionFailure> {
         if unsafe {
@@ -339,43 +931,478 @@ impl<Service: service::Service, PayloadType: Debug + ?Sized> UpdateConnections
     }
 }
 
-impl<Service: service::Service, PayloadType: Debug> Subscriber<Service, PayloadType> {
+impl<Service: service::Service, PayloadType: Debug, UserHeader: Debug>
+    Subscriber<Service, PayloadType, UserHeader>
+{
     /// Receives a [`crate::sample::Sample`] from [`crate::port::publisher::Publisher`]. If no sample could be
     /// received [`None`] is returned. If a failure occurs [`SubscriberReceiveError`] is returned.
-    pub fn receive(&self) -> Result<Option<Sample<PayloadType, Service>>, SubscriberReceiveError> {
-        Ok(self.receive_impl()?.map(|(details, absolute_address)| {
-            let header_ptr = absolute_address as *const Header;
-            let payload_ptr = self.payload_ptr(header_ptr).cast();
-            Sample {
-                details,
-                ptr: unsafe { RawSample::new_unchecked(header_ptr, payload_ptr) },
-            }
-        }))
+    pub fn receive(
+        &self,
+    ) -> Result<Option<Sample<PayloadType, Service, UserHeader>>, SubscriberReceiveError> {
+        Ok(self
+            .receive_impl()?
+            .map(|(details, absolute_address)| self.create_sample(details, absolute_address)))
+    }
+
+    /// Blocks until a [`crate::sample::Sample`] from a [`crate::port::publisher::Publisher`]
+    /// could be received or the timeout has passed. If no sample was received in time [`None`]
+    /// is returned. If a failure occurs [`SubscriberReceiveError`] is returned.
+    ///
+    /// The first call couples the [`Subscriber`] to an internal event service of the
+    /// publish-subscribe service, from then on every [`crate::port::publisher::Publisher`]
+    /// wakes it up after it has sent samples.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use core::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// if let Some(sample) = subscriber.blocking_receive(Duration::from_millis(10))? {
+    ///     println!("received: {:?}", *sample);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn blocking_receive(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Sample<PayloadType, Service, UserHeader>>, SubscriberReceiveError> {
+        Ok(self
+            .blocking_receive_impl(timeout)?
+            .map(|(details, absolute_address)| self.create_sample(details, absolute_address)))
+    }
+
+    /// Receives a [`crate::sample::Sample`] of the history. The history consists of the samples
+    /// that were sent before the [`Subscriber`] was created, the
+    /// [`crate::port::publisher::Publisher`] delivers up to
+    /// [`crate::service::builder::publish_subscribe::Builder::history_size()`] of them when it
+    /// updates its connections. If no further history sample is available [`None`] is returned,
+    /// samples that were sent later remain available via [`Subscriber::receive()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::port::update_connections::UpdateConnections;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .history_size(2)
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = service.publisher_builder().create()?;
+    /// publisher.send_copy(1)?;
+    /// publisher.send_copy(2)?;
+    ///
+    /// // a late joining subscriber
+    /// let subscriber = service.subscriber_builder().create()?;
+    /// publisher.update_connections()?;
+    ///
+    /// while let Some(sample) = subscriber.receive_history()? {
+    ///     println!("history: {}", *sample);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn receive_history(
+        &self,
+    ) -> Result<Option<Sample<PayloadType, Service, UserHeader>>, SubscriberReceiveError> {
+        Ok(self
+            .receive_history_impl()?
+            .map(|(details, absolute_address)| self.create_sample(details, absolute_address)))
+    }
+
+    /// Receives all pending [`crate::sample::Sample`]s and returns only the latest
+    /// [`crate::sample::Sample`] of every key, see
+    /// [`SampleMut::set_key()`](crate::sample_mut::SampleMut::set_key()), so that one service
+    /// can carry the state of many entities. The [`crate::sample::Sample`]s are returned in a
+    /// map ordered by their key, superseded [`crate::sample::Sample`]s are released without
+    /// being handed to the user. Every returned [`crate::sample::Sample`] is borrowed, when a
+    /// [`crate::port::publisher::Publisher`] would exceed the max borrowed samples the remaining
+    /// [`crate::sample::Sample`]s stay pending for the next call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// let latest_per_key = subscriber.receive_latest_per_key()?;
+    /// if let Some(sample) = latest_per_key.get(&42) {
+    ///     println!("latest state of entity 42: {}", *sample);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn receive_latest_per_key(
+        &self,
+    ) -> Result<BTreeMap<u64, Sample<PayloadType, Service, UserHeader>>, SubscriberReceiveError>
+    {
+        self.receive_latest_per_key_impl(|details, absolute_address| {
+            self.create_sample(details, absolute_address)
+        })
+    }
+
+    fn create_sample(
+        &self,
+        details: SampleDetails<Service>,
+        absolute_address: usize,
+    ) -> Sample<PayloadType, Service, UserHeader> {
+        let header_ptr = absolute_address as *const Header;
+        let user_header_ptr = self.user_header_ptr(header_ptr).cast();
+        let payload_ptr = self.payload_ptr(header_ptr).cast();
+        Sample {
+            details,
+            ptr: unsafe { RawSample::new_unchecked(header_ptr, user_header_ptr, payload_ptr) },
+        }
+    }
+
+    /// Returns a [`Future`](core::future::Future) that resolves to the next received
+    /// [`crate::sample::Sample`] or to a [`SubscriberReceiveError`]. The future does not depend
+    /// on a specific executor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::port::subscriber::Subscriber;
+    ///
+    /// async fn print_next_sample(
+    ///     subscriber: &Subscriber<zero_copy::Service, u64>,
+    /// ) -> Result<(), Box<dyn std::error::Error>> {
+    ///     let sample = subscriber.receive_async().await?;
+    ///     println!("received: {:?}", *sample);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn receive_async(&self) -> SubscriberReceiveFuture<'_, Service, PayloadType, UserHeader> {
+        SubscriberReceiveFuture {
+            subscriber: self,
+            registration: AsyncRegistration::default(),
+        }
     }
 }
 
-impl<Service: service::Service, PayloadType: Debug> Subscriber<Service, [PayloadType]> {
+impl<Service: service::Service, PayloadType: Debug + Copy, UserHeader: Debug>
+    Subscriber<Service, PayloadType, UserHeader>
+{
+    /// Receives a [`crate::sample::Sample`], returns a copy of its payload and releases the
+    /// [`crate::sample::Sample`] immediately. If no sample could be received [`None`] is returned.
+    pub fn receive_copy(&self) -> Result<Option<PayloadType>, SubscriberReceiveError> {
+        Ok(self.receive()?.map(|sample| *sample))
+    }
+}
+
+impl<Service: service::Service, PayloadType: Debug, UserHeader: Debug>
+    Subscriber<Service, [PayloadType], UserHeader>
+{
     /// Receives a [`crate::sample::Sample`] from [`crate::port::publisher::Publisher`]. If no sample could be
     /// received [`None`] is returned. If a failure occurs [`SubscriberReceiveError`] is returned.
     pub fn receive(
         &self,
-    ) -> Result<Option<Sample<[PayloadType], Service>>, SubscriberReceiveError> {
-        Ok(self.receive_impl()?.map(|(details, absolute_address)| {
-            let header_ptr = absolute_address as *const Header;
-            let payload_ptr = self.payload_ptr(header_ptr).cast();
-
-            let payload_layout = unsafe { (*header_ptr).payload_type_layout() };
-            let number_of_elements = payload_layout.size() / core::mem::size_of::<PayloadType>();
-
-            Sample {
-                details,
-                ptr: unsafe {
-                    RawSample::<Header, [PayloadType]>::new_slice_unchecked(
-                        header_ptr,
-                        core::slice::from_raw_parts(payload_ptr, number_of_elements),
-                    )
-                },
+    ) -> Result<Option<Sample<[PayloadType], Service, UserHeader>>, SubscriberReceiveError> {
+        Ok(self
+            .receive_impl()?
+            .map(|(details, absolute_address)| self.create_sample(details, absolute_address)))
+    }
+
+    /// Blocks until a [`crate::sample::Sample`] from a [`crate::port::publisher::Publisher`]
+    /// could be received or the timeout has passed. If no sample was received in time [`None`]
+    /// is returned. If a failure occurs [`SubscriberReceiveError`] is returned.
+    ///
+    /// The first call couples the [`Subscriber`] to an internal event service of the
+    /// publish-subscribe service, from then on every [`crate::port::publisher::Publisher`]
+    /// wakes it up after it has sent samples.
+    pub fn blocking_receive(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<Sample<[PayloadType], Service, UserHeader>>, SubscriberReceiveError> {
+        Ok(self
+            .blocking_receive_impl(timeout)?
+            .map(|(details, absolute_address)| self.create_sample(details, absolute_address)))
+    }
+
+    /// Receives a [`crate::sample::Sample`] of the history. The history consists of the samples
+    /// that were sent before the [`Subscriber`] was created, the
+    /// [`crate::port::publisher::Publisher`] delivers up to
+    /// [`crate::service::builder::publish_subscribe::Builder::history_size()`] of them when it
+    /// updates its connections. If no further history sample is available [`None`] is returned,
+    /// samples that were sent later remain available via [`Subscriber::receive()`].
+    pub fn receive_history(
+        &self,
+    ) -> Result<Option<Sample<[PayloadType], Service, UserHeader>>, SubscriberReceiveError> {
+        Ok(self
+            .receive_history_impl()?
+            .map(|(details, absolute_address)| self.create_sample(details, absolute_address)))
+    }
+
+    /// Receives all pending [`crate::sample::Sample`]s and returns only the latest
+    /// [`crate::sample::Sample`] of every key, see
+    /// [`SampleMut::set_key()`](crate::sample_mut::SampleMut::set_key()). Superseded
+    /// [`crate::sample::Sample`]s are released without being handed to the user. When a
+    /// [`crate::port::publisher::Publisher`] would exceed the max borrowed samples the
+    /// remaining [`crate::sample::Sample`]s stay pending for the next call.
+    pub fn receive_latest_per_key(
+        &self,
+    ) -> Result<BTreeMap<u64, Sample<[PayloadType], Service, UserHeader>>, SubscriberReceiveError>
+    {
+        self.receive_latest_per_key_impl(|details, absolute_address| {
+            self.create_sample(details, absolute_address)
+        })
+    }
+
+    fn create_sample(
+        &self,
+        details: SampleDetails<Service>,
+        absolute_address: usize,
+    ) -> Sample<[PayloadType], Service, UserHeader> {
+        let header_ptr = absolute_address as *const Header;
+        let user_header_ptr = self.user_header_ptr(header_ptr).cast();
+        let payload_ptr = self.payload_ptr(header_ptr).cast();
+
+        let payload_layout = unsafe { (*header_ptr).payload_type_layout() };
+        let number_of_elements = payload_layout.size() / core::mem::size_of::<PayloadType>();
+
+        Sample {
+            details,
+            ptr: unsafe {
+                RawSample::<Header, UserHeader, [PayloadType]>::new_slice_unchecked(
+                    header_ptr,
+                    user_header_ptr,
+                    core::slice::from_raw_parts(payload_ptr, number_of_elements),
+                )
+            },
+        }
+    }
+
+    /// Returns a [`Future`](core::future::Future) that resolves to the next received
+    /// [`crate::sample::Sample`] or to a [`SubscriberReceiveError`]. The future does not depend
+    /// on a specific executor.
+    #[cfg(feature = "async")]
+    pub fn receive_async(&self) -> SubscriberReceiveFuture<'_, Service, [PayloadType], UserHeader> {
+        SubscriberReceiveFuture {
+            subscriber: self,
+            registration: AsyncRegistration::default(),
+        }
+    }
+}
+
+#[cfg(not(feature = "safety"))]
+impl<Service: service::Service, PayloadType: Debug + Copy, UserHeader: Debug>
+    Subscriber<Service, [PayloadType], UserHeader>
+{
+    /// Receives the next complete message. Messages that were split into fragments with
+    /// [`Publisher::send_slice_copy_fragmented()`](crate::port::publisher::Publisher::send_slice_copy_fragmented())
+    /// are reassembled, all other [`crate::sample::Sample`]s are returned as they are. The
+    /// payload is copied so that the fragments are released immediately. A message is
+    /// discarded when one of its fragments was not received, for instance since it was dropped
+    /// due to a full buffer. If no complete message could be received [`None`] is returned,
+    /// the fragments that were received so far are kept until the message is complete.
+    ///
+    /// Since the reassembly allocates heap memory, it is not available with the `safety`
+    /// feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<[u8]>()
+    ///     .open_or_create()?;
+    ///
+    /// let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// while let Some(message) = subscriber.receive_reassembled()? {
+    ///     println!("received message with {} bytes", message.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn receive_reassembled(&self) -> Result<Option<Vec<PayloadType>>, SubscriberReceiveError> {
+        while let Some(sample) = self.receive()? {
+            let header = sample.header();
+            if !header.is_fragment() {
+                return Ok(Some(sample.payload().to_vec()));
             }
-        }))
+
+            let element_size = core::mem::size_of::<PayloadType>();
+            let mut pending_messages = self.pending_messages.borrow_mut();
+            let index = pending_messages
+                .iter()
+                .position(|m| m.publisher_id == header.publisher_id());
+
+            // the first fragment starts a new message, an incomplete previous message of the
+            // same publisher lost a fragment
+            let index = match index {
+                Some(index) if header.fragment_offset() == 0 => {
+                    if let Some(message) = pending_messages.get_mut(index) {
+                        *message = Self::pending_message(header, element_size);
+                    }
+                    index
+                }
+                None if header.fragment_offset() == 0 => {
+                    pending_messages.push(Self::pending_message(header, element_size));
+                    pending_messages.len() - 1
+                }
+                Some(index) => {
+                    let is_next_fragment = pending_messages.get(index).is_some_and(|m| {
+                        m.next_publisher_sequence_number == header.publisher_sequence_number()
+                            && m.payload.len() == header.fragment_offset() as usize * element_size
+                    });
+                    if !is_next_fragment {
+                        pending_messages.remove(index);
+                        continue;
+                    }
+                    index
+                }
+                None => continue,
+            };
+
+            let message = match pending_messages.get_mut(index) {
+                Some(message) => message,
+                None => continue,
+            };
+            // the payload is copied untyped since PayloadType may contain padding bytes
+            let fragment_size = core::mem::size_of_val(sample.payload());
+            message.payload.reserve(fragment_size);
+            unsafe {
+                core::ptr::copy_nonoverlapping(
+                    sample.payload().as_ptr().cast::<u8>(),
+                    message.payload.as_mut_ptr().add(message.payload.len()),
+                    fragment_size,
+                );
+                message
+                    .payload
+                    .set_len(message.payload.len() + fragment_size);
+            }
+            message.next_publisher_sequence_number = header.publisher_sequence_number() + 1;
+
+            if message.payload.len() >= header.fragmented_message_len() as usize * element_size {
+                let message = pending_messages.remove(index);
+                let number_of_elements = header.fragmented_message_len() as usize;
+                let mut payload = Vec::<PayloadType>::with_capacity(number_of_elements);
+                unsafe {
+                    core::ptr::copy_nonoverlapping(
+                        message.payload.as_ptr(),
+                        payload.as_mut_ptr().cast::<u8>(),
+                        number_of_elements * element_size,
+                    );
+                    payload.set_len(number_of_elements);
+                }
+                return Ok(Some(payload));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn pending_message(header: &Header, element_size: usize) -> PendingMessage {
+        PendingMessage {
+            publisher_id: header.publisher_id(),
+            next_publisher_sequence_number: header.publisher_sequence_number(),
+            payload: Vec::with_capacity(header.fragmented_message_len() as usize * element_size),
+        }
+    }
+}
+
+/// The [`Future`](core::future::Future) returned by [`Subscriber::receive_async()`]. It
+/// resolves as soon as a [`crate::sample::Sample`] can be received.
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub struct SubscriberReceiveFuture<
+    'subscriber,
+    Service: service::Service,
+    PayloadType: Debug + ?Sized,
+    UserHeader: Debug = (),
+> {
+    subscriber: &'subscriber Subscriber<Service, PayloadType, UserHeader>,
+    registration: AsyncRegistration,
+}
+
+#[cfg(feature = "async")]
+impl<Service: service::Service, PayloadType: Debug, UserHeader: Debug> core::future::Future
+    for SubscriberReceiveFuture<'_, Service, PayloadType, UserHeader>
+{
+    type Output = Result<Sample<PayloadType, Service, UserHeader>, SubscriberReceiveError>;
+
+    fn poll(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Self::Output> {
+        let this = self.get_mut();
+        // the notifications are consumed before the receive, otherwise a sample that arrives
+        // in between would not wake up the task
+        let listener = match this.subscriber.consume_sample_arrivals() {
+            Ok(listener) => listener,
+            Err(e) => return core::task::Poll::Ready(Err(e)),
+        };
+
+        match this.subscriber.receive() {
+            Ok(Some(sample)) => core::task::Poll::Ready(Ok(sample)),
+            Ok(None) => {
+                this.registration.wake_when_readable(
+                    listener.as_multiplexable(),
+                    this.subscriber.remaining_hold_back(),
+                    cx.waker(),
+                );
+                core::task::Poll::Pending
+            }
+            Err(e) => core::task::Poll::Ready(Err(e)),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<Service: service::Service, PayloadType: Debug, UserHeader: Debug> core::future::Future
+    for SubscriberReceiveFuture<'_, Service, [PayloadType], UserHeader>
+{
+    type Output = Result<Sample<[PayloadType], Service, UserHeader>, SubscriberReceiveError>;
+
+    fn poll(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut core::task::Context<'_>,
+    ) -> core::task::Poll<Self::Output> {
+        let this = self.get_mut();
+        // the notifications are consumed before the receive, otherwise a sample that arrives
+        // in between would not wake up the task
+        let listener = match this.subscriber.consume_sample_arrivals() {
+            Ok(listener) => listener,
+            Err(e) => return core::task::Poll::Ready(Err(e)),
+        };
+
+        match this.subscriber.receive() {
+            Ok(Some(sample)) => core::task::Poll::Ready(Ok(sample)),
+            Ok(None) => {
+                this.registration.wake_when_readable(
+                    listener.as_multiplexable(),
+                    this.subscriber.remaining_hold_back(),
+                    cx.waker(),
+                );
+                core::task::Poll::Pending
+            }
+            Err(e) => core::task::Poll::Ready(Err(e)),
+        }
     }
 }
@@ -0,0 +1,421 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::port::waitset::WaitSet;
+//! use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let event_1 = node.service_builder("MyEventName1".try_into()?)
+//!     .event()
+//!     .open_or_create()?;
+//! let event_2 = node.service_builder("MyEventName2".try_into()?)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! let listener_1 = event_1.listener_builder().create()?;
+//! let listener_2 = event_2.listener_builder().create()?;
+//!
+//! let mut waitset = WaitSet::new();
+//! let id_1 = waitset.attach(&listener_1)?;
+//! let id_2 = waitset.attach(&listener_2)?;
+//!
+//! // waits for the events of both listeners at once
+//! waitset.timed_wait(|attachment_id, event_id| {
+//!     if attachment_id == id_1 {
+//!         println!("listener 1 received: {:?}", event_id);
+//!     } else if attachment_id == id_2 {
+//!         println!("listener 2 received: {:?}", event_id);
+//!     }
+//! }, Duration::from_millis(10))?;
+//! # Ok(())
+//! # }
+//! ```
+
+use core::cell::{Cell, RefCell};
+use core::time::Duration;
+use std::fmt::Debug;
+use std::time::Instant;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::adaptive_wait::{AdaptiveWaitBuilder, AdaptiveWaitError};
+use iceoryx2_bb_posix::clock::NanosleepError;
+use iceoryx2_bb_posix::file_descriptor_set::{
+    FileDescriptorSet, FileDescriptorSetWaitError, FileEvent,
+};
+use iceoryx2_cal::event::ListenerWaitError;
+
+use crate::port::event_id::EventId;
+use crate::port::listener::Listener;
+use crate::port::port_identifiers::UniqueNotifierId;
+use crate::service;
+
+/// The priority of an attachment that was attached with [`WaitSet::attach()`].
+pub const DEFAULT_PRIORITY: u8 = 0;
+
+/// Defines the failures that can occur when a [`Listener`] is attached to a [`WaitSet`] with
+/// [`WaitSet::attach()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetAttachmentError {
+    AlreadyAttached,
+}
+
+impl std::fmt::Display for WaitSetAttachmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "WaitSetAttachmentError::{:?}", self)
+    }
+}
+
+impl std::error::Error for WaitSetAttachmentError {}
+
+/// Defines the failures that can occur when a [`WaitSet`] waits for its attachments.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetWaitError {
+    ListenerFailure(ListenerWaitError),
+    /// The wait was interrupted by a signal before any attachment woke up.
+    InterruptSignal,
+    InternalFailure,
+}
+
+impl std::fmt::Display for WaitSetWaitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "WaitSetWaitError::{:?}", self)
+    }
+}
+
+impl std::error::Error for WaitSetWaitError {}
+
+/// Identifies an attachment of a [`WaitSet`]. It is returned by [`WaitSet::attach()`] and
+/// provided to the callback of the wait calls together with the received [`EventId`] so that
+/// the caller knows which attachment woke up.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, PartialOrd, Ord)]
+pub struct WaitSetAttachmentId(usize);
+
+impl WaitSetAttachmentId {
+    /// Returns the index of the attachment inside the [`WaitSet`].
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug)]
+struct Attachment<'attachment, Service: service::Service> {
+    listener: &'attachment Listener<Service>,
+    priority: u8,
+    // the time of the last notification for which the missed deadline was reported, so that
+    // a silent service wakes up the WaitSet only once
+    reported_missed_deadline: Cell<Option<u64>>,
+    // the notifiers whose lost liveliness was already reported, so that every notifier wakes
+    // up the WaitSet only once
+    reported_lost_liveliness: RefCell<Vec<UniqueNotifierId>>,
+}
+
+/// Multiplexes many [`Listener`]s so that a single [`WaitSet::timed_wait()`] or
+/// [`WaitSet::blocking_wait()`] call waits for all of them. Every received [`EventId`] is
+/// reported together with the [`WaitSetAttachmentId`] of the [`Listener`] that received it.
+/// The [`Listener`]s must outlive the [`WaitSet`] they are attached to.
+///
+/// When multiple attachments woke up, the [`EventId`]s of the attachments with a higher
+/// priority, see [`WaitSet::attach_with_priority()`], are reported first. Attachments with the
+/// same priority are reported in the order of their [`WaitSetAttachmentId`]s.
+///
+/// The [`WaitSet`] blocks on the file descriptors of all attached [`Listener`]s. When at least
+/// one [`Listener`] is not based on a file descriptor, like the ones of a
+/// [`crate::service::process_local::Service`], all attachments are polled instead.
+///
+/// When the service of an attached [`Listener`] has a deadline, see
+/// [`crate::service::builder::event::Builder::deadline()`], and no notification arrived
+/// within it, the [`WaitSet`] wakes up once and counts the attachment as woken up without
+/// calling the callback. [`Listener::has_missed_deadline()`] identifies the silent service.
+/// Likewise, the [`WaitSet`] wakes up once for every [`crate::port::notifier::Notifier`] that
+/// lost its liveliness, see [`Listener::notifiers_with_lost_liveliness()`].
+#[derive(Debug)]
+pub struct WaitSet<'attachment, Service: service::Service> {
+    attachments: Vec<Option<Attachment<'attachment, Service>>>,
+}
+
+impl<'attachment, Service: service::Service> Default for WaitSet<'attachment, Service> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'attachment, Service: service::Service> WaitSet<'attachment, Service> {
+    /// Creates a new [`WaitSet`] without any attachments.
+    pub fn new() -> Self {
+        Self {
+            attachments: vec![],
+        }
+    }
+
+    /// Attaches a [`Listener`] with the [`DEFAULT_PRIORITY`] to the [`WaitSet`] and returns
+    /// the [`WaitSetAttachmentId`] with which its events are reported. Fails when the
+    /// [`Listener`] is already attached.
+    pub fn attach(
+        &mut self,
+        listener: &'attachment Listener<Service>,
+    ) -> Result<WaitSetAttachmentId, WaitSetAttachmentError> {
+        self.attach_with_priority(listener, DEFAULT_PRIORITY)
+    }
+
+    /// Attaches a [`Listener`] with the provided priority to the [`WaitSet`], see
+    /// [`WaitSet::attach()`]. When multiple attachments woke up, the [`EventId`]s of the
+    /// attachments with a higher priority are reported first.
+    pub fn attach_with_priority(
+        &mut self,
+        listener: &'attachment Listener<Service>,
+        priority: u8,
+    ) -> Result<WaitSetAttachmentId, WaitSetAttachmentError> {
+        if self
+            .attachments
+            .iter()
+            .flatten()
+            .any(|attachment| attachment.listener.id() == listener.id())
+        {
+            fail!(from self, with WaitSetAttachmentError::AlreadyAttached,
+                "Unable to attach the listener {:?} since it is already attached.", listener.id());
+        }
+
+        let attachment = Attachment {
+            listener,
+            priority,
+            reported_missed_deadline: Cell::new(None),
+            reported_lost_liveliness: RefCell::new(vec![]),
+        };
+
+        let index = match self.attachments.iter().position(|a| a.is_none()) {
+            Some(index) => {
+                self.attachments[index] = Some(attachment);
+                index
+            }
+            None => {
+                self.attachments.push(Some(attachment));
+                self.attachments.len() - 1
+            }
+        };
+
+        Ok(WaitSetAttachmentId(index))
+    }
+
+    /// Detaches the attachment with the provided [`WaitSetAttachmentId`]. Returns false when
+    /// no such attachment exists, otherwise true. The [`WaitSetAttachmentId`] can be reused
+    /// by a later [`WaitSet::attach()`] call.
+    pub fn detach(&mut self, id: WaitSetAttachmentId) -> bool {
+        match self.attachments.get_mut(id.0) {
+            Some(attachment) => attachment.take().is_some(),
+            None => false,
+        }
+    }
+
+    /// Returns the number of attachments.
+    pub fn len(&self) -> usize {
+        self.attachments.iter().flatten().count()
+    }
+
+    /// Returns true when the [`WaitSet`] has no attachments, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Non-blocking wait for new [`EventId`]s on all attachments. For every received
+    /// [`EventId`] the provided callback is called with the [`WaitSetAttachmentId`] of the
+    /// attachment that received it. Returns the number of attachments that woke up.
+    pub fn try_wait<F: FnMut(WaitSetAttachmentId, EventId)>(
+        &self,
+        mut fn_call: F,
+    ) -> Result<usize, WaitSetWaitError> {
+        self.collect_events(&mut fn_call)
+    }
+
+    /// Blocking wait for new [`EventId`]s on all attachments until at least one attachment
+    /// woke up or the timeout has passed. For every received [`EventId`] the provided callback
+    /// is called with the [`WaitSetAttachmentId`] of the attachment that received it. Returns
+    /// the number of attachments that woke up, zero when the timeout has passed.
+    pub fn timed_wait<F: FnMut(WaitSetAttachmentId, EventId)>(
+        &self,
+        mut fn_call: F,
+        timeout: Duration,
+    ) -> Result<usize, WaitSetWaitError> {
+        self.wait(&mut fn_call, Some(timeout))
+    }
+
+    /// Blocks until at least one attachment woke up. For every received [`EventId`] the
+    /// provided callback is called with the [`WaitSetAttachmentId`] of the attachment that
+    /// received it. Returns the number of attachments that woke up.
+    pub fn blocking_wait<F: FnMut(WaitSetAttachmentId, EventId)>(
+        &self,
+        mut fn_call: F,
+    ) -> Result<usize, WaitSetWaitError> {
+        self.wait(&mut fn_call, None)
+    }
+
+    fn wait<F: FnMut(WaitSetAttachmentId, EventId)>(
+        &self,
+        fn_call: &mut F,
+        timeout: Option<Duration>,
+    ) -> Result<usize, WaitSetWaitError> {
+        let msg = "Unable to wait for events";
+        let start = Instant::now();
+
+        let fd_set = FileDescriptorSet::new();
+        let mut guards = Vec::with_capacity(self.attachments.len());
+        let mut are_all_multiplexable = true;
+        for attachment in self.attachments.iter().flatten() {
+            match attachment.listener.as_multiplexable() {
+                Some(listener) => guards.push(fail!(from self, when fd_set.add(listener),
+                    with WaitSetWaitError::InternalFailure,
+                    "{} since the listener {:?} could not be added to the file descriptor set.",
+                    msg, attachment.listener.id())),
+                None => are_all_multiplexable = false,
+            }
+        }
+
+        // listeners that are not based on a file descriptor, like the process local ones,
+        // cannot be multiplexed and must be polled
+        let mut adaptive_wait = match are_all_multiplexable {
+            true => None,
+            false => Some(fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                with WaitSetWaitError::InternalFailure,
+                "{} since the adaptive wait could not be created.", msg)),
+        };
+
+        loop {
+            match self.collect_events(fn_call)? {
+                0 => (),
+                n => return Ok(n),
+            }
+
+            let remaining_time = match timeout {
+                Some(timeout) => match timeout.saturating_sub(start.elapsed()) {
+                    Duration::ZERO => return Ok(0),
+                    remaining_time => Some(remaining_time),
+                },
+                None => None,
+            };
+
+            if let Some(adaptive_wait) = &mut adaptive_wait {
+                match adaptive_wait.wait() {
+                    Ok(_) => (),
+                    Err(AdaptiveWaitError::NanosleepError(
+                        NanosleepError::InterruptedBySignal(_),
+                    )) => {
+                        fail!(from self, with WaitSetWaitError::InterruptSignal,
+                            "{} since an interrupt signal was received.", msg);
+                    }
+                    Err(e) => {
+                        fail!(from self, with WaitSetWaitError::InternalFailure,
+                            "{} due to an internal failure ({:?}).", msg, e);
+                    }
+                }
+                continue;
+            }
+
+            let wake_up_time = match (remaining_time, self.time_until_next_check()) {
+                (Some(remaining_time), Some(next_check)) => Some(remaining_time.min(next_check)),
+                (remaining_time, next_check) => remaining_time.or(next_check),
+            };
+
+            let result = match wake_up_time {
+                Some(wake_up_time) => fd_set.timed_wait(wake_up_time, FileEvent::Read, |_| {}),
+                None => fd_set.blocking_wait(FileEvent::Read, |_| {}),
+            };
+
+            match result {
+                Ok(()) => (),
+                Err(FileDescriptorSetWaitError::Interrupt) => {
+                    fail!(from self, with WaitSetWaitError::InterruptSignal,
+                        "{} since an interrupt signal was received.", msg);
+                }
+                Err(e) => {
+                    fail!(from self, with WaitSetWaitError::InternalFailure,
+                        "{} due to an internal failure ({:?}).", msg, e);
+                }
+            }
+        }
+    }
+
+    // Missed deadlines and notifiers that lost their liveliness do not wake up the file
+    // descriptors of the listeners, therefore the wait must return in time to check them.
+    fn time_until_next_check(&self) -> Option<Duration> {
+        self.attachments
+            .iter()
+            .flatten()
+            .flat_map(|attachment| {
+                let listener = attachment.listener;
+                let deadline = match listener.deadline() {
+                    Some(deadline) if !listener.has_missed_deadline() => {
+                        Some(deadline.saturating_sub(listener.last_notification().elapsed()))
+                    }
+                    _ => None,
+                };
+
+                [deadline, listener.notifier_liveliness()]
+            })
+            .flatten()
+            .min()
+    }
+
+    fn collect_events<F: FnMut(WaitSetAttachmentId, EventId)>(
+        &self,
+        fn_call: &mut F,
+    ) -> Result<usize, WaitSetWaitError> {
+        // stable sort, attachments with the same priority keep the order of their ids
+        let mut attachments: Vec<(usize, &Attachment<'attachment, Service>)> = self
+            .attachments
+            .iter()
+            .enumerate()
+            .filter_map(|(index, attachment)| attachment.as_ref().map(|a| (index, a)))
+            .collect();
+        attachments.sort_by_key(|(_, attachment)| core::cmp::Reverse(attachment.priority));
+
+        let mut number_of_woken_attachments = 0;
+        for (index, attachment) in attachments {
+            let listener = attachment.listener;
+            let mut has_woken_up = false;
+            if let Err(e) = listener.try_wait_all(|event_id| {
+                has_woken_up = true;
+                fn_call(WaitSetAttachmentId(index), event_id)
+            }) {
+                fail!(from self, with WaitSetWaitError::ListenerFailure(e),
+                    "Unable to collect the events of the listener {:?} ({:?}).", listener.id(), e);
+            }
+
+            if listener.has_missed_deadline() {
+                let last_notification = Some(listener.last_notification().value());
+                if attachment.reported_missed_deadline.get() != last_notification {
+                    attachment.reported_missed_deadline.set(last_notification);
+                    has_woken_up = true;
+                }
+            }
+
+            let lost_notifiers = listener.notifiers_with_lost_liveliness();
+            let mut reported_notifiers = attachment.reported_lost_liveliness.borrow_mut();
+            if lost_notifiers
+                .iter()
+                .any(|id| !reported_notifiers.contains(id))
+            {
+                has_woken_up = true;
+            }
+            *reported_notifiers = lost_notifiers;
+
+            if has_woken_up {
+                number_of_woken_attachments += 1;
+            }
+        }
+
+        Ok(number_of_woken_attachments)
+    }
+}
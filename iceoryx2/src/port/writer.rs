@@ -0,0 +1,223 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let blackboard = node.service_builder("MyBlackboardName".try_into()?)
+//!     .blackboard::<u64>()
+//!     .add::<f32>(0, 1.5)
+//!     .open_or_create()?;
+//! let event = node.service_builder("MyBlackboardName".try_into()?)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! let writer = blackboard.writer_builder().create()?;
+//! let notifier = event.notifier_builder().create()?;
+//!
+//! let entry = writer.entry::<f32>(&0)?;
+//! entry.update_with_copy(3.25);
+//! // inform the readers that the entry has changed
+//! notifier.notify_with_custom_event_id(entry.entry_id())?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use iceoryx2_bb_lock_free::mpmc::container::ContainerHandle;
+use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::{Producer, UnrestrictedAtomic};
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use super::event_id::EventId;
+use super::port_identifiers::UniqueWriterId;
+use crate::service;
+use crate::service::dynamic_config::blackboard::WriterDetails;
+use crate::service::static_config::blackboard::{EntryTypeDetails, StaticConfig};
+
+/// Failures that can occur when a new [`Writer`] is created with the
+/// [`crate::service::port_factory::writer::PortFactoryWriter`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum WriterCreateError {
+    ExceedsMaxSupportedWriters,
+}
+
+impl std::fmt::Display for WriterCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "WriterCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for WriterCreateError {}
+
+/// Failures that can occur when an [`EntryHandleMut`] is acquired with [`Writer::entry()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum EntryHandleMutError {
+    EntryDoesNotExist,
+    IncompatibleValueType,
+    HandleAlreadyExists,
+}
+
+impl std::fmt::Display for EntryHandleMutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "EntryHandleMutError::{:?}", self)
+    }
+}
+
+impl std::error::Error for EntryHandleMutError {}
+
+/// Provides write access to the value of a single entry of the blackboard. The value is
+/// written directly into the shared memory of the service. There can be only one
+/// [`EntryHandleMut`] per entry at a time, system-wide.
+pub struct EntryHandleMut<'writer, ValueType: Copy> {
+    producer: Producer<'writer, ValueType>,
+    entry_id: EventId,
+}
+
+impl<ValueType: Copy> Debug for EntryHandleMut<'_, ValueType> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "EntryHandleMut<{}> {{ entry_id: {:?} }}",
+            core::any::type_name::<ValueType>(),
+            self.entry_id
+        )
+    }
+}
+
+impl<ValueType: Copy> EntryHandleMut<'_, ValueType> {
+    /// Replaces the value of the entry with a copy of the provided value. Every
+    /// [`crate::port::reader::Reader`] sees the new value with its next read.
+    pub fn update_with_copy(&self, value: ValueType) {
+        self.producer.store(value);
+    }
+
+    /// Returns the [`EventId`] that identifies the entry. It can be used with an
+    /// [`Event`](crate::service::messaging_pattern::MessagingPattern::Event) service to signal
+    /// the change of the entry to the readers.
+    pub fn entry_id(&self) -> EventId {
+        self.entry_id
+    }
+}
+
+/// The writing endpoint of a blackboard based communication.
+#[derive(Debug)]
+pub struct Writer<Service: service::Service, KeyType: Copy + Eq + Debug> {
+    dynamic_storage: Arc<Service::DynamicStorage>,
+    static_config: StaticConfig,
+    dynamic_writer_handle: Option<ContainerHandle>,
+    port_id: UniqueWriterId,
+    _key: PhantomData<KeyType>,
+}
+
+impl<Service: service::Service, KeyType: Copy + Eq + Debug> Drop for Writer<Service, KeyType> {
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_writer_handle {
+            self.dynamic_storage
+                .get()
+                .blackboard()
+                .release_writer_handle(handle)
+        }
+    }
+}
+
+impl<Service: service::Service, KeyType: Copy + Eq + Debug> Writer<Service, KeyType> {
+    pub(crate) fn new(service: &Service) -> Result<Self, WriterCreateError> {
+        let msg = "Unable to create Writer port";
+        let origin = "Writer::new()";
+        let port_id = UniqueWriterId::new();
+        let static_config = service.state().static_config.blackboard().clone();
+
+        let mut new_self = Self {
+            dynamic_storage: Arc::clone(&service.state().dynamic_storage),
+            static_config,
+            dynamic_writer_handle: None,
+            port_id,
+            _key: PhantomData,
+        };
+
+        let dynamic_writer_handle =
+            match new_self
+                .dynamic_storage
+                .get()
+                .blackboard()
+                .add_writer_id(WriterDetails {
+                    writer_id: port_id,
+                    node_id: *service.state().shared_node.id(),
+                }) {
+                Some(handle) => handle,
+                None => {
+                    fail!(from origin, with WriterCreateError::ExceedsMaxSupportedWriters,
+                    "{} since it would exceed the maximum supported amount of writers of {}.",
+                    msg, new_self.static_config.max_writers);
+                }
+            };
+        new_self.dynamic_writer_handle = Some(dynamic_writer_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueWriterId`] of the [`Writer`]
+    pub fn id(&self) -> UniqueWriterId {
+        self.port_id
+    }
+
+    /// Returns an [`EntryHandleMut`] to update the value of the entry with the provided key.
+    /// Fails when no entry with the key exists, when the entry stores a value of another type
+    /// or when an [`EntryHandleMut`] for the entry already exists.
+    pub fn entry<ValueType: Copy>(
+        &self,
+        key: &KeyType,
+    ) -> Result<EntryHandleMut<'_, ValueType>, EntryHandleMutError> {
+        let msg = "Unable to acquire mutable entry handle";
+        let dynamic_config = self.dynamic_storage.get().blackboard();
+
+        // the key type was verified when the service was opened
+        let index = match unsafe { dynamic_config.entry_index(key) } {
+            Some(index) => index,
+            None => {
+                fail!(from self, with EntryHandleMutError::EntryDoesNotExist,
+                    "{} since no entry with the key {:?} exists.", msg, key);
+            }
+        };
+
+        let entry = &self.static_config.entries[index];
+        if entry.value_type_details != EntryTypeDetails::from::<ValueType>() {
+            fail!(from self, with EntryHandleMutError::IncompatibleValueType,
+                "{} since the entry with the key {:?} stores a value of type {:?} but {:?} was requested.",
+                msg, key, entry.value_type_details, EntryTypeDetails::from::<ValueType>());
+        }
+
+        let atomic = unsafe {
+            &*(dynamic_config.entries_ptr().add(entry.offset)
+                as *const UnrestrictedAtomic<ValueType>)
+        };
+
+        match atomic.acquire_producer() {
+            Some(producer) => Ok(EntryHandleMut {
+                producer,
+                entry_id: EventId::new(index),
+            }),
+            None => {
+                fail!(from self, with EntryHandleMutError::HandleAlreadyExists,
+                    "{} since a mutable handle for the entry with the key {:?} already exists.", msg, key);
+            }
+        }
+    }
+}
@@ -11,15 +11,14 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 pub use crate::config::Config;
-pub use crate::iox2::Iox2;
-pub use crate::iox2::Iox2Event;
-pub use crate::node::{node_name::NodeName, Node, NodeBuilder, NodeState};
+pub use crate::node::{node_name::NodeName, Node, NodeBuilder, NodeEvent, NodeState};
 pub use crate::port::event_id::EventId;
 pub use crate::service::{
     attribute::AttributeSet, attribute::AttributeSpecifier, attribute::AttributeVerifier,
     port_factory::publisher::UnableToDeliverStrategy, port_factory::PortFactory, process_local,
-    service_name::ServiceName, zero_copy, Service,
+    service_name::ServiceName, static_config::ServiceLifetime, zero_copy, Service,
 };
 pub use iceoryx2_bb_derive_macros::PlacementDefault;
 pub use iceoryx2_bb_elementary::alignment::Alignment;
+pub use iceoryx2_bb_elementary::cache_line_padded::CacheLinePadded;
 pub use iceoryx2_bb_elementary::placement_default::PlacementDefault;
@@ -12,36 +12,43 @@
 
 use core::fmt;
 
-/// Contains the pointer to the underlying header and payload of a sample.
+/// Contains the pointer to the underlying header, user header and payload of a sample.
 #[repr(C)]
-pub(crate) struct RawSample<Header, PayloadType: ?Sized> {
+pub(crate) struct RawSample<Header, UserHeader, PayloadType: ?Sized> {
     header: *const Header,
+    user_header: *const UserHeader,
     payload: *const PayloadType,
 }
 
-impl<Header, PayloadType> RawSample<Header, [PayloadType]> {
+impl<Header, UserHeader, PayloadType> RawSample<Header, UserHeader, [PayloadType]> {
     /// Creates a new `RawSample`.
     ///
     /// # Safety
     ///
     /// * `header` must be non-null.
+    /// * `user_header` must be non-null.
     /// * `payload` must be non-null.
     ///
     #[inline]
     pub(crate) unsafe fn new_slice_unchecked(
         header: *const Header,
+        user_header: *const UserHeader,
         payload: *const [PayloadType],
     ) -> Self {
         debug_assert!(
-            !header.is_null() && !payload.is_null(),
-            "RawSample::new_unchecked requires that the header- and payload-pointer is non-null"
+            !header.is_null() && !user_header.is_null() && !payload.is_null(),
+            "RawSample::new_unchecked requires that the header-, user-header- and payload-pointer is non-null"
         );
 
-        Self { header, payload }
+        Self {
+            header,
+            user_header,
+            payload,
+        }
     }
 }
 
-impl<Header, PayloadType: ?Sized> RawSample<Header, PayloadType> {
+impl<Header, UserHeader, PayloadType: ?Sized> RawSample<Header, UserHeader, PayloadType> {
     /// Acquires the underlying header as reference.
     #[must_use]
     #[inline(always)]
@@ -49,6 +56,13 @@ impl<Header, PayloadType: ?Sized> RawSample<Header, PayloadType> {
         unsafe { &*self.header }
     }
 
+    /// Acquires the underlying user header as reference.
+    #[must_use]
+    #[inline(always)]
+    pub(crate) fn as_user_header_ref(&self) -> &UserHeader {
+        unsafe { &*self.user_header }
+    }
+
     /// Acquires the underlying data as reference.
     #[must_use]
     #[inline(always)]
@@ -57,68 +71,89 @@ impl<Header, PayloadType: ?Sized> RawSample<Header, PayloadType> {
     }
 }
 
-impl<Header, PayloadType> RawSample<Header, PayloadType> {
+impl<Header, UserHeader, PayloadType> RawSample<Header, UserHeader, PayloadType> {
     /// Creates a new `RawSample`.
     ///
     /// # Safety
     ///
     /// * `header` must be non-null.
+    /// * `user_header` must be non-null.
     /// * `payload` must be non-null.
     ///
     #[inline]
-    pub(crate) unsafe fn new_unchecked(header: *const Header, payload: *const PayloadType) -> Self {
+    pub(crate) unsafe fn new_unchecked(
+        header: *const Header,
+        user_header: *const UserHeader,
+        payload: *const PayloadType,
+    ) -> Self {
         debug_assert!(
-            !header.is_null() && !payload.is_null(),
-            "RawSample::new_unchecked requires that the header- and payload-pointer is non-null"
+            !header.is_null() && !user_header.is_null() && !payload.is_null(),
+            "RawSample::new_unchecked requires that the header-, user-header- and payload-pointer is non-null"
         );
 
-        Self { header, payload }
+        Self {
+            header,
+            user_header,
+            payload,
+        }
     }
 }
 
-impl<Header, PayloadType> Clone for RawSample<Header, PayloadType> {
+impl<Header, UserHeader, PayloadType> Clone for RawSample<Header, UserHeader, PayloadType> {
     #[inline(always)]
     fn clone(&self) -> Self {
         *self
     }
 }
 
-impl<Header, PayloadType> Copy for RawSample<Header, PayloadType> {}
+impl<Header, UserHeader, PayloadType> Copy for RawSample<Header, UserHeader, PayloadType> {}
 
-impl<Header: fmt::Debug, PayloadType: fmt::Debug> fmt::Debug for RawSample<Header, PayloadType> {
+impl<Header: fmt::Debug, UserHeader, PayloadType: fmt::Debug> fmt::Debug
+    for RawSample<Header, UserHeader, PayloadType>
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Pointer::fmt(&self.payload, f)
     }
 }
 
-impl<Header, PayloadType> fmt::Pointer for RawSample<Header, PayloadType> {
+impl<Header, UserHeader, PayloadType> fmt::Pointer for RawSample<Header, UserHeader, PayloadType> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Pointer::fmt(&self.payload, f)
     }
 }
 
-/// Contains the mutable pointer to the underlying header and payload of a sample.
+/// Contains the mutable pointer to the underlying header, user header and payload of a sample.
 #[repr(C)]
-pub(crate) struct RawSampleMut<Header, PayloadType: ?Sized> {
+pub(crate) struct RawSampleMut<Header, UserHeader, PayloadType: ?Sized> {
     header: *mut Header,
+    user_header: *mut UserHeader,
     payload: *mut PayloadType,
 }
 
-impl<Header, PayloadType: ?Sized> RawSampleMut<Header, PayloadType> {
+impl<Header, UserHeader, PayloadType: ?Sized> RawSampleMut<Header, UserHeader, PayloadType> {
     /// Creates a new `RawSampleMut`.
     ///
     /// # Safety
     ///
     ///  * `header` mut be non-null.
+    ///  * `user_header` must be non-null.
     ///  * `payload` must be non-null.
     ///
     #[inline]
-    pub(crate) unsafe fn new_unchecked(header: *mut Header, payload: *mut PayloadType) -> Self {
+    pub(crate) unsafe fn new_unchecked(
+        header: *mut Header,
+        user_header: *mut UserHeader,
+        payload: *mut PayloadType,
+    ) -> Self {
         debug_assert!(
-            !header.is_null() && !payload.is_null(),
-            "RawSampleMut::new_unchecked requires that the payload pointer is non-null"
+            !header.is_null() && !user_header.is_null() && !payload.is_null(),
+            "RawSampleMut::new_unchecked requires that the header-, user-header- and payload-pointer is non-null"
         );
-        Self { header, payload }
+        Self {
+            header,
+            user_header,
+            payload,
+        }
     }
 
     /// Acquires the underlying header as reference.
@@ -128,6 +163,13 @@ impl<Header, PayloadType: ?Sized> RawSampleMut<Header, PayloadType> {
         unsafe { &*self.header }
     }
 
+    /// Acquires the underlying user header as reference.
+    #[must_use]
+    #[inline(always)]
+    pub(crate) fn as_user_header_ref(&self) -> &UserHeader {
+        unsafe { &*self.user_header }
+    }
+
     /// Acquires the underlying payload as reference.
     #[must_use]
     #[inline(always)]
@@ -135,6 +177,20 @@ impl<Header, PayloadType: ?Sized> RawSampleMut<Header, PayloadType> {
         unsafe { &*self.payload }
     }
 
+    /// Acquires the underlying header as mutable reference.
+    #[must_use]
+    #[inline(always)]
+    pub(crate) fn as_header_mut(&mut self) -> &mut Header {
+        unsafe { &mut *self.header }
+    }
+
+    /// Acquires the underlying user header as mutable reference.
+    #[must_use]
+    #[inline(always)]
+    pub(crate) fn as_user_header_mut(&mut self) -> &mut UserHeader {
+        unsafe { &mut *self.user_header }
+    }
+
     /// Acquires the underlying payload as mutable reference.
     #[must_use]
     #[inline(always)]
@@ -143,22 +199,26 @@ impl<Header, PayloadType: ?Sized> RawSampleMut<Header, PayloadType> {
     }
 }
 
-impl<Header, PayloadType> Clone for RawSampleMut<Header, PayloadType> {
+impl<Header, UserHeader, PayloadType> Clone for RawSampleMut<Header, UserHeader, PayloadType> {
     #[inline(always)]
     fn clone(&self) -> Self {
         *self
     }
 }
 
-impl<Header, PayloadType> Copy for RawSampleMut<Header, PayloadType> {}
+impl<Header, UserHeader, PayloadType> Copy for RawSampleMut<Header, UserHeader, PayloadType> {}
 
-impl<Header: fmt::Debug, PayloadType: fmt::Debug> fmt::Debug for RawSampleMut<Header, PayloadType> {
+impl<Header: fmt::Debug, UserHeader, PayloadType: fmt::Debug> fmt::Debug
+    for RawSampleMut<Header, UserHeader, PayloadType>
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Pointer::fmt(&self.as_header_ref(), f)
     }
 }
 
-impl<Header, PayloadType> fmt::Pointer for RawSampleMut<Header, PayloadType> {
+impl<Header, UserHeader, PayloadType> fmt::Pointer
+    for RawSampleMut<Header, UserHeader, PayloadType>
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Pointer::fmt(&self.as_header_ref(), f)
     }
@@ -0,0 +1,41 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Reports an internal inconsistency on the communication path. With the `safety` feature the
+// failure is logged and returned as error, otherwise the process panics.
+macro_rules! fatal_panic_or_fail {
+    (from $o:expr, with $error:expr, $($e:expr),*) => {
+        {
+            #[cfg(feature = "safety")]
+            iceoryx2_bb_log::fail!(from $o, with $error, $($e),*);
+            #[cfg(not(feature = "safety"))]
+            iceoryx2_bb_log::fatal_panic!(from $o, $($e),*);
+        }
+    };
+}
+
+// Reports an internal inconsistency on the communication path where no error can be returned,
+// like in a destructor. With the `safety` feature the failure is only logged, otherwise the
+// process panics.
+macro_rules! fatal_panic_or_error {
+    (from $o:expr, $($e:expr),*) => {
+        {
+            #[cfg(feature = "safety")]
+            iceoryx2_bb_log::error!(from $o, $($e),*);
+            #[cfg(not(feature = "safety"))]
+            iceoryx2_bb_log::fatal_panic!(from $o, $($e),*);
+        }
+    };
+}
+
+pub(crate) use fatal_panic_or_error;
+pub(crate) use fatal_panic_or_fail;
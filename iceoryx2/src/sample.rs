@@ -30,15 +30,28 @@
 //! # }
 //! ```
 
+#![cfg_attr(
+    feature = "safety",
+    deny(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::indexing_slicing,
+        clippy::unreachable
+    )
+)]
+
 use std::sync::Arc;
 use std::{fmt::Debug, ops::Deref};
 
-use iceoryx2_bb_log::{fatal_panic, warn};
+use iceoryx2_bb_elementary::plain_old_data::PlainOldData;
+use iceoryx2_bb_log::warn;
 use iceoryx2_cal::zero_copy_connection::{PointerOffset, ZeroCopyReceiver, ZeroCopyReleaseError};
 
 use crate::port::details::publisher_connections::PublisherConnections;
 use crate::port::port_identifiers::UniquePublisherId;
 use crate::raw_sample::RawSample;
+use crate::safety::fatal_panic_or_error;
 use crate::service::header::publish_subscribe::Header;
 
 #[derive(Debug)]
@@ -52,27 +65,32 @@ pub(crate) struct SampleDetails<Service: crate::service::Service> {
 /// It stores the payload and is acquired by the [`Subscriber`](crate::port::subscriber::Subscriber) whenever
 /// it receives new data from a [`Publisher`](crate::port::publisher::Publisher) via
 /// [`Subscriber::receive()`](crate::port::subscriber::Subscriber::receive()).
-pub struct Sample<PayloadType: Debug + ?Sized, Service: crate::service::Service> {
-    pub(crate) ptr: RawSample<Header, PayloadType>,
+pub struct Sample<
+    PayloadType: Debug + ?Sized,
+    Service: crate::service::Service,
+    UserHeader: Debug = (),
+> {
+    pub(crate) ptr: RawSample<Header, UserHeader, PayloadType>,
     pub(crate) details: SampleDetails<Service>,
 }
 
-impl<PayloadType: Debug + ?Sized, Service: crate::service::Service> Debug
-    for Sample<PayloadType, Service>
+impl<PayloadType: Debug + ?Sized, Service: crate::service::Service, UserHeader: Debug> Debug
+    for Sample<PayloadType, Service, UserHeader>
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Sample<{}, {}> {{ details: {:?} }}",
+            "Sample<{}, {}, {}> {{ details: {:?} }}",
             core::any::type_name::<PayloadType>(),
             core::any::type_name::<Service>(),
+            core::any::type_name::<UserHeader>(),
             self.details
         )
     }
 }
 
-impl<PayloadType: Debug + ?Sized, Service: crate::service::Service> Deref
-    for Sample<PayloadType, Service>
+impl<PayloadType: Debug + ?Sized, Service: crate::service::Service, UserHeader: Debug> Deref
+    for Sample<PayloadType, Service, UserHeader>
 {
     type Target = PayloadType;
     fn deref(&self) -> &Self::Target {
@@ -80,8 +98,8 @@ impl<PayloadType: Debug + ?Sized, Service: crate::service::Service> Deref
     }
 }
 
-impl<PayloadType: Debug + ?Sized, Service: crate::service::Service> Drop
-    for Sample<PayloadType, Service>
+impl<PayloadType: Debug + ?Sized, Service: crate::service::Service, UserHeader: Debug> Drop
+    for Sample<PayloadType, Service, UserHeader>
 {
     fn drop(&mut self) {
         match self
@@ -94,7 +112,7 @@ impl<PayloadType: Debug + ?Sized, Service: crate::service::Service> Drop
                     match c.receiver.release(self.details.offset) {
                         Ok(()) => (),
                         Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
-                            fatal_panic!(from self, "This should never happen! The publishers retrieve channel is full and the sample cannot be returned.");
+                            fatal_panic_or_error!(from self, "This should never happen! The publishers retrieve channel is full and the sample cannot be returned.");
                         }
                     }
                 }
@@ -106,7 +124,9 @@ impl<PayloadType: Debug + ?Sized, Service: crate::service::Service> Drop
     }
 }
 
-impl<PayloadType: Debug + ?Sized, Service: crate::service::Service> Sample<PayloadType, Service> {
+impl<PayloadType: Debug + ?Sized, Service: crate::service::Service, UserHeader: Debug>
+    Sample<PayloadType, Service, UserHeader>
+{
     /// Returns a reference to the payload of the [`Sample`]
     pub fn payload(&self) -> &PayloadType {
         self.ptr.as_payload_ref()
@@ -117,8 +137,58 @@ impl<PayloadType: Debug + ?Sized, Service: crate::service::Service> Sample<Paylo
         self.ptr.as_header_ref()
     }
 
+    /// Returns a reference to the user header of the [`Sample`], see
+    /// [`Builder::user_header()`](crate::service::builder::publish_subscribe::Builder::user_header()).
+    pub fn user_header(&self) -> &UserHeader {
+        self.ptr.as_user_header_ref()
+    }
+
     /// Returns the [`UniquePublisherId`] of the [`Publisher`](crate::port::publisher::Publisher)
     pub fn origin(&self) -> UniquePublisherId {
         self.details.origin
     }
 }
+
+impl<
+        PayloadType: Debug + PlainOldData + ?Sized,
+        Service: crate::service::Service,
+        UserHeader: Debug,
+    > Sample<PayloadType, Service, UserHeader>
+{
+    /// Returns the payload of the [`Sample`] as byte slice with the size of the payload, e.g.
+    /// to record it or to compute a checksum.
+    ///
+    /// # Notes
+    ///
+    /// Only available for payloads that implement [`PlainOldData`] since the bytes of a
+    /// type with padding are not guaranteed to be initialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    /// #   .publish_subscribe::<u64>()
+    /// #   .open_or_create()?;
+    /// # let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// while let Some(sample) = subscriber.receive()? {
+    ///     let checksum = sample.payload_as_bytes().iter().fold(0u8, |a, b| a ^ b);
+    ///     println!("checksum: {}", checksum);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn payload_as_bytes(&self) -> &[u8] {
+        let payload = self.ptr.as_payload_ref();
+        unsafe {
+            core::slice::from_raw_parts(
+                (payload as *const PayloadType).cast(),
+                core::mem::size_of_val(payload),
+            )
+        }
+    }
+}
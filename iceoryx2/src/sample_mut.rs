@@ -65,16 +65,32 @@
 //! # }
 //! ```
 
+#![cfg_attr(
+    feature = "safety",
+    deny(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::indexing_slicing,
+        clippy::unreachable
+    )
+)]
+
 use crate::{
-    port::publisher::{DataSegment, PublisherSendError},
+    port::acknowledgment::SampleId,
+    port::publisher::{DataSegment, PublisherSendError, TimedSendReport},
     raw_sample::RawSampleMut,
     service::header::publish_subscribe::Header,
 };
+use iceoryx2_bb_elementary::plain_old_data::PlainOldData;
+use iceoryx2_bb_log::fail;
 use iceoryx2_cal::shared_memory::*;
 use std::{
+    alloc::Layout,
     fmt::{Debug, Formatter},
     mem::MaybeUninit,
     sync::Arc,
+    time::Duration,
 };
 
 /// Acquired by a [`crate::port::publisher::Publisher`] via
@@ -94,41 +110,54 @@ use std::{
 ///
 /// The generic parameter `M` is either a `PayloadType` or a [`core::mem::MaybeUninit<PayloadType>`], depending
 /// which API is used to obtain the sample.
-pub struct SampleMut<PayloadType: Debug + ?Sized, Service: crate::service::Service> {
+pub struct SampleMut<
+    PayloadType: Debug + ?Sized,
+    Service: crate::service::Service,
+    UserHeader: Debug = (),
+> {
     data_segment: Arc<DataSegment<Service>>,
-    ptr: RawSampleMut<Header, PayloadType>,
+    ptr: RawSampleMut<Header, UserHeader, PayloadType>,
     pub(crate) offset_to_chunk: PointerOffset,
 }
 
-impl<PayloadType: Debug + ?Sized, Service: crate::service::Service> Debug
-    for SampleMut<PayloadType, Service>
+impl<PayloadType: Debug + ?Sized, Service: crate::service::Service, UserHeader: Debug> Debug
+    for SampleMut<PayloadType, Service, UserHeader>
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "SampleMut<{}, {}> {{ data_segment: {:?}, offset_to_chunk: {:?} }}",
+            "SampleMut<{}, {}, {}> {{ data_segment: {:?}, offset_to_chunk: {:?} }}",
             core::any::type_name::<PayloadType>(),
             core::any::type_name::<Service>(),
+            core::any::type_name::<UserHeader>(),
             self.data_segment,
             self.offset_to_chunk
         )
     }
 }
 
-impl<PayloadType: Debug + ?Sized, Service: crate::service::Service> Drop
-    for SampleMut<PayloadType, Service>
+impl<PayloadType: Debug + ?Sized, Service: crate::service::Service, UserHeader: Debug> Drop
+    for SampleMut<PayloadType, Service, UserHeader>
 {
     fn drop(&mut self) {
         self.data_segment.return_loaned_sample(self.offset_to_chunk);
     }
 }
 
-impl<PayloadType: Debug, Service: crate::service::Service>
-    SampleMut<MaybeUninit<PayloadType>, Service>
+impl<PayloadType: Debug + ?Sized, Service: crate::service::Service, UserHeader: Debug>
+    SampleMut<PayloadType, Service, UserHeader>
+{
+    pub(crate) fn data_segment(&self) -> &DataSegment<Service> {
+        &self.data_segment
+    }
+}
+
+impl<PayloadType: Debug, Service: crate::service::Service, UserHeader: Debug>
+    SampleMut<MaybeUninit<PayloadType>, Service, UserHeader>
 {
     pub(crate) fn new(
         data_segment: &Arc<DataSegment<Service>>,
-        ptr: RawSampleMut<Header, MaybeUninit<PayloadType>>,
+        ptr: RawSampleMut<Header, UserHeader, MaybeUninit<PayloadType>>,
         offset_to_chunk: PointerOffset,
     ) -> Self {
         Self {
@@ -139,12 +168,12 @@ impl<PayloadType: Debug, Service: crate::service::Service>
     }
 }
 
-impl<PayloadType: Debug, Service: crate::service::Service>
-    SampleMut<[MaybeUninit<PayloadType>], Service>
+impl<PayloadType: Debug, Service: crate::service::Service, UserHeader: Debug>
+    SampleMut<[MaybeUninit<PayloadType>], Service, UserHeader>
 {
     pub(crate) fn new(
         data_segment: &Arc<DataSegment<Service>>,
-        ptr: RawSampleMut<Header, [MaybeUninit<PayloadType>]>,
+        ptr: RawSampleMut<Header, UserHeader, [MaybeUninit<PayloadType>]>,
         offset_to_chunk: PointerOffset,
     ) -> Self {
         Self {
@@ -155,8 +184,8 @@ impl<PayloadType: Debug, Service: crate::service::Service>
     }
 }
 
-impl<PayloadType: Debug, Service: crate::service::Service>
-    SampleMut<MaybeUninit<PayloadType>, Service>
+impl<PayloadType: Debug, Service: crate::service::Service, UserHeader: Debug>
+    SampleMut<MaybeUninit<PayloadType>, Service, UserHeader>
 {
     /// Writes the payload to the sample and labels the sample as initialized
     ///
@@ -181,7 +210,10 @@ impl<PayloadType: Debug, Service: crate::service::Service>
     /// # Ok(())
     /// # }
     /// ```
-    pub fn write_payload(mut self, value: PayloadType) -> SampleMut<PayloadType, Service> {
+    pub fn write_payload(
+        mut self,
+        value: PayloadType,
+    ) -> SampleMut<PayloadType, Service, UserHeader> {
         self.payload_mut().write(value);
         // SAFETY: this is safe since the payload was initialized on the line above
         unsafe { self.assume_init() }
@@ -216,14 +248,14 @@ impl<PayloadType: Debug, Service: crate::service::Service>
     /// # Ok(())
     /// # }
     /// ```
-    pub unsafe fn assume_init(self) -> SampleMut<PayloadType, Service> {
+    pub unsafe fn assume_init(self) -> SampleMut<PayloadType, Service, UserHeader> {
         // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
         std::mem::transmute(self)
     }
 }
 
-impl<PayloadType: Debug, Service: crate::service::Service>
-    SampleMut<[MaybeUninit<PayloadType>], Service>
+impl<PayloadType: Debug, Service: crate::service::Service, UserHeader: Debug>
+    SampleMut<[MaybeUninit<PayloadType>], Service, UserHeader>
 {
     /// Extracts the value of the slice of [`core::mem::MaybeUninit<PayloadType>`] and labels the sample as initialized
     ///
@@ -261,7 +293,7 @@ impl<PayloadType: Debug, Service: crate::service::Service>
     /// # Ok(())
     /// # }
     /// ```
-    pub unsafe fn assume_init(self) -> SampleMut<[PayloadType], Service> {
+    pub unsafe fn assume_init(self) -> SampleMut<[PayloadType], Service, UserHeader> {
         // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
         std::mem::transmute(self)
     }
@@ -293,7 +325,7 @@ impl<PayloadType: Debug, Service: crate::service::Service>
     pub fn write_from_fn<F: FnMut(usize) -> PayloadType>(
         mut self,
         mut initializer: F,
-    ) -> SampleMut<[PayloadType], Service> {
+    ) -> SampleMut<[PayloadType], Service, UserHeader> {
         for (i, element) in self.payload_mut().iter_mut().enumerate() {
             element.write(initializer(i));
         }
@@ -301,12 +333,237 @@ impl<PayloadType: Debug, Service: crate::service::Service>
         // SAFETY: this is safe since the payload was initialized on the line above
         unsafe { self.assume_init() }
     }
+
+    /// Returns a [`SliceWriter`] that initializes the slice element by element from the
+    /// beginning. When it is finished, the sample is truncated to the written elements, so that
+    /// variable-length encodings do not have to initialize the whole slice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[u8]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().max_slice_len(64).create()?;
+    ///
+    /// let mut writer = publisher.loan_slice_uninit(64)?.writer();
+    /// writer.push(3)?;
+    /// writer.extend_from_slice(b"abc")?;
+    ///
+    /// let sample = writer.finish();
+    /// assert_eq!(sample.payload(), &[3, b'a', b'b', b'c']);
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn writer(self) -> SliceWriter<PayloadType, Service, UserHeader> {
+        SliceWriter {
+            sample: self,
+            len: 0,
+        }
+    }
+}
+
+/// Failures that can occur when elements are written with a [`SliceWriter`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum SliceWriteError {
+    /// The elements do not fit into the remaining capacity of the loaned slice.
+    ExceedsCapacity,
+}
+
+impl std::fmt::Display for SliceWriteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "SliceWriteError::{:?}", self)
+    }
+}
+
+impl std::error::Error for SliceWriteError {}
+
+/// Initializes a loaned slice of [`core::mem::MaybeUninit<PayloadType>`] element by element,
+/// acquired with [`SampleMut::writer()`]. When it goes out of scope without being finished, the
+/// sample is released without being sent.
+#[derive(Debug)]
+pub struct SliceWriter<PayloadType: Debug, Service: crate::service::Service, UserHeader: Debug = ()>
+{
+    sample: SampleMut<[MaybeUninit<PayloadType>], Service, UserHeader>,
+    len: usize,
+}
+
+impl<PayloadType: Debug, Service: crate::service::Service, UserHeader: Debug>
+    SliceWriter<PayloadType, Service, UserHeader>
+{
+    /// Returns the number of elements that were written.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true when no element was written.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of elements that can still be written.
+    pub fn remaining(&self) -> usize {
+        self.sample.payload().len() - self.len
+    }
+
+    /// Appends `value` after the last written element.
+    pub fn push(&mut self, value: PayloadType) -> Result<(), SliceWriteError> {
+        match self.sample.payload_mut().get_mut(self.len) {
+            Some(element) => {
+                element.write(value);
+                self.len += 1;
+                Ok(())
+            }
+            None => {
+                fail!(from self, with SliceWriteError::ExceedsCapacity,
+                    "Unable to push the element since the loaned slice is full.");
+            }
+        }
+    }
+
+    /// Appends all `values` after the last written element. Nothing is written when they do
+    /// not fit into the remaining capacity.
+    pub fn extend_from_slice(&mut self, values: &[PayloadType]) -> Result<(), SliceWriteError>
+    where
+        PayloadType: Clone,
+    {
+        let len = self.len;
+        match self.sample.payload_mut().get_mut(len..len + values.len()) {
+            Some(elements) => {
+                for (element, value) in elements.iter_mut().zip(values) {
+                    element.write(value.clone());
+                }
+                self.len += values.len();
+                Ok(())
+            }
+            None => {
+                fail!(from self, with SliceWriteError::ExceedsCapacity,
+                    "Unable to extend the slice by {} elements since only {} elements remain.",
+                    values.len(), self.remaining());
+            }
+        }
+    }
+
+    /// Truncates the sample to the written elements and labels it as initialized.
+    pub fn finish(self) -> SampleMut<[PayloadType], Service, UserHeader> {
+        let mut sample = self.sample;
+        sample.truncate(self.len);
+
+        // SAFETY: the first `len` elements were written and the sample was truncated to them
+        unsafe { sample.assume_init() }
+    }
+}
+
+impl<
+        M: Debug, // `M` is either a `PayloadType` or a `MaybeUninit<PayloadType>`
+        Service: crate::service::Service,
+        UserHeader: Debug,
+    > SampleMut<[M], Service, UserHeader>
+{
+    /// Shortens the slice to the first `len` elements, so that only those are delivered to the
+    /// [`crate::port::subscriber::Subscriber`]s. If `len` is greater than or equal to the
+    /// current length, this has no effect. It allows to loan a slice with the worst-case size,
+    /// initialize only the required elements and call [`SampleMut::assume_init()`] afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[usize]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().max_slice_len(32).create()?;
+    ///
+    /// let mut sample = publisher.loan_slice_uninit(32)?;
+    /// sample.truncate(3);
+    /// let sample = sample.write_from_fn(|n| n * 2);
+    /// assert_eq!(sample.payload().len(), 3);
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        let current_len = self.ptr.as_payload_ref().len();
+        if current_len <= len {
+            return;
+        }
+
+        let payload_ptr = self.ptr.as_payload_mut().as_mut_ptr();
+        let payload_layout = self.ptr.as_header_ref().payload_type_layout();
+        let header = self.ptr.as_header_mut();
+        header.set_payload_type_layout(unsafe {
+            Layout::from_size_align_unchecked(
+                core::mem::size_of::<M>() * len,
+                payload_layout.align(),
+            )
+        });
+        let header_ptr = header as *mut Header;
+        let user_header_ptr = self.ptr.as_user_header_mut() as *mut UserHeader;
+
+        self.ptr = unsafe {
+            RawSampleMut::new_unchecked(
+                header_ptr,
+                user_header_ptr,
+                core::ptr::slice_from_raw_parts_mut(payload_ptr, len),
+            )
+        };
+    }
+}
+
+impl<PayloadType: Debug, Service: crate::service::Service, UserHeader: Debug>
+    SampleMut<[PayloadType], Service, UserHeader>
+{
+    /// Sends only the first `len` elements of the slice to all connected
+    /// [`crate::port::subscriber::Subscriber`]s, see [`SampleMut::truncate()`] and
+    /// [`SampleMut::send()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<[usize]>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().max_slice_len(32).create()?;
+    ///
+    /// let sample = publisher.loan_slice(32)?;
+    /// sample.send_truncated(5)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_truncated(mut self, len: usize) -> Result<usize, PublisherSendError> {
+        self.truncate(len);
+        self.send()
+    }
 }
 
 impl<
         M: Debug + ?Sized, // `M` is either a `PayloadType` or a `MaybeUninit<PayloadType>`
         Service: crate::service::Service,
-    > SampleMut<M, Service>
+        UserHeader: Debug,
+    > SampleMut<M, Service, UserHeader>
 {
     /// Returns a reference to the header of the sample.
     ///
@@ -333,6 +590,93 @@ impl<
         self.ptr.as_header_ref()
     }
 
+    /// Returns a reference to the user header of the sample, see
+    /// [`Builder::user_header()`](crate::service::builder::publish_subscribe::Builder::user_header()).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .user_header::<u32>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let sample = publisher.loan()?;
+    /// println!("Sample user header {}", sample.user_header());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn user_header(&self) -> &UserHeader {
+        self.ptr.as_user_header_ref()
+    }
+
+    /// Returns a mutable reference to the user header of the sample, see
+    /// [`Builder::user_header()`](crate::service::builder::publish_subscribe::Builder::user_header()).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .user_header::<u32>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// *sample.user_header_mut() = 123;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn user_header_mut(&mut self) -> &mut UserHeader {
+        self.ptr.as_user_header_mut()
+    }
+
+    /// Sets the key of the entity the sample describes, for instance the id of a tracked object,
+    /// so that one service can carry the state of many entities. The key can be acquired with
+    /// [`Header::key()`] and a [`crate::port::subscriber::Subscriber`] can receive the latest
+    /// sample of every key with
+    /// [`Subscriber::receive_latest_per_key()`](crate::port::subscriber::Subscriber::receive_latest_per_key()).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// sample.set_key(42);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_key(&mut self, key: u64) {
+        self.ptr.as_header_mut().set_key(key);
+    }
+
+    pub(crate) fn set_fragment(&mut self, offset: u64, message_len: u64) {
+        self.ptr.as_header_mut().set_fragment(offset, message_len);
+    }
+
     /// Returns a reference to the payload of the sample.
     ///
     /// # Notes
@@ -428,4 +772,126 @@ impl<
     pub fn send(self) -> Result<usize, PublisherSendError> {
         self.data_segment.send_sample(self.offset_to_chunk.value())
     }
+
+    /// Sends a [`SampleMut`] like [`SampleMut::send()`] but waits at most `timeout` for the
+    /// [`crate::port::subscriber::Subscriber`]s whose buffer is full when the
+    /// [`crate::service::port_factory::publisher::UnableToDeliverStrategy::Block`] is active.
+    /// The subscribers whose buffer stayed full do not receive the sample and are listed in the
+    /// returned [`TimedSendReport`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// let publisher = service
+    ///     .publisher_builder()
+    ///     .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+    ///     .create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// *sample.payload_mut() = 1234;
+    /// let report = sample.send_with_timeout(Duration::from_millis(10))?;
+    ///
+    /// for subscriber_id in report.timed_out_subscribers() {
+    ///     publisher.disconnect_subscriber(*subscriber_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_with_timeout(
+        self,
+        timeout: Duration,
+    ) -> Result<TimedSendReport, PublisherSendError> {
+        self.data_segment
+            .send_sample_with_timeout(self.offset_to_chunk.value(), timeout)
+    }
+
+    /// Sends a [`SampleMut`] like [`SampleMut::send()`] and returns the [`SampleId`] with which
+    /// the acknowledgments of the [`crate::port::subscriber::Subscriber`]s can be acquired, see
+    /// [`crate::port::publisher::Publisher::acknowledgment_report()`]. Fails with
+    /// [`PublisherSendError::AcknowledgmentsNotEnabled`] when the
+    /// [`crate::port::publisher::Publisher`] was not created with
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::enable_acknowledgments()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// let publisher = service.publisher_builder().enable_acknowledgments(true).create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// *sample.payload_mut() = 4567;
+    ///
+    /// let sample_id = sample.send_acknowledged()?;
+    /// let report = publisher.acknowledgment_report(sample_id);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_acknowledged(self) -> Result<SampleId, PublisherSendError> {
+        self.data_segment
+            .send_acknowledged_sample(self.offset_to_chunk.value())
+    }
+}
+
+impl<
+        M: Debug + PlainOldData + ?Sized, // `M` is either a `PayloadType` or a `MaybeUninit<PayloadType>`
+        Service: crate::service::Service,
+        UserHeader: Debug,
+    > SampleMut<M, Service, UserHeader>
+{
+    /// Returns the payload of the sample as mutable byte slice with the size of the payload,
+    /// e.g. to fill it with recorded data.
+    ///
+    /// # Notes
+    ///
+    /// Only available for payloads that implement [`PlainOldData`] since only those have no
+    /// padding bytes and accept any byte pattern. The values of the bytes are unspecified until
+    /// they are written, they may contain data of previously sent samples.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// #
+    /// # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan_uninit()?;
+    /// sample
+    ///     .payload_as_bytes_mut()
+    ///     .copy_from_slice(&4567u64.to_ne_bytes());
+    /// let sample = unsafe { sample.assume_init() };
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn payload_as_bytes_mut(&mut self) -> &mut [u8] {
+        let payload = self.ptr.as_payload_mut();
+        let size = core::mem::size_of_val(payload);
+        unsafe { core::slice::from_raw_parts_mut((payload as *mut M).cast(), size) }
+    }
 }
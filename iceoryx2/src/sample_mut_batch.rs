@@ -0,0 +1,130 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! #
+//! # let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+//! #     .publish_subscribe::<u64>()
+//! #     .open_or_create()?;
+//! #
+//! # let publisher = service.publisher_builder().max_loaned_samples(4).create()?;
+//!
+//! let mut batch = publisher.loan_batch(4)?;
+//! for (n, sample) in batch.iter_mut().enumerate() {
+//!     *sample.payload_mut() = n as u64;
+//! }
+//!
+//! batch.send_all()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+
+use crate::{port::publisher::PublisherSendError, sample_mut::SampleMut};
+
+/// Acquired by a [`crate::port::publisher::Publisher`] via
+/// [`crate::port::publisher::Publisher::loan_batch()`].
+///
+/// Contains multiple [`SampleMut`]s that are filled independently and sent together with
+/// [`SampleMutBatch::send_all()`]. Every connected [`crate::port::subscriber::Subscriber`]
+/// receives all [`SampleMut`]s of the batch in a row, in the order of the batch. If the batch
+/// is not sent, all [`SampleMut`]s are released when it goes out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since the [`SampleMut`]s are not thread-safe.
+pub struct SampleMutBatch<
+    PayloadType: Debug + ?Sized,
+    Service: crate::service::Service,
+    UserHeader: Debug = (),
+> {
+    samples: Vec<SampleMut<PayloadType, Service, UserHeader>>,
+    offsets_to_chunks: Vec<usize>,
+}
+
+impl<PayloadType: Debug + ?Sized, Service: crate::service::Service, UserHeader: Debug> Debug
+    for SampleMutBatch<PayloadType, Service, UserHeader>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SampleMutBatch<{}, {}, {}> {{ samples: {:?} }}",
+            core::any::type_name::<PayloadType>(),
+            core::any::type_name::<Service>(),
+            core::any::type_name::<UserHeader>(),
+            self.samples
+        )
+    }
+}
+
+impl<PayloadType: Debug + ?Sized, Service: crate::service::Service, UserHeader: Debug>
+    SampleMutBatch<PayloadType, Service, UserHeader>
+{
+    pub(crate) fn new(samples: Vec<SampleMut<PayloadType, Service, UserHeader>>) -> Self {
+        let offsets_to_chunks = samples
+            .iter()
+            .map(|sample| sample.offset_to_chunk.value())
+            .collect();
+
+        Self {
+            samples,
+            offsets_to_chunks,
+        }
+    }
+
+    /// Returns the number of [`SampleMut`]s in the batch
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns true when the batch contains no [`SampleMut`]
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Returns a mutable reference to the [`SampleMut`] at the provided index or [`None`] when
+    /// the index is out of bounds.
+    pub fn get_mut(
+        &mut self,
+        index: usize,
+    ) -> Option<&mut SampleMut<PayloadType, Service, UserHeader>> {
+        self.samples.get_mut(index)
+    }
+
+    /// Returns an iterator over all [`SampleMut`]s of the batch to fill them.
+    pub fn iter_mut(
+        &mut self,
+    ) -> core::slice::IterMut<'_, SampleMut<PayloadType, Service, UserHeader>> {
+        self.samples.iter_mut()
+    }
+
+    /// Sends all [`SampleMut`]s of the batch to all connected
+    /// [`crate::port::subscriber::Subscriber`]s of the service. The connections are updated
+    /// once and every connection receives the whole batch before the next one is served.
+    ///
+    /// On success the sum of the number of [`crate::port::subscriber::Subscriber`]s that
+    /// received each [`SampleMut`] is returned, otherwise a [`PublisherSendError`] describing
+    /// the failure.
+    pub fn send_all(self) -> Result<usize, PublisherSendError> {
+        match self.samples.first() {
+            Some(sample) => sample.data_segment().send_samples(&self.offsets_to_chunks),
+            None => Ok(0),
+        }
+    }
+}
@@ -195,6 +195,16 @@ impl AttributeVerifier {
         &self.required_keys
     }
 
+    pub(crate) fn merged_with(&self, attributes: &AttributeSet) -> Self {
+        let mut attribute_set = self.attribute_set.clone();
+        attribute_set.merge(attributes);
+
+        Self {
+            attribute_set,
+            required_keys: self.required_keys.clone(),
+        }
+    }
+
     /// Verifies if the [`AttributeSet`] contains all required keys and key-value pairs.
     pub fn verify_requirements(&self, rhs: &AttributeSet) -> Result<(), &str> {
         let is_subset = |lhs: Vec<&str>, rhs: Vec<&str>| lhs.iter().all(|v| rhs.contains(v));
@@ -243,6 +253,15 @@ impl AttributeSet {
         self.0.sort();
     }
 
+    pub(crate) fn merge(&mut self, rhs: &AttributeSet) {
+        for attribute in rhs.iter() {
+            if !self.0.contains(attribute) {
+                self.0.push(attribute.clone());
+            }
+        }
+        self.0.sort();
+    }
+
     /// Returns all values to a specific key
     pub fn get(&self, key: &str) -> Vec<&str> {
         self.0
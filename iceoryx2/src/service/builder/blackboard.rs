@@ -0,0 +1,620 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//!
+//! let blackboard = node.service_builder("My/Funk/ServiceName".try_into()?)
+//!     // define the messaging pattern and the key type
+//!     .blackboard::<u64>()
+//!     // the entries and their initial values, only used when the service is created
+//!     .add::<f32>(0, 1.5)
+//!     .add::<[u8; 4]>(1, [0, 1, 2, 3])
+//!     // various QoS
+//!     .max_readers(4)
+//!     .max_writers(1)
+//!     // if the service already exists, open it, otherwise create it
+//!     .open_or_create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+use std::alloc::Layout;
+use std::fmt::Debug;
+use std::time::Duration;
+
+use crate::service;
+use crate::service::dynamic_config::blackboard::DynamicConfigSettings;
+use crate::service::messaging_pattern::MessagingPattern;
+use crate::service::port_factory::blackboard;
+use crate::service::static_config::blackboard::{EntryDetails, EntryTypeDetails};
+use crate::service::*;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_elementary::math::align;
+use iceoryx2_bb_lock_free::spmc::unrestricted_atomic::UnrestrictedAtomic;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
+
+use self::attribute::{AttributeSpecifier, AttributeVerifier};
+
+use super::{ServiceLimitViolation, ServiceState};
+
+/// Failures that can occur when an existing [`MessagingPattern::Blackboard`] [`Service`] shall
+/// be opened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlackboardOpenError {
+    DoesNotExist,
+    PermissionDenied,
+    ServiceInCorruptedState,
+    IncompatibleMessagingPattern,
+    IncompatibleAttributes,
+    IncompatibleKeys,
+    InternalFailure,
+    HangsInCreation,
+    DoesNotSupportRequestedAmountOfReaders,
+    DoesNotSupportRequestedAmountOfWriters,
+    UnableToOpenDynamicServiceInformation,
+}
+
+impl std::fmt::Display for BlackboardOpenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "BlackboardOpenError::{:?}", self)
+    }
+}
+
+impl std::error::Error for BlackboardOpenError {}
+
+/// Failures that can occur when a new [`MessagingPattern::Blackboard`] [`Service`] shall be
+/// created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlackboardCreateError {
+    Corrupted,
+    InternalFailure,
+    IsBeingCreatedByAnotherInstance,
+    AlreadyExists,
+    PermissionDenied,
+    UnableToCreateStaticServiceInformation,
+    OldConnectionsStillActive,
+    NoEntriesProvided,
+    /// The service name is longer than
+    /// [`Service::max_name_length`](crate::config::Service::max_name_length).
+    ServiceNameExceedsMaxLength,
+    /// The service has more attributes than
+    /// [`Service::max_attributes`](crate::config::Service::max_attributes).
+    ExceedsMaxNumberOfAttributes,
+    /// The service would exceed
+    /// [`Service::max_services`](crate::config::Service::max_services).
+    ExceedsMaxNumberOfServices,
+}
+
+impl std::fmt::Display for BlackboardCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "BlackboardCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for BlackboardCreateError {}
+
+enum_gen! {
+    /// Failures that can occur when a [`MessagingPattern::Blackboard`] [`Service`] shall be
+    /// opened or created.
+    BlackboardOpenOrCreateError
+  mapping:
+    BlackboardOpenError,
+    BlackboardCreateError
+}
+
+impl std::fmt::Display for BlackboardOpenOrCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "BlackboardOpenOrCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for BlackboardOpenOrCreateError {}
+
+// an entry that was added to the builder, the value type is erased so that entries with
+// different value types can be stored together
+struct BuilderEntry<KeyType> {
+    key: KeyType,
+    value_type_details: EntryTypeDetails,
+    value_layout: Layout,
+    value_initializer: Box<dyn Fn(*mut u8)>,
+}
+
+impl<KeyType: Debug> Debug for BuilderEntry<KeyType> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BuilderEntry {{ key: {:?}, value_type_details: {:?} }}",
+            self.key, self.value_type_details
+        )
+    }
+}
+
+/// Builder to create new [`MessagingPattern::Blackboard`] based [`Service`]s
+///
+/// # Example
+///
+/// See [`crate::service::builder::blackboard`]
+#[derive(Debug)]
+pub struct Builder<KeyType: Copy + Eq + Debug, ServiceType: service::Service> {
+    base: builder::BuilderWithServiceType<ServiceType>,
+    entries: Vec<BuilderEntry<KeyType>>,
+    verify_max_readers: bool,
+    verify_max_writers: bool,
+}
+
+impl<KeyType: Copy + Eq + Debug, ServiceType: service::Service> Builder<KeyType, ServiceType> {
+    pub(crate) fn new(base: builder::BuilderWithServiceType<ServiceType>) -> Self {
+        let mut new_self = Self {
+            base,
+            entries: vec![],
+            verify_max_readers: false,
+            verify_max_writers: false,
+        };
+
+        new_self.base.service_config.messaging_pattern = MessagingPattern::Blackboard(Box::new(
+            static_config::blackboard::StaticConfig::new(new_self.base.shared_node.config()),
+        ));
+        new_self.config_details().key_type_details = EntryTypeDetails::from::<KeyType>();
+
+        new_self
+    }
+
+    fn config_details(&mut self) -> &mut static_config::blackboard::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::Blackboard(ref mut v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in Blackboard builder!");
+            }
+        }
+    }
+
+    /// Adds an entry with the provided key and initial value to the [`Service`]. The entries
+    /// are only defined when the [`Service`] is created, when an existing [`Service`] is
+    /// opened they are ignored. If an entry with the same key was already added, it is
+    /// replaced.
+    pub fn add<ValueType: Copy + Debug + 'static>(
+        mut self,
+        key: KeyType,
+        value: ValueType,
+    ) -> Self {
+        self.entries.retain(|entry| entry.key != key);
+        self.entries.push(BuilderEntry {
+            key,
+            value_type_details: EntryTypeDetails::from::<ValueType>(),
+            value_layout: Layout::new::<UnrestrictedAtomic<ValueType>>(),
+            value_initializer: Box::new(move |ptr: *mut u8| unsafe {
+                (ptr as *mut UnrestrictedAtomic<ValueType>).write(UnrestrictedAtomic::new(value))
+            }),
+        });
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::reader::Reader`] shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::reader::Reader`] must be at least supported.
+    pub fn max_readers(mut self, value: usize) -> Self {
+        self.config_details().max_readers = value;
+        self.verify_max_readers = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::writer::Writer`] shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::writer::Writer`] must be at least supported.
+    pub fn max_writers(mut self, value: usize) -> Self {
+        self.config_details().max_writers = value;
+        self.verify_max_writers = true;
+        self
+    }
+
+    /// Adds a key-value [`crate::service::attribute::Attribute`] to the [`Service`]. If the
+    /// [`Service`] is created the attribute is defined and can be discovered via
+    /// [`crate::service::Service::list()`]. If an existing [`Service`] is opened the attribute
+    /// is required, otherwise opening fails with an incompatible attributes error. A key is
+    /// allowed to have multiple values.
+    pub fn add_attribute(mut self, key: &str, value: &str) -> Self {
+        self.base.attributes.add(key, value);
+        self
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create(
+        self,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenOrCreateError> {
+        self.open_or_create_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. It defines a set of attributes. If the [`Service`] already exists all attribute
+    /// requirements must be satisfied otherwise the open process will fail. If the [`Service`]
+    /// does not exist the required attributes will be defined in the [`Service`].
+    pub fn open_or_create_with_attributes(
+        mut self,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenOrCreateError> {
+        let msg = "Unable to open or create blackboard service";
+
+        match self.base.is_service_available() {
+            Ok(Some(_)) => Ok(self.open_with_attributes(required_attributes)?),
+            Ok(None) => {
+                match self.create_impl(&AttributeSpecifier(
+                    required_attributes.attributes().clone(),
+                )) {
+                    Ok(factory) => Ok(factory),
+                    Err(BlackboardCreateError::AlreadyExists)
+                    | Err(BlackboardCreateError::IsBeingCreatedByAnotherInstance) => {
+                        Ok(self.open()?)
+                    }
+                    Err(e) => Err(e.into()),
+                }
+            }
+            Err(ServiceState::IsBeingCreatedByAnotherInstance) => Ok(self.open()?),
+            Err(ServiceState::Corrupted) => {
+                fail!(from self, with BlackboardOpenOrCreateError::BlackboardOpenError(BlackboardOpenError::ServiceInCorruptedState),
+                    "{} since the blackboard is in a corrupted state.", msg);
+            }
+            Err(ServiceState::IncompatibleMessagingPattern) => {
+                fail!(from self, with BlackboardOpenOrCreateError::BlackboardOpenError(BlackboardOpenError::IncompatibleMessagingPattern),
+                    "{} since the services messaging pattern does not match.", msg);
+            }
+            Err(ServiceState::PermissionDenied) => {
+                fail!(from self, with BlackboardOpenOrCreateError::BlackboardOpenError(BlackboardOpenError::PermissionDenied),
+                    "{} due to insufficient permissions.", msg);
+            }
+        }
+    }
+
+    /// Opens an existing [`Service`].
+    pub fn open(
+        self,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenError> {
+        self.open_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// Opens an existing [`Service`]. If the [`Service`] does not exist yet, it waits until
+    /// another instance created it. Fails with [`BlackboardOpenError::DoesNotExist`] when the
+    /// [`Service`] was not created within the provided timeout.
+    pub fn open_with_timeout(
+        self,
+        timeout: Duration,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenError> {
+        let msg = "Unable to open blackboard service";
+
+        let does_exist = fail!(from self, when self.base.wait_for_service(timeout),
+                                with BlackboardOpenError::InternalFailure,
+                                "{} since a failure occurred while waiting for the service.", msg);
+        if !does_exist {
+            fail!(from self, with BlackboardOpenError::DoesNotExist,
+                "{} since the blackboard was not created within the timeout of {:?}.", msg, timeout);
+        }
+
+        self.open()
+    }
+
+    /// Opens an existing [`Service`] with attribute requirements. If the defined attribute
+    /// requirements are not satisfied the open process will fail.
+    pub fn open_with_attributes(
+        mut self,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardOpenError> {
+        let msg = "Unable to open blackboard service";
+
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                        with BlackboardOpenError::InternalFailure,
+                                        "{} since the adaptive wait could not be created.", msg);
+
+        loop {
+            match self.base.is_service_available() {
+                Ok(None) => {
+                    fail!(from self, with BlackboardOpenError::DoesNotExist,
+                        "{} since the blackboard does not exist.", msg);
+                }
+                Ok(Some((static_config, static_storage))) => {
+                    let blackboard_static_config =
+                        self.verify_service_attributes(&static_config, required_attributes)?;
+
+                    let dynamic_config = Arc::new(
+                        fail!(from self, when self.base.open_dynamic_config_storage(),
+                            with BlackboardOpenError::UnableToOpenDynamicServiceInformation,
+                            "{} since the dynamic service informations could not be opened.", msg),
+                    );
+
+                    self.base.service_config.messaging_pattern =
+                        MessagingPattern::Blackboard(Box::new(blackboard_static_config));
+
+                    return Ok(blackboard::PortFactory::new(ServiceType::from_state(
+                        service::ServiceState::new(
+                            static_config,
+                            self.base.shared_node,
+                            dynamic_config,
+                            static_storage,
+                        ),
+                    )));
+                }
+                Err(ServiceState::IsBeingCreatedByAnotherInstance) => {
+                    let timeout = fail!(from self, when adaptive_wait.wait(),
+                                        with BlackboardOpenError::InternalFailure,
+                                        "{} since the adaptive wait failed.", msg);
+
+                    if timeout
+                        > self
+                            .base
+                            .shared_node
+                            .config()
+                            .global
+                            .service
+                            .creation_timeout
+                    {
+                        fail!(from self, with BlackboardOpenError::HangsInCreation,
+                            "{} since the service hangs while being created, max timeout for service creation of {:?} exceeded. Waited for {:?} but the state did not change.",
+                            msg, self.base.shared_node.config().global.service.creation_timeout, timeout);
+                    }
+                }
+                Err(ServiceState::PermissionDenied) => {
+                    fail!(from self, with BlackboardOpenError::PermissionDenied,
+                        "{} due to insufficient permissions.", msg);
+                }
+                Err(ServiceState::IncompatibleMessagingPattern) => {
+                    fail!(from self, with BlackboardOpenError::IncompatibleMessagingPattern,
+                        "{} since the services messaging pattern does not match.", msg);
+                }
+                Err(ServiceState::Corrupted) => {
+                    fail!(from self, with BlackboardOpenError::ServiceInCorruptedState,
+                        "{} since the blackboard is in a corrupted state.", msg);
+                }
+            }
+        }
+    }
+
+    /// Creates a new [`Service`].
+    pub fn create(
+        mut self,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardCreateError> {
+        self.create_impl(&AttributeSpecifier::new())
+    }
+
+    /// Creates a new [`Service`] with a set of attributes.
+    pub fn create_with_attributes(
+        mut self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardCreateError> {
+        self.create_impl(attributes)
+    }
+
+    fn create_impl(
+        &mut self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<blackboard::PortFactory<ServiceType, KeyType>, BlackboardCreateError> {
+        self.adjust_attributes_to_meaningful_values();
+
+        let msg = "Unable to create blackboard service";
+
+        if self.entries.is_empty() {
+            fail!(from self, with BlackboardCreateError::NoEntriesProvided,
+                "{} since no entries were added to the blackboard.", msg);
+        }
+
+        match self.base.is_service_available() {
+            Ok(None) => {
+                if let Err(e) = self.base.verify_service_limits(attributes) {
+                    let error = match e {
+                        ServiceLimitViolation::NameTooLong => {
+                            BlackboardCreateError::ServiceNameExceedsMaxLength
+                        }
+                        ServiceLimitViolation::TooManyAttributes => {
+                            BlackboardCreateError::ExceedsMaxNumberOfAttributes
+                        }
+                        ServiceLimitViolation::TooManyServices => {
+                            BlackboardCreateError::ExceedsMaxNumberOfServices
+                        }
+                        ServiceLimitViolation::UnableToListServices => {
+                            BlackboardCreateError::InternalFailure
+                        }
+                    };
+                    fail!(from self, with error,
+                        "{} since the service would exceed the limits of the config.", msg);
+                }
+
+                let static_config = match self.base.create_static_config_storage() {
+                    Ok(c) => c,
+                    Err(StaticStorageCreateError::AlreadyExists) => {
+                        fail!(from self, with BlackboardCreateError::AlreadyExists,
+                           "{} since the service already exists.", msg);
+                    }
+                    Err(StaticStorageCreateError::Creation) => {
+                        fail!(from self, with BlackboardCreateError::IsBeingCreatedByAnotherInstance,
+                            "{} since the service is being created by another instance.", msg);
+                    }
+                    Err(e) => {
+                        fail!(from self, with BlackboardCreateError::UnableToCreateStaticServiceInformation,
+                            "{} since the static service information could not be created ({:?}).", msg, e);
+                    }
+                };
+
+                self.prepare_entry_layout();
+                let blackboard_config = self.base.service_config.blackboard();
+
+                let dynamic_config_setting = DynamicConfigSettings {
+                    number_of_readers: blackboard_config.max_readers,
+                    number_of_writers: blackboard_config.max_writers,
+                    number_of_entries: blackboard_config.entries.len(),
+                    entries_layout: blackboard_config.entries_layout(),
+                };
+
+                let dynamic_config = match self.base.create_dynamic_config_storage(
+                    dynamic_config::MessagingPattern::Blackboard(
+                        dynamic_config::blackboard::DynamicConfig::new(&dynamic_config_setting),
+                    ),
+                    dynamic_config::blackboard::DynamicConfig::memory_size(&dynamic_config_setting),
+                ) {
+                    Ok(c) => Arc::new(c),
+                    Err(DynamicStorageCreateError::AlreadyExists) => {
+                        fail!(from self, with BlackboardCreateError::OldConnectionsStillActive,
+                            "{} since there are still active Readers or Writers.", msg);
+                    }
+                    Err(e) => {
+                        fail!(from self, with BlackboardCreateError::InternalFailure,
+                            "{} since the dynamic service segment could not be created ({:?}).", msg, e);
+                    }
+                };
+
+                // the service is not visible before the static details are unlocked, therefore
+                // the entries can be initialized without any synchronization
+                self.initialize_entries(dynamic_config.get().blackboard().entries_ptr());
+
+                let mut attributes = attributes.0.clone();
+                attributes.merge(&self.base.attributes);
+                self.base.service_config.attributes = attributes;
+
+                let service_config = fail!(from self, when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
+                                            with BlackboardCreateError::Corrupted,
+                                            "{} since the configuration could not be serialized.", msg);
+
+                // only unlock the static details when the service is successfully created
+                let mut unlocked_static_details = fail!(from self, when static_config.unlock(service_config.as_slice()),
+                            with BlackboardCreateError::Corrupted,
+                            "{} since the configuration could not be written to the static storage.", msg);
+
+                unlocked_static_details.release_ownership();
+
+                Ok(blackboard::PortFactory::new(ServiceType::from_state(
+                    service::ServiceState::new(
+                        self.base.service_config.clone(),
+                        self.base.shared_node.clone(),
+                        dynamic_config,
+                        unlocked_static_details,
+                    ),
+                )))
+            }
+            Ok(Some(_)) | Err(ServiceState::IncompatibleMessagingPattern) => {
+                fail!(from self, with BlackboardCreateError::AlreadyExists,
+                    "{} since the service already exists.", msg);
+            }
+            Err(ServiceState::PermissionDenied) => {
+                fail!(from self, with BlackboardCreateError::PermissionDenied,
+                    "{} due to possible insufficient permissions to access the underlying service details.", msg);
+            }
+            Err(ServiceState::Corrupted) => {
+                fail!(from self, with BlackboardCreateError::Corrupted,
+                    "{} since a service in a corrupted state already exists. A cleanup of the service constructs may help,", msg);
+            }
+            Err(ServiceState::IsBeingCreatedByAnotherInstance) => {
+                fail!(from self, with BlackboardCreateError::IsBeingCreatedByAnotherInstance,
+                    "{} since the service is being created by another instance.", msg);
+            }
+        }
+    }
+
+    // the keys are stored as array at the beginning of the entry memory, followed by the
+    // values in the order in which the entries were added
+    fn prepare_entry_layout(&mut self) {
+        let key_layout = Layout::new::<KeyType>();
+        let mut entries = Vec::with_capacity(self.entries.len());
+        let mut entries_size = key_layout.size() * self.entries.len();
+        let mut entries_alignment = key_layout.align();
+
+        for entry in &self.entries {
+            let offset = align(entries_size, entry.value_layout.align());
+            entries_size = offset + entry.value_layout.size();
+            entries_alignment = entries_alignment.max(entry.value_layout.align());
+            entries.push(EntryDetails {
+                value_type_details: entry.value_type_details.clone(),
+                offset,
+            });
+        }
+
+        let settings = self.base.service_config.blackboard_mut();
+        settings.entries = entries;
+        settings.entries_size = entries_size;
+        settings.entries_alignment = entries_alignment;
+    }
+
+    fn initialize_entries(&self, entries_ptr: *mut u8) {
+        let settings = self.base.service_config.blackboard();
+        for (n, entry) in self.entries.iter().enumerate() {
+            unsafe { (entries_ptr as *mut KeyType).add(n).write(entry.key) };
+            (entry.value_initializer)(unsafe { entries_ptr.add(settings.entries[n].offset) });
+        }
+    }
+
+    fn adjust_attributes_to_meaningful_values(&mut self) {
+        let origin = format!("{:?}", self);
+        let settings = self.base.service_config.blackboard_mut();
+
+        if settings.max_readers == 0 {
+            warn!(from origin, "Setting the maximum amount of readers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_readers = 1;
+        }
+
+        if settings.max_writers == 0 {
+            warn!(from origin, "Setting the maximum amount of writers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_writers = 1;
+        }
+    }
+
+    fn verify_service_attributes(
+        &self,
+        existing_settings: &static_config::StaticConfig,
+        required_attributes: &AttributeVerifier,
+    ) -> Result<static_config::blackboard::StaticConfig, BlackboardOpenError> {
+        let msg = "Unable to open blackboard";
+
+        let existing_attributes = existing_settings.attributes();
+        let required_attributes = required_attributes.merged_with(&self.base.attributes);
+        if let Err(incompatible_key) = required_attributes.verify_requirements(existing_attributes)
+        {
+            fail!(from self, with BlackboardOpenError::IncompatibleAttributes,
+                "{} due to incompatible service attribute key {}. The following attributes {:?} are required but the service has the attributes {:?}.",
+                msg, incompatible_key, required_attributes, existing_attributes);
+        }
+
+        let required_settings = self.base.service_config.blackboard();
+        let existing_settings = match &existing_settings.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            p => {
+                fail!(from self, with BlackboardOpenError::IncompatibleMessagingPattern,
+                "{} since a service with the messaging pattern {:?} exists but MessagingPattern::Blackboard is required.", msg, p);
+            }
+        };
+
+        if existing_settings.key_type_details != required_settings.key_type_details {
+            fail!(from self, with BlackboardOpenError::IncompatibleKeys,
+                "{} since the blackboard uses the key type {:?} but the key type {:?} was requested.",
+                msg, existing_settings.key_type_details, required_settings.key_type_details);
+        }
+
+        if self.verify_max_readers && existing_settings.max_readers < required_settings.max_readers
+        {
+            fail!(from self, with BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders,
+                "{} since the blackboard supports only {} readers but a support of {} readers was requested.",
+                msg, existing_settings.max_readers, required_settings.max_readers);
+        }
+
+        if self.verify_max_writers && existing_settings.max_writers < required_settings.max_writers
+        {
+            fail!(from self, with BlackboardOpenError::DoesNotSupportRequestedAmountOfWriters,
+                "{} since the blackboard supports only {} writers but a support of {} writers was requested.",
+                msg, existing_settings.max_writers, required_settings.max_writers);
+        }
+
+        Ok((**existing_settings).clone())
+    }
+}
@@ -14,19 +14,21 @@
 //!
 //! See [`crate::service`]
 //!
+use core::time::Duration;
+
 pub use crate::port::event_id::EventId;
 use crate::service::messaging_pattern::MessagingPattern;
 use crate::service::port_factory::event;
 use crate::service::*;
 use crate::service::{self, dynamic_config::event::DynamicConfigSettings};
 use iceoryx2_bb_elementary::enum_gen;
-use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 
 use self::attribute::{AttributeSpecifier, AttributeVerifier};
 
-use super::ServiceState;
+use super::{ServiceLimitViolation, ServiceState};
 
 /// Failures that can occur when an existing [`MessagingPattern::Event`] [`Service`] shall be opened.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -41,6 +43,9 @@ pub enum EventOpenError {
     DoesNotSupportRequestedAmountOfNotifiers,
     DoesNotSupportRequestedAmountOfListeners,
     DoesNotSupportRequestedMaxEventId,
+    IncompatibleDeadline,
+    IncompatibleNotifierLiveliness,
+    UnknownQosProfile,
     UnableToOpenDynamicServiceInformation,
 }
 
@@ -62,6 +67,16 @@ pub enum EventCreateError {
     PermissionDenied,
     UnableToCreateStaticServiceInformation,
     OldConnectionsStillActive,
+    UnknownQosProfile,
+    /// The service name is longer than
+    /// [`Service::max_name_length`](crate::config::Service::max_name_length).
+    ServiceNameExceedsMaxLength,
+    /// The service has more attributes than
+    /// [`Service::max_attributes`](crate::config::Service::max_attributes).
+    ExceedsMaxNumberOfAttributes,
+    /// The service would exceed
+    /// [`Service::max_services`](crate::config::Service::max_services).
+    ExceedsMaxNumberOfServices,
 }
 
 impl std::fmt::Display for EventCreateError {
@@ -100,6 +115,9 @@ pub struct Builder<ServiceType: service::Service> {
     verify_max_notifiers: bool,
     verify_max_listeners: bool,
     verify_event_id_max_value: bool,
+    verify_deadline: bool,
+    verify_notifier_liveliness: bool,
+    unknown_qos_profile: Option<String>,
 }
 
 impl<ServiceType: service::Service> Builder<ServiceType> {
@@ -109,6 +127,9 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             verify_max_notifiers: false,
             verify_max_listeners: false,
             verify_event_id_max_value: false,
+            verify_deadline: false,
+            verify_notifier_liveliness: false,
+            unknown_qos_profile: None,
         };
 
         new_self.base.service_config.messaging_pattern = MessagingPattern::Event(
@@ -154,6 +175,102 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self
     }
 
+    /// If the [`Service`] is created it defines the deadline of the service. When no
+    /// [`crate::port::notifier::Notifier`] notified within the deadline,
+    /// [`crate::port::notifier::Notifier::notify()`] reports
+    /// [`NotifierNotifyError::MissedDeadline`](crate::port::notifier::NotifierNotifyError::MissedDeadline)
+    /// and every [`crate::port::waitset::WaitSet`] with an attached
+    /// [`crate::port::listener::Listener`] wakes up, see
+    /// [`crate::port::listener::Listener::has_missed_deadline()`]. If an existing [`Service`]
+    /// is opened it requires the service to have the same deadline.
+    pub fn deadline(mut self, value: Duration) -> Self {
+        self.config_details().deadline = Some(value);
+        self.verify_deadline = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines the interval in which every
+    /// [`crate::port::notifier::Notifier`] must show a sign of life, either with a notification
+    /// or with [`crate::port::notifier::Notifier::assert_liveliness()`]. A
+    /// [`crate::port::notifier::Notifier`] that missed the interval or whose process died has
+    /// lost its liveliness, see
+    /// [`crate::port::listener::Listener::notifiers_with_lost_liveliness()`], and every
+    /// [`crate::port::waitset::WaitSet`] with an attached [`crate::port::listener::Listener`]
+    /// wakes up. If an existing [`Service`] is opened it requires the service to have the same
+    /// interval.
+    pub fn notifier_liveliness(mut self, interval: Duration) -> Self {
+        self.config_details().notifier_liveliness = Some(interval);
+        self.verify_notifier_liveliness = true;
+        self
+    }
+
+    /// Applies all event settings of the [`QosProfile`](crate::config::QosProfile) with the
+    /// provided name that is defined in
+    /// [`Config::qos_profiles`](crate::config::Config::qos_profiles). Every setting is applied
+    /// as if the corresponding builder method was called, settings that are called afterwards
+    /// override it. If no profile with the name exists, the creation or opening of the
+    /// [`Service`] fails.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::{Config, QosProfile};
+    /// use core::time::Duration;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut profile = QosProfile::default();
+    /// profile.max_listeners = Some(4);
+    /// profile.deadline = Some(Duration::from_millis(100));
+    ///
+    /// let mut config = Config::default();
+    /// config.qos_profiles.insert("watchdog".to_string(), profile);
+    ///
+    /// let node = NodeBuilder::new().config(&config).create::<zero_copy::Service>()?;
+    /// let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .event()
+    ///     .qos_profile("watchdog")
+    ///     .open_or_create()?;
+    ///
+    /// assert_eq!(service.static_config().max_supported_listeners(), 4);
+    /// assert_eq!(service.static_config().deadline(), Some(Duration::from_millis(100)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn qos_profile(mut self, name: &str) -> Self {
+        let profile = match self.base.shared_node.config().qos_profiles.get(name) {
+            Some(profile) => profile.clone(),
+            None => {
+                warn!(from self, "The QoS profile \"{}\" is not defined in the config.", name);
+                self.unknown_qos_profile = Some(name.to_string());
+                return self;
+            }
+        };
+
+        if let Some(value) = profile.max_listeners {
+            self = self.max_listeners(value);
+        }
+        if let Some(value) = profile.max_notifiers {
+            self = self.max_notifiers(value);
+        }
+        if let Some(value) = profile.event_id_max_value {
+            self = self.event_id_max_value(value);
+        }
+        if let Some(value) = profile.deadline {
+            self = self.deadline(value);
+        }
+
+        self
+    }
+
+    /// Adds a key-value [`crate::service::attribute::Attribute`] to the [`Service`]. If the
+    /// [`Service`] is created the attribute is defined and can be discovered via
+    /// [`crate::service::Service::list()`]. If an existing [`Service`] is opened the attribute
+    /// is required, otherwise opening fails with an incompatible attributes error. A key is
+    /// allowed to have multiple values.
+    pub fn add_attribute(mut self, key: &str, value: &str) -> Self {
+        self.base.attributes.add(key, value);
+        self
+    }
+
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created.
     pub fn open_or_create(self) -> Result<event::PortFactory<ServiceType>, EventOpenOrCreateError> {
@@ -203,6 +320,26 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         self.open_with_attributes(&AttributeVerifier::new())
     }
 
+    /// Opens an existing [`Service`]. If the [`Service`] does not exist yet, it waits until
+    /// another instance created it. Fails with [`EventOpenError::DoesNotExist`] when the
+    /// [`Service`] was not created within the provided timeout.
+    pub fn open_with_timeout(
+        self,
+        timeout: Duration,
+    ) -> Result<event::PortFactory<ServiceType>, EventOpenError> {
+        let msg = "Unable to open event service";
+
+        let does_exist = fail!(from self, when self.base.wait_for_service(timeout),
+                                with EventOpenError::InternalFailure,
+                                "{} since a failure occurred while waiting for the service.", msg);
+        if !does_exist {
+            fail!(from self, with EventOpenError::DoesNotExist,
+                "{} since the event was not created within the timeout of {:?}.", msg, timeout);
+        }
+
+        self.open()
+    }
+
     /// Opens an existing [`Service`] with attribute requirements. If the defined attribute
     /// requirements are not satisfied the open process will fail.
     pub fn open_with_attributes(
@@ -211,6 +348,11 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
     ) -> Result<event::PortFactory<ServiceType>, EventOpenError> {
         let msg = "Unable to open event service";
 
+        if let Some(name) = &self.unknown_qos_profile {
+            fail!(from self, with EventOpenError::UnknownQosProfile,
+                "{} since the QoS profile \"{}\" is not defined in the config.", msg, name);
+        }
+
         let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
                                         with EventOpenError::InternalFailure,
                                         "{} since the adaptive wait could not be created.", msg);
@@ -299,8 +441,32 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
 
         let msg = "Unable to create event service";
 
+        if let Some(name) = &self.unknown_qos_profile {
+            fail!(from self, with EventCreateError::UnknownQosProfile,
+                "{} since the QoS profile \"{}\" is not defined in the config.", msg, name);
+        }
+
         match self.base.is_service_available() {
             Ok(None) => {
+                if let Err(e) = self.base.verify_service_limits(attributes) {
+                    let error = match e {
+                        ServiceLimitViolation::NameTooLong => {
+                            EventCreateError::ServiceNameExceedsMaxLength
+                        }
+                        ServiceLimitViolation::TooManyAttributes => {
+                            EventCreateError::ExceedsMaxNumberOfAttributes
+                        }
+                        ServiceLimitViolation::TooManyServices => {
+                            EventCreateError::ExceedsMaxNumberOfServices
+                        }
+                        ServiceLimitViolation::UnableToListServices => {
+                            EventCreateError::InternalFailure
+                        }
+                    };
+                    fail!(from self, with error,
+                        "{} since the service would exceed the limits of the config.", msg);
+                }
+
                 let static_config = match self.base.create_static_config_storage() {
                     Ok(c) => c,
                     Err(StaticStorageCreateError::AlreadyExists) => {
@@ -341,7 +507,9 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                     }
                 };
 
-                self.base.service_config.attributes = attributes.0.clone();
+                let mut attributes = attributes.0.clone();
+                attributes.merge(&self.base.attributes);
+                self.base.service_config.attributes = attributes;
 
                 let service_config = fail!(from self, when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
                                             with EventCreateError::Corrupted,
@@ -391,6 +559,16 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             settings.max_notifiers = 1;
         }
 
+        if settings.deadline == Some(Duration::ZERO) {
+            warn!(from origin, "Setting the deadline to 0 is not supported. Disable the deadline.");
+            settings.deadline = None;
+        }
+
+        if settings.notifier_liveliness == Some(Duration::ZERO) {
+            warn!(from origin, "Setting the notifier liveliness interval to 0 is not supported. Disable the liveliness tracking.");
+            settings.notifier_liveliness = None;
+        }
+
         if settings.max_listeners == 0 {
             warn!(from origin, "Setting the maximum amount of listeners to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_listeners = 1;
@@ -405,6 +583,7 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         let msg = "Unable to open event";
 
         let existing_attributes = existing_settings.attributes();
+        let required_attributes = required_attributes.merged_with(&self.base.attributes);
         if let Err(incompatible_key) = required_attributes.verify_requirements(existing_attributes)
         {
             fail!(from self, with EventOpenError::IncompatibleAttributes,
@@ -445,6 +624,20 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
                 msg, existing_settings.event_id_max_value, required_settings.event_id_max_value);
         }
 
+        if self.verify_deadline && existing_settings.deadline != required_settings.deadline {
+            fail!(from self, with EventOpenError::IncompatibleDeadline,
+                "{} since the event has the deadline {:?} but the deadline {:?} was requested.",
+                msg, existing_settings.deadline, required_settings.deadline);
+        }
+
+        if self.verify_notifier_liveliness
+            && existing_settings.notifier_liveliness != required_settings.notifier_liveliness
+        {
+            fail!(from self, with EventOpenError::IncompatibleNotifierLiveliness,
+                "{} since the event has the notifier liveliness interval {:?} but the interval {:?} was requested.",
+                msg, existing_settings.notifier_liveliness, required_settings.notifier_liveliness);
+        }
+
         Ok(*existing_settings)
     }
 }
@@ -14,6 +14,9 @@
 //!
 //! See [`crate::service`]
 
+/// Builder for [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+pub mod blackboard;
+
 /// Builder for [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
 pub mod event;
 
@@ -23,12 +26,16 @@ pub mod publish_subscribe;
 use crate::node::SharedNode;
 use crate::service;
 use crate::service::dynamic_config::DynamicConfig;
+use crate::service::static_config::type_details::TypeVariant;
 use crate::service::static_config::*;
 use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_elementary::alignment::Alignment;
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::adaptive_wait::{AdaptiveWaitBuilder, AdaptiveWaitError};
+use iceoryx2_bb_posix::permission::Permission;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::dynamic_storage::DynamicStorageOpenError;
@@ -41,7 +48,9 @@ use iceoryx2_cal::static_storage::*;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::sync::Arc;
+use std::time::Duration;
 
+use super::attribute::{AttributeSet, AttributeSpecifier};
 use super::config_scheme::dynamic_config_storage_config;
 use super::config_scheme::static_config_storage_config;
 use super::naming_scheme::dynamic_config_storage_name;
@@ -57,6 +66,15 @@ enum ServiceState {
     Corrupted,
 }
 
+// the limits of Config::global::service that a new service would exceed
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+enum ServiceLimitViolation {
+    NameTooLong,
+    TooManyAttributes,
+    TooManyServices,
+    UnableToListServices,
+}
+
 enum_gen! {
 #[doc(hidden)]
     OpenDynamicStorageFailure
@@ -99,6 +117,9 @@ impl std::error::Error for ReadStaticStorageFailure {}
 pub struct Builder<S: Service> {
     name: ServiceName,
     shared_node: Arc<SharedNode<S>>,
+    permission: Permission,
+    lifetime: ServiceLifetime,
+    is_internal: bool,
     _phantom_s: PhantomData<S>,
 }
 
@@ -107,10 +128,81 @@ impl<S: Service> Builder<S> {
         Self {
             name,
             shared_node,
+            permission: Permission::OWNER_ALL,
+            lifetime: ServiceLifetime::default(),
+            is_internal: false,
             _phantom_s: PhantomData,
         }
     }
 
+    /// Marks the [`Service`] as internal service of iceoryx2, like the sample arrival service
+    /// of a publish-subscribe service. Its name is derived by iceoryx2 and not chosen by the
+    /// user, therefore it is exempt from the `max_name_length` limit of the config.
+    pub(crate) fn internal(mut self) -> Self {
+        self.is_internal = true;
+        self
+    }
+
+    /// Defines the [`Permission`] of the static storage and of all shared memory segments of
+    /// the [`Service`]. Groups or others that have read access can open the [`Service`] and
+    /// receive data but they cannot write into the data segments of other processes. The owner
+    /// always has read and write access and the resources stay owned by the process that
+    /// created them. Only the [`Permission`] of the creator is applied, when an existing
+    /// [`Service`] is opened the setting is ignored.
+    ///
+    /// By default, only the owner has access to the [`Service`].
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_bb_posix::permission::Permission;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    ///
+    /// let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .permissions(Permission::OWNER_ALL | Permission::GROUP_READ)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn permissions(mut self, value: Permission) -> Self {
+        self.permission = value;
+        self
+    }
+
+    /// Defines the [`ServiceLifetime`] of the [`Service`]. An
+    /// [`ServiceLifetime::OwnerBound`] [`Service`] is removed when the last
+    /// [`Node`](crate::node::Node) that owns it releases it or dies. A
+    /// [`ServiceLifetime::Persistent`] [`Service`], like an infrastructure topic, outlives
+    /// the process that created it and must be removed explicitly with [`Service::remove()`].
+    /// Only the [`ServiceLifetime`] of the creator is applied, when an existing [`Service`] is
+    /// opened the setting is ignored.
+    ///
+    /// By default, the [`Service`] is [`ServiceLifetime::OwnerBound`].
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let service_name = ServiceName::new("My/Funk/InfrastructureTopic")?;
+    ///
+    /// let service = node.service_builder(service_name.clone())
+    ///     .lifetime(ServiceLifetime::Persistent)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    /// drop(service);
+    ///
+    /// // the service still exists until it is removed
+    /// assert!(zero_copy::Service::does_exist(&service_name, node.config())?);
+    /// zero_copy::Service::remove(&service_name, node.config())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn lifetime(mut self, value: ServiceLifetime) -> Self {
+        self.lifetime = value;
+        self
+    }
+
     /// Create a new builder to create a
     /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`].
     pub fn publish_subscribe<PayloadType: Debug + ?Sized>(
@@ -122,19 +214,118 @@ impl<S: Service> Builder<S> {
                 self.shared_node.config(),
             ),
             self.shared_node,
+            self.permission,
+            self.lifetime,
+            self.is_internal,
         )
         .publish_subscribe()
     }
 
+    /// Create a new builder to create a
+    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`]
+    /// whose payload type is unknown at compile time, for instance in gateways or language
+    /// bindings. Every payload consists of `payload_size` bytes with the provided [`Alignment`]
+    /// and is exchanged as raw `[u8]`, see
+    /// [`Publisher::loan_untyped_uninit()`](crate::port::publisher::Publisher::loan_untyped_uninit).
+    /// To communicate with a typed [`Service`], the payload type name of the typed [`Service`]
+    /// must be set with
+    /// [`publish_subscribe::Builder::payload_type_name()`](crate::service::builder::publish_subscribe::Builder::payload_type_name).
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    ///
+    /// let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe_untyped(16, Alignment::new(8).unwrap())
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = service.publisher_builder().create()?;
+    /// let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan_untyped_uninit()?;
+    /// for byte in sample.payload_mut().iter_mut() {
+    ///     byte.write(0xff);
+    /// }
+    /// unsafe { sample.assume_init() }.send()?;
+    ///
+    /// let sample = subscriber.receive()?.unwrap();
+    /// assert_eq!(sample.payload(), &[0xff; 16]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn publish_subscribe_untyped(
+        self,
+        payload_size: usize,
+        alignment: Alignment,
+    ) -> publish_subscribe::Builder<[u8], S> {
+        // raw bytes are valid for every payload layout and the layout of an existing service is
+        // verified when it is opened
+        unsafe {
+            self.publish_subscribe::<[u8]>()
+                .__internal_set_payload_layout(
+                    TypeVariant::FixedSize,
+                    payload_size,
+                    alignment.value(),
+                )
+        }
+    }
+
     /// Create a new builder to create a
     /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) [`Service`].
     pub fn event(self) -> event::Builder<S> {
         BuilderWithServiceType::new(
             StaticConfig::new_event::<S::ServiceNameHasher>(&self.name, self.shared_node.config()),
             self.shared_node,
+            self.permission,
+            self.lifetime,
+            self.is_internal,
         )
         .event()
     }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) [`Service`].
+    pub fn blackboard<KeyType: Copy + Eq + Debug>(self) -> blackboard::Builder<KeyType, S> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_blackboard::<S::ServiceNameHasher>(
+                &self.name,
+                self.shared_node.config(),
+            ),
+            self.shared_node,
+            self.permission,
+            self.lifetime,
+            self.is_internal,
+        )
+        .blackboard()
+    }
+}
+
+// Polls `does_exist` until it reports that the service with the provided name exists or the
+// timeout has passed. Returns false when the service did not appear within the timeout.
+pub(crate) fn wait_for_service<E: From<AdaptiveWaitError>, F: FnMut() -> Result<bool, E>>(
+    origin: &impl Debug,
+    service_name: &ServiceName,
+    timeout: Duration,
+    mut does_exist: F,
+) -> Result<bool, E> {
+    let msg = "Unable to wait for the service";
+    let mut adaptive_wait = fail!(from origin, when AdaptiveWaitBuilder::new().create(),
+        "{} \"{}\" since the adaptive wait could not be created.", msg, service_name);
+
+    loop {
+        if fail!(from origin, when does_exist(),
+            "{} \"{}\" since the existence of the service could not be verified.", msg, service_name)
+        {
+            return Ok(true);
+        }
+
+        let elapsed = fail!(from origin, when adaptive_wait.wait(),
+            "{} \"{}\" since the adaptive wait failed.", msg, service_name);
+        if elapsed >= timeout {
+            return Ok(false);
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -142,14 +333,26 @@ impl<S: Service> Builder<S> {
 pub struct BuilderWithServiceType<ServiceType: service::Service> {
     service_config: StaticConfig,
     shared_node: Arc<SharedNode<ServiceType>>,
+    attributes: AttributeSet,
+    is_internal: bool,
     _phantom_data: PhantomData<ServiceType>,
 }
 
 impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
-    fn new(service_config: StaticConfig, shared_node: Arc<SharedNode<ServiceType>>) -> Self {
+    fn new(
+        mut service_config: StaticConfig,
+        shared_node: Arc<SharedNode<ServiceType>>,
+        permission: Permission,
+        lifetime: ServiceLifetime,
+        is_internal: bool,
+    ) -> Self {
+        service_config.set_permission(permission);
+        service_config.set_lifetime(lifetime);
         Self {
             service_config,
             shared_node,
+            attributes: AttributeSet::new(),
+            is_internal,
             _phantom_data: PhantomData,
         }
     }
@@ -164,6 +367,10 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         event::Builder::new(self)
     }
 
+    fn blackboard<KeyType: Copy + Eq + Debug>(self) -> blackboard::Builder<KeyType, ServiceType> {
+        blackboard::Builder::new(self)
+    }
+
     fn is_service_available(
         &self,
     ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceState> {
@@ -230,6 +437,53 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         }
     }
 
+    /// Waits until the service exists or is being created by another instance. Returns false
+    /// when the service did not appear within the provided timeout.
+    fn wait_for_service(&self, timeout: Duration) -> Result<bool, AdaptiveWaitError> {
+        wait_for_service(self, self.service_config.name(), timeout, || {
+            Ok(!matches!(self.is_service_available(), Ok(None)))
+        })
+    }
+
+    fn verify_service_limits(
+        &self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<(), ServiceLimitViolation> {
+        let msg = "The service exceeds the limits of the config";
+        let limits = &self.shared_node.config().global.service;
+        let name = self.service_config.name();
+
+        if !self.is_internal && limits.max_name_length != 0 && name.len() > limits.max_name_length {
+            fail!(from self, with ServiceLimitViolation::NameTooLong,
+                "{} since the service name has {} characters but at most {} are allowed.",
+                msg, name.len(), limits.max_name_length);
+        }
+
+        let mut all_attributes = attributes.0.clone();
+        all_attributes.merge(&self.attributes);
+        if limits.max_attributes != 0 && all_attributes.len() > limits.max_attributes {
+            fail!(from self, with ServiceLimitViolation::TooManyAttributes,
+                "{} since the service has {} attributes but at most {} are allowed.",
+                msg, all_attributes.len(), limits.max_attributes);
+        }
+
+        if limits.max_services != 0 {
+            let number_of_services = fail!(from self,
+                when ServiceType::list(self.shared_node.config()),
+                with ServiceLimitViolation::UnableToListServices,
+                "{} since the existing services could not be listed.", msg)
+            .len();
+
+            if number_of_services >= limits.max_services {
+                fail!(from self, with ServiceLimitViolation::TooManyServices,
+                    "{} since already {} services exist but at most {} are allowed.",
+                    msg, number_of_services, limits.max_services);
+            }
+        }
+
+        Ok(())
+    }
+
     fn config_init_call(config: &mut DynamicConfig, allocator: &mut BumpAllocator) -> bool {
         unsafe { config.init(allocator) };
         true
@@ -248,8 +502,9 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
             .config(&dynamic_config_storage_config::<ServiceType>(self.shared_node.config()))
             .supplementary_size(additional_size)
             .has_ownership(false)
+            .permission(self.service_config.participant_permission())
             .initializer(Self::config_init_call)
-            .create(DynamicConfig::new_uninit(messaging_pattern) ) {
+            .create(DynamicConfig::new_uninit(messaging_pattern, self.service_config.lifetime())) {
                 Ok(dynamic_storage) => Ok(dynamic_storage),
                 Err(e) => {
                     fail!(from self, with e, "Failed to create dynamic storage for service.");
@@ -291,6 +546,7 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
                         self.shared_node.config(),
                     ))
                     .has_ownership(true)
+                    .permission(self.service_config.permission())
                     .create_locked(),
                     "Failed to create static service information since the underlying static storage could not be created."),
         )
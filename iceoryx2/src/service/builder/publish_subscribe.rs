@@ -15,14 +15,18 @@
 //! See [`crate::service`]
 //!
 use std::marker::PhantomData;
+use std::time::Duration;
 
+use crate::port::publisher::PublisherMemoryBreakdown;
 use crate::service;
 use crate::service::dynamic_config::publish_subscribe::DynamicConfigSettings;
 use crate::service::header::publish_subscribe::Header;
 use crate::service::messaging_pattern::MessagingPattern;
 use crate::service::port_factory::publish_subscribe;
+use crate::service::port_factory::publisher::LocalPublisherConfig;
+use crate::service::port_factory::subscriber::{GroupDeliveryStrategy, ReceiveOrder};
 use crate::service::*;
-use iceoryx2_bb_elementary::{alignment::Alignment, enum_gen};
+use iceoryx2_bb_elementary::{alignment::Alignment, cache_line_padded::CacheLinePadded, enum_gen};
 use iceoryx2_bb_log::{fail, fatal_panic, warn};
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
@@ -31,10 +35,10 @@ use iceoryx2_cal::static_storage::StaticStorageLocked;
 
 use self::{
     attribute::{AttributeSpecifier, AttributeVerifier},
-    type_details::{TypeDetails, TypeVariant},
+    type_details::{FieldLayout, TypeDetails, TypeVariant},
 };
 
-use super::ServiceState;
+use super::{ServiceLimitViolation, ServiceState};
 
 /// Errors that can occur when an existing [`MessagingPattern::PublishSubscribe`] [`Service`] shall be opened.
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
@@ -49,7 +53,10 @@ pub enum PublishSubscribeOpenError {
     DoesNotSupportRequestedMinSubscriberBorrowedSamples,
     DoesNotSupportRequestedAmountOfPublishers,
     DoesNotSupportRequestedAmountOfSubscribers,
+    DoesNotSupportRequestedAmountOfObservers,
     IncompatibleOverflowBehavior,
+    IncompatibleDeadline,
+    UnknownQosProfile,
     Inaccessible,
     PermissionDenied,
     ServiceInCorruptedState,
@@ -76,6 +83,19 @@ pub enum PublishSubscribeCreateError {
     IsBeingCreatedByAnotherInstance,
     UnableToCreateStaticServiceInformation,
     OldConnectionsStillActive,
+    UnknownQosProfile,
+    /// The service name is longer than
+    /// [`Service::max_name_length`](crate::config::Service::max_name_length).
+    ServiceNameExceedsMaxLength,
+    /// The service has more attributes than
+    /// [`Service::max_attributes`](crate::config::Service::max_attributes).
+    ExceedsMaxNumberOfAttributes,
+    /// The service would exceed
+    /// [`Service::max_services`](crate::config::Service::max_services).
+    ExceedsMaxNumberOfServices,
+    /// The service was configured to route its lost samples into itself, see
+    /// [`Builder::dead_letter_service()`].
+    DeadLetterServiceRefersToItself,
 }
 
 impl std::fmt::Display for PublishSubscribeCreateError {
@@ -115,34 +135,56 @@ impl std::error::Error for PublishSubscribeOpenOrCreateError {}
 ///
 /// See [`crate::service`]
 #[derive(Debug)]
-pub struct Builder<PayloadType: Debug + ?Sized, ServiceType: service::Service> {
+pub struct Builder<
+    PayloadType: Debug + ?Sized,
+    ServiceType: service::Service,
+    UserHeader: Debug = (),
+> {
     base: builder::BuilderWithServiceType<ServiceType>,
     override_alignment: Option<usize>,
+    override_payload_type_name: Option<String>,
+    override_payload_layout: Option<(TypeVariant, usize, usize)>,
+    payload_field_layouts: Vec<FieldLayout>,
     verify_number_of_subscribers: bool,
+    verify_number_of_observers: bool,
     verify_number_of_publishers: bool,
     verify_subscriber_max_buffer_size: bool,
     verify_subscriber_max_borrowed_samples: bool,
     verify_publisher_history_size: bool,
     verify_enable_safe_overflow: bool,
+    verify_deadline: bool,
+    unknown_qos_profile: Option<String>,
     _data: PhantomData<PayloadType>,
+    _user_header: PhantomData<UserHeader>,
 }
 
-impl<PayloadType: Debug + ?Sized, ServiceType: service::Service> Builder<PayloadType, ServiceType> {
+impl<PayloadType: Debug + ?Sized, ServiceType: service::Service, UserHeader: Debug>
+    Builder<PayloadType, ServiceType, UserHeader>
+{
     pub(crate) fn new(base: builder::BuilderWithServiceType<ServiceType>) -> Self {
         let mut new_self = Self {
             base,
             verify_number_of_publishers: false,
             verify_number_of_subscribers: false,
+            verify_number_of_observers: false,
             verify_subscriber_max_buffer_size: false,
             verify_publisher_history_size: false,
             verify_subscriber_max_borrowed_samples: false,
             verify_enable_safe_overflow: false,
+            verify_deadline: false,
+            unknown_qos_profile: None,
             override_alignment: None,
+            override_payload_type_name: None,
+            override_payload_layout: None,
+            payload_field_layouts: vec![],
             _data: PhantomData,
+            _user_header: PhantomData,
         };
 
         new_self.base.service_config.messaging_pattern = MessagingPattern::PublishSubscribe(
-            static_config::publish_subscribe::StaticConfig::new(new_self.base.shared_node.config()),
+            Box::new(static_config::publish_subscribe::StaticConfig::new(
+                new_self.base.shared_node.config(),
+            )),
         );
 
         new_self
@@ -172,14 +214,14 @@ impl<PayloadType: Debug + ?Sized, ServiceType: service::Service> Builder<Payload
     ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
         match self.base.is_service_available() {
             Ok(Some((config, storage))) => {
-                if !self
+                if let Some(reason) = self
                     .config_details()
                     .type_details
-                    .is_compatible_to(&config.publish_subscribe().type_details)
+                    .incompatibility_to(&config.publish_subscribe().type_details)
                 {
                     fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
-                        "{} since the service offers the type \"{:?}\" which is not compatible to the requested type \"{:?}\".",
-                        error_msg, &config.publish_subscribe().type_details , self.config_details().type_details);
+                        "{} since the service offers the type \"{:?}\" which is not compatible to the requested type \"{:?}\", {}.",
+                        error_msg, &config.publish_subscribe().type_details , self.config_details().type_details, reason);
                 }
 
                 Ok(Some((config, storage)))
@@ -198,6 +240,85 @@ impl<PayloadType: Debug + ?Sized, ServiceType: service::Service> Builder<Payload
         self
     }
 
+    /// Overrides the payload type name that is stored in the [`Service`] and that must match
+    /// when an existing [`Service`] is opened. By default, [`core::any::type_name()`] of the
+    /// PayloadType is used. It allows participants written in other languages, like C or C++,
+    /// to use the same identifier for the same payload type.
+    pub fn payload_type_name(mut self, value: &str) -> Self {
+        self.override_payload_type_name = Some(value.to_string());
+        self
+    }
+
+    /// Defines the layout of every field of the payload type, see
+    /// [`TypeLayout`](iceoryx2_bb_elementary::type_layout::TypeLayout). If the [`Service`] is
+    /// created, the layout is stored in the [`Service`]. If an existing [`Service`] is opened
+    /// and both sides have defined a layout, the layouts must be equal. It allows to detect
+    /// ABI mismatches between the payload types of participants written in different languages.
+    pub fn payload_field_layouts(mut self, value: &[FieldLayout]) -> Self {
+        self.payload_field_layouts = value.to_vec();
+        self
+    }
+
+    /// Defines the user header type that is stored in front of the payload of every sample. It
+    /// can be used to transfer custom meta information like a timestamp alongside the payload
+    /// without copying. The user header is initialized with [`Default::default()`] when a sample
+    /// is loaned and accessible via
+    /// [`SampleMut::user_header_mut()`](crate::sample_mut::SampleMut::user_header_mut()) before
+    /// it is sent. If an existing [`Service`] is opened, the user header type must be equal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// #[derive(Debug, Default)]
+    /// #[repr(C)]
+    /// struct TimeStamp {
+    ///     nanoseconds: u64,
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .user_header::<TimeStamp>()
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = service.publisher_builder().create()?;
+    /// let subscriber = service.subscriber_builder().create()?;
+    ///
+    /// let mut sample = publisher.loan()?;
+    /// sample.user_header_mut().nanoseconds = 123456;
+    /// *sample.payload_mut() = 42;
+    /// sample.send()?;
+    ///
+    /// while let Some(sample) = subscriber.receive()? {
+    ///     println!("received {} at {}", *sample, sample.user_header().nanoseconds);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn user_header<M: Debug + Default>(self) -> Builder<PayloadType, ServiceType, M> {
+        Builder {
+            base: self.base,
+            override_alignment: self.override_alignment,
+            override_payload_type_name: self.override_payload_type_name,
+            override_payload_layout: self.override_payload_layout,
+            payload_field_layouts: self.payload_field_layouts,
+            verify_number_of_subscribers: self.verify_number_of_subscribers,
+            verify_number_of_observers: self.verify_number_of_observers,
+            verify_number_of_publishers: self.verify_number_of_publishers,
+            verify_subscriber_max_buffer_size: self.verify_subscriber_max_buffer_size,
+            verify_subscriber_max_borrowed_samples: self.verify_subscriber_max_borrowed_samples,
+            verify_publisher_history_size: self.verify_publisher_history_size,
+            verify_enable_safe_overflow: self.verify_enable_safe_overflow,
+            verify_deadline: self.verify_deadline,
+            unknown_qos_profile: self.unknown_qos_profile,
+            _data: PhantomData,
+            _user_header: PhantomData,
+        }
+    }
+
     /// If the [`Service`] is created, defines the overflow behavior of the service. If an existing
     /// [`Service`] is opened it requires the service to have the defined overflow behavior.
     pub fn enable_safe_overflow(mut self, value: bool) -> Self {
@@ -206,6 +327,141 @@ impl<PayloadType: Debug + ?Sized, ServiceType: service::Service> Builder<Payload
         self
     }
 
+    /// If the [`Service`] is created it defines the deadline of the service. When no
+    /// [`crate::port::publisher::Publisher`] sent a [`crate::sample::Sample`] within the
+    /// deadline, the deadline is missed and every [`crate::port::subscriber::Subscriber`] can
+    /// detect it with [`crate::port::subscriber::Subscriber::has_missed_deadline()`]. If an
+    /// existing [`Service`] is opened it requires the service to have the same deadline.
+    pub fn deadline(mut self, value: Duration) -> Self {
+        self.config_details_mut().deadline = Some(value);
+        self.verify_deadline = true;
+        self
+    }
+
+    /// Applies all publish-subscribe settings of the [`QosProfile`](crate::config::QosProfile)
+    /// with the provided name that is defined in
+    /// [`Config::qos_profiles`](crate::config::Config::qos_profiles). Every setting is applied
+    /// as if the corresponding builder method was called, settings that are called afterwards
+    /// override it. If no profile with the name exists, the creation or opening of the
+    /// [`Service`] fails.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::{Config, QosProfile};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut profile = QosProfile::default();
+    /// profile.subscriber_max_buffer_size = Some(16);
+    /// profile.publisher_history_size = Some(0);
+    ///
+    /// let mut config = Config::default();
+    /// config.qos_profiles.insert("sensor_high_rate".to_string(), profile);
+    ///
+    /// let node = NodeBuilder::new().config(&config).create::<zero_copy::Service>()?;
+    /// let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .qos_profile("sensor_high_rate")
+    ///     .open_or_create()?;
+    ///
+    /// assert_eq!(service.static_config().subscriber_max_buffer_size(), 16);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn qos_profile(mut self, name: &str) -> Self {
+        let profile = match self.base.shared_node.config().qos_profiles.get(name) {
+            Some(profile) => profile.clone(),
+            None => {
+                warn!(from self, "The QoS profile \"{}\" is not defined in the config.", name);
+                self.unknown_qos_profile = Some(name.to_string());
+                return self;
+            }
+        };
+
+        if let Some(value) = profile.max_subscribers {
+            self = self.max_subscribers(value);
+        }
+        if let Some(value) = profile.max_publishers {
+            self = self.max_publishers(value);
+        }
+        if let Some(value) = profile.subscriber_max_buffer_size {
+            self = self.subscriber_max_buffer_size(value);
+        }
+        if let Some(value) = profile.subscriber_max_borrowed_samples {
+            self = self.subscriber_max_borrowed_samples(value);
+        }
+        if let Some(value) = profile.publisher_history_size {
+            self = self.history_size(value);
+        }
+        if let Some(value) = profile.enable_safe_overflow {
+            self = self.enable_safe_overflow(value);
+        }
+        if let Some(value) = profile.deadline {
+            self = self.deadline(value);
+        }
+
+        self
+    }
+
+    /// If the [`Service`] is created, every [`crate::sample::Sample`] that could not be
+    /// delivered to a [`crate::port::subscriber::Subscriber`], since it was removed by an
+    /// overflow or discarded due to a full buffer, is routed into the publish-subscribe
+    /// service with the provided name and the payload type `[u8]`. Every routed sample
+    /// contains a [`DeadLetter`](crate::port::dead_letter::DeadLetter) and, when
+    /// `include_payload` is true, the payload of the lost sample. If an existing [`Service`]
+    /// is opened the setting of the existing [`Service`] is used.
+    ///
+    /// The dead-letter service must neither be the [`Service`] itself nor route its own lost
+    /// samples into another dead-letter service. All publishers of a
+    /// [`Node`](crate::node::Node) share one publisher of the dead-letter service.
+    pub fn dead_letter_service(mut self, name: ServiceName, include_payload: bool) -> Self {
+        self.config_details_mut().dead_letter_service = Some(name);
+        self.config_details_mut().dead_letter_includes_payload = include_payload;
+        self
+    }
+
+    /// If the [`Service`] is created, every [`crate::port::publisher::Publisher`] retains the
+    /// last `capacity` [`crate::sample::Sample`]s it has sent in a persistent flight recorder
+    /// that survives a crash of the process, see [`crate::port::flight_recorder`]. A capacity
+    /// of 0 disables the flight recorder. If an existing [`Service`] is opened the setting of
+    /// the existing [`Service`] is used.
+    pub fn flight_recorder(mut self, capacity: usize) -> Self {
+        self.config_details_mut().flight_recorder_capacity = capacity;
+        self
+    }
+
+    /// If the [`Service`] is created it defines the [`GroupDeliveryStrategy`] that selects the
+    /// member of a work-sharing group of [`crate::port::subscriber::Subscriber`]s that receives
+    /// a [`crate::sample::Sample`]. If an existing [`Service`] is opened the setting of the
+    /// existing [`Service`] is used.
+    pub fn subscriber_group_delivery(mut self, strategy: GroupDeliveryStrategy) -> Self {
+        self.config_details_mut().group_delivery_strategy = strategy;
+        self
+    }
+
+    /// If the [`Service`] is created it defines the [`ReceiveOrder`] in which a
+    /// [`crate::port::subscriber::Subscriber`] receives the [`crate::sample::Sample`]s of
+    /// multiple [`crate::port::publisher::Publisher`]s. If an existing [`Service`] is opened
+    /// the setting of the existing [`Service`] is used.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::port_factory::subscriber::ReceiveOrder;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .max_publishers(4)
+    ///     .subscriber_receive_order(ReceiveOrder::SendOrder)
+    ///     .open_or_create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subscriber_receive_order(mut self, order: ReceiveOrder) -> Self {
+        self.config_details_mut().receive_order = order;
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`crate::sample::Sample`] a
     /// [`crate::port::subscriber::Subscriber`] can borrow at most in parallel. If an existing
     /// [`Service`] is opened it defines the minimum required.
@@ -242,6 +498,28 @@ impl<PayloadType: Debug + ?Sized, ServiceType: service::Service> Builder<Payload
         self
     }
 
+    /// If the [`Service`] is created it defines how many observers, see
+    /// [`crate::service::port_factory::publish_subscribe::PortFactory::observer_builder()`], shall
+    /// be supported at most. Every [`crate::port::publisher::Publisher`] reserves the memory for
+    /// their samples when it is created, so that an attached observer never changes the
+    /// resources available to the [`crate::port::subscriber::Subscriber`]s. If an existing
+    /// [`Service`] is opened it defines how many observers must be at least supported.
+    pub fn max_observers(mut self, value: usize) -> Self {
+        self.config_details_mut().max_observers = value;
+        self.verify_number_of_observers = true;
+        self
+    }
+
+    /// Adds a key-value [`crate::service::attribute::Attribute`] to the [`Service`]. If the
+    /// [`Service`] is created the attribute is defined and can be discovered via
+    /// [`crate::service::Service::list()`]. If an existing [`Service`] is opened the attribute
+    /// is required, otherwise opening fails with an incompatible attributes error. A key is
+    /// allowed to have multiple values.
+    pub fn add_attribute(mut self, key: &str, value: &str) -> Self {
+        self.base.attributes.add(key, value);
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`crate::port::publisher::Publisher`] shall
     /// be supported at most. If an existing [`Service`] is opened it defines how many
     /// [`crate::port::publisher::Publisher`] must be at least supported.
@@ -278,6 +556,30 @@ impl<PayloadType: Debug + ?Sized, ServiceType: service::Service> Builder<Payload
                 "Setting the maximum amount of publishers to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_publishers = 1;
         }
+
+        if settings.deadline == Some(Duration::ZERO) {
+            warn!(from origin,
+                "Setting the deadline to 0 is not supported. Disable the deadline.");
+            settings.deadline = None;
+        }
+    }
+
+    fn warn_when_service_memory_budget_is_too_small(&self) {
+        let config = self.base.shared_node.config();
+        let budget = self.config_details().memory_budget;
+        if budget == 0 {
+            return;
+        }
+
+        let memory_breakdown = PublisherMemoryBreakdown::new(
+            self.config_details(),
+            &LocalPublisherConfig::new(config),
+        );
+        if memory_breakdown.data_segment_size() > budget {
+            warn!(from self,
+                "The service memory budget of {} bytes is too small for a single publisher with the default settings, its {}.",
+                budget, memory_breakdown);
+        }
     }
 
     fn verify_service_attributes(
@@ -288,6 +590,7 @@ impl<PayloadType: Debug + ?Sized, ServiceType: service::Service> Builder<Payload
         let msg = "Unable to open publish subscribe service";
 
         let existing_attributes = existing_settings.attributes();
+        let required_attributes = required_attributes.merged_with(&self.base.attributes);
         if let Err(incompatible_key) = required_attributes.verify_requirements(existing_attributes)
         {
             fail!(from self, with PublishSubscribeOpenError::IncompatibleAttributes,
@@ -320,6 +623,14 @@ impl<PayloadType: Debug + ?Sized, ServiceType: service::Service> Builder<Payload
                                 msg, existing_settings.max_subscribers, required_settings.max_subscribers);
         }
 
+        if self.verify_number_of_observers
+            && existing_settings.max_observers < required_settings.max_observers
+        {
+            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfObservers,
+                                "{} since the service supports only {} observers but a support of {} observers was requested.",
+                                msg, existing_settings.max_observers, required_settings.max_observers);
+        }
+
         if self.verify_subscriber_max_buffer_size
             && existing_settings.subscriber_max_buffer_size
                 < required_settings.subscriber_max_buffer_size
@@ -354,18 +665,31 @@ impl<PayloadType: Debug + ?Sized, ServiceType: service::Service> Builder<Payload
                                 msg);
         }
 
-        Ok(existing_settings.clone())
+        if self.verify_deadline && existing_settings.deadline != required_settings.deadline {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleDeadline,
+                                "{} since the service has the deadline {:?} but the deadline {:?} was requested.",
+                                msg, existing_settings.deadline, required_settings.deadline);
+        }
+
+        Ok((**existing_settings).clone())
     }
 
     fn create_impl(
         &mut self,
         attributes: &AttributeSpecifier,
-    ) -> Result<publish_subscribe::PortFactory<ServiceType, PayloadType>, PublishSubscribeCreateError>
-    {
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, PayloadType, UserHeader>,
+        PublishSubscribeCreateError,
+    > {
         self.adjust_attributes_to_meaningful_values();
 
         let msg = "Unable to create publish subscribe service";
 
+        if let Some(name) = &self.unknown_qos_profile {
+            fail!(from self, with PublishSubscribeCreateError::UnknownQosProfile,
+                "{} since the QoS profile \"{}\" is not defined in the config.", msg, name);
+        }
+
         if !self.config_details().enable_safe_overflow
             && (self.config_details().subscriber_max_buffer_size
                 < self.config_details().history_size)
@@ -374,8 +698,36 @@ impl<PayloadType: Debug + ?Sized, ServiceType: service::Service> Builder<Payload
                 "{} since the history size is greater than the subscriber buffer size. The subscriber buffer size must be always greater or equal to the history size in the non-overflowing setup.", msg);
         }
 
+        if self.config_details().dead_letter_service.as_ref()
+            == Some(self.base.service_config.name())
+        {
+            fail!(from self, with PublishSubscribeCreateError::DeadLetterServiceRefersToItself,
+                "{} since the service cannot be its own dead-letter service.", msg);
+        }
+
+        self.warn_when_service_memory_budget_is_too_small();
+
         match self.is_service_available(msg) {
             Ok(None) => {
+                if let Err(e) = self.base.verify_service_limits(attributes) {
+                    let error = match e {
+                        ServiceLimitViolation::NameTooLong => {
+                            PublishSubscribeCreateError::ServiceNameExceedsMaxLength
+                        }
+                        ServiceLimitViolation::TooManyAttributes => {
+                            PublishSubscribeCreateError::ExceedsMaxNumberOfAttributes
+                        }
+                        ServiceLimitViolation::TooManyServices => {
+                            PublishSubscribeCreateError::ExceedsMaxNumberOfServices
+                        }
+                        ServiceLimitViolation::UnableToListServices => {
+                            PublishSubscribeCreateError::InternalFailure
+                        }
+                    };
+                    fail!(from self, with error,
+                        "{} since the service would exceed the limits of the config.", msg);
+                }
+
                 // create static config
                 let static_config = match self.base.create_static_config_storage() {
                     Ok(c) => c,
@@ -399,6 +751,8 @@ impl<PayloadType: Debug + ?Sized, ServiceType: service::Service> Builder<Payload
                 let dynamic_config_setting = DynamicConfigSettings {
                     number_of_publishers: pubsub_config.max_publishers,
                     number_of_subscribers: pubsub_config.max_subscribers,
+                    number_of_observers: pubsub_config.max_observers,
+                    memory_budget: pubsub_config.memory_budget,
                 };
 
                 let dynamic_config = match self.base.create_dynamic_config_storage(
@@ -422,7 +776,9 @@ impl<PayloadType: Debug + ?Sized, ServiceType: service::Service> Builder<Payload
                     }
                 };
 
-                self.base.service_config.attributes = attributes.0.clone();
+                let mut attributes = attributes.0.clone();
+                attributes.merge(&self.base.attributes);
+                self.base.service_config.attributes = attributes;
                 let service_config = fail!(from self,
                             when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
                             with PublishSubscribeCreateError::Corrupted,
@@ -472,10 +828,17 @@ impl<PayloadType: Debug + ?Sized, ServiceType: service::Service> Builder<Payload
     fn open_impl(
         &mut self,
         attributes: &AttributeVerifier,
-    ) -> Result<publish_subscribe::PortFactory<ServiceType, PayloadType>, PublishSubscribeOpenError>
-    {
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, PayloadType, UserHeader>,
+        PublishSubscribeOpenError,
+    > {
         let msg = "Unable to open publish subscribe service";
 
+        if let Some(name) = &self.unknown_qos_profile {
+            fail!(from self, with PublishSubscribeOpenError::UnknownQosProfile,
+                "{} since the QoS profile \"{}\" is not defined in the config.", msg, name);
+        }
+
         let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
                                         with PublishSubscribeOpenError::InternalFailure,
                                         "{} since the adaptive wait could not be created.", msg);
@@ -497,7 +860,7 @@ impl<PayloadType: Debug + ?Sized, ServiceType: service::Service> Builder<Payload
                     );
 
                     self.base.service_config.messaging_pattern =
-                        MessagingPattern::PublishSubscribe(pub_sub_static_config.clone());
+                        MessagingPattern::PublishSubscribe(Box::new(pub_sub_static_config.clone()));
 
                     return Ok(publish_subscribe::PortFactory::new(
                         ServiceType::from_state(service::ServiceState::new(
@@ -551,11 +914,31 @@ impl<PayloadType: Debug + ?Sized, ServiceType: service::Service> Builder<Payload
         }
     }
 
+    fn open_with_timeout_impl(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, PayloadType, UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        let msg = "Unable to open publish subscribe service";
+
+        let does_exist = fail!(from self, when self.base.wait_for_service(timeout),
+                                with PublishSubscribeOpenError::InternalFailure,
+                                "{} since a failure occurred while waiting for the service.", msg);
+        if !does_exist {
+            fail!(from self, with PublishSubscribeOpenError::DoesNotExist,
+                "{} since the service was not created within the timeout of {:?}.", msg, timeout);
+        }
+
+        self.open_impl(&AttributeVerifier::new())
+    }
+
     fn open_or_create_impl(
         mut self,
         attributes: &AttributeVerifier,
     ) -> Result<
-        publish_subscribe::PortFactory<ServiceType, PayloadType>,
+        publish_subscribe::PortFactory<ServiceType, PayloadType, UserHeader>,
         PublishSubscribeOpenOrCreateError,
     > {
         let msg = "Unable to open or create publish subscribe service";
@@ -611,12 +994,36 @@ impl<PayloadType: Debug + ?Sized, ServiceType: service::Service> Builder<Payload
                 .max(alignment);
         }
     }
+
+    fn adjust_payload_type_details(&mut self) {
+        if let Some(name) = self.override_payload_type_name.clone() {
+            self.config_details_mut().type_details.payload_type_name = name;
+        }
+
+        if let Some((variant, size, alignment)) = self.override_payload_layout.clone() {
+            let type_details = &mut self.config_details_mut().type_details;
+            type_details.variant = variant;
+            type_details.payload_size = size;
+            type_details.payload_alignment = type_details.payload_alignment.max(alignment);
+        }
+
+        let payload_fields = self.payload_field_layouts.clone();
+        self.config_details_mut().type_details.payload_fields = payload_fields;
+        self.config_details_mut()
+            .type_details
+            .update_payload_type_hash();
+    }
 }
 
-impl<PayloadType: Debug, ServiceType: service::Service> Builder<PayloadType, ServiceType> {
+impl<PayloadType: Debug, ServiceType: service::Service, UserHeader: Debug>
+    Builder<PayloadType, ServiceType, UserHeader>
+{
     fn prepare_config_details(&mut self) {
         self.config_details_mut().type_details =
-            TypeDetails::from::<PayloadType, Header>(TypeVariant::FixedSize);
+            TypeDetails::from::<PayloadType, UserHeader, Header>(TypeVariant::FixedSize);
+        // the fingerprint is created before the alignment is increased, so that services with
+        // a different payload alignment remain compatible
+        self.adjust_payload_type_details();
         self.adjust_payload_alignment();
     }
 
@@ -625,7 +1032,7 @@ impl<PayloadType: Debug, ServiceType: service::Service> Builder<PayloadType, Ser
     pub fn open_or_create(
         self,
     ) -> Result<
-        publish_subscribe::PortFactory<ServiceType, PayloadType>,
+        publish_subscribe::PortFactory<ServiceType, PayloadType, UserHeader>,
         PublishSubscribeOpenOrCreateError,
     > {
         self.open_or_create_with_attributes(&AttributeVerifier::new())
@@ -639,7 +1046,7 @@ impl<PayloadType: Debug, ServiceType: service::Service> Builder<PayloadType, Ser
         mut self,
         required_attributes: &AttributeVerifier,
     ) -> Result<
-        publish_subscribe::PortFactory<ServiceType, PayloadType>,
+        publish_subscribe::PortFactory<ServiceType, PayloadType, UserHeader>,
         PublishSubscribeOpenOrCreateError,
     > {
         self.prepare_config_details();
@@ -649,18 +1056,36 @@ impl<PayloadType: Debug, ServiceType: service::Service> Builder<PayloadType, Ser
     /// Opens an existing [`Service`].
     pub fn open(
         self,
-    ) -> Result<publish_subscribe::PortFactory<ServiceType, PayloadType>, PublishSubscribeOpenError>
-    {
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, PayloadType, UserHeader>,
+        PublishSubscribeOpenError,
+    > {
         self.open_with_attributes(&AttributeVerifier::new())
     }
 
+    /// Opens an existing [`Service`]. If the [`Service`] does not exist yet, it waits until
+    /// another instance created it. Fails with [`PublishSubscribeOpenError::DoesNotExist`] when
+    /// the [`Service`] was not created within the provided timeout.
+    pub fn open_with_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, PayloadType, UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        self.prepare_config_details();
+        self.open_with_timeout_impl(timeout)
+    }
+
     /// Opens an existing [`Service`] with attribute requirements. If the defined attribute
     /// requirements are not satisfied the open process will fail.
     pub fn open_with_attributes(
         mut self,
         required_attributes: &AttributeVerifier,
-    ) -> Result<publish_subscribe::PortFactory<ServiceType, PayloadType>, PublishSubscribeOpenError>
-    {
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, PayloadType, UserHeader>,
+        PublishSubscribeOpenError,
+    > {
         self.prepare_config_details();
         self.open_impl(required_attributes)
     }
@@ -668,8 +1093,10 @@ impl<PayloadType: Debug, ServiceType: service::Service> Builder<PayloadType, Ser
     /// Creates a new [`Service`].
     pub fn create(
         self,
-    ) -> Result<publish_subscribe::PortFactory<ServiceType, PayloadType>, PublishSubscribeCreateError>
-    {
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, PayloadType, UserHeader>,
+        PublishSubscribeCreateError,
+    > {
         self.create_with_attributes(&AttributeSpecifier::new())
     }
 
@@ -677,17 +1104,85 @@ impl<PayloadType: Debug, ServiceType: service::Service> Builder<PayloadType, Ser
     pub fn create_with_attributes(
         mut self,
         attributes: &AttributeSpecifier,
-    ) -> Result<publish_subscribe::PortFactory<ServiceType, PayloadType>, PublishSubscribeCreateError>
-    {
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, PayloadType, UserHeader>,
+        PublishSubscribeCreateError,
+    > {
         self.prepare_config_details();
         self.create_impl(attributes)
     }
 }
 
-impl<PayloadType: Debug, ServiceType: service::Service> Builder<[PayloadType], ServiceType> {
+impl<PayloadType: Debug, ServiceType: service::Service, UserHeader: Debug>
+    Builder<[PayloadType], ServiceType, UserHeader>
+{
+    #[doc(hidden)]
+    /// Defines the variant, size and alignment of the payload type for language bindings
+    /// whose payload types are only known at runtime. The element type of the slice is then
+    /// only used as byte storage, every slice element must be exactly one byte large.
+    ///
+    /// # Safety
+    ///
+    ///  * the size and alignment must describe the payload type of all participants
+    pub unsafe fn __internal_set_payload_layout(
+        mut self,
+        variant: TypeVariant,
+        size: usize,
+        alignment: usize,
+    ) -> Self {
+        self.override_payload_layout = Some((variant, size, alignment));
+        self
+    }
+
+    /// Pads every slice element to a multiple of the cache line size by wrapping it into a
+    /// [`CacheLinePadded`]. Multiple threads can then process disjoint elements of one sample
+    /// without false sharing.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let service = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<[u64]>()
+    ///     .cache_line_padded_elements()
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = service.publisher_builder().max_slice_len(4).create()?;
+    /// let mut sample = publisher.loan_slice(4)?;
+    /// *sample.payload_mut()[2] = 42;
+    /// sample.send()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn cache_line_padded_elements(
+        self,
+    ) -> Builder<[CacheLinePadded<PayloadType>], ServiceType, UserHeader> {
+        Builder {
+            base: self.base,
+            override_alignment: self.override_alignment,
+            override_payload_type_name: self.override_payload_type_name,
+            override_payload_layout: self.override_payload_layout,
+            payload_field_layouts: self.payload_field_layouts,
+            verify_number_of_subscribers: self.verify_number_of_subscribers,
+            verify_number_of_observers: self.verify_number_of_observers,
+            verify_number_of_publishers: self.verify_number_of_publishers,
+            verify_subscriber_max_buffer_size: self.verify_subscriber_max_buffer_size,
+            verify_subscriber_max_borrowed_samples: self.verify_subscriber_max_borrowed_samples,
+            verify_publisher_history_size: self.verify_publisher_history_size,
+            verify_enable_safe_overflow: self.verify_enable_safe_overflow,
+            unknown_qos_profile: self.unknown_qos_profile,
+            _data: PhantomData,
+            _user_header: PhantomData,
+        }
+    }
+
     fn prepare_config_details(&mut self) {
         self.config_details_mut().type_details =
-            TypeDetails::from::<PayloadType, Header>(TypeVariant::Dynamic);
+            TypeDetails::from::<PayloadType, UserHeader, Header>(TypeVariant::Dynamic);
+        // the fingerprint is created before the alignment is increased, so that services with
+        // a different payload alignment remain compatible
+        self.adjust_payload_type_details();
         self.adjust_payload_alignment();
     }
 
@@ -696,7 +1191,7 @@ impl<PayloadType: Debug, ServiceType: service::Service> Builder<[PayloadType], S
     pub fn open_or_create(
         self,
     ) -> Result<
-        publish_subscribe::PortFactory<ServiceType, [PayloadType]>,
+        publish_subscribe::PortFactory<ServiceType, [PayloadType], UserHeader>,
         PublishSubscribeOpenOrCreateError,
     > {
         self.open_or_create_with_attributes(&AttributeVerifier::new())
@@ -710,7 +1205,7 @@ impl<PayloadType: Debug, ServiceType: service::Service> Builder<[PayloadType], S
         mut self,
         attributes: &AttributeVerifier,
     ) -> Result<
-        publish_subscribe::PortFactory<ServiceType, [PayloadType]>,
+        publish_subscribe::PortFactory<ServiceType, [PayloadType], UserHeader>,
         PublishSubscribeOpenOrCreateError,
     > {
         self.prepare_config_details();
@@ -720,18 +1215,36 @@ impl<PayloadType: Debug, ServiceType: service::Service> Builder<[PayloadType], S
     /// Opens an existing [`Service`].
     pub fn open(
         self,
-    ) -> Result<publish_subscribe::PortFactory<ServiceType, [PayloadType]>, PublishSubscribeOpenError>
-    {
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, [PayloadType], UserHeader>,
+        PublishSubscribeOpenError,
+    > {
         self.open_with_attributes(&AttributeVerifier::new())
     }
 
+    /// Opens an existing [`Service`]. If the [`Service`] does not exist yet, it waits until
+    /// another instance created it. Fails with [`PublishSubscribeOpenError::DoesNotExist`] when
+    /// the [`Service`] was not created within the provided timeout.
+    pub fn open_with_timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, [PayloadType], UserHeader>,
+        PublishSubscribeOpenError,
+    > {
+        self.prepare_config_details();
+        self.open_with_timeout_impl(timeout)
+    }
+
     /// Opens an existing [`Service`] with attribute requirements. If the defined attribute
     /// requirements are not satisfied the open process will fail.
     pub fn open_with_attributes(
         mut self,
         attributes: &AttributeVerifier,
-    ) -> Result<publish_subscribe::PortFactory<ServiceType, [PayloadType]>, PublishSubscribeOpenError>
-    {
+    ) -> Result<
+        publish_subscribe::PortFactory<ServiceType, [PayloadType], UserHeader>,
+        PublishSubscribeOpenError,
+    > {
         self.prepare_config_details();
         self.open_impl(attributes)
     }
@@ -740,7 +1253,7 @@ impl<PayloadType: Debug, ServiceType: service::Service> Builder<[PayloadType], S
     pub fn create(
         self,
     ) -> Result<
-        publish_subscribe::PortFactory<ServiceType, [PayloadType]>,
+        publish_subscribe::PortFactory<ServiceType, [PayloadType], UserHeader>,
         PublishSubscribeCreateError,
     > {
         self.create_with_attributes(&AttributeSpecifier::new())
@@ -751,7 +1264,7 @@ impl<PayloadType: Debug, ServiceType: service::Service> Builder<[PayloadType], S
         mut self,
         attributes: &AttributeSpecifier,
     ) -> Result<
-        publish_subscribe::PortFactory<ServiceType, [PayloadType]>,
+        publish_subscribe::PortFactory<ServiceType, [PayloadType], UserHeader>,
         PublishSubscribeCreateError,
     > {
         self.prepare_config_details();
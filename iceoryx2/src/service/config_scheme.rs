@@ -13,13 +13,16 @@
 use crate::config;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::event::Event;
 use iceoryx2_cal::named_concept::{NamedConceptConfiguration, NamedConceptMgmt};
+use iceoryx2_cal::shared_memory::SharedMemory;
+use iceoryx2_cal::shm_allocator::selectable_allocator::SelectableAllocator;
 
 pub(crate) fn dynamic_config_storage_config<Service: crate::service::Service>(
     global_config: &config::Config,
 ) -> <Service::DynamicStorage as NamedConceptMgmt>::Configuration {
     <<Service::DynamicStorage as NamedConceptMgmt>::Configuration>::default()
-        .prefix(global_config.global.prefix)
+        .prefix(global_config.global.domain_prefix())
         .suffix(global_config.global.service.dynamic_config_storage_suffix)
         .path_hint(global_config.global.root_path())
 }
@@ -35,7 +38,7 @@ pub(crate) fn static_config_storage_config<Service: crate::service::Service>(
             msg, path_hint, global_config.global.service.directory);
 
     <<Service::StaticStorage as NamedConceptMgmt>::Configuration>::default()
-        .prefix(global_config.global.prefix)
+        .prefix(global_config.global.domain_prefix())
         .suffix(global_config.global.service.static_config_storage_suffix)
         .path_hint(path_hint)
 }
@@ -44,7 +47,7 @@ pub(crate) fn connection_config<Service: crate::service::Service>(
     global_config: &config::Config,
 ) -> <Service::Connection as NamedConceptMgmt>::Configuration {
     <<Service::Connection as NamedConceptMgmt>::Configuration>::default()
-        .prefix(global_config.global.prefix)
+        .prefix(global_config.global.domain_prefix())
         .suffix(global_config.global.service.connection_suffix)
         .path_hint(global_config.global.root_path())
 }
@@ -52,17 +55,20 @@ pub(crate) fn connection_config<Service: crate::service::Service>(
 pub(crate) fn data_segment_config<Service: crate::service::Service>(
     global_config: &config::Config,
 ) -> <Service::SharedMemory as NamedConceptMgmt>::Configuration {
-    <<Service::SharedMemory as NamedConceptMgmt>::Configuration>::default()
-        .prefix(global_config.global.prefix)
-        .suffix(global_config.global.service.publisher_data_segment_suffix)
-        .path_hint(global_config.global.root_path())
+    <Service::SharedMemory as SharedMemory<SelectableAllocator>>::select_mechanism(
+        <<Service::SharedMemory as NamedConceptMgmt>::Configuration>::default()
+            .prefix(global_config.global.domain_prefix())
+            .suffix(global_config.global.service.publisher_data_segment_suffix)
+            .path_hint(global_config.global.root_path()),
+        global_config.global.service.shared_memory_mechanism,
+    )
 }
 
 pub(crate) fn node_monitoring_config<Service: crate::service::Service>(
     global_config: &config::Config,
 ) -> <Service::Monitoring as NamedConceptMgmt>::Configuration {
     <<Service::Monitoring as NamedConceptMgmt>::Configuration>::default()
-        .prefix(global_config.global.prefix)
+        .prefix(global_config.global.domain_prefix())
         .suffix(global_config.global.node.monitor_suffix)
         .path_hint(global_config.global.node_dir())
 }
@@ -83,7 +89,16 @@ pub(crate) fn node_details_config<Service: crate::service::Service>(
     monitor_name: &FileName,
 ) -> <Service::StaticStorage as NamedConceptMgmt>::Configuration {
     <<Service::StaticStorage as NamedConceptMgmt>::Configuration>::default()
-        .prefix(global_config.global.prefix)
+        .prefix(global_config.global.domain_prefix())
         .suffix(global_config.global.node.static_config_suffix)
         .path_hint(node_details_path(global_config, monitor_name))
 }
+
+pub(crate) fn event_config<Service: crate::service::Service>(
+    global_config: &config::Config,
+) -> <Service::Event as NamedConceptMgmt>::Configuration {
+    <Service::Event as Event>::select_mechanism(
+        <<Service::Event as NamedConceptMgmt>::Configuration>::default(),
+        global_config.global.service.event_mechanism,
+    )
+}
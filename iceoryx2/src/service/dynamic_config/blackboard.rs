@@ -0,0 +1,178 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let blackboard = node.service_builder("MyBlackboardName".try_into()?)
+//!     .blackboard::<u64>()
+//!     .add::<f32>(0, 1.5)
+//!     .open_or_create()?;
+//!
+//! println!("number of active readers:     {:?}", blackboard.dynamic_config().number_of_readers());
+//! println!("number of active writers:     {:?}", blackboard.dynamic_config().number_of_writers());
+//! # Ok(())
+//! # }
+//! ```
+use std::alloc::Layout;
+
+use iceoryx2_bb_elementary::allocator::BaseAllocator;
+use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary::relocatable_ptr::{PointerTrait, RelocatablePointer};
+use iceoryx2_bb_lock_free::mpmc::container::*;
+use iceoryx2_bb_log::{debug, fatal_panic};
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+
+use crate::port::port_identifiers::{UniqueReaderId, UniqueWriterId};
+
+use super::StalePortResource;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DynamicConfigSettings {
+    pub number_of_readers: usize,
+    pub number_of_writers: usize,
+    pub number_of_entries: usize,
+    pub entries_layout: Layout,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReaderDetails {
+    pub(crate) reader_id: UniqueReaderId,
+    pub(crate) node_id: UniqueSystemId,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WriterDetails {
+    pub(crate) writer_id: UniqueWriterId,
+    pub(crate) node_id: UniqueSystemId,
+}
+
+/// The dynamic configuration of an
+/// [`crate::service::messaging_pattern::MessagingPattern::Blackboard`]
+/// based service. Contains dynamic parameters like the connected endpoints etc..
+#[derive(Debug)]
+pub struct DynamicConfig {
+    pub(crate) readers: Container<ReaderDetails>,
+    pub(crate) writers: Container<WriterDetails>,
+    // the keys and values of all entries, they are stored in the same memory as the dynamic
+    // config so that every reader can access them without copying them
+    entries: RelocatablePointer<u8>,
+    entries_layout: Layout,
+    number_of_entries: usize,
+}
+
+impl DynamicConfig {
+    pub(crate) fn new(config: &DynamicConfigSettings) -> Self {
+        Self {
+            readers: unsafe { Container::new_uninit(config.number_of_readers) },
+            writers: unsafe { Container::new_uninit(config.number_of_writers) },
+            entries: unsafe { RelocatablePointer::new_uninit() },
+            entries_layout: config.entries_layout,
+            number_of_entries: config.number_of_entries,
+        }
+    }
+
+    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
+        fatal_panic!(from "blackboard::DynamicConfig::init",
+            when self.readers.init(allocator),
+            "This should never happen! Unable to initialize reader port id container.");
+        fatal_panic!(from "blackboard::DynamicConfig::init",
+            when self.writers.init(allocator),
+            "This should never happen! Unable to initialize writer port id container.");
+        let entries = fatal_panic!(from "blackboard::DynamicConfig::init",
+            when allocator.allocate(self.entries_layout),
+            "This should never happen! Unable to allocate the memory for the blackboard entries.");
+        self.entries.init(entries);
+    }
+
+    pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
+        Container::<ReaderDetails>::memory_size(config.number_of_readers)
+            + Container::<WriterDetails>::memory_size(config.number_of_writers)
+            + config.entries_layout.size()
+            + config.entries_layout.align()
+            - 1
+    }
+
+    /// Returns how many [`crate::port::reader::Reader`] ports are currently connected.
+    pub fn number_of_readers(&self) -> usize {
+        self.readers.len()
+    }
+
+    /// Returns how many [`crate::port::writer::Writer`] ports are currently connected.
+    pub fn number_of_writers(&self) -> usize {
+        self.writers.len()
+    }
+
+    pub(crate) fn entries_ptr(&self) -> *mut u8 {
+        unsafe { self.entries.as_ptr() as *mut u8 }
+    }
+
+    /// Returns the index of the entry with the provided key.
+    ///
+    /// # Safety
+    ///
+    ///  * the `KeyType` must be the key type the blackboard was created with
+    pub(crate) unsafe fn entry_index<KeyType: Eq>(&self, key: &KeyType) -> Option<usize> {
+        let keys = core::slice::from_raw_parts(
+            self.entries_ptr() as *const KeyType,
+            self.number_of_entries,
+        );
+        keys.iter().position(|k| k == key)
+    }
+
+    pub(crate) fn add_reader_id(&self, details: ReaderDetails) -> Option<ContainerHandle> {
+        unsafe { self.readers.add(details) }
+    }
+
+    pub(crate) fn release_reader_handle(&self, handle: ContainerHandle) {
+        unsafe { self.readers.remove(handle) }
+    }
+
+    pub(crate) fn add_writer_id(&self, details: WriterDetails) -> Option<ContainerHandle> {
+        unsafe { self.writers.add(details) }
+    }
+
+    pub(crate) fn release_writer_handle(&self, handle: ContainerHandle) {
+        unsafe { self.writers.remove(handle) }
+    }
+
+    pub(crate) fn remove_dead_node_id<F: FnMut(StalePortResource)>(
+        &self,
+        node_id: &UniqueSystemId,
+        _remove_resource: F,
+    ) {
+        let readers = unsafe { self.readers.get_state() };
+        readers.for_each(|index, reader| {
+            if reader.node_id == *node_id {
+                if let Some(handle) = readers.handle(index) {
+                    unsafe { self.readers.remove(handle) };
+                }
+                debug!(from self, "Removed reader {:?} of dead node {:?}.", reader.reader_id, node_id);
+            }
+        });
+
+        let writers = unsafe { self.writers.get_state() };
+        writers.for_each(|index, writer| {
+            if writer.node_id == *node_id {
+                if let Some(handle) = writers.handle(index) {
+                    unsafe { self.writers.remove(handle) };
+                }
+                debug!(from self, "Removed writer {:?} of dead node {:?}.", writer.writer_id, node_id);
+            }
+        });
+    }
+}
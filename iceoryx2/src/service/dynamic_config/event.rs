@@ -26,25 +26,55 @@
 //! # Ok(())
 //! # }
 //! ```
+use std::alloc::Layout;
+
+use iceoryx2_bb_elementary::allocator::BaseAllocator;
 use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary::relocatable_ptr::{PointerTrait, RelocatablePointer};
 use iceoryx2_bb_lock_free::mpmc::container::*;
-use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_log::{debug, fatal_panic};
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
 
 use crate::port::port_identifiers::{UniqueListenerId, UniqueNotifierId};
 
+use super::{ActivityTimestamp, StalePortResource};
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct DynamicConfigSettings {
     pub number_of_listeners: usize,
     pub number_of_notifiers: usize,
 }
 
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ListenerDetails {
+    pub(crate) listener_id: UniqueListenerId,
+    pub(crate) node_id: UniqueSystemId,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct NotifierDetails {
+    pub(crate) notifier_id: UniqueNotifierId,
+    pub(crate) node_id: UniqueSystemId,
+}
+
 /// The dynamic configuration of an [`crate::service::messaging_pattern::MessagingPattern::Event`]
 /// based service. Contains dynamic parameters like the connected endpoints etc..
 #[derive(Debug)]
 pub struct DynamicConfig {
-    pub(crate) listeners: Container<UniqueListenerId>,
-    pub(crate) notifiers: Container<UniqueNotifierId>,
+    pub(crate) listeners: Container<ListenerDetails>,
+    pub(crate) notifiers: Container<NotifierDetails>,
+    // initialized with the creation time of the service so that a deadline is also missed
+    // when no notifier ever notified
+    last_notification: ActivityTimestamp,
+    // notifications that were not delivered since they were coalesced by their notifier
+    coalesced_notifications: IoxAtomicU64,
+    // the last sign of life of every notifier, indexed like the notifiers
+    notifier_heartbeats: RelocatablePointer<ActivityTimestamp>,
+    number_of_notifiers: usize,
 }
 
 impl DynamicConfig {
@@ -52,6 +82,10 @@ impl DynamicConfig {
         Self {
             listeners: unsafe { Container::new_uninit(config.number_of_listeners) },
             notifiers: unsafe { Container::new_uninit(config.number_of_notifiers) },
+            last_notification: ActivityTimestamp::new(),
+            coalesced_notifications: IoxAtomicU64::new(0),
+            notifier_heartbeats: unsafe { RelocatablePointer::new_uninit() },
+            number_of_notifiers: config.number_of_notifiers,
         }
     }
 
@@ -62,11 +96,30 @@ impl DynamicConfig {
         fatal_panic!(from "event::DynamicConfig::init",
             when self.notifiers.init(allocator),
             "This should never happen! Unable to initialize notifier port id container.");
+        let heartbeats = fatal_panic!(from "event::DynamicConfig::init",
+            when allocator.allocate(Self::heartbeats_layout(self.number_of_notifiers)),
+            "This should never happen! Unable to allocate the notifier heartbeats.");
+        let timestamps = heartbeats.as_ptr() as *mut ActivityTimestamp;
+        for i in 0..self.number_of_notifiers {
+            timestamps.add(i).write(ActivityTimestamp::new());
+        }
+        self.notifier_heartbeats.init(heartbeats);
+    }
+
+    // the service supports at least one notifier, therefore the layout is never empty
+    fn heartbeats_layout(number_of_notifiers: usize) -> Layout {
+        fatal_panic!(from "event::DynamicConfig::heartbeats_layout",
+            when Layout::array::<ActivityTimestamp>(number_of_notifiers),
+            "This should never happen! Unable to acquire the layout of {} notifier heartbeats.",
+            number_of_notifiers)
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
-        Container::<UniqueListenerId>::memory_size(config.number_of_listeners)
-            + Container::<UniqueNotifierId>::memory_size(config.number_of_notifiers)
+        Container::<ListenerDetails>::memory_size(config.number_of_listeners)
+            + Container::<NotifierDetails>::memory_size(config.number_of_notifiers)
+            + Self::heartbeats_layout(config.number_of_notifiers).size()
+            + core::mem::align_of::<ActivityTimestamp>()
+            - 1
     }
 
     /// Returns the how many [`crate::port::listener::Listener`] ports are currently connected.
@@ -79,19 +132,88 @@ impl DynamicConfig {
         self.notifiers.len()
     }
 
-    pub(crate) fn add_listener_id(&self, id: UniqueListenerId) -> Option<ContainerHandle> {
-        unsafe { self.listeners.add(id) }
+    pub(crate) fn last_notification(&self) -> &ActivityTimestamp {
+        &self.last_notification
+    }
+
+    pub(crate) fn number_of_coalesced_notifications(&self) -> u64 {
+        self.coalesced_notifications.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn increment_coalesced_notifications(&self) {
+        self.coalesced_notifications.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_listener_id(&self, details: ListenerDetails) -> Option<ContainerHandle> {
+        unsafe { self.listeners.add(details) }
     }
 
     pub(crate) fn release_listener_handle(&self, handle: ContainerHandle) {
         unsafe { self.listeners.remove(handle) }
     }
 
-    pub(crate) fn add_notifier_id(&self, id: UniqueNotifierId) -> Option<ContainerHandle> {
-        unsafe { self.notifiers.add(id) }
+    pub(crate) fn add_notifier_id(&self, details: NotifierDetails) -> Option<ContainerHandle> {
+        let handle = unsafe { self.notifiers.add(details) }?;
+        // the heartbeat still contains the last sign of life of the previous notifier
+        self.update_notifier_heartbeat(handle.index());
+        Some(handle)
+    }
+
+    fn notifier_heartbeat(&self, index: u32) -> Option<&ActivityTimestamp> {
+        match (index as usize) < self.number_of_notifiers {
+            true => Some(unsafe { &*self.notifier_heartbeats.as_ptr().add(index as usize) }),
+            false => None,
+        }
+    }
+
+    /// Stores the current time as the last sign of life of the notifier at the provided index.
+    pub(crate) fn update_notifier_heartbeat(&self, index: u32) {
+        if let Some(heartbeat) = self.notifier_heartbeat(index) {
+            heartbeat.update();
+        }
+    }
+
+    /// Calls the provided callback for every notifier with the time that has passed since its
+    /// last sign of life.
+    pub(crate) fn for_each_notifier_heartbeat<F: FnMut(&NotifierDetails, Duration)>(
+        &self,
+        mut callback: F,
+    ) {
+        unsafe { self.notifiers.get_state() }.for_each(|index, details| {
+            if let Some(heartbeat) = self.notifier_heartbeat(index) {
+                callback(details, heartbeat.elapsed());
+            }
+        });
     }
 
     pub(crate) fn release_notifier_handle(&self, handle: ContainerHandle) {
         unsafe { self.notifiers.remove(handle) }
     }
+
+    pub(crate) fn remove_dead_node_id<F: FnMut(StalePortResource)>(
+        &self,
+        node_id: &UniqueSystemId,
+        mut remove_resource: F,
+    ) {
+        let listeners = unsafe { self.listeners.get_state() };
+        listeners.for_each(|index, listener| {
+            if listener.node_id == *node_id {
+                if let Some(handle) = listeners.handle(index) {
+                    unsafe { self.listeners.remove(handle) };
+                }
+                debug!(from self, "Removed listener {:?} of dead node {:?}.", listener.listener_id, node_id);
+                remove_resource(StalePortResource::Event(listener.listener_id));
+            }
+        });
+
+        let notifiers = unsafe { self.notifiers.get_state() };
+        notifiers.for_each(|index, notifier| {
+            if notifier.node_id == *node_id {
+                if let Some(handle) = notifiers.handle(index) {
+                    unsafe { self.notifiers.remove(handle) };
+                }
+                debug!(from self, "Removed notifier {:?} of dead node {:?}.", notifier.notifier_id, node_id);
+            }
+        });
+    }
 }
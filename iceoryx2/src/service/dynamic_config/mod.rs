@@ -10,6 +10,11 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+/// The dynamic service configuration of an
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based service.
+pub mod blackboard;
+
 /// The dynamic service configuration of an
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
 /// based service.
@@ -22,8 +27,14 @@ pub mod publish_subscribe;
 
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
-use std::{fmt::Display, sync::atomic::Ordering};
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64};
+use std::{fmt::Display, sync::atomic::Ordering, time::Duration};
+
+use crate::port::metrics::PublisherMetrics;
+use crate::port::port_identifiers::{UniqueListenerId, UniquePublisherId, UniqueSubscriberId};
+use crate::service::static_config::ServiceLifetime;
 
 const MARKED_FOR_DESTRUCTION: u64 = u64::MAX - 1;
 
@@ -33,10 +44,65 @@ pub(crate) enum DecrementReferenceCounterResult {
     NoMoreOwners,
 }
 
+/// A resource of a port that belonged to a dead [`crate::node::Node`] and must be removed since
+/// the port can no longer remove it by itself.
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub(crate) enum StalePortResource {
+    DataSegment(UniquePublisherId, usize),
+    Connection(UniquePublisherId, UniqueSubscriberId),
+    Event(UniqueListenerId),
+}
+
+/// The time of the last activity of a service, like the last notification or the last sent
+/// sample, that is used to detect a missed deadline. It is stored in the dynamic config and
+/// therefore the clock must be the same for all processes.
+#[derive(Debug)]
+pub(crate) struct ActivityTimestamp {
+    time_in_ns: IoxAtomicU64,
+}
+
+impl ActivityTimestamp {
+    pub(crate) fn new() -> Self {
+        Self {
+            time_in_ns: IoxAtomicU64::new(Self::now()),
+        }
+    }
+
+    /// Returns the current time of the clock that is shared by all processes.
+    pub(crate) fn now() -> u64 {
+        match Time::now_with_clock(ClockType::default()) {
+            Ok(time) => time.as_duration().as_nanos() as u64,
+            Err(e) => {
+                fatal_panic!(from "ActivityTimestamp::now()",
+                    "This should never happen! Unable to acquire the current time ({:?}).", e);
+            }
+        }
+    }
+
+    /// Returns the raw timestamp of the last activity.
+    pub(crate) fn value(&self) -> u64 {
+        self.time_in_ns.load(Ordering::Relaxed)
+    }
+
+    /// Stores the current time as the time of the last activity and returns the time that
+    /// has passed since the previous activity.
+    pub(crate) fn update(&self) -> Duration {
+        let now = Self::now();
+        let previous = self.time_in_ns.swap(now, Ordering::Relaxed);
+        Duration::from_nanos(now.saturating_sub(previous))
+    }
+
+    /// Returns the time that has passed since the last activity.
+    pub(crate) fn elapsed(&self) -> Duration {
+        Duration::from_nanos(Self::now().saturating_sub(self.value()))
+    }
+}
+
 #[derive(Debug)]
 pub(crate) enum MessagingPattern {
     PublishSubscribe(publish_subscribe::DynamicConfig),
     Event(event::DynamicConfig),
+    Blackboard(blackboard::DynamicConfig),
 }
 
 #[doc(hidden)]
@@ -44,6 +110,8 @@ pub(crate) enum MessagingPattern {
 pub struct DynamicConfig {
     messaging_pattern: MessagingPattern,
     reference_counter: IoxAtomicU64,
+    // a persistent service holds a reference to itself until it is explicitly removed
+    has_persistent_reference: IoxAtomicBool,
 }
 
 impl Display for DynamicConfig {
@@ -57,10 +125,15 @@ impl Display for DynamicConfig {
 }
 
 impl DynamicConfig {
-    pub(crate) fn new_uninit(messaging_pattern: MessagingPattern) -> Self {
+    pub(crate) fn new_uninit(
+        messaging_pattern: MessagingPattern,
+        lifetime: ServiceLifetime,
+    ) -> Self {
+        let is_persistent = lifetime == ServiceLifetime::Persistent;
         Self {
             messaging_pattern,
-            reference_counter: IoxAtomicU64::new(1),
+            reference_counter: IoxAtomicU64::new(1 + is_persistent as u64),
+            has_persistent_reference: IoxAtomicBool::new(is_persistent),
         }
     }
 
@@ -68,6 +141,7 @@ impl DynamicConfig {
         match &self.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => v.init(allocator),
             MessagingPattern::Event(ref v) => v.init(allocator),
+            MessagingPattern::Blackboard(ref v) => v.init(allocator),
         }
     }
 
@@ -118,6 +192,44 @@ impl DynamicConfig {
         result
     }
 
+    /// Releases the reference a persistent service holds to itself. Returns [`None`] when the
+    /// service is not persistent or the reference was already released.
+    pub(crate) fn release_persistent_reference(&self) -> Option<DecrementReferenceCounterResult> {
+        if self.has_persistent_reference.swap(false, Ordering::Relaxed) {
+            Some(self.decrement_reference_counter())
+        } else {
+            None
+        }
+    }
+
+    /// Removes all ports that are owned by the dead node with the provided id and calls
+    /// `remove_resource` for every resource those ports left behind.
+    pub(crate) fn remove_dead_node_id<F: FnMut(StalePortResource)>(
+        &self,
+        node_id: &UniqueSystemId,
+        remove_resource: F,
+    ) {
+        match &self.messaging_pattern {
+            MessagingPattern::PublishSubscribe(ref v) => {
+                v.remove_dead_node_id(node_id, remove_resource)
+            }
+            MessagingPattern::Event(ref v) => v.remove_dead_node_id(node_id, remove_resource),
+            MessagingPattern::Blackboard(ref v) => v.remove_dead_node_id(node_id, remove_resource),
+        }
+    }
+
+    /// Returns the [`PublisherMetrics`] of all publishers that belong to the node with the
+    /// provided id. Services without publishers return an empty list.
+    pub(crate) fn publisher_metrics_of_node(
+        &self,
+        node_id: &UniqueSystemId,
+    ) -> Vec<PublisherMetrics> {
+        match &self.messaging_pattern {
+            MessagingPattern::PublishSubscribe(ref v) => v.publisher_metrics_of_node(node_id),
+            MessagingPattern::Event(_) | MessagingPattern::Blackboard(_) => vec![],
+        }
+    }
+
     pub(crate) fn publish_subscribe(&self) -> &publish_subscribe::DynamicConfig {
         match &self.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => v,
@@ -135,4 +247,13 @@ impl DynamicConfig {
             }
         }
     }
+
+    pub(crate) fn blackboard(&self) -> &blackboard::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Try to access blackboard::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
 }
@@ -23,33 +23,79 @@
 //!
 //! println!("number of active publishers:      {:?}", pubsub.dynamic_config().number_of_publishers());
 //! println!("number of active subscribers:     {:?}", pubsub.dynamic_config().number_of_subscribers());
+//! println!("number of active observers:       {:?}", pubsub.dynamic_config().number_of_observers());
 //! # Ok(())
 //! # }
 //! ```
+use std::alloc::Layout;
+
+use iceoryx2_bb_elementary::allocator::BaseAllocator;
 use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary::relocatable_ptr::{PointerTrait, RelocatablePointer};
 use iceoryx2_bb_lock_free::mpmc::container::*;
-use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_log::{debug, fatal_panic};
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicUsize};
+use std::sync::atomic::Ordering;
 
+use crate::port::metrics::{ConnectionMetrics, PublisherMetrics};
 use crate::port::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+use crate::service::port_factory::subscriber::{Decimation, OverflowStrategy};
+
+use super::{ActivityTimestamp, StalePortResource};
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct DynamicConfigSettings {
     pub number_of_subscribers: usize,
+    pub number_of_observers: usize,
     pub number_of_publishers: usize,
+    pub memory_budget: usize,
+}
+
+/// The failures that can occur when a publisher acquires its slot in the [`DynamicConfig`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PublisherSlotError {
+    ExceedsMaxNumberOfPublishers,
+    ExceedsMemoryBudget,
 }
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct PublisherDetails {
     pub(crate) publisher_id: UniquePublisherId,
+    pub(crate) node_id: UniqueSystemId,
     pub(crate) number_of_samples: usize,
-    pub(crate) max_slice_len: usize,
+    pub(crate) chunk_size: usize,
+    pub(crate) data_segment_size: usize,
+    // the maximum number of segments the data segment of the publisher can grow to
+    pub(crate) number_of_segments: usize,
+    // the number of samples the first segment was dimensioned for
+    pub(crate) sample_capacity: usize,
 }
 
+/// The counters of a publisher that are stored in the dynamic config, see
+/// [`crate::port::metrics::PublisherMetrics`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PublisherMetric {
+    SentSamples,
+    DroppedSamples,
+    LoanFailures,
+    SamplesInUse,
+    // the bytes of the segments the data segment of the publisher grew by, they are accounted
+    // to the memory budget of the service in addition to the first segment
+    AdditionalSegmentBytes,
+}
+
+const NUMBER_OF_PUBLISHER_METRICS: usize = 5;
+
 #[derive(Debug, Copy, Clone)]
 pub(crate) struct SubscriberDetails {
     pub(crate) port_id: UniqueSubscriberId,
+    pub(crate) node_id: UniqueSystemId,
     pub(crate) buffer_size: usize,
+    pub(crate) group: Option<u64>,
+    pub(crate) decimation: Decimation,
+    pub(crate) overflow_strategy: Option<OverflowStrategy>,
 }
 
 /// The dynamic configuration of an [`crate::service::messaging_pattern::MessagingPattern::Event`]
@@ -57,14 +103,45 @@ pub(crate) struct SubscriberDetails {
 #[derive(Debug)]
 pub struct DynamicConfig {
     pub(crate) subscribers: Container<SubscriberDetails>,
+    pub(crate) observers: Container<SubscriberDetails>,
     pub(crate) publishers: Container<PublisherDetails>,
+    // the sequence number of the next sample that is sent by any publisher of the service
+    next_sequence_number: IoxAtomicU64,
+    // initialized with the creation time of the service so that a deadline is also missed
+    // when no publisher ever sent a sample
+    last_send: ActivityTimestamp,
+    // the number of samples that were dropped for a subscriber, indexed like the subscribers
+    dropped_samples: RelocatablePointer<IoxAtomicU64>,
+    // the metrics of every publisher, indexed like the publishers, followed by the samples the
+    // publisher dropped for every subscriber, indexed like the subscribers
+    publisher_metrics: RelocatablePointer<IoxAtomicU64>,
+    // the number of subscribers that wait for samples with a sample arrival listener, the
+    // publishers notify the sample arrival service only when it is not zero
+    number_of_sample_arrival_listeners: IoxAtomicUsize,
+    // the sum of the data segment sizes of all publishers, it is accounted together with the
+    // acquisition of the publisher slot or of an additional segment so that concurrently
+    // created publishers and segments cannot exceed the memory budget
+    used_memory: IoxAtomicUsize,
+    memory_budget: usize,
+    number_of_subscribers: usize,
+    number_of_publishers: usize,
 }
 
 impl DynamicConfig {
     pub(crate) fn new(config: &DynamicConfigSettings) -> Self {
         Self {
             subscribers: unsafe { Container::new_uninit(config.number_of_subscribers) },
+            observers: unsafe { Container::new_uninit(Self::observer_capacity(config)) },
             publishers: unsafe { Container::new_uninit(config.number_of_publishers) },
+            next_sequence_number: IoxAtomicU64::new(0),
+            last_send: ActivityTimestamp::new(),
+            dropped_samples: unsafe { RelocatablePointer::new_uninit() },
+            publisher_metrics: unsafe { RelocatablePointer::new_uninit() },
+            number_of_sample_arrival_listeners: IoxAtomicUsize::new(0),
+            used_memory: IoxAtomicUsize::new(0),
+            memory_budget: config.memory_budget,
+            number_of_subscribers: config.number_of_subscribers,
+            number_of_publishers: config.number_of_publishers,
         }
     }
 
@@ -72,14 +149,73 @@ impl DynamicConfig {
         fatal_panic!(from "publish_subscribe::DynamicConfig::init",
             when self.subscribers.init(allocator),
             "This should never happen! Unable to initialize subscriber port id container.");
+        fatal_panic!(from "publish_subscribe::DynamicConfig::init",
+            when self.observers.init(allocator),
+            "This should never happen! Unable to initialize observer port id container.");
         fatal_panic!(from "publish_subscribe::DynamicConfig::init",
             when self.publishers.init(allocator),
             "This should never happen! Unable to initialize publisher port id container.");
+        self.dropped_samples.init(Self::allocate_counters(
+            allocator,
+            self.number_of_subscribers,
+        ));
+        self.publisher_metrics.init(Self::allocate_counters(
+            allocator,
+            Self::number_of_publisher_counters(
+                self.number_of_publishers,
+                self.number_of_subscribers,
+            ),
+        ));
+    }
+
+    unsafe fn allocate_counters(
+        allocator: &BumpAllocator,
+        number_of_counters: usize,
+    ) -> core::ptr::NonNull<[u8]> {
+        let memory = fatal_panic!(from "publish_subscribe::DynamicConfig::init",
+            when allocator.allocate(Self::counters_layout(number_of_counters)),
+            "This should never happen! Unable to allocate {} counters.", number_of_counters);
+        let counters = memory.as_ptr() as *mut IoxAtomicU64;
+        for i in 0..number_of_counters {
+            counters.add(i).write(IoxAtomicU64::new(0));
+        }
+        memory
+    }
+
+    // the service supports at least one subscriber and one publisher, therefore the layout is
+    // never empty
+    fn counters_layout(number_of_counters: usize) -> Layout {
+        fatal_panic!(from "publish_subscribe::DynamicConfig::counters_layout",
+            when Layout::array::<IoxAtomicU64>(number_of_counters),
+            "This should never happen! Unable to acquire the layout of {} counters.",
+            number_of_counters)
+    }
+
+    fn number_of_publisher_counters(
+        number_of_publishers: usize,
+        number_of_subscribers: usize,
+    ) -> usize {
+        number_of_publishers * (NUMBER_OF_PUBLISHER_METRICS + number_of_subscribers)
+    }
+
+    // a service without observers still has a container of capacity one, the observer limit
+    // is enforced when the observer is created
+    fn observer_capacity(config: &DynamicConfigSettings) -> usize {
+        config.number_of_observers.max(1)
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<SubscriberDetails>::memory_size(config.number_of_subscribers)
+            + Container::<SubscriberDetails>::memory_size(Self::observer_capacity(config))
             + Container::<PublisherDetails>::memory_size(config.number_of_publishers)
+            + Self::counters_layout(config.number_of_subscribers).size()
+            + Self::counters_layout(Self::number_of_publisher_counters(
+                config.number_of_publishers,
+                config.number_of_subscribers,
+            ))
+            .size()
+            + core::mem::align_of::<IoxAtomicU64>()
+            - 1
     }
 
     /// Returns how many [`crate::port::publisher::Publisher`] ports are currently connected.
@@ -92,19 +228,427 @@ impl DynamicConfig {
         self.subscribers.len()
     }
 
+    /// Returns how many observers, passive [`crate::port::subscriber::Subscriber`] ports that
+    /// are not counted in [`DynamicConfig::number_of_subscribers()`], are currently connected.
+    pub fn number_of_observers(&self) -> usize {
+        self.observers.len()
+    }
+
+    /// Returns the amount of shared memory in bytes that the data segments of all connected
+    /// [`crate::port::publisher::Publisher`]s of the service consume together. It is the
+    /// amount that is limited by the service memory budget, see
+    /// [`crate::config::PublishSubscribe::service_memory_budget`].
+    pub fn shared_memory_bytes(&self) -> usize {
+        self.used_memory.load(Ordering::Relaxed)
+    }
+
+    /// Returns the amount of shared memory in bytes that the data segments of the connected
+    /// [`crate::port::publisher::Publisher`]s of the [`crate::node::Node`] with the provided
+    /// id consume in the service. It is the share of the node in
+    /// [`DynamicConfig::shared_memory_bytes()`].
+    pub fn shared_memory_bytes_of_node(&self, node_id: &UniqueSystemId) -> usize {
+        let mut used_memory = 0;
+        unsafe { self.publishers.get_state() }.for_each(|index, details| {
+            if details.node_id == *node_id {
+                used_memory += self.memory_of_publisher(index, details);
+            }
+        });
+        used_memory
+    }
+
+    /// Returns the ids of all connected [`crate::port::publisher::Publisher`] ports together
+    /// with the ids of their nodes.
+    pub(crate) fn publisher_ids(&self) -> Vec<(UniquePublisherId, UniqueSystemId)> {
+        let mut ids = vec![];
+        unsafe { self.publishers.get_state() }.for_each(|_, details| {
+            ids.push((details.publisher_id, details.node_id));
+        });
+        ids
+    }
+
+    /// Returns the ids of all connected [`crate::port::publisher::Publisher`] ports together
+    /// with the ids of their nodes and the shared memory in bytes their data segments consume,
+    /// the consumers of [`DynamicConfig::shared_memory_bytes()`].
+    pub(crate) fn publisher_memory_usage(&self) -> Vec<(UniquePublisherId, UniqueSystemId, usize)> {
+        let mut usage = vec![];
+        unsafe { self.publishers.get_state() }.for_each(|index, details| {
+            usage.push((
+                details.publisher_id,
+                details.node_id,
+                self.memory_of_publisher(index, details),
+            ));
+        });
+        usage
+    }
+
+    /// Returns the ids of all connected [`crate::port::subscriber::Subscriber`] ports, observers
+    /// excluded, together with the ids of their nodes.
+    pub(crate) fn subscriber_ids(&self) -> Vec<(UniqueSubscriberId, UniqueSystemId)> {
+        let mut ids = vec![];
+        unsafe { self.subscribers.get_state() }.for_each(|_, details| {
+            ids.push((details.port_id, details.node_id));
+        });
+        ids
+    }
+
+    /// Returns the sequence number the next sample that is sent by any
+    /// [`crate::port::publisher::Publisher`] of the service will have.
+    pub fn next_sequence_number(&self) -> u64 {
+        self.next_sequence_number.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn acquire_sequence_number(&self) -> u64 {
+        self.next_sequence_number.fetch_add(1, Ordering::Relaxed)
+    }
+
+    pub(crate) fn last_send(&self) -> &ActivityTimestamp {
+        &self.last_send
+    }
+
+    fn dropped_samples_of(&self, index: u32) -> Option<&IoxAtomicU64> {
+        match (index as usize) < self.number_of_subscribers {
+            true => Some(unsafe { &*self.dropped_samples.as_ptr().add(index as usize) }),
+            false => None,
+        }
+    }
+
+    /// Returns how many samples were dropped for the subscriber at the provided index.
+    pub(crate) fn number_of_dropped_samples(&self, index: u32) -> u64 {
+        match self.dropped_samples_of(index) {
+            Some(counter) => counter.load(Ordering::Relaxed),
+            None => 0,
+        }
+    }
+
+    pub(crate) fn add_dropped_sample(&self, index: u32) {
+        if let Some(counter) = self.dropped_samples_of(index) {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    // the counters are reset before the index is released so that the next subscriber at this
+    // index starts with zero dropped samples
+    fn reset_dropped_samples(&self, index: u32) {
+        if let Some(counter) = self.dropped_samples_of(index) {
+            counter.store(0, Ordering::Relaxed);
+        }
+        for publisher_index in 0..self.number_of_publishers as u32 {
+            if let Some(counter) = self.dropped_samples_of_connection(publisher_index, index) {
+                counter.store(0, Ordering::Relaxed);
+            }
+        }
+    }
+
+    fn publisher_counter(&self, publisher_index: u32, counter: usize) -> Option<&IoxAtomicU64> {
+        let stride = NUMBER_OF_PUBLISHER_METRICS + self.number_of_subscribers;
+        match (publisher_index as usize) < self.number_of_publishers && counter < stride {
+            true => Some(unsafe {
+                &*self
+                    .publisher_metrics
+                    .as_ptr()
+                    .add(publisher_index as usize * stride + counter)
+            }),
+            false => None,
+        }
+    }
+
+    fn dropped_samples_of_connection(
+        &self,
+        publisher_index: u32,
+        subscriber_index: u32,
+    ) -> Option<&IoxAtomicU64> {
+        match (subscriber_index as usize) < self.number_of_subscribers {
+            true => self.publisher_counter(
+                publisher_index,
+                NUMBER_OF_PUBLISHER_METRICS + subscriber_index as usize,
+            ),
+            false => None,
+        }
+    }
+
+    pub(crate) fn add_publisher_metric(
+        &self,
+        publisher_index: u32,
+        metric: PublisherMetric,
+        value: u64,
+    ) {
+        if let Some(counter) = self.publisher_counter(publisher_index, metric as usize) {
+            counter.fetch_add(value, Ordering::Relaxed);
+        }
+    }
+
+    // a sample that was loaned before the counters were reset must not underflow the counter
+    pub(crate) fn remove_publisher_metric(
+        &self,
+        publisher_index: u32,
+        metric: PublisherMetric,
+        value: u64,
+    ) {
+        if let Some(counter) = self.publisher_counter(publisher_index, metric as usize) {
+            let _ = counter.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |v| {
+                Some(v.saturating_sub(value))
+            });
+        }
+    }
+
+    /// Counts a dropped sample of the connection from the publisher to the subscriber at the
+    /// provided indices.
+    pub(crate) fn add_dropped_sample_of_connection(
+        &self,
+        publisher_index: u32,
+        subscriber_index: u32,
+    ) {
+        if let Some(counter) = self.dropped_samples_of_connection(publisher_index, subscriber_index)
+        {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn publisher_metric(&self, publisher_index: u32, metric: PublisherMetric) -> u64 {
+        match self.publisher_counter(publisher_index, metric as usize) {
+            Some(counter) => counter.load(Ordering::Relaxed),
+            None => 0,
+        }
+    }
+
+    // the counters are reset before the index is released so that the next publisher at this
+    // index starts with empty metrics
+    fn reset_publisher_metrics(&self, publisher_index: u32) {
+        for n in 0..NUMBER_OF_PUBLISHER_METRICS + self.number_of_subscribers {
+            if let Some(counter) = self.publisher_counter(publisher_index, n) {
+                counter.store(0, Ordering::Relaxed);
+            }
+        }
+    }
+
+    fn metrics_of_publisher(&self, index: u32, details: &PublisherDetails) -> PublisherMetrics {
+        let mut connections = vec![];
+        unsafe { self.subscribers.get_state() }.for_each(|subscriber_index, subscriber| {
+            connections.push(ConnectionMetrics::new(
+                subscriber.port_id,
+                self.dropped_samples_of_connection(index, subscriber_index)
+                    .map_or(0, |counter| counter.load(Ordering::Relaxed)),
+            ));
+        });
+
+        PublisherMetrics {
+            publisher_id: details.publisher_id,
+            sent_samples: self.publisher_metric(index, PublisherMetric::SentSamples),
+            dropped_samples: self.publisher_metric(index, PublisherMetric::DroppedSamples),
+            loan_failures: self.publisher_metric(index, PublisherMetric::LoanFailures),
+            samples_in_use: self.publisher_metric(index, PublisherMetric::SamplesInUse) as usize,
+            sample_capacity: details.sample_capacity,
+            connections,
+        }
+    }
+
+    /// Returns the [`PublisherMetrics`] of the publisher with the provided id.
+    pub(crate) fn publisher_metrics(
+        &self,
+        publisher_id: UniquePublisherId,
+    ) -> Option<PublisherMetrics> {
+        let mut metrics = None;
+        unsafe { self.publishers.get_state() }.for_each(|index, details| {
+            if details.publisher_id == publisher_id {
+                metrics = Some(self.metrics_of_publisher(index, details));
+            }
+        });
+        metrics
+    }
+
+    /// Returns the [`PublisherMetrics`] of all publishers that belong to the node with the
+    /// provided id.
+    pub(crate) fn publisher_metrics_of_node(
+        &self,
+        node_id: &UniqueSystemId,
+    ) -> Vec<PublisherMetrics> {
+        let mut metrics = vec![];
+        unsafe { self.publishers.get_state() }.for_each(|index, details| {
+            if details.node_id == *node_id {
+                metrics.push(self.metrics_of_publisher(index, details));
+            }
+        });
+        metrics
+    }
+
+    // SeqCst, since the publisher delivers before it checks for listeners while the subscriber
+    // registers its listener before it checks for samples, one of them sees the other
+    pub(crate) fn add_sample_arrival_listener(&self) {
+        self.number_of_sample_arrival_listeners
+            .fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub(crate) fn remove_sample_arrival_listener(&self) {
+        self.number_of_sample_arrival_listeners
+            .fetch_sub(1, Ordering::SeqCst);
+    }
+
+    pub(crate) fn has_sample_arrival_listeners(&self) -> bool {
+        self.number_of_sample_arrival_listeners
+            .load(Ordering::SeqCst)
+            > 0
+    }
+
     pub(crate) fn add_subscriber_id(&self, details: SubscriberDetails) -> Option<ContainerHandle> {
         unsafe { self.subscribers.add(details) }
     }
 
     pub(crate) fn release_subscriber_handle(&self, handle: ContainerHandle) {
+        self.reset_dropped_samples(handle.index());
         unsafe { self.subscribers.remove(handle) }
     }
 
-    pub(crate) fn add_publisher_id(&self, details: PublisherDetails) -> Option<ContainerHandle> {
-        unsafe { self.publishers.add(details) }
+    pub(crate) fn add_observer_id(&self, details: SubscriberDetails) -> Option<ContainerHandle> {
+        unsafe { self.observers.add(details) }
+    }
+
+    pub(crate) fn release_observer_handle(&self, handle: ContainerHandle) {
+        unsafe { self.observers.remove(handle) }
+    }
+
+    // the first segment of the data segment plus all segments it grew by
+    fn memory_of_publisher(&self, index: u32, details: &PublisherDetails) -> usize {
+        details.data_segment_size
+            + self.publisher_metric(index, PublisherMetric::AdditionalSegmentBytes) as usize
+    }
+
+    fn reserve_memory(&self, size: usize) -> Result<(), PublisherSlotError> {
+        match self
+            .used_memory
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                match used.checked_add(size) {
+                    Some(v) if self.memory_budget == 0 || v <= self.memory_budget => Some(v),
+                    _ => None,
+                }
+            }) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(PublisherSlotError::ExceedsMemoryBudget),
+        }
+    }
+
+    /// Acquires the slot of the publisher and accounts its data segment to the memory budget
+    /// of the service in one step.
+    pub(crate) fn add_publisher_id(
+        &self,
+        details: PublisherDetails,
+    ) -> Result<ContainerHandle, PublisherSlotError> {
+        let size = details.data_segment_size;
+        self.reserve_memory(size)?;
+
+        match unsafe { self.publishers.add(details) } {
+            Some(handle) => Ok(handle),
+            None => {
+                self.used_memory.fetch_sub(size, Ordering::Relaxed);
+                Err(PublisherSlotError::ExceedsMaxNumberOfPublishers)
+            }
+        }
+    }
+
+    /// Accounts an additional segment of the data segment of the publisher at the provided
+    /// index to the memory budget of the service. It is released together with the publisher
+    /// handle or with [`DynamicConfig::release_additional_segment()`].
+    pub(crate) fn reserve_additional_segment(
+        &self,
+        publisher_index: u32,
+        size: usize,
+    ) -> Result<(), PublisherSlotError> {
+        self.reserve_memory(size)?;
+        self.add_publisher_metric(
+            publisher_index,
+            PublisherMetric::AdditionalSegmentBytes,
+            size as u64,
+        );
+        Ok(())
+    }
+
+    pub(crate) fn release_additional_segment(&self, publisher_index: u32, size: usize) {
+        self.remove_publisher_metric(
+            publisher_index,
+            PublisherMetric::AdditionalSegmentBytes,
+            size as u64,
+        );
+        self.used_memory.fetch_sub(size, Ordering::Relaxed);
     }
 
     pub(crate) fn release_publisher_handle(&self, handle: ContainerHandle) {
-        unsafe { self.publishers.remove(handle) }
+        let mut used_memory = 0;
+        unsafe { self.publishers.get_state() }.for_each(|index, details| {
+            if index == handle.index() {
+                used_memory = self.memory_of_publisher(index, details);
+            }
+        });
+
+        self.reset_publisher_metrics(handle.index());
+        unsafe { self.publishers.remove(handle) };
+        self.used_memory.fetch_sub(used_memory, Ordering::Relaxed);
+    }
+
+    pub(crate) fn remove_dead_node_id<F: FnMut(StalePortResource)>(
+        &self,
+        node_id: &UniqueSystemId,
+        mut remove_resource: F,
+    ) {
+        let publishers = unsafe { self.publishers.get_state() };
+        let subscriber_lists = unsafe {
+            [
+                (&self.subscribers, self.subscribers.get_state()),
+                (&self.observers, self.observers.get_state()),
+            ]
+        };
+
+        publishers.for_each(|index, publisher| {
+            if publisher.node_id != *node_id {
+                return;
+            }
+
+            if let Some(handle) = publishers.handle(index) {
+                let used_memory = self.memory_of_publisher(index, publisher);
+                self.reset_publisher_metrics(index);
+                unsafe { self.publishers.remove(handle) };
+                self.used_memory.fetch_sub(used_memory, Ordering::Relaxed);
+            }
+            debug!(from self, "Removed publisher {:?} of dead node {:?}.", publisher.publisher_id, node_id);
+
+            for segment_id in 0..publisher.number_of_segments {
+                remove_resource(StalePortResource::DataSegment(
+                    publisher.publisher_id,
+                    segment_id,
+                ));
+            }
+            for (_, subscribers) in &subscriber_lists {
+                subscribers.for_each(|_, subscriber| {
+                    remove_resource(StalePortResource::Connection(
+                        publisher.publisher_id,
+                        subscriber.port_id,
+                    ))
+                });
+            }
+        });
+
+        for (container, subscribers) in &subscriber_lists {
+            subscribers.for_each(|index, subscriber| {
+                if subscriber.node_id != *node_id {
+                    return;
+                }
+
+                if let Some(handle) = subscribers.handle(index) {
+                    if core::ptr::eq(*container, &self.subscribers) {
+                        self.reset_dropped_samples(index);
+                    }
+                    unsafe { container.remove(handle) };
+                }
+                debug!(from self, "Removed subscriber {:?} of dead node {:?}.", subscriber.port_id, node_id);
+
+                // the connections to the publishers of the dead node were already removed
+                publishers.for_each(|_, publisher| {
+                    if publisher.node_id != *node_id {
+                        remove_resource(StalePortResource::Connection(
+                            publisher.publisher_id,
+                            subscriber.port_id,
+                        ))
+                    }
+                });
+            });
+        }
     }
 }
@@ -31,6 +31,7 @@
 //! # }
 //! ```
 use std::alloc::Layout;
+use std::time::Duration;
 
 use crate::port::port_identifiers::UniquePublisherId;
 
@@ -41,6 +42,12 @@ use crate::port::port_identifiers::UniquePublisherId;
 pub struct Header {
     publisher_port_id: UniquePublisherId,
     payload_type_layout: Layout,
+    sequence_number: u64,
+    publisher_sequence_number: u64,
+    publish_timestamp_in_ns: u64,
+    key: u64,
+    fragment_offset: u64,
+    fragmented_message_len: u64,
 }
 
 impl Header {
@@ -48,14 +55,93 @@ impl Header {
         Self {
             publisher_port_id,
             payload_type_layout,
+            sequence_number: 0,
+            publisher_sequence_number: 0,
+            publish_timestamp_in_ns: 0,
+            key: 0,
+            fragment_offset: 0,
+            fragmented_message_len: 0,
         }
     }
 
+    pub(crate) fn set_sequence_number(&mut self, value: u64) {
+        self.sequence_number = value;
+    }
+
+    pub(crate) fn set_publisher_sequence_number(&mut self, value: u64) {
+        self.publisher_sequence_number = value;
+    }
+
+    pub(crate) fn set_publish_timestamp(&mut self, time_in_ns: u64) {
+        self.publish_timestamp_in_ns = time_in_ns;
+    }
+
+    pub(crate) fn set_key(&mut self, key: u64) {
+        self.key = key;
+    }
+
+    pub(crate) fn set_fragment(&mut self, offset: u64, message_len: u64) {
+        self.fragment_offset = offset;
+        self.fragmented_message_len = message_len;
+    }
+
+    pub(crate) fn set_payload_type_layout(&mut self, payload_type_layout: Layout) {
+        self.payload_type_layout = payload_type_layout;
+    }
+
     /// Returns the [`UniquePublisherId`] of the source [`crate::port::publisher::Publisher`].
     pub fn publisher_id(&self) -> UniquePublisherId {
         self.publisher_port_id
     }
 
+    /// Returns the sequence number of the sample. All samples of a service are numbered in the
+    /// order in which they were sent, independent of their
+    /// [`crate::port::publisher::Publisher`].
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    /// Returns the sequence number of the sample in the scope of its
+    /// [`crate::port::publisher::Publisher`]. The first sample of every
+    /// [`crate::port::publisher::Publisher`] has the number 0, a gap indicates a sample that
+    /// was not received.
+    pub fn publisher_sequence_number(&self) -> u64 {
+        self.publisher_sequence_number
+    }
+
+    /// Returns the time at which the sample was sent. It is acquired from the steady system
+    /// clock [`ClockType::default()`](iceoryx2_bb_posix::clock::ClockType) and can be compared
+    /// with the current time of this clock in any process on the same host to measure the
+    /// latency, for instance with [`Time::now_with_clock()`](iceoryx2_bb_posix::clock::Time).
+    pub fn publish_timestamp(&self) -> Duration {
+        Duration::from_nanos(self.publish_timestamp_in_ns)
+    }
+
+    /// Returns the key of the entity the sample describes, see
+    /// [`SampleMut::set_key()`](crate::sample_mut::SampleMut::set_key()). Samples without an
+    /// explicitly set key have the key 0.
+    pub fn key(&self) -> u64 {
+        self.key
+    }
+
+    /// Returns true when the sample is a fragment of a message that was split with
+    /// [`Publisher::send_slice_copy_fragmented()`](crate::port::publisher::Publisher::send_slice_copy_fragmented()),
+    /// otherwise false.
+    pub fn is_fragment(&self) -> bool {
+        self.fragmented_message_len != 0
+    }
+
+    /// Returns the position of the first element of the fragment in the fragmented message.
+    pub fn fragment_offset(&self) -> u64 {
+        self.fragment_offset
+    }
+
+    /// Returns the number of elements of the fragmented message, 0 when the sample is not a
+    /// fragment.
+    pub fn fragmented_message_len(&self) -> u64 {
+        self.fragmented_message_len
+    }
+
     /// Returns the [`Layout`] of the corresponding payload.
     pub fn payload_type_layout(&self) -> Layout {
         self.payload_type_layout
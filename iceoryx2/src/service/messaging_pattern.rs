@@ -31,8 +31,17 @@
 //! [`Listener`](crate::port::listener::Listener)s.
 //!
 //! **Note:** This does **not** send or receive POSIX signals nor is it based on them.
+//!
+//! ### Blackboard
+//!
+//! Shares a set of key-value entries between processes. `n`
+//! [`Writer`](crate::port::writer::Writer)s update the values of the entries and `m`
+//! [`Reader`](crate::port::reader::Reader)s read the latest value of an entry. Changes can be
+//! signaled with an [`Event`](crate::service::messaging_pattern::MessagingPattern::Event)
+//! service, every entry is identified by its own [`crate::port::event_id::EventId`].
 use std::fmt::Display;
 
+use crate::service::static_config::blackboard;
 use crate::service::static_config::event;
 use crate::service::static_config::publish_subscribe;
 use serde::{Deserialize, Serialize};
@@ -45,13 +54,19 @@ pub enum MessagingPattern {
     /// Unidirectional communication pattern where the
     /// [`Publisher`](crate::port::publisher::Publisher) sends arbitrary data to the
     /// [`Subscriber`](crate::port::subscriber::Subscriber)
-    PublishSubscribe(publish_subscribe::StaticConfig),
+    PublishSubscribe(Box<publish_subscribe::StaticConfig>),
 
     /// Unidirectional communication pattern where the [`Notifier`](crate::port::notifier::Notifier)
     /// sends signals/events to the [`Listener`](crate::port::listener::Listener) which has the
     /// ability to sleep until a signal/event arrives.
     /// Building block to realize push-notifications.
     Event(event::StaticConfig),
+
+    /// Key-value communication pattern where the [`Writer`](crate::port::writer::Writer)
+    /// updates the values of entries in shared memory and the
+    /// [`Reader`](crate::port::reader::Reader) reads the latest value of an entry.
+    /// Building block for parameter servers and low-rate state distribution.
+    Blackboard(Box<blackboard::StaticConfig>),
 }
 
 impl Display for MessagingPattern {
@@ -59,6 +74,7 @@ impl Display for MessagingPattern {
         match self {
             MessagingPattern::Event(_) => write!(f, "Event"),
             MessagingPattern::PublishSubscribe(_) => write!(f, "PublishSubscribe"),
+            MessagingPattern::Blackboard(_) => write!(f, "Blackboard"),
         }
     }
 }
@@ -68,6 +84,7 @@ impl From<MessagingPattern> for u32 {
         match value {
             MessagingPattern::Event(_) => 0,
             MessagingPattern::PublishSubscribe(_) => 1,
+            MessagingPattern::Blackboard(_) => 2,
         }
     }
 }
@@ -81,22 +98,9 @@ impl MessagingPattern {
             MessagingPattern::Event(_) => {
                 matches!(rhs, MessagingPattern::Event(_))
             }
-        }
-    }
-
-    pub(crate) fn required_amount_of_samples_per_data_segment(
-        &self,
-        publisher_max_loaned_samples: usize,
-    ) -> usize {
-        match self {
-            MessagingPattern::PublishSubscribe(v) => {
-                v.max_subscribers
-                    * (v.subscriber_max_buffer_size + v.subscriber_max_borrowed_samples)
-                    + v.history_size
-                    + publisher_max_loaned_samples
-                    + 1
+            MessagingPattern::Blackboard(_) => {
+                matches!(rhs, MessagingPattern::Blackboard(_))
             }
-            _ => 0,
         }
     }
 }
@@ -165,12 +165,16 @@ use std::fmt::Debug;
 use std::sync::Arc;
 
 use crate::config;
+use crate::node::resource_usage::{NodeResource, NodeResourceRegistration};
 use crate::node::SharedNode;
 use crate::service::dynamic_config::DynamicConfig;
 use crate::service::static_config::*;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::{fail, trace, warn};
-use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitError;
+use iceoryx2_cal::dynamic_storage::{
+    DynamicStorage, DynamicStorageBuilder, DynamicStorageOpenError,
+};
 use iceoryx2_cal::event::Event;
 use iceoryx2_cal::hash::Hash;
 use iceoryx2_cal::monitoring::Monitoring;
@@ -178,11 +182,13 @@ use iceoryx2_cal::named_concept::NamedConceptListError;
 use iceoryx2_cal::named_concept::*;
 use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::shared_memory::SharedMemory;
-use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::shm_allocator::selectable_allocator::SelectableAllocator;
 use iceoryx2_cal::static_storage::*;
 use iceoryx2_cal::zero_copy_connection::ZeroCopyConnection;
 
+use self::config_scheme::{dynamic_config_storage_config, static_config_storage_config};
 use self::dynamic_config::DecrementReferenceCounterResult;
+use self::naming_scheme::{dynamic_config_storage_name, static_config_storage_name};
 use self::service_name::ServiceName;
 
 /// Failure that can be reported by [`Service::does_exist()`].
@@ -200,6 +206,12 @@ impl std::fmt::Display for ServiceDoesExistError {
 
 impl std::error::Error for ServiceDoesExistError {}
 
+impl From<AdaptiveWaitError> for ServiceDoesExistError {
+    fn from(_: AdaptiveWaitError) -> Self {
+        ServiceDoesExistError::InternalError
+    }
+}
+
 /// Failure that can be reported by [`Service::list()`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ServiceListError {
@@ -215,6 +227,21 @@ impl std::fmt::Display for ServiceListError {
 
 impl std::error::Error for ServiceListError {}
 
+/// Failure that can be reported by [`Service::remove()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceRemoveError {
+    InsufficientPermissions,
+    InternalError,
+}
+
+impl std::fmt::Display for ServiceRemoveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ServiceRemoveError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ServiceRemoveError {}
+
 /// Represents the [`Service`]s state.
 #[derive(Debug)]
 pub struct ServiceState<S: Service> {
@@ -222,6 +249,8 @@ pub struct ServiceState<S: Service> {
     pub(crate) shared_node: Arc<SharedNode<S>>,
     pub(crate) dynamic_storage: Arc<S::DynamicStorage>,
     pub(crate) static_storage: S::StaticStorage,
+    service_tag: Option<S::StaticStorage>,
+    _resource_registration: NodeResourceRegistration<S>,
 }
 
 impl<S: Service> ServiceState<S> {
@@ -231,11 +260,18 @@ impl<S: Service> ServiceState<S> {
         dynamic_storage: Arc<S::DynamicStorage>,
         static_storage: S::StaticStorage,
     ) -> Self {
+        // the dynamic config is kept open as long as the service exists
+        let resource_registration =
+            NodeResourceRegistration::new(shared_node.clone(), NodeResource::Service, 1);
+        // the tag allows the cleanup of a dead node to release the service
+        let service_tag = shared_node.create_service_tag(static_config.uuid());
         let new_self = Self {
             static_config,
             shared_node,
             dynamic_storage,
             static_storage,
+            service_tag,
+            _resource_registration: resource_registration,
         };
         trace!(from new_self, "open service");
         new_self
@@ -244,6 +280,8 @@ impl<S: Service> ServiceState<S> {
 
 impl<S: Service> Drop for ServiceState<S> {
     fn drop(&mut self) {
+        // the tag must be removed first, otherwise a cleanup would release the service twice
+        drop(self.service_tag.take());
         match self.dynamic_storage.get().decrement_reference_counter() {
             DecrementReferenceCounterResult::HasOwners => {
                 trace!(from self, "close service");
@@ -277,7 +315,7 @@ pub trait Service: Debug + Sized {
     type DynamicStorage: DynamicStorage<DynamicConfig>;
 
     /// The memory used to store the payload.
-    type SharedMemory: SharedMemory<PoolAllocator>;
+    type SharedMemory: SharedMemory<SelectableAllocator>;
 
     /// The connection used to exchange pointers to the payload
     type Connection: ZeroCopyConnection;
@@ -450,4 +488,80 @@ pub trait Service: Debug + Sized {
 
         Ok(service_vec)
     }
+
+    /// Removes all [`ServiceLifetime::Persistent`] services with the given [`ServiceName`] under
+    /// a given [`config::Config`]. When the service is still in use, it is removed as soon as
+    /// the last [`Node`](crate::node::Node) releases it. Returns true when a service was
+    /// removed, [`ServiceLifetime::OwnerBound`] services are not affected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::config::Config;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let name = ServiceName::new("Some/Name")?;
+    /// let mut custom_config = Config::default();
+    /// let has_removed_service = zero_copy::Service::remove(&name, &custom_config)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn remove(
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Result<bool, ServiceRemoveError> {
+        let msg = format!("Unable to remove service \"{}\"", service_name);
+        let origin = "Service::remove()";
+
+        let services = fail!(from origin, when Self::list(config),
+                map ServiceListError::InsufficientPermissions => ServiceRemoveError::InsufficientPermissions,
+                unmatched ServiceRemoveError::InternalError,
+                "{} due to a failure while collecting all active services for config: {:?}", msg, config);
+
+        let mut has_removed_service = false;
+        for service_config in services
+            .iter()
+            .filter(|s| s.name() == service_name && s.lifetime() == ServiceLifetime::Persistent)
+        {
+            let dynamic_storage = match <<Self::DynamicStorage as DynamicStorage<
+                DynamicConfig,
+            >>::Builder<'_> as NamedConceptBuilder<Self::DynamicStorage>>::new(
+                &dynamic_config_storage_name(service_config),
+            )
+            .config(&dynamic_config_storage_config::<Self>(config))
+            .has_ownership(false)
+            .open()
+            {
+                Ok(storage) => storage,
+                // removed concurrently by the last owner
+                Err(DynamicStorageOpenError::DoesNotExist) => continue,
+                Err(e) => {
+                    fail!(from origin, with ServiceRemoveError::InternalError,
+                        "{} since its dynamic config could not be opened ({:?}).", msg, e);
+                }
+            };
+
+            match dynamic_storage.get().release_persistent_reference() {
+                None => continue,
+                Some(DecrementReferenceCounterResult::HasOwners) => {
+                    trace!(from origin, "service {:?} is removed by its last owner", service_config.name());
+                }
+                Some(DecrementReferenceCounterResult::NoMoreOwners) => {
+                    dynamic_storage.acquire_ownership();
+                    fail!(from origin, when unsafe {
+                        <Self::StaticStorage as NamedConceptMgmt>::remove_cfg(
+                            &static_config_storage_name(service_config.uuid()),
+                            &static_config_storage_config::<Self>(config),
+                        ) },
+                        map NamedConceptRemoveError::InsufficientPermissions => ServiceRemoveError::InsufficientPermissions,
+                        unmatched ServiceRemoveError::InternalError,
+                        "{} since its static config could not be removed.", msg);
+                }
+            }
+            has_removed_service = true;
+        }
+
+        Ok(has_removed_service)
+    }
 }
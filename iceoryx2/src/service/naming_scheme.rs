@@ -13,6 +13,7 @@
 use crate::port::port_identifiers::{UniqueListenerId, UniquePublisherId, UniqueSubscriberId};
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_bb_system_types::file_name::FileName;
 
 use super::static_config::StaticConfig;
@@ -34,6 +35,21 @@ pub(crate) fn static_config_storage_name(uuid: &str) -> FileName {
     FileName::new(uuid.as_bytes()).unwrap()
 }
 
+pub(crate) fn service_tag_name(uuid: &str, tag_id: &UniqueSystemId) -> FileName {
+    let msg = "The system does not support the required file name length for the service tag.";
+    let origin = "service_tag_name()";
+
+    let mut file = fatal_panic!(from origin, when FileName::new(uuid.as_bytes()), "{}", msg);
+    fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
+    fatal_panic!(from origin, when file.push_bytes(tag_id.value().to_string().as_bytes()), "{}", msg);
+    file
+}
+
+pub(crate) fn uuid_from_service_tag_name(tag_name: &FileName) -> Option<String> {
+    let tag_name = core::str::from_utf8(tag_name.as_bytes()).ok()?;
+    tag_name.split_once('_').map(|(uuid, _)| uuid.to_string())
+}
+
 pub(crate) fn connection_name(
     publisher_id: UniquePublisherId,
     subscriber_id: UniqueSubscriberId,
@@ -45,12 +61,18 @@ pub(crate) fn connection_name(
     file
 }
 
-pub(crate) fn data_segment_name(publisher_id: UniquePublisherId) -> FileName {
+// The first segment of a data segment has no segment id in its name, the additional segments
+// of a growing data segment are appended with their segment id.
+pub(crate) fn data_segment_name(publisher_id: UniquePublisherId, segment_id: usize) -> FileName {
     let msg = "The system does not support the required file name length for the publishers data segment.";
     let origin = "data_segment_name()";
 
     let mut file = fatal_panic!(from origin, when FileName::new(publisher_id.0.pid().to_string().as_bytes()), "{}", msg);
     fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
     fatal_panic!(from origin, when file.push_bytes(publisher_id.0.value().to_string().as_bytes()), "{}", msg);
+    if segment_id != 0 {
+        fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
+        fatal_panic!(from origin, when file.push_bytes(segment_id.to_string().as_bytes()), "{}", msg);
+    }
     file
 }
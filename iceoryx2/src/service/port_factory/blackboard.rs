@@ -0,0 +1,151 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Examples
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let blackboard = node.service_builder("MyBlackboardName".try_into()?)
+//!     .blackboard::<u64>()
+//!     .add::<f32>(0, 1.5)
+//!     .open_or_create()?;
+//!
+//! println!("name:                         {:?}", blackboard.name());
+//! println!("uuid:                         {:?}", blackboard.uuid());
+//! println!("max readers:                  {:?}", blackboard.static_config().max_supported_readers());
+//! println!("max writers:                  {:?}", blackboard.static_config().max_supported_writers());
+//! println!("number of active readers:     {:?}", blackboard.dynamic_config().number_of_readers());
+//! println!("number of active writers:     {:?}", blackboard.dynamic_config().number_of_writers());
+//!
+//! let reader = blackboard.reader_builder().create()?;
+//! let writer = blackboard.writer_builder().create()?;
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use iceoryx2_bb_posix::permission::Permission;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::service::attribute::AttributeSet;
+use crate::service::{self, static_config};
+use crate::service::{dynamic_config, ServiceName};
+
+use super::reader::PortFactoryReader;
+use super::writer::PortFactoryWriter;
+
+/// The factory for
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard).
+/// It can acquire dynamic and static service informations and create
+/// [`crate::port::reader::Reader`] or [`crate::port::writer::Writer`] ports.
+#[derive(Debug)]
+pub struct PortFactory<Service: service::Service, KeyType: Copy + Eq + Debug> {
+    pub(crate) service: Service,
+    _key: PhantomData<KeyType>,
+}
+
+unsafe impl<Service: service::Service, KeyType: Copy + Eq + Debug> Send
+    for PortFactory<Service, KeyType>
+{
+}
+unsafe impl<Service: service::Service, KeyType: Copy + Eq + Debug> Sync
+    for PortFactory<Service, KeyType>
+{
+}
+
+impl<Service: service::Service, KeyType: Copy + Eq + Debug>
+    crate::service::port_factory::PortFactory for PortFactory<Service, KeyType>
+{
+    type StaticConfig = static_config::blackboard::StaticConfig;
+    type DynamicConfig = dynamic_config::blackboard::DynamicConfig;
+
+    fn name(&self) -> &ServiceName {
+        self.service.state().static_config.name()
+    }
+
+    fn uuid(&self) -> &str {
+        self.service.state().static_config.uuid()
+    }
+
+    fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
+    fn permission(&self) -> Permission {
+        self.service.state().static_config.permission()
+    }
+
+    fn static_config(&self) -> &static_config::blackboard::StaticConfig {
+        self.service.state().static_config.blackboard()
+    }
+
+    fn dynamic_config(&self) -> &dynamic_config::blackboard::DynamicConfig {
+        self.service.state().dynamic_storage.get().blackboard()
+    }
+}
+
+impl<Service: service::Service, KeyType: Copy + Eq + Debug> PortFactory<Service, KeyType> {
+    pub(crate) fn new(service: Service) -> Self {
+        Self {
+            service,
+            _key: PhantomData,
+        }
+    }
+
+    /// Returns a [`PortFactoryReader`] to create a new [`crate::port::reader::Reader`] port
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let blackboard = node.service_builder("MyBlackboardName".try_into()?)
+    ///     .blackboard::<u64>()
+    ///     .add::<f32>(0, 1.5)
+    ///     .open_or_create()?;
+    ///
+    /// let reader = blackboard.reader_builder().create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reader_builder(&self) -> PortFactoryReader<Service, KeyType> {
+        PortFactoryReader::new(self)
+    }
+
+    /// Returns a [`PortFactoryWriter`] to create a new [`crate::port::writer::Writer`] port
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let blackboard = node.service_builder("MyBlackboardName".try_into()?)
+    ///     .blackboard::<u64>()
+    ///     .add::<f32>(0, 1.5)
+    ///     .open_or_create()?;
+    ///
+    /// let writer = blackboard.writer_builder().create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn writer_builder(&self) -> PortFactoryWriter<Service, KeyType> {
+        PortFactoryWriter::new(self)
+    }
+}
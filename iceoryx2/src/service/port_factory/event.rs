@@ -33,6 +33,9 @@
 //! # Ok(())
 //! # }
 //! ```
+use std::time::Duration;
+
+use iceoryx2_bb_posix::permission::Permission;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
 use crate::service::attribute::AttributeSet;
@@ -41,6 +44,7 @@ use crate::service::{dynamic_config, ServiceName};
 
 use super::listener::PortFactoryListener;
 use super::notifier::PortFactoryNotifier;
+use super::signal_bridge::PortFactorySignalBridge;
 
 /// The factory for
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event). It can
@@ -70,6 +74,10 @@ impl<Service: service::Service> crate::service::port_factory::PortFactory for Po
         self.service.state().static_config.attributes()
     }
 
+    fn permission(&self) -> Permission {
+        self.service.state().static_config.permission()
+    }
+
     fn static_config(&self) -> &static_config::event::StaticConfig {
         self.service.state().static_config.event()
     }
@@ -123,6 +131,36 @@ impl<Service: service::Service> PortFactory<Service> {
     /// # }
     /// ```
     pub fn listener_builder(&self) -> PortFactoryListener<Service> {
-        PortFactoryListener { factory: self }
+        PortFactoryListener {
+            factory: self,
+            coalescing_window: Duration::ZERO,
+        }
+    }
+
+    /// Returns a [`PortFactorySignalBridge`] to create a new
+    /// [`crate::port::signal_bridge::SignalBridge`] that raises signals for received events.
+    /// The [`crate::port::signal_bridge::SignalBridge`] owns a
+    /// [`crate::port::listener::Listener`] and occupies one listener slot of the service.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_bb_posix::signal::Signal;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let event = node.service_builder("MyEventName".try_into()?)
+    ///     .event()
+    ///     .open_or_create()?;
+    ///
+    /// let bridge = event.signal_bridge_builder()
+    ///     .map(EventId::new(3), Signal::UserDefined1)
+    ///     .create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn signal_bridge_builder(&self) -> PortFactorySignalBridge<'_, Service> {
+        PortFactorySignalBridge::new(self)
     }
 }
@@ -0,0 +1,104 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let pubsub = node.service_builder("My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .open_or_create()?;
+//!
+//! let fan_in = pubsub.fan_in_builder()
+//!                     .buffer_size(2)
+//!                     .max_samples_per_publisher(1)
+//!                     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+
+use iceoryx2_bb_log::fail;
+
+use crate::port::fan_in::FanIn;
+use crate::port::subscriber::{Subscriber, SubscriberCreateError};
+use crate::service;
+
+use super::publish_subscribe::PortFactory;
+use super::subscriber::{Decimation, SubscriberConfig};
+
+const DEFAULT_MAX_SAMPLES_PER_PUBLISHER: usize = 1;
+
+/// Factory to create a new [`FanIn`] port/endpoint for
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryFanIn<
+    'factory,
+    Service: service::Service,
+    PayloadType: Debug + ?Sized,
+    UserHeader: Debug = (),
+> {
+    config: SubscriberConfig,
+    pub(crate) factory: &'factory PortFactory<Service, PayloadType, UserHeader>,
+}
+
+impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized, UserHeader: Debug>
+    PortFactoryFanIn<'factory, Service, PayloadType, UserHeader>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<Service, PayloadType, UserHeader>) -> Self {
+        Self {
+            config: SubscriberConfig {
+                buffer_size: None,
+                group: None,
+                max_samples_per_publisher: Some(DEFAULT_MAX_SAMPLES_PER_PUBLISHER),
+                degration_callback: None,
+                is_observer: false,
+                decimation: Decimation::Disabled,
+                filter: None,
+                receive_hook: None,
+                overflow_strategy: None,
+            },
+            factory,
+        }
+    }
+
+    /// Defines the buffer size of the [`FanIn`] per connected
+    /// [`crate::port::publisher::Publisher`]. With many
+    /// [`crate::port::publisher::Publisher`]s a small buffer size keeps the memory footprint low.
+    pub fn buffer_size(mut self, value: usize) -> Self {
+        self.config.buffer_size = Some(value.max(1));
+        self
+    }
+
+    /// Defines how many [`crate::sample::Sample`]s the [`FanIn`] receives at most in a row from
+    /// the same [`crate::port::publisher::Publisher`] before the next
+    /// [`crate::port::publisher::Publisher`] is served.
+    pub fn max_samples_per_publisher(mut self, value: usize) -> Self {
+        self.config.max_samples_per_publisher = Some(value.max(1));
+        self
+    }
+
+    /// Creates a new [`FanIn`] or returns a [`SubscriberCreateError`] on failure.
+    pub fn create(self) -> Result<FanIn<Service, PayloadType, UserHeader>, SubscriberCreateError> {
+        let origin = format!("{:?}", self);
+        Ok(FanIn::new(
+            fail!(from origin, when Subscriber::new(&self.factory.service, self.factory.service.state().static_config.publish_subscribe(), self.config),
+                "Failed to create new FanIn port."),
+        ))
+    }
+}
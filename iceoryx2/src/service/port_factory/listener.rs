@@ -27,6 +27,8 @@
 //! ```
 use std::fmt::Debug;
 
+use std::time::Duration;
+
 use iceoryx2_bb_log::fail;
 
 use crate::port::{listener::Listener, listener::ListenerCreateError};
@@ -40,12 +42,26 @@ use super::event::PortFactory;
 #[derive(Debug)]
 pub struct PortFactoryListener<'factory, Service: service::Service> {
     pub(crate) factory: &'factory PortFactory<Service>,
+    pub(crate) coalescing_window: Duration,
 }
 
 impl<'factory, Service: service::Service> PortFactoryListener<'factory, Service> {
+    /// Sets the coalescing window of the [`Listener`]. When the [`Listener`] is woken up in
+    /// [`Listener::timed_wait_all()`] or [`Listener::blocking_wait_all()`] it waits for the
+    /// duration of the window and collects all notifications that arrive in the meantime, so
+    /// that a burst of notifications causes only a single wakeup. Trades a small amount of
+    /// latency for fewer context switches. A window of [`Duration::ZERO`] (default) disables the
+    /// coalescing.
+    pub fn coalescing_window(mut self, value: Duration) -> Self {
+        self.coalescing_window = value;
+        self
+    }
+
     /// Creates the [`Listener`] port or returns a [`ListenerCreateError`] on failure.
     pub fn create(&self) -> Result<Listener<Service>, ListenerCreateError> {
-        Ok(fail!(from self, when Listener::new(&self.factory.service),
-                    "Failed to create new Listener port."))
+        Ok(
+            fail!(from self, when Listener::new(&self.factory.service, self.coalescing_window),
+                    "Failed to create new Listener port."),
+        )
     }
 }
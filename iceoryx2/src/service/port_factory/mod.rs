@@ -10,13 +10,23 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use iceoryx2_bb_posix::permission::Permission;
+
 use super::{attribute::AttributeSet, service_name::ServiceName};
 
+/// Factory to create the endpoints of
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) based
+/// communication and to acquire static and dynamic service information
+pub mod blackboard;
+
 /// Factory to create the endpoints of
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) based
 /// communication and to acquire static and dynamic service information
 pub mod event;
 
+/// Factory to create a [`FanIn`](crate::port::fan_in::FanIn)
+pub mod fan_in;
+
 /// Factory to create a [`Listener`](crate::port::listener::Listener)
 pub mod listener;
 
@@ -28,12 +38,25 @@ pub mod notifier;
 /// communication and to acquire static and dynamic service information
 pub mod publish_subscribe;
 
+/// Factory to create an observer, a passive [`Subscriber`](crate::port::subscriber::Subscriber)
+/// for tooling
+pub mod observer;
+
 /// Factory to create a [`Publisher`](crate::port::publisher::Publisher)
 pub mod publisher;
 
+/// Factory to create a [`Reader`](crate::port::reader::Reader)
+pub mod reader;
+
+/// Factory to create a [`SignalBridge`](crate::port::signal_bridge::SignalBridge)
+pub mod signal_bridge;
+
 /// Factory to create a [`Subscriber`](crate::port::subscriber::Subscriber)
 pub mod subscriber;
 
+/// Factory to create a [`Writer`](crate::port::writer::Writer)
+pub mod writer;
+
 /// The trait that contains the interface of all port factories for any kind of
 /// [`crate::service::messaging_pattern::MessagingPattern`].
 pub trait PortFactory {
@@ -49,6 +72,9 @@ pub trait PortFactory {
     /// Returns the attributes defined in the [`crate::service::Service`]
     fn attributes(&self) -> &AttributeSet;
 
+    /// Returns the [`Permission`] of the resources of the [`crate::service::Service`]
+    fn permission(&self) -> Permission;
+
     /// Returns the StaticConfig of the [`crate::service::Service`].
     /// Contains all settings that never change during the lifetime of the service.
     fn static_config(&self) -> &Self::StaticConfig;
@@ -29,6 +29,7 @@
 //! # }
 //! ```
 use std::fmt::Debug;
+use std::time::Duration;
 
 use crate::port::{event_id::EventId, notifier::Notifier, notifier::NotifierCreateError};
 use iceoryx2_bb_log::fail;
@@ -44,6 +45,7 @@ use super::event::PortFactory;
 pub struct PortFactoryNotifier<'factory, Service: service::Service> {
     pub(crate) factory: &'factory PortFactory<Service>,
     default_event_id: EventId,
+    coalescing_window: Duration,
 }
 
 impl<'factory, Service: service::Service> PortFactoryNotifier<'factory, Service> {
@@ -51,6 +53,7 @@ impl<'factory, Service: service::Service> PortFactoryNotifier<'factory, Service>
         Self {
             factory,
             default_event_id: EventId::default(),
+            coalescing_window: Duration::ZERO,
         }
     }
 
@@ -61,10 +64,22 @@ impl<'factory, Service: service::Service> PortFactoryNotifier<'factory, Service>
         self
     }
 
+    /// Sets the coalescing window of the [`Notifier`]. Every further notification with the same
+    /// [`EventId`] within the window after a delivered notification is not delivered to the
+    /// [`crate::port::listener::Listener`]s so that a burst of notifications does not wake
+    /// them up thousands of times per second. The number of coalesced notifications can be
+    /// acquired with
+    /// [`Listener::number_of_coalesced_notifications()`](crate::port::listener::Listener::number_of_coalesced_notifications()).
+    /// A window of [`Duration::ZERO`] (default) disables the coalescing.
+    pub fn coalescing_window(mut self, value: Duration) -> Self {
+        self.coalescing_window = value;
+        self
+    }
+
     /// Creates a new [`Notifier`] port or returns a [`NotifierCreateError`] on failure.
     pub fn create(&self) -> Result<Notifier<Service>, NotifierCreateError> {
         Ok(
-            fail!(from self, when Notifier::new(&self.factory.service, self.default_event_id),
+            fail!(from self, when Notifier::new(&self.factory.service, self.default_event_id, self.coalescing_window),
                     "Failed to create new Notifier port."),
         )
     }
@@ -0,0 +1,120 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! An observer is a passive [`Subscriber`] for tooling like monitors or recorders. Attaching it
+//! to a running system never changes the behavior of the system:
+//!
+//!  * it does not occupy a slot of
+//!    [`StaticConfig::max_supported_subscribers()`](crate::service::static_config::publish_subscribe::StaticConfig::max_supported_subscribers())
+//!    but one of
+//!    [`StaticConfig::max_supported_observers()`](crate::service::static_config::publish_subscribe::StaticConfig::max_supported_observers())
+//!  * the [`crate::port::publisher::Publisher`] reserves the memory for the observers when it is
+//!    created, independent of whether an observer is attached
+//!  * the [`crate::port::publisher::Publisher`] never blocks on an observer, when its buffer is
+//!    full the [`crate::sample::Sample`] is not delivered to it
+//!  * it is not counted as recipient, is not tracked by acknowledgments, never joins a
+//!    work-sharing group and its lost [`crate::sample::Sample`]s are not routed into the
+//!    dead-letter service
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let pubsub = node.service_builder("My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<u64>()
+//!     .max_observers(1)
+//!     .open_or_create()?;
+//!
+//! let observer = pubsub.observer_builder()
+//!                     .buffer_size(1)
+//!                     .create()?;
+//!
+//! while let Some(sample) = observer.receive()? {
+//!     println!("observed: {:?}", *sample);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+
+use iceoryx2_bb_log::fail;
+
+use crate::port::subscriber::{Subscriber, SubscriberCreateError};
+use crate::service;
+
+use super::publish_subscribe::PortFactory;
+use super::subscriber::{Decimation, SubscriberConfig};
+
+/// Factory to create a new observer, a passive [`Subscriber`] port/endpoint for
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryObserver<
+    'factory,
+    Service: service::Service,
+    PayloadType: Debug + ?Sized,
+    UserHeader: Debug = (),
+> {
+    config: SubscriberConfig,
+    pub(crate) factory: &'factory PortFactory<Service, PayloadType, UserHeader>,
+}
+
+impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized, UserHeader: Debug>
+    PortFactoryObserver<'factory, Service, PayloadType, UserHeader>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<Service, PayloadType, UserHeader>) -> Self {
+        Self {
+            config: SubscriberConfig {
+                buffer_size: None,
+                group: None,
+                max_samples_per_publisher: None,
+                degration_callback: None,
+                is_observer: true,
+                decimation: Decimation::Disabled,
+                filter: None,
+                receive_hook: None,
+                overflow_strategy: None,
+            },
+            factory,
+        }
+    }
+
+    /// Defines the buffer size of the observer, it cannot exceed
+    /// [`StaticConfig::subscriber_max_buffer_size()`](crate::service::static_config::publish_subscribe::StaticConfig::subscriber_max_buffer_size()).
+    pub fn buffer_size(mut self, value: usize) -> Self {
+        self.config.buffer_size = Some(value.max(1));
+        self
+    }
+
+    /// Defines the [`Decimation`] of the observer, so that a monitor of a high-rate service
+    /// receives only every Nth [`crate::sample::Sample`] or at most one per interval.
+    pub fn decimation(mut self, value: Decimation) -> Self {
+        self.config.decimation = value.normalize();
+        self
+    }
+
+    /// Creates a new observer or returns a [`SubscriberCreateError`] on failure.
+    pub fn create(
+        self,
+    ) -> Result<Subscriber<Service, PayloadType, UserHeader>, SubscriberCreateError> {
+        let origin = format!("{:?}", self);
+        Ok(
+            fail!(from origin, when Subscriber::new(&self.factory.service, self.factory.service.state().static_config.publish_subscribe(), self.config),
+                "Failed to create new observer port."),
+        )
+    }
+}
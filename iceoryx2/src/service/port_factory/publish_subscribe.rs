@@ -26,12 +26,14 @@
 //! println!("type details:                     {:?}", pubsub.static_config().type_details());
 //! println!("max publishers:                   {:?}", pubsub.static_config().max_supported_publishers());
 //! println!("max subscribers:                  {:?}", pubsub.static_config().max_supported_subscribers());
+//! println!("max observers:                    {:?}", pubsub.static_config().max_supported_observers());
 //! println!("subscriber buffer size:           {:?}", pubsub.static_config().subscriber_max_buffer_size());
 //! println!("history size:                     {:?}", pubsub.static_config().history_size());
 //! println!("subscriber max borrowed samples:  {:?}", pubsub.static_config().subscriber_max_borrowed_samples());
 //! println!("safe overflow:                    {:?}", pubsub.static_config().has_safe_overflow());
 //! println!("number of active publishers:      {:?}", pubsub.dynamic_config().number_of_publishers());
 //! println!("number of active subscribers:     {:?}", pubsub.dynamic_config().number_of_subscribers());
+//! println!("number of active observers:       {:?}", pubsub.dynamic_config().number_of_observers());
 //!
 //! let publisher = pubsub.publisher_builder().create()?;
 //! let subscriber = pubsub.subscriber_builder().create()?;
@@ -42,13 +44,17 @@
 
 use std::{fmt::Debug, marker::PhantomData};
 
+use iceoryx2_bb_posix::permission::Permission;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
 use crate::service::attribute::AttributeSet;
 use crate::service::service_name::ServiceName;
 use crate::service::{self, dynamic_config, static_config};
 
-use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
+use super::{
+    fan_in::PortFactoryFanIn, observer::PortFactoryObserver, publisher::PortFactoryPublisher,
+    subscriber::PortFactorySubscriber,
+};
 
 /// The factory for
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe).
@@ -56,22 +62,27 @@ use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
 /// [`crate::port::publisher::Publisher`]
 /// or [`crate::port::subscriber::Subscriber`] ports.
 #[derive(Debug)]
-pub struct PortFactory<Service: service::Service, PayloadType: Debug + ?Sized> {
+pub struct PortFactory<
+    Service: service::Service,
+    PayloadType: Debug + ?Sized,
+    UserHeader: Debug = (),
+> {
     pub(crate) service: Service,
     _phantom_payload_type: PhantomData<PayloadType>,
+    _phantom_user_header: PhantomData<UserHeader>,
 }
 
-unsafe impl<Service: service::Service, PayloadType: Debug + ?Sized> Send
-    for PortFactory<Service, PayloadType>
+unsafe impl<Service: service::Service, PayloadType: Debug + ?Sized, UserHeader: Debug> Send
+    for PortFactory<Service, PayloadType, UserHeader>
 {
 }
-unsafe impl<Service: service::Service, PayloadType: Debug + ?Sized> Sync
-    for PortFactory<Service, PayloadType>
+unsafe impl<Service: service::Service, PayloadType: Debug + ?Sized, UserHeader: Debug> Sync
+    for PortFactory<Service, PayloadType, UserHeader>
 {
 }
 
-impl<Service: service::Service, PayloadType: Debug + ?Sized>
-    crate::service::port_factory::PortFactory for PortFactory<Service, PayloadType>
+impl<Service: service::Service, PayloadType: Debug + ?Sized, UserHeader: Debug>
+    crate::service::port_factory::PortFactory for PortFactory<Service, PayloadType, UserHeader>
 {
     type StaticConfig = static_config::publish_subscribe::StaticConfig;
     type DynamicConfig = dynamic_config::publish_subscribe::DynamicConfig;
@@ -88,6 +99,10 @@ impl<Service: service::Service, PayloadType: Debug + ?Sized>
         self.service.state().static_config.attributes()
     }
 
+    fn permission(&self) -> Permission {
+        self.service.state().static_config.permission()
+    }
+
     fn static_config(&self) -> &static_config::publish_subscribe::StaticConfig {
         self.service.state().static_config.publish_subscribe()
     }
@@ -101,11 +116,14 @@ impl<Service: service::Service, PayloadType: Debug + ?Sized>
     }
 }
 
-impl<Service: service::Service, PayloadType: Debug + ?Sized> PortFactory<Service, PayloadType> {
+impl<Service: service::Service, PayloadType: Debug + ?Sized, UserHeader: Debug>
+    PortFactory<Service, PayloadType, UserHeader>
+{
     pub(crate) fn new(service: Service) -> Self {
         Self {
             service,
             _phantom_payload_type: PhantomData,
+            _phantom_user_header: PhantomData,
         }
     }
 
@@ -128,10 +146,60 @@ impl<Service: service::Service, PayloadType: Debug + ?Sized> PortFactory<Service
     /// # Ok(())
     /// # }
     /// ```
-    pub fn subscriber_builder(&self) -> PortFactorySubscriber<Service, PayloadType> {
+    pub fn subscriber_builder(&self) -> PortFactorySubscriber<Service, PayloadType, UserHeader> {
         PortFactorySubscriber::new(self)
     }
 
+    /// Returns a [`PortFactoryObserver`] to create a new observer, a passive
+    /// [`crate::port::subscriber::Subscriber`] port for tooling like monitors or recorders.
+    /// Observers do not occupy a slot of the maximum supported subscribers and cannot
+    /// influence the [`crate::port::publisher::Publisher`]s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let pubsub = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .max_observers(1)
+    ///     .open_or_create()?;
+    ///
+    /// let observer = pubsub.observer_builder().create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn observer_builder(&self) -> PortFactoryObserver<'_, Service, PayloadType, UserHeader> {
+        PortFactoryObserver::new(self)
+    }
+
+    /// Returns a [`PortFactoryFanIn`] to create a new
+    /// [`crate::port::fan_in::FanIn`] port that merges the samples of many
+    /// [`crate::port::publisher::Publisher`]s fairly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let pubsub = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let fan_in = pubsub.fan_in_builder().create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fan_in_builder(&self) -> PortFactoryFanIn<'_, Service, PayloadType, UserHeader> {
+        PortFactoryFanIn::new(self)
+    }
+
     /// Returns a [`PortFactoryPublisher`] to create a new
     /// [`crate::port::publisher::Publisher`] port.
     ///
@@ -155,7 +223,7 @@ impl<Service: service::Service, PayloadType: Debug + ?Sized> PortFactory<Service
     /// # Ok(())
     /// # }
     /// ```
-    pub fn publisher_builder(&self) -> PortFactoryPublisher<Service, PayloadType> {
+    pub fn publisher_builder(&self) -> PortFactoryPublisher<Service, PayloadType, UserHeader> {
         PortFactoryPublisher::new(self)
     }
 }
@@ -56,18 +56,21 @@
 
 use std::fmt::Debug;
 
+use iceoryx2_bb_elementary::alignment::Alignment;
 use iceoryx2_bb_log::fail;
 use serde::{de::Visitor, Deserialize, Serialize};
 
 use super::publish_subscribe::PortFactory;
 use crate::{
+    config,
     port::{
         port_identifiers::{UniquePublisherId, UniqueSubscriberId},
         publisher::Publisher,
-        publisher::PublisherCreateError,
-        DegrationAction, DegrationCallback,
+        publisher::{PublisherCreateError, PublisherMemoryBreakdown},
+        DegrationAction, DegrationCallback, SampleHook,
     },
     service,
+    service::header::publish_subscribe::Header,
 };
 
 /// Defines the strategy the [`Publisher`] shall pursue in
@@ -125,55 +128,221 @@ impl<'de> Deserialize<'de> for UnableToDeliverStrategy {
     }
 }
 
+/// Defines when the memory of the data segment of a [`Publisher`] is zeroed.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ZeroingPolicy {
+    /// The whole data segment is zeroed when the [`Publisher`] is created.
+    OnCreate,
+    /// Every sample is zeroed when it is loaned with [`Publisher::loan_uninit()`] or
+    /// one of its variants.
+    OnLoan,
+    /// The memory is never zeroed explicitly, it contains only the zeroes the operating system
+    /// provides for new memory. Useful for large payloads that are always fully written before
+    /// they are sent.
+    Never,
+}
+
+impl Serialize for ZeroingPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(match self {
+            ZeroingPolicy::OnCreate => "on_create",
+            ZeroingPolicy::OnLoan => "on_loan",
+            ZeroingPolicy::Never => "never",
+        })
+    }
+}
+
+struct ZeroingPolicyVisitor;
+
+impl<'de> Visitor<'de> for ZeroingPolicyVisitor {
+    type Value = ZeroingPolicy;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a string containing either 'on_create', 'on_loan' or 'never'")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "on_create" => Ok(ZeroingPolicy::OnCreate),
+            "on_loan" => Ok(ZeroingPolicy::OnLoan),
+            "never" => Ok(ZeroingPolicy::Never),
+            v => Err(E::custom(format!(
+                "Invalid ZeroingPolicy provided: \"{:?}\".",
+                v
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ZeroingPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ZeroingPolicyVisitor)
+    }
+}
+
+/// The maximum number of memory pools that can be added with
+/// [`PortFactoryPublisher::mem_pool()`].
+pub const MAX_NUMBER_OF_MEM_POOLS: usize =
+    iceoryx2_cal::shm_allocator::multi_pool_allocator::MAX_NUMBER_OF_POOLS;
+
+/// Defines the allocator the data segment of a [`Publisher`] uses to manage its samples.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum DataSegmentAllocator {
+    /// Every sample occupies a bucket of the size of the largest possible sample. Well suited
+    /// for uniform payloads like frames of a fixed size.
+    Pool,
+    /// Samples are placed one after another and the memory is reclaimed as a whole as soon as
+    /// every sample of the cycle was released. Well suited for bursts of variable sized samples
+    /// that are consumed together. Samples that are kept in the history of the service are
+    /// never released, therefore it should be used with a history size of zero.
+    BumpPerCycle,
+    /// Every sample occupies only the memory it requires and is placed into the smallest free
+    /// memory range that fits. Well suited for variable sized payloads like blobs.
+    BestFit,
+    /// The data segment is divided into memory pools with different sample sizes that are
+    /// added with [`PortFactoryPublisher::mem_pool()`]. Every sample is placed into the pool
+    /// with the smallest samples that fit and falls back to the pools with larger samples when
+    /// it is exhausted. Well suited for variable sized slices with a known size distribution.
+    /// Without any configured memory pool it behaves like [`DataSegmentAllocator::Pool`].
+    MultiPool,
+}
+
+/// Defines how the data segment of a [`Publisher`] grows when a slice is loaned with
+/// [`Publisher::loan_slice()`] or [`Publisher::loan_slice_uninit()`] that is larger than the
+/// current maximum slice length. The data segment grows by mapping an additional shared memory
+/// segment that is used for all following loans. Previous segments stay mapped until the
+/// [`Publisher`] goes out of scope since subscribers may still hold samples from them.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum AllocationStrategy {
+    /// The data segment never grows, a loan that exceeds the `max_slice_len` fails.
+    Static,
+    /// The new segment fits exactly the requested slice length.
+    BestFit,
+    /// The new segment fits the next power of two of the requested slice length. Reduces the
+    /// number of segments when the payload grows steadily.
+    PowerOfTwo,
+}
+
+// A memory pool of the [`DataSegmentAllocator::MultiPool`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub(crate) struct MemPoolConfig {
+    pub(crate) max_slice_len: usize,
+    pub(crate) number_of_samples: usize,
+}
+
 #[derive(Debug)]
 pub(crate) struct LocalPublisherConfig {
     pub(crate) max_loaned_samples: usize,
     pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
     pub(crate) degration_callback: Option<DegrationCallback<'static>>,
+    pub(crate) send_hook: Option<SampleHook>,
     pub(crate) max_slice_len: usize,
+    pub(crate) zeroing_policy: ZeroingPolicy,
+    pub(crate) data_segment_allocator: DataSegmentAllocator,
+    pub(crate) mem_pools: Vec<MemPoolConfig>,
+    pub(crate) allocation_strategy: AllocationStrategy,
+    pub(crate) enable_canaries: bool,
+    pub(crate) enable_acknowledgments: bool,
+    pub(crate) payload_alignment: Option<Alignment>,
+}
+
+impl LocalPublisherConfig {
+    pub(crate) fn new(config: &config::Config) -> Self {
+        Self {
+            degration_callback: None,
+            send_hook: None,
+            max_slice_len: 1,
+            max_loaned_samples: config
+                .defaults
+                .publish_subscribe
+                .publisher_max_loaned_samples,
+            unable_to_deliver_strategy: config
+                .defaults
+                .publish_subscribe
+                .unable_to_deliver_strategy,
+            zeroing_policy: config.defaults.publish_subscribe.publisher_zeroing_policy,
+            data_segment_allocator: DataSegmentAllocator::Pool,
+            mem_pools: vec![],
+            allocation_strategy: AllocationStrategy::Static,
+            enable_canaries: false,
+            enable_acknowledgments: false,
+            payload_alignment: None,
+        }
+    }
+
+    // The static config of the service with the payload alignment of the [`Publisher`]. All
+    // samples of the [`Publisher`] are placed according to it.
+    pub(crate) fn static_config_of(
+        &self,
+        service_config: &service::static_config::StaticConfig,
+    ) -> service::static_config::StaticConfig {
+        let mut static_config = service_config.clone();
+        if let Some(alignment) = self.payload_alignment {
+            let type_details = &mut static_config.publish_subscribe_mut().type_details;
+            type_details.payload_alignment = type_details.payload_alignment.max(alignment.value());
+        }
+        static_config
+    }
+
+    // The memory pools of the [`DataSegmentAllocator::MultiPool`] sorted by their slice length.
+    // Without a configured memory pool a single pool provides a sample of `max_slice_len`
+    // for every slot a sample can be stored in.
+    pub(crate) fn mem_pools(
+        &self,
+        max_slice_len: usize,
+        number_of_samples: usize,
+    ) -> Vec<MemPoolConfig> {
+        if self.mem_pools.is_empty() {
+            return vec![MemPoolConfig {
+                max_slice_len,
+                number_of_samples,
+            }];
+        }
+
+        let mut mem_pools = self.mem_pools.clone();
+        mem_pools.sort_by_key(|pool| pool.max_slice_len);
+        mem_pools
+    }
 }
 
 /// Factory to create a new [`Publisher`] port/endpoint for
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
 /// communication.
 #[derive(Debug)]
-pub struct PortFactoryPublisher<'factory, Service: service::Service, PayloadType: Debug + ?Sized> {
+pub struct PortFactoryPublisher<
+    'factory,
+    Service: service::Service,
+    PayloadType: Debug + ?Sized,
+    UserHeader: Debug = (),
+> {
     config: LocalPublisherConfig,
-    pub(crate) factory: &'factory PortFactory<Service, PayloadType>,
+    pub(crate) factory: &'factory PortFactory<Service, PayloadType, UserHeader>,
 }
 
-impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized>
-    PortFactoryPublisher<'factory, Service, PayloadType>
+impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized, UserHeader: Debug>
+    PortFactoryPublisher<'factory, Service, PayloadType, UserHeader>
 {
-    pub(crate) fn new(factory: &'factory PortFactory<Service, PayloadType>) -> Self {
+    pub(crate) fn new(factory: &'factory PortFactory<Service, PayloadType, UserHeader>) -> Self {
         Self {
-            config: LocalPublisherConfig {
-                degration_callback: None,
-                max_slice_len: 1,
-                max_loaned_samples: factory
-                    .service
-                    .state()
-                    .shared_node
-                    .config()
-                    .defaults
-                    .publish_subscribe
-                    .publisher_max_loaned_samples,
-                unable_to_deliver_strategy: factory
-                    .service
-                    .state()
-                    .shared_node
-                    .config()
-                    .defaults
-                    .publish_subscribe
-                    .unable_to_deliver_strategy,
-            },
+            config: LocalPublisherConfig::new(factory.service.state().shared_node.config()),
             factory,
         }
     }
 
     /// Defines how many [`crate::sample_mut::SampleMut`] the [`Publisher`] can loan with
     /// [`Publisher::loan()`] or
-    /// [`Publisher::loan_uninit()`] in parallel.
+    /// [`Publisher::loan_uninit()`] in parallel. Every further loan fails with
+    /// [`PublisherLoanError::ExceedsMaxLoans`](crate::port::publisher::PublisherLoanError::ExceedsMaxLoans),
+    /// the outstanding loans are returned by [`Publisher::loaned_samples()`].
     pub fn max_loaned_samples(mut self, value: usize) -> Self {
         self.config.max_loaned_samples = value;
         self
@@ -185,6 +354,70 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized>
         self
     }
 
+    /// Sets the [`ZeroingPolicy`] that defines when the memory of the data segment is zeroed.
+    pub fn zeroing_policy(mut self, value: ZeroingPolicy) -> Self {
+        self.config.zeroing_policy = value;
+        self
+    }
+
+    /// Sets the [`DataSegmentAllocator`] of the data segment of the [`Publisher`].
+    pub fn data_segment_allocator(mut self, value: DataSegmentAllocator) -> Self {
+        self.config.data_segment_allocator = value;
+        self
+    }
+
+    /// Places a canary region with a known pattern behind the payload of every sample. The
+    /// canary is verified when the sample is sent and when it is released and the
+    /// [`Publisher`] panics when an out-of-bounds write into the neighboring memory is detected.
+    /// It is intended for development since it costs additional memory and time.
+    pub fn enable_canaries(mut self, value: bool) -> Self {
+        self.config.enable_canaries = value;
+        self
+    }
+
+    /// Aligns the payload of every loaned sample to at least the provided [`Alignment`], for
+    /// instance to 64 bytes for SIMD kernels or to 4 KiB for DMA engines. The alignment of the
+    /// payload type of the service is used when it is larger. The
+    /// [`crate::port::subscriber::Subscriber`]s acquire the alignment from the sample, so that
+    /// every [`Publisher`] of a service can choose its own alignment. Alignments beyond the page
+    /// size are not supported since the data segment is mapped at page boundaries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let pubsub = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<[f32]>()
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = pubsub.publisher_builder()
+    ///                     .max_slice_len(1024)
+    ///                     .payload_alignment(Alignment::new(64).unwrap())
+    ///                     .create()?;
+    ///
+    /// let sample = publisher.loan_slice(1024)?;
+    /// assert_eq!(sample.payload().as_ptr() as usize % 64, 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn payload_alignment(mut self, value: Alignment) -> Self {
+        self.config.payload_alignment = Some(value);
+        self
+    }
+
+    /// Tracks which [`crate::port::subscriber::Subscriber`] has processed the
+    /// [`crate::sample::Sample`]s that are sent with
+    /// [`crate::sample_mut::SampleMut::send_acknowledged()`]. Intended for command-style
+    /// services that require a stronger guarantee than the delivery into the buffer, see
+    /// [`Publisher::acknowledgment_report()`].
+    pub fn enable_acknowledgments(mut self, value: bool) -> Self {
+        self.config.enable_acknowledgments = value;
+        self
+    }
+
     /// Sets the [`DegrationCallback`] of the [`Publisher`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegrationAction`] measures will be taken.
@@ -207,18 +440,64 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized>
         self
     }
 
+    /// Sets a hook that is called with the [`Header`] of every [`crate::sample::Sample`]
+    /// right before it is delivered to the [`crate::port::subscriber::Subscriber`]s. The
+    /// sequence number is already assigned, so that the hook can correlate the send with the
+    /// receive hook of the [`crate::port::subscriber::Subscriber`] to trace the latency, see
+    /// [`crate::service::port_factory::subscriber::PortFactorySubscriber::set_receive_hook()`].
+    /// The hook runs in the sending thread and shall therefore return quickly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::header::publish_subscribe::Header;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let pubsub = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = pubsub.publisher_builder()
+    ///                     .set_send_hook(Some(|header: &Header| {
+    ///                         println!("send sample {}", header.sequence_number())
+    ///                     }))
+    ///                     .create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_send_hook<F: Fn(&Header) + 'static>(mut self, hook: Option<F>) -> Self {
+        self.config.send_hook = hook.map(SampleHook::new);
+        self
+    }
+
+    /// Returns how the data segment of the [`Publisher`] would be composed with the current
+    /// settings without creating the [`Publisher`]. Use it to find out which setting has to
+    /// be reduced when the [`Publisher`] exceeds a memory limit.
+    pub fn memory_breakdown(&self) -> PublisherMemoryBreakdown {
+        PublisherMemoryBreakdown::new(
+            self.config
+                .static_config_of(&self.factory.service.state().static_config)
+                .publish_subscribe(),
+            &self.config,
+        )
+    }
+
     /// Creates a new [`Publisher`] or returns a [`PublisherCreateError`] on failure.
-    pub fn create(self) -> Result<Publisher<Service, PayloadType>, PublisherCreateError> {
+    pub fn create(
+        self,
+    ) -> Result<Publisher<Service, PayloadType, UserHeader>, PublisherCreateError> {
         let origin = format!("{:?}", self);
         Ok(
-            fail!(from origin, when Publisher::new(&self.factory.service, self.factory.service.state().static_config.publish_subscribe(), self.config),
+            fail!(from origin, when Publisher::new(&self.factory.service, self.config),
                 "Failed to create new Publisher port."),
         )
     }
 }
 
-impl<'factory, Service: service::Service, PayloadType: Debug>
-    PortFactoryPublisher<'factory, Service, [PayloadType]>
+impl<'factory, Service: service::Service, PayloadType: Debug, UserHeader: Debug>
+    PortFactoryPublisher<'factory, Service, [PayloadType], UserHeader>
 {
     /// Sets the maximum slice length that a user can allocate with
     /// [`Publisher::loan_slice()`] or [`Publisher::loan_slice_uninit()`].
@@ -226,4 +505,58 @@ impl<'factory, Service: service::Service, PayloadType: Debug>
         self.config.max_slice_len = value;
         self
     }
+
+    /// Sets the [`AllocationStrategy`] that allows to loan slices that are larger than the
+    /// [`PortFactoryPublisher::max_slice_len()`]. The `max_slice_len` defines then only the
+    /// initial size of the data segment.
+    pub fn allocation_strategy(mut self, value: AllocationStrategy) -> Self {
+        self.config.allocation_strategy = value;
+        self
+    }
+
+    /// Adds a memory pool with `number_of_samples` samples that fit slices with up to
+    /// `max_slice_len` elements and sets the [`DataSegmentAllocator::MultiPool`]. The largest
+    /// memory pool defines the maximum slice length of the [`Publisher`] and replaces
+    /// [`PortFactoryPublisher::max_slice_len()`].
+    ///
+    /// A loan fails with
+    /// [`PublisherLoanError::OutOfMemory`](crate::port::publisher::PublisherLoanError::OutOfMemory)
+    /// when every memory pool that fits the slice is exhausted, therefore the memory pools
+    /// together shall provide at least
+    /// [`PublisherMemoryBreakdown::number_of_samples()`] samples. The creation fails with
+    /// [`PublisherCreateError::InvalidMemPoolConfig`] when a memory pool has no samples, more
+    /// than [`MAX_NUMBER_OF_MEM_POOLS`] memory pools are added or a [`AllocationStrategy`]
+    /// other than [`AllocationStrategy::Static`] is set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let pubsub = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<[u8]>()
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = pubsub.publisher_builder()
+    ///                     .mem_pool(64, 32)
+    ///                     .mem_pool(1024, 8)
+    ///                     .mem_pool(65536, 2)
+    ///                     .create()?;
+    ///
+    /// // is placed into the memory pool with samples of up to 1024 bytes
+    /// let sample = publisher.loan_slice(200)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mem_pool(mut self, max_slice_len: usize, number_of_samples: usize) -> Self {
+        self.config.data_segment_allocator = DataSegmentAllocator::MultiPool;
+        self.config.mem_pools.push(MemPoolConfig {
+            max_slice_len,
+            number_of_samples,
+        });
+        self
+    }
 }
@@ -0,0 +1,91 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_bb_posix::signal::Signal;
+//! use core::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let event = node.service_builder("MyEventName".try_into()?)
+//!     .event()
+//!     .open_or_create()?;
+//!
+//! let bridge = event.signal_bridge_builder()
+//!     .map(EventId::new(3), Signal::UserDefined1)
+//!     .map(EventId::new(4), Signal::UserDefined2)
+//!     .cycle_time(Duration::from_millis(50))
+//!     .create()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::time::Duration;
+
+use iceoryx2_bb_log::fail;
+
+use crate::port::event_id::EventId;
+use crate::port::signal_bridge::{BridgeSignal, SignalBridge, SignalBridgeCreateError};
+use crate::service;
+
+use super::event::PortFactory;
+
+const DEFAULT_CYCLE_TIME: Duration = Duration::from_millis(100);
+
+/// Factory to create a new [`SignalBridge`] for
+/// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactorySignalBridge<'factory, Service: service::Service> {
+    pub(crate) factory: &'factory PortFactory<Service>,
+    mapping: Vec<(EventId, BridgeSignal)>,
+    cycle_time: Duration,
+}
+
+impl<'factory, Service: service::Service> PortFactorySignalBridge<'factory, Service> {
+    pub(crate) fn new(factory: &'factory PortFactory<Service>) -> Self {
+        Self {
+            factory,
+            mapping: vec![],
+            cycle_time: DEFAULT_CYCLE_TIME,
+        }
+    }
+
+    /// Raises the signal in the current process whenever the [`EventId`] is received. The
+    /// signal is either a [`iceoryx2_bb_posix::signal::Signal`] or a
+    /// [`iceoryx2_bb_posix::signal::RealTimeSignal`], see [`BridgeSignal`]. An [`EventId`] can
+    /// be mapped to multiple signals, unmapped [`EventId`]s are ignored.
+    pub fn map<S: Into<BridgeSignal>>(mut self, event_id: EventId, signal: S) -> Self {
+        self.mapping.push((event_id, signal.into()));
+        self
+    }
+
+    /// Defines the maximum time the background thread of the [`SignalBridge`] waits for
+    /// events before it checks if it shall stop. It defines how long the drop of the
+    /// [`SignalBridge`] can take at most.
+    pub fn cycle_time(mut self, value: Duration) -> Self {
+        self.cycle_time = value;
+        self
+    }
+
+    /// Creates the [`SignalBridge`] or returns a [`SignalBridgeCreateError`] on failure.
+    pub fn create(self) -> Result<SignalBridge<'factory>, SignalBridgeCreateError> {
+        Ok(
+            fail!(from self, when SignalBridge::new(self.factory, self.mapping.clone(), self.cycle_time),
+                "Failed to create new SignalBridge."),
+        )
+    }
+}
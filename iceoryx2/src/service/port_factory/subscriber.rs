@@ -30,43 +30,177 @@
 //! ```
 
 use std::fmt::Debug;
+use std::time::Duration;
 
 use iceoryx2_bb_log::fail;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     port::{
         port_identifiers::{UniquePublisherId, UniqueSubscriberId},
         subscriber::{Subscriber, SubscriberCreateError},
-        DegrationAction, DegrationCallback,
+        DegrationAction, DegrationCallback, SampleHook,
     },
-    service,
+    service::{self, header::publish_subscribe::Header},
 };
 
 use super::publish_subscribe::PortFactory;
 
+/// Defines to which member of a work-sharing group of [`Subscriber`]s, see
+/// [`PortFactorySubscriber::group()`], a [`crate::sample::Sample`] is delivered.
+#[derive(Debug, Default, Eq, Hash, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum GroupDeliveryStrategy {
+    /// The members of the group receive the [`crate::sample::Sample`]s in turns.
+    #[default]
+    RoundRobin,
+    /// The member with the least amount of unreceived [`crate::sample::Sample`]s in its buffer
+    /// receives the [`crate::sample::Sample`].
+    LeastLoaded,
+}
+
+/// Defines in which order a [`Subscriber`] receives the [`crate::sample::Sample`]s when multiple
+/// [`crate::port::publisher::Publisher`]s are connected, see
+/// [`crate::service::builder::publish_subscribe::Builder::subscriber_receive_order()`].
+#[derive(Debug, Default, Eq, Hash, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum ReceiveOrder {
+    /// The [`crate::port::publisher::Publisher`]s take turns. Every receive call starts with the
+    /// [`crate::port::publisher::Publisher`] after the one that provided the previous
+    /// [`crate::sample::Sample`], so that a busy [`crate::port::publisher::Publisher`] cannot
+    /// starve the others.
+    #[default]
+    RoundRobin,
+    /// The [`crate::sample::Sample`]s of all [`crate::port::publisher::Publisher`]s are received
+    /// in the order in which they were sent, see
+    /// [`crate::service::header::publish_subscribe::Header::sequence_number()`].
+    SendOrder,
+    /// Like [`ReceiveOrder::SendOrder`] but a [`crate::sample::Sample`] is held back as long as
+    /// a [`crate::sample::Sample`] with a smaller sequence number is missing, so that all
+    /// [`crate::sample::Sample`]s of all [`crate::port::publisher::Publisher`]s are received
+    /// in a total order. A missing [`crate::sample::Sample`] may never arrive, for instance when
+    /// it was dropped or delivered to another group member, therefore the held back
+    /// [`crate::sample::Sample`] is received at the latest after the provided duration. Trades
+    /// latency for determinism.
+    TotalOrder(Duration),
+}
+
+/// Defines which [`crate::sample::Sample`]s of a [`crate::port::publisher::Publisher`] are
+/// delivered to a [`Subscriber`], see [`PortFactorySubscriber::decimation()`]. The
+/// [`crate::port::publisher::Publisher`] drops all other [`crate::sample::Sample`]s before they
+/// enter the buffer of the [`Subscriber`], so that a low-rate consumer of a high-rate service
+/// does not have to receive and discard them. The decimation is applied per
+/// [`crate::port::publisher::Publisher`].
+#[derive(Debug, Default, Eq, Hash, PartialEq, Clone, Copy)]
+pub enum Decimation {
+    /// Every [`crate::sample::Sample`] is delivered.
+    #[default]
+    Disabled,
+    /// Only the first and then every Nth [`crate::sample::Sample`] is delivered.
+    EveryNth(usize),
+    /// At most one [`crate::sample::Sample`] is delivered per interval.
+    MinInterval(Duration),
+}
+
+impl Decimation {
+    // every sample is delivered when N is 0 or 1 or the interval is zero
+    pub(crate) fn normalize(self) -> Self {
+        match self {
+            Decimation::EveryNth(n) if n <= 1 => Decimation::Disabled,
+            Decimation::MinInterval(d) if d.is_zero() => Decimation::Disabled,
+            v => v,
+        }
+    }
+}
+
+/// Defines how a [`crate::port::publisher::Publisher`] handles a [`crate::sample::Sample`] when
+/// the buffer of a [`Subscriber`] is full, see
+/// [`PortFactorySubscriber::unable_to_deliver_strategy()`]. It overrides the safe overflow
+/// setting of the service and the
+/// [`crate::service::port_factory::publisher::UnableToDeliverStrategy`] of the
+/// [`crate::port::publisher::Publisher`] for this [`Subscriber`].
+#[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
+pub enum OverflowStrategy {
+    /// The oldest [`crate::sample::Sample`] in the buffer is replaced with the new one.
+    DiscardOldest,
+    /// The new [`crate::sample::Sample`] is not delivered to the [`Subscriber`].
+    DiscardNewest,
+    /// The [`crate::port::publisher::Publisher`] blocks until the [`Subscriber`] has received
+    /// a [`crate::sample::Sample`] and there is space in the buffer again.
+    Block,
+}
+
+impl OverflowStrategy {
+    // without a strategy the connection follows the safe overflow setting of the service
+    pub(crate) fn enables_safe_overflow(strategy: Option<Self>, service_setting: bool) -> bool {
+        match strategy {
+            Some(strategy) => strategy == OverflowStrategy::DiscardOldest,
+            None => service_setting,
+        }
+    }
+}
+
+/// Decides on the receiving side whether a [`crate::sample::Sample`] is handed to the user,
+/// see [`PortFactorySubscriber::filter()`]. The payload is provided as pointer so that the
+/// [`SubscriberConfig`] does not depend on the payload type.
+pub(crate) struct SampleFilter(Box<dyn Fn(&Header, *const u8) -> bool>);
+
+impl SampleFilter {
+    fn new<F: Fn(&Header, *const u8) -> bool + 'static>(filter: F) -> Self {
+        Self(Box::new(filter))
+    }
+
+    pub(crate) fn accepts(&self, header: &Header, payload: *const u8) -> bool {
+        (self.0)(header, payload)
+    }
+}
+
+impl Debug for SampleFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SampleFilter")
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct SubscriberConfig {
     pub(crate) buffer_size: Option<usize>,
+    pub(crate) group: Option<u64>,
+    pub(crate) max_samples_per_publisher: Option<usize>,
     pub(crate) degration_callback: Option<DegrationCallback<'static>>,
+    pub(crate) is_observer: bool,
+    pub(crate) decimation: Decimation,
+    pub(crate) filter: Option<SampleFilter>,
+    pub(crate) receive_hook: Option<SampleHook>,
+    pub(crate) overflow_strategy: Option<OverflowStrategy>,
 }
 
 /// Factory to create a new [`Subscriber`] port/endpoint for
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
 /// communication.
 #[derive(Debug)]
-pub struct PortFactorySubscriber<'factory, Service: service::Service, PayloadType: Debug + ?Sized> {
+pub struct PortFactorySubscriber<
+    'factory,
+    Service: service::Service,
+    PayloadType: Debug + ?Sized,
+    UserHeader: Debug = (),
+> {
     config: SubscriberConfig,
-    pub(crate) factory: &'factory PortFactory<Service, PayloadType>,
+    pub(crate) factory: &'factory PortFactory<Service, PayloadType, UserHeader>,
 }
 
-impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized>
-    PortFactorySubscriber<'factory, Service, PayloadType>
+impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized, UserHeader: Debug>
+    PortFactorySubscriber<'factory, Service, PayloadType, UserHeader>
 {
-    pub(crate) fn new(factory: &'factory PortFactory<Service, PayloadType>) -> Self {
+    pub(crate) fn new(factory: &'factory PortFactory<Service, PayloadType, UserHeader>) -> Self {
         Self {
             config: SubscriberConfig {
                 buffer_size: None,
+                group: None,
+                max_samples_per_publisher: None,
                 degration_callback: None,
+                is_observer: false,
+                decimation: Decimation::Disabled,
+                filter: None,
+                receive_hook: None,
+                overflow_strategy: None,
             },
             factory,
         }
@@ -77,6 +211,75 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized>
         self
     }
 
+    /// Adds the [`Subscriber`] to the work-sharing group with the provided id. Every
+    /// [`crate::sample::Sample`] is delivered to exactly one member of the group, selected with
+    /// the [`GroupDeliveryStrategy`] of the service, see
+    /// [`crate::service::builder::publish_subscribe::Builder::subscriber_group_delivery()`].
+    /// [`Subscriber`]s without a group receive every [`crate::sample::Sample`].
+    pub fn group(mut self, id: u64) -> Self {
+        self.config.group = Some(id);
+        self
+    }
+
+    /// Defines the [`Decimation`] of the [`Subscriber`], so that it receives only every Nth
+    /// [`crate::sample::Sample`] or at most one per interval. It is ignored when the
+    /// [`Subscriber`] is a member of a work-sharing group, see
+    /// [`PortFactorySubscriber::group()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::port_factory::subscriber::Decimation;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let pubsub = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let subscriber = pubsub.subscriber_builder()
+    ///                     .decimation(Decimation::EveryNth(10))
+    ///                     .create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn decimation(mut self, value: Decimation) -> Self {
+        self.config.decimation = value.normalize();
+        self
+    }
+
+    /// Defines the [`OverflowStrategy`] of the [`Subscriber`], so that subscribers of the same
+    /// service can have different loss semantics. When it is not set, the safe overflow setting
+    /// of the service and the
+    /// [`crate::service::port_factory::publisher::UnableToDeliverStrategy`] of the
+    /// [`crate::port::publisher::Publisher`] are used. The number of
+    /// [`crate::sample::Sample`]s that were dropped can be acquired with
+    /// [`Subscriber::number_of_dropped_samples()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::port_factory::subscriber::OverflowStrategy;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let pubsub = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let subscriber = pubsub.subscriber_builder()
+    ///                     .unable_to_deliver_strategy(OverflowStrategy::DiscardNewest)
+    ///                     .create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn unable_to_deliver_strategy(mut self, value: OverflowStrategy) -> Self {
+        self.config.overflow_strategy = Some(value);
+        self
+    }
+
     /// Sets the [`DegrationCallback`] of the [`Subscriber`]. Whenever a connection to a
     /// [`crate::port::subscriber::Subscriber`] is corrupted or it seems to be dead, this callback
     /// is called and depending on the returned [`DegrationAction`] measures will be taken.
@@ -99,8 +302,20 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized>
         self
     }
 
+    /// Sets a hook that is called with the [`Header`] of every [`crate::sample::Sample`] that
+    /// is received, after it passed the [`PortFactorySubscriber::filter()`]. Together with
+    /// [`crate::service::port_factory::publisher::PortFactoryPublisher::set_send_hook()`] it
+    /// allows to trace the latency of every [`crate::sample::Sample`] without touching the
+    /// payload. The hook runs in the receiving thread and shall therefore return quickly.
+    pub fn set_receive_hook<F: Fn(&Header) + 'static>(mut self, hook: Option<F>) -> Self {
+        self.config.receive_hook = hook.map(SampleHook::new);
+        self
+    }
+
     /// Creates a new [`Subscriber`] or returns a [`SubscriberCreateError`] on failure.
-    pub fn create(self) -> Result<Subscriber<Service, PayloadType>, SubscriberCreateError> {
+    pub fn create(
+        self,
+    ) -> Result<Subscriber<Service, PayloadType, UserHeader>, SubscriberCreateError> {
         let origin = format!("{:?}", self);
         Ok(
             fail!(from origin, when Subscriber::new(&self.factory.service, self.factory.service.state().static_config.publish_subscribe(), self.config),
@@ -108,3 +323,57 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized>
         )
     }
 }
+
+impl<'factory, Service: service::Service, PayloadType: Debug, UserHeader: Debug>
+    PortFactorySubscriber<'factory, Service, PayloadType, UserHeader>
+{
+    /// Sets a filter that is evaluated for every received [`crate::sample::Sample`] before it
+    /// is handed to the user. [`crate::sample::Sample`]s for which the filter returns false are
+    /// released immediately without being copied, for instance to downsample a high-frequency
+    /// service or to receive only the [`crate::sample::Sample`]s of a specific
+    /// [`crate::port::publisher::Publisher`]. Filtered [`crate::sample::Sample`]s are still
+    /// counted by [`Subscriber::number_of_pending_samples()`] until they are received.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+    /// let pubsub = node.service_builder("My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let subscriber = pubsub.subscriber_builder()
+    ///                     .filter(|_header, payload| *payload % 2 == 0)
+    ///                     .create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn filter<F: Fn(&Header, &PayloadType) -> bool + 'static>(mut self, filter: F) -> Self {
+        self.config.filter = Some(SampleFilter::new(move |header, payload| {
+            filter(header, unsafe { &*payload.cast::<PayloadType>() })
+        }));
+        self
+    }
+}
+
+impl<'factory, Service: service::Service, PayloadType: Debug, UserHeader: Debug>
+    PortFactorySubscriber<'factory, Service, [PayloadType], UserHeader>
+{
+    /// Sets a filter that is evaluated for every received [`crate::sample::Sample`] before it
+    /// is handed to the user. [`crate::sample::Sample`]s for which the filter returns false are
+    /// released immediately without being copied. Filtered [`crate::sample::Sample`]s are
+    /// still counted by [`Subscriber::number_of_pending_samples()`] until they are received.
+    pub fn filter<F: Fn(&Header, &[PayloadType]) -> bool + 'static>(mut self, filter: F) -> Self {
+        self.config.filter = Some(SampleFilter::new(move |header, payload| {
+            let number_of_elements =
+                header.payload_type_layout().size() / core::mem::size_of::<PayloadType>();
+            filter(header, unsafe {
+                core::slice::from_raw_parts(payload.cast(), number_of_elements)
+            })
+        }));
+        self
+    }
+}
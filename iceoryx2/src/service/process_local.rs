@@ -33,7 +33,7 @@
 //! See [`Service`](crate::service) for more detailed examples.
 
 use crate::service::dynamic_config::DynamicConfig;
-use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::shm_allocator::selectable_allocator::SelectableAllocator;
 use iceoryx2_cal::*;
 
 use super::ServiceState;
@@ -49,7 +49,7 @@ impl crate::service::Service for Service {
     type ConfigSerializer = serialize::toml::Toml;
     type DynamicStorage = dynamic_storage::process_local::Storage<DynamicConfig>;
     type ServiceNameHasher = hash::sha1::Sha1;
-    type SharedMemory = shared_memory::process_local::Memory<PoolAllocator>;
+    type SharedMemory = shared_memory::process_local::Memory<SelectableAllocator>;
     type Connection = zero_copy_connection::process_local::Connection;
     type Event = event::sem_bitset_process_local::Event;
     type Monitoring = monitoring::process_local::ProcessLocalMonitoring;
@@ -0,0 +1,128 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Examples
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<zero_copy::Service>()?;
+//! let blackboard = node.service_builder("MyBlackboardName".try_into()?)
+//!     .blackboard::<u64>()
+//!     .add::<f32>(0, 1.5)
+//!     .open_or_create()?;
+//!
+//! println!("max readers:                  {:?}", blackboard.static_config().max_supported_readers());
+//! println!("max writers:                  {:?}", blackboard.static_config().max_supported_writers());
+//! println!("number of entries:            {:?}", blackboard.static_config().number_of_entries());
+//!
+//! # Ok(())
+//! # }
+//! ```
+use std::alloc::Layout;
+
+use crate::config;
+use serde::{Deserialize, Serialize};
+
+/// The name, size and alignment of the key type or of the value type of an entry of a
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based service.
+#[derive(Default, Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct EntryTypeDetails {
+    pub type_name: String,
+    pub size: usize,
+    pub alignment: usize,
+}
+
+impl EntryTypeDetails {
+    pub(crate) fn from<T>() -> Self {
+        Self {
+            type_name: core::any::type_name::<T>().to_string(),
+            size: core::mem::size_of::<T>(),
+            alignment: core::mem::align_of::<T>(),
+        }
+    }
+}
+
+/// Describes a single entry of the blackboard, its value type and where the value is stored
+/// in the entry memory of the service.
+#[derive(Default, Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct EntryDetails {
+    pub(crate) value_type_details: EntryTypeDetails,
+    pub(crate) offset: usize,
+}
+
+impl EntryDetails {
+    /// Returns the [`EntryTypeDetails`] of the value that is stored in the entry
+    pub fn value_type_details(&self) -> &EntryTypeDetails {
+        &self.value_type_details
+    }
+}
+
+/// The static configuration of an
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based service. Contains all parameters that do not change during the lifetime of a
+/// [`Service`](crate::service::Service).
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct StaticConfig {
+    pub(crate) max_readers: usize,
+    pub(crate) max_writers: usize,
+    pub(crate) entries_size: usize,
+    pub(crate) entries_alignment: usize,
+    pub(crate) key_type_details: EntryTypeDetails,
+    // the keys are stored as array at the beginning of the entry memory, in the same order
+    // as the entries
+    pub(crate) entries: Vec<EntryDetails>,
+}
+
+impl StaticConfig {
+    pub(crate) fn new(config: &config::Config) -> Self {
+        Self {
+            max_readers: config.defaults.blackboard.max_readers,
+            max_writers: config.defaults.blackboard.max_writers,
+            entries_size: 0,
+            entries_alignment: 1,
+            key_type_details: EntryTypeDetails::default(),
+            entries: vec![],
+        }
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::reader::Reader`] ports
+    pub fn max_supported_readers(&self) -> usize {
+        self.max_readers
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::writer::Writer`] ports
+    pub fn max_supported_writers(&self) -> usize {
+        self.max_writers
+    }
+
+    /// Returns the [`EntryTypeDetails`] of the key type
+    pub fn key_type_details(&self) -> &EntryTypeDetails {
+        &self.key_type_details
+    }
+
+    /// Returns the number of entries stored in the blackboard
+    pub fn number_of_entries(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the [`EntryDetails`] of all entries stored in the blackboard
+    pub fn entries(&self) -> &[EntryDetails] {
+        &self.entries
+    }
+
+    pub(crate) fn entries_layout(&self) -> Layout {
+        unsafe { Layout::from_size_align_unchecked(self.entries_size, self.entries_alignment) }
+    }
+}
@@ -28,6 +28,8 @@
 //! # Ok(())
 //! # }
 //! ```
+use core::time::Duration;
+
 use crate::config;
 use serde::{Deserialize, Serialize};
 
@@ -39,6 +41,10 @@ pub struct StaticConfig {
     pub(crate) max_notifiers: usize,
     pub(crate) max_listeners: usize,
     pub(crate) event_id_max_value: usize,
+    #[serde(default)]
+    pub(crate) deadline: Option<Duration>,
+    #[serde(default)]
+    pub(crate) notifier_liveliness: Option<Duration>,
 }
 
 impl StaticConfig {
@@ -47,6 +53,8 @@ impl StaticConfig {
             max_notifiers: config.defaults.event.max_notifiers,
             max_listeners: config.defaults.event.max_listeners,
             event_id_max_value: config.defaults.event.event_id_max_value,
+            deadline: None,
+            notifier_liveliness: None,
         }
     }
 
@@ -64,4 +72,18 @@ impl StaticConfig {
     pub fn event_id_max_value(&self) -> usize {
         self.event_id_max_value
     }
+
+    /// Returns the deadline of the service. When no [`crate::port::notifier::Notifier`]
+    /// notified within the deadline, the deadline is missed. Returns [`None`] when the service
+    /// has no deadline.
+    pub fn deadline(&self) -> Option<Duration> {
+        self.deadline
+    }
+
+    /// Returns the interval in which every [`crate::port::notifier::Notifier`] must show a
+    /// sign of life, otherwise it has lost its liveliness. Returns [`None`] when the liveliness
+    /// of the notifiers is not tracked.
+    pub fn notifier_liveliness(&self) -> Option<Duration> {
+        self.notifier_liveliness
+    }
 }
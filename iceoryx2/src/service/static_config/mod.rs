@@ -10,6 +10,11 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+/// The static service configuration of an
+/// [`MessagingPattern::Blackboard`]
+/// based service.
+pub mod blackboard;
+
 /// The static service configuration of an
 /// [`MessagingPattern::Event`]
 /// based service.
@@ -26,6 +31,7 @@ pub mod type_details;
 
 use crate::service::messaging_pattern::MessagingPattern;
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_posix::permission::Permission;
 use iceoryx2_cal::hash::Hash;
 use serde::{Deserialize, Serialize};
 
@@ -33,6 +39,19 @@ use crate::config;
 
 use super::{attribute::AttributeSet, service_name::ServiceName};
 
+/// Defines how long a [`crate::service::Service`] exists.
+#[derive(Debug, Default, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum ServiceLifetime {
+    /// The [`crate::service::Service`] is removed as soon as the last
+    /// [`Node`](crate::node::Node) that owns it, starting with its creator, releases it or dies.
+    #[default]
+    OwnerBound,
+    /// The [`crate::service::Service`] outlives all [`Node`](crate::node::Node)s that use it,
+    /// including its creator, and exists until it is explicitly removed with
+    /// [`crate::service::Service::remove()`].
+    Persistent,
+}
+
 /// Defines a common set of static service configuration details every service shares.
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct StaticConfig {
@@ -40,6 +59,14 @@ pub struct StaticConfig {
     service_name: ServiceName,
     pub(crate) attributes: AttributeSet,
     pub(crate) messaging_pattern: MessagingPattern,
+    #[serde(default = "default_permission")]
+    permission: Permission,
+    #[serde(default)]
+    lifetime: ServiceLifetime,
+}
+
+fn default_permission() -> Permission {
+    Permission::OWNER_ALL
 }
 
 fn create_uuid<Hasher: Hash>(
@@ -65,15 +92,36 @@ impl StaticConfig {
             service_name: service_name.clone(),
             messaging_pattern,
             attributes: AttributeSet::new(),
+            permission: default_permission(),
+            lifetime: ServiceLifetime::default(),
         }
     }
 
     pub(crate) fn new_publish_subscribe<Hasher: Hash>(
         service_name: &ServiceName,
         config: &config::Config,
+    ) -> Self {
+        let messaging_pattern = MessagingPattern::PublishSubscribe(Box::new(
+            publish_subscribe::StaticConfig::new(config),
+        ));
+        Self {
+            uuid: create_uuid::<Hasher>(service_name, &messaging_pattern)
+                .value()
+                .into(),
+            service_name: service_name.clone(),
+            messaging_pattern,
+            attributes: AttributeSet::new(),
+            permission: default_permission(),
+            lifetime: ServiceLifetime::default(),
+        }
+    }
+
+    pub(crate) fn new_blackboard<Hasher: Hash>(
+        service_name: &ServiceName,
+        config: &config::Config,
     ) -> Self {
         let messaging_pattern =
-            MessagingPattern::PublishSubscribe(publish_subscribe::StaticConfig::new(config));
+            MessagingPattern::Blackboard(Box::new(blackboard::StaticConfig::new(config)));
         Self {
             uuid: create_uuid::<Hasher>(service_name, &messaging_pattern)
                 .value()
@@ -81,6 +129,8 @@ impl StaticConfig {
             service_name: service_name.clone(),
             messaging_pattern,
             attributes: AttributeSet::new(),
+            permission: default_permission(),
+            lifetime: ServiceLifetime::default(),
         }
     }
 
@@ -104,6 +154,41 @@ impl StaticConfig {
         &self.messaging_pattern
     }
 
+    /// Returns the [`Permission`] of the static storage and all shared memory segments of the
+    /// [`crate::service::Service`]
+    pub fn permission(&self) -> Permission {
+        self.permission
+    }
+
+    /// Returns the [`ServiceLifetime`] of the [`crate::service::Service`]
+    pub fn lifetime(&self) -> ServiceLifetime {
+        self.lifetime
+    }
+
+    pub(crate) fn set_lifetime(&mut self, value: ServiceLifetime) {
+        self.lifetime = value;
+    }
+
+    pub(crate) fn set_permission(&mut self, value: Permission) {
+        // the owner must always be able to create and remove the resources of the service
+        self.permission =
+            (value & Permission::ALL) | Permission::OWNER_READ | Permission::OWNER_WRITE;
+    }
+
+    // The dynamic config and the zero copy connections are written by every participant, for
+    // instance when a subscriber registers itself or returns a sample. Everyone who may read
+    // the service therefore requires write access to them.
+    pub(crate) fn participant_permission(&self) -> Permission {
+        let mut permission = self.permission;
+        if permission.has(Permission::GROUP_READ) {
+            permission |= Permission::GROUP_WRITE;
+        }
+        if permission.has(Permission::OTHERS_READ) {
+            permission |= Permission::OTHERS_WRITE;
+        }
+        permission
+    }
+
     pub(crate) fn has_same_messaging_pattern(&self, rhs: &StaticConfig) -> bool {
         self.messaging_pattern
             .is_same_pattern(&rhs.messaging_pattern)
@@ -146,4 +231,23 @@ impl StaticConfig {
             }
         }
     }
+
+    pub(crate) fn blackboard(&self) -> &blackboard::StaticConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen. Trying to access blackboard::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn blackboard_mut(&mut self) -> &mut blackboard::StaticConfig {
+        let origin = format!("{:?}", self);
+        match &mut self.messaging_pattern {
+            MessagingPattern::Blackboard(ref mut v) => v,
+            m => {
+                fatal_panic!(from origin, "This should never happen. Trying to access blackboard::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
 }
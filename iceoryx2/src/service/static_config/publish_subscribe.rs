@@ -24,17 +24,23 @@
 //! println!("type details:                     {:?}", pubsub.static_config().type_details());
 //! println!("max publishers:                   {:?}", pubsub.static_config().max_supported_publishers());
 //! println!("max subscribers:                  {:?}", pubsub.static_config().max_supported_subscribers());
+//! println!("max observers:                    {:?}", pubsub.static_config().max_supported_observers());
 //! println!("subscriber buffer size:           {:?}", pubsub.static_config().subscriber_max_buffer_size());
 //! println!("history size:                     {:?}", pubsub.static_config().history_size());
 //! println!("subscriber max borrowed samples:  {:?}", pubsub.static_config().subscriber_max_borrowed_samples());
 //! println!("safe overflow:                    {:?}", pubsub.static_config().has_safe_overflow());
+//! println!("dead-letter service:              {:?}", pubsub.static_config().dead_letter_service());
 //!
 //! # Ok(())
 //! # }
 //! ```
 
+use core::time::Duration;
+
 use super::type_details::TypeDetails;
 use crate::config;
+use crate::service::port_factory::subscriber::{GroupDeliveryStrategy, ReceiveOrder};
+use crate::service::service_name::ServiceName;
 use serde::{Deserialize, Serialize};
 
 /// The static configuration of an
@@ -50,6 +56,22 @@ pub struct StaticConfig {
     pub(crate) subscriber_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
     pub(crate) type_details: TypeDetails,
+    #[serde(default)]
+    pub(crate) dead_letter_service: Option<ServiceName>,
+    #[serde(default)]
+    pub(crate) dead_letter_includes_payload: bool,
+    #[serde(default)]
+    pub(crate) group_delivery_strategy: GroupDeliveryStrategy,
+    #[serde(default)]
+    pub(crate) max_observers: usize,
+    #[serde(default)]
+    pub(crate) flight_recorder_capacity: usize,
+    #[serde(default)]
+    pub(crate) deadline: Option<Duration>,
+    #[serde(default)]
+    pub(crate) receive_order: ReceiveOrder,
+    #[serde(default)]
+    pub(crate) memory_budget: usize,
 }
 
 impl StaticConfig {
@@ -68,6 +90,14 @@ impl StaticConfig {
                 .subscriber_max_borrowed_samples,
             enable_safe_overflow: config.defaults.publish_subscribe.enable_safe_overflow,
             type_details: TypeDetails::default(),
+            dead_letter_service: None,
+            dead_letter_includes_payload: false,
+            group_delivery_strategy: GroupDeliveryStrategy::default(),
+            max_observers: config.defaults.publish_subscribe.max_observers,
+            flight_recorder_capacity: 0,
+            deadline: None,
+            receive_order: ReceiveOrder::default(),
+            memory_budget: config.defaults.publish_subscribe.service_memory_budget,
         }
     }
 
@@ -81,6 +111,13 @@ impl StaticConfig {
         self.max_subscribers
     }
 
+    /// Returns the maximum supported amount of observers, see
+    /// [`crate::service::port_factory::publish_subscribe::PortFactory::observer_builder()`].
+    /// Observers do not count against [`StaticConfig::max_supported_subscribers()`].
+    pub fn max_supported_observers(&self) -> usize {
+        self.max_observers
+    }
+
     /// Returns the maximum history size that can be requested on connect.
     pub fn history_size(&self) -> usize {
         self.history_size
@@ -109,4 +146,52 @@ impl StaticConfig {
     pub fn type_details(&self) -> &TypeDetails {
         &self.type_details
     }
+
+    /// Returns the maximum amount of shared memory in bytes the data segments of all
+    /// [`crate::port::publisher::Publisher`]s of the [`crate::service::Service`] can consume
+    /// together. It is defined by the creator of the [`crate::service::Service`], see
+    /// [`crate::config::PublishSubscribe::service_memory_budget`]. Returns 0 when the
+    /// [`crate::service::Service`] has no memory budget.
+    pub fn memory_budget(&self) -> usize {
+        self.memory_budget
+    }
+
+    /// Returns the name of the dead-letter service into which all samples are routed that
+    /// could not be delivered to a [`crate::port::subscriber::Subscriber`]. Returns [`None`]
+    /// when the [`crate::service::Service`] has no dead-letter service.
+    pub fn dead_letter_service(&self) -> Option<&ServiceName> {
+        self.dead_letter_service.as_ref()
+    }
+
+    /// Returns true if the dead letters contain the payload of the lost
+    /// [`crate::sample::Sample`], otherwise only the metadata is provided.
+    pub fn dead_letter_includes_payload(&self) -> bool {
+        self.dead_letter_includes_payload
+    }
+
+    /// Returns the [`GroupDeliveryStrategy`] that selects the member of a work-sharing group of
+    /// [`crate::port::subscriber::Subscriber`]s that receives a [`crate::sample::Sample`].
+    pub fn group_delivery_strategy(&self) -> GroupDeliveryStrategy {
+        self.group_delivery_strategy
+    }
+
+    /// Returns how many of the most recent [`crate::sample::Sample`]s every
+    /// [`crate::port::publisher::Publisher`] retains in its flight recorder, see
+    /// [`crate::port::flight_recorder`]. Returns 0 when the flight recorder is disabled.
+    pub fn flight_recorder_capacity(&self) -> usize {
+        self.flight_recorder_capacity
+    }
+
+    /// Returns the deadline of the service. When no [`crate::port::publisher::Publisher`]
+    /// sent a [`crate::sample::Sample`] within the deadline, the deadline is missed. Returns
+    /// [`None`] when the service has no deadline.
+    pub fn deadline(&self) -> Option<Duration> {
+        self.deadline
+    }
+
+    /// Returns the [`ReceiveOrder`] in which a [`crate::port::subscriber::Subscriber`] receives
+    /// the [`crate::sample::Sample`]s of multiple [`crate::port::publisher::Publisher`]s.
+    pub fn receive_order(&self) -> ReceiveOrder {
+        self.receive_order
+    }
 }
@@ -13,6 +13,8 @@
 use std::alloc::Layout;
 
 use iceoryx2_bb_elementary::math::align;
+pub use iceoryx2_bb_elementary::type_layout::FieldLayout;
+use iceoryx2_cal::hash::{sha1::Sha1, Hash};
 use serde::{Deserialize, Serialize};
 
 /// Defines if the type is a slice with a runtime-size ([`TypeVariant::Dynamic`])
@@ -31,27 +33,96 @@ pub struct TypeDetails {
     pub header_type_name: String,
     pub header_size: usize,
     pub header_alignment: usize,
+    /// The type name of the user header, see
+    /// [`Builder::user_header()`](crate::service::builder::publish_subscribe::Builder::user_header()).
+    #[serde(default = "unit_type_name")]
+    pub user_header_type_name: String,
+    #[serde(default)]
+    pub user_header_size: usize,
+    #[serde(default = "unit_alignment")]
+    pub user_header_alignment: usize,
     pub payload_type_name: String,
     pub payload_size: usize,
     pub payload_alignment: usize,
+    /// The optional layout of every field of the payload type. When it is empty, the field
+    /// layout is not verified.
+    #[serde(default)]
+    pub payload_fields: Vec<FieldLayout>,
+    /// The structural fingerprint of the payload type. It is derived from the type variant,
+    /// the type name, the size and the alignment of the payload type. The optional field layout
+    /// is not part of it since it is verified only when both sides define it. It is empty for
+    /// services that were created before the fingerprint was introduced, then it is not
+    /// verified.
+    #[serde(default)]
+    pub payload_type_hash: String,
+}
+
+// services that were created before user headers were supported have the user header `()`
+fn unit_type_name() -> String {
+    core::any::type_name::<()>().to_string()
+}
+
+fn unit_alignment() -> usize {
+    core::mem::align_of::<()>()
 }
 
 impl TypeDetails {
-    pub(crate) fn from<PayloadType, Header>(variant: TypeVariant) -> Self {
+    pub(crate) fn from<PayloadType, UserHeader, Header>(variant: TypeVariant) -> Self {
         Self {
             variant,
             header_type_name: core::any::type_name::<Header>().to_string(),
             header_size: core::mem::size_of::<Header>(),
             header_alignment: core::mem::align_of::<Header>(),
+            user_header_type_name: core::any::type_name::<UserHeader>().to_string(),
+            user_header_size: core::mem::size_of::<UserHeader>(),
+            user_header_alignment: core::mem::align_of::<UserHeader>(),
             payload_type_name: core::any::type_name::<PayloadType>().to_string(),
             payload_size: core::mem::size_of::<PayloadType>(),
             payload_alignment: core::mem::align_of::<PayloadType>(),
+            payload_fields: vec![],
+            payload_type_hash: String::new(),
         }
     }
 
-    pub(crate) fn payload_ptr_from_header(&self, header: *const u8) -> *const u8 {
+    // must be called whenever a payload property that is part of the fingerprint changes
+    pub(crate) fn update_payload_type_hash(&mut self) {
+        let fingerprint = format!(
+            "{:?};{};{};{}",
+            self.variant, self.payload_type_name, self.payload_size, self.payload_alignment
+        );
+        self.payload_type_hash = Sha1::new(fingerprint.as_bytes()).value().into();
+    }
+
+    /// Returns true when no payload field layout is stored or when it is equal to the
+    /// provided one.
+    pub fn is_payload_field_layout_compatible_to(&self, field_layouts: &[FieldLayout]) -> bool {
+        self.payload_fields.is_empty()
+            || field_layouts.is_empty()
+            || self.payload_fields == field_layouts
+    }
+
+    pub(crate) fn user_header_ptr_from_header(&self, header: *const u8) -> *const u8 {
         let header = header as usize;
-        let payload_start = align(header + self.header_size, self.payload_alignment);
+        let user_header_start = align(header + self.header_size, self.user_header_alignment);
+        user_header_start as *const u8
+    }
+
+    pub(crate) fn payload_ptr_from_header(&self, header: *const u8) -> *const u8 {
+        self.payload_ptr_from_header_with_alignment(header, self.payload_alignment)
+    }
+
+    // a publisher can over-align its payload, the receiver uses then the alignment that is
+    // stored in the header of the sample
+    pub(crate) fn payload_ptr_from_header_with_alignment(
+        &self,
+        header: *const u8,
+        alignment: usize,
+    ) -> *const u8 {
+        let user_header = self.user_header_ptr_from_header(header) as usize;
+        let payload_start = align(
+            user_header + self.user_header_size,
+            self.payload_alignment.max(alignment),
+        );
         payload_start as *const u8
     }
 
@@ -59,7 +130,8 @@ impl TypeDetails {
         unsafe {
             Layout::from_size_align_unchecked(
                 align(
-                    self.header_size
+                    self.header_size + self.user_header_alignment - 1
+                        + self.user_header_size
                         + self.payload_size * number_of_elements
                         + self.payload_alignment
                         - 1,
@@ -79,13 +151,81 @@ impl TypeDetails {
         }
     }
 
-    pub(crate) fn is_compatible_to(&self, rhs: &Self) -> bool {
-        self.variant == rhs.variant
-            && self.header_type_name == rhs.header_type_name
-            && self.header_size == rhs.header_size
-            && self.header_alignment == rhs.header_alignment
-            && self.payload_type_name == rhs.payload_type_name
-            && self.payload_size == rhs.payload_size
-            && self.payload_alignment <= rhs.payload_alignment
+    /// Describes why the requested type details are not compatible to the type details `rhs`
+    /// of an existing service. Returns [`None`] when they are compatible.
+    pub(crate) fn incompatibility_to(&self, rhs: &Self) -> Option<String> {
+        if self.variant != rhs.variant {
+            return Some(format!(
+                "the type variant {:?} differs from {:?}",
+                self.variant, rhs.variant
+            ));
+        }
+
+        if self.header_type_name != rhs.header_type_name
+            || self.header_size != rhs.header_size
+            || self.header_alignment != rhs.header_alignment
+        {
+            return Some(format!(
+                "the header type \"{}\" (size {}, alignment {}) differs from \"{}\" (size {}, alignment {})",
+                self.header_type_name, self.header_size, self.header_alignment,
+                rhs.header_type_name, rhs.header_size, rhs.header_alignment
+            ));
+        }
+
+        if self.user_header_type_name != rhs.user_header_type_name
+            || self.user_header_size != rhs.user_header_size
+            || self.user_header_alignment != rhs.user_header_alignment
+        {
+            return Some(format!(
+                "the user header type \"{}\" (size {}, alignment {}) differs from \"{}\" (size {}, alignment {})",
+                self.user_header_type_name, self.user_header_size, self.user_header_alignment,
+                rhs.user_header_type_name, rhs.user_header_size, rhs.user_header_alignment
+            ));
+        }
+
+        if self.payload_type_name != rhs.payload_type_name {
+            return Some(format!(
+                "the payload type name \"{}\" differs from \"{}\"",
+                self.payload_type_name, rhs.payload_type_name
+            ));
+        }
+
+        if self.payload_size != rhs.payload_size {
+            return Some(format!(
+                "the payload size of {} bytes differs from {} bytes",
+                self.payload_size, rhs.payload_size
+            ));
+        }
+
+        if self.payload_alignment > rhs.payload_alignment {
+            return Some(format!(
+                "the required payload alignment of {} exceeds the alignment {} of the service",
+                self.payload_alignment, rhs.payload_alignment
+            ));
+        }
+
+        if !self.payload_fields.is_empty()
+            && !rhs.payload_fields.is_empty()
+            && self.payload_fields != rhs.payload_fields
+        {
+            return Some(format!(
+                "the payload field layout {:?} differs from {:?}",
+                self.payload_fields, rhs.payload_fields
+            ));
+        }
+
+        // the fingerprints are created with the alignment of the payload type and not with the
+        // possibly increased alignment of the service
+        if !self.payload_type_hash.is_empty()
+            && !rhs.payload_type_hash.is_empty()
+            && self.payload_type_hash != rhs.payload_type_hash
+        {
+            return Some(format!(
+                "the payload type fingerprint {} differs from {}",
+                self.payload_type_hash, rhs.payload_type_hash
+            ));
+        }
+
+        None
     }
 }
@@ -31,27 +31,147 @@
 //! ```
 //!
 //! See [`Service`](crate::service) for more detailed examples.
+//!
+//! # Custom Monitoring
+//!
+//! The mechanism that detects dead nodes can be replaced with any
+//! [`Monitoring`](iceoryx2_cal::monitoring::Monitoring) implementation, for instance one that
+//! is based on a hardware watchdog, when the default file lock based monitoring is unsuitable.
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_cal::monitoring::process_local::ProcessLocalMonitoring;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new()
+//!     .create::<zero_copy::CustomMonitoringService<ProcessLocalMonitoring>>()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Pinned Memory
+//!
+//! The data segments of the publishers can be registered as pinned host memory with a
+//! [`HostMemoryRegistration`] so that the loaned samples can be transferred to a device like
+//! a GPU via DMA without an additional copy into a pinned staging buffer.
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_cal::shared_memory::pinned::PageLocked;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new()
+//!     .create::<zero_copy::PinnedMemoryService<PageLocked>>()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Event Mechanism
+//!
+//! The events are based on unix datagram sockets by default. On platforms like QNX, where
+//! posix message queues are the preferred kernel primitive, they can be selected with
+//! [`EventMechanism`](crate::config::EventMechanism) in the [`Config`](crate::config::Config).
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::config::{Config, EventMechanism};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut config = Config::default();
+//! config.global.service.event_mechanism = EventMechanism::MessageQueue;
+//!
+//! let node = NodeBuilder::new()
+//!     .config(&config)
+//!     .create::<zero_copy::Service>()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Sealed Shared Memory
+//!
+//! On Linux, hardened deployments can base the publisher data segments on memory files whose
+//! size is sealed after their creation with
+//! [`SharedMemoryMechanism::Sealed`](crate::config::SharedMemoryMechanism::Sealed). A
+//! misbehaving process can then no longer truncate a data segment and crash every process
+//! that has it mapped.
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::config::{Config, SharedMemoryMechanism};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut config = Config::default();
+//! # if cfg!(target_os = "linux") {
+//! config.global.service.shared_memory_mechanism = SharedMemoryMechanism::Sealed;
+//! # }
+//!
+//! let node = NodeBuilder::new()
+//!     .config(&config)
+//!     .create::<zero_copy::Service>()?;
+//! # Ok(())
+//! # }
+//! ```
 
 use crate::service::dynamic_config::DynamicConfig;
-use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::monitoring::Monitoring;
+use iceoryx2_cal::shared_memory::pinned::HostMemoryRegistration;
+use iceoryx2_cal::shm_allocator::selectable_allocator::SelectableAllocator;
 use iceoryx2_cal::*;
 
 use super::ServiceState;
 
 /// Defines a zero copy inter-process communication setup based on posix mechanisms.
+pub type Service = CustomMonitoringService<monitoring::file_lock::FileLockMonitoring>;
+
+/// Defines a zero copy inter-process communication setup based on posix mechanisms that uses
+/// the provided [`Monitoring`] to detect dead nodes.
+#[derive(Debug)]
+pub struct CustomMonitoringService<NodeMonitoring: Monitoring> {
+    state: ServiceState<Self>,
+}
+
+impl<NodeMonitoring: Monitoring> crate::service::Service
+    for CustomMonitoringService<NodeMonitoring>
+{
+    type StaticStorage = static_storage::file::Storage;
+    type ConfigSerializer = serialize::toml::Toml;
+    type DynamicStorage = dynamic_storage::posix_shared_memory::Storage<DynamicConfig>;
+    type ServiceNameHasher = hash::sha1::Sha1;
+    type SharedMemory = shared_memory::selectable::Memory<SelectableAllocator>;
+    type Connection = zero_copy_connection::posix_shared_memory::Connection;
+    type Event = event::selectable::EventImpl;
+    type Monitoring = NodeMonitoring;
+
+    fn from_state(state: ServiceState<Self>) -> Self {
+        Self { state }
+    }
+
+    fn state(&self) -> &ServiceState<Self> {
+        &self.state
+    }
+
+    fn state_mut(&mut self) -> &mut ServiceState<Self> {
+        &mut self.state
+    }
+}
+
+/// Defines a zero copy inter-process communication setup based on posix mechanisms whose data
+/// segments are registered as pinned host memory with the provided [`HostMemoryRegistration`].
 #[derive(Debug)]
-pub struct Service {
+pub struct PinnedMemoryService<Registration: HostMemoryRegistration> {
     state: ServiceState<Self>,
 }
 
-impl crate::service::Service for Service {
+impl<Registration: HostMemoryRegistration> crate::service::Service
+    for PinnedMemoryService<Registration>
+{
     type StaticStorage = static_storage::file::Storage;
     type ConfigSerializer = serialize::toml::Toml;
     type DynamicStorage = dynamic_storage::posix_shared_memory::Storage<DynamicConfig>;
     type ServiceNameHasher = hash::sha1::Sha1;
-    type SharedMemory = shared_memory::posix::Memory<PoolAllocator>;
+    type SharedMemory = shared_memory::pinned::Memory<SelectableAllocator, Registration>;
     type Connection = zero_copy_connection::posix_shared_memory::Connection;
-    type Event = event::unix_datagram_socket::EventImpl;
+    type Event = event::selectable::EventImpl;
     type Monitoring = monitoring::file_lock::FileLockMonitoring;
 
     fn from_state(state: ServiceState<Self>) -> Self {
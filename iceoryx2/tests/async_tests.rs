@@ -0,0 +1,205 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "async")]
+
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake};
+use std::thread::Thread;
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+struct NoopWaker;
+
+impl Wake for NoopWaker {
+    fn wake(self: Arc<Self>) {}
+}
+
+// Minimal executor that parks the current thread until the future is woken up again.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+#[generic_tests::define]
+mod async_ports {
+    use super::{block_on, NoopWaker};
+    use std::future::Future;
+    use std::pin::pin;
+    use std::sync::{Arc, Barrier};
+    use std::task::Context;
+    use std::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Service;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+
+    const TIMEOUT: Duration = Duration::from_millis(25);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "async_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn receive_async_resolves_immediately_when_sample_is_available<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(1234).unwrap();
+
+        let sample = block_on(subscriber.receive_async()).unwrap();
+        assert_that!(*sample, eq 1234);
+    }
+
+    #[test]
+    fn receive_async_resolves_when_sample_is_sent_later<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let node = NodeBuilder::new().create::<Sut>().unwrap();
+                let service = node
+                    .service_builder(service_name.clone())
+                    .publish_subscribe::<[u8]>()
+                    .open_or_create()
+                    .unwrap();
+                let subscriber = service.subscriber_builder().create().unwrap();
+                barrier.wait();
+
+                let sample = block_on(subscriber.receive_async()).unwrap();
+                assert_that!(*sample, eq [1u8, 2, 3]);
+                barrier.wait();
+            });
+
+            let node = NodeBuilder::new().create::<Sut>().unwrap();
+            let service = node
+                .service_builder(service_name.clone())
+                .publish_subscribe::<[u8]>()
+                .open_or_create()
+                .unwrap();
+            let publisher = service
+                .publisher_builder()
+                .max_slice_len(3)
+                .create()
+                .unwrap();
+            barrier.wait();
+
+            std::thread::sleep(TIMEOUT);
+            let sample = publisher.loan_slice_uninit(3).unwrap();
+            let sample = sample.write_from_fn(|i| i as u8 + 1);
+            sample.send().unwrap();
+
+            // keeps the publisher alive until the sample was received
+            barrier.wait();
+        });
+    }
+
+    #[test]
+    fn wait_async_resolves_when_event_is_notified_later<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let node = NodeBuilder::new().create::<Sut>().unwrap();
+                let event = node
+                    .service_builder(service_name.clone())
+                    .event()
+                    .open_or_create()
+                    .unwrap();
+                let listener = event.listener_builder().create().unwrap();
+                barrier.wait();
+
+                let event_id = block_on(listener.wait_async()).unwrap();
+                assert_that!(event_id, eq EventId::new(5));
+            });
+
+            let node = NodeBuilder::new().create::<Sut>().unwrap();
+            let event = node
+                .service_builder(service_name.clone())
+                .event()
+                .open_or_create()
+                .unwrap();
+            let notifier = event.notifier_builder().create().unwrap();
+            barrier.wait();
+
+            std::thread::sleep(TIMEOUT);
+            notifier
+                .notify_with_custom_event_id(EventId::new(5))
+                .unwrap();
+        });
+    }
+
+    #[test]
+    fn dropped_pending_future_does_not_affect_other_futures<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = node
+            .service_builder(generate_name())
+            .event()
+            .create()
+            .unwrap();
+        let notifier = event.notifier_builder().create().unwrap();
+
+        let waker = Arc::new(NoopWaker).into();
+        let mut cx = Context::from_waker(&waker);
+        {
+            let listener = event.listener_builder().create().unwrap();
+            let mut future = pin!(listener.wait_async());
+            assert_that!(future.as_mut().poll(&mut cx).is_pending(), eq true);
+        }
+
+        let listener = event.listener_builder().create().unwrap();
+        notifier
+            .notify_with_custom_event_id(EventId::new(7))
+            .unwrap();
+        let event_id = block_on(listener.wait_async()).unwrap();
+        assert_that!(event_id, eq EventId::new(7));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
@@ -0,0 +1,76 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod config {
+    use iceoryx2::config::{Config, ConfigCreationError};
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::config::temp_directory;
+    use iceoryx2_bb_posix::file::File;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_system_types::file_path::FilePath;
+    use iceoryx2_bb_system_types::path::Path;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_config_file_name() -> FilePath {
+        let file = FileName::new(
+            format!(
+                "config_tests_{}.toml",
+                UniqueSystemId::new().unwrap().value()
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        FilePath::from_path_and_file(&temp_directory(), &file).unwrap()
+    }
+
+    #[test]
+    fn saved_config_can_be_loaded_again() {
+        let config_file = generate_config_file_name();
+        let mut sut = Config::default();
+        sut.defaults.publish_subscribe.max_publishers = 17;
+        sut.global.service.directory = Path::new(b"saved_service_dir").unwrap();
+
+        assert_that!(sut.save(&config_file), is_ok);
+        let loaded_config = Config::from_file(&config_file);
+        assert_that!(File::remove(&config_file), eq Ok(true));
+
+        assert_that!(loaded_config, eq Ok(sut));
+    }
+
+    #[test]
+    fn environment_variables_override_config_entries() {
+        let mut sut = Config::default();
+
+        std::env::set_var("IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_PUBLISHERS", "13");
+        std::env::set_var("IOX2_GLOBAL_SERVICE_DIRECTORY", "env_service_dir");
+        std::env::set_var("IOX2_ENTRY_THAT_DOES_NOT_EXIST", "1");
+        assert_that!(sut.apply_environment_overrides(), is_ok);
+
+        assert_that!(sut.defaults.publish_subscribe.max_publishers, eq 13);
+        assert_that!(sut.global.service.directory, eq Path::new(b"env_service_dir").unwrap());
+
+        std::env::set_var("IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_PUBLISHERS", "many");
+        let config_before_failure = sut.clone();
+        assert_that!(
+            sut.apply_environment_overrides().err(), eq
+            Some(ConfigCreationError::InvalidEnvironmentVariable)
+        );
+        assert_that!(sut, eq config_before_failure);
+
+        std::env::remove_var("IOX2_DEFAULTS_PUBLISH_SUBSCRIBE_MAX_PUBLISHERS");
+        std::env::remove_var("IOX2_GLOBAL_SERVICE_DIRECTORY");
+        std::env::remove_var("IOX2_ENTRY_THAT_DOES_NOT_EXIST");
+    }
+}
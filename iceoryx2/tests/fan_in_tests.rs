@@ -0,0 +1,155 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod fan_in {
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::port::fan_in::FanIn;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::{service_name::ServiceName, Service};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const TIMEOUT: Duration = Duration::from_millis(25);
+    const NUMBER_OF_SAMPLES: u64 = 4;
+    // the payload of publisher n is n * PUBLISHER_OFFSET + sample number
+    const PUBLISHER_OFFSET: u64 = 100;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "fan_in_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn receive_publisher_order<S: Service>(fan_in: &FanIn<S, u64>) -> Vec<u64> {
+        let mut order = vec![];
+        while let Some(value) = fan_in.receive_copy().unwrap() {
+            order.push(value / PUBLISHER_OFFSET);
+        }
+        order
+    }
+
+    fn receive_with_fairness<S: Service>(max_samples_per_publisher: usize) -> Vec<u64> {
+        let node = NodeBuilder::new().create::<S>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create()
+            .unwrap();
+
+        let fan_in = sut
+            .fan_in_builder()
+            .max_samples_per_publisher(max_samples_per_publisher)
+            .create()
+            .unwrap();
+        let publishers = [
+            sut.publisher_builder().create().unwrap(),
+            sut.publisher_builder().create().unwrap(),
+        ];
+
+        for (n, publisher) in publishers.iter().enumerate() {
+            for i in 0..NUMBER_OF_SAMPLES {
+                publisher
+                    .send_copy(n as u64 * PUBLISHER_OFFSET + i)
+                    .unwrap();
+            }
+        }
+
+        receive_publisher_order(&fan_in)
+    }
+
+    #[test]
+    fn fan_in_serves_publishers_in_turns<S: Service>() {
+        let order = receive_with_fairness::<S>(1);
+
+        assert_that!(order, len 2 * NUMBER_OF_SAMPLES as usize);
+        for pair in order.windows(2) {
+            assert_that!(pair[0], ne pair[1]);
+        }
+    }
+
+    #[test]
+    fn fan_in_receives_at_most_max_samples_in_a_row_from_one_publisher<S: Service>() {
+        let order = receive_with_fairness::<S>(2);
+
+        assert_that!(order, len 2 * NUMBER_OF_SAMPLES as usize);
+        for chunk in order.chunks(2) {
+            assert_that!(chunk[0], eq chunk[1]);
+        }
+        for pair in order.chunks(2).collect::<Vec<_>>().windows(2) {
+            assert_that!(pair[0][0], ne pair[1][0]);
+        }
+    }
+
+    #[test]
+    fn fan_in_continues_with_remaining_publisher_when_other_is_drained<S: Service>() {
+        let node = NodeBuilder::new().create::<S>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create()
+            .unwrap();
+
+        let fan_in = sut.fan_in_builder().create().unwrap();
+        let publisher_1 = sut.publisher_builder().create().unwrap();
+        let publisher_2 = sut.publisher_builder().create().unwrap();
+
+        publisher_1.send_copy(0).unwrap();
+        for i in 0..NUMBER_OF_SAMPLES {
+            publisher_2.send_copy(PUBLISHER_OFFSET + i).unwrap();
+        }
+
+        let mut received = vec![];
+        while let Some(value) = fan_in.receive_copy().unwrap() {
+            received.push(value);
+        }
+        received.sort();
+
+        assert_that!(received, eq vec![0, 100, 101, 102, 103]);
+    }
+
+    #[test]
+    fn fan_in_waits_for_samples_of_all_publishers<S: Service>() {
+        let node = NodeBuilder::new().create::<S>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let fan_in = sut.fan_in_builder().create().unwrap();
+        let _publisher_1 = sut.publisher_builder().create().unwrap();
+        let publisher_2 = sut.publisher_builder().create().unwrap();
+
+        let start = Instant::now();
+        assert_that!(fan_in.timed_wait(TIMEOUT), eq Ok(false));
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+
+        publisher_2.send_copy(42).unwrap();
+        assert_that!(fan_in.timed_wait(TIMEOUT), eq Ok(true));
+        assert_that!(fan_in.blocking_wait(), is_ok);
+        assert_that!(fan_in.number_of_pending_samples(), eq Ok(1));
+        assert_that!(fan_in.receive_copy().unwrap(), eq Some(42));
+        assert_that!(fan_in.has_samples(), eq Ok(false));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
@@ -14,10 +14,15 @@
 mod node_death_tests {
     use iceoryx2::config::Config;
     use iceoryx2::node::testing::__internal_node_staged_death;
-    use iceoryx2::node::{NodeState, NodeView};
+    use iceoryx2::node::{CleanupState, NodeCreationFailure, NodeState, NodeView};
+    use iceoryx2::port::update_connections::UpdateConnections;
     use iceoryx2::prelude::*;
     use iceoryx2::service::Service;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_testing::assert_that;
+    use std::time::Duration;
 
     trait Test {
         type Service: Service;
@@ -29,6 +34,15 @@ mod node_death_tests {
         fn staged_death(node: &mut Node<Self::Service>);
     }
 
+    // a config with a unique prefix so that the nodes of the test are not listed by other tests
+    fn generate_isolated_config() -> Config {
+        let mut config = Config::default();
+        config.global.prefix =
+            FileName::new(format!("test_{}_", UniqueSystemId::new().unwrap().value()).as_bytes())
+                .unwrap();
+        config
+    }
+
     struct ZeroCopy;
 
     impl Test for ZeroCopy {
@@ -66,6 +80,350 @@ mod node_death_tests {
         assert_that!(node_list, len 0);
     }
 
+    #[test]
+    fn dead_node_can_be_reattached<S: Test>() {
+        let config = generate_isolated_config();
+        let node_name = S::generate_node_name(0, "phoenix");
+
+        let mut sut = NodeBuilder::new()
+            .name(node_name.clone())
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let node_id = *sut.id();
+        S::staged_death(&mut sut);
+
+        let sut = NodeBuilder::new()
+            .name(node_name.clone())
+            .config(&config)
+            .reattach(node_id)
+            .create::<S::Service>()
+            .unwrap();
+        assert_that!(*sut.id(), eq node_id);
+
+        let mut node_list = Node::<S::Service>::list(&config).unwrap();
+        assert_that!(node_list, len 1);
+
+        if let Some(NodeState::Alive(state)) = node_list.pop() {
+            assert_that!(*state.id(), eq node_id);
+            assert_that!(*state.details().as_ref().unwrap().name(), eq node_name);
+        } else {
+            assert_that!(true, eq false);
+        }
+
+        drop(sut);
+        let node_list = Node::<S::Service>::list(&config).unwrap();
+        assert_that!(node_list, len 0);
+    }
+
+    #[test]
+    fn alive_node_cannot_be_reattached<S: Test>() {
+        let config = generate_isolated_config();
+
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+
+        let sut = NodeBuilder::new()
+            .config(&config)
+            .reattach(*node.id())
+            .create::<S::Service>();
+        assert_that!(sut.err(), eq Some(NodeCreationFailure::NodeStillAlive));
+
+        let node_list = Node::<S::Service>::list(&config).unwrap();
+        assert_that!(node_list, len 1);
+    }
+
+    #[test]
+    fn cleanup_dead_nodes_removes_only_dead_nodes<S: Test>() {
+        let config = generate_isolated_config();
+
+        let mut nodes = vec![];
+        for i in 0..4 {
+            nodes.push(
+                NodeBuilder::new()
+                    .name(S::generate_node_name(i, "frankenstein"))
+                    .config(&config)
+                    .create::<S::Service>()
+                    .unwrap(),
+            );
+        }
+
+        S::staged_death(&mut nodes[1]);
+        S::staged_death(&mut nodes[3]);
+
+        let cleanup_state = Node::<S::Service>::cleanup_dead_nodes(&config);
+        assert_that!(cleanup_state, eq CleanupState { cleanups: 2, failed_cleanups: 0 });
+
+        let node_list = Node::<S::Service>::list(&config).unwrap();
+        assert_that!(node_list, len 2);
+        for node_state in node_list {
+            assert_that!(matches!(node_state, NodeState::Alive(_)), eq true);
+        }
+
+        let cleanup_state = Node::<S::Service>::cleanup_dead_nodes(&config);
+        assert_that!(cleanup_state, eq CleanupState::default());
+    }
+
+    #[test]
+    fn samples_held_by_dead_subscriber_are_reclaimed_after_cleanup<S: Test>() {
+        let config = generate_isolated_config();
+        let service_name = ServiceName::new("dead subscriber").unwrap();
+
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let service = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .subscriber_max_borrowed_samples(2)
+            .history_size(0)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let available_bytes = publisher.available_bytes();
+
+        let mut dead_node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let dead_service = dead_node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let subscriber = dead_service.subscriber_builder().create().unwrap();
+
+        for i in 0..4 {
+            publisher.send_copy(i).unwrap();
+        }
+        let samples = [
+            subscriber.receive().unwrap().unwrap(),
+            subscriber.receive().unwrap().unwrap(),
+        ];
+        assert_that!(publisher.available_bytes(), lt available_bytes);
+
+        S::staged_death(&mut dead_node);
+        core::mem::forget(samples);
+        core::mem::forget(subscriber);
+        core::mem::forget(dead_service);
+        core::mem::forget(dead_node);
+
+        let cleanup_state = Node::<S::Service>::cleanup_dead_nodes(&config);
+        assert_that!(cleanup_state.cleanups, eq 1);
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 0);
+
+        assert_that!(publisher.update_connections(), is_ok);
+        assert_that!(publisher.available_bytes(), eq available_bytes);
+    }
+
+    #[test]
+    fn samples_held_by_dead_subscriber_are_reclaimed_by_publisher_without_cleanup<S: Test>() {
+        let config = generate_isolated_config();
+        let service_name = ServiceName::new("dead subscriber without cleanup").unwrap();
+
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let service = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .subscriber_max_borrowed_samples(2)
+            .history_size(0)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let available_bytes = publisher.available_bytes();
+
+        let mut dead_node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let dead_service = dead_node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let subscriber = dead_service.subscriber_builder().create().unwrap();
+
+        for i in 0..4 {
+            publisher.send_copy(i).unwrap();
+        }
+        let samples = [
+            subscriber.receive().unwrap().unwrap(),
+            subscriber.receive().unwrap().unwrap(),
+        ];
+        assert_that!(publisher.reclaim_samples_of_dead_subscribers(), eq 0);
+        assert_that!(publisher.available_bytes(), lt available_bytes);
+
+        S::staged_death(&mut dead_node);
+        core::mem::forget(samples);
+        core::mem::forget(subscriber);
+        core::mem::forget(dead_service);
+        core::mem::forget(dead_node);
+
+        assert_that!(publisher.reclaim_samples_of_dead_subscribers(), eq 2);
+        assert_that!(publisher.available_bytes(), eq available_bytes);
+        assert_that!(service.dynamic_config().number_of_subscribers(), eq 1);
+
+        // the dead subscriber no longer receives samples
+        for i in 0..4 {
+            assert_that!(publisher.send_copy(i), eq Ok(0));
+        }
+        assert_that!(publisher.available_bytes(), eq available_bytes);
+    }
+
+    #[test]
+    fn dead_publisher_is_removed_after_cleanup<S: Test>() {
+        let config = generate_isolated_config();
+        let service_name = ServiceName::new("dead publisher").unwrap();
+
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let service = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut dead_node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let dead_service = dead_node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let publisher = dead_service.publisher_builder().create().unwrap();
+        publisher.send_copy(42).unwrap();
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 42);
+
+        S::staged_death(&mut dead_node);
+        core::mem::forget(publisher);
+        core::mem::forget(dead_service);
+        core::mem::forget(dead_node);
+
+        let cleanup_state = Node::<S::Service>::cleanup_dead_nodes(&config);
+        assert_that!(cleanup_state.cleanups, eq 1);
+        assert_that!(service.dynamic_config().number_of_publishers(), eq 0);
+
+        drop(sample);
+        assert_that!(subscriber.update_connections(), is_ok);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn notifier_of_dead_node_loses_liveliness_immediately<S: Test>() {
+        let config = generate_isolated_config();
+        let service_name = ServiceName::new("dead notifier").unwrap();
+
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let service = node
+            .service_builder(service_name.clone())
+            .event()
+            .notifier_liveliness(Duration::from_secs(3600))
+            .create()
+            .unwrap();
+        let listener = service.listener_builder().create().unwrap();
+
+        let mut dead_node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let dead_service = dead_node
+            .service_builder(service_name.clone())
+            .event()
+            .open()
+            .unwrap();
+        let notifier = dead_service.notifier_builder().create().unwrap();
+        assert_that!(listener.notifiers_with_lost_liveliness(), len 0);
+
+        let notifier_id = notifier.id();
+        S::staged_death(&mut dead_node);
+        core::mem::forget(notifier);
+        core::mem::forget(dead_service);
+        core::mem::forget(dead_node);
+
+        assert_that!(listener.notifiers_with_lost_liveliness(), eq vec![notifier_id]);
+
+        let cleanup_state = Node::<S::Service>::cleanup_dead_nodes(&config);
+        assert_that!(cleanup_state.cleanups, eq 1);
+        assert_that!(listener.notifiers_with_lost_liveliness(), len 0);
+    }
+
+    #[test]
+    fn service_only_used_by_dead_node_is_removed_after_cleanup<S: Test>() {
+        let config = generate_isolated_config();
+        let service_name = ServiceName::new("abandoned service").unwrap();
+
+        let mut dead_node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let dead_service = dead_node
+            .service_builder(service_name.clone())
+            .event()
+            .create()
+            .unwrap();
+        let listener = dead_service.listener_builder().create().unwrap();
+        let notifier = dead_service.notifier_builder().create().unwrap();
+
+        S::staged_death(&mut dead_node);
+        core::mem::forget(listener);
+        core::mem::forget(notifier);
+        core::mem::forget(dead_service);
+        core::mem::forget(dead_node);
+
+        assert_that!(S::Service::does_exist(&service_name, &config), eq Ok(true));
+
+        let cleanup_state = Node::<S::Service>::cleanup_dead_nodes(&config);
+        assert_that!(cleanup_state.cleanups, eq 1);
+        assert_that!(S::Service::does_exist(&service_name, &config), eq Ok(false));
+    }
+
+    #[test]
+    fn dead_node_monitor_notifies_listeners_when_dead_nodes_are_cleaned_up<S: Test>() {
+        let config = generate_isolated_config();
+        let service_name = ServiceName::new("dead nodes").unwrap();
+
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let sut = node.dead_node_monitor(service_name.clone()).unwrap();
+        let listener = node
+            .service_builder(service_name)
+            .event()
+            .open()
+            .unwrap()
+            .listener_builder()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.cleanup(), eq CleanupState::default());
+        assert_that!(listener.try_wait_one().unwrap(), is_none);
+
+        let mut dead_node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        S::staged_death(&mut dead_node);
+
+        assert_that!(sut.cleanup().cleanups, eq 1);
+        assert_that!(listener.try_wait_one().unwrap(), is_some);
+    }
+
     #[instantiate_tests(<ZeroCopy>)]
     mod zero_copy {}
 }
@@ -12,14 +12,22 @@
 
 #[generic_tests::define]
 mod node {
+    use std::cell::RefCell;
     use std::collections::{HashSet, VecDeque};
+    use std::sync::Barrier;
+    use std::time::Instant;
+
+    use core::time::Duration;
 
     use iceoryx2::config::Config;
-    use iceoryx2::node::{NodeState, NodeView};
+    use iceoryx2::node::event_loop::{CallbackProgression, Callbacks, NodeRunEvent};
+    use iceoryx2::node::resource_usage::NodeResourceUsage;
+    use iceoryx2::node::{NodeCreationFailure, NodeState, NodeView};
     use iceoryx2::prelude::*;
     use iceoryx2::service::Service;
     use iceoryx2_bb_posix::directory::Directory;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_system_types::path::*;
     use iceoryx2_bb_testing::assert_that;
 
@@ -64,6 +72,14 @@ mod node {
         }
     }
 
+    fn generate_service_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "node_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
     fn generate_node_name(i: usize, prefix: &str) -> NodeName {
         NodeName::new(&(prefix.to_string() + &i.to_string())).unwrap()
     }
@@ -86,6 +102,44 @@ mod node {
         assert_that!(*sut.name(), eq node_name);
     }
 
+    #[test]
+    fn node_creation_fails_when_max_number_of_nodes_is_reached<S: Service>() {
+        const MAX_NODES: usize = 2;
+        let domain = FileName::new(
+            format!(
+                "node_tests_domain_{}",
+                UniqueSystemId::new().unwrap().value()
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+        let mut config = Config::default();
+        config.global.node.max_nodes = MAX_NODES;
+        let builder = || NodeBuilder::new().config(&config).domain(domain);
+
+        let mut nodes = vec![];
+        for _ in 0..MAX_NODES {
+            nodes.push(builder().create::<S>().unwrap());
+        }
+
+        let sut = builder().create::<S>();
+        assert_that!(sut.err(), eq Some(NodeCreationFailure::ExceedsMaxNumberOfNodes));
+
+        nodes.pop();
+        let sut = builder().create::<S>();
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn node_wait_returns_tick_after_cycle_time<S: Service>() {
+        const CYCLE_TIME: Duration = Duration::from_millis(10);
+        let sut = NodeBuilder::new().create::<S>().unwrap();
+
+        let start = std::time::Instant::now();
+        assert_that!(sut.wait(CYCLE_TIME), eq NodeEvent::Tick);
+        assert_that!(start.elapsed(), time_at_least CYCLE_TIME);
+    }
+
     #[test]
     fn multiple_nodes_with_the_same_name_can_be_created<S: Service>() {
         const NUMBER_OF_NODES: usize = 16;
@@ -208,9 +262,391 @@ mod node {
         let _ = Directory::remove(&path);
     }
 
+    #[test]
+    fn nodes_and_services_of_different_domains_are_isolated<S: Service>() {
+        let domain = FileName::new(
+            format!(
+                "node_tests_domain_{}",
+                UniqueSystemId::new().unwrap().value()
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+        let service_name = generate_service_name();
+
+        let default_node = NodeBuilder::new().create::<S>().unwrap();
+        let sut = NodeBuilder::new().domain(domain).create::<S>().unwrap();
+        assert_that!(sut.config().global.domain, eq Some(domain));
+
+        let mut node_details = VecDeque::new();
+        node_details.push_back(Details::from_node(&sut));
+        assert_node_presence::<S>(&node_details, sut.config());
+
+        let default_service = default_node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(default_service, is_ok);
+        let domain_service = sut
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(domain_service, is_ok);
+
+        let root_path = sut.config().global.root_path();
+        drop(domain_service);
+        drop(sut);
+        let _ = Directory::remove(&root_path);
+    }
+
+    #[test]
+    fn run_dispatches_samples_events_and_timers<S: Service>() {
+        let node = NodeBuilder::new().create::<S>().unwrap();
+        let pubsub = node
+            .service_builder(generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let event = node
+            .service_builder(generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let subscriber = pubsub.subscriber_builder().create().unwrap();
+        let publisher = pubsub.publisher_builder().create().unwrap();
+        let listener = event.listener_builder().create().unwrap();
+        let notifier = event.notifier_builder().create().unwrap();
+
+        publisher.send_copy(1234).unwrap();
+        notifier
+            .notify_with_custom_event_id(EventId::new(3))
+            .unwrap();
+        notifier
+            .notify_with_custom_event_id(EventId::new(5))
+            .unwrap();
+
+        let mut received_samples = vec![];
+        let mut received_event_3 = 0;
+        let mut received_any_event = 0;
+        let mut timer_calls = 0;
+        let mut callbacks = Callbacks::new()
+            .on_sample(&subscriber, |sample| {
+                received_samples.push(*sample);
+                CallbackProgression::Continue
+            })
+            .on_event(&listener, EventId::new(3), |_| {
+                received_event_3 += 1;
+                CallbackProgression::Continue
+            })
+            .on_any_event(&listener, |_| {
+                received_any_event += 1;
+                CallbackProgression::Continue
+            })
+            .on_timer(Duration::from_millis(1), || {
+                timer_calls += 1;
+                CallbackProgression::Stop
+            });
+
+        let result = node.run(&mut callbacks, Duration::from_millis(10));
+        drop(callbacks);
+
+        assert_that!(result, eq Ok(NodeRunEvent::Stopped));
+        assert_that!(received_samples, eq vec![1234]);
+        assert_that!(received_event_3, eq 1);
+        assert_that!(received_any_event, eq 2);
+        assert_that!(timer_calls, eq 1);
+    }
+
+    #[test]
+    fn run_calls_ready_callbacks_in_priority_order<S: Service>() {
+        let node = NodeBuilder::new().create::<S>().unwrap();
+        let pubsub = node
+            .service_builder(generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let event = node
+            .service_builder(generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let subscriber = pubsub.subscriber_builder().create().unwrap();
+        let publisher = pubsub.publisher_builder().create().unwrap();
+        let listener = event.listener_builder().create().unwrap();
+        let notifier = event.notifier_builder().create().unwrap();
+
+        publisher.send_copy(1234).unwrap();
+        notifier.notify().unwrap();
+
+        let calls = RefCell::new(vec![]);
+        let mut callbacks = Callbacks::new()
+            .on_sample(&subscriber, |_| {
+                calls.borrow_mut().push("sample");
+                CallbackProgression::Stop
+            })
+            .priority(5)
+            .on_any_event(&listener, |_| {
+                calls.borrow_mut().push("event");
+                CallbackProgression::Continue
+            });
+
+        let result = node.run(&mut callbacks, Duration::from_millis(10));
+        drop(callbacks);
+
+        assert_that!(result, eq Ok(NodeRunEvent::Stopped));
+        assert_that!(calls.into_inner(), eq vec!["event", "sample"]);
+    }
+
+    #[test]
+    fn run_with_only_highest_priority_defers_lower_priority_callbacks<S: Service>() {
+        let node = NodeBuilder::new().create::<S>().unwrap();
+        let pubsub = node
+            .service_builder(generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let event = node
+            .service_builder(generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let subscriber = pubsub.subscriber_builder().create().unwrap();
+        let publisher = pubsub.publisher_builder().create().unwrap();
+        let listener = event.listener_builder().create().unwrap();
+        let notifier = event.notifier_builder().create().unwrap();
+
+        publisher.send_copy(1234).unwrap();
+        notifier.notify().unwrap();
+
+        let calls = RefCell::new(vec![]);
+        let mut callbacks = Callbacks::new()
+            .dispatch_only_highest_priority(true)
+            .on_sample(&subscriber, |_| {
+                calls.borrow_mut().push("sample");
+                CallbackProgression::Stop
+            })
+            .priority(5)
+            .on_any_event(&listener, |_| {
+                calls.borrow_mut().push("event");
+                // keeps the high priority callback ready for the next two cycles
+                if calls.borrow().len() < 3 {
+                    notifier.notify().unwrap();
+                }
+                CallbackProgression::Continue
+            });
+
+        let result = node.run(&mut callbacks, Duration::from_secs(10));
+        drop(callbacks);
+
+        assert_that!(result, eq Ok(NodeRunEvent::Stopped));
+        assert_that!(calls.into_inner(), eq vec!["event", "event", "event", "sample"]);
+    }
+
+    #[test]
+    fn run_wakes_up_on_arrival_without_waiting_for_the_cycle_time<S: Service>() {
+        const CYCLE_TIME: Duration = Duration::from_secs(60);
+        let service_name = generate_service_name();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let node = NodeBuilder::new().create::<S>().unwrap();
+                let pubsub = node
+                    .service_builder(service_name.clone())
+                    .publish_subscribe::<u64>()
+                    .open_or_create()
+                    .unwrap();
+                let publisher = pubsub.publisher_builder().create().unwrap();
+                barrier.wait();
+
+                std::thread::sleep(Duration::from_millis(50));
+                publisher.send_copy(1234).unwrap();
+                // keeps the publisher alive until the sample was received
+                barrier.wait();
+            });
+
+            let node = NodeBuilder::new().create::<S>().unwrap();
+            let pubsub = node
+                .service_builder(service_name.clone())
+                .publish_subscribe::<u64>()
+                .open_or_create()
+                .unwrap();
+            let subscriber = pubsub.subscriber_builder().create().unwrap();
+            barrier.wait();
+
+            let start = Instant::now();
+            let mut received_samples = vec![];
+            let mut callbacks = Callbacks::new().on_sample(&subscriber, |sample| {
+                received_samples.push(*sample);
+                CallbackProgression::Stop
+            });
+
+            let result = node.run(&mut callbacks, CYCLE_TIME);
+            drop(callbacks);
+            barrier.wait();
+
+            assert_that!(result, eq Ok(NodeRunEvent::Stopped));
+            assert_that!(received_samples, eq vec![1234]);
+            assert_that!(start.elapsed(), lt CYCLE_TIME);
+        });
+    }
+
+    #[test]
+    fn resource_usage_accounts_services_ports_and_connections<S: Service>() {
+        let node = NodeBuilder::new().create::<S>().unwrap();
+        assert_that!(node.resource_usage(), eq NodeResourceUsage::default());
+
+        let pubsub = node
+            .service_builder(generate_service_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let event = node
+            .service_builder(generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let publisher = pubsub.publisher_builder().create().unwrap();
+        let subscriber = pubsub.subscriber_builder().create().unwrap();
+        let listener = event.listener_builder().create().unwrap();
+        let notifier = event.notifier_builder().create().unwrap();
+        publisher.send_copy(1234).unwrap();
+
+        let usage = node.resource_usage();
+        assert_that!(usage.number_of_services(), eq 2);
+        assert_that!(usage.number_of_publishers(), eq 1);
+        assert_that!(usage.number_of_subscribers(), eq 1);
+        assert_that!(usage.number_of_notifiers(), eq 1);
+        assert_that!(usage.number_of_listeners(), eq 1);
+        // publisher to subscriber, subscriber to publisher and notifier to listener
+        assert_that!(usage.number_of_connections(), eq 3);
+        assert_that!(usage.number_of_file_descriptors(), eq 8);
+        assert_that!(usage.shared_memory_bytes(), gt 0);
+
+        drop(notifier);
+        drop(listener);
+        drop(subscriber);
+        drop(publisher);
+        drop(event);
+        drop(pubsub);
+
+        assert_that!(node.resource_usage(), eq NodeResourceUsage::default());
+    }
+
+    #[test]
+    fn metrics_aggregate_the_publishers_of_the_node<S: Service>() {
+        let node = NodeBuilder::new().create::<S>().unwrap();
+        let other_node = NodeBuilder::new().create::<S>().unwrap();
+        assert_that!(node.metrics().unwrap().publishers(), len 0);
+
+        let service_name = generate_service_name();
+        let pubsub = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let other_pubsub = other_node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let _event = node
+            .service_builder(generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+
+        let publisher_1 = pubsub.publisher_builder().create().unwrap();
+        let publisher_2 = pubsub.publisher_builder().create().unwrap();
+        let other_publisher = other_pubsub.publisher_builder().create().unwrap();
+        publisher_1.send_copy(1).unwrap();
+        publisher_2.send_copy(2).unwrap();
+        publisher_2.send_copy(3).unwrap();
+        other_publisher.send_copy(4).unwrap();
+
+        let metrics = node.metrics().unwrap();
+        assert_that!(metrics.publishers(), len 2);
+        assert_that!(metrics.number_of_sent_samples(), eq 3);
+        for publisher in metrics.publishers() {
+            assert_that!([publisher_1.id(), publisher_2.id()], contains publisher.publisher_id());
+        }
+
+        let mut view_metrics = None;
+        Node::<S>::list(Config::get_global_config())
+            .unwrap()
+            .into_iter()
+            .for_each(|state| {
+                if let NodeState::Alive(view) = state {
+                    if view.id() == node.id() {
+                        view_metrics = Some(view.metrics().unwrap());
+                    }
+                }
+            });
+        assert_that!(view_metrics, eq Some(metrics));
+
+        drop(publisher_1);
+        drop(publisher_2);
+        assert_that!(node.metrics().unwrap().publishers(), len 0);
+    }
+
+    #[test]
+    fn service_prefix_namespaces_all_services_of_the_node<S: Service>() {
+        let prefix_1 = generate_service_name();
+        let prefix_2 = generate_service_name();
+        let service_name = ServiceName::new("Pose").unwrap();
+
+        let robot_1 = NodeBuilder::new()
+            .service_prefix(prefix_1.clone())
+            .create::<S>()
+            .unwrap();
+        let robot_2 = NodeBuilder::new()
+            .service_prefix(prefix_2.clone())
+            .create::<S>()
+            .unwrap();
+        assert_that!(robot_1.service_prefix(), eq Some(&prefix_1));
+
+        let service_1 = robot_1
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(service_1, is_ok);
+        let service_1 = service_1.unwrap();
+        assert_that!(service_1.name().as_str(), eq format!("{}/Pose", prefix_1));
+
+        let service_2 = robot_2
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(service_2, is_ok);
+
+        let unprefixed_node = NodeBuilder::new().create::<S>().unwrap();
+        assert_that!(unprefixed_node.service_prefix(), eq None);
+        let sut = unprefixed_node
+            .service_builder(service_1.name().clone())
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut, is_ok);
+
+        let sut = unprefixed_node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut, is_err);
+
+        assert_that!(robot_1.wait_for_service(&service_name, Duration::ZERO), eq Ok(true));
+        assert_that!(unprefixed_node.wait_for_service(&service_name, Duration::ZERO), eq Ok(false));
+    }
+
     #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
     mod zero_copy {}
 
     #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
     mod process_local {}
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::CustomMonitoringService<iceoryx2_cal::monitoring::process_local::ProcessLocalMonitoring>>)]
+    mod zero_copy_with_custom_monitoring {}
 }
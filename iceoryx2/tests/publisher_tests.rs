@@ -14,11 +14,22 @@
 mod publisher {
     use std::time::{Duration, Instant};
 
-    use iceoryx2::port::publisher::PublisherLoanError;
+    use iceoryx2::config::Config;
+    use iceoryx2::port::flight_recorder::{FlightRecording, FlightRecordingOpenError};
+    use iceoryx2::port::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+    use iceoryx2::port::publisher::{PublisherCreateError, PublisherLoanError, PublisherSendError};
     use iceoryx2::prelude::*;
-    use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+    use iceoryx2::service::port_factory::publisher::{
+        AllocationStrategy, DataSegmentAllocator, UnableToDeliverStrategy, ZeroingPolicy,
+        MAX_NUMBER_OF_MEM_POOLS,
+    };
     use iceoryx2::service::{service_name::ServiceName, Service};
+    use iceoryx2_bb_container::semantic_string::SemanticString;
     use iceoryx2_bb_posix::barrier::*;
+    use iceoryx2_bb_posix::creation_mode::CreationMode;
+    use iceoryx2_bb_posix::permission::Permission;
+    use iceoryx2_bb_posix::shared_memory::SharedMemoryBuilder;
+    use iceoryx2_bb_posix::system_configuration::SystemInfo;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
@@ -34,6 +45,16 @@ mod publisher {
         ))?)
     }
 
+    fn open_flight_recording<Sut: Service>(
+        node: &Node<Sut>,
+        publisher_id: UniquePublisherId,
+    ) -> Option<FlightRecording> {
+        FlightRecording::list(node.config())
+            .iter()
+            .filter_map(|name| FlightRecording::open(name).ok())
+            .find(|recording| recording.publisher_id() == publisher_id)
+    }
+
     const COMPLEX_TYPE_DEFAULT_VALUE: u64 = 872379237;
 
     #[derive(Debug)]
@@ -131,6 +152,72 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn publisher_sends_only_truncated_part_of_slice<Sut: Service>() -> TestResult<()> {
+        const MAX_ELEMENTS: usize = 16;
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(MAX_ELEMENTS)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        let mut sample = publisher.loan_slice_uninit(MAX_ELEMENTS)?;
+        sample.truncate(MAX_ELEMENTS + 1);
+        assert_that!(sample.payload(), len MAX_ELEMENTS);
+        sample.truncate(3);
+        assert_that!(sample.payload(), len 3);
+        let sample = sample.write_from_fn(|i| i as u64 * 7);
+        sample.send()?;
+
+        let sample = publisher.loan_slice(MAX_ELEMENTS)?;
+        sample.send_truncated(5)?;
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(sample.payload(), eq [0, 7, 14]);
+        assert_that!(sample.header().payload_type_layout().size(), eq 3 * core::mem::size_of::<u64>());
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(sample.payload(), eq [0; 5]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_send_slice_copy_delivers_slice<Sut: Service>() -> TestResult<()> {
+        const MAX_ELEMENTS: usize = 16;
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(MAX_ELEMENTS)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(publisher.send_slice_copy(&[3, 5, 7, 9]), eq Ok(1));
+        assert_that!(publisher.send_slice_copy(&[]), eq Ok(1));
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(sample.payload(), eq [3, 5, 7, 9]);
+        assert_that!(sample.header().payload_type_layout().size(), eq 4 * core::mem::size_of::<u64>());
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(sample.payload(), len 0);
+
+        Ok(())
+    }
+
     #[test]
     fn publisher_loan_slice_more_than_max_elements_fails<Sut: Service>() -> TestResult<()> {
         const NUMBER_OF_ELEMENTS: usize = 125;
@@ -153,174 +240,777 @@ mod publisher {
         Ok(())
     }
 
-    #[test]
-    fn publisher_loan_unit_and_send_sample_works<Sut: Service>() -> TestResult<()> {
+    fn publisher_with_data_segment_allocator_delivers_slices<Sut: Service>(
+        strategy: DataSegmentAllocator,
+    ) -> TestResult<()> {
+        const MAX_ELEMENTS: usize = 32;
         let service_name = generate_name()?;
         let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service = node
             .service_builder(service_name)
-            .publish_subscribe::<u64>()
+            .publish_subscribe::<[u64]>()
+            .history_size(0)
             .create()?;
 
-        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(MAX_ELEMENTS)
+            .data_segment_allocator(strategy)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
 
-        let sample = sut.loan_uninit()?.write_payload(42);
+        for n in 0..4 * MAX_ELEMENTS {
+            let len = n % MAX_ELEMENTS + 1;
+            let sample = publisher.loan_slice_uninit(len)?;
+            let sample = sample.write_from_fn(|i| (n + i) as u64);
+            sample.send()?;
 
-        assert_that!(sample.send(), is_ok);
+            let sample = subscriber.receive()?.unwrap();
+            assert_that!(sample.payload().len(), eq len);
+            for (i, value) in sample.payload().iter().enumerate() {
+                assert_that!(*value, eq(n + i) as u64);
+            }
+        }
 
         Ok(())
     }
 
     #[test]
-    fn publisher_can_borrow_multiple_sample_at_once<Sut: Service>() -> TestResult<()> {
+    fn publisher_with_pool_data_segment_allocator_works<Sut: Service>() -> TestResult<()> {
+        publisher_with_data_segment_allocator_delivers_slices::<Sut>(DataSegmentAllocator::Pool)
+    }
+
+    #[test]
+    fn publisher_with_bump_per_cycle_data_segment_allocator_works<Sut: Service>() -> TestResult<()>
+    {
+        publisher_with_data_segment_allocator_delivers_slices::<Sut>(
+            DataSegmentAllocator::BumpPerCycle,
+        )
+    }
+
+    #[test]
+    fn publisher_with_best_fit_data_segment_allocator_works<Sut: Service>() -> TestResult<()> {
+        publisher_with_data_segment_allocator_delivers_slices::<Sut>(DataSegmentAllocator::BestFit)
+    }
+
+    #[test]
+    fn publisher_with_multi_pool_data_segment_allocator_works<Sut: Service>() -> TestResult<()> {
+        publisher_with_data_segment_allocator_delivers_slices::<Sut>(
+            DataSegmentAllocator::MultiPool,
+        )
+    }
+
+    #[test]
+    fn publisher_with_mem_pools_delivers_slices_of_every_pool<Sut: Service>() -> TestResult<()> {
+        const MAX_ELEMENTS: usize = 256;
         let service_name = generate_name()?;
         let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service = node
             .service_builder(service_name)
-            .publish_subscribe::<u64>()
+            .publish_subscribe::<[u64]>()
+            .history_size(0)
             .create()?;
 
-        let sut = service.publisher_builder().max_loaned_samples(4).create()?;
+        let publisher = service
+            .publisher_builder()
+            .mem_pool(MAX_ELEMENTS, 4)
+            .mem_pool(8, 16)
+            .mem_pool(32, 8)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
 
-        let sample1 = sut.loan_uninit()?.write_payload(1);
-        let sample2 = sut.loan_uninit()?.write_payload(2);
-        let sample3 = sut.loan_uninit()?.write_payload(3);
+        for len in [1, 8, 9, 32, 33, MAX_ELEMENTS, 2] {
+            let sample = publisher.loan_slice_uninit(len)?;
+            let sample = sample.write_from_fn(|i| (len + i) as u64);
+            sample.send()?;
 
-        let subscriber = service.subscriber_builder().create()?;
+            let sample = subscriber.receive()?.unwrap();
+            assert_that!(sample.payload().len(), eq len);
+            for (i, value) in sample.payload().iter().enumerate() {
+                assert_that!(*value, eq(len + i) as u64);
+            }
+        }
 
-        assert_that!(sut.send_copy(4), is_ok);
-        assert_that!(sample3.send(), is_ok);
-        drop(sample2);
-        drop(sample1);
+        let sut = publisher.loan_slice_uninit(MAX_ELEMENTS + 1);
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanSize);
 
-        let r = subscriber.receive()?;
-        assert_that!(r, is_some);
-        assert_that!( *r.unwrap(), eq 4);
-        let r = subscriber.receive()?;
-        assert_that!(r, is_some);
-        assert_that!( *r.unwrap(), eq 3);
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_mem_pools_requires_less_memory_than_pool<Sut: Service>() -> TestResult<()> {
+        const MAX_ELEMENTS: usize = 1024;
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<[u8]>()
+            .create()?;
+
+        let pool_breakdown = service
+            .publisher_builder()
+            .max_slice_len(MAX_ELEMENTS)
+            .memory_breakdown();
+
+        let multi_pool_breakdown = service
+            .publisher_builder()
+            .mem_pool(16, pool_breakdown.number_of_samples())
+            .mem_pool(MAX_ELEMENTS, 2)
+            .memory_breakdown();
+
+        assert_that!(multi_pool_breakdown.sample_size(), eq pool_breakdown.sample_size());
+        assert_that!(multi_pool_breakdown.data_segment_size(), lt pool_breakdown.data_segment_size());
 
         Ok(())
     }
 
     #[test]
-    fn publisher_max_loaned_samples_works<Sut: Service>() -> TestResult<()> {
+    fn publisher_with_invalid_mem_pools_fails<Sut: Service>() -> TestResult<()> {
         let service_name = generate_name()?;
         let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service = node
             .service_builder(service_name)
-            .publish_subscribe::<u64>()
+            .publish_subscribe::<[u64]>()
             .create()?;
 
-        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+        let sut = service.publisher_builder().mem_pool(8, 0).create();
+        assert_that!(sut.err(), eq Some(PublisherCreateError::InvalidMemPoolConfig));
 
-        let _sample1 = sut.loan_uninit()?;
-        let _sample2 = sut.loan_uninit()?;
+        let sut = service
+            .publisher_builder()
+            .mem_pool(8, 4)
+            .allocation_strategy(AllocationStrategy::BestFit)
+            .create();
+        assert_that!(sut.err(), eq Some(PublisherCreateError::InvalidMemPoolConfig));
+
+        let mut builder = service.publisher_builder();
+        for n in 0..MAX_NUMBER_OF_MEM_POOLS + 1 {
+            builder = builder.mem_pool(n + 1, 1);
+        }
+        assert_that!(builder.create().err(), eq Some(PublisherCreateError::InvalidMemPoolConfig));
 
-        let sample3 = sut.loan_uninit();
-        assert_that!(sample3, is_err);
-        assert_that!(sample3.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedChunks);
+        Ok(())
+    }
+
+    fn publisher_with_growing_data_segment_delivers_larger_slices<Sut: Service>(
+        strategy: AllocationStrategy,
+    ) -> TestResult<()> {
+        const INITIAL_MAX_ELEMENTS: usize = 8;
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<[u64]>()
+            .history_size(0)
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(INITIAL_MAX_ELEMENTS)
+            .allocation_strategy(strategy)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        for len in [1, INITIAL_MAX_ELEMENTS, 3 * INITIAL_MAX_ELEMENTS, 1, 100] {
+            let sample = publisher.loan_slice_uninit(len)?;
+            let sample = sample.write_from_fn(|i| (len + i) as u64);
+            sample.send()?;
+
+            let sample = subscriber.receive()?.unwrap();
+            assert_that!(sample.payload().len(), eq len);
+            for (i, value) in sample.payload().iter().enumerate() {
+                assert_that!(*value, eq(len + i) as u64);
+            }
+        }
 
         Ok(())
     }
 
     #[test]
-    fn publisher_sending_sample_reduces_loan_counter<Sut: Service>() -> TestResult<()> {
+    fn publisher_with_best_fit_segment_growth_delivers_larger_slices<Sut: Service>(
+    ) -> TestResult<()> {
+        publisher_with_growing_data_segment_delivers_larger_slices::<Sut>(
+            AllocationStrategy::BestFit,
+        )
+    }
+
+    #[test]
+    fn publisher_with_power_of_two_segment_growth_delivers_larger_slices<Sut: Service>(
+    ) -> TestResult<()> {
+        publisher_with_growing_data_segment_delivers_larger_slices::<Sut>(
+            AllocationStrategy::PowerOfTwo,
+        )
+    }
+
+    #[test]
+    fn publisher_with_growing_data_segment_keeps_samples_of_older_segments_valid<Sut: Service>(
+    ) -> TestResult<()> {
+        const INITIAL_MAX_ELEMENTS: usize = 4;
         let service_name = generate_name()?;
         let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service = node
             .service_builder(service_name)
-            .publish_subscribe::<u64>()
+            .publish_subscribe::<[u64]>()
+            .subscriber_max_buffer_size(4)
+            .history_size(0)
             .create()?;
 
-        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(INITIAL_MAX_ELEMENTS)
+            .allocation_strategy(AllocationStrategy::BestFit)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
 
-        let _sample1 = sut.loan_uninit()?;
-        let sample2 = sut.loan_uninit()?.write_payload(2);
+        publisher
+            .loan_slice_uninit(INITIAL_MAX_ELEMENTS)?
+            .write_from_fn(|i| i as u64)
+            .send()?;
+        publisher
+            .loan_slice_uninit(2 * INITIAL_MAX_ELEMENTS)?
+            .write_from_fn(|i| (100 + i) as u64)
+            .send()?;
+
+        let old_sample = subscriber.receive()?.unwrap();
+        let new_sample = subscriber.receive()?.unwrap();
+        assert_that!(old_sample.payload(), eq [0, 1, 2, 3]);
+        assert_that!(new_sample.payload().len(), eq 2 * INITIAL_MAX_ELEMENTS);
+        assert_that!(new_sample.payload()[0], eq 100);
 
-        assert_that!(sample2.send(), is_ok);
+        Ok(())
+    }
 
-        let _sample3 = sut.loan_uninit();
-        let sample4 = sut.loan_uninit();
-        assert_that!(sample4, is_err);
-        assert_that!(sample4.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedChunks);
+    #[test]
+    fn growing_data_segment_is_accounted_to_the_service_memory_budget<Sut: Service>(
+    ) -> TestResult<()> {
+        const INITIAL_MAX_ELEMENTS: usize = 8;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let initial_size = node
+            .service_builder(generate_name()?)
+            .publish_subscribe::<[u64]>()
+            .create()?
+            .publisher_builder()
+            .max_slice_len(INITIAL_MAX_ELEMENTS)
+            .memory_breakdown()
+            .data_segment_size();
+
+        let mut config = Config::default();
+        config.defaults.publish_subscribe.service_memory_budget = initial_size * 3;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(generate_name()?)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(INITIAL_MAX_ELEMENTS)
+            .allocation_strategy(AllocationStrategy::BestFit)
+            .create()?;
+        assert_that!(service.dynamic_config().shared_memory_bytes(), eq initial_size);
+
+        assert_that!(publisher.loan_slice_uninit(2 * INITIAL_MAX_ELEMENTS), is_ok);
+        let used_memory = service.dynamic_config().shared_memory_bytes();
+        assert_that!(used_memory, gt initial_size);
+        assert_that!(service.dynamic_config().shared_memory_bytes_of_node(node.id()), eq used_memory);
+
+        let sut = publisher.loan_slice_uninit(8 * INITIAL_MAX_ELEMENTS);
+        assert_that!(sut.err(), eq Some(PublisherLoanError::ExceedsServiceMemoryBudget));
+        assert_that!(service.dynamic_config().shared_memory_bytes(), eq used_memory);
+
+        drop(publisher);
+        assert_that!(service.dynamic_config().shared_memory_bytes(), eq 0);
 
         Ok(())
     }
 
     #[test]
-    fn publisher_dropping_sample_reduces_loan_counter<Sut: Service>() -> TestResult<()> {
+    fn publisher_with_canaries_delivers_slices<Sut: Service>() -> TestResult<()> {
+        const MAX_ELEMENTS: usize = 8;
         let service_name = generate_name()?;
         let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service = node
             .service_builder(service_name)
-            .publish_subscribe::<u64>()
+            .publish_subscribe::<[u64]>()
             .create()?;
+        let subscriber = service.subscriber_builder().create()?;
 
-        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+        for strategy in [
+            DataSegmentAllocator::Pool,
+            DataSegmentAllocator::BumpPerCycle,
+            DataSegmentAllocator::BestFit,
+        ] {
+            let publisher = service
+                .publisher_builder()
+                .max_slice_len(MAX_ELEMENTS)
+                .data_segment_allocator(strategy)
+                .enable_canaries(true)
+                .create()?;
+
+            for n in 0..4 * MAX_ELEMENTS {
+                let len = n % MAX_ELEMENTS + 1;
+                let sample = publisher.loan_slice_uninit(len)?;
+                let sample = sample.write_from_fn(|i| (n * i) as u64);
+                sample.send()?;
+
+                let sample = subscriber.receive()?.unwrap();
+                assert_that!(sample.payload().len(), eq len);
+                for (i, value) in sample.payload().iter().enumerate() {
+                    assert_that!(*value, eq(n * i) as u64);
+                }
+            }
+        }
 
-        let _sample1 = sut.loan_uninit()?;
-        let sample2 = sut.loan_uninit()?;
+        Ok(())
+    }
 
-        drop(sample2);
+    #[test]
+    #[should_panic]
+    fn publisher_with_canaries_detects_out_of_bounds_write<Sut: Service>() {
+        let service_name = generate_name().unwrap();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
 
-        let _sample3 = sut.loan_uninit();
-        let sample4 = sut.loan_uninit();
-        assert_that!(sample4, is_err);
-        assert_that!(sample4.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedChunks);
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(4)
+            .enable_canaries(true)
+            .create()
+            .unwrap();
+
+        let mut sample = publisher.loan_slice(1).unwrap();
+        unsafe { sample.payload_mut().as_mut_ptr().add(1).write(u64::MAX) };
+        let _ = sample.send();
+    }
+
+    #[test]
+    fn publisher_data_segment_report_tracks_loans<Sut: Service>() -> TestResult<()> {
+        const MAX_ELEMENTS: usize = 16;
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_slice_len(MAX_ELEMENTS)
+            .data_segment_allocator(DataSegmentAllocator::BestFit)
+            .create()?;
+
+        let report = sut.data_segment_report();
+        assert_that!(report.memory().used_memory(), eq 0);
+        assert_that!(report.memory().fragmentation(), eq 0.0);
+        assert_that!(report.largest_allocatable_payload(), eq MAX_ELEMENTS * std::mem::size_of::<u64>());
+
+        let sample = sut.loan_slice(MAX_ELEMENTS)?;
+        let report = sut.data_segment_report();
+        assert_that!(report.memory().used_memory(), gt 0);
+
+        drop(sample);
+        let report = sut.data_segment_report();
+        assert_that!(report.memory().used_memory(), eq 0);
 
         Ok(())
     }
 
     #[test]
-    fn publisher_block_when_unable_to_deliver_blocks<Sut: Service>() -> TestResult<()> {
-        let _watchdog = Watchdog::new();
+    fn publisher_data_segment_with_huge_pages_falls_back_to_available_page_size<Sut: Service>(
+    ) -> TestResult<()> {
+        const HUGE_PAGE_SIZE: usize = 2 * 1024 * 1024;
+        let page_size = SystemInfo::PageSize.value();
+        let mut config = Config::default();
+        config.global.shared_memory.huge_page_size = HUGE_PAGE_SIZE;
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_slice_len(1024).create()?;
+
+        let report = sut.data_segment_report();
+        assert_that!(report.page_size(), ge page_size);
+        assert_that!(report.page_size(), le HUGE_PAGE_SIZE);
+
+        let sample = sut.loan_slice_uninit(1)?;
+        let sample = sample.write_from_fn(|_| 123);
+        assert_that!(sample.send(), is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_available_loans_and_bytes_are_reduced_by_loans<Sut: Service>() -> TestResult<()> {
+        const MAX_LOANED_SAMPLES: usize = 4;
         let service_name = generate_name()?;
         let node = NodeBuilder::new().create::<Sut>().unwrap();
         let service = node
-            .service_builder(service_name.clone())
+            .service_builder(service_name)
             .publish_subscribe::<u64>()
-            .subscriber_max_buffer_size(1)
-            .enable_safe_overflow(false)
             .create()?;
 
         let sut = service
             .publisher_builder()
-            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
             .create()?;
 
-        let handle = BarrierHandle::new();
-        let barrier = BarrierBuilder::new(2).create(&handle).unwrap();
+        let initial_bytes = sut.available_bytes();
+        assert_that!(sut.available_loans(), eq MAX_LOANED_SAMPLES);
 
-        std::thread::scope(|s| {
-            s.spawn(|| {
-                let service = node
-                    .service_builder(service_name)
-                    .publish_subscribe::<u64>()
-                    .subscriber_max_buffer_size(1)
-                    .open()
-                    .unwrap();
+        let mut samples = vec![];
+        for i in 0..MAX_LOANED_SAMPLES {
+            samples.push(sut.loan()?);
+            assert_that!(sut.available_loans(), eq MAX_LOANED_SAMPLES - i - 1);
+            assert_that!(sut.available_bytes(), lt initial_bytes);
+        }
 
-                let subscriber = service.subscriber_builder().create().unwrap();
-                let receive_sample = || loop {
-                    if let Some(sample) = subscriber.receive().unwrap() {
-                        return sample;
-                    }
-                };
+        samples.clear();
+        assert_that!(sut.available_loans(), eq MAX_LOANED_SAMPLES);
+        assert_that!(sut.available_bytes(), eq initial_bytes);
 
-                barrier.wait();
-                std::thread::sleep(TIMEOUT);
-                let sample_1 = receive_sample();
-                std::thread::sleep(TIMEOUT);
-                let sample_2 = receive_sample();
+        Ok(())
+    }
 
-                assert_that!(*sample_1, eq 8192);
-                assert_that!(*sample_2, eq 2);
-            });
+    #[test]
+    fn publisher_loan_unit_and_send_sample_works<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
 
-            barrier.wait();
-            let now = Instant::now();
-            sut.send_copy(8192).unwrap();
+        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+
+        let sample = sut.loan_uninit()?.write_payload(42);
+
+        assert_that!(sample.send(), is_ok);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_zeroing_policy_on_loan_zeroes_samples<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .zeroing_policy(ZeroingPolicy::OnLoan)
+            .create()?;
+
+        for _ in 0..4 {
+            let mut sample = sut.loan_uninit()?;
+            let sample_copy = unsafe { sample.payload_mut().assume_init_read() };
+            assert_that!(sample_copy, eq 0);
+            sample.payload_mut().write(0xdeadbeef);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_zeroing_policy_on_create_provides_zeroed_samples<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .zeroing_policy(ZeroingPolicy::OnCreate)
+            .max_loaned_samples(4)
+            .create()?;
+
+        let mut samples = vec![];
+        for _ in 0..4 {
+            let mut sample = sut.loan_uninit()?;
+            let sample_copy = unsafe { sample.payload_mut().assume_init_read() };
+            assert_that!(sample_copy, eq 0);
+            samples.push(sample);
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "sanitizer_asan")]
+    #[test]
+    fn publisher_with_zeroing_policy_on_create_works_with_poisoned_memory<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        for _ in 0..2 {
+            let sut = service
+                .publisher_builder()
+                .zeroing_policy(ZeroingPolicy::OnCreate)
+                .create()?;
+
+            let mut sample = sut.loan_uninit()?;
+            let sample_copy = unsafe { sample.payload_mut().assume_init_read() };
+            assert_that!(sample_copy, eq 0);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_can_borrow_multiple_sample_at_once<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(4).create()?;
+
+        let sample1 = sut.loan_uninit()?.write_payload(1);
+        let sample2 = sut.loan_uninit()?.write_payload(2);
+        let sample3 = sut.loan_uninit()?.write_payload(3);
+
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.send_copy(4), is_ok);
+        assert_that!(sample3.send(), is_ok);
+        drop(sample2);
+        drop(sample1);
+
+        let r = subscriber.receive()?;
+        assert_that!(r, is_some);
+        assert_that!( *r.unwrap(), eq 4);
+        let r = subscriber.receive()?;
+        assert_that!(r, is_some);
+        assert_that!( *r.unwrap(), eq 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_max_loaned_samples_works<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+
+        let _sample1 = sut.loan_uninit()?;
+        let _sample2 = sut.loan_uninit()?;
+
+        let sample3 = sut.loan_uninit();
+        assert_that!(sample3, is_err);
+        assert_that!(sample3.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoans);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_sending_sample_reduces_loan_counter<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+
+        let _sample1 = sut.loan_uninit()?;
+        let sample2 = sut.loan_uninit()?.write_payload(2);
+
+        assert_that!(sample2.send(), is_ok);
+
+        let _sample3 = sut.loan_uninit();
+        let sample4 = sut.loan_uninit();
+        assert_that!(sample4, is_err);
+        assert_that!(sample4.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoans);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_dropping_sample_reduces_loan_counter<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+
+        let _sample1 = sut.loan_uninit()?;
+        let sample2 = sut.loan_uninit()?;
+
+        drop(sample2);
+
+        let _sample3 = sut.loan_uninit();
+        let sample4 = sut.loan_uninit();
+        assert_that!(sample4, is_err);
+        assert_that!(sample4.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoans);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_tracks_loaned_samples<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(3).create()?;
+        assert_that!(sut.max_loaned_samples(), eq 3);
+        assert_that!(sut.loaned_samples(), eq 0);
+
+        let sample1 = sut.loan_uninit()?;
+        let sample2 = sut.loan()?;
+        let sample3 = sut.loan_uninit()?;
+        assert_that!(sut.loaned_samples(), eq 3);
+        assert_that!(sut.loan_uninit().err(), eq Some(PublisherLoanError::ExceedsMaxLoans));
+        assert_that!(sut.loaned_samples(), eq 3);
+
+        drop(sample1);
+        assert_that!(sut.loaned_samples(), eq 2);
+        assert_that!(sample2.send(), is_ok);
+        assert_that!(sut.loaned_samples(), eq 1);
+        assert_that!(sample3.write_payload(5).send(), is_ok);
+        assert_that!(sut.loaned_samples(), eq 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_sends_batch_to_all_subscribers_in_order<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(2)
+            .subscriber_max_buffer_size(4)
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(4).create()?;
+        let subscriber_1 = service.subscriber_builder().create()?;
+        let subscriber_2 = service.subscriber_builder().create()?;
+
+        let mut batch = sut.loan_batch(4)?;
+        assert_that!(batch.len(), eq 4);
+        assert_that!(sut.loaned_samples(), eq 4);
+        for (n, sample) in batch.iter_mut().enumerate() {
+            *sample.payload_mut() = n as u64 * 10;
+        }
+
+        assert_that!(batch.send_all(), eq Ok(8));
+        assert_that!(sut.loaned_samples(), eq 0);
+
+        for subscriber in [&subscriber_1, &subscriber_2] {
+            for n in 0..4 {
+                let sample = subscriber.receive()?.unwrap();
+                assert_that!(*sample, eq n * 10);
+            }
+            assert_that!(subscriber.receive()?, is_none);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_releases_unsent_batch<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(2).create()?;
+
+        assert_that!(sut.loan_batch(3).err(), eq Some(PublisherLoanError::ExceedsMaxLoans));
+        assert_that!(sut.loaned_samples(), eq 0);
+
+        let batch = sut.loan_batch(2)?;
+        assert_that!(sut.loaned_samples(), eq 2);
+        drop(batch);
+        assert_that!(sut.loaned_samples(), eq 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_block_when_unable_to_deliver_blocks<Sut: Service>() -> TestResult<()> {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()?;
+
+        let handle = BarrierHandle::new();
+        let barrier = BarrierBuilder::new(2).create(&handle).unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let service = node
+                    .service_builder(service_name)
+                    .publish_subscribe::<u64>()
+                    .subscriber_max_buffer_size(1)
+                    .open()
+                    .unwrap();
+
+                let subscriber = service.subscriber_builder().create().unwrap();
+                let receive_sample = || loop {
+                    if let Some(sample) = subscriber.receive().unwrap() {
+                        return sample;
+                    }
+                };
+
+                barrier.wait();
+                std::thread::sleep(TIMEOUT);
+                let sample_1 = receive_sample();
+                std::thread::sleep(TIMEOUT);
+                let sample_2 = receive_sample();
+
+                assert_that!(*sample_1, eq 8192);
+                assert_that!(*sample_2, eq 2);
+            });
+
+            barrier.wait();
+            let now = Instant::now();
+            sut.send_copy(8192).unwrap();
             sut.send_copy(2).unwrap();
             assert_that!(now.elapsed(), time_at_least TIMEOUT);
         });
@@ -328,6 +1018,534 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn send_with_timeout_reports_subscribers_whose_buffer_stays_full<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()?;
+        let slow_subscriber = service.subscriber_builder().create()?;
+        let fast_subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.send_copy(1)?, eq 2);
+        assert_that!(fast_subscriber.receive()?, is_some);
+
+        let mut sample = sut.loan()?;
+        *sample.payload_mut() = 2;
+        let now = Instant::now();
+        let report = sample.send_with_timeout(TIMEOUT)?;
+        assert_that!(now.elapsed(), time_at_least TIMEOUT);
+
+        assert_that!(report.has_timed_out(), eq true);
+        assert_that!(report.number_of_recipients(), eq 1);
+        let expected_subscribers = [slow_subscriber.id()];
+        assert_that!(report.timed_out_subscribers(), eq expected_subscribers);
+        assert_that!(*fast_subscriber.receive()?.unwrap(), eq 2);
+
+        let statistics = sut.blocking_statistics();
+        assert_that!(statistics, len 2);
+        for entry in statistics {
+            if entry.subscriber_id() == slow_subscriber.id() {
+                assert_that!(entry.number_of_blocked_deliveries(), eq 1);
+                assert_that!(entry.number_of_timeouts(), eq 1);
+                assert_that!(entry.total_blocking_time(), time_at_least TIMEOUT);
+                assert_that!(entry.max_blocking_time(), eq entry.total_blocking_time());
+            } else {
+                assert_that!(entry.subscriber_id(), eq fast_subscriber.id());
+                assert_that!(entry.number_of_blocked_deliveries(), eq 0);
+                assert_that!(entry.number_of_timeouts(), eq 0);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn disconnected_subscriber_no_longer_blocks_publisher<Sut: Service>() -> TestResult<()> {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        assert_that!(sut.send_copy(1)?, eq 1);
+        assert_that!(sut.disconnect_subscriber(subscriber.id()), eq true);
+        assert_that!(sut.disconnect_subscriber(UniqueSubscriberId::default()), eq false);
+
+        assert_that!(sut.send_copy(2)?, eq 0);
+        assert_that!(*subscriber.receive()?.unwrap(), eq 1);
+        assert_that!(subscriber.receive()?, is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_reports_acknowledgments_of_subscribers<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .enable_acknowledgments(true)
+            .create()?;
+        let subscriber_1 = service.subscriber_builder().create()?;
+        let subscriber_2 = service.subscriber_builder().create()?;
+
+        let sample_id = sut.loan_uninit()?.write_payload(42).send_acknowledged()?;
+        let report = sut.acknowledgment_report(sample_id).unwrap();
+        assert_that!(report.sample_id(), eq sample_id);
+        assert_that!(report.pending(), len 2);
+        assert_that!(report.is_complete(), eq false);
+
+        let sample = subscriber_1.receive()?.unwrap();
+        // a received but not yet released sample is still pending
+        let report = sut.acknowledgment_report(sample_id).unwrap();
+        assert_that!(report.pending(), len 2);
+        drop(sample);
+
+        let report = sut.acknowledgment_report(sample_id).unwrap();
+        assert_that!(report.acknowledged_by(), eq[subscriber_1.id()]);
+        assert_that!(report.pending(), eq[subscriber_2.id()]);
+
+        drop(subscriber_2.receive()?);
+        let report = sut.acknowledgment_report(sample_id).unwrap();
+        assert_that!(report.acknowledged_by(), len 2);
+        assert_that!(report.lost(), len 0);
+        assert_that!(report.is_complete(), eq true);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_reports_overflowed_samples_as_lost<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(true)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .enable_acknowledgments(true)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        let first_id = sut.loan_uninit()?.write_payload(1).send_acknowledged()?;
+        let second_id = sut.loan_uninit()?.write_payload(2).send_acknowledged()?;
+        assert_that!(first_id.value() < second_id.value(), eq true);
+
+        let report = sut.acknowledgment_report(first_id).unwrap();
+        assert_that!(report.lost(), eq[subscriber.id()]);
+        assert_that!(report.is_complete(), eq true);
+        let report = sut.acknowledgment_report(second_id).unwrap();
+        assert_that!(report.pending(), eq[subscriber.id()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_send_acknowledged_fails_when_acknowledgments_are_disabled<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let result = sut.loan_uninit()?.write_payload(1).send_acknowledged();
+
+        assert_that!(result.err(), eq Some(PublisherSendError::AcknowledgmentsNotEnabled));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_timed_wait_for_acknowledgments_works<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .enable_acknowledgments(true)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+        let sample_id = sut.loan_uninit()?.write_payload(1).send_acknowledged()?;
+
+        let start = Instant::now();
+        assert_that!(sut.timed_wait_for_acknowledgments(sample_id, TIMEOUT), eq Ok(false));
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+
+        drop(subscriber.receive()?);
+        assert_that!(sut.timed_wait_for_acknowledgments(sample_id, TIMEOUT), eq Ok(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_flight_recorder_retains_last_samples<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .flight_recorder(3)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        for i in 0..5u64 {
+            sut.send_copy(i)?;
+        }
+
+        let recording = open_flight_recording(&node, sut.id()).unwrap();
+        assert_that!(recording.service_name(), eq service_name.as_str());
+        assert_that!(recording.capacity(), eq 3);
+        assert_that!(recording.number_of_sent_samples(), eq 5);
+
+        let records = recording.records();
+        assert_that!(records, len 3);
+        for (record, value) in records.iter().zip(2..5u64) {
+            assert_that!(record.sequence_number(), eq value + 1);
+            assert_that!(record.payload_size(), eq 8);
+            assert_that!(record.payload(), eq value.to_ne_bytes());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_flight_recording_is_removed_when_publisher_goes_out_of_scope<Sut: Service>(
+    ) -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .flight_recorder(2)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let sut_id = sut.id();
+        sut.send_copy(1234)?;
+
+        let recording = open_flight_recording(&node, sut_id).unwrap();
+        let records = recording.records();
+        assert_that!(records, len 1);
+        assert_that!(records[0].payload(), eq 1234u64.to_ne_bytes());
+        drop(recording);
+
+        drop(sut);
+        assert_that!(open_flight_recording(&node, sut_id), is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_flight_recorder_retention_limit_keeps_recordings_of_living_publishers<
+        Sut: Service,
+    >() -> TestResult<()> {
+        let mut config = Config::default();
+        config
+            .defaults
+            .publish_subscribe
+            .flight_recorder_max_recordings = 1;
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .flight_recorder(2)
+            .create()?;
+
+        let sut_1 = service.publisher_builder().create()?;
+        let sut_2 = service.publisher_builder().create()?;
+
+        assert_that!(open_flight_recording(&node, sut_1.id()), is_some);
+        assert_that!(open_flight_recording(&node, sut_2.id()), is_some);
+
+        Ok(())
+    }
+
+    #[test]
+    fn flight_recording_with_corrupted_header_is_rejected<Sut: Service>() -> TestResult<()> {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let mut name = node.config().global.domain_prefix();
+        name.push_bytes(b"flight_recorder_corrupted_")?;
+        name.push_bytes(UniqueSystemId::new()?.value().to_string().as_bytes())?;
+
+        let mut memory = SharedMemoryBuilder::new(&name)
+            .creation_mode(CreationMode::PurgeAndCreate)
+            .size(4096)
+            .permission(Permission::OWNER_ALL)
+            .create()?;
+        let bytes = memory.as_mut_slice();
+        bytes[0..8].copy_from_slice(b"IOX2FLR1");
+        // capacity and max payload size would overflow the size calculation
+        bytes[8..16].copy_from_slice(&u64::MAX.to_le_bytes());
+        bytes[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+        bytes[48..56].copy_from_slice(&0u64.to_le_bytes());
+
+        let sut = FlightRecording::open(&name);
+        assert_that!(sut.err(), eq Some(FlightRecordingOpenError::Corrupted));
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_flight_recorder_is_disabled_by_default<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        sut.send_copy(1)?;
+
+        assert_that!(service.static_config().flight_recorder_capacity(), eq 0);
+        assert_that!(open_flight_recording(&node, sut.id()), is_none);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_metrics_track_sent_samples_and_samples_in_use<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .history_size(0)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        let metrics = sut.metrics();
+        assert_that!(metrics.publisher_id(), eq sut.id());
+        assert_that!(metrics.number_of_sent_samples(), eq 0);
+        assert_that!(metrics.number_of_samples_in_use(), eq 0);
+        assert_that!(metrics.sample_capacity(), ge 1);
+        assert_that!(metrics.connections(), len 1);
+        assert_that!(metrics.connections()[0].subscriber_id(), eq subscriber.id());
+
+        let sample = sut.loan_uninit()?.write_payload(1);
+        assert_that!(sut.metrics().number_of_samples_in_use(), eq 1);
+        sample.send()?;
+        sut.send_copy(2)?;
+
+        let metrics = sut.metrics();
+        assert_that!(metrics.number_of_sent_samples(), eq 2);
+        assert_that!(metrics.number_of_samples_in_use(), eq 2);
+
+        while let Some(sample) = subscriber.receive()? {
+            drop(sample);
+        }
+        assert_that!(sut.metrics().number_of_samples_in_use(), eq 0);
+        assert_that!(sut.metrics().segment_utilization(), eq 0.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_metrics_track_loan_failures<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().max_loaned_samples(1).create()?;
+
+        let _sample = sut.loan_uninit()?;
+        assert_that!(sut.loan_uninit(), is_err);
+        assert_that!(sut.loan_uninit(), is_err);
+
+        assert_that!(sut.metrics().number_of_loan_failures(), eq 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_metrics_track_dropped_samples_per_connection<Sut: Service>() -> TestResult<()> {
+        const NUMBER_OF_SAMPLES: u64 = 3;
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(true)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        let subscriber_1 = service.subscriber_builder().create()?;
+        let subscriber_2 = service.subscriber_builder().create()?;
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            sut.send_copy(n)?;
+        }
+
+        let metrics = sut.metrics();
+        assert_that!(metrics.number_of_dropped_samples(), eq 2 * (NUMBER_OF_SAMPLES - 1));
+        assert_that!(metrics.connections(), len 2);
+        for connection in metrics.connections() {
+            assert_that!([subscriber_1.id(), subscriber_2.id()], contains connection.subscriber_id());
+            assert_that!(connection.number_of_dropped_samples(), eq NUMBER_OF_SAMPLES - 1);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_and_subscriber_list_connected_ports<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let publisher_node = NodeBuilder::new()
+            .name("publisher_node".try_into()?)
+            .create::<Sut>()?;
+        let subscriber_node = NodeBuilder::new()
+            .name("subscriber_node".try_into()?)
+            .create::<Sut>()?;
+        let service = publisher_node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        assert_that!(sut.connected_subscribers(), len 0);
+
+        let subscriber = subscriber_node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .open()?
+            .subscriber_builder()
+            .create()?;
+
+        let connected_subscribers = sut.connected_subscribers();
+        assert_that!(connected_subscribers, len 1);
+        assert_that!(connected_subscribers[0].port_id(), eq subscriber.id());
+        assert_that!(connected_subscribers[0].node_id(), eq subscriber_node.id());
+        assert_that!(connected_subscribers[0].node_name(), eq Some(subscriber_node.name()));
+
+        let connected_publishers = subscriber.connected_publishers();
+        assert_that!(connected_publishers, len 1);
+        assert_that!(connected_publishers[0].port_id(), eq sut.id());
+        assert_that!(connected_publishers[0].node_id(), eq publisher_node.id());
+        assert_that!(connected_publishers[0].node_name(), eq Some(publisher_node.name()));
+
+        drop(sut);
+        assert_that!(subscriber.connected_publishers(), len 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn payload_alignment_over_aligns_loaned_payloads<Sut: Service>() -> TestResult<()> {
+        const ALIGNMENT: usize = 4096;
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(2)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .payload_alignment(Alignment::new(ALIGNMENT).unwrap())
+            .max_loaned_samples(2)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        let sample_1 = sut.loan_uninit()?;
+        let sample_2 = sut.loan_uninit()?;
+        assert_that!(sample_1.payload().as_ptr() as usize % ALIGNMENT, eq 0);
+        assert_that!(sample_2.payload().as_ptr() as usize % ALIGNMENT, eq 0);
+        assert_that!(sample_1.header().payload_type_layout().align(), eq ALIGNMENT);
+
+        sample_1.write_payload(1234).send()?;
+        sample_2.write_payload(5678).send()?;
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(sample.payload() as *const u64 as usize % ALIGNMENT, eq 0);
+        assert_that!(*sample, eq 1234);
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(*sample, eq 5678);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publishers_with_different_payload_alignments_coexist<Sut: Service>() -> TestResult<()> {
+        const ALIGNMENT: usize = 64;
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(service_name)
+            .publish_subscribe::<[u32]>()
+            .subscriber_max_buffer_size(2)
+            .create()?;
+
+        let sut = service
+            .publisher_builder()
+            .max_slice_len(16)
+            .payload_alignment(Alignment::new(ALIGNMENT).unwrap())
+            .create()?;
+        let publisher = service.publisher_builder().max_slice_len(16).create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        let sample = sut.loan_slice_uninit(16)?;
+        assert_that!(sample.payload().as_ptr() as usize % ALIGNMENT, eq 0);
+        sample.write_from_fn(|n| n as u32).send()?;
+        publisher.send_slice_copy(&[7; 3])?;
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(sample.payload().as_ptr() as usize % ALIGNMENT, eq 0);
+        assert_that!(sample.payload(), len 16);
+        for (n, value) in sample.payload().iter().enumerate() {
+            assert_that!(*value as usize, eq n);
+        }
+
+        let sample = subscriber.receive()?.unwrap();
+        assert_that!(sample.payload(), eq [7, 7, 7]);
+
+        Ok(())
+    }
+
     #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
     mod zero_copy {}
 
@@ -0,0 +1,160 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "safety")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+thread_local! {
+    static IS_COUNTING: Cell<bool> = const { Cell::new(false) };
+    static NUMBER_OF_ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+struct CountingAllocator;
+
+impl CountingAllocator {
+    fn count() {
+        let _ = IS_COUNTING.try_with(|is_counting| {
+            if is_counting.get() {
+                NUMBER_OF_ALLOCATIONS.with(|v| v.set(v.get() + 1));
+            }
+        });
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Self::count();
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        Self::count();
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// Returns the number of heap allocations the current thread performed in `f`.
+fn count_allocations<F: FnOnce()>(f: F) -> usize {
+    NUMBER_OF_ALLOCATIONS.with(|v| v.set(0));
+    IS_COUNTING.with(|v| v.set(true));
+    f();
+    IS_COUNTING.with(|v| v.set(false));
+    NUMBER_OF_ALLOCATIONS.with(|v| v.get())
+}
+
+#[generic_tests::define]
+mod safety {
+    use super::count_allocations;
+    use iceoryx2::port::publisher::Publisher;
+    use iceoryx2::port::subscriber::Subscriber;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::port_factory::subscriber::GroupDeliveryStrategy;
+    use iceoryx2::service::Service;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    const NUMBER_OF_ITERATIONS: u64 = 128;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "safety_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn send_and_receive<Sut: Service>(
+        publisher: &Publisher<Sut, u64>,
+        subscribers: &[Subscriber<Sut, u64>],
+        value: u64,
+    ) -> u64 {
+        let sample = publisher.loan_uninit().unwrap().write_payload(value);
+        sample.send().unwrap();
+
+        let mut received = 0;
+        for subscriber in subscribers {
+            while let Some(sample) = subscriber.receive().unwrap() {
+                received += *sample;
+            }
+        }
+        received
+    }
+
+    #[test]
+    fn communication_path_does_not_allocate<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscribers = [service.subscriber_builder().create().unwrap()];
+
+        // establishes the connections, this may allocate
+        assert_that!(send_and_receive(&publisher, &subscribers, 1), eq 1);
+
+        let mut received = 0;
+        let number_of_allocations = count_allocations(|| {
+            for i in 0..NUMBER_OF_ITERATIONS {
+                received += send_and_receive(&publisher, &subscribers, i);
+            }
+        });
+
+        assert_that!(number_of_allocations, eq 0);
+        assert_that!(received, eq (0..NUMBER_OF_ITERATIONS).sum::<u64>());
+    }
+
+    #[test]
+    fn group_delivery_does_not_allocate<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_group_delivery(GroupDeliveryStrategy::LeastLoaded)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscribers = [
+            service.subscriber_builder().group(1).create().unwrap(),
+            service.subscriber_builder().group(1).create().unwrap(),
+            service.subscriber_builder().group(2).create().unwrap(),
+        ];
+
+        assert_that!(send_and_receive(&publisher, &subscribers, 1), eq 2);
+
+        let mut received = 0;
+        let number_of_allocations = count_allocations(|| {
+            for i in 0..NUMBER_OF_ITERATIONS {
+                received += send_and_receive(&publisher, &subscribers, i);
+            }
+        });
+
+        assert_that!(number_of_allocations, eq 0);
+        assert_that!(received, eq 2 * (0..NUMBER_OF_ITERATIONS).sum::<u64>());
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
@@ -15,6 +15,7 @@ mod sample_mut {
     use iceoryx2::port::publisher::{Publisher, PublisherLoanError};
     use iceoryx2::port::subscriber::Subscriber;
     use iceoryx2::prelude::*;
+    use iceoryx2::sample_mut::SliceWriteError;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
     use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
     use iceoryx2::service::Service;
@@ -68,6 +69,22 @@ mod sample_mut {
         }
     }
 
+    #[test]
+    fn payload_can_be_written_as_bytes<Sut: Service>() {
+        let test_context = TestContext::<Sut>::new();
+
+        let mut sample = test_context.publisher.loan_uninit().unwrap();
+        assert_that!(sample.payload_as_bytes_mut(), len core::mem::size_of::<u64>());
+        sample
+            .payload_as_bytes_mut()
+            .copy_from_slice(&0x0123456789abcdefu64.to_ne_bytes());
+        let sample = unsafe { sample.assume_init() };
+        assert_that!(sample.send(), eq Ok(1));
+
+        let received_sample = test_context.subscriber.receive().unwrap().unwrap();
+        assert_that!(*received_sample, eq 0x0123456789abcdef);
+    }
+
     #[test]
     fn when_going_out_of_scope_it_is_released<Sut: Service>() {
         let test_context = TestContext::<Sut>::new();
@@ -83,7 +100,7 @@ mod sample_mut {
 
             let loan_result = test_context.publisher.loan();
             assert_that!(loan_result, is_err);
-            assert_that!(loan_result.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedChunks);
+            assert_that!(loan_result.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoans);
 
             sample_vec.clear();
 
@@ -170,6 +187,60 @@ mod sample_mut {
         assert_that!(service.publisher_builder().create(), is_ok);
     }
 
+    #[test]
+    fn slice_writer_sends_only_written_elements<Sut: Service>() {
+        const MAX_SLICE_LEN: usize = 8;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(generate_name())
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(MAX_SLICE_LEN)
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut sut = publisher.loan_slice_uninit(MAX_SLICE_LEN).unwrap().writer();
+        assert_that!(sut.is_empty(), eq true);
+        assert_that!(sut.push(1), is_ok);
+        assert_that!(sut.extend_from_slice(&[2, 3, 4]), is_ok);
+        assert_that!(sut.len(), eq 4);
+        assert_that!(sut.remaining(), eq MAX_SLICE_LEN - 4);
+
+        assert_that!(sut.extend_from_slice(&[0; MAX_SLICE_LEN]).err(), eq Some(SliceWriteError::ExceedsCapacity));
+        assert_that!(sut.len(), eq 4);
+
+        let sample = sut.finish();
+        assert_that!(sample.payload(), eq [1, 2, 3, 4]);
+        assert_that!(sample.send(), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), eq [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn slice_writer_fails_to_push_into_full_slice<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(generate_name())
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(2)
+            .create()
+            .unwrap();
+
+        let mut sut = publisher.loan_slice_uninit(1).unwrap().writer();
+        assert_that!(sut.push(5), is_ok);
+        assert_that!(sut.push(6).err(), eq Some(SliceWriteError::ExceedsCapacity));
+        assert_that!(sut.finish().payload(), eq[5]);
+    }
+
     #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
     mod zero_copy {}
 
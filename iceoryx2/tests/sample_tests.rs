@@ -79,6 +79,15 @@ mod sample {
         assert_that!(sample.origin(), eq test_context.publisher_2.id());
     }
 
+    #[test]
+    fn payload_can_be_accessed_as_bytes<Sut: Service>() {
+        let test_context = TestContext::<Sut>::new();
+
+        assert_that!(test_context.publisher_1.send_copy(0x0123456789abcdef), eq Ok(1));
+        let sample = test_context.subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload_as_bytes(), eq 0x0123456789abcdefu64.to_ne_bytes());
+    }
+
     #[test]
     fn sample_of_dropped_service_does_not_block_new_service_creation<Sut: Service>() {
         let test_context = TestContext::<Sut>::new();
@@ -0,0 +1,279 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_blackboard {
+    use iceoryx2::port::reader::{EntryHandleError, ReaderCreateError};
+    use iceoryx2::port::writer::{EntryHandleMutError, WriterCreateError};
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::blackboard::{BlackboardCreateError, BlackboardOpenError};
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn creating_non_existing_service_works<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name.clone())
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .create();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(*sut.name(), eq service_name);
+        assert_that!(sut.static_config().number_of_entries(), eq 1);
+    }
+
+    #[test]
+    fn creating_service_without_entries_fails<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .blackboard::<u64>()
+            .create();
+
+        assert_that!(sut.err(), eq Some(BlackboardCreateError::NoEntriesProvided));
+    }
+
+    #[test]
+    fn creating_same_service_twice_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(service_name.clone())
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(service_name)
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .create();
+        assert_that!(sut2.err(), eq Some(BlackboardCreateError::AlreadyExists));
+    }
+
+    #[test]
+    fn open_fails_when_key_type_differs<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(service_name.clone())
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(service_name)
+            .blackboard::<u16>()
+            .open();
+        assert_that!(sut2.err(), eq Some(BlackboardOpenError::IncompatibleKeys));
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_max_readers_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(service_name.clone())
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .max_readers(2)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(service_name.clone())
+            .blackboard::<u64>()
+            .max_readers(3)
+            .open();
+        assert_that!(sut2.err(), eq Some(BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders));
+
+        let sut2 = node
+            .service_builder(service_name)
+            .blackboard::<u64>()
+            .max_readers(1)
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn reader_reads_initial_values<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .blackboard::<u64>()
+            .add::<u8>(3, 12)
+            .add::<u64>(7, 891)
+            .add::<[u32; 3]>(11, [1, 2, 3])
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+
+        assert_that!(reader.entry::<u8>(&3).unwrap().get(), eq 12);
+        assert_that!(reader.entry::<u64>(&7).unwrap().get(), eq 891);
+        assert_that!(reader.entry::<[u32; 3]>(&11).unwrap().get(), eq [1, 2, 3]);
+    }
+
+    #[test]
+    fn reader_receives_updates_of_writer_of_opened_service<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name.clone())
+            .blackboard::<u64>()
+            .add::<u16>(0, 1)
+            .add::<i64>(1, -1)
+            .create()
+            .unwrap();
+        let sut2 = node
+            .service_builder(service_name)
+            .blackboard::<u64>()
+            .open()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut2.reader_builder().create().unwrap();
+
+        let writer_entry = writer.entry::<i64>(&1).unwrap();
+        let reader_entry = reader.entry::<i64>(&1).unwrap();
+
+        for value in 0..10 {
+            writer_entry.update_with_copy(value * 1000);
+            assert_that!(reader_entry.get(), eq value * 1000);
+        }
+        assert_that!(reader.entry::<u16>(&0).unwrap().get(), eq 1);
+    }
+
+    #[test]
+    fn entry_with_non_existing_key_or_wrong_value_type_fails<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+
+        assert_that!(reader.entry::<u32>(&1).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+        assert_that!(reader.entry::<i32>(&0).err(), eq Some(EntryHandleError::IncompatibleValueType));
+        assert_that!(writer.entry::<u32>(&1).err(), eq Some(EntryHandleMutError::EntryDoesNotExist));
+        assert_that!(writer.entry::<u64>(&0).err(), eq Some(EntryHandleMutError::IncompatibleValueType));
+    }
+
+    #[test]
+    fn only_one_mutable_entry_handle_per_entry_exists<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .add::<u32>(1, 0)
+            .max_writers(2)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let writer2 = sut.writer_builder().create().unwrap();
+
+        let entry = writer.entry::<u32>(&0).unwrap();
+        assert_that!(writer.entry::<u32>(&0).err(), eq Some(EntryHandleMutError::HandleAlreadyExists));
+        assert_that!(writer2.entry::<u32>(&0).err(), eq Some(EntryHandleMutError::HandleAlreadyExists));
+        assert_that!(writer2.entry::<u32>(&1), is_ok);
+
+        drop(entry);
+        assert_that!(writer2.entry::<u32>(&0), is_ok);
+    }
+
+    #[test]
+    fn number_of_ports_is_limited_and_tracked<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .max_readers(2)
+            .max_writers(1)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        let _reader2 = sut.reader_builder().create().unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+
+        assert_that!(sut.dynamic_config().number_of_readers(), eq 2);
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 1);
+        assert_that!(sut.reader_builder().create().err(), eq Some(ReaderCreateError::ExceedsMaxSupportedReaders));
+        assert_that!(sut.writer_builder().create().err(), eq Some(WriterCreateError::ExceedsMaxSupportedWriters));
+
+        drop(reader);
+        drop(writer);
+        assert_that!(sut.dynamic_config().number_of_readers(), eq 1);
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 0);
+        assert_that!(sut.writer_builder().create(), is_ok);
+    }
+
+    #[test]
+    fn entry_changes_can_be_signaled_with_event_service<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name.clone())
+            .blackboard::<u64>()
+            .add::<u32>(10, 0)
+            .add::<u32>(20, 0)
+            .create()
+            .unwrap();
+        let event = node.service_builder(service_name).event().create().unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let reader = sut.reader_builder().create().unwrap();
+        let notifier = event.notifier_builder().create().unwrap();
+        let listener = event.listener_builder().create().unwrap();
+
+        let writer_entry = writer.entry::<u32>(&20).unwrap();
+        let reader_entry = reader.entry::<u32>(&20).unwrap();
+        assert_that!(writer_entry.entry_id(), eq reader_entry.entry_id());
+        assert_that!(reader.entry::<u32>(&10).unwrap().entry_id(), ne reader_entry.entry_id());
+
+        writer_entry.update_with_copy(55);
+        notifier
+            .notify_with_custom_event_id(writer_entry.entry_id())
+            .unwrap();
+
+        let event_id = listener.try_wait_one().unwrap();
+        assert_that!(event_id, eq Some(reader_entry.entry_id()));
+        assert_that!(reader_entry.get(), eq 55);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}
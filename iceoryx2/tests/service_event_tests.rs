@@ -14,17 +14,20 @@
 mod service_event {
     use std::collections::HashSet;
     use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-    use std::sync::Barrier;
+    use std::sync::{Barrier, Mutex};
     use std::time::{Duration, Instant};
 
-    use iceoryx2::config::Config;
-    use iceoryx2::port::listener::Listener;
+    use iceoryx2::config::{Config, QosProfile};
+    use iceoryx2::port::listener::{Listener, TimedWaitResult};
     use iceoryx2::port::notifier::NotifierNotifyError;
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::event::{EventCreateError, EventOpenError};
+    use iceoryx2_bb_posix::process::Process;
+    use iceoryx2_bb_posix::signal::{FetchableSignal, Signal, SignalHandler};
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
 
     const TIMEOUT: Duration = Duration::from_millis(50);
 
@@ -292,6 +295,31 @@ mod service_event {
         assert_that!(received_events, eq 1);
     }
 
+    #[test]
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    fn communication_with_message_queue_event_mechanism_works<Sut: Service>() {
+        use iceoryx2::config::EventMechanism;
+
+        let service_name = generate_name();
+        let mut config = Config::default();
+        config.global.service.event_mechanism = EventMechanism::MessageQueue;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let event_id = EventId::new(12);
+
+        let sut = node.service_builder(service_name).event().create().unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut
+            .notifier_builder()
+            .default_event_id(event_id)
+            .create()
+            .unwrap();
+
+        assert_that!(notifier.notify(), eq Ok(1));
+        assert_that!(listener.timed_wait_one(TIMEOUT).unwrap(), eq Some(event_id));
+        assert_that!(listener.try_wait_one().unwrap(), eq None);
+    }
+
     #[test]
     fn communication_with_max_notifiers_and_listeners_single_notification<Sut: Service>() {
         const MAX_LISTENERS: usize = 4;
@@ -467,6 +495,47 @@ mod service_event {
         }
     }
 
+    #[test]
+    fn single_listener_distinguishes_event_ids_of_many_notifiers<Sut: Service>() {
+        const NUMBER_OF_NOTIFIERS: usize = 6;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(generate_name())
+            .event()
+            .max_notifiers(NUMBER_OF_NOTIFIERS)
+            .event_id_max_value(2 * NUMBER_OF_NOTIFIERS)
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifiers: Vec<_> = (0..NUMBER_OF_NOTIFIERS)
+            .map(|i| {
+                sut.notifier_builder()
+                    .default_event_id(EventId::new(2 * i))
+                    .create()
+                    .unwrap()
+            })
+            .collect();
+
+        // only every second condition fires
+        for notifier in notifiers.iter().step_by(2) {
+            assert_that!(notifier.notify(), eq Ok(1));
+        }
+
+        let mut fired_ids = HashSet::new();
+        assert_that!(listener.try_wait_all(|id| { fired_ids.insert(id); }), is_ok);
+
+        assert_that!(fired_ids, len NUMBER_OF_NOTIFIERS / 2);
+        for i in (0..NUMBER_OF_NOTIFIERS).step_by(2) {
+            assert_that!(fired_ids.contains(&EventId::new(2 * i)), eq true);
+        }
+
+        let mut ids_after_wait = vec![];
+        assert_that!(listener.try_wait_all(|id| ids_after_wait.push(id)), is_ok);
+        assert_that!(ids_after_wait, is_empty);
+    }
+
     #[test]
     fn max_event_id_works<Sut: Service>() {
         let service_name = generate_name();
@@ -821,7 +890,7 @@ mod service_event {
         let mut callback_called = false;
         assert_that!(
             listener.timed_wait_all(|_| callback_called = true, TIMEOUT),
-            is_ok
+            eq Ok(TimedWaitResult::TimeoutElapsed)
         );
         assert_that!(now.elapsed(), time_at_least TIMEOUT);
         assert_that!(callback_called, eq false);
@@ -902,7 +971,7 @@ mod service_event {
         const NUMBER_OF_NOTIFICATIONS: usize = 8;
         wait_collects_all_notifications(NUMBER_OF_NOTIFICATIONS, |l: &Listener<Sut>, ids| {
             let result = l.timed_wait_all(|id| assert_that!(ids.insert(id), eq true), TIMEOUT);
-            assert_that!(result, is_ok);
+            assert_that!(result, eq Ok(TimedWaitResult::EventsReceived));
         });
     }
 
@@ -915,6 +984,374 @@ mod service_event {
         });
     }
 
+    #[test]
+    fn listener_with_coalescing_window_collects_burst_in_one_wakeup<Sut: Service>() {
+        let _watch_dog = Watchdog::new();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node.service_builder(service_name).event().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+
+        let barrier = Barrier::new(2);
+        std::thread::scope(|s| {
+            let t = s.spawn(|| {
+                let listener = sut
+                    .listener_builder()
+                    .coalescing_window(TIMEOUT * 4)
+                    .create()
+                    .unwrap();
+                assert_that!(listener.coalescing_window(), eq TIMEOUT * 4);
+
+                let mut ids = HashSet::new();
+                barrier.wait();
+                listener
+                    .blocking_wait_all(|id| {
+                        ids.insert(id);
+                    })
+                    .unwrap();
+                ids
+            });
+
+            barrier.wait();
+            assert_that!(notifier.notify_with_custom_event_id(EventId::new(1)).unwrap(), eq 1);
+            std::thread::sleep(TIMEOUT);
+            assert_that!(notifier.notify_with_custom_event_id(EventId::new(2)).unwrap(), eq 1);
+
+            let ids = t.join().unwrap();
+            assert_that!(ids, len 2);
+        });
+    }
+
+    #[test]
+    fn notifier_with_coalescing_window_coalesces_burst_of_same_event_id<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node.service_builder(service_name).event().create().unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut
+            .notifier_builder()
+            .coalescing_window(Duration::from_secs(60))
+            .create()
+            .unwrap();
+        assert_that!(notifier.coalescing_window(), eq Duration::from_secs(60));
+
+        const NUMBER_OF_NOTIFICATIONS: usize = 10;
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(1)).unwrap(), eq 1);
+        for _ in 1..NUMBER_OF_NOTIFICATIONS {
+            assert_that!(notifier.notify_with_custom_event_id(EventId::new(1)).unwrap(), eq 0);
+        }
+        // other event ids are coalesced independently
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(2)).unwrap(), eq 1);
+
+        let mut ids = vec![];
+        listener.try_wait_all(|id| ids.push(id)).unwrap();
+        assert_that!(ids, len 2);
+        assert_that!(listener.number_of_coalesced_notifications(), eq NUMBER_OF_NOTIFICATIONS as u64 - 1);
+    }
+
+    #[test]
+    fn notifier_without_coalescing_window_delivers_every_notification<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node.service_builder(service_name).event().create().unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+        assert_that!(notifier.coalescing_window(), eq Duration::ZERO);
+
+        for _ in 0..10 {
+            assert_that!(notifier.notify_with_custom_event_id(EventId::new(1)).unwrap(), eq 1);
+        }
+
+        assert_that!(listener.number_of_coalesced_notifications(), eq 0);
+    }
+
+    // signal handlers are process wide, the tests that raise signals must not run concurrently
+    static SIGNAL_TEST_MUTEX: Mutex<()> = Mutex::new(());
+    static NUMBER_OF_RAISED_SIGNALS: IoxAtomicUsize = IoxAtomicUsize::new(0);
+
+    fn count_signal(_: FetchableSignal) {
+        NUMBER_OF_RAISED_SIGNALS.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[test]
+    fn signal_bridge_raises_signal_for_mapped_event_ids<Sut: Service>() {
+        let _watch_dog = Watchdog::new();
+        let _lock = SIGNAL_TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        let _guard = SignalHandler::register(FetchableSignal::UserDefined1, &count_signal).unwrap();
+
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node.service_builder(service_name).event().create().unwrap();
+
+        let bridge = sut
+            .signal_bridge_builder()
+            .map(EventId::new(3), Signal::UserDefined1)
+            .cycle_time(Duration::from_millis(10))
+            .create();
+        assert_that!(bridge, is_ok);
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        let number_of_signals = NUMBER_OF_RAISED_SIGNALS.load(Ordering::Relaxed);
+
+        notifier
+            .notify_with_custom_event_id(EventId::new(4))
+            .unwrap();
+        notifier
+            .notify_with_custom_event_id(EventId::new(3))
+            .unwrap();
+
+        while NUMBER_OF_RAISED_SIGNALS.load(Ordering::Relaxed) == number_of_signals {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        std::thread::sleep(TIMEOUT);
+        assert_that!(NUMBER_OF_RAISED_SIGNALS.load(Ordering::Relaxed), eq number_of_signals + 1);
+
+        drop(bridge);
+        notifier
+            .notify_with_custom_event_id(EventId::new(3))
+            .unwrap();
+        std::thread::sleep(TIMEOUT);
+        assert_that!(NUMBER_OF_RAISED_SIGNALS.load(Ordering::Relaxed), eq number_of_signals + 1);
+    }
+
+    #[test]
+    fn timed_wait_is_not_ended_early_by_signals<Sut: Service>() {
+        let _watch_dog = Watchdog::new();
+        let _lock = SIGNAL_TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        let _guard = SignalHandler::register(FetchableSignal::UserDefined1, &count_signal).unwrap();
+
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node.service_builder(service_name).event().create().unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                for _ in 0..4 {
+                    std::thread::sleep(TIMEOUT / 4);
+                    Process::from_self()
+                        .send_signal(Signal::UserDefined1)
+                        .unwrap();
+                }
+            });
+
+            let now = Instant::now();
+            assert_that!(listener.timed_wait_one(TIMEOUT * 2), eq Ok(None));
+            assert_that!(now.elapsed(), time_at_least TIMEOUT * 2);
+
+            let now = Instant::now();
+            assert_that!(
+                listener.timed_wait_all(|_| {}, TIMEOUT * 2),
+                eq Ok(TimedWaitResult::TimeoutElapsed)
+            );
+            assert_that!(now.elapsed(), time_at_least TIMEOUT * 2);
+        });
+    }
+
+    #[test]
+    fn open_fails_with_incompatible_deadline<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(service_name.clone())
+            .event()
+            .deadline(TIMEOUT)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(service_name.clone())
+            .event()
+            .deadline(TIMEOUT * 2)
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq EventOpenError::IncompatibleDeadline);
+
+        let sut2 = node.service_builder(service_name).event().open().unwrap();
+        assert_that!(sut2.static_config().deadline(), eq Some(TIMEOUT));
+    }
+
+    #[test]
+    fn open_fails_with_incompatible_notifier_liveliness<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(service_name.clone())
+            .event()
+            .notifier_liveliness(TIMEOUT)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(service_name.clone())
+            .event()
+            .notifier_liveliness(TIMEOUT * 2)
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq EventOpenError::IncompatibleNotifierLiveliness);
+
+        let sut2 = node.service_builder(service_name).event().open().unwrap();
+        assert_that!(sut2.static_config().notifier_liveliness(), eq Some(TIMEOUT));
+    }
+
+    #[test]
+    fn listener_detects_notifier_without_sign_of_life<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name)
+            .event()
+            .notifier_liveliness(TIMEOUT)
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+        assert_that!(listener.notifier_liveliness(), eq Some(TIMEOUT));
+        assert_that!(listener.notifiers_with_lost_liveliness(), len 0);
+
+        std::thread::sleep(TIMEOUT * 2);
+        assert_that!(listener.notifiers_with_lost_liveliness(), eq vec![notifier.id()]);
+
+        notifier.assert_liveliness();
+        assert_that!(listener.notifiers_with_lost_liveliness(), len 0);
+
+        std::thread::sleep(TIMEOUT * 2);
+        assert_that!(notifier.notify(), eq Ok(1));
+        assert_that!(listener.notifiers_with_lost_liveliness(), len 0);
+
+        drop(notifier);
+        std::thread::sleep(TIMEOUT * 2);
+        assert_that!(listener.notifiers_with_lost_liveliness(), len 0);
+    }
+
+    #[test]
+    fn service_without_notifier_liveliness_never_loses_notifiers<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node.service_builder(service_name).event().create().unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let _notifier = sut.notifier_builder().create().unwrap();
+        assert_that!(listener.notifier_liveliness(), is_none);
+
+        std::thread::sleep(TIMEOUT);
+        assert_that!(listener.notifiers_with_lost_liveliness(), len 0);
+    }
+
+    #[test]
+    fn notifier_reports_missed_deadline_but_still_notifies<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name)
+            .event()
+            .deadline(TIMEOUT)
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+        assert_that!(listener.deadline(), eq Some(TIMEOUT));
+
+        assert_that!(notifier.notify(), eq Ok(1));
+        assert_that!(listener.has_missed_deadline(), eq false);
+        assert_that!(listener.try_wait_one().unwrap(), is_some);
+
+        std::thread::sleep(TIMEOUT * 2);
+        assert_that!(listener.has_missed_deadline(), eq true);
+
+        let result = notifier.notify();
+        assert_that!(result.err(), eq Some(NotifierNotifyError::MissedDeadline));
+        assert_that!(listener.has_missed_deadline(), eq false);
+        assert_that!(listener.try_wait_one().unwrap(), is_some);
+    }
+
+    #[test]
+    fn service_without_deadline_never_misses_it<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node.service_builder(service_name).event().create().unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let notifier = sut.notifier_builder().create().unwrap();
+
+        std::thread::sleep(TIMEOUT);
+        assert_that!(listener.deadline(), eq None);
+        assert_that!(listener.has_missed_deadline(), eq false);
+        assert_that!(notifier.notify(), eq Ok(1));
+    }
+
+    #[test]
+    fn qos_profile_from_config_is_applied<Sut: Service>() {
+        let service_name = generate_name();
+        let mut profile = QosProfile::default();
+        profile.max_listeners = Some(5);
+        profile.event_id_max_value = Some(32);
+        profile.deadline = Some(TIMEOUT);
+        profile.max_subscribers = Some(99);
+
+        let mut config = Config::default();
+        config.qos_profiles.insert("watchdog".to_string(), profile);
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name.clone())
+            .event()
+            .qos_profile("watchdog")
+            .max_listeners(6)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_supported_listeners(), eq 6);
+        assert_that!(sut.static_config().event_id_max_value(), eq 32);
+        assert_that!(sut.static_config().deadline(), eq Some(TIMEOUT));
+        assert_that!(sut.static_config().max_supported_notifiers(), eq config.defaults.event.max_notifiers);
+
+        let sut2 = node
+            .service_builder(service_name.clone())
+            .event()
+            .qos_profile("watchdog")
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut3 = node
+            .service_builder(service_name)
+            .event()
+            .deadline(TIMEOUT * 2)
+            .open();
+        assert_that!(sut3.err(), eq Some(EventOpenError::IncompatibleDeadline));
+    }
+
+    #[test]
+    fn unknown_qos_profile_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name.clone())
+            .event()
+            .qos_profile("does_not_exist")
+            .create();
+        assert_that!(sut.err(), eq Some(EventCreateError::UnknownQosProfile));
+
+        let sut = node
+            .service_builder(service_name)
+            .event()
+            .qos_profile("does_not_exist")
+            .open();
+        assert_that!(sut.err(), eq Some(EventOpenError::UnknownQosProfile));
+    }
+
     #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
     mod zero_copy {}
 
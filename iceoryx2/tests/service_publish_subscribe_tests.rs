@@ -13,22 +13,33 @@
 #[generic_tests::define]
 mod service_publish_subscribe {
     use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
-    use std::sync::Barrier;
+    use std::sync::{Arc, Barrier, Mutex};
     use std::thread;
+    use std::time::Duration;
 
-    use iceoryx2::config::Config;
+    use iceoryx2::config::{Config, QosProfile};
+    use iceoryx2::port::dead_letter::{DeadLetter, DeadLetterReason};
     use iceoryx2::port::publisher::{PublisherCreateError, PublisherLoanError};
-    use iceoryx2::port::subscriber::SubscriberCreateError;
+    use iceoryx2::port::subscriber::{Subscriber, SubscriberCreateError, SubscriberReceiveError};
     use iceoryx2::port::update_connections::UpdateConnections;
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::service::header::publish_subscribe::Header;
     use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+    use iceoryx2::service::port_factory::subscriber::{
+        Decimation, GroupDeliveryStrategy, OverflowStrategy, ReceiveOrder,
+    };
     use iceoryx2::service::static_config::type_details::TypeVariant;
     use iceoryx2::service::static_config::StaticConfig;
     use iceoryx2::service::Service;
+    use iceoryx2_bb_derive_macros::TypeLayout;
     use iceoryx2_bb_elementary::alignment::Alignment;
+    use iceoryx2_bb_elementary::type_layout::TypeLayout;
+    use iceoryx2_bb_posix::clock::{ClockType, Time};
+    use iceoryx2_bb_posix::permission::Permission;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
 
@@ -94,6 +105,38 @@ mod service_publish_subscribe {
         assert_that!(sut2, is_ok);
     }
 
+    #[test]
+    fn permissions_of_creator_are_applied_to_the_service<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name.clone())
+            .permissions(Permission::OWNER_READ | Permission::GROUP_READ)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(service_name)
+            .permissions(Permission::ALL)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        // the owner always keeps read and write access
+        let permission = Permission::OWNER_READ | Permission::OWNER_WRITE | Permission::GROUP_READ;
+        assert_that!(sut.permission(), eq permission);
+        assert_that!(sut2.permission(), eq permission);
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut2.subscriber_builder().create().unwrap();
+        assert_that!(publisher.send_copy(1234), is_ok);
+
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 1234);
+    }
+
     #[test]
     fn open_fails_when_service_does_not_exist<Sut: Service>() {
         let service_name = generate_name();
@@ -195,6 +238,282 @@ mod service_publish_subscribe {
         assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
     }
 
+    #[test]
+    fn open_fails_when_payload_type_name_override_differs<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .payload_type_name("MyFancyType")
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+
+        let sut2 = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .payload_type_name("AnotherFancyType")
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn cache_line_padded_elements_do_not_share_cache_lines<Sut: Service>() {
+        const NUMBER_OF_ELEMENTS: usize = 4;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<[u64]>()
+            .cache_line_padded_elements()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(service_name)
+            .publish_subscribe::<[u64]>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let publisher = sut
+            .publisher_builder()
+            .max_slice_len(NUMBER_OF_ELEMENTS)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let sample = publisher.loan_slice_uninit(NUMBER_OF_ELEMENTS).unwrap();
+        let sample = sample.write_from_fn(|i| CacheLinePadded::new(i as u64 * 3));
+        sample.send().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), len NUMBER_OF_ELEMENTS);
+        for (i, element) in sample.payload().iter().enumerate() {
+            assert_that!(**element, eq i as u64 * 3);
+            assert_that!((element as *const CacheLinePadded<u64> as usize) % CacheLinePadded::<u64>::CACHE_LINE_SIZE, eq 0);
+        }
+    }
+
+    #[test]
+    fn open_succeeds_for_different_types_with_same_payload_type_name_override<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .payload_type_name("MyFancyType")
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().type_details().payload_type_name, eq "MyFancyType");
+
+        let sut2 = node
+            .service_builder(service_name)
+            .publish_subscribe::<i64>()
+            .payload_type_name("MyFancyType")
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn byte_slice_with_overridden_payload_layout_communicates_with_sized_type<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = unsafe {
+            node.service_builder(service_name)
+                .publish_subscribe::<[u8]>()
+                .payload_type_name(core::any::type_name::<u64>())
+                .__internal_set_payload_layout(
+                    TypeVariant::FixedSize,
+                    core::mem::size_of::<u64>(),
+                    core::mem::align_of::<u64>(),
+                )
+                .open()
+                .unwrap()
+        };
+
+        let publisher = sut2.publisher_builder().max_slice_len(1).create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan_slice_uninit(1).unwrap();
+        let payload = sample.payload_mut().as_mut_ptr() as *mut u64;
+        unsafe { payload.write(8192) };
+        unsafe { sample.assume_init() }.send().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 8192);
+    }
+
+    #[test]
+    fn untyped_service_communicates_with_sized_type<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(service_name)
+            .publish_subscribe_untyped(
+                core::mem::size_of::<u64>(),
+                Alignment::new(core::mem::align_of::<u64>()).unwrap(),
+            )
+            .payload_type_name(core::any::type_name::<u64>())
+            .open()
+            .unwrap();
+
+        let publisher = sut2.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let untyped_subscriber = sut2.subscriber_builder().create().unwrap();
+
+        let sample = publisher.loan_untyped_uninit().unwrap();
+        assert_that!(sample.payload(), len core::mem::size_of::<u64>());
+        let bytes = 8192u64.to_ne_bytes();
+        let sample = sample.write_from_fn(|n| bytes[n]);
+        assert_that!(sample.send().unwrap(), eq 2);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 8192);
+
+        let sample = untyped_subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), eq bytes.as_slice());
+    }
+
+    #[test]
+    fn untyped_service_with_different_layout_is_incompatible<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe_untyped(16, Alignment::new(8).unwrap())
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(service_name.clone())
+            .publish_subscribe_untyped(32, Alignment::new(8).unwrap())
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+
+        let sut2 = node
+            .service_builder(service_name)
+            .publish_subscribe_untyped(16, Alignment::new(4).unwrap())
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[derive(Debug, TypeLayout)]
+    #[repr(C)]
+    struct LayoutTestType {
+        value_1: u8,
+        value_2: u64,
+    }
+
+    #[test]
+    fn open_fails_when_payload_field_layouts_differ<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<LayoutTestType>()
+            .payload_field_layouts(&LayoutTestType::field_layouts())
+            .create();
+        assert_that!(sut, is_ok);
+
+        let mut mismatching_layout = LayoutTestType::field_layouts();
+        mismatching_layout[1].offset = 4;
+
+        let sut2 = node
+            .service_builder(service_name)
+            .publish_subscribe::<LayoutTestType>()
+            .payload_field_layouts(&mismatching_layout)
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn open_succeeds_when_payload_field_layouts_match_or_are_not_defined<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<LayoutTestType>()
+            .payload_field_layouts(&LayoutTestType::field_layouts())
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().type_details().is_payload_field_layout_compatible_to(&LayoutTestType::field_layouts()), eq true);
+        assert_that!(sut.static_config().type_details().payload_fields, eq LayoutTestType::field_layouts());
+
+        let sut2 = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<LayoutTestType>()
+            .payload_field_layouts(&LayoutTestType::field_layouts())
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut3 = node
+            .service_builder(service_name)
+            .publish_subscribe::<LayoutTestType>()
+            .open();
+        assert_that!(sut3, is_ok);
+    }
+
+    #[test]
+    fn service_records_payload_type_fingerprint<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let sut_with_alignment = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .payload_alignment(Alignment::new(64).unwrap())
+            .create()
+            .unwrap();
+        let sut_with_other_type = node
+            .service_builder(generate_name())
+            .publish_subscribe::<i64>()
+            .create()
+            .unwrap();
+
+        let hash = &sut.static_config().type_details().payload_type_hash;
+        assert_that!(hash.is_empty(), eq false);
+        // the fingerprint describes the payload type and not the alignment of the service
+        assert_that!(
+            sut_with_alignment
+                .static_config()
+                .type_details()
+                .payload_type_hash,
+            eq *hash
+        );
+        assert_that!(
+            sut_with_other_type
+                .static_config()
+                .type_details()
+                .payload_type_hash,
+            ne *hash
+        );
+    }
+
     #[test]
     fn open_fails_when_service_does_not_satisfy_max_publishers_requirement<Sut: Service>() {
         let service_name = generate_name();
@@ -283,6 +602,56 @@ mod service_publish_subscribe {
         );
     }
 
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_deadline_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .deadline(Duration::from_millis(10))
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .deadline(Duration::from_millis(20))
+            .open();
+
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            PublishSubscribeOpenError::IncompatibleDeadline
+        );
+    }
+
+    #[test]
+    fn subscriber_detects_missed_deadline_of_silent_publisher<Sut: Service>() {
+        const DEADLINE: Duration = Duration::from_millis(50);
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .deadline(DEADLINE)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(subscriber.deadline(), eq Some(DEADLINE));
+
+        assert_that!(publisher.send_copy(1), is_ok);
+        assert_that!(subscriber.has_missed_deadline(), eq false);
+
+        std::thread::sleep(DEADLINE * 2);
+        assert_that!(subscriber.has_missed_deadline(), eq true);
+
+        assert_that!(publisher.send_copy(2), is_ok);
+        assert_that!(subscriber.has_missed_deadline(), eq false);
+    }
+
     #[test]
     fn open_fails_when_service_does_not_satisfy_history_requirement<Sut: Service>() {
         let service_name = generate_name();
@@ -770,32 +1139,114 @@ mod service_publish_subscribe {
     }
 
     #[test]
-    fn simple_communication_works_publisher_created_first<Sut: Service>() {
+    fn subscriber_reports_pending_samples<Sut: Service>() {
         let service_name = generate_name();
         let node = NodeBuilder::new().create::<Sut>().unwrap();
 
         let sut = node
-            .service_builder(service_name.clone())
-            .publish_subscribe::<u64>()
-            .create()
-            .unwrap();
-
-        let sut2 = node
             .service_builder(service_name)
             .publish_subscribe::<u64>()
-            .open()
+            .subscriber_max_buffer_size(4)
+            .history_size(0)
+            .create()
             .unwrap();
 
+        let subscriber = sut.subscriber_builder().buffer_size(4).create().unwrap();
         let publisher = sut.publisher_builder().create().unwrap();
-        let subscriber = sut2.subscriber_builder().create().unwrap();
-        assert_that!(publisher.update_connections(), is_ok);
+        assert_that!(subscriber.has_samples().unwrap(), eq false);
+        assert_that!(subscriber.number_of_pending_samples().unwrap(), eq 0);
 
-        assert_that!(publisher.send_copy(1234), is_ok);
-        assert_that!(publisher.send_copy(4567), is_ok);
-
-        let result = subscriber.receive().unwrap();
-        assert_that!(result, is_some);
-        assert_that!(*result.unwrap(), eq 1234);
+        for i in 0..3 {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
+        assert_that!(subscriber.has_samples().unwrap(), eq true);
+        assert_that!(subscriber.number_of_pending_samples().unwrap(), eq 3);
+
+        for i in 0..3 {
+            assert_that!(subscriber.receive().unwrap(), is_some);
+            assert_that!(subscriber.number_of_pending_samples().unwrap(), eq 2 - i);
+        }
+        assert_that!(subscriber.has_samples().unwrap(), eq false);
+    }
+
+    #[test]
+    fn subscriber_can_receive_copies_and_releases_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_borrowed_samples(1)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(subscriber.receive_copy().unwrap(), eq None);
+        for i in 0..3 {
+            assert_that!(publisher.send_copy(i), is_ok);
+            assert_that!(subscriber.receive_copy().unwrap(), eq Some(i));
+        }
+    }
+
+    #[test]
+    fn subscriber_can_receive_into_buffer<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<[u16]>()
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().max_slice_len(4).create().unwrap();
+
+        let mut buffer = [0u8; 8];
+        assert_that!(subscriber.receive_into(&mut buffer).unwrap(), eq None);
+
+        let sample = publisher.loan_slice_uninit(2).unwrap();
+        let sample = sample.write_from_fn(|i| 0x0101 * (i as u16 + 1));
+        assert_that!(sample.send(), is_ok);
+        assert_that!(subscriber.receive_into(&mut buffer).unwrap(), eq Some(4));
+        assert_that!(buffer, eq [1, 1, 2, 2, 0, 0, 0, 0]);
+
+        let sample = publisher.loan_slice(4).unwrap();
+        assert_that!(sample.send(), is_ok);
+        let result = subscriber.receive_into(&mut buffer[..7]);
+        assert_that!(result.err(), eq Some(SubscriberReceiveError::InsufficientBufferSize));
+        assert_that!(subscriber.has_samples().unwrap(), eq false);
+    }
+
+    #[test]
+    fn simple_communication_works_publisher_created_first<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut2.subscriber_builder().create().unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+
+        assert_that!(publisher.send_copy(1234), is_ok);
+        assert_that!(publisher.send_copy(4567), is_ok);
+
+        let result = subscriber.receive().unwrap();
+        assert_that!(result, is_some);
+        assert_that!(*result.unwrap(), eq 1234);
 
         let result = subscriber.receive().unwrap();
         assert_that!(result, is_some);
@@ -1140,6 +1591,276 @@ mod service_publish_subscribe {
         }
     }
 
+    #[test]
+    fn subscriber_receives_from_publishers_in_turns<Sut: Service>() {
+        const NUMBER_OF_PUBLISHERS: usize = 3;
+        const NUMBER_OF_SAMPLES: u64 = 4;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(NUMBER_OF_PUBLISHERS)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().receive_order(), eq ReceiveOrder::RoundRobin);
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publishers: Vec<_> = (0..NUMBER_OF_PUBLISHERS)
+            .map(|_| sut.publisher_builder().create().unwrap())
+            .collect();
+
+        // the first publisher sends a burst that must not starve the others
+        for publisher in &publishers {
+            for n in 0..NUMBER_OF_SAMPLES {
+                assert_that!(publisher.send_copy(n), is_ok);
+            }
+        }
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            let mut senders = vec![];
+            for _ in 0..NUMBER_OF_PUBLISHERS {
+                let sample = subscriber.receive().unwrap().unwrap();
+                assert_that!(*sample, eq n);
+                senders.push(sample.header().publisher_id());
+            }
+            for publisher in &publishers {
+                assert_that!(senders, contains publisher.id());
+            }
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn subscriber_receives_samples_of_all_publishers_in_send_order<Sut: Service>() {
+        const NUMBER_OF_PUBLISHERS: usize = 3;
+        const NUMBER_OF_SAMPLES: usize = 12;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .max_publishers(NUMBER_OF_PUBLISHERS)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .subscriber_receive_order(ReceiveOrder::SendOrder)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        assert_that!(sut2.static_config().receive_order(), eq ReceiveOrder::SendOrder);
+
+        let subscriber = sut2.subscriber_builder().create().unwrap();
+        let publishers: Vec<_> = (0..NUMBER_OF_PUBLISHERS)
+            .map(|_| sut.publisher_builder().create().unwrap())
+            .collect();
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            let publisher = &publishers[(n * n + 1) % NUMBER_OF_PUBLISHERS];
+            assert_that!(publisher.send_copy(n as u64), is_ok);
+        }
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq n as u64);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn subscriber_receives_samples_of_all_publishers_in_total_order<Sut: Service>() {
+        const NUMBER_OF_PUBLISHERS: usize = 3;
+        const NUMBER_OF_SAMPLES: usize = 12;
+        const HOLD_BACK: Duration = Duration::from_secs(3600);
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(NUMBER_OF_PUBLISHERS)
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .subscriber_receive_order(ReceiveOrder::TotalOrder(HOLD_BACK))
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().receive_order(), eq ReceiveOrder::TotalOrder(HOLD_BACK));
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publishers: Vec<_> = (0..NUMBER_OF_PUBLISHERS)
+            .map(|_| sut.publisher_builder().create().unwrap())
+            .collect();
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            let publisher = &publishers[(n * n + 1) % NUMBER_OF_PUBLISHERS];
+            assert_that!(publisher.send_copy(n as u64), is_ok);
+        }
+
+        // no sample is missing, therefore no sample is held back
+        for n in 0..NUMBER_OF_SAMPLES {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq n as u64);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn subscriber_with_total_order_holds_back_sample_after_missing_sample<Sut: Service>() {
+        const HOLD_BACK: Duration = Duration::from_millis(50);
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_receive_order(ReceiveOrder::TotalOrder(HOLD_BACK))
+            .create()
+            .unwrap();
+
+        // every second sample is dropped by the publisher and never arrives
+        let subscriber = sut
+            .subscriber_builder()
+            .decimation(Decimation::EveryNth(2))
+            .create()
+            .unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        for n in 0..3 {
+            assert_that!(publisher.send_copy(n), is_ok);
+        }
+
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 0);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+
+        std::thread::sleep(HOLD_BACK);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 2);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn subscriber_receives_latest_sample_per_key<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(5)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        assert_that!(subscriber.receive_latest_per_key().unwrap(), len 0);
+
+        for (key, value) in [(1, 10), (2, 20), (1, 11), (3, 30), (2, 21)] {
+            let mut sample = publisher.loan().unwrap();
+            sample.set_key(key);
+            assert_that!(sample.header().key(), eq key);
+            assert_that!(sample.write_payload(value).send(), is_ok);
+        }
+
+        let latest_per_key = subscriber.receive_latest_per_key().unwrap();
+        assert_that!(latest_per_key, len 3);
+        assert_that!(*latest_per_key[&1], eq 11);
+        assert_that!(*latest_per_key[&2], eq 21);
+        assert_that!(*latest_per_key[&3], eq 30);
+        assert_that!(latest_per_key[&3].header().key(), eq 3);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn subscriber_receive_latest_per_key_keeps_samples_beyond_max_borrowed_samples_pending<
+        Sut: Service,
+    >() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(5)
+            .subscriber_max_borrowed_samples(2)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().create().unwrap();
+
+        for key in 0..3 {
+            let mut sample = publisher.loan().unwrap();
+            sample.set_key(key);
+            assert_that!(sample.write_payload(key).send(), is_ok);
+        }
+
+        let latest_per_key = subscriber.receive_latest_per_key().unwrap();
+        assert_that!(latest_per_key, len 2);
+        assert_that!(*latest_per_key[&0], eq 0);
+        assert_that!(*latest_per_key[&1], eq 1);
+        drop(latest_per_key);
+
+        let latest_per_key = subscriber.receive_latest_per_key().unwrap();
+        assert_that!(latest_per_key, len 1);
+        assert_that!(*latest_per_key[&2], eq 2);
+    }
+
+    #[cfg(not(feature = "safety"))]
+    #[test]
+    fn subscriber_reassembles_fragmented_message<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<[u16]>()
+            .subscriber_max_buffer_size(8)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().max_slice_len(4).create().unwrap();
+
+        let message: Vec<u16> = (0..10).collect();
+        assert_that!(publisher.send_slice_copy_fragmented(&message), eq Ok(1));
+        assert_that!(publisher.send_slice_copy_fragmented(&[7, 8]), eq Ok(1));
+
+        assert_that!(subscriber.receive_reassembled().unwrap(), eq Some(message));
+        assert_that!(subscriber.receive_reassembled().unwrap(), eq Some(vec![7, 8]));
+        assert_that!(subscriber.receive_reassembled().unwrap(), eq None);
+    }
+
+    #[cfg(not(feature = "safety"))]
+    #[test]
+    fn subscriber_discards_fragmented_message_with_lost_fragment<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<[u16]>()
+            .subscriber_max_buffer_size(2)
+            .enable_safe_overflow(true)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let publisher = sut.publisher_builder().max_slice_len(4).create().unwrap();
+
+        // the buffer overflows and the first fragment is lost
+        let message: Vec<u16> = (0..12).collect();
+        assert_that!(publisher.send_slice_copy_fragmented(&message), is_ok);
+        assert_that!(subscriber.receive_reassembled().unwrap(), eq None);
+
+        assert_that!(publisher.send_slice_copy_fragmented(&[3]), is_ok);
+        assert_that!(subscriber.receive_reassembled().unwrap(), eq Some(vec![3]));
+    }
+
     #[test]
     fn multi_channel_communication_with_max_subscribers_and_publishers<Sut: Service>() {
         const MAX_PUB: usize = 5;
@@ -1264,46 +1985,153 @@ mod service_publish_subscribe {
     }
 
     #[test]
-    fn publish_non_overflow_with_greater_history_than_buffer_fails<Sut: Service>() {
+    fn subscribers_of_same_service_can_have_different_overflow_strategies<Sut: Service>() {
         let service_name = generate_name();
         let node = NodeBuilder::new().create::<Sut>().unwrap();
+        const BUFFER_SIZE: usize = 2;
 
         let sut = node
             .service_builder(service_name)
             .publish_subscribe::<usize>()
-            .enable_safe_overflow(false)
-            .history_size(12)
-            .subscriber_max_buffer_size(11)
-            .create();
+            .enable_safe_overflow(true)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .max_subscribers(2)
+            .create()
+            .unwrap();
 
-        assert_that!(sut, is_err);
-        assert_that!(
-            sut.err().unwrap(), eq
-            PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize
-        );
+        let publisher = sut.publisher_builder().create().unwrap();
+        let discard_oldest = sut
+            .subscriber_builder()
+            .unable_to_deliver_strategy(OverflowStrategy::DiscardOldest)
+            .create()
+            .unwrap();
+        let discard_newest = sut
+            .subscriber_builder()
+            .unable_to_deliver_strategy(OverflowStrategy::DiscardNewest)
+            .create()
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE + 3 {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
+
+        assert_that!(discard_oldest.number_of_dropped_samples(), eq 3);
+        assert_that!(discard_newest.number_of_dropped_samples(), eq 3);
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(*discard_oldest.receive().unwrap().unwrap(), eq i + 3);
+            assert_that!(*discard_newest.receive().unwrap().unwrap(), eq i);
+        }
+        assert_that!(discard_oldest.receive().unwrap(), is_none);
+        assert_that!(discard_newest.receive().unwrap(), is_none);
     }
 
     #[test]
-    fn publish_history_is_delivered_on_subscription<Sut: Service>() {
-        const BUFFER_SIZE: usize = 2;
+    fn subscriber_overflow_strategy_overrides_publisher_strategy<Sut: Service>() {
         let service_name = generate_name();
         let node = NodeBuilder::new().create::<Sut>().unwrap();
+        const BUFFER_SIZE: usize = 3;
 
         let sut = node
             .service_builder(service_name)
             .publish_subscribe::<usize>()
-            .history_size(3)
+            .enable_safe_overflow(false)
             .subscriber_max_buffer_size(BUFFER_SIZE)
             .create()
             .unwrap();
 
-        let sut_publisher = sut.publisher_builder().create().unwrap();
-        assert_that!(sut_publisher.send_copy(29), is_ok);
-        assert_that!(sut_publisher.send_copy(32), is_ok);
-        assert_that!(sut_publisher.send_copy(35), is_ok);
-
-        let sut_subscriber = sut.subscriber_builder().create().unwrap();
-        assert_that!(sut_publisher.update_connections(), is_ok);
+        let publisher = sut
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()
+            .unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .unable_to_deliver_strategy(OverflowStrategy::DiscardOldest)
+            .create()
+            .unwrap();
+
+        // the publisher would block forever without the strategy of the subscriber
+        for i in 0..2 * BUFFER_SIZE {
+            assert_that!(publisher.send_copy(i), eq Ok(1));
+        }
+
+        assert_that!(subscriber.number_of_dropped_samples(), eq BUFFER_SIZE as u64);
+        for i in 0..BUFFER_SIZE {
+            assert_that!(*subscriber.receive().unwrap().unwrap(), eq i + BUFFER_SIZE);
+        }
+    }
+
+    #[test]
+    fn dropped_samples_are_counted_from_the_creation_of_the_subscriber<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<usize>()
+            .subscriber_max_buffer_size(1)
+            .history_size(0)
+            .max_subscribers(1)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for i in 0..5 {
+            assert_that!(publisher.send_copy(i), is_ok);
+        }
+        assert_that!(subscriber.number_of_dropped_samples(), eq 4);
+        drop(subscriber);
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(subscriber.number_of_dropped_samples(), eq 0);
+        assert_that!(publisher.send_copy(12), is_ok);
+        assert_that!(subscriber.number_of_dropped_samples(), eq 0);
+    }
+
+    #[test]
+    fn publish_non_overflow_with_greater_history_than_buffer_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<usize>()
+            .enable_safe_overflow(false)
+            .history_size(12)
+            .subscriber_max_buffer_size(11)
+            .create();
+
+        assert_that!(sut, is_err);
+        assert_that!(
+            sut.err().unwrap(), eq
+            PublishSubscribeCreateError::SubscriberBufferMustBeLargerThanHistorySize
+        );
+    }
+
+    #[test]
+    fn publish_history_is_delivered_on_subscription<Sut: Service>() {
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<usize>()
+            .history_size(3)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut_publisher.send_copy(29), is_ok);
+        assert_that!(sut_publisher.send_copy(32), is_ok);
+        assert_that!(sut_publisher.send_copy(35), is_ok);
+
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.update_connections(), is_ok);
 
         for i in 0..BUFFER_SIZE {
             let data = sut_subscriber.receive().unwrap();
@@ -1336,6 +2164,135 @@ mod service_publish_subscribe {
         assert_that!(data, is_none);
     }
 
+    #[test]
+    fn receive_history_returns_only_samples_sent_before_subscription<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<usize>()
+            .history_size(3)
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut_publisher.send_copy(29), is_ok);
+        assert_that!(sut_publisher.send_copy(32), is_ok);
+
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(sut_publisher.send_copy(35), is_ok);
+
+        assert_that!(*sut_subscriber.receive_history().unwrap().unwrap(), eq 29);
+        assert_that!(*sut_subscriber.receive_history().unwrap().unwrap(), eq 32);
+        assert_that!(sut_subscriber.receive_history().unwrap(), is_none);
+        assert_that!(sut_subscriber.number_of_pending_samples().unwrap(), eq 1);
+
+        assert_that!(*sut_subscriber.receive().unwrap().unwrap(), eq 35);
+        assert_that!(sut_subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn receive_history_does_not_return_samples_sent_after_subscription<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<usize>()
+            .history_size(3)
+            .create()
+            .unwrap();
+
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        let sut_publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut_publisher.send_copy(12), is_ok);
+
+        assert_that!(sut_subscriber.receive_history().unwrap(), is_none);
+        assert_that!(sut_subscriber.has_samples().unwrap(), eq true);
+        assert_that!(*sut_subscriber.receive().unwrap().unwrap(), eq 12);
+    }
+
+    #[test]
+    fn samples_of_all_publishers_are_numbered_in_send_order<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<usize>()
+            .max_publishers(2)
+            .create()
+            .unwrap();
+
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        let sut_publisher_1 = sut.publisher_builder().create().unwrap();
+        let sut_publisher_2 = sut.publisher_builder().create().unwrap();
+
+        let next_sequence_number = sut.dynamic_config().next_sequence_number();
+        assert_that!(sut_publisher_1.send_copy(1), is_ok);
+        assert_that!(sut_publisher_2.send_copy(2), is_ok);
+
+        let mut sequence_numbers = vec![];
+        while let Some(sample) = sut_subscriber.receive().unwrap() {
+            sequence_numbers.push((*sample, sample.header().sequence_number()));
+        }
+        sequence_numbers.sort();
+
+        assert_that!(sequence_numbers, eq vec![(1, next_sequence_number), (2, next_sequence_number + 1)]);
+        assert_that!(sut.dynamic_config().next_sequence_number(), eq next_sequence_number + 2);
+    }
+
+    #[test]
+    fn samples_are_numbered_and_timestamped_per_publisher<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let now = || {
+            Time::now_with_clock(ClockType::default())
+                .unwrap()
+                .as_duration()
+        };
+
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<usize>()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(5)
+            .create()
+            .unwrap();
+
+        let sut_subscriber = sut.subscriber_builder().create().unwrap();
+        let sut_publisher_1 = sut.publisher_builder().create().unwrap();
+        let sut_publisher_2 = sut.publisher_builder().create().unwrap();
+
+        let start = now();
+        for n in 0..3 {
+            assert_that!(sut_publisher_1.send_copy(n), is_ok);
+        }
+        for n in 0..2 {
+            assert_that!(sut_publisher_2.send_copy(n), is_ok);
+        }
+
+        let mut publisher_1_samples = vec![];
+        let mut publisher_2_samples = vec![];
+        while let Some(sample) = sut_subscriber.receive().unwrap() {
+            let timestamp = sample.header().publish_timestamp();
+            assert_that!(timestamp, ge start);
+            assert_that!(timestamp, le now());
+
+            let details = (*sample, sample.header().publisher_sequence_number());
+            if sample.header().publisher_id() == sut_publisher_1.id() {
+                publisher_1_samples.push(details);
+            } else {
+                publisher_2_samples.push(details);
+            }
+        }
+
+        assert_that!(publisher_1_samples, eq vec![(0, 0), (1, 1), (2, 2)]);
+        assert_that!(publisher_2_samples, eq vec![(0, 0), (1, 1)]);
+    }
+
     #[test]
     fn publish_send_copy_with_huge_overflow_works<Sut: Service>() {
         let service_name = generate_name();
@@ -1440,7 +2397,7 @@ mod service_publish_subscribe {
 
             let sample = sut_publisher.loan_uninit();
             assert_that!(sample, is_err);
-            assert_that!(sample.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanedChunks);
+            assert_that!(sample.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoans);
 
             // cleanup
             borrowed_samples.clear();
@@ -2135,6 +3092,1273 @@ mod service_publish_subscribe {
         }
     }
 
+    #[test]
+    fn communication_works_with_and_without_read_only_data_segments<Sut: Service>() {
+        let service_name = generate_name();
+
+        for read_only in [true, false] {
+            let mut custom_config = Config::default();
+            custom_config
+                .defaults
+                .publish_subscribe
+                .subscriber_read_only_data_segments = read_only;
+            let node = NodeBuilder::new()
+                .config(&custom_config)
+                .create::<Sut>()
+                .unwrap();
+            let sut = node
+                .service_builder(service_name.clone())
+                .publish_subscribe::<u64>()
+                .create()
+                .unwrap();
+
+            let publisher = sut.publisher_builder().create().unwrap();
+            let subscriber = sut.subscriber_builder().create().unwrap();
+
+            assert_that!(publisher.send_copy(8127), eq Ok(1));
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq 8127);
+        }
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn communication_with_sealed_shared_memory_mechanism_works<Sut: Service>() {
+        use iceoryx2::config::SharedMemoryMechanism;
+
+        let service_name = generate_name();
+        let mut config = Config::default();
+        config.global.service.shared_memory_mechanism = SharedMemoryMechanism::Sealed;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(publisher.send_copy(4711), eq Ok(1));
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 4711);
+    }
+
+    #[test]
+    fn publisher_creation_fails_when_service_memory_budget_is_exceeded<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .max_slice_len(1024)
+            .create()
+            .unwrap();
+        let data_segment_size = publisher.data_segment_report().memory().total_memory();
+        drop(publisher);
+
+        let mut custom_config = Config::default();
+        custom_config
+            .defaults
+            .publish_subscribe
+            .service_memory_budget = data_segment_size * 3 / 2;
+        let node_with_budget = NodeBuilder::new()
+            .config(&custom_config)
+            .create::<Sut>()
+            .unwrap();
+        let sut_with_budget = node_with_budget
+            .service_builder(generate_name())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+
+        let publisher = sut_with_budget
+            .publisher_builder()
+            .max_slice_len(1024)
+            .create();
+        assert_that!(publisher, is_ok);
+
+        let publisher_2 = sut_with_budget
+            .publisher_builder()
+            .max_slice_len(1024)
+            .create();
+        assert_that!(publisher_2, is_err);
+        assert_that!(
+            publisher_2.err().unwrap(), eq
+            PublisherCreateError::ExceedsServiceMemoryBudget
+        );
+
+        drop(publisher);
+        let publisher_2 = sut_with_budget
+            .publisher_builder()
+            .max_slice_len(1024)
+            .create();
+        assert_that!(publisher_2, is_ok);
+    }
+
+    #[test]
+    fn service_memory_budget_is_defined_by_the_creator<Sut: Service>() {
+        let service_name = generate_name();
+        let mut custom_config = Config::default();
+        custom_config
+            .defaults
+            .publish_subscribe
+            .service_memory_budget = 4096;
+        let node_with_budget = NodeBuilder::new()
+            .config(&custom_config)
+            .create::<Sut>()
+            .unwrap();
+        let sut = node_with_budget
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut_2 = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        assert_that!(sut.static_config().memory_budget(), eq 4096);
+        assert_that!(sut_2.static_config().memory_budget(), eq 4096);
+    }
+
+    #[test]
+    fn dynamic_config_accounts_shared_memory_of_all_publishers<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let data_segment_size = sut
+            .publisher_builder()
+            .memory_breakdown()
+            .data_segment_size();
+
+        assert_that!(sut.dynamic_config().shared_memory_bytes(), eq 0);
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut.dynamic_config().shared_memory_bytes(), eq data_segment_size);
+
+        let publisher_2 = sut.publisher_builder().create().unwrap();
+        assert_that!(sut.dynamic_config().shared_memory_bytes(), eq 2 * data_segment_size);
+
+        drop(publisher);
+        assert_that!(sut.dynamic_config().shared_memory_bytes(), eq data_segment_size);
+
+        drop(publisher_2);
+        assert_that!(sut.dynamic_config().shared_memory_bytes(), eq 0);
+    }
+
+    #[test]
+    fn dynamic_config_accounts_shared_memory_per_node<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let node_2 = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let sut_2 = node_2
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+        let data_segment_size = sut
+            .publisher_builder()
+            .memory_breakdown()
+            .data_segment_size();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(sut.dynamic_config().shared_memory_bytes_of_node(node.id()), eq data_segment_size);
+        assert_that!(sut.dynamic_config().shared_memory_bytes_of_node(node_2.id()), eq 0);
+
+        let _publisher_2 = sut_2.publisher_builder().create().unwrap();
+        assert_that!(sut.dynamic_config().shared_memory_bytes_of_node(node_2.id()), eq data_segment_size);
+        assert_that!(sut.dynamic_config().shared_memory_bytes(), eq 2 * data_segment_size);
+
+        drop(publisher);
+        assert_that!(sut.dynamic_config().shared_memory_bytes_of_node(node.id()), eq 0);
+        assert_that!(sut.dynamic_config().shared_memory_bytes(), eq data_segment_size);
+    }
+
+    #[test]
+    fn publisher_memory_breakdown_describes_data_segment<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .max_subscribers(3)
+            .max_observers(1)
+            .subscriber_max_buffer_size(4)
+            .subscriber_max_borrowed_samples(2)
+            .history_size(2)
+            .create()
+            .unwrap();
+
+        let breakdown = sut
+            .publisher_builder()
+            .max_loaned_samples(5)
+            .memory_breakdown();
+        let sample_size = breakdown.sample_size();
+
+        assert_that!(sample_size, ge core::mem::size_of::<u64>());
+        assert_that!(breakdown.subscriber_queue_memory(), eq 3 * (4 + 2) * sample_size);
+        assert_that!(breakdown.observer_queue_memory(), eq(4 + 2) * sample_size);
+        assert_that!(breakdown.history_memory(), eq 2 * sample_size);
+        assert_that!(breakdown.loan_memory(), eq(5 + 1) * sample_size);
+        assert_that!(breakdown.number_of_samples(), eq 18 + 6 + 2 + 6);
+        assert_that!(breakdown.data_segment_size(), ge breakdown.number_of_samples() * sample_size);
+    }
+
+    #[test]
+    fn publisher_memory_breakdown_predicts_service_memory_budget_violation<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let data_segment_size = sut
+            .publisher_builder()
+            .memory_breakdown()
+            .data_segment_size();
+
+        let mut custom_config = Config::default();
+        custom_config
+            .defaults
+            .publish_subscribe
+            .service_memory_budget = data_segment_size - 1;
+        let node_with_budget = NodeBuilder::new()
+            .config(&custom_config)
+            .create::<Sut>()
+            .unwrap();
+        let sut_with_budget = node_with_budget
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut_with_budget.publisher_builder().create();
+        assert_that!(publisher.err(), eq Some(PublisherCreateError::ExceedsServiceMemoryBudget));
+
+        let publisher = sut_with_budget
+            .publisher_builder()
+            .max_loaned_samples(1)
+            .create();
+        assert_that!(publisher, is_ok);
+    }
+
+    #[test]
+    fn publisher_creation_fails_when_node_shared_memory_quota_is_exceeded<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+        let publisher = sut
+            .publisher_builder()
+            .max_slice_len(1024)
+            .create()
+            .unwrap();
+        let data_segment_size = publisher.data_segment_report().memory().total_memory();
+        drop(publisher);
+
+        let mut custom_config = Config::default();
+        custom_config.global.node.shared_memory_quota = data_segment_size * 3 / 2;
+        let node_with_quota = NodeBuilder::new()
+            .config(&custom_config)
+            .create::<Sut>()
+            .unwrap();
+        let sut_1 = node_with_quota
+            .service_builder(generate_name())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+        let sut_2 = node_with_quota
+            .service_builder(generate_name())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+
+        let publisher = sut_1.publisher_builder().max_slice_len(1024).create();
+        assert_that!(publisher, is_ok);
+
+        // the quota is shared by all services of the node
+        let publisher_2 = sut_2.publisher_builder().max_slice_len(1024).create();
+        assert_that!(publisher_2, is_err);
+        assert_that!(
+            publisher_2.err().unwrap(), eq
+            PublisherCreateError::ExceedsNodeSharedMemoryQuota
+        );
+
+        // other nodes have their own quota
+        let other_node = NodeBuilder::new()
+            .config(&custom_config)
+            .create::<Sut>()
+            .unwrap();
+        let other_sut = other_node
+            .service_builder(generate_name())
+            .publish_subscribe::<[u8]>()
+            .create()
+            .unwrap();
+        assert_that!(
+            other_sut.publisher_builder().max_slice_len(1024).create(),
+            is_ok
+        );
+
+        drop(publisher);
+        let publisher_2 = sut_2.publisher_builder().max_slice_len(1024).create();
+        assert_that!(publisher_2, is_ok);
+    }
+
+    #[test]
+    fn subscriber_group_receives_every_sample_once_in_round_robin<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .subscriber_group_delivery(GroupDeliveryStrategy::RoundRobin)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let members = [
+            sut.subscriber_builder()
+                .buffer_size(4)
+                .group(1)
+                .create()
+                .unwrap(),
+            sut.subscriber_builder()
+                .buffer_size(4)
+                .group(1)
+                .create()
+                .unwrap(),
+        ];
+        let other_group = sut
+            .subscriber_builder()
+            .buffer_size(4)
+            .group(2)
+            .create()
+            .unwrap();
+        let ungrouped = sut.subscriber_builder().buffer_size(4).create().unwrap();
+
+        for i in 0..4 {
+            assert_that!(publisher.send_copy(i).unwrap(), eq 3);
+        }
+
+        let receive_all = |subscriber: &Subscriber<Sut, u64>| {
+            let mut received = vec![];
+            while let Some(sample) = subscriber.receive().unwrap() {
+                received.push(*sample);
+            }
+            received
+        };
+
+        let mut received_by_group = vec![];
+        for member in &members {
+            let received = receive_all(member);
+            assert_that!(received, len 2);
+            received_by_group.extend(received);
+        }
+        received_by_group.sort();
+        assert_that!(received_by_group, eq vec![0, 1, 2, 3]);
+        assert_that!(receive_all(&other_group), eq vec![0, 1, 2, 3]);
+        assert_that!(receive_all(&ungrouped), eq vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn subscriber_group_delivers_to_least_loaded_member<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(4)
+            .subscriber_group_delivery(GroupDeliveryStrategy::LeastLoaded)
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().group_delivery_strategy(), eq GroupDeliveryStrategy::LeastLoaded);
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let busy_member = sut
+            .subscriber_builder()
+            .buffer_size(4)
+            .group(7)
+            .create()
+            .unwrap();
+        let idle_member = sut
+            .subscriber_builder()
+            .buffer_size(4)
+            .group(7)
+            .create()
+            .unwrap();
+
+        assert_that!(publisher.send_copy(1).unwrap(), eq 1);
+        assert_that!(publisher.send_copy(2).unwrap(), eq 1);
+        // the idle member consumes immediately and stays the least loaded one
+        let sample = idle_member.receive().unwrap().unwrap();
+        let busy_member_value = if *sample == 1 { 2 } else { 1 };
+        drop(sample);
+
+        assert_that!(publisher.send_copy(3).unwrap(), eq 1);
+        assert_that!(*idle_member.receive().unwrap().unwrap(), eq 3);
+        assert_that!(*busy_member.receive().unwrap().unwrap(), eq busy_member_value);
+        assert_that!(busy_member.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn overflowed_samples_are_routed_into_dead_letter_service<Sut: Service>() {
+        let service_name = generate_name();
+        let dead_letter_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(true)
+            .dead_letter_service(dead_letter_name.clone(), true)
+            .create()
+            .unwrap();
+        assert_that!(sut.static_config().dead_letter_service(), eq Some(&dead_letter_name));
+
+        let dead_letters = node
+            .service_builder(dead_letter_name)
+            .publish_subscribe::<[u8]>()
+            .open_or_create()
+            .unwrap();
+        let auditor = dead_letters.subscriber_builder().create().unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().buffer_size(1).create().unwrap();
+
+        publisher.send_copy(1234).unwrap();
+        publisher.send_copy(5678).unwrap();
+
+        let sample = auditor.receive().unwrap().unwrap();
+        let (letter, payload) = DeadLetter::from_bytes(sample.payload()).unwrap();
+        assert_that!(letter.reason(), eq DeadLetterReason::Overflow);
+        assert_that!(letter.payload_size(), eq core::mem::size_of::<u64>());
+        assert_that!(payload, eq 1234u64.to_ne_bytes());
+        assert_that!(auditor.receive().unwrap(), is_none);
+
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 5678);
+    }
+
+    #[test]
+    fn discarded_samples_are_routed_into_dead_letter_service<Sut: Service>() {
+        let service_name = generate_name();
+        let dead_letter_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(false)
+            .dead_letter_service(dead_letter_name.clone(), false)
+            .create()
+            .unwrap();
+
+        let dead_letters = node
+            .service_builder(dead_letter_name)
+            .publish_subscribe::<[u8]>()
+            .open_or_create()
+            .unwrap();
+        let auditor = dead_letters.subscriber_builder().create().unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().buffer_size(1).create().unwrap();
+
+        publisher.send_copy(1234).unwrap();
+        publisher.send_copy(5678).unwrap();
+
+        let sample = auditor.receive().unwrap().unwrap();
+        let (letter, payload) = DeadLetter::from_bytes(sample.payload()).unwrap();
+        assert_that!(letter.reason(), eq DeadLetterReason::Discarded);
+        assert_that!(letter.payload_size(), eq core::mem::size_of::<u64>());
+        assert_that!(payload, len 0);
+
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1234);
+    }
+
+    #[test]
+    fn service_cannot_be_its_own_dead_letter_service<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .dead_letter_service(service_name, true)
+            .create();
+
+        assert_that!(sut.err(), eq Some(PublishSubscribeCreateError::DeadLetterServiceRefersToItself));
+    }
+
+    #[test]
+    fn publishers_share_the_dead_letter_publisher_of_the_node<Sut: Service>() {
+        const NUMBER_OF_PUBLISHERS: usize = 5;
+        let service_name = generate_name();
+        let dead_letter_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(NUMBER_OF_PUBLISHERS)
+            .dead_letter_service(dead_letter_name.clone(), true)
+            .create()
+            .unwrap();
+
+        let dead_letters = node
+            .service_builder(dead_letter_name)
+            .publish_subscribe::<[u8]>()
+            .max_publishers(1)
+            .open_or_create()
+            .unwrap();
+
+        let mut publishers = vec![];
+        for _ in 0..NUMBER_OF_PUBLISHERS {
+            publishers.push(sut.publisher_builder().create().unwrap());
+        }
+
+        assert_that!(dead_letters.dynamic_config().number_of_publishers(), eq 1);
+        drop(publishers);
+        assert_that!(dead_letters.dynamic_config().number_of_publishers(), eq 0);
+    }
+
+    #[test]
+    fn dead_letter_service_with_own_dead_letter_service_is_rejected<Sut: Service>() {
+        let service_name = generate_name();
+        let dead_letter_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .dead_letter_service(dead_letter_name.clone(), true)
+            .create()
+            .unwrap();
+
+        let _dead_letters = node
+            .service_builder(dead_letter_name)
+            .publish_subscribe::<[u8]>()
+            .dead_letter_service(service_name, true)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create();
+        assert_that!(publisher.err(), eq Some(PublisherCreateError::UnableToCreateDeadLetterChannel));
+    }
+
+    #[test]
+    fn qos_profile_from_config_is_applied<Sut: Service>() {
+        let service_name = generate_name();
+        let mut profile = QosProfile::default();
+        profile.max_subscribers = Some(12);
+        profile.subscriber_max_buffer_size = Some(16);
+        profile.publisher_history_size = Some(3);
+        profile.enable_safe_overflow = Some(false);
+
+        let mut config = Config::default();
+        config
+            .qos_profiles
+            .insert("sensor_high_rate".to_string(), profile);
+
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .qos_profile("sensor_high_rate")
+            .max_subscribers(13)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_supported_subscribers(), eq 13);
+        assert_that!(sut.static_config().subscriber_max_buffer_size(), eq 16);
+        assert_that!(sut.static_config().history_size(), eq 3);
+        assert_that!(sut.static_config().has_safe_overflow(), eq false);
+        assert_that!(sut.static_config().max_supported_publishers(), eq config.defaults.publish_subscribe.max_publishers);
+
+        let sut2 = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .enable_safe_overflow(true)
+            .qos_profile("sensor_high_rate")
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn unknown_qos_profile_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .qos_profile("does_not_exist")
+            .create();
+        assert_that!(sut.err(), eq Some(PublishSubscribeCreateError::UnknownQosProfile));
+
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .qos_profile("does_not_exist")
+            .open();
+        assert_that!(sut.err(), eq Some(PublishSubscribeOpenError::UnknownQosProfile));
+    }
+
+    #[test]
+    fn create_fails_when_service_exceeds_name_or_attribute_limits<Sut: Service>() {
+        let service_name = generate_name();
+        let mut config = Config::default();
+        config.global.service.max_name_length = service_name.len() - 1;
+        config.global.service.max_attributes = 1;
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut.err(), eq Some(PublishSubscribeCreateError::ServiceNameExceedsMaxLength));
+
+        config.global.service.max_name_length = service_name.len();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .create_with_attributes(
+                &AttributeSpecifier::new()
+                    .define("first", "value")
+                    .define("second", "value"),
+            );
+        assert_that!(sut.err(), eq Some(PublishSubscribeCreateError::ExceedsMaxNumberOfAttributes));
+
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create_with_attributes(&AttributeSpecifier::new().define("first", "value"));
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn create_fails_when_max_number_of_services_is_reached<Sut: Service>() {
+        const MAX_SERVICES: usize = 2;
+        let domain = FileName::new(
+            format!(
+                "pubsub_tests_domain_{}",
+                UniqueSystemId::new().unwrap().value()
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+        let mut config = Config::default();
+        config.global.service.max_services = MAX_SERVICES;
+        let node = NodeBuilder::new()
+            .config(&config)
+            .domain(domain)
+            .create::<Sut>()
+            .unwrap();
+
+        let mut services = vec![];
+        for _ in 0..MAX_SERVICES {
+            let service = node
+                .service_builder(generate_name())
+                .publish_subscribe::<u64>()
+                .create();
+            assert_that!(service, is_ok);
+            services.push(service.unwrap());
+        }
+
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut.err(), eq Some(PublishSubscribeCreateError::ExceedsMaxNumberOfServices));
+
+        services.pop();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .create();
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn observer_does_not_occupy_subscriber_slot<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .max_subscribers(1)
+            .max_observers(1)
+            .create()
+            .unwrap();
+
+        let subscriber = sut.subscriber_builder().create();
+        assert_that!(subscriber, is_ok);
+        let observer = sut.observer_builder().create();
+        assert_that!(observer, is_ok);
+        assert_that!(observer.as_ref().unwrap().is_observer(), eq true);
+        assert_that!(subscriber.as_ref().unwrap().is_observer(), eq false);
+
+        assert_that!(sut.dynamic_config().number_of_subscribers(), eq 1);
+        assert_that!(sut.dynamic_config().number_of_observers(), eq 1);
+
+        let observer_2 = sut.observer_builder().create();
+        assert_that!(observer_2.err(), eq Some(SubscriberCreateError::ExceedsMaxSupportedObservers));
+
+        drop(observer);
+        assert_that!(sut.dynamic_config().number_of_observers(), eq 0);
+        assert_that!(sut.dynamic_config().number_of_subscribers(), eq 1);
+
+        let observer = sut.observer_builder().create();
+        assert_that!(observer, is_ok);
+    }
+
+    #[test]
+    fn publisher_reserves_no_observer_memory_by_default<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_supported_observers(), eq 0);
+        assert_that!(sut.publisher_builder().memory_breakdown().observer_queue_memory(), eq 0);
+    }
+
+    #[test]
+    fn observer_creation_fails_when_service_has_no_observers<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .max_observers(0)
+            .create()
+            .unwrap();
+
+        let observer = sut.observer_builder().create();
+        assert_that!(observer.err(), eq Some(SubscriberCreateError::ExceedsMaxSupportedObservers));
+        assert_that!(sut.subscriber_builder().create(), is_ok);
+    }
+
+    #[test]
+    fn observer_receives_samples_but_is_not_counted_as_recipient<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .max_observers(1)
+            .history_size(0)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let observer = sut.observer_builder().create().unwrap();
+        assert_that!(publisher.send_copy(12), eq Ok(0));
+
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(publisher.send_copy(34), eq Ok(1));
+
+        assert_that!(observer.receive_copy(), eq Ok(Some(12)));
+        assert_that!(observer.receive_copy(), eq Ok(Some(34)));
+        assert_that!(observer.receive_copy(), eq Ok(None));
+        assert_that!(subscriber.receive_copy(), eq Ok(Some(34)));
+        assert_that!(subscriber.receive_copy(), eq Ok(None));
+    }
+
+    #[test]
+    fn publisher_never_blocks_on_full_observer<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .max_observers(1)
+            .enable_safe_overflow(false)
+            .subscriber_max_buffer_size(1)
+            .history_size(0)
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()
+            .unwrap();
+        let observer = sut.observer_builder().create().unwrap();
+
+        for i in 0..5 {
+            assert_that!(publisher.send_copy(i), eq Ok(0));
+        }
+
+        // without safe overflow the observer keeps the oldest sample and misses the others
+        assert_that!(observer.receive_copy(), eq Ok(Some(0)));
+        assert_that!(observer.receive_copy(), eq Ok(None));
+
+        // all samples the observer missed were returned to the publisher
+        for i in 0..5 {
+            let sample = publisher.loan_uninit();
+            assert_that!(sample, is_ok);
+            assert_that!(sample.unwrap().write_payload(i).send(), is_ok);
+        }
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_support_required_observers<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .max_observers(1)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .max_observers(2)
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfObservers));
+
+        let sut2 = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .max_observers(1)
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn subscriber_with_every_nth_decimation_receives_every_nth_sample<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(10)
+            .history_size(0)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        let decimated_subscriber = sut
+            .subscriber_builder()
+            .decimation(Decimation::EveryNth(3))
+            .create()
+            .unwrap();
+
+        for i in 0..10 {
+            let expected_recipients = if i % 3 == 0 { 2 } else { 1 };
+            assert_that!(publisher.send_copy(i), eq Ok(expected_recipients));
+        }
+
+        for i in 0..10 {
+            assert_that!(subscriber.receive_copy(), eq Ok(Some(i)));
+        }
+        for i in [0, 3, 6, 9] {
+            assert_that!(decimated_subscriber.receive_copy(), eq Ok(Some(i)));
+        }
+        assert_that!(decimated_subscriber.receive_copy(), eq Ok(None));
+    }
+
+    #[test]
+    fn subscriber_with_min_interval_decimation_receives_at_most_one_sample_per_interval<
+        Sut: Service,
+    >() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .max_observers(1)
+            .subscriber_max_buffer_size(10)
+            .history_size(0)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let decimated_subscriber = sut
+            .subscriber_builder()
+            .decimation(Decimation::MinInterval(Duration::from_secs(3600)))
+            .create()
+            .unwrap();
+        let observer = sut
+            .observer_builder()
+            .decimation(Decimation::MinInterval(Duration::ZERO))
+            .create()
+            .unwrap();
+
+        for i in 0..5 {
+            assert_that!(publisher.send_copy(i), eq Ok(if i == 0 { 1 } else { 0 }));
+        }
+
+        assert_that!(decimated_subscriber.receive_copy(), eq Ok(Some(0)));
+        assert_that!(decimated_subscriber.receive_copy(), eq Ok(None));
+
+        // a zero interval disables the decimation
+        for i in 0..5 {
+            assert_that!(observer.receive_copy(), eq Ok(Some(i)));
+        }
+    }
+
+    #[test]
+    fn subscriber_with_filter_receives_only_accepted_samples<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(10)
+            .subscriber_max_borrowed_samples(1)
+            .history_size(0)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .filter(|_, payload| *payload % 3 == 0)
+            .create()
+            .unwrap();
+
+        for i in 0..10 {
+            assert_that!(publisher.send_copy(i), eq Ok(1));
+        }
+
+        // rejected samples are released and do not count against the borrowed samples
+        for i in [0, 3, 6, 9] {
+            assert_that!(subscriber.receive_copy(), eq Ok(Some(i)));
+        }
+        assert_that!(subscriber.receive_copy(), eq Ok(None));
+    }
+
+    #[test]
+    fn blocking_receive_returns_none_when_timeout_has_passed<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(25);
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let start = std::time::Instant::now();
+        assert_that!(subscriber.blocking_receive(TIMEOUT).unwrap(), is_none);
+        assert_that!(start.elapsed(), ge TIMEOUT);
+
+        assert_that!(publisher.send_copy(5), eq Ok(1));
+        let sample = subscriber.blocking_receive(TIMEOUT).unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 5);
+    }
+
+    #[test]
+    fn blocking_receive_wakes_up_when_sample_arrives<Sut: Service>() {
+        let _watch_dog = Watchdog::new();
+        let service_name = generate_name();
+        let barrier = Barrier::new(2);
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        thread::scope(|s| {
+            s.spawn(|| {
+                let sut2 = node
+                    .service_builder(service_name.clone())
+                    .publish_subscribe::<u64>()
+                    .open()
+                    .unwrap();
+                let publisher = sut2.publisher_builder().create().unwrap();
+
+                barrier.wait();
+                thread::sleep(Duration::from_millis(10));
+                assert_that!(publisher.send_copy(1234), eq Ok(1));
+            });
+
+            barrier.wait();
+            let sample = subscriber
+                .blocking_receive(Duration::from_secs(60))
+                .unwrap();
+            assert_that!(sample, is_some);
+            assert_that!(*sample.unwrap(), eq 1234);
+        });
+    }
+
+    #[test]
+    fn blocking_receive_works_when_service_name_has_max_name_length<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(25);
+        let service_name = generate_name();
+        let mut config = Config::default();
+        config.global.service.max_name_length = service_name.len();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(subscriber.blocking_receive(TIMEOUT).unwrap(), is_none);
+
+        assert_that!(publisher.send_copy(5), eq Ok(1));
+        let sample = subscriber.blocking_receive(TIMEOUT).unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 5);
+    }
+
+    #[test]
+    fn subscriber_can_filter_samples_by_publisher_id<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<[u8]>()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(10)
+            .history_size(0)
+            .create()
+            .unwrap();
+
+        let publisher_1 = sut.publisher_builder().max_slice_len(4).create().unwrap();
+        let publisher_2 = sut.publisher_builder().max_slice_len(4).create().unwrap();
+        let publisher_id = publisher_2.id();
+        let subscriber = sut
+            .subscriber_builder()
+            .filter(move |header, _| header.publisher_id() == publisher_id)
+            .create()
+            .unwrap();
+
+        for i in 0..3 {
+            publisher_1
+                .loan_slice_uninit(1)
+                .unwrap()
+                .write_from_fn(|_| i)
+                .send()
+                .unwrap();
+            publisher_2
+                .loan_slice_uninit(2)
+                .unwrap()
+                .write_from_fn(|_| i)
+                .send()
+                .unwrap();
+        }
+
+        for i in 0..3 {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.header().publisher_id(), eq publisher_id);
+            assert_that!(sample.payload(), eq [i, i]);
+        }
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[derive(Debug, Default, PartialEq, Clone, Copy)]
+    #[repr(C)]
+    struct TestUserHeader {
+        timestamp: u64,
+        counter: u32,
+    }
+
+    #[derive(Debug, Default)]
+    #[repr(C, align(256))]
+    struct OverAlignedUserHeader {
+        value: u8,
+    }
+
+    #[test]
+    fn send_and_receive_hooks_are_called_with_the_header_of_every_sample<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(10)
+            .history_size(0)
+            .create()
+            .unwrap();
+
+        let sent = Arc::new(Mutex::new(vec![]));
+        let received = Arc::new(Mutex::new(vec![]));
+        let sent_clone = sent.clone();
+        let received_clone = received.clone();
+
+        let publisher = sut
+            .publisher_builder()
+            .set_send_hook(Some(move |header: &Header| {
+                sent_clone.lock().unwrap().push(header.sequence_number())
+            }))
+            .create()
+            .unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .filter(|_, payload| *payload != 1)
+            .set_receive_hook(Some(move |header: &Header| {
+                received_clone
+                    .lock()
+                    .unwrap()
+                    .push(header.sequence_number())
+            }))
+            .create()
+            .unwrap();
+
+        for i in 0..3 {
+            assert_that!(publisher.send_copy(i), eq Ok(1));
+        }
+        while subscriber.receive().unwrap().is_some() {}
+
+        let sent = sent.lock().unwrap().clone();
+        let received = received.lock().unwrap().clone();
+        assert_that!(sent, len 3);
+        // filtered samples are not reported to the receive hook
+        assert_that!(received, eq vec![sent[0], sent[2]]);
+    }
+
+    #[test]
+    fn user_header_is_delivered_with_sample<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u64>()
+            .user_header::<TestUserHeader>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().type_details().user_header_size, eq core::mem::size_of::<TestUserHeader>());
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan().unwrap();
+        assert_that!(*sample.user_header(), eq TestUserHeader::default());
+        *sample.user_header_mut() = TestUserHeader {
+            timestamp: 123456789,
+            counter: 42,
+        };
+        *sample.payload_mut() = 8192;
+        sample.send().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 8192);
+        assert_that!(sample.user_header().timestamp, eq 123456789);
+        assert_that!(sample.user_header().counter, eq 42);
+    }
+
+    #[test]
+    fn user_header_is_delivered_with_slice_sample<Sut: Service>() {
+        const NUMBER_OF_ELEMENTS: usize = 7;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<[u16]>()
+            .user_header::<TestUserHeader>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .max_slice_len(NUMBER_OF_ELEMENTS)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher
+            .loan_slice_uninit(NUMBER_OF_ELEMENTS)
+            .unwrap()
+            .write_from_fn(|i| i as u16 * 5);
+        sample.user_header_mut().counter = 99;
+        sample.send().unwrap();
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.user_header().counter, eq 99);
+        assert_that!(sample.payload(), len NUMBER_OF_ELEMENTS);
+        for (i, element) in sample.payload().iter().enumerate() {
+            assert_that!(*element, eq i as u16 * 5);
+        }
+    }
+
+    #[test]
+    fn open_fails_when_user_header_type_differs<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .user_header::<TestUserHeader>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let sut2 = node
+            .service_builder(service_name.clone())
+            .publish_subscribe::<u64>()
+            .user_header::<u64>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let sut2 = node
+            .service_builder(service_name)
+            .publish_subscribe::<u64>()
+            .user_header::<TestUserHeader>()
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn over_aligned_user_header_is_placed_correctly<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(generate_name())
+            .publish_subscribe::<u8>()
+            .user_header::<OverAlignedUserHeader>()
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        for i in 0..4 {
+            let mut sample = publisher.loan().unwrap();
+            let user_header = sample.user_header_mut();
+            assert_that!(user_header as *mut OverAlignedUserHeader as usize % 256, eq 0);
+            user_header.value = i;
+            *sample.payload_mut() = i * 2;
+            sample.send().unwrap();
+        }
+
+        for i in 0..4 {
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.user_header() as *const OverAlignedUserHeader as usize % 256, eq 0);
+            assert_that!(sample.user_header().value, eq i);
+            assert_that!(*sample, eq i * 2);
+        }
+    }
+
     #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
     mod zero_copy {}
 
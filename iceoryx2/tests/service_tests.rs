@@ -14,6 +14,7 @@
 mod service {
     use std::sync::atomic::{AtomicU64, Ordering};
     use std::sync::Barrier;
+    use std::time::Duration;
 
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::event::{EventCreateError, EventOpenError};
@@ -51,9 +52,32 @@ mod service {
             service_name: &ServiceName,
             attributes: &AttributeVerifier,
         ) -> Result<Self::Factory, Self::OpenError>;
+        fn open_with_timeout(
+            &self,
+            service_name: &ServiceName,
+            timeout: Duration,
+        ) -> Result<Self::Factory, Self::OpenError>;
+        fn create_with_added_attribute(
+            &self,
+            service_name: &ServiceName,
+            key: &str,
+            value: &str,
+        ) -> Result<Self::Factory, Self::CreateError>;
+        fn open_with_added_attribute(
+            &self,
+            service_name: &ServiceName,
+            key: &str,
+            value: &str,
+        ) -> Result<Self::Factory, Self::OpenError>;
+        fn create_with_lifetime(
+            &self,
+            service_name: &ServiceName,
+            lifetime: ServiceLifetime,
+        ) -> Result<Self::Factory, Self::CreateError>;
 
         fn assert_create_error(error: Self::CreateError);
         fn assert_open_error(error: Self::OpenError);
+        fn assert_does_not_exist_error(error: Self::OpenError);
         fn assert_attribute_error(error: Self::OpenError);
     }
 
@@ -102,10 +126,63 @@ mod service {
                 .open_with_attributes(attributes)
         }
 
+        fn open_with_timeout(
+            &self,
+            service_name: &ServiceName,
+            timeout: Duration,
+        ) -> Result<Self::Factory, Self::OpenError> {
+            self.node
+                .service_builder(service_name.clone())
+                .publish_subscribe::<u64>()
+                .open_with_timeout(timeout)
+        }
+
+        fn create_with_added_attribute(
+            &self,
+            service_name: &ServiceName,
+            key: &str,
+            value: &str,
+        ) -> Result<Self::Factory, Self::CreateError> {
+            self.node
+                .service_builder(service_name.clone())
+                .publish_subscribe::<u64>()
+                .add_attribute(key, value)
+                .create()
+        }
+
+        fn open_with_added_attribute(
+            &self,
+            service_name: &ServiceName,
+            key: &str,
+            value: &str,
+        ) -> Result<Self::Factory, Self::OpenError> {
+            self.node
+                .service_builder(service_name.clone())
+                .publish_subscribe::<u64>()
+                .add_attribute(key, value)
+                .open()
+        }
+
+        fn create_with_lifetime(
+            &self,
+            service_name: &ServiceName,
+            lifetime: ServiceLifetime,
+        ) -> Result<Self::Factory, Self::CreateError> {
+            self.node
+                .service_builder(service_name.clone())
+                .lifetime(lifetime)
+                .publish_subscribe::<u64>()
+                .create()
+        }
+
         fn assert_attribute_error(error: Self::OpenError) {
             assert_that!(error, eq PublishSubscribeOpenError::IncompatibleAttributes);
         }
 
+        fn assert_does_not_exist_error(error: Self::OpenError) {
+            assert_that!(error, eq PublishSubscribeOpenError::DoesNotExist);
+        }
+
         fn assert_create_error(error: Self::CreateError) {
             assert_that!(
                 error,
@@ -165,10 +242,63 @@ mod service {
                 .open_with_attributes(attributes)
         }
 
+        fn open_with_timeout(
+            &self,
+            service_name: &ServiceName,
+            timeout: Duration,
+        ) -> Result<Self::Factory, Self::OpenError> {
+            self.node
+                .service_builder(service_name.clone())
+                .event()
+                .open_with_timeout(timeout)
+        }
+
+        fn create_with_added_attribute(
+            &self,
+            service_name: &ServiceName,
+            key: &str,
+            value: &str,
+        ) -> Result<Self::Factory, Self::CreateError> {
+            self.node
+                .service_builder(service_name.clone())
+                .event()
+                .add_attribute(key, value)
+                .create()
+        }
+
+        fn open_with_added_attribute(
+            &self,
+            service_name: &ServiceName,
+            key: &str,
+            value: &str,
+        ) -> Result<Self::Factory, Self::OpenError> {
+            self.node
+                .service_builder(service_name.clone())
+                .event()
+                .add_attribute(key, value)
+                .open()
+        }
+
+        fn create_with_lifetime(
+            &self,
+            service_name: &ServiceName,
+            lifetime: ServiceLifetime,
+        ) -> Result<Self::Factory, Self::CreateError> {
+            self.node
+                .service_builder(service_name.clone())
+                .lifetime(lifetime)
+                .event()
+                .create()
+        }
+
         fn assert_attribute_error(error: Self::OpenError) {
             assert_that!(error, eq EventOpenError::IncompatibleAttributes);
         }
 
+        fn assert_does_not_exist_error(error: Self::OpenError) {
+            assert_that!(error, eq EventOpenError::DoesNotExist);
+        }
+
         fn assert_create_error(error: Self::CreateError) {
             assert_that!(
                 error,
@@ -512,6 +642,202 @@ mod service {
         assert_that!(sut_open, is_ok);
     }
 
+    #[test]
+    fn added_attribute_is_defined_and_listed<Sut: Service, Factory: SutFactory<Sut>>() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let sut = test
+            .create_with_added_attribute(&service_name, "unit", "m/s")
+            .unwrap();
+
+        assert_that!(sut.attributes().get("unit"), eq vec!["m/s"]);
+
+        let services = Sut::list(test.node().config()).unwrap();
+        let listed_service = services.iter().find(|s| *s.name() == service_name);
+        assert_that!(listed_service, is_some);
+        assert_that!(listed_service.unwrap().attributes().get("unit"), eq vec!["m/s"]);
+    }
+
+    #[test]
+    fn opener_with_matching_added_attribute_succeeds<Sut: Service, Factory: SutFactory<Sut>>() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let _sut_create = test
+            .create_with_added_attribute(&service_name, "unit", "m/s")
+            .unwrap();
+
+        let sut_open = test.open_with_added_attribute(&service_name, "unit", "m/s");
+
+        assert_that!(sut_open, is_ok);
+    }
+
+    #[test]
+    fn opener_with_mismatching_added_attribute_fails<Sut: Service, Factory: SutFactory<Sut>>() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let _sut_create = test
+            .create_with_added_attribute(&service_name, "unit", "m/s")
+            .unwrap();
+
+        let sut_open = test.open_with_added_attribute(&service_name, "unit", "km/h");
+
+        assert_that!(sut_open, is_err);
+        Factory::assert_attribute_error(sut_open.err().unwrap());
+    }
+
+    #[test]
+    fn persistent_service_outlives_its_creator<Sut: Service, Factory: SutFactory<Sut>>() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = test.node().config().clone();
+
+        let sut = test
+            .create_with_lifetime(&service_name, ServiceLifetime::Persistent)
+            .unwrap();
+        drop(sut);
+
+        assert_that!(Sut::does_exist(&service_name, &config), eq Ok(true));
+        let services = Sut::list(&config).unwrap();
+        let listed_service = services.iter().find(|s| *s.name() == service_name);
+        assert_that!(listed_service.unwrap().lifetime(), eq ServiceLifetime::Persistent);
+
+        let sut_open = test.open(&service_name, &AttributeVerifier::new());
+        assert_that!(sut_open, is_ok);
+        drop(sut_open);
+
+        assert_that!(Sut::remove(&service_name, &config), eq Ok(true));
+        assert_that!(Sut::does_exist(&service_name, &config), eq Ok(false));
+        assert_that!(Sut::remove(&service_name, &config), eq Ok(false));
+    }
+
+    #[test]
+    fn owner_bound_service_is_not_removed_explicitly<Sut: Service, Factory: SutFactory<Sut>>() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = test.node().config().clone();
+
+        let sut = test
+            .create_with_lifetime(&service_name, ServiceLifetime::OwnerBound)
+            .unwrap();
+
+        assert_that!(Sut::remove(&service_name, &config), eq Ok(false));
+        assert_that!(Sut::does_exist(&service_name, &config), eq Ok(true));
+
+        drop(sut);
+        assert_that!(Sut::does_exist(&service_name, &config), eq Ok(false));
+    }
+
+    #[test]
+    fn removed_persistent_service_is_removed_with_last_owner<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_name();
+        let config = test.node().config().clone();
+
+        let sut = test
+            .create_with_lifetime(&service_name, ServiceLifetime::Persistent)
+            .unwrap();
+
+        assert_that!(Sut::remove(&service_name, &config), eq Ok(true));
+        assert_that!(Sut::does_exist(&service_name, &config), eq Ok(true));
+
+        drop(sut);
+        assert_that!(Sut::does_exist(&service_name, &config), eq Ok(false));
+    }
+
+    #[test]
+    fn open_with_timeout_fails_when_service_is_not_created_in_time<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        const TIMEOUT: Duration = Duration::from_millis(25);
+        let test = Factory::new();
+        let service_name = generate_name();
+
+        let start = std::time::Instant::now();
+        let sut = test.open_with_timeout(&service_name, TIMEOUT);
+        assert_that!(start.elapsed(), ge TIMEOUT);
+        assert_that!(sut, is_err);
+        Factory::assert_does_not_exist_error(sut.err().unwrap());
+    }
+
+    #[test]
+    fn open_with_timeout_succeeds_when_service_is_created_while_waiting<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let _watch_dog = Watchdog::new();
+        let test = Factory::new();
+        let service_name = generate_name();
+        let barrier_enter = Barrier::new(2);
+        let barrier_exit = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                barrier_enter.wait();
+                std::thread::sleep(Duration::from_millis(25));
+                let _sut = test
+                    .create(&service_name, &AttributeSpecifier::new())
+                    .unwrap();
+                barrier_exit.wait();
+            });
+
+            barrier_enter.wait();
+            let sut = test.open_with_timeout(&service_name, Duration::from_secs(5));
+            barrier_exit.wait();
+            assert_that!(sut, is_ok);
+        });
+    }
+
+    #[test]
+    fn open_with_timeout_opens_existing_service_immediately<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let test = Factory::new();
+        let service_name = generate_name();
+
+        let _sut = test
+            .create(&service_name, &AttributeSpecifier::new())
+            .unwrap();
+        let sut = test.open_with_timeout(&service_name, Duration::ZERO);
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn node_wait_for_service_returns_true_when_service_is_created_while_waiting<
+        Sut: Service,
+        Factory: SutFactory<Sut>,
+    >() {
+        let _watch_dog = Watchdog::new();
+        let test = Factory::new();
+        let service_name = generate_name();
+        let barrier_enter = Barrier::new(2);
+        let barrier_exit = Barrier::new(2);
+
+        assert_that!(test.node().wait_for_service(&service_name, Duration::from_millis(10)), eq Ok(false));
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                barrier_enter.wait();
+                std::thread::sleep(Duration::from_millis(25));
+                let _sut = test
+                    .create(&service_name, &AttributeSpecifier::new())
+                    .unwrap();
+                barrier_exit.wait();
+            });
+
+            barrier_enter.wait();
+            let result = test
+                .node()
+                .wait_for_service(&service_name, Duration::from_secs(5));
+            barrier_exit.wait();
+            assert_that!(result, eq Ok(true));
+        });
+    }
+
     mod zero_copy {
         use iceoryx2::service::zero_copy::Service;
 
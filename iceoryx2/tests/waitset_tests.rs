@@ -0,0 +1,290 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod waitset {
+    use std::sync::Barrier;
+    use std::time::{Duration, Instant};
+
+    use iceoryx2::port::waitset::{WaitSet, WaitSetAttachmentError};
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::port_factory::event::PortFactory;
+    use iceoryx2::service::Service;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+
+    const TIMEOUT: Duration = Duration::from_millis(25);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "waitset_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn create_event<Sut: Service>(node: &Node<Sut>, name: &ServiceName) -> PortFactory<Sut> {
+        node.service_builder(name.clone())
+            .event()
+            .open_or_create()
+            .unwrap()
+    }
+
+    #[test]
+    fn attaching_listener_twice_fails<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = create_event(&node, &generate_name());
+        let listener = event.listener_builder().create().unwrap();
+
+        let mut sut = WaitSet::new();
+        assert_that!(sut.attach(&listener), is_ok);
+        assert_that!(sut.attach(&listener).err(), eq Some(WaitSetAttachmentError::AlreadyAttached));
+        assert_that!(sut, len 1);
+    }
+
+    #[test]
+    fn detached_attachment_id_is_reused<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event_1 = create_event(&node, &generate_name());
+        let event_2 = create_event(&node, &generate_name());
+        let listener_1 = event_1.listener_builder().create().unwrap();
+        let listener_2 = event_2.listener_builder().create().unwrap();
+
+        let mut sut = WaitSet::new();
+        let id_1 = sut.attach(&listener_1).unwrap();
+        assert_that!(sut.detach(id_1), eq true);
+        assert_that!(sut.detach(id_1), eq false);
+        assert_that!(sut, is_empty);
+
+        let id_2 = sut.attach(&listener_2).unwrap();
+        assert_that!(id_2, eq id_1);
+    }
+
+    #[test]
+    fn wait_reports_the_attachment_that_woke_up<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event_1 = create_event(&node, &generate_name());
+        let event_2 = create_event(&node, &generate_name());
+        let listener_1 = event_1.listener_builder().create().unwrap();
+        let listener_2 = event_2.listener_builder().create().unwrap();
+        let notifier = event_2.notifier_builder().create().unwrap();
+
+        let mut sut = WaitSet::new();
+        let _id_1 = sut.attach(&listener_1).unwrap();
+        let id_2 = sut.attach(&listener_2).unwrap();
+
+        notifier
+            .notify_with_custom_event_id(EventId::new(7))
+            .unwrap();
+
+        let mut events = vec![];
+        let number_of_woken_attachments = sut
+            .timed_wait(|id, event_id| events.push((id, event_id)), TIMEOUT)
+            .unwrap();
+
+        assert_that!(number_of_woken_attachments, eq 1);
+        assert_that!(events, eq vec![(id_2, EventId::new(7))]);
+    }
+
+    #[test]
+    fn wait_reports_attachments_with_higher_priority_first<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event_1 = create_event(&node, &generate_name());
+        let event_2 = create_event(&node, &generate_name());
+        let event_3 = create_event(&node, &generate_name());
+        let listener_1 = event_1.listener_builder().create().unwrap();
+        let listener_2 = event_2.listener_builder().create().unwrap();
+        let listener_3 = event_3.listener_builder().create().unwrap();
+        let notifier_1 = event_1.notifier_builder().create().unwrap();
+        let notifier_2 = event_2.notifier_builder().create().unwrap();
+        let notifier_3 = event_3.notifier_builder().create().unwrap();
+
+        let mut sut = WaitSet::new();
+        let id_1 = sut.attach(&listener_1).unwrap();
+        let id_2 = sut.attach_with_priority(&listener_2, 10).unwrap();
+        let id_3 = sut.attach_with_priority(&listener_3, 5).unwrap();
+
+        notifier_1
+            .notify_with_custom_event_id(EventId::new(1))
+            .unwrap();
+        notifier_2
+            .notify_with_custom_event_id(EventId::new(2))
+            .unwrap();
+        notifier_3
+            .notify_with_custom_event_id(EventId::new(3))
+            .unwrap();
+
+        let mut events = vec![];
+        let number_of_woken_attachments = sut
+            .timed_wait(|id, event_id| events.push((id, event_id)), TIMEOUT)
+            .unwrap();
+
+        assert_that!(number_of_woken_attachments, eq 3);
+        assert_that!(events, eq vec![
+            (id_2, EventId::new(2)),
+            (id_3, EventId::new(3)),
+            (id_1, EventId::new(1))
+        ]);
+    }
+
+    #[test]
+    fn timed_wait_returns_zero_when_no_attachment_woke_up<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = create_event(&node, &generate_name());
+        let listener = event.listener_builder().create().unwrap();
+
+        let mut sut = WaitSet::new();
+        sut.attach(&listener).unwrap();
+
+        let start = Instant::now();
+        let number_of_woken_attachments = sut.timed_wait(|_, _| {}, TIMEOUT).unwrap();
+
+        assert_that!(number_of_woken_attachments, eq 0);
+        assert_that!(start.elapsed(), ge TIMEOUT);
+        assert_that!(sut.try_wait(|_, _| {}).unwrap(), eq 0);
+    }
+
+    #[test]
+    fn detached_listener_does_not_wake_up_the_waitset<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = create_event(&node, &generate_name());
+        let listener = event.listener_builder().create().unwrap();
+        let notifier = event.notifier_builder().create().unwrap();
+
+        let mut sut = WaitSet::new();
+        let id = sut.attach(&listener).unwrap();
+        sut.detach(id);
+
+        notifier.notify().unwrap();
+
+        assert_that!(sut.try_wait(|_, _| {}).unwrap(), eq 0);
+        assert_that!(listener.try_wait_one().unwrap(), is_some);
+    }
+
+    #[test]
+    fn blocking_wait_wakes_up_when_any_attachment_is_notified<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        let service_name_1 = generate_name();
+        let service_name_2 = generate_name();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let node = NodeBuilder::new().create::<Sut>().unwrap();
+                let event_1 = create_event(&node, &service_name_1);
+                let event_2 = create_event(&node, &service_name_2);
+                let listener_1 = event_1.listener_builder().create().unwrap();
+                let listener_2 = event_2.listener_builder().create().unwrap();
+
+                let mut sut = WaitSet::new();
+                sut.attach(&listener_1).unwrap();
+                let id_2 = sut.attach(&listener_2).unwrap();
+                barrier.wait();
+
+                let mut woken_attachments = vec![];
+                let number_of_woken_attachments = sut
+                    .blocking_wait(|id, _| woken_attachments.push(id))
+                    .unwrap();
+
+                assert_that!(number_of_woken_attachments, eq 1);
+                assert_that!(woken_attachments, eq vec![id_2]);
+            });
+
+            barrier.wait();
+            let node = NodeBuilder::new().create::<Sut>().unwrap();
+            let event = create_event(&node, &service_name_2);
+            let notifier = event.notifier_builder().create().unwrap();
+            std::thread::sleep(TIMEOUT);
+            notifier.notify().unwrap();
+        });
+    }
+
+    #[test]
+    fn waitset_wakes_up_once_when_deadline_is_missed<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = node
+            .service_builder(generate_name())
+            .event()
+            .deadline(TIMEOUT)
+            .create()
+            .unwrap();
+        let listener = event.listener_builder().create().unwrap();
+        let notifier = event.notifier_builder().create().unwrap();
+
+        let mut sut = WaitSet::new();
+        sut.attach(&listener).unwrap();
+
+        assert_that!(sut.try_wait(|_, _| {}).unwrap(), eq 0);
+
+        let start = Instant::now();
+        let mut number_of_callbacks = 0;
+        let number_of_woken_attachments = sut
+            .timed_wait(|_, _| number_of_callbacks += 1, TIMEOUT * 10)
+            .unwrap();
+        assert_that!(number_of_woken_attachments, eq 1);
+        assert_that!(number_of_callbacks, eq 0);
+        assert_that!(start.elapsed(), lt TIMEOUT * 10);
+        assert_that!(listener.has_missed_deadline(), eq true);
+
+        // the missed deadline is reported only once until the next notification
+        assert_that!(sut.try_wait(|_, _| {}).unwrap(), eq 0);
+
+        let _ = notifier.notify();
+        assert_that!(sut.try_wait(|_, _| number_of_callbacks += 1).unwrap(), eq 1);
+        assert_that!(number_of_callbacks, eq 1);
+    }
+
+    #[test]
+    fn waitset_wakes_up_once_when_notifier_loses_liveliness<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let event = node
+            .service_builder(generate_name())
+            .event()
+            .notifier_liveliness(TIMEOUT)
+            .create()
+            .unwrap();
+        let listener = event.listener_builder().create().unwrap();
+        let notifier = event.notifier_builder().create().unwrap();
+
+        let mut sut = WaitSet::new();
+        sut.attach(&listener).unwrap();
+
+        assert_that!(sut.try_wait(|_, _| {}).unwrap(), eq 0);
+
+        let start = Instant::now();
+        let mut number_of_callbacks = 0;
+        let number_of_woken_attachments = sut
+            .timed_wait(|_, _| number_of_callbacks += 1, TIMEOUT * 10)
+            .unwrap();
+        assert_that!(number_of_woken_attachments, eq 1);
+        assert_that!(number_of_callbacks, eq 0);
+        assert_that!(start.elapsed(), lt TIMEOUT * 10);
+        assert_that!(listener.notifiers_with_lost_liveliness(), eq vec![notifier.id()]);
+
+        // the lost liveliness is reported only once until the notifier loses it again
+        assert_that!(sut.try_wait(|_, _| {}).unwrap(), eq 0);
+
+        notifier.assert_liveliness();
+        assert_that!(sut.try_wait(|_, _| {}).unwrap(), eq 0);
+        assert_that!(
+            sut.timed_wait(|_, _| {}, TIMEOUT * 10).unwrap(),
+            eq 1
+        );
+    }
+
+    #[instantiate_tests(<iceoryx2::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<iceoryx2::service::process_local::Service>)]
+    mod process_local {}
+}